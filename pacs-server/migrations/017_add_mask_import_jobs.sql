@@ -0,0 +1,39 @@
+-- Migration: Add mask import jobs
+-- Created: 2025-10-20
+-- Description: Tracks imports of DICOM SEG / NIfTI label volumes into mask groups
+
+DO $$ BEGIN
+    CREATE TYPE mask_import_status_enum AS ENUM ('PENDING', 'RUNNING', 'COMPLETED', 'FAILED');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+-- annotation_mask_import_job 테이블 생성
+-- 라벨 볼륨 임포트 작업의 진행 상태와 결과를 저장하는 테이블
+CREATE TABLE IF NOT EXISTS annotation_mask_import_job (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    annotation_id INTEGER NOT NULL REFERENCES annotation_annotation(id) ON DELETE CASCADE,
+    mask_group_id INTEGER REFERENCES annotation_mask_group(id) ON DELETE SET NULL,
+    source_path TEXT NOT NULL,                          -- 원본 볼륨 파일의 스토리지 경로
+    source_format TEXT NOT NULL,                        -- nifti / dicom_seg
+    status mask_import_status_enum NOT NULL DEFAULT 'PENDING',
+    total_frames INTEGER NOT NULL DEFAULT 0,            -- 볼륨에서 추출된 프레임 수
+    imported_masks INTEGER NOT NULL DEFAULT 0,          -- 생성된 마스크 수
+    unmapped_frames JSONB NOT NULL DEFAULT '[]'::jsonb, -- SOP Instance UID에 매핑되지 않은 프레임
+    error_message TEXT,
+    requested_by INTEGER,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_mask_import_job_annotation_id ON annotation_mask_import_job(annotation_id);
+CREATE INDEX IF NOT EXISTS idx_mask_import_job_status ON annotation_mask_import_job(status);
+
+COMMENT ON TABLE annotation_mask_import_job IS 'DICOM SEG / NIfTI 라벨 볼륨을 마스크 그룹으로 임포트하는 작업';
+COMMENT ON COLUMN annotation_mask_import_job.source_path IS '원본 볼륨 파일의 스토리지 경로';
+COMMENT ON COLUMN annotation_mask_import_job.source_format IS '원본 포맷 (nifti, dicom_seg)';
+COMMENT ON COLUMN annotation_mask_import_job.status IS '작업 상태 (PENDING, RUNNING, COMPLETED, FAILED)';
+COMMENT ON COLUMN annotation_mask_import_job.total_frames IS '볼륨에서 추출된 프레임 수';
+COMMENT ON COLUMN annotation_mask_import_job.imported_masks IS '생성된 마스크 수';
+COMMENT ON COLUMN annotation_mask_import_job.unmapped_frames IS 'SOP Instance UID에 매핑되지 않은 프레임 목록';
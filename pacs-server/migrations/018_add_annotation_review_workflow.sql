@@ -0,0 +1,43 @@
+-- Migration: Add annotation review workflow
+-- Created: 2025-10-22
+-- Description: Adds review state machine (DRAFT → SUBMITTED → APPROVED/REJECTED → LOCKED),
+-- reviewer assignment and review comments for annotations
+
+DO $$ BEGIN
+    CREATE TYPE annotation_review_status_enum AS ENUM ('DRAFT', 'SUBMITTED', 'APPROVED', 'REJECTED', 'LOCKED');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+-- 어노테이션 검토 상태 및 검토자 컬럼 추가
+ALTER TABLE annotation_annotation
+    ADD COLUMN IF NOT EXISTS review_status annotation_review_status_enum NOT NULL DEFAULT 'DRAFT',
+    ADD COLUMN IF NOT EXISTS reviewer_id INTEGER REFERENCES security_user(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS reviewed_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_annotation_review_status ON annotation_annotation(project_id, review_status);
+CREATE INDEX IF NOT EXISTS idx_annotation_reviewer ON annotation_annotation(reviewer_id, review_status);
+
+-- annotation_review_comment 테이블 생성
+-- 검토 과정에서 남긴 코멘트를 저장하는 테이블
+CREATE TABLE IF NOT EXISTS annotation_review_comment (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    annotation_id INTEGER NOT NULL REFERENCES annotation_annotation(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES security_user(id),
+    review_status annotation_review_status_enum NOT NULL, -- 코멘트 작성 시점의 검토 상태
+    comment TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_review_comment_annotation ON annotation_review_comment(annotation_id, created_at);
+
+-- 프로젝트 단위 검토자 역할
+INSERT INTO security_role (name, description, scope) VALUES
+    ('REVIEWER', '어노테이션 검토자', 'PROJECT')
+ON CONFLICT (name) DO NOTHING;
+
+COMMENT ON COLUMN annotation_annotation.review_status IS '검토 상태 (DRAFT, SUBMITTED, APPROVED, REJECTED, LOCKED)';
+COMMENT ON COLUMN annotation_annotation.reviewer_id IS '배정된 검토자 ID';
+COMMENT ON COLUMN annotation_annotation.reviewed_at IS '마지막 검토(승인/반려) 시각';
+COMMENT ON TABLE annotation_review_comment IS '어노테이션 검토 코멘트';
+COMMENT ON COLUMN annotation_review_comment.review_status IS '코멘트 작성 시점의 검토 상태';
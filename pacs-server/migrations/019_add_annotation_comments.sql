@@ -0,0 +1,57 @@
+-- Migration: Add threaded comments for annotations and mask groups
+-- Created: 2025-10-23
+-- Description: Adds comment threads with @mentions, resolved state and edit/delete history
+
+DO $$ BEGIN
+    CREATE TYPE comment_target_enum AS ENUM ('ANNOTATION', 'MASK_GROUP');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+-- annotation_comment 테이블 생성
+-- 어노테이션 / 마스크 그룹에 달린 코멘트 스레드를 저장하는 테이블
+CREATE TABLE IF NOT EXISTS annotation_comment (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    target_type comment_target_enum NOT NULL,
+    target_id INTEGER NOT NULL,                          -- 어노테이션 ID 또는 마스크 그룹 ID
+    annotation_id INTEGER NOT NULL REFERENCES annotation_annotation(id) ON DELETE CASCADE, -- 가시성 판단 기준 어노테이션
+    parent_id INTEGER REFERENCES annotation_comment(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES security_user(id),
+    body TEXT NOT NULL,
+    mentions INTEGER[] NOT NULL DEFAULT '{}',            -- 멘션된 프로젝트 멤버 ID 목록
+    is_resolved BOOLEAN NOT NULL DEFAULT false,
+    resolved_by INTEGER REFERENCES security_user(id) ON DELETE SET NULL,
+    resolved_at TIMESTAMPTZ,
+    is_deleted BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_comment_target ON annotation_comment(target_type, target_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_comment_annotation ON annotation_comment(annotation_id);
+CREATE INDEX IF NOT EXISTS idx_comment_parent ON annotation_comment(parent_id);
+CREATE INDEX IF NOT EXISTS idx_comment_mentions ON annotation_comment USING GIN (mentions);
+
+-- annotation_comment_history 테이블 생성
+-- 코멘트 수정/삭제/해결 상태 변경 이력을 저장하는 테이블
+CREATE TABLE IF NOT EXISTS annotation_comment_history (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    comment_id INTEGER NOT NULL REFERENCES annotation_comment(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES security_user(id),
+    action TEXT NOT NULL,                                -- UPDATE, DELETE, RESOLVE, REOPEN
+    body_before TEXT,
+    body_after TEXT,
+    action_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_comment_history_comment ON annotation_comment_history(comment_id);
+
+COMMENT ON TABLE annotation_comment IS '어노테이션 / 마스크 그룹 코멘트 스레드';
+COMMENT ON COLUMN annotation_comment.target_type IS '코멘트 대상 타입 (ANNOTATION, MASK_GROUP)';
+COMMENT ON COLUMN annotation_comment.target_id IS '코멘트 대상 ID';
+COMMENT ON COLUMN annotation_comment.annotation_id IS '가시성 판단 기준이 되는 어노테이션 ID';
+COMMENT ON COLUMN annotation_comment.parent_id IS '상위 코멘트 ID (최상위 코멘트는 NULL)';
+COMMENT ON COLUMN annotation_comment.mentions IS '멘션된 프로젝트 멤버 ID 목록';
+COMMENT ON COLUMN annotation_comment.is_resolved IS '스레드 해결 여부';
+COMMENT ON COLUMN annotation_comment.is_deleted IS '삭제 여부 (답글 유지를 위한 소프트 삭제)';
+COMMENT ON TABLE annotation_comment_history IS '코멘트 변경 이력';
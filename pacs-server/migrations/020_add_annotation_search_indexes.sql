@@ -0,0 +1,24 @@
+-- Migration: Add annotation search indexes
+-- Created: 2025-10-24
+-- Description: Indexes backing the combined annotation search endpoint
+-- (keyset pagination on created_at/updated_at, UID/tool filters and JSONB path predicates)
+
+-- 키셋 페이지네이션: (정렬 키, id) 튜플 비교 및 정렬용 인덱스
+CREATE INDEX IF NOT EXISTS idx_annotation_created_keyset ON annotation_annotation(created_at, id);
+CREATE INDEX IF NOT EXISTS idx_annotation_updated_keyset ON annotation_annotation(updated_at, id);
+
+-- 대시보드의 주 사용 패턴인 프로젝트 단위 최신순 조회용 인덱스
+CREATE INDEX IF NOT EXISTS idx_annotation_project_created_keyset ON annotation_annotation(project_id, created_at, id);
+CREATE INDEX IF NOT EXISTS idx_annotation_project_updated_keyset ON annotation_annotation(project_id, updated_at, id);
+
+-- 단일 필터용 인덱스 (study/series는 001에서 생성됨)
+CREATE INDEX IF NOT EXISTS idx_annotation_instance ON annotation_annotation(instance_uid);
+CREATE INDEX IF NOT EXISTS idx_annotation_tool_name ON annotation_annotation(tool_name);
+
+-- annotation data JSONB 포함(@>) 조건용 인덱스
+-- (measurement_values는 005에서 GIN 인덱스가 생성됨)
+CREATE INDEX IF NOT EXISTS idx_annotation_data ON annotation_annotation USING GIN (data jsonb_path_ops);
+
+COMMENT ON INDEX idx_annotation_created_keyset IS '어노테이션 검색 키셋 페이지네이션 (생성 시간 정렬)';
+COMMENT ON INDEX idx_annotation_updated_keyset IS '어노테이션 검색 키셋 페이지네이션 (수정 시간 정렬)';
+COMMENT ON INDEX idx_annotation_data IS '어노테이션 data JSONB 포함 조건 검색';
@@ -0,0 +1,16 @@
+-- Migration: Add stable external IDs for annotation bundles
+-- Created: 2025-10-25
+-- Description: Adds environment-independent external IDs to annotations and mask groups
+-- so NDJSON bundle imports can be repeated idempotently
+
+ALTER TABLE annotation_annotation
+    ADD COLUMN IF NOT EXISTS external_id UUID NOT NULL DEFAULT gen_random_uuid();
+
+ALTER TABLE annotation_mask_group
+    ADD COLUMN IF NOT EXISTS external_id UUID NOT NULL DEFAULT gen_random_uuid();
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_annotation_external_id ON annotation_annotation(external_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_mask_group_external_id ON annotation_mask_group(external_id);
+
+COMMENT ON COLUMN annotation_annotation.external_id IS '환경 간 이동 시에도 유지되는 어노테이션 외부 식별자 (번들 임포트 멱등성 보장)';
+COMMENT ON COLUMN annotation_mask_group.external_id IS '환경 간 이동 시에도 유지되는 마스크 그룹 외부 식별자 (번들 임포트 멱등성 보장)';
@@ -0,0 +1,44 @@
+-- Migration: Add dataset export jobs
+-- Created: 2025-10-28
+-- Description: Tracks ML dataset exports (COCO JSON, CSV/JSON per-slice manifests) of a project's annotations and masks
+
+DO $$ BEGIN
+    CREATE TYPE dataset_export_status_enum AS ENUM ('PENDING', 'RUNNING', 'COMPLETED', 'FAILED');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+-- annotation_dataset_export_job 테이블 생성
+-- 데이터셋 내보내기 작업의 조건, 진행 상태, 결과 파일 경로를 저장하는 테이블
+CREATE TABLE IF NOT EXISTS annotation_dataset_export_job (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    project_id INTEGER NOT NULL REFERENCES security_project(id) ON DELETE CASCADE,
+    format TEXT NOT NULL,                               -- coco / csv / json
+    filters JSONB NOT NULL DEFAULT '{}'::jsonb,         -- label, model_name, review_status 필터
+    include_signed_urls BOOLEAN NOT NULL DEFAULT FALSE, -- 마스크 다운로드 Signed URL 포함 여부
+    url_ttl_seconds INTEGER,                            -- Signed URL 만료 시간 (초)
+    status dataset_export_status_enum NOT NULL DEFAULT 'PENDING',
+    annotation_count INTEGER NOT NULL DEFAULT 0,        -- 내보낸 어노테이션 수
+    mask_count INTEGER NOT NULL DEFAULT 0,              -- 내보낸 마스크 슬라이스 수
+    skipped_count INTEGER NOT NULL DEFAULT 0,           -- 변환할 수 없어 제외된 항목 수
+    output_path TEXT,                                   -- 결과 파일의 스토리지 경로
+    error_message TEXT,
+    requested_by INTEGER,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_dataset_export_job_project_id ON annotation_dataset_export_job(project_id);
+CREATE INDEX IF NOT EXISTS idx_dataset_export_job_status ON annotation_dataset_export_job(status);
+
+-- 마스크 그룹 모델 이름 필터용 인덱스
+CREATE INDEX IF NOT EXISTS idx_mask_group_model_name ON annotation_mask_group(model_name);
+
+COMMENT ON TABLE annotation_dataset_export_job IS 'ML 학습용 데이터셋 내보내기 작업 (COCO JSON, CSV/JSON 매니페스트)';
+COMMENT ON COLUMN annotation_dataset_export_job.format IS '내보내기 포맷 (coco, csv, json)';
+COMMENT ON COLUMN annotation_dataset_export_job.filters IS '라벨, 모델 이름, 검토 상태 필터';
+COMMENT ON COLUMN annotation_dataset_export_job.include_signed_urls IS '마스크 다운로드 Signed URL 포함 여부 (false면 오브젝트 키만 기록)';
+COMMENT ON COLUMN annotation_dataset_export_job.status IS '작업 상태 (PENDING, RUNNING, COMPLETED, FAILED)';
+COMMENT ON COLUMN annotation_dataset_export_job.skipped_count IS '폴리곤/이미지 참조로 변환할 수 없어 제외된 항목 수';
+COMMENT ON COLUMN annotation_dataset_export_job.output_path IS '결과 파일의 스토리지 경로';
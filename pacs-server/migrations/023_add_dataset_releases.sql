@@ -0,0 +1,71 @@
+-- Migration: Add dataset releases
+-- Created: 2025-10-29
+-- Description: Immutable, named snapshots of annotation versions (pinned by history ID) and masks (pinned by checksum)
+
+-- annotation_dataset_release 테이블 생성
+-- 프로젝트 어노테이션/마스크의 불변 스냅샷(릴리스)을 저장하는 테이블
+CREATE TABLE IF NOT EXISTS annotation_dataset_release (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    project_id INTEGER NOT NULL REFERENCES security_project(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    release_key UUID NOT NULL UNIQUE,                   -- 스토리지 경로에 사용하는 릴리스 키
+    manifest_path TEXT NOT NULL,                        -- 매니페스트 JSON의 스토리지 경로
+    filters JSONB NOT NULL DEFAULT '{}'::jsonb,         -- 릴리스 생성 시 사용한 선택 조건
+    annotation_count INTEGER NOT NULL DEFAULT 0,
+    mask_count INTEGER NOT NULL DEFAULT 0,
+    created_by INTEGER,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT uq_dataset_release_project_name UNIQUE (project_id, name)
+);
+
+-- 릴리스에 고정된 어노테이션 버전 (어노테이션이 삭제되어도 기록은 유지)
+CREATE TABLE IF NOT EXISTS annotation_dataset_release_annotation (
+    release_id INTEGER NOT NULL REFERENCES annotation_dataset_release(id) ON DELETE CASCADE,
+    annotation_id INTEGER NOT NULL,
+    history_id INTEGER NOT NULL,                        -- 고정된 annotation_annotation_history ID
+    PRIMARY KEY (release_id, annotation_id)
+);
+
+-- 릴리스에 고정된 마스크 (체크섬과 릴리스 전용 사본 경로)
+CREATE TABLE IF NOT EXISTS annotation_dataset_release_mask (
+    release_id INTEGER NOT NULL REFERENCES annotation_dataset_release(id) ON DELETE CASCADE,
+    mask_id INTEGER NOT NULL,
+    mask_group_id INTEGER NOT NULL,
+    annotation_id INTEGER NOT NULL,
+    checksum TEXT NOT NULL,                             -- 고정된 마스크 파일의 SHA-256 체크섬
+    source_path TEXT NOT NULL,                          -- 릴리스 당시 원본 파일 경로
+    object_key TEXT NOT NULL,                           -- 릴리스 전용 사본 경로
+    PRIMARY KEY (release_id, mask_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_dataset_release_project_id ON annotation_dataset_release(project_id);
+
+-- 릴리스는 생성 후 수정할 수 없음 (프로젝트 삭제에 따른 CASCADE 삭제만 허용)
+CREATE OR REPLACE FUNCTION prevent_dataset_release_update()
+RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'Dataset releases are immutable (table %)', TG_TABLE_NAME;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_dataset_release_immutable ON annotation_dataset_release;
+CREATE TRIGGER trg_dataset_release_immutable
+    BEFORE UPDATE ON annotation_dataset_release
+    FOR EACH ROW EXECUTE FUNCTION prevent_dataset_release_update();
+
+DROP TRIGGER IF EXISTS trg_dataset_release_annotation_immutable ON annotation_dataset_release_annotation;
+CREATE TRIGGER trg_dataset_release_annotation_immutable
+    BEFORE UPDATE ON annotation_dataset_release_annotation
+    FOR EACH ROW EXECUTE FUNCTION prevent_dataset_release_update();
+
+DROP TRIGGER IF EXISTS trg_dataset_release_mask_immutable ON annotation_dataset_release_mask;
+CREATE TRIGGER trg_dataset_release_mask_immutable
+    BEFORE UPDATE ON annotation_dataset_release_mask
+    FOR EACH ROW EXECUTE FUNCTION prevent_dataset_release_update();
+
+COMMENT ON TABLE annotation_dataset_release IS '프로젝트 어노테이션/마스크의 불변 스냅샷 (데이터셋 릴리스)';
+COMMENT ON COLUMN annotation_dataset_release.release_key IS '릴리스 파일 경로에 사용하는 키 (releases/project_{id}/{release_key}/...)';
+COMMENT ON COLUMN annotation_dataset_release.manifest_path IS '릴리스 매니페스트 JSON의 스토리지 경로';
+COMMENT ON TABLE annotation_dataset_release_annotation IS '릴리스에 고정된 어노테이션 버전 (히스토리 ID)';
+COMMENT ON TABLE annotation_dataset_release_mask IS '릴리스에 고정된 마스크 (체크섬, 릴리스 전용 사본 경로)';
@@ -0,0 +1,25 @@
+-- Migration: Add soft delete (trash) for annotations and mask groups
+-- Created: 2025-10-30
+-- Description: Deleted annotations/mask groups are moved to a per-project trash and purged after a retention period
+
+-- annotation_annotation: 휴지통 이동 정보
+ALTER TABLE annotation_annotation
+    ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS deleted_by INTEGER REFERENCES security_user(id) ON DELETE SET NULL;
+
+-- annotation_mask_group: 휴지통 이동 정보
+ALTER TABLE annotation_mask_group
+    ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS deleted_by INTEGER REFERENCES security_user(id) ON DELETE SET NULL;
+
+-- 휴지통 목록 조회 및 보관 기간 만료 항목 정리를 위한 부분 인덱스
+CREATE INDEX IF NOT EXISTS idx_annotation_deleted_at
+    ON annotation_annotation(project_id, deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_mask_group_deleted_at
+    ON annotation_mask_group(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- 컬럼 설명 추가
+COMMENT ON COLUMN annotation_annotation.deleted_at IS '휴지통으로 이동된 시간 (NULL이면 활성 상태)';
+COMMENT ON COLUMN annotation_annotation.deleted_by IS '휴지통으로 이동한 사용자 ID';
+COMMENT ON COLUMN annotation_mask_group.deleted_at IS '휴지통으로 이동된 시간 (NULL이면 활성 상태, 어노테이션과 함께 삭제되면 같은 시간)';
+COMMENT ON COLUMN annotation_mask_group.deleted_by IS '휴지통으로 이동한 사용자 ID';
@@ -0,0 +1,38 @@
+-- Migration: Add per-project label taxonomy
+-- Created: 2025-10-31
+-- Description: Projects define coded labels (color, SNOMED CT/RadLex code, hierarchy, allowed tools) that mask and annotation labels are validated against
+
+CREATE TABLE IF NOT EXISTS annotation_label (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    project_id INTEGER NOT NULL REFERENCES security_project(id) ON DELETE CASCADE,
+    parent_id INTEGER REFERENCES annotation_label(id) ON DELETE RESTRICT,
+    name TEXT NOT NULL,
+    display_name TEXT,
+    color TEXT,
+    code_scheme TEXT,
+    code_value TEXT,
+    code_meaning TEXT,
+    allowed_tools TEXT[] NOT NULL DEFAULT '{}',
+    aliases TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT chk_annotation_label_code CHECK ((code_scheme IS NULL) = (code_value IS NULL))
+);
+
+-- 프로젝트 내 라벨 이름은 대소문자 구분 없이 유일
+CREATE UNIQUE INDEX IF NOT EXISTS idx_annotation_label_project_name
+    ON annotation_label(project_id, lower(name));
+CREATE INDEX IF NOT EXISTS idx_annotation_label_parent_id
+    ON annotation_label(parent_id);
+
+-- 테이블 및 컬럼 설명 추가
+COMMENT ON TABLE annotation_label IS '프로젝트 라벨 분류 체계 (마스크/어노테이션 라벨 검증 기준)';
+COMMENT ON COLUMN annotation_label.parent_id IS '상위 라벨 ID (계층 구조)';
+COMMENT ON COLUMN annotation_label.name IS '정식 라벨 이름 (마스크 label_name / 어노테이션 data.label에 저장되는 값)';
+COMMENT ON COLUMN annotation_label.display_name IS '화면 표시 이름';
+COMMENT ON COLUMN annotation_label.color IS '표시 색상 (#RRGGBB)';
+COMMENT ON COLUMN annotation_label.code_scheme IS '코드 체계 (SCT: SNOMED CT, RADLEX: RadLex)';
+COMMENT ON COLUMN annotation_label.code_value IS '코드 값';
+COMMENT ON COLUMN annotation_label.code_meaning IS '코드 의미';
+COMMENT ON COLUMN annotation_label.allowed_tools IS '이 라벨을 사용할 수 있는 어노테이션 도구 (비어 있으면 제한 없음)';
+COMMENT ON COLUMN annotation_label.aliases IS '정식 이름으로 매핑되는 자유 입력 라벨 별칭';
@@ -0,0 +1,53 @@
+-- Migration: Add annotation worklist tasks
+-- Created: 2025-11-01
+-- Description: Per-study annotation tasks assigned to project members, with claim/release,
+-- status tracking (TODO → IN_PROGRESS → DONE/SKIPPED), time spent and links to resulting annotations/mask groups
+
+DO $$ BEGIN
+    CREATE TYPE worklist_task_status_enum AS ENUM ('TODO', 'IN_PROGRESS', 'DONE', 'SKIPPED');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS annotation_worklist_task (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    project_id INTEGER NOT NULL REFERENCES security_project(id) ON DELETE CASCADE,
+    study_id INTEGER NOT NULL REFERENCES project_data_study(id) ON DELETE CASCADE,
+    assignee_id INTEGER REFERENCES security_user(id) ON DELETE SET NULL,
+    status worklist_task_status_enum NOT NULL DEFAULT 'TODO',
+    priority INTEGER NOT NULL DEFAULT 0,
+    note TEXT,
+    time_spent_seconds BIGINT NOT NULL DEFAULT 0,
+    started_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    created_by INTEGER REFERENCES security_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (study_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_worklist_task_project_status ON annotation_worklist_task(project_id, status, priority DESC, id);
+CREATE INDEX IF NOT EXISTS idx_worklist_task_assignee ON annotation_worklist_task(assignee_id, status);
+
+-- 완료된 작업의 결과물 (어노테이션 또는 마스크 그룹)
+CREATE TABLE IF NOT EXISTS annotation_worklist_task_result (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    task_id INTEGER NOT NULL REFERENCES annotation_worklist_task(id) ON DELETE CASCADE,
+    annotation_id INTEGER REFERENCES annotation_annotation(id) ON DELETE CASCADE,
+    mask_group_id INTEGER REFERENCES annotation_mask_group(id) ON DELETE CASCADE,
+    CONSTRAINT chk_worklist_task_result_target CHECK ((annotation_id IS NULL) <> (mask_group_id IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_worklist_task_result_task ON annotation_worklist_task_result(task_id);
+
+-- 테이블 및 컬럼 설명 추가
+COMMENT ON TABLE annotation_worklist_task IS '어노테이션 작업 목록 (Study 단위 작업)';
+COMMENT ON COLUMN annotation_worklist_task.study_id IS '작업 대상 Study (project_data_study, Study당 작업 하나)';
+COMMENT ON COLUMN annotation_worklist_task.assignee_id IS '배정된(또는 작업을 가져간) 판독자 ID';
+COMMENT ON COLUMN annotation_worklist_task.status IS '작업 상태 (TODO, IN_PROGRESS, DONE, SKIPPED)';
+COMMENT ON COLUMN annotation_worklist_task.priority IS '우선순위 (높을수록 먼저)';
+COMMENT ON COLUMN annotation_worklist_task.note IS '완료/건너뛰기 메모';
+COMMENT ON COLUMN annotation_worklist_task.time_spent_seconds IS '종료된 작업 구간의 누적 소요 시간 (초)';
+COMMENT ON COLUMN annotation_worklist_task.started_at IS '현재 작업 구간 시작 시간 (IN_PROGRESS일 때만)';
+COMMENT ON COLUMN annotation_worklist_task.completed_at IS '완료 또는 건너뛴 시간';
+COMMENT ON TABLE annotation_worklist_task_result IS '작업 완료 시 연결된 어노테이션 / 마스크 그룹';
@@ -0,0 +1,39 @@
+-- Migration: Add advisory edit locks
+-- Created: 2025-11-02
+-- Description: Exclusive edit locks on a study or an annotation with TTL and heartbeat renewal.
+-- Locks are advisory for readers but enforced when updating/deleting annotations and updating mask groups.
+
+DO $$ BEGIN
+    CREATE TYPE edit_lock_target_enum AS ENUM ('STUDY', 'ANNOTATION');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS annotation_edit_lock (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    project_id INTEGER NOT NULL REFERENCES security_project(id) ON DELETE CASCADE,
+    target_type edit_lock_target_enum NOT NULL,
+    study_uid TEXT NOT NULL,
+    annotation_id INTEGER REFERENCES annotation_annotation(id) ON DELETE CASCADE,
+    holder_id INTEGER NOT NULL REFERENCES security_user(id) ON DELETE CASCADE,
+    acquired_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    renewed_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT chk_edit_lock_target CHECK ((target_type = 'ANNOTATION') = (annotation_id IS NOT NULL))
+);
+
+-- 대상별 잠금은 하나만 (만료된 잠금은 획득 시 정리)
+CREATE UNIQUE INDEX IF NOT EXISTS idx_edit_lock_study_target
+    ON annotation_edit_lock(project_id, study_uid) WHERE target_type = 'STUDY';
+CREATE UNIQUE INDEX IF NOT EXISTS idx_edit_lock_annotation_target
+    ON annotation_edit_lock(annotation_id) WHERE target_type = 'ANNOTATION';
+CREATE INDEX IF NOT EXISTS idx_edit_lock_project_study ON annotation_edit_lock(project_id, study_uid, expires_at);
+
+-- 테이블 및 컬럼 설명 추가
+COMMENT ON TABLE annotation_edit_lock IS 'Study / 어노테이션 편집 잠금 (TTL과 heartbeat로 유지)';
+COMMENT ON COLUMN annotation_edit_lock.target_type IS '잠금 대상 (STUDY: Study 전체, ANNOTATION: 어노테이션 하나)';
+COMMENT ON COLUMN annotation_edit_lock.study_uid IS '잠금 대상 Study (어노테이션 잠금은 어노테이션의 Study)';
+COMMENT ON COLUMN annotation_edit_lock.annotation_id IS '잠금 대상 어노테이션 (ANNOTATION 잠금만)';
+COMMENT ON COLUMN annotation_edit_lock.holder_id IS '잠금을 가진 사용자 ID';
+COMMENT ON COLUMN annotation_edit_lock.renewed_at IS '마지막 heartbeat 시간';
+COMMENT ON COLUMN annotation_edit_lock.expires_at IS '만료 시간 (heartbeat가 없으면 이후 다른 사용자가 획득 가능)';
@@ -0,0 +1,77 @@
+-- Migration: Add outgoing webhooks
+-- Created: 2025-11-03
+-- Description: Per-project webhook subscriptions fed by a transactional outbox.
+-- Domain events are written to the outbox in the same transaction as the change, fanned out to matching
+-- subscriptions, and delivered with HMAC-signed payloads, exponential backoff retries and a dead-letter state.
+
+DO $$ BEGIN
+    CREATE TYPE webhook_delivery_status_enum AS ENUM ('PENDING', 'SUCCEEDED', 'DEAD');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS webhook_subscription (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    project_id INTEGER NOT NULL REFERENCES security_project(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    event_types TEXT[] NOT NULL DEFAULT '{}',
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by INTEGER REFERENCES security_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_subscription_project ON webhook_subscription(project_id);
+
+CREATE TABLE IF NOT EXISTS webhook_outbox_event (
+    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    project_id INTEGER NOT NULL REFERENCES security_project(id) ON DELETE CASCADE,
+    event_type TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    dispatched_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_outbox_pending ON webhook_outbox_event(id) WHERE dispatched_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS webhook_delivery (
+    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    subscription_id INTEGER NOT NULL REFERENCES webhook_subscription(id) ON DELETE CASCADE,
+    event_id BIGINT NOT NULL REFERENCES webhook_outbox_event(id) ON DELETE CASCADE,
+    status webhook_delivery_status_enum NOT NULL DEFAULT 'PENDING',
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_status_code INTEGER,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    delivered_at TIMESTAMPTZ,
+    CONSTRAINT uq_webhook_delivery_event UNIQUE (subscription_id, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_due ON webhook_delivery(next_attempt_at) WHERE status = 'PENDING';
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_subscription ON webhook_delivery(subscription_id, status);
+
+CREATE TABLE IF NOT EXISTS webhook_delivery_attempt (
+    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    delivery_id BIGINT NOT NULL REFERENCES webhook_delivery(id) ON DELETE CASCADE,
+    attempt_number INTEGER NOT NULL,
+    status_code INTEGER,
+    error TEXT,
+    duration_ms INTEGER NOT NULL,
+    attempted_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_attempt_delivery ON webhook_delivery_attempt(delivery_id);
+
+-- 테이블 및 컬럼 설명 추가
+COMMENT ON TABLE webhook_subscription IS '프로젝트 webhook 구독';
+COMMENT ON COLUMN webhook_subscription.secret IS '페이로드 HMAC-SHA256 서명 키';
+COMMENT ON COLUMN webhook_subscription.event_types IS '받을 이벤트 종류 (비어 있으면 모든 이벤트)';
+COMMENT ON TABLE webhook_outbox_event IS 'Webhook 트랜잭션 아웃박스 (도메인 변경과 같은 트랜잭션에서 기록)';
+COMMENT ON COLUMN webhook_outbox_event.dispatched_at IS '구독별 전송 작업으로 나눈 시간 (NULL이면 대기 중)';
+COMMENT ON TABLE webhook_delivery IS '구독별 이벤트 전송 (DEAD는 재시도 횟수를 모두 소진한 dead-letter)';
+COMMENT ON COLUMN webhook_delivery.next_attempt_at IS '다음 전송 시도 시간 (지수 백오프, 전송 중에는 임대 만료 시간)';
+COMMENT ON TABLE webhook_delivery_attempt IS 'Webhook 전송 시도 로그';
@@ -0,0 +1,47 @@
+-- Migration: Add server-side measurement computation
+-- Created: 2025-11-04
+-- Description: Stores DICOM instance pixel spacing per project and the measurements the server computes
+-- from annotation geometry, alongside the viewer-supplied measurement_values and any discrepancies between them.
+
+CREATE TABLE IF NOT EXISTS dicom_instance_metadata (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    project_id INTEGER NOT NULL REFERENCES security_project(id) ON DELETE CASCADE,
+    study_uid TEXT NOT NULL,
+    series_uid TEXT NOT NULL,
+    instance_uid TEXT NOT NULL,
+    pixel_spacing_row DOUBLE PRECISION NOT NULL CHECK (pixel_spacing_row > 0),
+    pixel_spacing_column DOUBLE PRECISION NOT NULL CHECK (pixel_spacing_column > 0),
+    rows INTEGER,
+    columns INTEGER,
+    updated_by INTEGER REFERENCES security_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT uq_dicom_instance_metadata UNIQUE (project_id, instance_uid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_dicom_instance_metadata_series ON dicom_instance_metadata(project_id, series_uid);
+
+CREATE TABLE IF NOT EXISTS annotation_measurement (
+    annotation_id INTEGER PRIMARY KEY REFERENCES annotation_annotation(id) ON DELETE CASCADE,
+    geometry_type TEXT,
+    measurements JSONB NOT NULL DEFAULT '[]',
+    pixel_spacing_row DOUBLE PRECISION,
+    pixel_spacing_column DOUBLE PRECISION,
+    spacing_source TEXT NOT NULL,
+    discrepancies JSONB NOT NULL DEFAULT '[]',
+    has_discrepancy BOOLEAN NOT NULL DEFAULT FALSE,
+    error TEXT,
+    computed_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_annotation_measurement_discrepancy ON annotation_measurement(annotation_id) WHERE has_discrepancy;
+
+-- 테이블 및 컬럼 설명 추가
+COMMENT ON TABLE dicom_instance_metadata IS '측정값 계산에 사용하는 DICOM 인스턴스 메타데이터';
+COMMENT ON COLUMN dicom_instance_metadata.pixel_spacing_row IS 'Pixel Spacing (0028,0030) 첫 번째 값: 행 간격 (세로, mm)';
+COMMENT ON COLUMN dicom_instance_metadata.pixel_spacing_column IS 'Pixel Spacing (0028,0030) 두 번째 값: 열 간격 (가로, mm)';
+COMMENT ON TABLE annotation_measurement IS '어노테이션 도형에서 서버가 계산한 측정값';
+COMMENT ON COLUMN annotation_measurement.geometry_type IS '인식한 도형 종류 (인식하지 못하면 NULL)';
+COMMENT ON COLUMN annotation_measurement.spacing_source IS '픽셀 간격 출처 (INSTANCE, SERIES, NONE - NONE이면 픽셀 단위)';
+COMMENT ON COLUMN annotation_measurement.discrepancies IS '클라이언트 측정값과 허용 오차 이상 차이 나는 항목';
+COMMENT ON COLUMN annotation_measurement.error IS '계산할 수 없는 이유 (지원하지 않는 도형 등)';
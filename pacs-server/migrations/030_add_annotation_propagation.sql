@@ -0,0 +1,25 @@
+-- Migration: Add annotation propagation to follow-up studies
+-- Created: 2025-11-05
+-- Description: Links annotations copied from a baseline study to a follow-up study of the same patient,
+-- so each lesion can be tracked across timepoints through its root (baseline) annotation.
+
+CREATE TABLE IF NOT EXISTS annotation_propagation (
+    annotation_id INTEGER PRIMARY KEY REFERENCES annotation_annotation(id) ON DELETE CASCADE,
+    source_annotation_id INTEGER NOT NULL REFERENCES annotation_annotation(id) ON DELETE CASCADE,
+    root_annotation_id INTEGER NOT NULL REFERENCES annotation_annotation(id) ON DELETE CASCADE,
+    source_study_uid TEXT NOT NULL,
+    target_study_uid TEXT NOT NULL,
+    series_match TEXT NOT NULL,
+    propagated_by INTEGER REFERENCES security_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT uq_annotation_propagation_target UNIQUE (source_annotation_id, target_study_uid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_annotation_propagation_root ON annotation_propagation(root_annotation_id);
+
+-- 테이블 및 컬럼 설명 추가
+COMMENT ON TABLE annotation_propagation IS '후속 Study로 복사한 어노테이션과 원본 어노테이션의 연결';
+COMMENT ON COLUMN annotation_propagation.annotation_id IS '복사해서 만든 어노테이션 (DRAFT 상태로 생성)';
+COMMENT ON COLUMN annotation_propagation.source_annotation_id IS '복사한 원본 어노테이션';
+COMMENT ON COLUMN annotation_propagation.root_annotation_id IS '병변의 최초(기준 시점) 어노테이션 - 시점 간 병변 추적 키';
+COMMENT ON COLUMN annotation_propagation.series_match IS 'Series 매핑 방식 (SERIES_DESCRIPTION, MODALITY, UNMATCHED, STUDY_LEVEL)';
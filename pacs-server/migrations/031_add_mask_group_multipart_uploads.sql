@@ -0,0 +1,41 @@
+-- Migration: Add resumable multipart uploads for mask groups
+-- Created: 2025-11-06
+-- Description: Tracks object storage multipart upload sessions per mask group so clients can resume
+-- interrupted uploads of large mask volumes (e.g. full-resolution CT masks, NIfTI files).
+-- Sessions that stay IN_PROGRESS past their expiry are aborted by a background job.
+
+DO $$ BEGIN
+    CREATE TYPE multipart_upload_status_enum AS ENUM ('IN_PROGRESS', 'COMPLETED', 'ABORTED');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS annotation_mask_group_multipart_upload (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    mask_group_id INTEGER NOT NULL REFERENCES annotation_mask_group(id) ON DELETE CASCADE,
+    file_path TEXT NOT NULL,                            -- 업로드 대상 객체 경로
+    upload_id TEXT NOT NULL,                            -- 스토리지가 발급한 멀티파트 업로드 ID
+    content_type TEXT,
+    part_size BIGINT NOT NULL,                          -- 마지막 파트를 제외한 파트 크기 (bytes)
+    total_size BIGINT NOT NULL,                         -- 전체 파일 크기 (bytes)
+    part_count INTEGER NOT NULL,
+    status multipart_upload_status_enum NOT NULL DEFAULT 'IN_PROGRESS',
+    created_by INTEGER,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMPTZ NOT NULL,                    -- 이 시각까지 완료되지 않으면 정리 대상
+    completed_at TIMESTAMPTZ,
+    CONSTRAINT uq_mask_group_multipart_upload_id UNIQUE (upload_id)
+);
+
+-- 같은 경로에 진행 중인 업로드는 하나만 허용
+CREATE UNIQUE INDEX IF NOT EXISTS uq_mask_group_multipart_upload_active_path
+    ON annotation_mask_group_multipart_upload(file_path) WHERE status = 'IN_PROGRESS';
+CREATE INDEX IF NOT EXISTS idx_mask_group_multipart_upload_group
+    ON annotation_mask_group_multipart_upload(mask_group_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_mask_group_multipart_upload_expiry
+    ON annotation_mask_group_multipart_upload(expires_at) WHERE status = 'IN_PROGRESS';
+
+COMMENT ON TABLE annotation_mask_group_multipart_upload IS '마스크 그룹 파일의 이어받기 가능한 멀티파트 업로드 세션';
+COMMENT ON COLUMN annotation_mask_group_multipart_upload.upload_id IS 'Object Storage 멀티파트 업로드 ID';
+COMMENT ON COLUMN annotation_mask_group_multipart_upload.expires_at IS '만료 시각 (지나면 백그라운드 작업이 업로드를 중단)';
@@ -0,0 +1,34 @@
+-- Migration: Add storage deletion queue
+-- Created: 2025-11-07
+-- Description: Durable queue of Object Storage objects to delete.
+-- Mask deletions and trash purges enqueue the backing objects in the same transaction as the row deletion,
+-- and a background worker deletes them with exponential backoff retries and a dead-letter state.
+
+DO $$ BEGIN
+    CREATE TYPE storage_deletion_status_enum AS ENUM ('PENDING', 'DELETED', 'DEAD');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS storage_deletion_queue (
+    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    object_key TEXT NOT NULL,
+    is_prefix BOOLEAN NOT NULL DEFAULT FALSE,
+    reason TEXT NOT NULL,
+    status storage_deletion_status_enum NOT NULL DEFAULT 'PENDING',
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_storage_deletion_due ON storage_deletion_queue(next_attempt_at) WHERE status = 'PENDING';
+
+-- 테이블 및 컬럼 설명 추가
+COMMENT ON TABLE storage_deletion_queue IS 'Object Storage 삭제 대기열 (행 삭제와 같은 트랜잭션에서 기록, DEAD는 재시도 횟수를 모두 소진한 dead-letter)';
+COMMENT ON COLUMN storage_deletion_queue.object_key IS '삭제할 객체 경로 (is_prefix이면 이 경로로 시작하는 모든 객체)';
+COMMENT ON COLUMN storage_deletion_queue.reason IS '삭제 사유 (MASK_DELETED, MASK_GROUP_PURGED, ANNOTATION_PURGED)';
+COMMENT ON COLUMN storage_deletion_queue.next_attempt_at IS '다음 삭제 시도 시간 (지수 백오프, 처리 중에는 임대 만료 시간)';
+COMMENT ON COLUMN storage_deletion_queue.deleted_at IS '객체 삭제를 완료한 시간';
@@ -0,0 +1,77 @@
+-- Migration: Add storage reconciliation
+-- Created: 2025-11-08
+-- Description: Reconciliation runs comparing Object Storage with annotation_mask rows.
+-- Each run records orphan objects (no row), dangling rows (object missing) and size/checksum mismatches,
+-- and optionally quarantines or queues deletion of orphans older than the grace period.
+
+DO $$ BEGIN
+    CREATE TYPE storage_reconciliation_status_enum AS ENUM ('RUNNING', 'COMPLETED', 'FAILED');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE storage_orphan_action_enum AS ENUM ('REPORT', 'QUARANTINE', 'DELETE');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE storage_reconciliation_finding_kind_enum AS ENUM (
+        'ORPHAN_OBJECT', 'DANGLING_ROW', 'SIZE_MISMATCH', 'CHECKSUM_MISMATCH'
+    );
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS storage_reconciliation_run (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    status storage_reconciliation_status_enum NOT NULL DEFAULT 'RUNNING',
+    orphan_action storage_orphan_action_enum NOT NULL DEFAULT 'REPORT',
+    grace_period_hours INTEGER NOT NULL,
+    triggered_by INTEGER REFERENCES security_user(id) ON DELETE SET NULL,
+    objects_scanned BIGINT NOT NULL DEFAULT 0,
+    rows_scanned BIGINT NOT NULL DEFAULT 0,
+    recent_skipped BIGINT NOT NULL DEFAULT 0,
+    orphan_objects BIGINT NOT NULL DEFAULT 0,
+    dangling_rows BIGINT NOT NULL DEFAULT 0,
+    size_mismatches BIGINT NOT NULL DEFAULT 0,
+    checksum_mismatches BIGINT NOT NULL DEFAULT 0,
+    orphans_quarantined BIGINT NOT NULL DEFAULT 0,
+    orphans_queued_for_deletion BIGINT NOT NULL DEFAULT 0,
+    error TEXT,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    finished_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_storage_reconciliation_run_started ON storage_reconciliation_run(started_at DESC);
+
+CREATE TABLE IF NOT EXISTS storage_reconciliation_finding (
+    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    run_id INTEGER NOT NULL REFERENCES storage_reconciliation_run(id) ON DELETE CASCADE,
+    kind storage_reconciliation_finding_kind_enum NOT NULL,
+    object_key TEXT NOT NULL,
+    mask_id INTEGER,
+    expected TEXT,
+    actual TEXT,
+    action storage_orphan_action_enum NOT NULL DEFAULT 'REPORT',
+    quarantine_key TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_storage_reconciliation_finding_run ON storage_reconciliation_finding(run_id, kind);
+
+-- 점검 시 객체 경로로 마스크 행을 찾음
+CREATE INDEX IF NOT EXISTS idx_mask_file_path ON annotation_mask(file_path);
+
+-- 테이블 및 컬럼 설명 추가
+COMMENT ON TABLE storage_reconciliation_run IS 'Object Storage와 마스크 행의 정합성 점검 실행 기록';
+COMMENT ON COLUMN storage_reconciliation_run.orphan_action IS '고아 객체 처리 방식 (REPORT: 보고만, QUARANTINE: 격리 경로로 이동, DELETE: 삭제 대기열에 예약)';
+COMMENT ON COLUMN storage_reconciliation_run.grace_period_hours IS '이 시간보다 최근에 만들어진 객체/행은 업로드 중일 수 있어 점검에서 제외';
+COMMENT ON COLUMN storage_reconciliation_run.triggered_by IS '관리자 API로 실행한 사용자 (NULL이면 정기 실행)';
+COMMENT ON COLUMN storage_reconciliation_run.recent_skipped IS '유예 기간 안이라 제외한 객체/행 수';
+COMMENT ON TABLE storage_reconciliation_finding IS '정합성 점검에서 발견한 문제 (실행당 기록 수 상한 있음, 집계는 실행 기록에 있음)';
+COMMENT ON COLUMN storage_reconciliation_finding.kind IS 'ORPHAN_OBJECT: 행이 없는 객체, DANGLING_ROW: 객체가 없는 행, SIZE_MISMATCH / CHECKSUM_MISMATCH: 행과 객체 메타데이터 불일치';
+COMMENT ON COLUMN storage_reconciliation_finding.action IS '고아 객체에 실제로 적용한 처리';
+COMMENT ON COLUMN storage_reconciliation_finding.quarantine_key IS '격리한 객체의 새 경로';
+COMMENT ON COLUMN storage_deletion_queue.reason IS '삭제 사유 (MASK_DELETED, MASK_GROUP_PURGED, ANNOTATION_PURGED, RECONCILIATION_ORPHAN)';
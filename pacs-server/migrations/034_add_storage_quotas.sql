@@ -0,0 +1,56 @@
+-- Migration: Add storage quotas and usage accounting
+-- Created: 2025-11-09
+-- Description: Persisted mask storage usage per (project, user), updated in the same transaction as mask
+-- creation/update/deletion and purges, plus per-project and per-user soft/hard quotas.
+-- Hard quotas are enforced when upload URLs are issued; crossing a soft quota records a webhook event once.
+
+CREATE TABLE IF NOT EXISTS storage_usage (
+    project_id INTEGER NOT NULL REFERENCES security_project(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES security_user(id) ON DELETE CASCADE,
+    bytes_used BIGINT NOT NULL DEFAULT 0,
+    object_count BIGINT NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (project_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_storage_usage_user ON storage_usage(user_id);
+
+CREATE TABLE IF NOT EXISTS storage_quota (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    project_id INTEGER REFERENCES security_project(id) ON DELETE CASCADE,
+    user_id INTEGER REFERENCES security_user(id) ON DELETE CASCADE,
+    soft_limit_bytes BIGINT,
+    hard_limit_bytes BIGINT,
+    alerted_at TIMESTAMPTZ,
+    updated_by INTEGER REFERENCES security_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT chk_storage_quota_scope CHECK ((project_id IS NULL) <> (user_id IS NULL)),
+    CONSTRAINT chk_storage_quota_limits CHECK (
+        (soft_limit_bytes IS NULL OR soft_limit_bytes >= 0)
+        AND (hard_limit_bytes IS NULL OR hard_limit_bytes >= 0)
+        AND (soft_limit_bytes IS NULL OR hard_limit_bytes IS NULL OR soft_limit_bytes <= hard_limit_bytes)
+    )
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_storage_quota_project ON storage_quota(project_id) WHERE project_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS uq_storage_quota_user ON storage_quota(user_id) WHERE user_id IS NOT NULL;
+
+-- 기존 마스크로 사용량 초기화 (휴지통의 마스크도 객체가 남아 있으므로 포함)
+INSERT INTO storage_usage (project_id, user_id, bytes_used, object_count)
+SELECT a.project_id, COALESCE(u.id, a.user_id), COALESCE(SUM(m.file_size), 0), COUNT(*)
+FROM annotation_mask m
+JOIN annotation_mask_group g ON g.id = m.mask_group_id
+JOIN annotation_annotation a ON a.id = g.annotation_id
+LEFT JOIN security_user u ON u.id = g.created_by
+GROUP BY a.project_id, COALESCE(u.id, a.user_id)
+ON CONFLICT (project_id, user_id) DO NOTHING;
+
+-- 테이블 및 컬럼 설명 추가
+COMMENT ON TABLE storage_usage IS '프로젝트/사용자별 마스크 저장 사용량 (마스크 생성/수정/삭제, 영구 삭제와 같은 트랜잭션에서 갱신)';
+COMMENT ON COLUMN storage_usage.user_id IS '마스크 그룹 생성자 (생성자가 없으면 어노테이션 작성자)';
+COMMENT ON COLUMN storage_usage.bytes_used IS '마스크 파일 크기 합계 (크기를 모르는 마스크는 0으로 계산)';
+COMMENT ON TABLE storage_quota IS '프로젝트 또는 사용자 단위 저장 용량 한도 (project_id와 user_id 중 하나만 지정)';
+COMMENT ON COLUMN storage_quota.soft_limit_bytes IS '경고 한도 (넘으면 업로드는 허용하고 STORAGE_QUOTA_THRESHOLD_REACHED 이벤트 기록, NULL이면 없음)';
+COMMENT ON COLUMN storage_quota.hard_limit_bytes IS '업로드 URL 발급을 거부하는 한도 (NULL이면 없음)';
+COMMENT ON COLUMN storage_quota.alerted_at IS '경고 한도 이벤트를 기록한 시각 (사용량이 경고 한도 아래로 내려가면 NULL로 초기화)';
@@ -0,0 +1,33 @@
+-- Migration: Add server-side mask validation
+-- Created: 2025-11-10
+-- Description: Post-upload validation state and per-label statistics on annotation_mask.
+-- A background worker downloads each pending mask object, verifies its checksum and size, decodes the PNG to
+-- confirm its dimensions against the referenced instance, and stores per-label voxel counts and bounding boxes.
+-- Masks that fail the checks are flagged INVALID; masks whose object could not be read after retries are ERROR.
+
+DO $$ BEGIN
+    CREATE TYPE mask_validation_status_enum AS ENUM ('PENDING', 'VALID', 'INVALID', 'ERROR');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+ALTER TABLE annotation_mask
+    ADD COLUMN IF NOT EXISTS validation_status mask_validation_status_enum NOT NULL DEFAULT 'PENDING',
+    ADD COLUMN IF NOT EXISTS validation_errors JSONB NOT NULL DEFAULT '[]'::jsonb,
+    ADD COLUMN IF NOT EXISTS label_stats JSONB,
+    ADD COLUMN IF NOT EXISTS content_sha256 TEXT,
+    ADD COLUMN IF NOT EXISTS validation_attempts INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS next_validation_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS validated_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_annotation_mask_validation_due
+    ON annotation_mask(next_validation_at) WHERE validation_status = 'PENDING';
+
+-- 컬럼 설명 추가
+COMMENT ON COLUMN annotation_mask.validation_status IS '서버 검증 상태 (PENDING: 대기, VALID: 통과, INVALID: 검증 실패, ERROR: 재시도 소진)';
+COMMENT ON COLUMN annotation_mask.validation_errors IS '검증 실패 사유 목록';
+COMMENT ON COLUMN annotation_mask.label_stats IS '라벨 값별 복셀 수와 경계 상자 [x, y, width, height]';
+COMMENT ON COLUMN annotation_mask.content_sha256 IS '서버가 계산한 객체의 SHA-256';
+COMMENT ON COLUMN annotation_mask.validation_attempts IS '객체를 읽지 못해 실패한 검증 시도 횟수';
+COMMENT ON COLUMN annotation_mask.next_validation_at IS '다음 검증 시도 시간 (처리 중에는 임대 만료 시간)';
+COMMENT ON COLUMN annotation_mask.validated_at IS '검증을 마친 시간';
@@ -0,0 +1,45 @@
+-- Migration: Add mask thumbnails, colorized previews and mask group contact sheets
+-- Created: 2025-11-11
+-- Description: Derived preview objects generated by a background worker after a mask passes validation.
+-- Each mask gets a downscaled thumbnail and an overlay-friendly colorized preview stored under the derived/
+-- prefix, and each mask group gets a contact sheet of its thumbnails that is regenerated when they change.
+-- Generation is retried with exponential backoff and marked FAILED once retries are exhausted.
+
+DO $$ BEGIN
+    CREATE TYPE mask_preview_status_enum AS ENUM ('PENDING', 'READY', 'FAILED');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+ALTER TABLE annotation_mask
+    ADD COLUMN IF NOT EXISTS preview_status mask_preview_status_enum NOT NULL DEFAULT 'PENDING',
+    ADD COLUMN IF NOT EXISTS thumbnail_key TEXT,
+    ADD COLUMN IF NOT EXISTS preview_key TEXT,
+    ADD COLUMN IF NOT EXISTS preview_attempts INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS next_preview_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS preview_error TEXT,
+    ADD COLUMN IF NOT EXISTS preview_generated_at TIMESTAMPTZ;
+
+ALTER TABLE annotation_mask_group
+    ADD COLUMN IF NOT EXISTS contact_sheet_status mask_preview_status_enum NOT NULL DEFAULT 'PENDING',
+    ADD COLUMN IF NOT EXISTS contact_sheet_key TEXT,
+    ADD COLUMN IF NOT EXISTS contact_sheet_mask_count INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS contact_sheet_attempts INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS next_contact_sheet_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS contact_sheet_error TEXT,
+    ADD COLUMN IF NOT EXISTS contact_sheet_generated_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_annotation_mask_preview_due
+    ON annotation_mask(next_preview_at) WHERE preview_status = 'PENDING' AND validation_status = 'VALID';
+CREATE INDEX IF NOT EXISTS idx_annotation_mask_group_contact_sheet_due
+    ON annotation_mask_group(next_contact_sheet_at) WHERE contact_sheet_status = 'PENDING';
+
+-- 컬럼 설명 추가
+COMMENT ON COLUMN annotation_mask.preview_status IS '썸네일/컬러 미리보기 생성 상태 (검증을 통과한 마스크만 생성, FAILED는 재시도 소진)';
+COMMENT ON COLUMN annotation_mask.thumbnail_key IS '썸네일 객체 경로 (derived/ 아래)';
+COMMENT ON COLUMN annotation_mask.preview_key IS '컬러 미리보기 객체 경로 (derived/ 아래, 배경 투명)';
+COMMENT ON COLUMN annotation_mask.next_preview_at IS '다음 미리보기 생성 시도 시간 (처리 중에는 임대 만료 시간)';
+COMMENT ON COLUMN annotation_mask_group.contact_sheet_status IS 'contact sheet 생성 상태 (썸네일이 바뀌면 PENDING)';
+COMMENT ON COLUMN annotation_mask_group.contact_sheet_key IS 'contact sheet 객체 경로 (썸네일이 없으면 NULL)';
+COMMENT ON COLUMN annotation_mask_group.contact_sheet_mask_count IS 'contact sheet에 담긴 마스크 수';
+COMMENT ON COLUMN annotation_mask_group.next_contact_sheet_at IS '다음 contact sheet 생성 시도 시간 (처리 중에는 임대 만료 시간)';
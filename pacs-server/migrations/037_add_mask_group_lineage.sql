@@ -0,0 +1,21 @@
+-- Migration: Add mask group lineage
+-- Created: 2025-11-12
+-- Description: Links a mask group to the group it was derived from (e.g. a human-corrected group derived
+-- from an AI-generated one) so lineage can be shown per annotation and derived groups can be compared
+-- against their source. Both groups must belong to the same annotation; cycles are rejected by the service.
+
+ALTER TABLE annotation_mask_group
+    ADD COLUMN IF NOT EXISTS derived_from_id INTEGER REFERENCES annotation_mask_group(id) ON DELETE SET NULL;
+
+DO $$ BEGIN
+    ALTER TABLE annotation_mask_group
+        ADD CONSTRAINT chk_annotation_mask_group_not_self_derived CHECK (derived_from_id <> id);
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE INDEX IF NOT EXISTS idx_annotation_mask_group_derived_from
+    ON annotation_mask_group(derived_from_id) WHERE derived_from_id IS NOT NULL;
+
+-- 컬럼 설명 추가
+COMMENT ON COLUMN annotation_mask_group.derived_from_id IS '이 마스크 그룹이 파생된 원본 마스크 그룹 (같은 어노테이션, 예: AI 결과를 사람이 수정한 그룹)';
@@ -0,0 +1,6 @@
+-- Migration: Add mask import failure deletion reason
+-- Created: 2025-11-13
+-- Description: A failed mask import removes the mask group it created and queues the group's uploaded
+-- slice objects for deletion with the MASK_IMPORT_FAILED reason.
+
+COMMENT ON COLUMN storage_deletion_queue.reason IS '삭제 사유 (MASK_DELETED, MASK_GROUP_PURGED, ANNOTATION_PURGED, RECONCILIATION_ORPHAN, MASK_IMPORT_FAILED)';
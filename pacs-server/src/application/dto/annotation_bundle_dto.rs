@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 번들 내보내기 쿼리 파라미터
+#[derive(Debug, Default, Deserialize)]
+pub struct BundleExportQuery {
+    /// gzip 압축 여부
+    pub gzip: Option<bool>,
+}
+
+/// 번들 가져오기 쿼리 파라미터
+#[derive(Debug, Default, Deserialize)]
+pub struct BundleImportQuery {
+    /// 충돌만 확인하고 실제로 저장하지 않음
+    pub dry_run: Option<bool>,
+
+    /// 대상 프로젝트 이름 (지정하지 않으면 번들 헤더의 프로젝트 이름 사용)
+    pub project_name: Option<String>,
+}
+
+/// 레코드 타입별 처리 건수
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BundleRecordCounts {
+    /// 번들에 포함된 레코드 수
+    pub total: usize,
+
+    /// 새로 생성된 (dry run에서는 생성될) 레코드 수
+    pub created: usize,
+
+    /// 이미 존재하여 건너뛴 레코드 수
+    pub skipped: usize,
+}
+
+/// 임포트 충돌 정보
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BundleConflict {
+    /// 번들 내 줄 번호 (1부터 시작, 번들 전체에 대한 충돌은 0)
+    #[schema(example = 12)]
+    pub line: usize,
+
+    /// 레코드 타입 (header, annotation, history, mask_group)
+    #[schema(example = "annotation")]
+    pub record_type: String,
+
+    /// 레코드의 external_id
+    pub external_id: Option<String>,
+
+    /// 충돌 사유
+    #[schema(example = "Unknown username: alice")]
+    pub reason: String,
+}
+
+/// 번들 가져오기 결과 응답 DTO
+#[derive(Debug, Default, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BundleImportReport {
+    /// dry run 여부
+    pub dry_run: bool,
+
+    /// 실제로 저장되었는지 여부 (충돌이 있거나 dry run이면 false)
+    pub applied: bool,
+
+    /// 대상 프로젝트 ID
+    pub project_id: Option<i32>,
+
+    /// 어노테이션 처리 건수
+    pub annotations: BundleRecordCounts,
+
+    /// 히스토리 처리 건수
+    pub history: BundleRecordCounts,
+
+    /// 마스크 그룹 처리 건수
+    pub mask_groups: BundleRecordCounts,
+
+    /// 충돌 목록 (하나라도 있으면 아무것도 저장하지 않음)
+    pub conflicts: Vec<BundleConflict>,
+}
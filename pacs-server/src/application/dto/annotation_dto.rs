@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 // use chrono::NaiveDateTime;
 use chrono::{DateTime, Utc};
+use crate::domain::entities::{AnnotationReviewStatus, AnnotationSortField, JsonPathPredicate, SortDirection};
 
 /// Annotation 생성 요청 DTO
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -147,6 +148,15 @@ pub struct AnnotationResponse {
     /// 측정값
     pub measurement_values: Option<serde_json::Value>,
 
+    /// 검토 상태
+    /// DRAFT, SUBMITTED, APPROVED, REJECTED, LOCKED 중 하나
+    #[schema(example = "DRAFT")]
+    pub review_status: AnnotationReviewStatus,
+
+    /// 검토자 ID
+    /// 배정된 검토자의 식별자
+    pub reviewer_id: Option<i32>,
+
     /// 생성 시간
     /// 어노테이션이 생성된 시각
     #[schema(value_type = String, example = "2024-01-01T00:00:00")]
@@ -169,3 +179,90 @@ pub struct AnnotationListResponse {
     /// 전체 개수
     pub total: usize,
 }
+
+/// Annotation 검색 요청 DTO
+///
+/// 지정된 필터는 모두 AND로 결합됩니다. 다음 페이지는 응답의 `next_cursor`를
+/// 같은 필터/정렬과 함께 `cursor`로 전달하여 조회합니다.
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(default)]
+pub struct AnnotationSearchRequest {
+    /// Project ID
+    #[schema(example = 299)]
+    pub project_id: Option<i32>,
+
+    /// 작성자 User ID
+    #[schema(example = 336)]
+    pub user_id: Option<i32>,
+
+    /// Study Instance UID
+    pub study_instance_uid: Option<String>,
+
+    /// Series Instance UID
+    pub series_instance_uid: Option<String>,
+
+    /// SOP Instance UID
+    pub sop_instance_uid: Option<String>,
+
+    /// 측정 도구 이름
+    #[schema(example = "Circle Tool")]
+    pub tool_name: Option<String>,
+
+    /// 뷰어 소프트웨어
+    #[schema(example = "OHIF Viewer")]
+    pub viewer_software: Option<String>,
+
+    /// 공유 여부
+    pub is_shared: Option<bool>,
+
+    /// 검토 상태
+    pub review_status: Option<AnnotationReviewStatus>,
+
+    /// 생성 시간 하한 (포함)
+    #[schema(value_type = Option<String>, example = "2024-01-01T00:00:00Z")]
+    pub created_from: Option<DateTime<Utc>>,
+
+    /// 생성 시간 상한 (미포함)
+    #[schema(value_type = Option<String>, example = "2024-02-01T00:00:00Z")]
+    pub created_to: Option<DateTime<Utc>>,
+
+    /// 수정 시간 하한 (포함)
+    #[schema(value_type = Option<String>, example = "2024-01-01T00:00:00Z")]
+    pub updated_from: Option<DateTime<Utc>>,
+
+    /// 수정 시간 상한 (미포함)
+    #[schema(value_type = Option<String>, example = "2024-02-01T00:00:00Z")]
+    pub updated_to: Option<DateTime<Utc>>,
+
+    /// `annotation_data` JSONB 경로 조건
+    pub data_filters: Vec<JsonPathPredicate>,
+
+    /// `measurement_values` JSONB 경로 조건
+    pub measurement_filters: Vec<JsonPathPredicate>,
+
+    /// 정렬 기준 (created_at, updated_at, id)
+    pub sort: AnnotationSortField,
+
+    /// 정렬 방향 (asc, desc)
+    pub direction: SortDirection,
+
+    /// 페이지 크기 (기본 50, 최대 200)
+    #[schema(example = 50)]
+    pub limit: Option<i64>,
+
+    /// 이전 응답의 `next_cursor`
+    pub cursor: Option<String>,
+}
+
+/// Annotation 검색 응답 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct AnnotationSearchResponse {
+    /// Annotation 목록
+    pub annotations: Vec<AnnotationResponse>,
+
+    /// 다음 페이지 커서 (마지막 페이지면 None)
+    pub next_cursor: Option<String>,
+
+    /// 다음 페이지 존재 여부
+    pub has_more: bool,
+}
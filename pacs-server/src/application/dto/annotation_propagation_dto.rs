@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use crate::domain::entities::annotation_propagation::{AnnotationPropagation, LesionTimepoint};
+use crate::domain::entities::{Annotation, AnnotationReviewStatus};
+
+/// 어노테이션 전파 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct PropagateAnnotationsRequest {
+    /// 원본(기준 시점) Study UID
+    #[schema(example = "1.2.840.113619.2.55.3.604688119.868.1234567890.1")]
+    pub source_study_uid: String,
+
+    /// 대상(후속 시점) Study UID - 같은 환자의 Study여야 함
+    #[schema(example = "1.2.840.113619.2.55.3.604688119.868.1234567899.1")]
+    pub target_study_uid: String,
+
+    /// 복사할 원본 Study의 어노테이션 ID
+    pub annotation_ids: Vec<i32>,
+}
+
+/// 전파된 어노테이션 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PropagatedAnnotationResponse {
+    /// 원본 어노테이션 ID
+    pub source_annotation_id: i32,
+
+    /// 병변 ID (최초 어노테이션 ID)
+    pub lesion_id: i32,
+
+    /// 새로 만든 어노테이션 ID (DRAFT)
+    pub annotation_id: i32,
+
+    pub series_uid: Option<String>,
+
+    pub instance_uid: Option<String>,
+
+    /// Series 매핑 방식 (SERIES_DESCRIPTION, MODALITY, UNMATCHED, STUDY_LEVEL)
+    pub series_match: String,
+}
+
+impl From<(Annotation, AnnotationPropagation)> for PropagatedAnnotationResponse {
+    fn from((annotation, propagation): (Annotation, AnnotationPropagation)) -> Self {
+        Self {
+            source_annotation_id: propagation.source_annotation_id,
+            lesion_id: propagation.root_annotation_id,
+            annotation_id: annotation.id,
+            series_uid: annotation.series_uid,
+            instance_uid: annotation.instance_uid,
+            series_match: propagation.series_match,
+        }
+    }
+}
+
+/// 전파하지 않은 어노테이션 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SkippedPropagationResponse {
+    pub source_annotation_id: i32,
+    pub reason: String,
+}
+
+/// 어노테이션 전파 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PropagateAnnotationsResponse {
+    pub source_study_uid: String,
+    pub target_study_uid: String,
+    pub propagated: Vec<PropagatedAnnotationResponse>,
+    /// 이미 대상 Study로 전파한 어노테이션
+    pub skipped: Vec<SkippedPropagationResponse>,
+}
+
+/// 병변 추적 조회 쿼리
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LesionTrackingQuery {
+    /// 환자 ID (`ProjectDataStudy.patient_id`)
+    pub patient_id: String,
+    /// 병변 ID (최초 어노테이션 ID)
+    pub lesion_id: Option<i32>,
+    /// 두 시점 이상에 있는 병변만 조회 (기본값 false)
+    pub tracked_only: Option<bool>,
+}
+
+/// 병변의 시점별 어노테이션 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LesionTimepointResponse {
+    pub annotation_id: i32,
+
+    pub study_uid: String,
+
+    /// 검사일 (YYYY-MM-DD)
+    pub study_date: Option<String>,
+
+    pub series_uid: Option<String>,
+
+    pub instance_uid: Option<String>,
+
+    pub review_status: AnnotationReviewStatus,
+
+    /// 전파된 어노테이션이면 원본 어노테이션 ID
+    pub source_annotation_id: Option<i32>,
+
+    /// 클라이언트 측정값
+    pub measurement_values: Option<Value>,
+
+    /// 서버에서 계산한 측정값
+    pub computed_measurements: Option<Value>,
+}
+
+impl From<LesionTimepoint> for LesionTimepointResponse {
+    fn from(timepoint: LesionTimepoint) -> Self {
+        Self {
+            annotation_id: timepoint.annotation_id,
+            study_uid: timepoint.study_uid,
+            study_date: timepoint.study_date.map(|date| date.to_string()),
+            series_uid: timepoint.series_uid,
+            instance_uid: timepoint.instance_uid,
+            review_status: timepoint.review_status,
+            source_annotation_id: timepoint.source_annotation_id,
+            measurement_values: timepoint.measurement_values,
+            computed_measurements: timepoint.computed_measurements,
+        }
+    }
+}
+
+/// 병변 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LesionResponse {
+    /// 병변 ID (최초 어노테이션 ID)
+    pub lesion_id: i32,
+
+    pub tool_name: String,
+
+    /// 최초 어노테이션 설명
+    pub description: Option<String>,
+
+    /// 검사일 순 시점별 어노테이션
+    pub timepoints: Vec<LesionTimepointResponse>,
+}
+
+/// 병변 추적 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LesionTrackingResponse {
+    pub patient_id: String,
+    pub lesions: Vec<LesionResponse>,
+}
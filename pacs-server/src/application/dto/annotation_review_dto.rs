@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::{AnnotationReviewComment, AnnotationReviewStatus};
+
+/// 검토 상태 전환 요청 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ChangeReviewStatusRequest {
+    /// 전환할 검토 상태
+    /// DRAFT → SUBMITTED → APPROVED / REJECTED → LOCKED
+    #[schema(example = "SUBMITTED")]
+    pub status: AnnotationReviewStatus,
+
+    /// 검토 코멘트
+    /// 반려(REJECTED) 시에는 필수
+    #[schema(example = "경계가 병변을 모두 포함하지 않습니다.")]
+    pub comment: Option<String>,
+}
+
+/// 검토자 배정 요청 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct AssignReviewerRequest {
+    /// 검토자 ID
+    /// 프로젝트에서 REVIEWER 또는 PROJECT_ADMIN 역할을 가진 사용자여야 함
+    #[schema(example = 42)]
+    pub reviewer_id: i32,
+}
+
+/// 검토 코멘트 작성 요청 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct CreateReviewCommentRequest {
+    /// 코멘트 내용
+    #[schema(example = "측정값을 다시 확인해주세요.")]
+    pub comment: String,
+}
+
+/// 검토 코멘트 응답 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ReviewCommentResponse {
+    /// 코멘트 ID
+    pub id: i32,
+
+    /// 어노테이션 ID
+    pub annotation_id: i32,
+
+    /// 작성자 ID
+    pub user_id: i32,
+
+    /// 코멘트 작성 시점의 검토 상태
+    pub review_status: AnnotationReviewStatus,
+
+    /// 코멘트 내용
+    pub comment: String,
+
+    /// 작성 시간
+    #[schema(value_type = String, example = "2024-01-01T00:00:00")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AnnotationReviewComment> for ReviewCommentResponse {
+    fn from(comment: AnnotationReviewComment) -> Self {
+        Self {
+            id: comment.id,
+            annotation_id: comment.annotation_id,
+            user_id: comment.user_id,
+            review_status: comment.review_status,
+            comment: comment.comment,
+            created_at: comment.created_at,
+        }
+    }
+}
+
+/// 검토 코멘트 목록 응답 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ReviewCommentListResponse {
+    /// 코멘트 목록 (작성 순)
+    pub comments: Vec<ReviewCommentResponse>,
+
+    /// 전체 개수
+    pub total: usize,
+}
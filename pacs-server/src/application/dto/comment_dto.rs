@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::comment::{Comment, CommentHistory, CommentTargetType};
+
+/// 코멘트 작성 요청 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct CreateCommentRequest {
+    /// 코멘트 본문
+    /// `@username` 형태로 프로젝트 멤버를 멘션할 수 있습니다.
+    #[schema(example = "@alice 우측 하엽 결절 경계 확인 부탁드립니다.")]
+    pub body: String,
+
+    /// 답글을 다는 경우 상위 코멘트 ID
+    #[schema(example = 12)]
+    pub parent_id: Option<i32>,
+}
+
+/// 코멘트 수정 요청 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UpdateCommentRequest {
+    /// 수정할 코멘트 본문
+    #[schema(example = "@alice 우측 하엽 결절 경계 재확인 부탁드립니다.")]
+    pub body: String,
+}
+
+/// 스레드 해결 상태 변경 요청 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ResolveCommentRequest {
+    /// 해결 여부 (false면 다시 미해결 상태로 변경)
+    #[schema(example = true)]
+    pub resolved: bool,
+}
+
+/// 코멘트 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentResponse {
+    /// 코멘트 ID
+    pub id: i32,
+
+    /// 대상 타입 (ANNOTATION, MASK_GROUP)
+    pub target_type: CommentTargetType,
+
+    /// 대상 ID
+    pub target_id: i32,
+
+    /// 상위 코멘트 ID
+    pub parent_id: Option<i32>,
+
+    /// 작성자 ID
+    pub user_id: i32,
+
+    /// 본문 (삭제된 코멘트는 빈 문자열)
+    pub body: String,
+
+    /// 멘션된 사용자 ID 목록
+    pub mentions: Vec<i32>,
+
+    /// 스레드 해결 여부
+    pub is_resolved: bool,
+
+    /// 해결 처리한 사용자 ID
+    pub resolved_by: Option<i32>,
+
+    /// 해결 시간
+    pub resolved_at: Option<String>,
+
+    /// 삭제 여부
+    pub is_deleted: bool,
+
+    /// 수정 여부
+    pub is_edited: bool,
+
+    /// 생성 시간
+    pub created_at: String,
+
+    /// 수정 시간
+    pub updated_at: String,
+
+    /// 답글 목록 (작성 순)
+    #[schema(no_recursion)]
+    pub replies: Vec<CommentResponse>,
+}
+
+impl From<Comment> for CommentResponse {
+    fn from(comment: Comment) -> Self {
+        Self {
+            id: comment.id,
+            target_type: comment.target_type,
+            target_id: comment.target_id,
+            parent_id: comment.parent_id,
+            user_id: comment.user_id,
+            body: comment.body,
+            mentions: comment.mentions,
+            is_resolved: comment.is_resolved,
+            resolved_by: comment.resolved_by,
+            resolved_at: comment.resolved_at.map(|t| t.to_rfc3339()),
+            is_deleted: comment.is_deleted,
+            is_edited: comment.updated_at > comment.created_at && !comment.is_deleted,
+            created_at: comment.created_at.to_rfc3339(),
+            updated_at: comment.updated_at.to_rfc3339(),
+            replies: Vec::new(),
+        }
+    }
+}
+
+/// 코멘트 스레드 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentThreadListResponse {
+    /// 최상위 코멘트 목록 (답글 포함)
+    pub threads: Vec<CommentResponse>,
+
+    /// 전체 코멘트 수 (답글 포함)
+    pub total_count: usize,
+
+    /// 미해결 스레드 수
+    pub unresolved_count: usize,
+}
+
+/// 코멘트 변경 이력 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentHistoryResponse {
+    /// 이력 ID
+    pub id: i32,
+
+    /// 변경한 사용자 ID
+    pub user_id: i32,
+
+    /// 수행된 작업 (UPDATE, DELETE, RESOLVE, REOPEN)
+    pub action: String,
+
+    /// 변경 전 본문
+    pub body_before: Option<String>,
+
+    /// 변경 후 본문
+    pub body_after: Option<String>,
+
+    /// 변경 시간
+    pub action_at: String,
+}
+
+impl From<CommentHistory> for CommentHistoryResponse {
+    fn from(history: CommentHistory) -> Self {
+        Self {
+            id: history.id,
+            user_id: history.user_id,
+            action: history.action,
+            body_before: history.body_before,
+            body_after: history.body_after,
+            action_at: history.action_at.to_rfc3339(),
+        }
+    }
+}
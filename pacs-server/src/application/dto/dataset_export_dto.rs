@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::annotation::AnnotationReviewStatus;
+use crate::domain::entities::dataset_export::{
+    DatasetExportFilter, DatasetExportFormat, DatasetExportJob, DatasetExportStatus,
+};
+
+/// 데이터셋 내보내기 요청 DTO
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CreateDatasetExportRequest {
+    /// 내보내기 포맷 (`coco`, `csv`, `json`)
+    #[schema(example = "coco")]
+    pub format: DatasetExportFormat,
+
+    /// 라벨 필터
+    /// 마스크는 `label_name`, 어노테이션은 `data.label`(없으면 도구 이름)과 비교
+    #[schema(example = "liver")]
+    pub label: Option<String>,
+
+    /// 마스크 그룹의 AI 모델 이름 필터
+    #[schema(example = "totalsegmentator")]
+    pub model_name: Option<String>,
+
+    /// 어노테이션 검토 상태 필터
+    #[schema(example = "APPROVED")]
+    pub review_status: Option<AnnotationReviewStatus>,
+
+    /// 마스크 오브젝트 키 대신 다운로드 Signed URL을 함께 기록할지 여부 (기본값 false)
+    pub include_signed_urls: Option<bool>,
+
+    /// Signed URL 만료 시간 (초)
+    #[schema(example = 86400)]
+    pub url_ttl_seconds: Option<u64>,
+}
+
+impl CreateDatasetExportRequest {
+    pub fn filter(&self) -> DatasetExportFilter {
+        DatasetExportFilter {
+            label: self.label.clone().filter(|s| !s.is_empty()),
+            model_name: self.model_name.clone().filter(|s| !s.is_empty()),
+            review_status: self.review_status,
+        }
+    }
+}
+
+/// 데이터셋 내보내기 작업 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DatasetExportJobResponse {
+    /// 작업 ID
+    pub id: i32,
+
+    /// 프로젝트 ID
+    pub project_id: i32,
+
+    /// 내보내기 포맷
+    pub format: String,
+
+    /// 적용된 필터
+    pub filter: DatasetExportFilter,
+
+    /// Signed URL 포함 여부
+    pub include_signed_urls: bool,
+
+    /// 작업 상태
+    pub status: DatasetExportStatus,
+
+    /// 내보낸 어노테이션 수
+    pub annotation_count: i32,
+
+    /// 내보낸 마스크 슬라이스 수
+    pub mask_count: i32,
+
+    /// 변환할 수 없어 제외된 항목 수
+    pub skipped_count: i32,
+
+    /// 결과 파일 경로
+    pub output_path: Option<String>,
+
+    /// 결과 파일 다운로드 Signed URL (완료된 작업 조회 시)
+    pub download_url: Option<String>,
+
+    /// 에러 메시지
+    pub error_message: Option<String>,
+
+    /// 요청자 ID
+    pub requested_by: Option<i32>,
+
+    /// 생성 시간
+    pub created_at: String,
+
+    /// 완료 시간
+    pub completed_at: Option<String>,
+}
+
+impl From<DatasetExportJob> for DatasetExportJobResponse {
+    fn from(job: DatasetExportJob) -> Self {
+        let filter = job.filter();
+        Self {
+            id: job.id,
+            project_id: job.project_id,
+            format: job.format,
+            filter,
+            include_signed_urls: job.include_signed_urls,
+            status: job.status,
+            annotation_count: job.annotation_count,
+            mask_count: job.mask_count,
+            skipped_count: job.skipped_count,
+            output_path: job.output_path,
+            download_url: None,
+            error_message: job.error_message,
+            requested_by: job.requested_by,
+            created_at: job.created_at.to_rfc3339(),
+            completed_at: job.completed_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+/// 데이터셋 내보내기 작업 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DatasetExportJobListResponse {
+    /// 내보내기 작업 목록 (최신순)
+    pub jobs: Vec<DatasetExportJobResponse>,
+
+    /// 전체 개수
+    pub total_count: i64,
+}
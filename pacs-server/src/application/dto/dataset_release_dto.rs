@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::annotation::AnnotationReviewStatus;
+use crate::domain::entities::dataset_export::DatasetExportFilter;
+use crate::domain::entities::dataset_release::{DatasetRelease, ReleaseItemDiff};
+
+/// 데이터셋 릴리스 생성 요청 DTO
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CreateDatasetReleaseRequest {
+    /// 릴리스 이름 (프로젝트 내에서 고유)
+    #[schema(example = "liver-seg-v1.0")]
+    pub name: String,
+
+    /// 설명
+    #[schema(example = "Training set for liver segmentation paper")]
+    pub description: Option<String>,
+
+    /// 포함할 어노테이션 ID 목록 (생략하면 조건에 맞는 전체)
+    pub annotation_ids: Option<Vec<i32>>,
+
+    /// 라벨 필터
+    #[schema(example = "liver")]
+    pub label: Option<String>,
+
+    /// 마스크 그룹의 AI 모델 이름 필터
+    #[schema(example = "totalsegmentator")]
+    pub model_name: Option<String>,
+
+    /// 어노테이션 검토 상태 필터
+    #[schema(example = "APPROVED")]
+    pub review_status: Option<AnnotationReviewStatus>,
+}
+
+impl CreateDatasetReleaseRequest {
+    pub fn filter(&self) -> DatasetExportFilter {
+        DatasetExportFilter {
+            label: self.label.clone().filter(|s| !s.is_empty()),
+            model_name: self.model_name.clone().filter(|s| !s.is_empty()),
+            review_status: self.review_status,
+        }
+    }
+}
+
+/// 데이터셋 릴리스 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DatasetReleaseResponse {
+    /// 릴리스 ID
+    pub id: i32,
+
+    /// 프로젝트 ID
+    pub project_id: i32,
+
+    /// 릴리스 이름
+    pub name: String,
+
+    /// 설명
+    pub description: Option<String>,
+
+    /// 릴리스 키 (스토리지 경로에 사용)
+    pub release_key: String,
+
+    /// 매니페스트 JSON 경로
+    pub manifest_path: String,
+
+    /// 매니페스트 다운로드 Signed URL (단건 조회 시)
+    pub manifest_url: Option<String>,
+
+    /// 릴리스 생성 시 사용한 선택 조건
+    pub filter: DatasetExportFilter,
+
+    /// 고정된 어노테이션 수
+    pub annotation_count: i32,
+
+    /// 고정된 마스크 수
+    pub mask_count: i32,
+
+    /// 생성자 ID
+    pub created_by: Option<i32>,
+
+    /// 생성 시간
+    pub created_at: String,
+}
+
+impl From<DatasetRelease> for DatasetReleaseResponse {
+    fn from(release: DatasetRelease) -> Self {
+        let filter = release.filter();
+        Self {
+            id: release.id,
+            project_id: release.project_id,
+            name: release.name,
+            description: release.description,
+            release_key: release.release_key.to_string(),
+            manifest_path: release.manifest_path,
+            manifest_url: None,
+            filter,
+            annotation_count: release.annotation_count,
+            mask_count: release.mask_count,
+            created_by: release.created_by,
+            created_at: release.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// 데이터셋 릴리스 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DatasetReleaseListResponse {
+    /// 릴리스 목록 (최신순)
+    pub releases: Vec<DatasetReleaseResponse>,
+
+    /// 전체 개수
+    pub total_count: i64,
+}
+
+/// 릴리스 비교 쿼리
+#[derive(Debug, Deserialize)]
+pub struct DatasetReleaseDiffQuery {
+    /// 기준 릴리스 ID
+    pub base: i32,
+}
+
+/// 릴리스 비교 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DatasetReleaseDiffResponse {
+    /// 기준 릴리스 ID
+    pub base_release_id: i32,
+
+    /// 비교 대상 릴리스 ID
+    pub release_id: i32,
+
+    /// 어노테이션 변화 (히스토리 ID 기준)
+    pub annotations: ReleaseItemDiff,
+
+    /// 마스크 변화 (체크섬 기준)
+    pub masks: ReleaseItemDiff,
+}
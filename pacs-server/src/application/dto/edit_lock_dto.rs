@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::edit_lock::{EditLock, EditLockTarget};
+
+/// 편집 잠금 획득 요청 DTO (`study_uid`와 `annotation_id` 중 하나만 지정)
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct AcquireEditLockRequest {
+    /// 잠글 Study Instance UID (Study 전체 잠금)
+    #[schema(example = "1.2.840.113619.2.55.3.604688119.868.1234567890.123")]
+    pub study_uid: Option<String>,
+
+    /// 잠글 어노테이션 ID (어노테이션 하나 잠금)
+    pub annotation_id: Option<i32>,
+
+    /// 잠금 유지 시간 (초, 기본값 120, 10~900)
+    #[schema(example = 120)]
+    pub ttl_seconds: Option<i64>,
+}
+
+/// 편집 잠금 갱신(heartbeat) 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct EditLockHeartbeatRequest {
+    /// 새 잠금 유지 시간 (초, 기본값 120, 10~900)
+    #[schema(example = 120)]
+    pub ttl_seconds: Option<i64>,
+}
+
+/// 편집 잠금 목록 조회 쿼리
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EditLockListQuery {
+    /// Study Instance UID
+    pub study_uid: Option<String>,
+}
+
+/// 편집 잠금 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EditLockResponse {
+    /// 잠금 ID
+    pub id: i32,
+
+    /// 프로젝트 ID
+    pub project_id: i32,
+
+    /// 잠금 대상 종류
+    pub target_type: EditLockTarget,
+
+    /// Study Instance UID (어노테이션 잠금은 어노테이션의 Study)
+    pub study_uid: String,
+
+    /// 어노테이션 ID (어노테이션 잠금만)
+    pub annotation_id: Option<i32>,
+
+    /// 잠금을 가진 사용자 ID
+    pub holder_id: i32,
+
+    /// 잠금을 가진 사용자 이름
+    pub holder_username: Option<String>,
+
+    /// 획득 시간
+    pub acquired_at: String,
+
+    /// 마지막 heartbeat 시간
+    pub renewed_at: String,
+
+    /// 만료 시간
+    pub expires_at: String,
+}
+
+impl From<EditLock> for EditLockResponse {
+    fn from(lock: EditLock) -> Self {
+        Self {
+            id: lock.id,
+            project_id: lock.project_id,
+            target_type: lock.target_type,
+            study_uid: lock.study_uid,
+            annotation_id: lock.annotation_id,
+            holder_id: lock.holder_id,
+            holder_username: lock.holder_username,
+            acquired_at: lock.acquired_at.to_rfc3339(),
+            renewed_at: lock.renewed_at.to_rfc3339(),
+            expires_at: lock.expires_at.to_rfc3339(),
+        }
+    }
+}
+
+/// 편집 잠금 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EditLockListResponse {
+    /// 유효한 잠금 (Study UID순)
+    pub locks: Vec<EditLockResponse>,
+}
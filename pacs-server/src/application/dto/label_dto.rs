@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::label::{Label, LabelDefinition};
+
+/// 라벨 생성/수정 요청 DTO (수정 시 전체 필드를 교체)
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct LabelRequest {
+    /// 정식 라벨 이름 (프로젝트 내에서 고유, 대소문자/구분자 무시)
+    #[schema(example = "liver")]
+    pub name: String,
+
+    /// 화면 표시 이름
+    #[schema(example = "Liver")]
+    pub display_name: Option<String>,
+
+    /// 표시 색상 (#RRGGBB)
+    #[schema(example = "#dd4444")]
+    pub color: Option<String>,
+
+    /// 코드 체계 (SCT: SNOMED CT, RADLEX: RadLex)
+    #[schema(example = "SCT")]
+    pub code_scheme: Option<String>,
+
+    /// 코드 값
+    #[schema(example = "10200004")]
+    pub code_value: Option<String>,
+
+    /// 코드 의미
+    #[schema(example = "Liver structure")]
+    pub code_meaning: Option<String>,
+
+    /// 상위 라벨 ID
+    pub parent_id: Option<i32>,
+
+    /// 이 라벨을 사용할 수 있는 어노테이션 도구 (비어 있으면 제한 없음)
+    #[serde(default)]
+    #[schema(example = json!(["Brush Tool", "Polygon Tool"]))]
+    pub allowed_tools: Vec<String>,
+
+    /// 정식 이름으로 매핑되는 별칭
+    #[serde(default)]
+    #[schema(example = json!(["LIVER_SEG", "hepar"]))]
+    pub aliases: Vec<String>,
+}
+
+impl LabelRequest {
+    /// 라벨 정의와 상위 라벨 ID로 분리
+    pub fn into_parts(self) -> (LabelDefinition, Option<i32>) {
+        let definition = LabelDefinition {
+            name: self.name,
+            display_name: self.display_name,
+            color: self.color,
+            code_scheme: self.code_scheme,
+            code_value: self.code_value,
+            code_meaning: self.code_meaning,
+            allowed_tools: self.allowed_tools,
+            aliases: self.aliases,
+        };
+        (definition, self.parent_id)
+    }
+}
+
+/// 라벨 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LabelResponse {
+    /// 라벨 ID
+    pub id: i32,
+
+    /// 프로젝트 ID
+    pub project_id: i32,
+
+    /// 상위 라벨 ID
+    pub parent_id: Option<i32>,
+
+    /// 정식 라벨 이름
+    pub name: String,
+
+    /// 화면 표시 이름
+    pub display_name: Option<String>,
+
+    /// 표시 색상
+    pub color: Option<String>,
+
+    /// 코드 체계
+    pub code_scheme: Option<String>,
+
+    /// 코드 값
+    pub code_value: Option<String>,
+
+    /// 코드 의미
+    pub code_meaning: Option<String>,
+
+    /// 허용 도구
+    pub allowed_tools: Vec<String>,
+
+    /// 별칭
+    pub aliases: Vec<String>,
+
+    /// 생성 시간
+    pub created_at: String,
+
+    /// 수정 시간
+    pub updated_at: String,
+}
+
+impl From<Label> for LabelResponse {
+    fn from(label: Label) -> Self {
+        Self {
+            id: label.id,
+            project_id: label.project_id,
+            parent_id: label.parent_id,
+            name: label.name,
+            display_name: label.display_name,
+            color: label.color,
+            code_scheme: label.code_scheme,
+            code_value: label.code_value,
+            code_meaning: label.code_meaning,
+            allowed_tools: label.allowed_tools,
+            aliases: label.aliases,
+            created_at: label.created_at.to_rfc3339(),
+            updated_at: label.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// 라벨 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LabelListResponse {
+    /// 프로젝트 라벨 (이름순)
+    pub labels: Vec<LabelResponse>,
+}
+
+/// 라벨 CSV 가져오기 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LabelImportResponse {
+    /// 새로 추가된 라벨 수
+    pub created: usize,
+
+    /// 덮어쓴 기존 라벨 수
+    pub updated: usize,
+
+    /// 가져오기 후의 프로젝트 라벨
+    pub labels: Vec<LabelResponse>,
+}
+
+/// 자유 입력 라벨의 분류 체계 매핑 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LabelMigrationStatus {
+    /// 이미 정식 라벨 이름
+    Canonical,
+    /// 대응하는 정식 라벨이 제안됨 (대소문자/구분자/별칭/접미사 차이)
+    Suggested,
+    /// 대응하는 정식 라벨이 없음
+    Unmapped,
+}
+
+/// 라벨 마이그레이션 미리보기 항목
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LabelMigrationEntry {
+    /// 사용 중인 라벨 값
+    pub label: String,
+
+    /// 이 라벨을 가진 마스크 수
+    pub mask_count: i64,
+
+    /// 이 라벨을 가진 어노테이션 수
+    pub annotation_count: i64,
+
+    /// 매핑 상태
+    pub status: LabelMigrationStatus,
+
+    /// 제안된 정식 라벨 ID
+    pub suggested_label_id: Option<i32>,
+
+    /// 제안된 정식 라벨 이름
+    pub suggested_label: Option<String>,
+}
+
+/// 라벨 마이그레이션 미리보기 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LabelMigrationPreviewResponse {
+    /// 프로젝트에서 사용 중인 라벨 (라벨 값순)
+    pub entries: Vec<LabelMigrationEntry>,
+}
+
+/// 자유 입력 라벨 → 정식 라벨 매핑
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct LabelMappingRequest {
+    /// 사용 중인 라벨 값 (정확히 일치)
+    #[schema(example = "LIVER_SEG")]
+    pub from: String,
+
+    /// 매핑할 정식 라벨 ID
+    pub label_id: i32,
+}
+
+/// 라벨 마이그레이션 적용 요청 DTO
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ApplyLabelMigrationRequest {
+    /// 직접 지정한 매핑 (제안보다 우선)
+    #[serde(default)]
+    pub mappings: Vec<LabelMappingRequest>,
+
+    /// 미리보기에서 제안된 매핑도 함께 적용
+    #[serde(default)]
+    pub apply_suggestions: bool,
+}
+
+/// 적용된 라벨 매핑
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AppliedLabelMapping {
+    /// 기존 라벨 값
+    pub from: String,
+
+    /// 정식 라벨 이름
+    pub to: String,
+}
+
+/// 라벨 마이그레이션 적용 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LabelMigrationResponse {
+    /// 적용된 매핑
+    pub mappings: Vec<AppliedLabelMapping>,
+
+    /// 라벨이 바뀐 마스크 수
+    pub masks_updated: u64,
+
+    /// 라벨이 바뀐 어노테이션 수 (잠긴 어노테이션 제외)
+    pub annotations_updated: u64,
+}
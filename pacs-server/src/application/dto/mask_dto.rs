@@ -46,6 +46,14 @@ pub struct MaskResponse {
     /// 마스크 이미지의 높이 (픽셀)
     pub height: Option<i32>,
     
+    /// 썸네일 URL
+    /// 축소한 그레이스케일 썸네일의 서명된 다운로드 URL (생성 전이면 null)
+    pub thumbnail_url: Option<String>,
+    
+    /// 미리보기 URL
+    /// 영상 위에 겹쳐 그리는 컬러 미리보기(배경 투명)의 서명된 다운로드 URL (생성 전이면 null)
+    pub preview_url: Option<String>,
+    
     /// 생성 시간
     pub created_at: String,
     
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::Mask;
+
+/// 마스크 그룹 ZIP 다운로드 쿼리 파라미터
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct MaskGroupArchiveQuery {
+    /// 포함할 첫 슬라이스 인덱스 (포함)
+    pub slice_start: Option<i32>,
+
+    /// 포함할 마지막 슬라이스 인덱스 (포함)
+    pub slice_end: Option<i32>,
+
+    /// 포함할 라벨 이름 (쉼표로 구분)
+    pub labels: Option<String>,
+}
+
+/// ZIP에 담긴 마스크 메타데이터
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskArchiveEntry {
+    pub mask_id: i32,
+    /// ZIP 안의 경로 (스토리지에서 읽지 못했으면 null)
+    pub archive_path: Option<String>,
+    pub slice_index: Option<i32>,
+    pub sop_instance_uid: Option<String>,
+    pub label_name: Option<String>,
+    /// 스토리지 파일 경로
+    pub file_path: String,
+    pub mime_type: Option<String>,
+    pub file_size: Option<i64>,
+    pub checksum: Option<String>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub created_at: String,
+}
+
+impl From<&Mask> for MaskArchiveEntry {
+    fn from(mask: &Mask) -> Self {
+        Self {
+            mask_id: mask.id,
+            archive_path: None,
+            slice_index: mask.slice_index,
+            sop_instance_uid: mask.sop_instance_uid.clone(),
+            label_name: mask.label_name.clone(),
+            file_path: mask.file_path.clone(),
+            mime_type: mask.mime_type.clone(),
+            file_size: mask.file_size,
+            checksum: mask.checksum.clone(),
+            width: mask.width,
+            height: mask.height,
+            created_at: mask.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// ZIP 마지막에 담기는 `manifest.json`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskGroupArchiveManifest {
+    pub mask_group_id: i32,
+    pub annotation_id: i32,
+    pub group_name: Option<String>,
+    pub modality: Option<String>,
+    pub mask_type: Option<String>,
+    /// 요청한 필터
+    pub filter: MaskGroupArchiveQuery,
+    pub generated_by: i32,
+    pub generated_at: String,
+    /// ZIP에 담긴 마스크 (슬라이스 순서)
+    pub masks: Vec<MaskArchiveEntry>,
+    /// 필터에 해당하지만 스토리지에서 읽지 못한 마스크
+    pub missing: Vec<MaskArchiveEntry>,
+}
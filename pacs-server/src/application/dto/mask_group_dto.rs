@@ -40,6 +40,11 @@ pub struct CreateMaskGroupRequest {
     /// 마스크 그룹에 대한 추가 설명이나 메모
     #[schema(example = "간 세그멘테이션 결과")]
     pub description: Option<String>,
+    
+    /// 파생 원본 마스크 그룹 ID
+    /// 이 그룹이 수정한 원본 그룹 (예: AI 결과), 같은 어노테이션에 속해야 함
+    #[schema(example = 12)]
+    pub derived_from_id: Option<i32>,
 }
 
 /// 마스크 그룹 응답 DTO
@@ -75,6 +80,9 @@ pub struct MaskGroupResponse {
     /// 생성자 ID
     pub created_by: Option<i32>,
     
+    /// 파생 원본 마스크 그룹 ID
+    pub derived_from_id: Option<i32>,
+    
     /// 생성 시간
     pub created_at: String,
     
@@ -185,6 +193,113 @@ pub struct SignedUrlResponse {
     /// Signed URL의 만료 시간
     #[schema(example = "2024-01-01T12:00:00Z")]
     pub expires_at: String,
+
+    /// 저장 용량 경고
+    /// 프로젝트 또는 사용자 사용량이 경고 한도 이상이면 포함 (업로드는 허용됨)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quota_warning: Option<String>,
+}
+
+/// 일괄 업로드 파일 DTO
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct BatchUploadFile {
+    /// 파일명 (확장자 포함, 그룹 내에서 중복 불가)
+    #[schema(example = "0001_liver.png")]
+    pub filename: String,
+
+    /// MIME 타입
+    #[schema(example = "image/png")]
+    pub mime_type: String,
+
+    /// 파일 크기 (바이트)
+    #[schema(example = 1024000)]
+    pub file_size: Option<i64>,
+
+    /// 슬라이스 인덱스
+    #[schema(example = 1)]
+    pub slice_index: Option<i32>,
+}
+
+/// 일괄 업로드 Signed URL 요청 DTO
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct BatchUploadUrlRequest {
+    /// 업로드할 파일 목록
+    pub files: Vec<BatchUploadFile>,
+
+    /// TTL (초, 모든 URL에 동일하게 적용)
+    #[schema(example = 3600)]
+    pub ttl_seconds: Option<u64>,
+}
+
+/// 일괄 다운로드 Signed URL 요청 DTO
+///
+/// 선택 조건을 모두 생략하면 그룹의 모든 마스크 URL을 반환합니다.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct BatchDownloadUrlRequest {
+    /// 슬라이스 인덱스 목록
+    #[schema(example = json!([0, 1, 2]))]
+    pub slice_indices: Option<Vec<i32>>,
+
+    /// 파일명 목록 (파일명 또는 전체 파일 경로)
+    #[schema(example = json!(["0001_liver.png"]))]
+    pub file_names: Option<Vec<String>>,
+
+    /// TTL (초, 모든 URL에 동일하게 적용)
+    #[schema(example = 3600)]
+    pub ttl_seconds: Option<u64>,
+}
+
+/// 일괄 발급된 Signed URL 항목 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchSignedUrlItem {
+    /// 마스크 ID (다운로드 URL인 경우)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_id: Option<i32>,
+
+    /// 슬라이스 인덱스
+    pub slice_index: Option<i32>,
+
+    /// 파일 경로
+    #[schema(example = "masks/annotation_1/group_1/0001_liver.png")]
+    pub file_path: String,
+
+    /// Signed URL
+    pub url: String,
+}
+
+/// 일괄 Signed URL 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchSignedUrlResponse {
+    /// 마스크 그룹 ID
+    #[schema(example = 1)]
+    pub mask_group_id: i32,
+
+    /// HTTP 메서드 (PUT: 업로드, GET: 다운로드)
+    #[schema(example = "GET")]
+    pub method: String,
+
+    /// 발급된 URL 목록 (업로드는 요청 순서, 다운로드는 슬라이스 순서)
+    pub urls: Vec<BatchSignedUrlItem>,
+
+    /// 그룹에 마스크가 없는 슬라이스 인덱스
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing_slice_indices: Vec<i32>,
+
+    /// 그룹에 마스크가 없는 파일명
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing_file_names: Vec<String>,
+
+    /// 만료 시간 (초, 발급된 URL이 없으면 0)
+    #[schema(example = 600)]
+    pub expires_in: u64,
+
+    /// 만료 시간 (ISO 8601, 발급된 URL이 없으면 빈 문자열)
+    #[schema(example = "2024-01-01T12:00:00Z")]
+    pub expires_at: String,
+
+    /// 저장 용량 경고 (업로드 URL인 경우)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quota_warning: Option<String>,
 }
 
 /// 업로드 완료 요청 DTO
@@ -285,6 +400,9 @@ pub struct MaskGroupDetailResponse {
     /// 생성자 ID
     pub created_by: Option<i32>,
     
+    /// 파생 원본 마스크 그룹 ID
+    pub derived_from_id: Option<i32>,
+    
     /// 생성 시간
     pub created_at: String,
     
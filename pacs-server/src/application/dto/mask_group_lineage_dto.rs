@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 계보에 속한 마스크 그룹
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskGroupLineageNodeResponse {
+    pub id: i32,
+    pub group_name: Option<String>,
+    pub model_name: Option<String>,
+    pub version: Option<String>,
+    pub mask_type: Option<String>,
+    pub created_by: Option<i32>,
+    /// 파생 원본 마스크 그룹 ID (접근할 수 없는 원본은 목록에 없음)
+    pub derived_from_id: Option<i32>,
+    /// 이 그룹에서 파생된 마스크 그룹 ID 목록
+    pub derived_ids: Vec<i32>,
+    /// 목록에 있는 가장 가까운 루트로부터의 깊이 (루트는 0)
+    pub depth: u32,
+    pub mask_count: i64,
+    pub created_at: String,
+}
+
+/// 어노테이션의 마스크 그룹 계보
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskGroupLineageResponse {
+    pub annotation_id: i32,
+    /// 루트부터 깊이 우선 순서
+    pub groups: Vec<MaskGroupLineageNodeResponse>,
+}
+
+/// 파생 원본 설정 요청
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SetDerivedFromRequest {
+    /// 파생 원본 마스크 그룹 ID (null이면 해제)
+    #[schema(example = 12)]
+    pub derived_from_id: Option<i32>,
+}
+
+/// 마스크 그룹 비교 쿼리 파라미터
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct MaskGroupComparisonQuery {
+    /// 기준 마스크 그룹 ID (기본값은 비교 대상 그룹의 파생 원본)
+    pub reference_id: Option<i32>,
+    /// 비교할 라벨 (쉼표로 구분, 기본값은 전체)
+    pub labels: Option<String>,
+}
+
+/// 라벨별 비교 결과
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LabelComparisonResponse {
+    /// 라벨 이름 (라벨이 없는 마스크는 null)
+    pub label_name: Option<String>,
+    pub reference_voxels: u64,
+    pub candidate_voxels: u64,
+    pub intersection_voxels: u64,
+    /// Dice 계수 (양쪽 모두 비어 있으면 null)
+    pub dice: Option<f64>,
+    /// Jaccard 지수 (양쪽 모두 비어 있으면 null)
+    pub jaccard: Option<f64>,
+    /// candidate - reference (복셀)
+    pub volume_difference_voxels: i64,
+    /// 기준 부피 대비 비율 (기준이 비어 있으면 null)
+    pub relative_volume_difference: Option<f64>,
+    /// 슬라이스별 2D Hausdorff 거리의 최댓값 (픽셀 단위, 픽셀 간격 미반영)
+    ///
+    /// 양쪽 모두 이 라벨이 있는 슬라이스만 반영하며, 그런 슬라이스가 없으면 null입니다.
+    /// 한쪽에만 있는 슬라이스는 `unmatched_slices`로 따로 집계됩니다.
+    pub hausdorff_distance_pixels: Option<f64>,
+    /// 이 라벨을 비교한 슬라이스 수
+    pub compared_slices: u32,
+    /// 이 라벨이 한쪽 그룹에만 있어 Hausdorff 거리에 반영되지 않은 슬라이스 수
+    pub unmatched_slices: u32,
+}
+
+/// 두 마스크 그룹의 비교 결과
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskGroupComparisonResponse {
+    pub annotation_id: i32,
+    pub reference_id: i32,
+    pub reference_model_name: Option<String>,
+    pub reference_version: Option<String>,
+    pub candidate_id: i32,
+    pub candidate_model_name: Option<String>,
+    pub candidate_version: Option<String>,
+    /// 양쪽에 모두 있어 비교한 슬라이스 수
+    pub matched_slices: usize,
+    /// 기준 그룹에만 있는 슬라이스 수
+    pub reference_only_slices: usize,
+    /// 비교 대상 그룹에만 있는 슬라이스 수
+    pub candidate_only_slices: usize,
+    pub labels: Vec<LabelComparisonResponse>,
+    /// 건너뛴 마스크 등 비교 중 발생한 경고
+    pub warnings: Vec<String>,
+}
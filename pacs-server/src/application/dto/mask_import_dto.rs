@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use crate::domain::entities::mask_import::{MaskImportJob, MaskImportStatus, UnmappedFrame};
+
+/// 라벨 볼륨 원본 업로드 URL 요청 DTO
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct MaskImportUploadUrlRequest {
+    /// 파일명
+    /// 업로드할 라벨 볼륨 파일의 이름 (확장자 포함)
+    #[schema(example = "liver_seg.nii.gz")]
+    pub filename: String,
+
+    /// URL 만료 시간 (초)
+    #[schema(example = 600)]
+    pub ttl_seconds: Option<u64>,
+}
+
+/// 라벨 볼륨 원본 업로드 URL 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MaskImportUploadUrlResponse {
+    /// 업로드용 Signed URL (PUT)
+    pub upload_url: String,
+
+    /// 임포트 요청에 사용할 원본 파일 경로
+    #[schema(example = "imports/annotation_123/5f0c..._liver_seg.nii.gz")]
+    pub source_path: String,
+
+    /// 만료 시간 (초)
+    pub expires_in: u64,
+
+    /// 만료 시간 (ISO 8601)
+    pub expires_at: String,
+}
+
+/// 라벨 볼륨 임포트 요청 DTO
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CreateMaskImportRequest {
+    /// 원본 파일 경로
+    /// 업로드 URL 발급 시 받은 `imports/annotation_{annotation_id}/` 아래의 DICOM SEG 또는 NIfTI 파일 경로
+    #[schema(example = "imports/annotation_123/liver_seg.nii.gz")]
+    pub source_path: String,
+
+    /// 원본 포맷
+    /// `nifti` 또는 `dicom_seg`. 생략하면 파일 확장자로 판단
+    #[schema(example = "nifti")]
+    pub format: Option<String>,
+
+    /// 생성할 마스크 그룹 이름
+    #[schema(example = "Liver_Segmentation_import")]
+    pub group_name: Option<String>,
+
+    /// AI 모델 이름
+    #[schema(example = "totalsegmentator")]
+    pub model_name: Option<String>,
+
+    /// 버전 정보
+    #[schema(example = "2.0.0")]
+    pub version: Option<String>,
+
+    /// 의료 영상 모달리티
+    #[schema(example = "CT")]
+    pub modality: Option<String>,
+
+    /// 마스크 타입 (기본값: segmentation)
+    #[schema(example = "segmentation")]
+    pub mask_type: Option<String>,
+
+    /// 설명
+    pub description: Option<String>,
+
+    /// 슬라이스 인덱스 순서의 SOP Instance UID 목록
+    /// 원본에 UID가 없는 프레임은 `instance_uids[slice_index]`로 매핑됩니다.
+    #[schema(example = json!(["1.2.840.113619.2.55.3.1", "1.2.840.113619.2.55.3.2"]))]
+    pub instance_uids: Option<Vec<String>>,
+
+    /// 라벨 값 → 라벨 이름 매핑 (NIfTI 용)
+    #[schema(example = json!({"1": "liver", "2": "tumor"}))]
+    pub label_names: Option<HashMap<String, String>>,
+}
+
+/// 라벨 볼륨 임포트 작업 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MaskImportJobResponse {
+    /// 작업 ID
+    pub id: i32,
+
+    /// 어노테이션 ID
+    pub annotation_id: i32,
+
+    /// 생성된 마스크 그룹 ID
+    pub mask_group_id: Option<i32>,
+
+    /// 원본 파일 경로
+    pub source_path: String,
+
+    /// 원본 포맷
+    pub source_format: String,
+
+    /// 작업 상태
+    pub status: MaskImportStatus,
+
+    /// 볼륨에서 추출된 프레임 수
+    pub total_frames: i32,
+
+    /// 생성된 마스크 수
+    pub imported_masks: i32,
+
+    /// SOP Instance UID에 매핑되지 않은 프레임 목록
+    pub unmapped_frames: Vec<UnmappedFrame>,
+
+    /// 에러 메시지
+    pub error_message: Option<String>,
+
+    /// 요청자 ID
+    pub requested_by: Option<i32>,
+
+    /// 생성 시간
+    pub created_at: String,
+
+    /// 완료 시간
+    pub completed_at: Option<String>,
+}
+
+impl From<MaskImportJob> for MaskImportJobResponse {
+    fn from(job: MaskImportJob) -> Self {
+        let unmapped_frames = job.unmapped_frame_list();
+        Self {
+            id: job.id,
+            annotation_id: job.annotation_id,
+            mask_group_id: job.mask_group_id,
+            source_path: job.source_path,
+            source_format: job.source_format,
+            status: job.status,
+            total_frames: job.total_frames,
+            imported_masks: job.imported_masks,
+            unmapped_frames,
+            error_message: job.error_message,
+            requested_by: job.requested_by,
+            created_at: job.created_at.to_rfc3339(),
+            completed_at: job.completed_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+/// 라벨 볼륨 임포트 작업 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MaskImportJobListResponse {
+    /// 임포트 작업 목록 (최신순)
+    pub jobs: Vec<MaskImportJobResponse>,
+
+    /// 전체 개수
+    pub total_count: i64,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::MaskPreviewStatus;
+
+/// contact sheet 조회 쿼리 파라미터
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct ContactSheetQuery {
+    /// 다운로드 URL 만료 시간 (초, 기본값은 서버 설정)
+    pub expires_in: Option<u64>,
+}
+
+/// 마스크 그룹 contact sheet 조회 결과
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskGroupContactSheetResponse {
+    pub mask_group_id: i32,
+    /// 생성 상태 (마스크 썸네일이 바뀌면 다시 `PENDING`)
+    pub status: MaskPreviewStatus,
+    /// contact sheet에 담긴 마스크 수
+    pub mask_count: i32,
+    /// 서명된 다운로드 URL (생성 전이거나 썸네일이 하나도 없으면 null)
+    pub url: Option<String>,
+    pub expires_in: Option<u64>,
+    pub expires_at: Option<String>,
+    /// 마지막 생성 실패 사유
+    pub error: Option<String>,
+    pub generated_at: Option<String>,
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::{MaskGroupLabelVolume, MaskLabelStats, MaskValidation, MaskValidationStatus};
+
+/// 마스크 안의 라벨 값별 통계
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskLabelStatsResponse {
+    /// PNG 픽셀 값 (이진 마스크는 255)
+    pub label_value: i32,
+    pub label_name: Option<String>,
+    pub voxel_count: i64,
+    /// `[x, y, width, height]` (픽셀)
+    pub bounding_box: [i32; 4],
+}
+
+impl From<MaskLabelStats> for MaskLabelStatsResponse {
+    fn from(stats: MaskLabelStats) -> Self {
+        Self {
+            label_value: stats.label_value,
+            label_name: stats.label_name,
+            voxel_count: stats.voxel_count,
+            bounding_box: stats.bounding_box,
+        }
+    }
+}
+
+/// 마스크 하나의 검증 결과
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskValidationResponse {
+    pub mask_id: i32,
+    pub slice_index: Option<i32>,
+    pub label_name: Option<String>,
+    pub file_path: String,
+    pub status: MaskValidationStatus,
+    /// 검증 실패 사유
+    pub errors: Vec<String>,
+    pub label_stats: Vec<MaskLabelStatsResponse>,
+    /// 서버가 계산한 SHA-256
+    pub content_sha256: Option<String>,
+    pub validated_at: Option<String>,
+}
+
+impl From<MaskValidation> for MaskValidationResponse {
+    fn from(validation: MaskValidation) -> Self {
+        let errors = serde_json::from_value(validation.validation_errors).unwrap_or_default();
+        let label_stats: Vec<MaskLabelStats> = validation
+            .label_stats
+            .and_then(|stats| serde_json::from_value(stats).ok())
+            .unwrap_or_default();
+        Self {
+            mask_id: validation.mask_id,
+            slice_index: validation.slice_index,
+            label_name: validation.label_name,
+            file_path: validation.file_path,
+            status: validation.validation_status,
+            errors,
+            label_stats: label_stats.into_iter().map(Into::into).collect(),
+            content_sha256: validation.content_sha256,
+            validated_at: validation.validated_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+/// 마스크 그룹의 라벨별 합계
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskGroupLabelVolumeResponse {
+    pub label_value: i32,
+    pub label_name: Option<String>,
+    /// 검증을 통과한 마스크의 복셀 수 합계
+    pub voxel_count: i64,
+    pub mask_count: i64,
+    pub slice_start: Option<i32>,
+    pub slice_end: Option<i32>,
+}
+
+impl From<MaskGroupLabelVolume> for MaskGroupLabelVolumeResponse {
+    fn from(volume: MaskGroupLabelVolume) -> Self {
+        Self {
+            label_value: volume.label_value,
+            label_name: volume.label_name,
+            voxel_count: volume.voxel_count,
+            mask_count: volume.mask_count,
+            slice_start: volume.slice_start,
+            slice_end: volume.slice_end,
+        }
+    }
+}
+
+/// 상태별 마스크 수
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+pub struct MaskValidationCounts {
+    pub total: usize,
+    pub pending: usize,
+    pub valid: usize,
+    pub invalid: usize,
+    pub error: usize,
+}
+
+/// 마스크 그룹 검증 결과
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskGroupValidationResponse {
+    pub mask_group_id: i32,
+    pub counts: MaskValidationCounts,
+    /// 라벨별 합계 (검증을 통과한 마스크만)
+    pub labels: Vec<MaskGroupLabelVolumeResponse>,
+    pub masks: Vec<MaskValidationResponse>,
+}
+
+/// 재검증 요청 결과
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaskValidationRequeueResponse {
+    pub mask_group_id: i32,
+    /// 다시 검증 대기 상태가 된 마스크 수
+    pub requeued: u64,
+}
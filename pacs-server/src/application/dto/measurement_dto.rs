@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::measurement::{AnnotationMeasurement, ComputedMeasurement, InstanceMetadata, MeasurementDiscrepancy};
+
+/// 인스턴스 메타데이터 항목
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct InstanceMetadataItem {
+    #[schema(example = "1.2.840.113619.2.55.3.604688119.868.1234567890.1")]
+    pub study_uid: String,
+
+    #[schema(example = "1.2.840.113619.2.55.3.604688119.868.1234567890.2")]
+    pub series_uid: String,
+
+    #[schema(example = "1.2.840.113619.2.55.3.604688119.868.1234567890.3")]
+    pub instance_uid: String,
+
+    /// DICOM Pixel Spacing (0028,0030) - [행 간격, 열 간격] (mm)
+    #[schema(example = json!([0.7, 0.7]))]
+    pub pixel_spacing: Vec<f64>,
+
+    /// 행 수 (0028,0010)
+    pub rows: Option<i32>,
+
+    /// 열 수 (0028,0011)
+    pub columns: Option<i32>,
+}
+
+/// 인스턴스 메타데이터 등록 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct UpsertInstanceMetadataRequest {
+    /// 등록할 인스턴스 (이미 있는 인스턴스는 갱신)
+    pub instances: Vec<InstanceMetadataItem>,
+
+    /// 등록한 인스턴스의 어노테이션 측정값을 다시 계산할지 여부 (기본값 true)
+    pub recompute: Option<bool>,
+}
+
+/// 인스턴스 메타데이터 조회 쿼리
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct InstanceMetadataQuery {
+    pub study_uid: Option<String>,
+    pub series_uid: Option<String>,
+    /// 최대 개수 (기본값 100, 최대 1000)
+    pub limit: Option<i64>,
+}
+
+/// 인스턴스 메타데이터 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InstanceMetadataResponse {
+    pub study_uid: String,
+    pub series_uid: String,
+    pub instance_uid: String,
+
+    /// [행 간격, 열 간격] (mm)
+    pub pixel_spacing: Vec<f64>,
+
+    pub rows: Option<i32>,
+    pub columns: Option<i32>,
+
+    /// 마지막으로 등록한 사용자 ID
+    pub updated_by: Option<i32>,
+
+    pub updated_at: String,
+}
+
+impl From<InstanceMetadata> for InstanceMetadataResponse {
+    fn from(metadata: InstanceMetadata) -> Self {
+        Self {
+            study_uid: metadata.study_uid,
+            series_uid: metadata.series_uid,
+            instance_uid: metadata.instance_uid,
+            pixel_spacing: vec![metadata.pixel_spacing_row, metadata.pixel_spacing_column],
+            rows: metadata.rows,
+            columns: metadata.columns,
+            updated_by: metadata.updated_by,
+            updated_at: metadata.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// 인스턴스 메타데이터 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InstanceMetadataListResponse {
+    pub instances: Vec<InstanceMetadataResponse>,
+
+    /// 다시 계산한 어노테이션 수 (등록 응답에만 포함)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recomputed_annotations: Option<usize>,
+}
+
+/// 어노테이션 측정값 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AnnotationMeasurementResponse {
+    /// 어노테이션 ID
+    pub annotation_id: i32,
+
+    /// 도형 종류 (인식하지 못한 도구면 없음)
+    pub geometry_type: Option<String>,
+
+    /// 서버에서 계산한 측정값
+    pub measurements: Vec<ComputedMeasurement>,
+
+    /// 계산에 사용한 [행 간격, 열 간격] (mm, 메타데이터가 없으면 없음 - 측정값은 픽셀 단위)
+    pub pixel_spacing: Option<Vec<f64>>,
+
+    /// 픽셀 간격 출처 (INSTANCE, SERIES, NONE)
+    pub spacing_source: String,
+
+    /// 클라이언트 측정값과 허용 오차를 넘게 차이 나는 측정값
+    pub discrepancies: Vec<MeasurementDiscrepancy>,
+
+    pub has_discrepancy: bool,
+
+    /// 계산하지 못한 이유
+    pub error: Option<String>,
+
+    pub computed_at: String,
+}
+
+impl From<AnnotationMeasurement> for AnnotationMeasurementResponse {
+    fn from(measurement: AnnotationMeasurement) -> Self {
+        let pixel_spacing = match (measurement.pixel_spacing_row, measurement.pixel_spacing_column) {
+            (Some(row), Some(column)) => Some(vec![row, column]),
+            _ => None,
+        };
+        Self {
+            annotation_id: measurement.annotation_id,
+            geometry_type: measurement.geometry_type,
+            measurements: serde_json::from_value(measurement.measurements).unwrap_or_default(),
+            pixel_spacing,
+            spacing_source: measurement.spacing_source,
+            discrepancies: serde_json::from_value(measurement.discrepancies).unwrap_or_default(),
+            has_discrepancy: measurement.has_discrepancy,
+            error: measurement.error,
+            computed_at: measurement.computed_at.to_rfc3339(),
+        }
+    }
+}
+
+/// 측정값 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AnnotationMeasurementListResponse {
+    pub measurements: Vec<AnnotationMeasurementResponse>,
+}
+
+/// 측정값 불일치 조회 쿼리
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MeasurementDiscrepancyQuery {
+    /// 최대 개수 (기본값 50, 최대 500)
+    pub limit: Option<i64>,
+}
+
+/// 프로젝트 측정값 재계산 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct RecomputeMeasurementsRequest {
+    /// 계산 결과가 없는 어노테이션만 계산할지 여부 (기본값 true)
+    pub only_missing: Option<bool>,
+}
+
+/// 프로젝트 측정값 재계산 응답 DTO
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct RecomputeMeasurementsResponse {
+    /// 계산한 어노테이션 수
+    pub processed: usize,
+
+    /// 클라이언트 측정값과 차이가 있는 어노테이션 수
+    pub with_discrepancy: usize,
+
+    /// 계산하지 못한 어노테이션 수 (도형을 인식하지 못한 경우 포함)
+    pub failed: usize,
+}
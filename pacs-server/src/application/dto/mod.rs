@@ -4,8 +4,29 @@ pub mod project_dto;
 pub mod permission_dto;
 pub mod access_control_dto;
 pub mod annotation_dto;
+pub mod annotation_bundle_dto;
+pub mod dataset_export_dto;
+pub mod dataset_release_dto;
+pub mod trash_dto;
+pub mod label_dto;
+pub mod worklist_dto;
+pub mod edit_lock_dto;
+pub mod realtime_dto;
+pub mod webhook_dto;
+pub mod measurement_dto;
+pub mod annotation_propagation_dto;
+pub mod multipart_upload_dto;
+pub mod storage_reconciliation_dto;
+pub mod storage_quota_dto;
+pub mod mask_group_archive_dto;
+pub mod mask_validation_dto;
+pub mod mask_preview_dto;
+pub mod mask_group_lineage_dto;
+pub mod annotation_review_dto;
 pub mod mask_group_dto;
 pub mod mask_dto;
+pub mod mask_import_dto;
+pub mod comment_dto;
 pub mod project_user_dto;
 pub mod project_user_matrix_dto;
 pub mod user_project_matrix_dto;
@@ -20,8 +41,29 @@ pub use project_dto::*;
 pub use permission_dto::*;
 pub use access_control_dto::*;
 pub use annotation_dto::*;
+pub use annotation_bundle_dto::*;
+pub use dataset_export_dto::*;
+pub use dataset_release_dto::*;
+pub use trash_dto::*;
+pub use label_dto::*;
+pub use worklist_dto::*;
+pub use edit_lock_dto::*;
+pub use realtime_dto::*;
+pub use webhook_dto::*;
+pub use measurement_dto::*;
+pub use annotation_propagation_dto::*;
+pub use multipart_upload_dto::*;
+pub use storage_reconciliation_dto::*;
+pub use storage_quota_dto::*;
+pub use mask_group_archive_dto::*;
+pub use mask_validation_dto::*;
+pub use mask_preview_dto::*;
+pub use mask_group_lineage_dto::*;
+pub use annotation_review_dto::*;
 pub use mask_group_dto::*;
 pub use mask_dto::*;
+pub use mask_import_dto::*;
+pub use comment_dto::*;
 pub use project_user_dto::*;
 pub use project_user_matrix_dto::*;
 pub use user_project_matrix_dto::*;
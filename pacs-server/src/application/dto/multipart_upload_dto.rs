@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::multipart_upload::{MaskGroupMultipartUpload, MultipartUploadStatus};
+
+/// 멀티파트 업로드 시작 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct InitiateMultipartUploadRequest {
+    /// 파일명 (확장자 포함)
+    #[schema(example = "liver_mask.nii.gz")]
+    pub filename: String,
+
+    /// MIME 타입
+    #[schema(example = "application/gzip")]
+    pub mime_type: Option<String>,
+
+    /// 전체 파일 크기 (bytes)
+    #[schema(example = 734003200)]
+    pub total_size: i64,
+
+    /// 파트 크기 (bytes, 5 MiB ~ 5 GiB, 기본값 16 MiB)
+    #[schema(example = 16777216)]
+    pub part_size: Option<i64>,
+}
+
+/// 파트 업로드 URL 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct MultipartPartUrlsRequest {
+    /// URL을 발급할 파트 번호 (1부터, 생략하면 아직 올리지 않은 모든 파트)
+    pub part_numbers: Option<Vec<i32>>,
+
+    /// URL 유효 시간 (초)
+    #[schema(example = 3600)]
+    pub ttl_seconds: Option<u64>,
+}
+
+/// 완료 요청의 파트 DTO
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CompletedPartRequest {
+    pub part_number: i32,
+    /// 파트 업로드 응답의 ETag 헤더
+    pub etag: String,
+}
+
+/// 멀티파트 업로드 완료 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct CompleteMultipartUploadRequest {
+    /// 조립할 파트 (생략하면 스토리지에 업로드된 파트를 사용)
+    pub parts: Option<Vec<CompletedPartRequest>>,
+}
+
+/// 멀티파트 업로드 세션 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MultipartUploadResponse {
+    pub id: i32,
+    pub mask_group_id: i32,
+    pub file_path: String,
+    pub content_type: Option<String>,
+    pub part_size: i64,
+    pub total_size: i64,
+    pub part_count: i32,
+    pub status: MultipartUploadStatus,
+    pub created_by: Option<i32>,
+    pub created_at: String,
+    /// 이 시각까지 완료되지 않으면 업로드가 중단됨
+    pub expires_at: String,
+    pub completed_at: Option<String>,
+    /// 업로드 시작 시 프로젝트 또는 사용자 사용량이 경고 한도 이상이면 포함
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quota_warning: Option<String>,
+}
+
+impl From<MaskGroupMultipartUpload> for MultipartUploadResponse {
+    fn from(upload: MaskGroupMultipartUpload) -> Self {
+        Self {
+            id: upload.id,
+            mask_group_id: upload.mask_group_id,
+            file_path: upload.file_path,
+            content_type: upload.content_type,
+            part_size: upload.part_size,
+            total_size: upload.total_size,
+            part_count: upload.part_count,
+            status: upload.status,
+            created_by: upload.created_by,
+            created_at: upload.created_at.to_rfc3339(),
+            expires_at: upload.expires_at.to_rfc3339(),
+            completed_at: upload.completed_at.map(|at| at.to_rfc3339()),
+            quota_warning: None,
+        }
+    }
+}
+
+/// 업로드된 파트 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UploadedPartResponse {
+    pub part_number: i32,
+    pub etag: String,
+    pub size: i64,
+}
+
+/// 멀티파트 업로드 진행 상태 DTO (이어받기용)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MultipartUploadDetailResponse {
+    pub upload: MultipartUploadResponse,
+    /// 스토리지에 업로드된 파트 (진행 중일 때만)
+    pub uploaded_parts: Vec<UploadedPartResponse>,
+    /// 아직 올리지 않은 파트 번호
+    pub missing_parts: Vec<i32>,
+    pub uploaded_bytes: i64,
+}
+
+/// 파트 업로드 URL DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MultipartPartUrlResponse {
+    pub part_number: i32,
+    /// 이 URL로 파트를 PUT하고 응답의 ETag를 보관
+    pub upload_url: String,
+    /// 파트 크기 (bytes)
+    pub size: i64,
+}
+
+/// 파트 업로드 URL 목록 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MultipartPartUrlsResponse {
+    pub upload_id: i32,
+    pub parts: Vec<MultipartPartUrlResponse>,
+    pub expires_in: u64,
+    pub expires_at: String,
+}
+
+/// 멀티파트 업로드 세션 목록 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MultipartUploadListResponse {
+    pub uploads: Vec<MultipartUploadResponse>,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+/// 실시간 이벤트 구독 쿼리
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RealtimeEventQuery {
+    /// 지정하면 이 Study의 이벤트만 받음
+    pub study_uid: Option<String>,
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::storage_quota::{StorageQuota, StorageQuotaLevel, StorageUsage};
+
+/// 저장 용량 한도 설정 요청 DTO (null이면 해당 한도 없음)
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct UpdateStorageQuotaRequest {
+    /// 경고 한도 (바이트, 넘으면 업로드는 허용하고 STORAGE_QUOTA_THRESHOLD_REACHED 이벤트 기록)
+    #[schema(example = 858993459200_i64)]
+    pub soft_limit_bytes: Option<i64>,
+
+    /// 업로드 한도 (바이트, 넘는 업로드 URL은 발급하지 않음)
+    #[schema(example = 1099511627776_i64)]
+    pub hard_limit_bytes: Option<i64>,
+}
+
+/// 저장 용량 한도 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StorageQuotaResponse {
+    pub soft_limit_bytes: Option<i64>,
+    pub hard_limit_bytes: Option<i64>,
+    /// 경고 한도 이벤트를 기록한 시각 (사용량이 경고 한도 아래로 내려가면 null)
+    pub alerted_at: Option<String>,
+    pub updated_by: Option<i32>,
+    pub updated_at: String,
+}
+
+impl From<StorageQuota> for StorageQuotaResponse {
+    fn from(quota: StorageQuota) -> Self {
+        Self {
+            soft_limit_bytes: quota.soft_limit_bytes,
+            hard_limit_bytes: quota.hard_limit_bytes,
+            alerted_at: quota.alerted_at.map(|at| at.to_rfc3339()),
+            updated_by: quota.updated_by,
+            updated_at: quota.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// (프로젝트, 사용자)별 사용량 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StorageUsageEntryResponse {
+    pub project_id: i32,
+    pub user_id: i32,
+    pub bytes_used: i64,
+    pub object_count: i64,
+    pub updated_at: String,
+}
+
+impl From<StorageUsage> for StorageUsageEntryResponse {
+    fn from(usage: StorageUsage) -> Self {
+        Self {
+            project_id: usage.project_id,
+            user_id: usage.user_id,
+            bytes_used: usage.bytes_used,
+            object_count: usage.object_count,
+            updated_at: usage.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// 프로젝트 저장 용량 사용량 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProjectStorageUsageResponse {
+    pub project_id: i32,
+    pub bytes_used: i64,
+    pub object_count: i64,
+    /// 한도 (null이면 제한 없음)
+    pub quota: Option<StorageQuotaResponse>,
+    pub quota_level: StorageQuotaLevel,
+    /// 사용자별 사용량 (사용량이 큰 순서)
+    pub users: Vec<StorageUsageEntryResponse>,
+}
+
+/// 사용자 저장 용량 사용량 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserStorageUsageResponse {
+    pub user_id: i32,
+    pub bytes_used: i64,
+    pub object_count: i64,
+    /// 한도 (null이면 제한 없음)
+    pub quota: Option<StorageQuotaResponse>,
+    pub quota_level: StorageQuotaLevel,
+    /// 프로젝트별 사용량 (사용량이 큰 순서)
+    pub projects: Vec<StorageUsageEntryResponse>,
+}
+
+/// 사용량 재계산 결과 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StorageUsageRecalculationResponse {
+    /// 다시 계산한 (프로젝트, 사용자) 수
+    pub usage_rows: u64,
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::storage_reconciliation::{
+    StorageOrphanAction, StorageReconciliationFinding, StorageReconciliationFindingKind, StorageReconciliationRun,
+    StorageReconciliationStatus,
+};
+
+/// 정합성 점검 실행 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct StartStorageReconciliationRequest {
+    /// 유예 기간이 지난 고아 객체 처리 방식 (기본값 REPORT)
+    pub orphan_action: Option<StorageOrphanAction>,
+
+    /// 이 시간보다 최근의 객체/행은 업로드 중일 수 있어 제외 (시간, 기본값 24)
+    #[schema(example = 24)]
+    pub grace_period_hours: Option<i32>,
+}
+
+/// 발견한 문제 조회 쿼리
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StorageReconciliationReportQuery {
+    /// 반환할 최대 문제 수 (기본값 1000)
+    pub limit: Option<i64>,
+}
+
+/// 정합성 점검 실행 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StorageReconciliationRunResponse {
+    pub id: i32,
+    pub status: StorageReconciliationStatus,
+    pub orphan_action: StorageOrphanAction,
+    pub grace_period_hours: i32,
+    /// 관리자 API로 실행한 사용자 (null이면 정기 실행)
+    pub triggered_by: Option<i32>,
+    pub objects_scanned: i64,
+    pub rows_scanned: i64,
+    /// 유예 기간 안이라 제외한 객체/행 수
+    pub recent_skipped: i64,
+    pub orphan_objects: i64,
+    pub dangling_rows: i64,
+    pub size_mismatches: i64,
+    pub checksum_mismatches: i64,
+    pub orphans_quarantined: i64,
+    pub orphans_queued_for_deletion: i64,
+    pub error: Option<String>,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+}
+
+impl From<StorageReconciliationRun> for StorageReconciliationRunResponse {
+    fn from(run: StorageReconciliationRun) -> Self {
+        Self {
+            id: run.id,
+            status: run.status,
+            orphan_action: run.orphan_action,
+            grace_period_hours: run.grace_period_hours,
+            triggered_by: run.triggered_by,
+            objects_scanned: run.objects_scanned,
+            rows_scanned: run.rows_scanned,
+            recent_skipped: run.recent_skipped,
+            orphan_objects: run.orphan_objects,
+            dangling_rows: run.dangling_rows,
+            size_mismatches: run.size_mismatches,
+            checksum_mismatches: run.checksum_mismatches,
+            orphans_quarantined: run.orphans_quarantined,
+            orphans_queued_for_deletion: run.orphans_queued_for_deletion,
+            error: run.error,
+            started_at: run.started_at.to_rfc3339(),
+            finished_at: run.finished_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+/// 발견한 문제 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StorageReconciliationFindingResponse {
+    pub kind: StorageReconciliationFindingKind,
+    pub object_key: String,
+    pub mask_id: Option<i32>,
+    /// 행에 기록된 값 (크기/체크섬 불일치)
+    pub expected: Option<String>,
+    /// 스토리지의 값 (고아 객체는 크기)
+    pub actual: Option<String>,
+    /// 고아 객체에 적용한 처리
+    pub action: StorageOrphanAction,
+    pub quarantine_key: Option<String>,
+}
+
+impl From<StorageReconciliationFinding> for StorageReconciliationFindingResponse {
+    fn from(finding: StorageReconciliationFinding) -> Self {
+        Self {
+            kind: finding.kind,
+            object_key: finding.object_key,
+            mask_id: finding.mask_id,
+            expected: finding.expected,
+            actual: finding.actual,
+            action: finding.action,
+            quarantine_key: finding.quarantine_key,
+        }
+    }
+}
+
+/// 정합성 점검 보고서 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StorageReconciliationReportResponse {
+    pub run: StorageReconciliationRunResponse,
+    /// 기록된 문제 (실행당 기록 수 상한이 있어 집계보다 적을 수 있음)
+    pub findings: Vec<StorageReconciliationFindingResponse>,
+}
+
+/// 정합성 점검 실행 목록 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StorageReconciliationRunListResponse {
+    pub runs: Vec<StorageReconciliationRunResponse>,
+}
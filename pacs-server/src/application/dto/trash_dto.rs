@@ -0,0 +1,106 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+use crate::domain::entities::trash::{purge_after, TrashedAnnotation, TrashedMaskGroup};
+
+/// 휴지통 어노테이션 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashedAnnotationResponse {
+    /// 어노테이션 ID
+    pub id: i32,
+
+    /// 작성자 ID
+    pub user_id: i32,
+
+    /// Study Instance UID
+    pub study_uid: String,
+
+    /// Series Instance UID
+    pub series_uid: Option<String>,
+
+    /// 도구 이름
+    pub tool_name: String,
+
+    /// 함께 휴지통으로 이동한 마스크 그룹 수
+    pub mask_group_count: i64,
+
+    /// 휴지통으로 이동한 시간
+    pub deleted_at: String,
+
+    /// 휴지통으로 이동한 사용자 ID
+    pub deleted_by: Option<i32>,
+
+    /// 영구 삭제 예정 시간
+    pub purge_after: String,
+}
+
+impl TrashedAnnotationResponse {
+    pub fn new(annotation: TrashedAnnotation, retention_days: i64) -> Self {
+        Self {
+            id: annotation.id,
+            user_id: annotation.user_id,
+            study_uid: annotation.study_uid,
+            series_uid: annotation.series_uid,
+            tool_name: annotation.tool_name,
+            mask_group_count: annotation.mask_group_count,
+            deleted_at: annotation.deleted_at.to_rfc3339(),
+            deleted_by: annotation.deleted_by,
+            purge_after: purge_after(annotation.deleted_at, retention_days).to_rfc3339(),
+        }
+    }
+}
+
+/// 휴지통 마스크 그룹 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashedMaskGroupResponse {
+    /// 마스크 그룹 ID
+    pub id: i32,
+
+    /// 어노테이션 ID
+    pub annotation_id: i32,
+
+    /// 그룹 이름
+    pub group_name: Option<String>,
+
+    /// AI 모델 이름
+    pub model_name: Option<String>,
+
+    /// 그룹에 포함된 마스크 수
+    pub mask_count: i64,
+
+    /// 휴지통으로 이동한 시간
+    pub deleted_at: String,
+
+    /// 휴지통으로 이동한 사용자 ID
+    pub deleted_by: Option<i32>,
+
+    /// 영구 삭제 예정 시간
+    pub purge_after: String,
+}
+
+impl TrashedMaskGroupResponse {
+    pub fn new(mask_group: TrashedMaskGroup, retention_days: i64) -> Self {
+        Self {
+            id: mask_group.id,
+            annotation_id: mask_group.annotation_id,
+            group_name: mask_group.group_name,
+            model_name: mask_group.model_name,
+            mask_count: mask_group.mask_count,
+            deleted_at: mask_group.deleted_at.to_rfc3339(),
+            deleted_by: mask_group.deleted_by,
+            purge_after: purge_after(mask_group.deleted_at, retention_days).to_rfc3339(),
+        }
+    }
+}
+
+/// 프로젝트 휴지통 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashListResponse {
+    /// 보관 기간 (일)
+    pub retention_days: i64,
+
+    /// 휴지통의 어노테이션 (최근 삭제순)
+    pub annotations: Vec<TrashedAnnotationResponse>,
+
+    /// 휴지통의 마스크 그룹 (어노테이션은 활성 상태인 것만, 최근 삭제순)
+    pub mask_groups: Vec<TrashedMaskGroupResponse>,
+}
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::webhook::{
+    WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus, WebhookEventType, WebhookSubscription,
+};
+
+/// Webhook 생성 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct CreateWebhookRequest {
+    /// 이름
+    #[schema(example = "Annotation pipeline")]
+    pub name: String,
+
+    /// 이벤트를 받을 URL (http/https)
+    #[schema(example = "https://example.com/hooks/pacs")]
+    pub url: String,
+
+    /// 받을 이벤트 종류 (비우거나 생략하면 모든 이벤트)
+    pub event_types: Option<Vec<WebhookEventType>>,
+}
+
+/// Webhook 수정 요청 DTO (생략한 필드는 유지)
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct UpdateWebhookRequest {
+    pub name: Option<String>,
+
+    pub url: Option<String>,
+
+    /// 받을 이벤트 종류 (빈 목록이면 모든 이벤트)
+    pub event_types: Option<Vec<WebhookEventType>>,
+
+    /// 비활성화하면 새 이벤트를 받지 않고 대기 중인 전송도 보류됨
+    pub is_active: Option<bool>,
+}
+
+/// Webhook 전송 목록 조회 쿼리
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WebhookDeliveryQuery {
+    /// Webhook ID
+    pub webhook_id: Option<i32>,
+    /// 전송 상태
+    pub status: Option<WebhookDeliveryStatus>,
+    /// 최대 개수 (기본값 50, 최대 500)
+    pub limit: Option<i64>,
+}
+
+/// Webhook 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookResponse {
+    /// Webhook ID
+    pub id: i32,
+
+    /// 프로젝트 ID
+    pub project_id: i32,
+
+    pub name: String,
+
+    pub url: String,
+
+    /// 받을 이벤트 종류 (비어 있으면 모든 이벤트)
+    pub event_types: Vec<String>,
+
+    pub is_active: bool,
+
+    /// 서명 키 (생성 응답에만 포함)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+
+    /// 생성한 사용자 ID
+    pub created_by: Option<i32>,
+
+    pub created_at: String,
+
+    pub updated_at: String,
+}
+
+impl From<WebhookSubscription> for WebhookResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id,
+            project_id: subscription.project_id,
+            name: subscription.name,
+            url: subscription.url,
+            event_types: subscription.event_types,
+            is_active: subscription.is_active,
+            secret: None,
+            created_by: subscription.created_by,
+            created_at: subscription.created_at.to_rfc3339(),
+            updated_at: subscription.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Webhook 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookListResponse {
+    pub webhooks: Vec<WebhookResponse>,
+}
+
+/// Webhook 전송 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    /// 전송 ID (`X-PACS-Delivery` 헤더 값)
+    pub id: i64,
+
+    /// Webhook ID
+    pub webhook_id: i32,
+
+    /// 이벤트 ID (페이로드의 `id`)
+    pub event_id: i64,
+
+    pub event_type: String,
+
+    pub status: WebhookDeliveryStatus,
+
+    /// 시도 횟수
+    pub attempt_count: i32,
+
+    /// 다음 시도 시간 (대기 중인 전송만 의미 있음)
+    pub next_attempt_at: String,
+
+    /// 마지막 응답 상태 코드
+    pub last_status_code: Option<i32>,
+
+    /// 마지막 오류
+    pub last_error: Option<String>,
+
+    pub created_at: String,
+
+    pub updated_at: String,
+
+    /// 전송 성공 시간
+    pub delivered_at: Option<String>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            webhook_id: delivery.subscription_id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            status: delivery.status,
+            attempt_count: delivery.attempt_count,
+            next_attempt_at: delivery.next_attempt_at.to_rfc3339(),
+            last_status_code: delivery.last_status_code,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at.to_rfc3339(),
+            updated_at: delivery.updated_at.to_rfc3339(),
+            delivered_at: delivery.delivered_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+/// Webhook 전송 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryListResponse {
+    pub deliveries: Vec<WebhookDeliveryResponse>,
+}
+
+/// Webhook 전송 시도 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryAttemptResponse {
+    /// 시도 번호
+    pub attempt_number: i32,
+
+    /// 응답 상태 코드 (연결 실패/시간 초과면 없음)
+    pub status_code: Option<i32>,
+
+    /// 오류 (성공하면 없음)
+    pub error: Option<String>,
+
+    /// 소요 시간 (ms)
+    pub duration_ms: i32,
+
+    pub attempted_at: String,
+}
+
+impl From<WebhookDeliveryAttempt> for WebhookDeliveryAttemptResponse {
+    fn from(attempt: WebhookDeliveryAttempt) -> Self {
+        Self {
+            attempt_number: attempt.attempt_number,
+            status_code: attempt.status_code,
+            error: attempt.error,
+            duration_ms: attempt.duration_ms,
+            attempted_at: attempt.attempted_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Webhook 전송 로그 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryLogResponse {
+    pub delivery: WebhookDeliveryResponse,
+    pub attempts: Vec<WebhookDeliveryAttemptResponse>,
+}
@@ -0,0 +1,250 @@
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::entities::worklist::{WorklistProgress, WorklistTask, WorklistTaskResults, WorklistTaskStatus};
+
+/// 작업 생성 요청 DTO
+///
+/// `study_ids`와 필터 조건은 모두 AND로 결합되며, 아무 조건도 없으면 프로젝트의 모든 Study가 대상입니다.
+/// 이미 작업이 있는 Study는 건너뜁니다.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct CreateWorklistTasksRequest {
+    /// 직접 지정한 프로젝트 데이터 Study ID
+    pub study_ids: Option<Vec<i32>>,
+
+    /// Study 날짜 범위 (시작)
+    #[schema(value_type = Option<String>, example = "2025-01-01")]
+    pub study_date_from: Option<NaiveDate>,
+
+    /// Study 날짜 범위 (종료)
+    #[schema(value_type = Option<String>, example = "2025-12-31")]
+    pub study_date_to: Option<NaiveDate>,
+
+    /// Series Modality
+    #[schema(example = "CT")]
+    pub modality: Option<String>,
+
+    /// Study 설명 부분 일치
+    #[schema(example = "chest")]
+    pub study_description: Option<String>,
+
+    /// 환자 ID
+    pub patient_id: Option<String>,
+
+    /// 작업을 순서대로 돌아가며 배정할 판독자 ID (비어 있으면 배정하지 않음)
+    #[serde(default)]
+    pub assignee_ids: Vec<i32>,
+
+    /// 우선순위 (높을수록 먼저, 기본값 0)
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// 작업 목록 조회 쿼리
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WorklistTaskListQuery {
+    /// 상태 (TODO, IN_PROGRESS, DONE, SKIPPED)
+    pub status: Option<String>,
+    /// 담당자 ID
+    pub assignee_id: Option<i32>,
+    /// 배정되지 않은 작업만
+    pub unassigned: Option<bool>,
+    /// 페이지 번호 (기본값: 1)
+    pub page: Option<i64>,
+    /// 페이지 크기 (기본값: 50, 최대 500)
+    pub page_size: Option<i64>,
+}
+
+/// 담당자 변경 요청 DTO
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AssignWorklistTaskRequest {
+    /// 담당자 ID (null이면 배정 해제)
+    pub assignee_id: Option<i32>,
+}
+
+/// 작업 완료 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct CompleteWorklistTaskRequest {
+    /// 작업 결과 어노테이션 ID (작업 Study의 어노테이션)
+    #[serde(default)]
+    pub annotation_ids: Vec<i32>,
+
+    /// 작업 결과 마스크 그룹 ID (작업 Study의 마스크 그룹)
+    #[serde(default)]
+    pub mask_group_ids: Vec<i32>,
+
+    /// 메모
+    pub note: Option<String>,
+}
+
+/// 작업 건너뛰기 요청 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct SkipWorklistTaskRequest {
+    /// 건너뛴 이유
+    #[schema(example = "Image quality too low")]
+    pub note: Option<String>,
+}
+
+/// 작업 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorklistTaskResponse {
+    /// 작업 ID
+    pub id: i32,
+
+    /// 프로젝트 ID
+    pub project_id: i32,
+
+    /// 프로젝트 데이터 Study ID
+    pub study_id: i32,
+
+    /// Study Instance UID
+    pub study_uid: String,
+
+    /// Study 설명
+    pub study_description: Option<String>,
+
+    /// 환자 ID
+    pub patient_id: Option<String>,
+
+    /// Study 날짜
+    pub study_date: Option<String>,
+
+    /// 담당자 ID
+    pub assignee_id: Option<i32>,
+
+    /// 상태
+    pub status: WorklistTaskStatus,
+
+    /// 우선순위
+    pub priority: i32,
+
+    /// 완료/건너뛰기 메모
+    pub note: Option<String>,
+
+    /// 진행 중인 구간을 포함한 총 소요 시간 (초)
+    pub time_spent_seconds: i64,
+
+    /// 현재 작업 구간 시작 시간
+    pub started_at: Option<String>,
+
+    /// 완료 또는 건너뛴 시간
+    pub completed_at: Option<String>,
+
+    /// 결과 어노테이션 ID
+    pub annotation_ids: Vec<i32>,
+
+    /// 결과 마스크 그룹 ID
+    pub mask_group_ids: Vec<i32>,
+
+    /// 생성 시간
+    pub created_at: String,
+
+    /// 수정 시간
+    pub updated_at: String,
+}
+
+impl WorklistTaskResponse {
+    pub fn new(task: WorklistTask, results: WorklistTaskResults) -> Self {
+        Self {
+            time_spent_seconds: task.elapsed_seconds(Utc::now()),
+            id: task.id,
+            project_id: task.project_id,
+            study_id: task.study_id,
+            study_uid: task.study_uid,
+            study_description: task.study_description,
+            patient_id: task.patient_id,
+            study_date: task.study_date.map(|d| d.to_string()),
+            assignee_id: task.assignee_id,
+            status: task.status,
+            priority: task.priority,
+            note: task.note,
+            started_at: task.started_at.map(|t| t.to_rfc3339()),
+            completed_at: task.completed_at.map(|t| t.to_rfc3339()),
+            annotation_ids: results.annotation_ids,
+            mask_group_ids: results.mask_group_ids,
+            created_at: task.created_at.to_rfc3339(),
+            updated_at: task.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<WorklistTask> for WorklistTaskResponse {
+    fn from(task: WorklistTask) -> Self {
+        Self::new(task, WorklistTaskResults::default())
+    }
+}
+
+/// 작업 생성 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateWorklistTasksResponse {
+    /// 생성된 작업
+    pub tasks: Vec<WorklistTaskResponse>,
+
+    /// 직접 지정했지만 프로젝트에 없거나 이미 작업이 있어 건너뛴 Study ID
+    pub skipped_study_ids: Vec<i32>,
+}
+
+/// 작업 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorklistTaskListResponse {
+    /// 작업 (우선순위 높은 순)
+    pub tasks: Vec<WorklistTaskResponse>,
+
+    /// 페이지 번호
+    pub page: i64,
+
+    /// 페이지 크기
+    pub page_size: i64,
+}
+
+/// 작업 진행 현황 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorklistProgressResponse {
+    /// 담당자 ID (null이면 배정되지 않은 작업)
+    pub assignee_id: Option<i32>,
+
+    /// 대기 중
+    pub todo: i64,
+
+    /// 작업 중
+    pub in_progress: i64,
+
+    /// 완료
+    pub done: i64,
+
+    /// 건너뜀
+    pub skipped: i64,
+
+    /// 전체 작업 수
+    pub total: i64,
+
+    /// 진행 중인 구간을 포함한 총 소요 시간 (초)
+    pub time_spent_seconds: i64,
+}
+
+impl From<WorklistProgress> for WorklistProgressResponse {
+    fn from(progress: WorklistProgress) -> Self {
+        Self {
+            total: progress.total(),
+            assignee_id: progress.assignee_id,
+            todo: progress.todo,
+            in_progress: progress.in_progress,
+            done: progress.done,
+            skipped: progress.skipped,
+            time_spent_seconds: progress.time_spent_seconds,
+        }
+    }
+}
+
+/// 프로젝트 작업 진행 현황 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorklistProjectProgressResponse {
+    /// 프로젝트 ID
+    pub project_id: i32,
+
+    /// 프로젝트 전체 합계 (`assignee_id`는 null)
+    pub project: WorklistProgressResponse,
+
+    /// 담당자별 현황 (배정되지 않은 작업은 `assignee_id`가 null인 항목)
+    pub users: Vec<WorklistProgressResponse>,
+}
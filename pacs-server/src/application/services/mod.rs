@@ -2,9 +2,9 @@ pub mod object_storage_service;
 pub mod signed_url_service;
 
 pub use object_storage_service::{
-    ObjectStorageService, ObjectStorageError, UploadedFile, SignedUrlOptions,
+    ObjectStorageService, ObjectStorageError, UploadedFile, SignedUrlOptions, UploadedPart, CompletedPart,
     ObjectStorageServiceFactory, ObjectStorageServiceBuilder,
 };
 pub use signed_url_service::{
-    SignedUrlService, SignedUrlError, SignedUrlRequest, SignedUrlResponse, SignedUrlServiceImpl,
+    SignedUrlService, SignedUrlError, SignedUrlRequest, SignedUrlResponse, SignedUrlServiceImpl, MaskUploadFile,
 };
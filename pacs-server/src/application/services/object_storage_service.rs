@@ -10,6 +10,9 @@ pub enum ObjectStorageError {
     #[error("MinIO operation failed: {0}")]
     MinIOError(String),
     
+    #[error("Local storage operation failed: {0}")]
+    LocalStorageError(String),
+    
     #[error("Configuration error: {0}")]
     ConfigError(String),
     
@@ -65,6 +68,30 @@ impl Default for SignedUrlOptions {
     }
 }
 
+/// 멀티파트 업로드의 파트 크기 하한 (마지막 파트 제외, S3 규격)
+pub const MIN_MULTIPART_PART_SIZE: i64 = 5 * 1024 * 1024;
+
+/// 멀티파트 업로드의 파트 크기 상한 (S3 규격)
+pub const MAX_MULTIPART_PART_SIZE: i64 = 5 * 1024 * 1024 * 1024;
+
+/// 멀티파트 업로드 하나의 최대 파트 수 (S3 규격)
+pub const MAX_MULTIPART_PARTS: i32 = 10_000;
+
+/// 스토리지에 업로드된 멀티파트 업로드의 파트
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadedPart {
+    pub part_number: i32,
+    pub etag: String,
+    pub size: i64,
+}
+
+/// 멀티파트 업로드 완료 시 조립할 파트
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletedPart {
+    pub part_number: i32,
+    pub etag: String,
+}
+
 /// Object Storage 서비스 trait
 #[async_trait]
 pub trait ObjectStorageService: Send + Sync {
@@ -101,6 +128,24 @@ pub trait ObjectStorageService: Send + Sync {
         max_keys: Option<i32>,
     ) -> Result<Vec<String>, ObjectStorageError>;
     
+    /// `start_after` 다음 키부터 파일 목록 조회 (키 순서, prefix 전체를 페이지로 나누어 조회할 때 사용)
+    ///
+    /// 기본 구현은 `list_files` 결과를 거르므로, 한 번에 모두 나열할 수 없는 스토리지는 재정의해야 합니다.
+    async fn list_files_after(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        max_keys: Option<i32>,
+    ) -> Result<Vec<String>, ObjectStorageError> {
+        let mut files = self.list_files(prefix, None).await?;
+        files.sort();
+        if let Some(start_after) = start_after {
+            files.retain(|key| key.as_str() > start_after);
+        }
+        files.truncate(max_keys.unwrap_or(1000).max(0) as usize);
+        Ok(files)
+    }
+    
     /// 파일 복사
     async fn copy_file(
         &self,
@@ -114,6 +159,182 @@ pub trait ObjectStorageService: Send + Sync {
         source_path: &str,
         destination_path: &str,
     ) -> Result<(), ObjectStorageError>;
+
+    /// 서버 측에서 생성한 파일을 직접 업로드
+    async fn upload_file(
+        &self,
+        file_path: &str,
+        data: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<(), ObjectStorageError>;
+
+    /// 파일 전체 내용을 다운로드
+    async fn download_file(&self, file_path: &str) -> Result<Vec<u8>, ObjectStorageError>;
+
+    /// 멀티파트 업로드 시작 (업로드 ID 반환)
+    async fn initiate_multipart_upload(
+        &self,
+        file_path: &str,
+        content_type: Option<&str>,
+    ) -> Result<String, ObjectStorageError>;
+
+    /// 파트 업로드용 Signed URL 생성
+    async fn generate_upload_part_url(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        part_number: i32,
+        ttl_seconds: u64,
+    ) -> Result<String, ObjectStorageError>;
+
+    /// 지금까지 업로드된 파트 목록 (파트 번호 순)
+    async fn list_uploaded_parts(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<Vec<UploadedPart>, ObjectStorageError>;
+
+    /// 업로드된 파트를 하나의 객체로 조립
+    async fn complete_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        parts: &[CompletedPart],
+    ) -> Result<(), ObjectStorageError>;
+
+    /// 멀티파트 업로드를 중단하고 업로드된 파트를 삭제
+    async fn abort_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<(), ObjectStorageError>;
+}
+
+/// 여러 서비스가 하나의 스토리지 인스턴스를 공유할 수 있도록 `Arc`에 위임
+#[async_trait]
+impl<T> ObjectStorageService for std::sync::Arc<T>
+where
+    T: ObjectStorageService + ?Sized,
+{
+    async fn generate_upload_url(
+        &self,
+        file_path: &str,
+        options: SignedUrlOptions,
+    ) -> Result<String, ObjectStorageError> {
+        (**self).generate_upload_url(file_path, options).await
+    }
+
+    async fn generate_download_url(
+        &self,
+        file_path: &str,
+        ttl_seconds: u64,
+    ) -> Result<String, ObjectStorageError> {
+        (**self).generate_download_url(file_path, ttl_seconds).await
+    }
+
+    async fn delete_file(&self, file_path: &str) -> Result<(), ObjectStorageError> {
+        (**self).delete_file(file_path).await
+    }
+
+    async fn get_file_metadata(
+        &self,
+        file_path: &str,
+    ) -> Result<UploadedFile, ObjectStorageError> {
+        (**self).get_file_metadata(file_path).await
+    }
+
+    async fn file_exists(&self, file_path: &str) -> Result<bool, ObjectStorageError> {
+        (**self).file_exists(file_path).await
+    }
+
+    async fn list_files(
+        &self,
+        prefix: &str,
+        max_keys: Option<i32>,
+    ) -> Result<Vec<String>, ObjectStorageError> {
+        (**self).list_files(prefix, max_keys).await
+    }
+
+    async fn list_files_after(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        max_keys: Option<i32>,
+    ) -> Result<Vec<String>, ObjectStorageError> {
+        (**self).list_files_after(prefix, start_after, max_keys).await
+    }
+
+    async fn copy_file(
+        &self,
+        source_path: &str,
+        destination_path: &str,
+    ) -> Result<(), ObjectStorageError> {
+        (**self).copy_file(source_path, destination_path).await
+    }
+
+    async fn move_file(
+        &self,
+        source_path: &str,
+        destination_path: &str,
+    ) -> Result<(), ObjectStorageError> {
+        (**self).move_file(source_path, destination_path).await
+    }
+
+    async fn upload_file(
+        &self,
+        file_path: &str,
+        data: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<(), ObjectStorageError> {
+        (**self).upload_file(file_path, data, content_type).await
+    }
+
+    async fn download_file(&self, file_path: &str) -> Result<Vec<u8>, ObjectStorageError> {
+        (**self).download_file(file_path).await
+    }
+
+    async fn initiate_multipart_upload(
+        &self,
+        file_path: &str,
+        content_type: Option<&str>,
+    ) -> Result<String, ObjectStorageError> {
+        (**self).initiate_multipart_upload(file_path, content_type).await
+    }
+
+    async fn generate_upload_part_url(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        part_number: i32,
+        ttl_seconds: u64,
+    ) -> Result<String, ObjectStorageError> {
+        (**self).generate_upload_part_url(file_path, upload_id, part_number, ttl_seconds).await
+    }
+
+    async fn list_uploaded_parts(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<Vec<UploadedPart>, ObjectStorageError> {
+        (**self).list_uploaded_parts(file_path, upload_id).await
+    }
+
+    async fn complete_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        parts: &[CompletedPart],
+    ) -> Result<(), ObjectStorageError> {
+        (**self).complete_multipart_upload(file_path, upload_id, parts).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<(), ObjectStorageError> {
+        (**self).abort_multipart_upload(file_path, upload_id).await
+    }
 }
 
 /// Object Storage 서비스 팩토리
@@ -139,8 +360,30 @@ impl ObjectStorageServiceFactory {
                 ).await?;
                 Ok(Box::new(s3_service))
             }
+            // S3 호환 MinIO: endpoint는 MinIO 서버 주소, 버킷이 없으면 생성
+            "minio" => {
+                let minio_service = crate::infrastructure::external::MinIOObjectStorageService::new(
+                    bucket_name,
+                    region,
+                    endpoint,
+                    access_key,
+                    secret_key,
+                ).await?;
+                minio_service.ensure_bucket_exists().await?;
+                Ok(Box::new(minio_service))
+            }
+            // 로컬 디스크: bucket_name은 루트 디렉터리, endpoint는 이 서버의 공개 주소,
+            // secret_key는 Signed URL 서명 키로 사용
+            "local" => {
+                let local_service = crate::infrastructure::external::LocalObjectStorageService::new(
+                    bucket_name,
+                    endpoint,
+                    secret_key,
+                ).await?;
+                Ok(Box::new(local_service))
+            }
             _ => Err(ObjectStorageError::ConfigError(
-                format!("Unsupported object storage provider: {}. Only 's3' is supported.", provider)
+                format!("Unsupported object storage provider: {}. Supported providers are 's3', 'minio' and 'local'.", provider)
             ))
         }
     }
@@ -165,6 +165,13 @@ impl SignedUrlResponse {
     }
 }
 
+/// 일괄 발급할 마스크 업로드 파일
+#[derive(Debug, Clone)]
+pub struct MaskUploadFile {
+    pub file_name: String,
+    pub content_type: String,
+}
+
 /// Signed URL 서비스 trait
 #[async_trait]
 pub trait SignedUrlService: Send + Sync {
@@ -198,6 +205,38 @@ pub trait SignedUrlService: Send + Sync {
         ttl_seconds: Option<u64>,
     ) -> Result<SignedUrlResponse, SignedUrlError>;
     
+    /// 마스크 업로드용 Signed URL 일괄 생성 (요청 순서대로 반환)
+    async fn generate_mask_upload_urls(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        files: Vec<MaskUploadFile>,
+        ttl_seconds: Option<u64>,
+        user_id: Option<i32>,
+    ) -> Result<Vec<SignedUrlResponse>, SignedUrlError> {
+        let mut responses = Vec::with_capacity(files.len());
+        for file in files {
+            responses.push(
+                self.generate_mask_upload_url(annotation_id, mask_group_id, file.file_name, file.content_type, ttl_seconds, user_id)
+                    .await?,
+            );
+        }
+        Ok(responses)
+    }
+    
+    /// 마스크 다운로드용 Signed URL 일괄 생성 (요청 순서대로 반환)
+    async fn generate_mask_download_urls(
+        &self,
+        file_paths: Vec<String>,
+        ttl_seconds: Option<u64>,
+    ) -> Result<Vec<SignedUrlResponse>, SignedUrlError> {
+        let mut responses = Vec::with_capacity(file_paths.len());
+        for file_path in file_paths {
+            responses.push(self.generate_mask_download_url(file_path, ttl_seconds).await?);
+        }
+        Ok(responses)
+    }
+    
     /// 어노테이션 데이터 업로드용 Signed URL 생성
     async fn generate_annotation_upload_url(
         &self,
@@ -382,6 +421,50 @@ impl SignedUrlService for SignedUrlServiceImpl {
         self.generate_download_url(request).await
     }
     
+    async fn generate_mask_upload_urls(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        files: Vec<MaskUploadFile>,
+        ttl_seconds: Option<u64>,
+        user_id: Option<i32>,
+    ) -> Result<Vec<SignedUrlResponse>, SignedUrlError> {
+        // 일부 URL만 발급되지 않도록 TTL과 경로를 먼저 모두 검증
+        let ttl_seconds = ttl_seconds.unwrap_or(self.default_ttl);
+        self.validate_ttl(ttl_seconds)?;
+        for file in &files {
+            self.validate_file_path(&file.file_name)?;
+        }
+        
+        let mut responses = Vec::with_capacity(files.len());
+        for file in files {
+            responses.push(
+                self.generate_mask_upload_url(annotation_id, mask_group_id, file.file_name, file.content_type, Some(ttl_seconds), user_id)
+                    .await?,
+            );
+        }
+        Ok(responses)
+    }
+    
+    async fn generate_mask_download_urls(
+        &self,
+        file_paths: Vec<String>,
+        ttl_seconds: Option<u64>,
+    ) -> Result<Vec<SignedUrlResponse>, SignedUrlError> {
+        // 일부 URL만 발급되지 않도록 TTL과 경로를 먼저 모두 검증
+        let ttl_seconds = ttl_seconds.unwrap_or(self.default_ttl);
+        self.validate_ttl(ttl_seconds)?;
+        for file_path in &file_paths {
+            self.validate_file_path(file_path)?;
+        }
+        
+        let mut responses = Vec::with_capacity(file_paths.len());
+        for file_path in file_paths {
+            responses.push(self.generate_mask_download_url(file_path, Some(ttl_seconds)).await?);
+        }
+        Ok(responses)
+    }
+    
     async fn generate_annotation_upload_url(
         &self,
         annotation_id: i32,
@@ -478,6 +561,45 @@ mod tests {
         assert!(response.remaining_seconds() > 0);
     }
     
+    async fn local_service() -> (SignedUrlServiceImpl, std::path::PathBuf) {
+        let root = std::env::temp_dir().join(format!("pacs-signed-url-{}", uuid::Uuid::new_v4()));
+        let storage = crate::infrastructure::external::LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+            .await
+            .unwrap();
+        (SignedUrlServiceImpl::new(Box::new(storage), 600, 3600), root)
+    }
+    
+    #[tokio::test]
+    async fn test_generate_mask_urls_in_batch() {
+        let (service, root) = local_service().await;
+        let files = vec![
+            MaskUploadFile { file_name: "slice_001.png".to_string(), content_type: "image/png".to_string() },
+            MaskUploadFile { file_name: "slice_002.png".to_string(), content_type: "image/png".to_string() },
+        ];
+        
+        let uploads = service.generate_mask_upload_urls(1, 2, files.clone(), None, Some(3)).await.unwrap();
+        assert_eq!(uploads.len(), 2);
+        assert_eq!(uploads[1].file_path, "masks/annotation_1/group_2/slice_002.png");
+        assert!(uploads.iter().all(|url| url.method == "PUT" && url.ttl_seconds == 600));
+        
+        let paths = uploads.iter().map(|url| url.file_path.clone()).collect();
+        let downloads = service.generate_mask_download_urls(paths, Some(1200)).await.unwrap();
+        assert_eq!(downloads.len(), 2);
+        assert!(downloads.iter().all(|url| url.method == "GET" && url.ttl_seconds == 1200));
+        
+        // TTL은 파일마다가 아니라 요청 전체에 대해 한 번 검증
+        assert!(matches!(
+            service.generate_mask_upload_urls(1, 2, files, Some(7200), None).await,
+            Err(SignedUrlError::InvalidTtl(_))
+        ));
+        assert!(matches!(
+            service.generate_mask_download_urls(vec!["masks/a.png".to_string(), "../b.png".to_string()], None).await,
+            Err(SignedUrlError::InvalidFilePath(_))
+        ));
+        
+        let _ = std::fs::remove_dir_all(root);
+    }
+    
     // Note: These tests require a mock ObjectStorageService implementation
     // For now, we'll skip these tests until we have a proper mock
     // #[tokio::test]
@@ -0,0 +1,495 @@
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::sync::Arc;
+use chrono::Utc;
+use flate2::read::GzDecoder;
+use futures::stream::{self, Stream, StreamExt};
+use uuid::Uuid;
+use crate::application::dto::annotation_bundle_dto::{
+    BundleConflict, BundleImportQuery, BundleImportReport, BundleRecordCounts,
+};
+use crate::domain::entities::{
+    BundleHeader, BundleImportPlan, BundleRecord, ResolvedBundleAnnotation, ResolvedBundleHistory,
+    ResolvedBundleMaskGroup, BUNDLE_FORMAT_VERSION,
+};
+use crate::domain::repositories::AnnotationBundleRepository;
+use crate::domain::ServiceError;
+
+/// 내보내기 시 한 번에 조회할 어노테이션 수
+const EXPORT_PAGE_SIZE: i64 = 500;
+/// 압축 해제된 번들의 최대 크기 (1 GiB)
+pub const MAX_BUNDLE_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// 어노테이션 NDJSON 번들 내보내기 / 가져오기 유스케이스
+///
+/// 번들은 헤더, 어노테이션, 히스토리, 마스크 그룹 메타데이터 레코드로 구성됩니다.
+/// 가져오기 시 사용자는 사용자명으로, 프로젝트는 프로젝트 이름으로 다시 매핑되며,
+/// 이미 존재하는 `external_id`는 건너뛰므로 같은 번들을 여러 번 가져와도 안전합니다.
+/// 내보내기와 가져오기 모두 대상 프로젝트의 멤버이거나 전역 관리자여야 합니다.
+pub struct AnnotationBundleUseCase<R>
+where
+    R: AnnotationBundleRepository + Send + Sync,
+{
+    repository: Arc<R>,
+}
+
+impl<R> Clone for AnnotationBundleUseCase<R>
+where
+    R: AnnotationBundleRepository + Send + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repository: self.repository.clone(),
+        }
+    }
+}
+
+impl<R> AnnotationBundleUseCase<R>
+where
+    R: AnnotationBundleRepository + Send + Sync + 'static,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// 프로젝트 번들 내보내기
+    ///
+    /// 헤더 레코드 다음으로 어노테이션을 페이지 단위로 조회하여, 페이지마다
+    /// 어노테이션 → 히스토리 → 마스크 그룹 순서의 레코드 묶음을 스트림으로 반환합니다.
+    /// 공유되었거나 요청한 사용자가 작성한 어노테이션만 포함됩니다.
+    pub async fn export_project(
+        &self,
+        project_id: i32,
+        user_id: i32,
+    ) -> Result<impl Stream<Item = Result<Vec<BundleRecord>, ServiceError>> + 'static, ServiceError> {
+        let project_name = self.repository
+            .find_project_name(project_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Project with id {} not found", project_id)))?;
+        self.ensure_project_access(project_id, user_id).await?;
+
+        let header = BundleRecord::Header(BundleHeader {
+            format_version: BUNDLE_FORMAT_VERSION,
+            project_name,
+            exported_at: Utc::now(),
+        });
+
+        let repository = self.repository.clone();
+        let pages = stream::try_unfold(Some(0), move |after_id| {
+            let repository = repository.clone();
+            async move {
+                let Some(after_id) = after_id else {
+                    return Ok(None);
+                };
+
+                let annotations = repository.export_annotations(project_id, user_id, after_id, EXPORT_PAGE_SIZE).await?;
+                let Some(last) = annotations.last() else {
+                    return Ok(None);
+                };
+                let next = (annotations.len() as i64 == EXPORT_PAGE_SIZE).then_some(last.source_id);
+
+                let ids: Vec<i32> = annotations.iter().map(|a| a.source_id).collect();
+                let history = repository.export_history(&ids).await?;
+                let mask_groups = repository.export_mask_groups(&ids).await?;
+
+                let mut records = Vec::with_capacity(annotations.len() + history.len() + mask_groups.len());
+                records.extend(annotations.into_iter().map(BundleRecord::Annotation));
+                records.extend(history.into_iter().map(BundleRecord::History));
+                records.extend(mask_groups.into_iter().map(BundleRecord::MaskGroup));
+                Ok(Some((records, next)))
+            }
+        });
+
+        Ok(stream::once(async move { Ok(vec![header]) }).chain(pages))
+    }
+
+    /// 번들 가져오기
+    ///
+    /// 충돌이 하나라도 있으면 아무것도 저장하지 않고 충돌 목록만 반환합니다.
+    /// `dry_run`이면 충돌 여부와 관계없이 저장하지 않습니다.
+    pub async fn import_bundle(&self, bundle: &[u8], query: BundleImportQuery, user_id: i32) -> Result<BundleImportReport, ServiceError> {
+        let dry_run = query.dry_run.unwrap_or(false);
+        let records = parse_bundle(bundle)?;
+
+        let header = records.iter().find_map(|(_, record)| match record {
+            BundleRecord::Header(header) => Some(header),
+            _ => None,
+        });
+        if let Some(header) = header {
+            if header.format_version > BUNDLE_FORMAT_VERSION {
+                return Err(ServiceError::ValidationError(format!(
+                    "Unsupported bundle format version: {}",
+                    header.format_version
+                )));
+            }
+        }
+
+        let project_name = query.project_name
+            .or_else(|| header.map(|h| h.project_name.clone()))
+            .ok_or_else(|| ServiceError::ValidationError(
+                "project_name is required when the bundle has no header".to_string(),
+            ))?;
+        let project_id = self.repository.find_project_id_by_name(&project_name).await?;
+        if let Some(project_id) = project_id {
+            self.ensure_project_access(project_id, user_id).await?;
+        }
+
+        let mut usernames = HashSet::new();
+        let mut annotation_ids = Vec::new();
+        let mut mask_group_ids = Vec::new();
+        for (_, record) in &records {
+            match record {
+                BundleRecord::Annotation(a) => {
+                    usernames.insert(a.username.clone());
+                    annotation_ids.push(a.external_id);
+                }
+                BundleRecord::History(h) => {
+                    usernames.insert(h.username.clone());
+                }
+                BundleRecord::MaskGroup(g) => {
+                    usernames.extend(g.created_by.clone());
+                    annotation_ids.push(g.annotation_external_id);
+                    mask_group_ids.push(g.external_id);
+                }
+                BundleRecord::Header(_) => {}
+            }
+        }
+        let usernames: Vec<String> = usernames.into_iter().collect();
+
+        let lookups = ImportLookups {
+            project_name,
+            project_id,
+            users: self.repository.find_user_ids(&usernames).await?,
+            existing_annotations: self.repository.find_existing_annotations(&annotation_ids).await?,
+            existing_mask_groups: self.repository.find_existing_mask_groups(&mask_group_ids).await?,
+        };
+
+        let (plan, mut report) = plan_import(&records, &lookups);
+        report.dry_run = dry_run;
+
+        if report.conflicts.is_empty() && !dry_run {
+            self.repository.apply_import(&plan).await?;
+            report.applied = true;
+        }
+
+        Ok(report)
+    }
+
+    async fn ensure_project_access(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        if !self.repository.has_project_access(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 임포트 계획 수립에 필요한 조회 결과
+struct ImportLookups {
+    project_name: String,
+    project_id: Option<i32>,
+    users: HashMap<String, i32>,
+    existing_annotations: HashMap<Uuid, i32>,
+    existing_mask_groups: HashSet<Uuid>,
+}
+
+/// NDJSON(선택적으로 gzip) 번들을 레코드 목록으로 파싱 (줄 번호 포함)
+fn parse_bundle(bundle: &[u8]) -> Result<Vec<(usize, BundleRecord)>, ServiceError> {
+    let decompressed;
+    let content = if bundle.starts_with(&[0x1f, 0x8b]) {
+        let mut buffer = Vec::new();
+        GzDecoder::new(bundle)
+            .take(MAX_BUNDLE_SIZE + 1)
+            .read_to_end(&mut buffer)
+            .map_err(|e| ServiceError::ValidationError(format!("Invalid gzip bundle: {}", e)))?;
+        if buffer.len() as u64 > MAX_BUNDLE_SIZE {
+            return Err(ServiceError::ValidationError("Bundle is too large".to_string()));
+        }
+        decompressed = buffer;
+        decompressed.as_slice()
+    } else {
+        bundle
+    };
+
+    let content = std::str::from_utf8(content)
+        .map_err(|_| ServiceError::ValidationError("Bundle must be UTF-8 encoded NDJSON".to_string()))?;
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str::<BundleRecord>(line)
+                .map(|record| (index + 1, record))
+                .map_err(|e| ServiceError::ValidationError(format!("Line {}: {}", index + 1, e)))
+        })
+        .collect()
+}
+
+/// 레코드들을 충돌 검사하여 임포트 계획과 결과 보고서를 생성
+fn plan_import(records: &[(usize, BundleRecord)], lookups: &ImportLookups) -> (BundleImportPlan, BundleImportReport) {
+    let mut plan = BundleImportPlan {
+        project_id: lookups.project_id.unwrap_or_default(),
+        ..Default::default()
+    };
+    let mut report = BundleImportReport {
+        project_id: lookups.project_id,
+        ..Default::default()
+    };
+
+    let mut conflict = |line: usize, record: &BundleRecord, external_id: Option<Uuid>, reason: String| {
+        report.conflicts.push(BundleConflict {
+            line,
+            record_type: record.kind().to_string(),
+            external_id: external_id.map(|id| id.to_string()),
+            reason,
+        });
+    };
+
+    // 번들 안에서 새로 생성될 어노테이션과 이미 존재하여 건너뛸 어노테이션
+    let mut new_annotations: HashSet<Uuid> = HashSet::new();
+    let mut skipped_annotations: HashSet<Uuid> = HashSet::new();
+    let mut seen_mask_groups: HashSet<Uuid> = HashSet::new();
+    let mut annotation_counts = BundleRecordCounts::default();
+    let mut history_counts = BundleRecordCounts::default();
+    let mut mask_group_counts = BundleRecordCounts::default();
+
+    for (line, record) in records {
+        let line = *line;
+        match record {
+            BundleRecord::Header(_) => {}
+            BundleRecord::Annotation(a) => {
+                annotation_counts.total += 1;
+                if new_annotations.contains(&a.external_id) || skipped_annotations.contains(&a.external_id) {
+                    conflict(line, record, Some(a.external_id), "Duplicate external_id in bundle".to_string());
+                    continue;
+                }
+                if let Some(&existing_project) = lookups.existing_annotations.get(&a.external_id) {
+                    if Some(existing_project) == lookups.project_id {
+                        skipped_annotations.insert(a.external_id);
+                        annotation_counts.skipped += 1;
+                    } else {
+                        conflict(line, record, Some(a.external_id), "external_id already exists in another project".to_string());
+                    }
+                    continue;
+                }
+                let Some(&user_id) = lookups.users.get(&a.username) else {
+                    conflict(line, record, Some(a.external_id), format!("Unknown username: {}", a.username));
+                    continue;
+                };
+                new_annotations.insert(a.external_id);
+                annotation_counts.created += 1;
+                plan.annotations.push(ResolvedBundleAnnotation { record: a.clone(), user_id });
+            }
+            BundleRecord::History(h) => {
+                history_counts.total += 1;
+                if skipped_annotations.contains(&h.annotation_external_id) {
+                    history_counts.skipped += 1;
+                    continue;
+                }
+                if !new_annotations.contains(&h.annotation_external_id) {
+                    conflict(line, record, Some(h.annotation_external_id), "History refers to an annotation that is not in the bundle".to_string());
+                    continue;
+                }
+                let Some(&user_id) = lookups.users.get(&h.username) else {
+                    conflict(line, record, Some(h.annotation_external_id), format!("Unknown username: {}", h.username));
+                    continue;
+                };
+                history_counts.created += 1;
+                plan.history.push(ResolvedBundleHistory { record: h.clone(), user_id });
+            }
+            BundleRecord::MaskGroup(g) => {
+                mask_group_counts.total += 1;
+                if !seen_mask_groups.insert(g.external_id) {
+                    conflict(line, record, Some(g.external_id), "Duplicate external_id in bundle".to_string());
+                    continue;
+                }
+                if lookups.existing_mask_groups.contains(&g.external_id) {
+                    mask_group_counts.skipped += 1;
+                    continue;
+                }
+                let parent_exists = new_annotations.contains(&g.annotation_external_id)
+                    || skipped_annotations.contains(&g.annotation_external_id)
+                    || (lookups.project_id.is_some()
+                        && lookups.existing_annotations.get(&g.annotation_external_id) == lookups.project_id.as_ref());
+                if !parent_exists {
+                    conflict(line, record, Some(g.external_id), "Mask group refers to an unknown annotation".to_string());
+                    continue;
+                }
+                let created_by = match &g.created_by {
+                    Some(username) => match lookups.users.get(username) {
+                        Some(&id) => Some(id),
+                        None => {
+                            conflict(line, record, Some(g.external_id), format!("Unknown username: {}", username));
+                            continue;
+                        }
+                    },
+                    None => None,
+                };
+                mask_group_counts.created += 1;
+                plan.mask_groups.push(ResolvedBundleMaskGroup { record: g.clone(), created_by });
+            }
+        }
+    }
+
+    if lookups.project_id.is_none() {
+        report.conflicts.insert(0, BundleConflict {
+            line: 0,
+            record_type: "header".to_string(),
+            external_id: None,
+            reason: format!("Unknown project: {}", lookups.project_name),
+        });
+    }
+
+    report.annotations = annotation_counts;
+    report.history = history_counts;
+    report.mask_groups = mask_group_counts;
+    (plan, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use crate::domain::entities::{AnnotationReviewStatus, BundleAnnotation, BundleHistory, BundleMaskGroup};
+
+    fn annotation(external_id: Uuid, username: &str) -> BundleRecord {
+        let now = Utc::now();
+        BundleRecord::Annotation(BundleAnnotation {
+            source_id: 0,
+            external_id,
+            username: username.to_string(),
+            study_uid: "1.2.3".to_string(),
+            series_uid: None,
+            instance_uid: None,
+            tool_name: "Circle Tool".to_string(),
+            tool_version: None,
+            viewer_software: None,
+            description: None,
+            data: serde_json::json!({}),
+            measurement_values: None,
+            is_shared: false,
+            review_status: AnnotationReviewStatus::Draft,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    fn history(annotation_external_id: Uuid) -> BundleRecord {
+        BundleRecord::History(BundleHistory {
+            annotation_external_id,
+            username: "alice".to_string(),
+            action: "create".to_string(),
+            data_before: None,
+            data_after: Some(serde_json::json!({})),
+            action_at: Utc::now(),
+        })
+    }
+
+    fn mask_group(external_id: Uuid, annotation_external_id: Uuid) -> BundleRecord {
+        let now = Utc::now();
+        BundleRecord::MaskGroup(BundleMaskGroup {
+            external_id,
+            annotation_external_id,
+            group_name: None,
+            model_name: None,
+            version: None,
+            modality: None,
+            slice_count: Some(1),
+            mask_type: None,
+            description: None,
+            created_by: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    fn lookups(existing_annotations: HashMap<Uuid, i32>) -> ImportLookups {
+        ImportLookups {
+            project_name: "target".to_string(),
+            project_id: Some(7),
+            users: HashMap::from([("alice".to_string(), 1)]),
+            existing_annotations,
+            existing_mask_groups: HashSet::new(),
+        }
+    }
+
+    fn numbered(records: Vec<BundleRecord>) -> Vec<(usize, BundleRecord)> {
+        records.into_iter().enumerate().map(|(i, r)| (i + 1, r)).collect()
+    }
+
+    #[test]
+    fn test_parse_bundle_accepts_plain_and_gzip() {
+        let ndjson = format!("{}\n\n{}\n", serde_json::to_string(&annotation(Uuid::new_v4(), "alice")).unwrap(),
+            serde_json::to_string(&history(Uuid::new_v4())).unwrap());
+
+        let plain = parse_bundle(ndjson.as_bytes()).unwrap();
+        assert_eq!(plain.iter().map(|(line, _)| *line).collect::<Vec<_>>(), vec![1, 3]);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(ndjson.as_bytes()).unwrap();
+        let gzipped = parse_bundle(&encoder.finish().unwrap()).unwrap();
+        assert_eq!(gzipped, plain);
+
+        let err = parse_bundle(b"{\"type\":\"annotation\"}").unwrap_err();
+        assert!(matches!(err, ServiceError::ValidationError(msg) if msg.starts_with("Line 1")));
+    }
+
+    #[test]
+    fn test_plan_import_skips_existing_and_creates_new() {
+        let existing = Uuid::new_v4();
+        let fresh = Uuid::new_v4();
+        let records = numbered(vec![
+            annotation(existing, "alice"),
+            annotation(fresh, "alice"),
+            history(existing),
+            history(fresh),
+            mask_group(Uuid::new_v4(), existing),
+        ]);
+
+        let (plan, report) = plan_import(&records, &lookups(HashMap::from([(existing, 7)])));
+
+        assert!(report.conflicts.is_empty());
+        assert_eq!(report.annotations, BundleRecordCounts { total: 2, created: 1, skipped: 1 });
+        assert_eq!(report.history, BundleRecordCounts { total: 2, created: 1, skipped: 1 });
+        assert_eq!(report.mask_groups.created, 1);
+        assert_eq!(plan.annotations.len(), 1);
+        assert_eq!(plan.annotations[0].user_id, 1);
+    }
+
+    #[test]
+    fn test_plan_import_reports_conflicts() {
+        let other_project = Uuid::new_v4();
+        let duplicated = Uuid::new_v4();
+        let records = numbered(vec![
+            annotation(other_project, "alice"),
+            annotation(Uuid::new_v4(), "mallory"),
+            annotation(duplicated, "alice"),
+            annotation(duplicated, "alice"),
+            history(Uuid::new_v4()),
+            mask_group(Uuid::new_v4(), Uuid::new_v4()),
+        ]);
+
+        let (_, report) = plan_import(&records, &lookups(HashMap::from([(other_project, 99)])));
+
+        let lines: Vec<usize> = report.conflicts.iter().map(|c| c.line).collect();
+        assert_eq!(lines, vec![1, 2, 4, 5, 6]);
+        assert!(report.conflicts[1].reason.contains("mallory"));
+    }
+
+    #[test]
+    fn test_plan_import_reports_unknown_project() {
+        let mut lookups = lookups(HashMap::new());
+        lookups.project_id = None;
+
+        let (_, report) = plan_import(&numbered(vec![annotation(Uuid::new_v4(), "alice")]), &lookups);
+
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].line, 0);
+        assert_eq!(report.project_id, None);
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use crate::application::dto::annotation_propagation_dto::{
+    LesionResponse, LesionTimepointResponse, LesionTrackingQuery, LesionTrackingResponse, PropagateAnnotationsRequest,
+    PropagateAnnotationsResponse, PropagatedAnnotationResponse, SkippedPropagationResponse,
+};
+use crate::domain::entities::{match_series, AnnotationPropagationPlan, LesionTimepoint, SeriesMatch};
+use crate::domain::entities::project_data::ProjectDataStudy;
+use crate::domain::repositories::AnnotationPropagationRepository;
+use crate::domain::ServiceError;
+
+/// 한 번에 전파할 수 있는 어노테이션 수
+pub const MAX_PROPAGATION_BATCH: usize = 200;
+
+/// 어노테이션 전파 유스케이스
+///
+/// 프로젝트 멤버는 기준 시점 Study의 어노테이션을 같은 환자의 후속 Study로 DRAFT 상태로 복사하고,
+/// 환자의 병변별 시점 측정값을 조회할 수 있습니다.
+pub struct AnnotationPropagationUseCase<R>
+where
+    R: AnnotationPropagationRepository + Send + Sync,
+{
+    propagation_repository: Arc<R>,
+}
+
+impl<R> AnnotationPropagationUseCase<R>
+where
+    R: AnnotationPropagationRepository + Send + Sync,
+{
+    pub fn new(propagation_repository: Arc<R>) -> Self {
+        Self { propagation_repository }
+    }
+
+    async fn ensure_member(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        if !self.propagation_repository.is_project_member(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn find_study(&self, project_id: i32, study_uid: &str) -> Result<ProjectDataStudy, ServiceError> {
+        self.propagation_repository
+            .find_study(project_id, study_uid)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Study {} not found in project {}", study_uid, project_id)))
+    }
+
+    /// 원본 Study의 어노테이션을 같은 환자의 후속 Study로 복사
+    ///
+    /// 이미 대상 Study로 전파한 어노테이션은 건너뜁니다.
+    pub async fn propagate(
+        &self,
+        project_id: i32,
+        request: PropagateAnnotationsRequest,
+        user_id: i32,
+    ) -> Result<PropagateAnnotationsResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let annotation_ids: Vec<i32> = request.annotation_ids.iter().copied().collect::<BTreeSet<_>>().into_iter().collect();
+        if annotation_ids.is_empty() {
+            return Err(ServiceError::ValidationError("At least one annotation is required".to_string()));
+        }
+        if annotation_ids.len() > MAX_PROPAGATION_BATCH {
+            return Err(ServiceError::ValidationError(format!(
+                "At most {} annotations can be propagated at once",
+                MAX_PROPAGATION_BATCH
+            )));
+        }
+        if request.source_study_uid == request.target_study_uid {
+            return Err(ServiceError::ValidationError("Source and target studies must be different".to_string()));
+        }
+
+        let source_study = self.find_study(project_id, &request.source_study_uid).await?;
+        let target_study = self.find_study(project_id, &request.target_study_uid).await?;
+        let patient_id = source_study.patient_id.as_deref().map(str::trim).filter(|id| !id.is_empty());
+        let Some(patient_id) = patient_id else {
+            return Err(ServiceError::ValidationError(format!(
+                "Study {} has no patient ID",
+                source_study.study_uid
+            )));
+        };
+        if target_study.patient_id.as_deref().map(str::trim) != Some(patient_id) {
+            return Err(ServiceError::ValidationError(format!(
+                "Study {} does not belong to patient {}",
+                target_study.study_uid, patient_id
+            )));
+        }
+
+        let annotations = self.propagation_repository.find_annotations(project_id, &annotation_ids).await?;
+        let missing: Vec<i32> = annotation_ids
+            .iter()
+            .copied()
+            .filter(|id| !annotations.iter().any(|annotation| annotation.id == *id))
+            .collect();
+        if !missing.is_empty() {
+            return Err(ServiceError::NotFound(format!(
+                "Annotations {:?} not found in project {}",
+                missing, project_id
+            )));
+        }
+        if let Some(annotation) = annotations.iter().find(|annotation| annotation.study_uid != source_study.study_uid) {
+            return Err(ServiceError::ValidationError(format!(
+                "Annotation {} is not on study {}",
+                annotation.id, source_study.study_uid
+            )));
+        }
+
+        let already_propagated = self.propagation_repository
+            .find_propagated_sources(&annotation_ids, &target_study.study_uid)
+            .await?;
+        let skipped = already_propagated
+            .iter()
+            .map(|id| SkippedPropagationResponse {
+                source_annotation_id: *id,
+                reason: format!("Already propagated to study {}", target_study.study_uid),
+            })
+            .collect();
+
+        // 원본 Series를 후속 Study의 Series에 대응
+        let source_series = self.propagation_repository.list_series(source_study.id).await?;
+        let target_series = self.propagation_repository.list_series(target_study.id).await?;
+        let mut plans: Vec<AnnotationPropagationPlan> = annotations
+            .into_iter()
+            .filter(|annotation| !already_propagated.contains(&annotation.id))
+            .map(|annotation| {
+                let (series_uid, series_match) = match annotation.series_uid.as_deref() {
+                    None => (None, SeriesMatch::StudyLevel),
+                    Some(uid) => source_series
+                        .iter()
+                        .find(|series| series.series_uid == uid)
+                        .and_then(|series| match_series(series, &target_series))
+                        .map(|(series, how)| (Some(series.series_uid.clone()), how))
+                        .unwrap_or((None, SeriesMatch::Unmatched)),
+                };
+                AnnotationPropagationPlan { source: annotation, series_uid, instance_uid: None, series_match }
+            })
+            .collect();
+
+        // 대상 Series가 단일 영상이면 그 인스턴스에 배치
+        let matched_series: Vec<String> = plans
+            .iter()
+            .filter_map(|plan| plan.series_uid.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if !matched_series.is_empty() {
+            let single_instances: HashMap<String, String> = self.propagation_repository
+                .find_single_instances(project_id, &matched_series)
+                .await?
+                .into_iter()
+                .collect();
+            for plan in &mut plans {
+                if let Some(series_uid) = &plan.series_uid {
+                    plan.instance_uid = single_instances.get(series_uid).cloned();
+                }
+            }
+        }
+
+        let propagated = if plans.is_empty() {
+            Vec::new()
+        } else {
+            self.propagation_repository
+                .propagate(&target_study.study_uid, &plans, user_id)
+                .await?
+        };
+
+        Ok(PropagateAnnotationsResponse {
+            source_study_uid: source_study.study_uid,
+            target_study_uid: target_study.study_uid,
+            propagated: propagated.into_iter().map(PropagatedAnnotationResponse::from).collect(),
+            skipped,
+        })
+    }
+
+    /// 환자의 병변별 시점 측정값
+    pub async fn track_lesions(
+        &self,
+        project_id: i32,
+        query: LesionTrackingQuery,
+        user_id: i32,
+    ) -> Result<LesionTrackingResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let patient_id = query.patient_id.trim().to_string();
+        if patient_id.is_empty() {
+            return Err(ServiceError::ValidationError("patient_id is required".to_string()));
+        }
+        let timepoints = self.propagation_repository
+            .list_lesion_timepoints(project_id, &patient_id, query.lesion_id)
+            .await?;
+
+        let tracked_only = query.tracked_only.unwrap_or(false);
+        let lesions = group_lesions(timepoints)
+            .into_iter()
+            .filter(|lesion| {
+                !tracked_only || lesion.timepoints.iter().map(|t| &t.study_uid).collect::<BTreeSet<_>>().len() > 1
+            })
+            .collect();
+        Ok(LesionTrackingResponse { patient_id, lesions })
+    }
+}
+
+/// 병변 ID 순으로 정렬된 시점 행을 병변별로 묶음
+fn group_lesions(timepoints: Vec<LesionTimepoint>) -> Vec<LesionResponse> {
+    let mut lesions: Vec<LesionResponse> = Vec::new();
+    for timepoint in timepoints {
+        let is_root = timepoint.annotation_id == timepoint.lesion_id;
+        let lesion = match lesions.last_mut() {
+            Some(lesion) if lesion.lesion_id == timepoint.lesion_id => lesion,
+            _ => {
+                lesions.push(LesionResponse {
+                    lesion_id: timepoint.lesion_id,
+                    tool_name: timepoint.tool_name.clone(),
+                    description: timepoint.description.clone(),
+                    timepoints: Vec::new(),
+                });
+                lesions.last_mut().expect("lesion was just pushed")
+            }
+        };
+        // 최초 어노테이션이 있으면 그 도구와 설명을 병변 정보로 사용
+        if is_root {
+            lesion.tool_name = timepoint.tool_name.clone();
+            lesion.description = timepoint.description.clone();
+        }
+        lesion.timepoints.push(LesionTimepointResponse::from(timepoint));
+    }
+    lesions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, Utc};
+    use crate::domain::entities::AnnotationReviewStatus;
+
+    fn timepoint(lesion_id: i32, annotation_id: i32, study_uid: &str, description: &str) -> LesionTimepoint {
+        LesionTimepoint {
+            lesion_id,
+            annotation_id,
+            study_uid: study_uid.to_string(),
+            study_date: NaiveDate::from_ymd_opt(2025, 1, 1),
+            series_uid: None,
+            instance_uid: None,
+            tool_name: "Length Tool".to_string(),
+            description: Some(description.to_string()),
+            review_status: AnnotationReviewStatus::Draft,
+            source_annotation_id: (lesion_id != annotation_id).then_some(lesion_id),
+            measurement_values: None,
+            computed_measurements: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_group_lesions_uses_root_annotation_details() {
+        let lesions = group_lesions(vec![
+            timepoint(1, 1, "baseline", "target lesion 1"),
+            timepoint(1, 5, "follow-up", "copied"),
+            timepoint(2, 7, "follow-up", "copy of deleted baseline"),
+            timepoint(2, 8, "follow-up 2", "second copy"),
+        ]);
+        assert_eq!(lesions.len(), 2);
+        assert_eq!(lesions[0].description.as_deref(), Some("target lesion 1"));
+        assert_eq!(lesions[0].timepoints.iter().map(|t| t.annotation_id).collect::<Vec<_>>(), vec![1, 5]);
+        // 최초 어노테이션이 없으면 첫 시점의 정보를 사용
+        assert_eq!(lesions[1].description.as_deref(), Some("copy of deleted baseline"));
+        assert_eq!(lesions[1].timepoints.len(), 2);
+    }
+}
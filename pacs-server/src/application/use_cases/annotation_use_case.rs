@@ -17,13 +17,18 @@
 // 애플리케이션 레이어의 DTO 모듈들
 use crate::application::dto::{
     CreateAnnotationRequest, UpdateAnnotationRequest, AnnotationResponse, AnnotationListResponse,
+    ChangeReviewStatusRequest, AssignReviewerRequest, CreateReviewCommentRequest,
+    ReviewCommentResponse, ReviewCommentListResponse, AnnotationSearchRequest, AnnotationSearchResponse,
 };
 // 도메인 레이어의 서비스 인터페이스
 use crate::domain::services::{AnnotationService};
 // 도메인 레이어의 에러 타입
 use crate::domain::ServiceError;
 // 도메인 레이어의 엔티티
-use crate::domain::entities::{NewAnnotation};
+use crate::domain::entities::{
+    Annotation, AnnotationReviewStatus, AnnotationSearchCriteria, AnnotationSearchCursor, NewAnnotation,
+    DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT,
+};
 
 /// 어노테이션 관리를 위한 Use Case
 /// 
@@ -121,21 +126,7 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
 
         let annotation = self.annotation_service.create_annotation(new_annotation).await?;
 
-        Ok(AnnotationResponse {
-            id: annotation.id,
-            user_id: annotation.user_id,
-            study_instance_uid: annotation.study_uid,
-            series_instance_uid: annotation.series_uid.unwrap_or_default(),
-            sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-            annotation_data: annotation.data,
-            tool_name: Some(annotation.tool_name),
-            tool_version: annotation.tool_version,
-            viewer_software: annotation.viewer_software,
-            description: annotation.description,
-            measurement_values: annotation.measurement_values,
-            created_at: annotation.created_at,
-            updated_at: annotation.updated_at,
-        })
+        Ok(Self::to_response(annotation))
     }
 
     /// ID로 어노테이션을 조회합니다.
@@ -158,21 +149,7 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
     pub async fn get_annotation_by_id(&self, annotation_id: i32) -> Result<AnnotationResponse, ServiceError> {
         let annotation = self.annotation_service.get_annotation_by_id(annotation_id).await?;
 
-        Ok(AnnotationResponse {
-            id: annotation.id,
-            user_id: annotation.user_id,
-            study_instance_uid: annotation.study_uid,
-            series_instance_uid: annotation.series_uid.unwrap_or_default(),
-            sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-            annotation_data: annotation.data,
-            tool_name: Some(annotation.tool_name),
-            tool_version: annotation.tool_version,
-            viewer_software: annotation.viewer_software,
-            description: annotation.description,
-            measurement_values: annotation.measurement_values,
-            created_at: annotation.created_at,
-            updated_at: annotation.updated_at,
-        })
+        Ok(Self::to_response(annotation))
     }
 
     /// 프로젝트의 어노테이션 목록을 조회합니다.
@@ -201,21 +178,7 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -247,21 +210,7 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -294,21 +243,7 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -341,21 +276,7 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -388,21 +309,7 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -436,21 +343,7 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -482,21 +375,7 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -513,20 +392,21 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
     /// # 매개변수
     /// - `annotation_id`: 업데이트할 어노테이션의 ID
     /// - `request`: 업데이트 요청 데이터
+    /// - `user_id`: 수정을 요청한 사용자 ID
     /// 
     /// # 반환값
     /// - `Ok(AnnotationResponse)`: 업데이트된 어노테이션 정보
-    /// - `Err(ServiceError)`: 어노테이션을 찾을 수 없거나 서비스 오류
+    /// - `Err(ServiceError)`: 어노테이션을 찾을 수 없거나, 다른 사용자가 편집 잠금을 가지고 있거나, 서비스 오류
     /// 
     /// # 예시
     /// ```rust
     /// let request = UpdateAnnotationRequest {
     ///     annotation_data: Some(serde_json::json!({"type": "polygon", "points": [[0, 0], [100, 100]]})),
     /// };
-    /// let updated = annotation_use_case.update_annotation(123, request).await?;
+    /// let updated = annotation_use_case.update_annotation(123, request, 1).await?;
     /// println!("업데이트된 어노테이션 ID: {}", updated.id);
     /// ```
-    pub async fn update_annotation(&self, annotation_id: i32, request: UpdateAnnotationRequest) -> Result<AnnotationResponse, ServiceError> {
+    pub async fn update_annotation(&self, annotation_id: i32, request: UpdateAnnotationRequest, user_id: i32) -> Result<AnnotationResponse, ServiceError> {
         // 현재 annotation 조회
         let current_annotation = self.annotation_service.get_annotation_by_id(annotation_id).await?;
         
@@ -535,32 +415,19 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         let is_shared = false; // 현재는 is_shared 업데이트 기능 없음
         let measurement_values = request.measurement_values.or(current_annotation.measurement_values);
 
-        let updated_annotation = self.annotation_service.update_annotation_with_measurements(annotation_id, new_data, is_shared, measurement_values).await?;
-
-        Ok(AnnotationResponse {
-            id: updated_annotation.id,
-            user_id: updated_annotation.user_id,
-            study_instance_uid: updated_annotation.study_uid,
-            series_instance_uid: updated_annotation.series_uid.unwrap_or_default(),
-            sop_instance_uid: updated_annotation.instance_uid.unwrap_or_default(),
-            annotation_data: updated_annotation.data,
-            tool_name: Some(updated_annotation.tool_name),
-            tool_version: updated_annotation.tool_version,
-            viewer_software: updated_annotation.viewer_software,
-            description: updated_annotation.description,
-            measurement_values: updated_annotation.measurement_values,
-            created_at: updated_annotation.created_at,
-            updated_at: updated_annotation.updated_at,
-        })
+        let updated_annotation = self.annotation_service.update_annotation_with_measurements(annotation_id, new_data, is_shared, measurement_values, user_id).await?;
+
+        Ok(Self::to_response(updated_annotation))
     }
 
     /// 어노테이션을 삭제합니다.
     /// 
-    /// 이 메서드는 지정된 ID를 가진 어노테이션을 삭제합니다.
-    /// 어노테이션과 관련된 히스토리도 함께 삭제됩니다.
+    /// 이 메서드는 지정된 ID를 가진 어노테이션을 휴지통으로 이동합니다.
+    /// 하위 마스크 그룹도 함께 이동하며, 보관 기간 내에는 프로젝트 휴지통에서 복원할 수 있습니다.
     /// 
     /// # 매개변수
     /// - `annotation_id`: 삭제할 어노테이션의 ID
+    /// - `user_id`: 삭제를 요청한 사용자 ID
     /// 
     /// # 반환값
     /// - `Ok(())`: 삭제 성공
@@ -568,11 +435,11 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
     /// 
     /// # 예시
     /// ```rust
-    /// annotation_use_case.delete_annotation(123).await?;
+    /// annotation_use_case.delete_annotation(123, 1).await?;
     /// println!("어노테이션이 삭제되었습니다.");
     /// ```
-    pub async fn delete_annotation(&self, annotation_id: i32) -> Result<(), ServiceError> {
-        self.annotation_service.delete_annotation(annotation_id).await
+    pub async fn delete_annotation(&self, annotation_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        self.annotation_service.delete_annotation(annotation_id, user_id).await
     }
 
     /// 사용자의 어노테이션 접근 권한을 확인합니다.
@@ -602,36 +469,23 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         self.annotation_service.can_access_annotation(user_id, annotation_id).await
     }
 
-    // viewer_software 필터링 메서드들
-    /// 사용자의 어노테이션 목록 조회 (viewer_software 필터링)
+    // viewer_software, review_status 필터링 메서드들
+    /// 사용자의 어노테이션 목록 조회 (viewer_software, review_status 필터링)
     /// 
     /// # Arguments
     /// * `user_id` - 사용자 ID
     /// * `viewer_software` - 뷰어 소프트웨어 (옵션)
+    /// * `review_status` - 검토 상태 (옵션)
     /// 
     /// # Returns
     /// * `Result<AnnotationListResponse, ServiceError>` - 어노테이션 목록 응답
-    pub async fn get_annotations_by_user_with_viewer(&self, user_id: i32, viewer_software: Option<&str>) -> Result<AnnotationListResponse, ServiceError> {
-        let annotations = self.annotation_service.get_annotations_by_user_with_viewer(user_id, viewer_software).await?;
+    pub async fn get_annotations_by_user_with_viewer(&self, user_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<AnnotationListResponse, ServiceError> {
+        let annotations = self.annotation_service.get_annotations_by_user_with_viewer(user_id, viewer_software, review_status).await?;
 
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -640,35 +494,22 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         })
     }
 
-    /// 프로젝트의 어노테이션 목록 조회 (viewer_software 필터링)
+    /// 프로젝트의 어노테이션 목록 조회 (viewer_software, review_status 필터링)
     /// 
     /// # Arguments
     /// * `project_id` - 프로젝트 ID
     /// * `viewer_software` - 뷰어 소프트웨어 (옵션)
+    /// * `review_status` - 검토 상태 (옵션)
     /// 
     /// # Returns
     /// * `Result<AnnotationListResponse, ServiceError>` - 어노테이션 목록 응답
-    pub async fn get_annotations_by_project_with_viewer(&self, project_id: i32, viewer_software: Option<&str>) -> Result<AnnotationListResponse, ServiceError> {
-        let annotations = self.annotation_service.get_annotations_by_project_with_viewer(project_id, viewer_software).await?;
+    pub async fn get_annotations_by_project_with_viewer(&self, project_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<AnnotationListResponse, ServiceError> {
+        let annotations = self.annotation_service.get_annotations_by_project_with_viewer(project_id, viewer_software, review_status).await?;
 
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -677,35 +518,22 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
         })
     }
 
-    /// Study UID로 어노테이션 목록 조회 (viewer_software 필터링)
+    /// Study UID로 어노테이션 목록 조회 (viewer_software, review_status 필터링)
     /// 
     /// # Arguments
     /// * `study_uid` - Study Instance UID
     /// * `viewer_software` - 뷰어 소프트웨어 (옵션)
+    /// * `review_status` - 검토 상태 (옵션)
     /// 
     /// # Returns
     /// * `Result<AnnotationListResponse, ServiceError>` - 어노테이션 목록 응답
-    pub async fn get_annotations_by_study_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>) -> Result<AnnotationListResponse, ServiceError> {
-        let annotations = self.annotation_service.get_annotations_by_study_with_viewer(study_uid, viewer_software).await?;
+    pub async fn get_annotations_by_study_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<AnnotationListResponse, ServiceError> {
+        let annotations = self.annotation_service.get_annotations_by_study_with_viewer(study_uid, viewer_software, review_status).await?;
 
         let total = annotations.len();
         let annotation_responses = annotations
             .into_iter()
-            .map(|annotation| AnnotationResponse {
-                id: annotation.id,
-                user_id: annotation.user_id,
-                study_instance_uid: annotation.study_uid,
-                series_instance_uid: annotation.series_uid.unwrap_or_default(),
-                sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
-                annotation_data: annotation.data,
-                tool_name: Some(annotation.tool_name),
-                tool_version: annotation.tool_version,
-                viewer_software: annotation.viewer_software,
-                description: annotation.description,
-                measurement_values: annotation.measurement_values,
-                created_at: annotation.created_at,
-                updated_at: annotation.updated_at,
-            })
+            .map(Self::to_response)
             .collect();
 
         Ok(AnnotationListResponse {
@@ -713,4 +541,141 @@ impl<A: AnnotationService> AnnotationUseCase<A> {
             total,
         })
     }
+
+    // 검토 워크플로우 메서드들
+    /// 어노테이션의 검토 상태를 전환합니다.
+    /// 
+    /// 제출(SUBMITTED)과 재작성(DRAFT)은 작성자만, 승인/반려/잠금은 배정된 검토자만 수행할 수 있습니다.
+    /// 모든 전환은 어노테이션 히스토리에 기록됩니다.
+    /// 
+    /// # 매개변수
+    /// - `annotation_id`: 대상 어노테이션의 ID
+    /// - `request`: 전환할 상태와 코멘트
+    /// - `actor_id`: 요청한 사용자의 ID
+    pub async fn change_review_status(&self, annotation_id: i32, request: ChangeReviewStatusRequest, actor_id: i32) -> Result<AnnotationResponse, ServiceError> {
+        let annotation = self.annotation_service
+            .change_review_status(annotation_id, actor_id, request.status, request.comment)
+            .await?;
+
+        Ok(Self::to_response(annotation))
+    }
+
+    /// 어노테이션에 검토자를 배정합니다.
+    /// 
+    /// 검토자는 어노테이션이 속한 프로젝트에서 검토 가능한 역할을 가진 사용자여야 합니다.
+    pub async fn assign_reviewer(&self, annotation_id: i32, request: AssignReviewerRequest, actor_id: i32) -> Result<AnnotationResponse, ServiceError> {
+        let annotation = self.annotation_service
+            .assign_reviewer(annotation_id, actor_id, request.reviewer_id)
+            .await?;
+
+        Ok(Self::to_response(annotation))
+    }
+
+    /// 검토자에게 배정된 어노테이션 목록을 조회합니다.
+    pub async fn get_annotations_by_reviewer(&self, reviewer_id: i32, review_status: Option<AnnotationReviewStatus>) -> Result<AnnotationListResponse, ServiceError> {
+        let annotations = self.annotation_service.get_annotations_by_reviewer(reviewer_id, review_status).await?;
+
+        let total = annotations.len();
+        Ok(AnnotationListResponse {
+            annotations: annotations.into_iter().map(Self::to_response).collect(),
+            total,
+        })
+    }
+
+    /// 검토 코멘트를 작성합니다.
+    pub async fn add_review_comment(&self, annotation_id: i32, request: CreateReviewCommentRequest, user_id: i32) -> Result<ReviewCommentResponse, ServiceError> {
+        let comment = self.annotation_service
+            .add_review_comment(annotation_id, user_id, &request.comment)
+            .await?;
+
+        Ok(comment.into())
+    }
+
+    /// 검토 코멘트 목록을 조회합니다.
+    pub async fn get_review_comments(&self, annotation_id: i32, user_id: i32) -> Result<ReviewCommentListResponse, ServiceError> {
+        let comments = self.annotation_service.get_review_comments(annotation_id, user_id).await?;
+
+        let total = comments.len();
+        Ok(ReviewCommentListResponse {
+            comments: comments.into_iter().map(ReviewCommentResponse::from).collect(),
+            total,
+        })
+    }
+
+    /// 필터/정렬/키셋 페이지네이션으로 어노테이션을 검색합니다.
+    ///
+    /// `viewer_id` 사용자가 볼 수 있는 어노테이션만 반환합니다.
+    pub async fn search_annotations(&self, request: AnnotationSearchRequest, viewer_id: i32) -> Result<AnnotationSearchResponse, ServiceError> {
+        let limit = request.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+        if !(1..=MAX_SEARCH_LIMIT).contains(&limit) {
+            return Err(ServiceError::ValidationError(format!("limit must be between 1 and {}", MAX_SEARCH_LIMIT)));
+        }
+        let cursor = request.cursor
+            .as_deref()
+            .map(AnnotationSearchCursor::decode)
+            .transpose()
+            .map_err(ServiceError::ValidationError)?;
+
+        let criteria = AnnotationSearchCriteria {
+            project_id: request.project_id,
+            user_id: request.user_id,
+            study_uid: request.study_instance_uid,
+            series_uid: request.series_instance_uid,
+            instance_uid: request.sop_instance_uid,
+            tool_name: request.tool_name,
+            viewer_software: request.viewer_software,
+            is_shared: request.is_shared,
+            review_status: request.review_status,
+            created_from: request.created_from,
+            created_to: request.created_to,
+            updated_from: request.updated_from,
+            updated_to: request.updated_to,
+            data_predicates: request.data_filters,
+            measurement_predicates: request.measurement_filters,
+            visible_to_user_id: Some(viewer_id),
+            sort: request.sort,
+            direction: request.direction,
+            cursor,
+            // 다음 페이지 존재 여부 확인을 위해 한 건 더 조회
+            limit: limit + 1,
+        };
+
+        let mut annotations = self.annotation_service.search_annotations(&criteria).await?;
+        let has_more = annotations.len() as i64 > limit;
+        annotations.truncate(limit as usize);
+
+        let next_cursor = if has_more {
+            annotations.last().map(|last| {
+                AnnotationSearchCursor::from_row(criteria.sort, criteria.direction, last.id, last.created_at, last.updated_at).encode()
+            })
+        } else {
+            None
+        };
+
+        Ok(AnnotationSearchResponse {
+            annotations: annotations.into_iter().map(Self::to_response).collect(),
+            next_cursor,
+            has_more,
+        })
+    }
+
+    fn to_response(annotation: Annotation) -> AnnotationResponse {
+        AnnotationResponse {
+            id: annotation.id,
+            user_id: annotation.user_id,
+            study_instance_uid: annotation.study_uid,
+            series_instance_uid: annotation.series_uid.unwrap_or_default(),
+            sop_instance_uid: annotation.instance_uid.unwrap_or_default(),
+            annotation_data: annotation.data,
+            tool_name: Some(annotation.tool_name),
+            tool_version: annotation.tool_version,
+            viewer_software: annotation.viewer_software,
+            description: annotation.description,
+            measurement_values: annotation.measurement_values,
+            review_status: annotation.review_status,
+            reviewer_id: annotation.reviewer_id,
+            created_at: annotation.created_at,
+            updated_at: annotation.updated_at,
+        }
+    }
 }
@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::application::dto::comment_dto::{
+    CommentHistoryResponse, CommentResponse, CommentThreadListResponse, CreateCommentRequest,
+    ResolveCommentRequest, UpdateCommentRequest,
+};
+use crate::domain::entities::comment::extract_mentions;
+use crate::domain::entities::{Annotation, Comment, CommentTargetType, NewComment};
+use crate::domain::repositories::CommentRepository;
+use crate::domain::services::{AnnotationService, MaskGroupService, ProjectService};
+use crate::domain::ServiceError;
+
+/// 어노테이션 / 마스크 그룹 코멘트 유스케이스
+///
+/// 코멘트의 가시성은 상위 어노테이션을 따릅니다.
+/// - 어노테이션 프로젝트의 멤버여야 하며 (`AnnotationService::can_access_annotation`)
+/// - 공유되지 않은(`is_shared = false`) 어노테이션은 작성자만 코멘트를 보고 쓸 수 있습니다.
+///
+/// 멘션(`@username`)은 `ProjectService::get_project_members`로 프로젝트 멤버 ID로 변환되며,
+/// 멤버가 아니거나 어노테이션을 볼 수 없는 사용자는 멘션할 수 없습니다.
+pub struct CommentUseCase<CR, AS, MGS, PS>
+where
+    CR: CommentRepository + Send + Sync,
+    AS: AnnotationService + Send + Sync,
+    MGS: MaskGroupService + Send + Sync,
+    PS: ProjectService + Send + Sync,
+{
+    comment_repository: Arc<CR>,
+    annotation_service: Arc<AS>,
+    mask_group_service: Arc<MGS>,
+    project_service: Arc<PS>,
+}
+
+impl<CR, AS, MGS, PS> CommentUseCase<CR, AS, MGS, PS>
+where
+    CR: CommentRepository + Send + Sync,
+    AS: AnnotationService + Send + Sync,
+    MGS: MaskGroupService + Send + Sync,
+    PS: ProjectService + Send + Sync,
+{
+    pub fn new(
+        comment_repository: Arc<CR>,
+        annotation_service: Arc<AS>,
+        mask_group_service: Arc<MGS>,
+        project_service: Arc<PS>,
+    ) -> Self {
+        Self {
+            comment_repository,
+            annotation_service,
+            mask_group_service,
+            project_service,
+        }
+    }
+
+    /// 코멘트 대상의 상위 어노테이션을 조회하고 사용자의 조회 권한 확인
+    async fn resolve_target(
+        &self,
+        annotation_id: i32,
+        target_type: CommentTargetType,
+        target_id: i32,
+        user_id: i32,
+    ) -> Result<Annotation, ServiceError> {
+        if target_type == CommentTargetType::MaskGroup {
+            let mask_group = self.mask_group_service
+                .get_mask_group_by_id(target_id)
+                .await?
+                .ok_or_else(|| ServiceError::NotFound(format!("Mask group with id {} not found", target_id)))?;
+            if mask_group.annotation_id != annotation_id {
+                return Err(ServiceError::NotFound(format!(
+                    "Mask group {} does not belong to annotation {}",
+                    target_id, annotation_id
+                )));
+            }
+        }
+
+        let annotation = self.annotation_service.get_annotation_by_id(annotation_id).await?;
+        self.ensure_can_view(&annotation, user_id).await?;
+        Ok(annotation)
+    }
+
+    async fn ensure_can_view(&self, annotation: &Annotation, user_id: i32) -> Result<(), ServiceError> {
+        let has_access = self.annotation_service.can_access_annotation(user_id, annotation.id).await?;
+        if can_view_comments(annotation, user_id, has_access) {
+            Ok(())
+        } else {
+            Err(ServiceError::Unauthorized(format!(
+                "User {} cannot view comments of annotation {}",
+                user_id, annotation.id
+            )))
+        }
+    }
+
+    /// 기존 코멘트를 조회하고 사용자의 조회 권한 확인
+    async fn load_comment(&self, comment_id: i32, user_id: i32) -> Result<(Comment, Annotation), ServiceError> {
+        let comment = self.comment_repository
+            .get_by_id(comment_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Comment with id {} not found", comment_id)))?;
+
+        let annotation = self.annotation_service.get_annotation_by_id(comment.annotation_id).await?;
+        self.ensure_can_view(&annotation, user_id).await?;
+        Ok((comment, annotation))
+    }
+
+    /// 본문의 `@username` 멘션을 프로젝트 멤버 ID로 변환
+    async fn resolve_mentions(&self, annotation: &Annotation, body: &str) -> Result<Vec<i32>, ServiceError> {
+        let usernames = extract_mentions(body);
+        if usernames.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let members = self.project_service.get_project_members(annotation.project_id).await?;
+        let member_ids: HashMap<String, i32> = members
+            .into_iter()
+            .map(|member| (member.username.to_lowercase(), member.id))
+            .collect();
+
+        let mut mention_ids = Vec::with_capacity(usernames.len());
+        let mut unknown = Vec::new();
+        for username in &usernames {
+            match member_ids.get(&username.to_lowercase()) {
+                Some(&id) if !mention_ids.contains(&id) => mention_ids.push(id),
+                Some(_) => {}
+                None => unknown.push(format!("@{}", username)),
+            }
+        }
+
+        if !unknown.is_empty() {
+            return Err(ServiceError::ValidationError(format!(
+                "Mentioned users are not members of this project: {}",
+                unknown.join(", ")
+            )));
+        }
+
+        // 공유되지 않은 어노테이션은 작성자 외에는 볼 수 없으므로 멘션할 수 없음
+        if !annotation.is_shared && mention_ids.iter().any(|&id| id != annotation.user_id) {
+            return Err(ServiceError::ValidationError(
+                "Only the author can be mentioned on an annotation that is not shared".to_string(),
+            ));
+        }
+
+        Ok(mention_ids)
+    }
+
+    /// 대상의 코멘트 스레드 목록 조회
+    pub async fn list_comments(
+        &self,
+        annotation_id: i32,
+        target_type: CommentTargetType,
+        target_id: i32,
+        user_id: i32,
+    ) -> Result<CommentThreadListResponse, ServiceError> {
+        self.resolve_target(annotation_id, target_type, target_id, user_id).await?;
+
+        let comments = self.comment_repository.list_by_target(target_type, target_id).await?;
+        let total_count = comments.len();
+        let threads = build_threads(comments);
+        let unresolved_count = threads
+            .iter()
+            .filter(|thread| !thread.is_resolved && !thread.is_deleted)
+            .count();
+
+        Ok(CommentThreadListResponse {
+            threads,
+            total_count,
+            unresolved_count,
+        })
+    }
+
+    /// 코멘트 작성 (답글 포함)
+    pub async fn create_comment(
+        &self,
+        annotation_id: i32,
+        target_type: CommentTargetType,
+        target_id: i32,
+        request: CreateCommentRequest,
+        user_id: i32,
+    ) -> Result<CommentResponse, ServiceError> {
+        let body = request.body.trim().to_string();
+        if body.is_empty() {
+            return Err(ServiceError::ValidationError("Comment body cannot be empty".to_string()));
+        }
+
+        let annotation = self.resolve_target(annotation_id, target_type, target_id, user_id).await?;
+
+        if let Some(parent_id) = request.parent_id {
+            let parent = self.comment_repository
+                .get_by_id(parent_id)
+                .await?
+                .ok_or_else(|| ServiceError::NotFound(format!("Parent comment with id {} not found", parent_id)))?;
+            if parent.target_type != target_type || parent.target_id != target_id {
+                return Err(ServiceError::ValidationError(
+                    "Parent comment belongs to a different target".to_string(),
+                ));
+            }
+            if parent.is_deleted {
+                return Err(ServiceError::ValidationError("Cannot reply to a deleted comment".to_string()));
+            }
+        }
+
+        let mentions = self.resolve_mentions(&annotation, &body).await?;
+
+        let comment = self.comment_repository
+            .create(&NewComment {
+                target_type,
+                target_id,
+                annotation_id: annotation.id,
+                parent_id: request.parent_id,
+                user_id,
+                body,
+                mentions,
+            })
+            .await?;
+
+        Ok(comment.into())
+    }
+
+    /// 코멘트 수정 (작성자만 가능)
+    pub async fn update_comment(
+        &self,
+        comment_id: i32,
+        request: UpdateCommentRequest,
+        user_id: i32,
+    ) -> Result<CommentResponse, ServiceError> {
+        let body = request.body.trim().to_string();
+        if body.is_empty() {
+            return Err(ServiceError::ValidationError("Comment body cannot be empty".to_string()));
+        }
+
+        let (comment, annotation) = self.load_comment(comment_id, user_id).await?;
+        if comment.user_id != user_id {
+            return Err(ServiceError::Unauthorized("Only the author can edit this comment".to_string()));
+        }
+        if comment.is_deleted {
+            return Err(ServiceError::ValidationError("Cannot edit a deleted comment".to_string()));
+        }
+
+        let mentions = self.resolve_mentions(&annotation, &body).await?;
+        let updated = self.comment_repository
+            .update_body(comment_id, user_id, &body, &mentions)
+            .await?;
+
+        Ok(updated.into())
+    }
+
+    /// 코멘트 삭제 (작성자만 가능, 답글 유지를 위해 소프트 삭제)
+    pub async fn delete_comment(&self, comment_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        let (comment, _) = self.load_comment(comment_id, user_id).await?;
+        if comment.user_id != user_id {
+            return Err(ServiceError::Unauthorized("Only the author can delete this comment".to_string()));
+        }
+
+        self.comment_repository.soft_delete(comment_id, user_id).await
+    }
+
+    /// 스레드 해결 / 미해결 상태 변경
+    pub async fn set_resolved(
+        &self,
+        comment_id: i32,
+        request: ResolveCommentRequest,
+        user_id: i32,
+    ) -> Result<CommentResponse, ServiceError> {
+        let (comment, _) = self.load_comment(comment_id, user_id).await?;
+        if comment.parent_id.is_some() {
+            return Err(ServiceError::ValidationError(
+                "Only top-level comments can be resolved".to_string(),
+            ));
+        }
+        if comment.is_deleted {
+            return Err(ServiceError::ValidationError("Cannot resolve a deleted comment".to_string()));
+        }
+
+        let updated = self.comment_repository
+            .set_resolved(comment_id, user_id, request.resolved)
+            .await?;
+
+        Ok(updated.into())
+    }
+
+    /// 코멘트 변경 이력 조회
+    pub async fn get_comment_history(
+        &self,
+        comment_id: i32,
+        user_id: i32,
+    ) -> Result<Vec<CommentHistoryResponse>, ServiceError> {
+        self.load_comment(comment_id, user_id).await?;
+
+        let history = self.comment_repository.get_history(comment_id).await?;
+        Ok(history.into_iter().map(CommentHistoryResponse::from).collect())
+    }
+}
+
+/// 코멘트 가시성 규칙
+///
+/// 프로젝트 멤버이면서, 어노테이션이 공유되었거나 본인이 작성한 경우에만 볼 수 있습니다.
+fn can_view_comments(annotation: &Annotation, user_id: i32, has_project_access: bool) -> bool {
+    has_project_access && (annotation.is_shared || annotation.user_id == user_id)
+}
+
+/// 작성 순으로 정렬된 코멘트 목록을 스레드(트리) 구조로 변환
+fn build_threads(comments: Vec<Comment>) -> Vec<CommentResponse> {
+    let mut children: HashMap<Option<i32>, Vec<Comment>> = HashMap::new();
+    for comment in comments {
+        children.entry(comment.parent_id).or_default().push(comment);
+    }
+
+    fn attach(comment: Comment, children: &mut HashMap<Option<i32>, Vec<Comment>>) -> CommentResponse {
+        let replies = children.remove(&Some(comment.id)).unwrap_or_default();
+        let mut response = CommentResponse::from(comment);
+        response.replies = replies
+            .into_iter()
+            .map(|reply| attach(reply, children))
+            .collect();
+        response
+    }
+
+    let roots = children.remove(&None).unwrap_or_default();
+    roots
+        .into_iter()
+        .map(|root| attach(root, &mut children))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn comment(id: i32, parent_id: Option<i32>) -> Comment {
+        let created_at = Utc.timestamp_opt(1_700_000_000 + id as i64, 0).unwrap();
+        Comment {
+            id,
+            target_type: CommentTargetType::Annotation,
+            target_id: 1,
+            annotation_id: 1,
+            parent_id,
+            user_id: 1,
+            body: format!("comment {}", id),
+            mentions: Vec::new(),
+            is_resolved: false,
+            resolved_by: None,
+            resolved_at: None,
+            is_deleted: false,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    fn annotation(user_id: i32, is_shared: bool) -> Annotation {
+        let now = Utc::now();
+        Annotation {
+            id: 1,
+            project_id: 1,
+            user_id,
+            study_uid: "1.2.3".to_string(),
+            series_uid: None,
+            instance_uid: None,
+            tool_name: "manual".to_string(),
+            tool_version: None,
+            data: serde_json::json!({}),
+            is_shared,
+            created_at: now,
+            updated_at: now,
+            viewer_software: None,
+            description: None,
+            measurement_values: None,
+            review_status: crate::domain::entities::AnnotationReviewStatus::Draft,
+            reviewer_id: None,
+            reviewed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_build_threads_nests_replies_in_order() {
+        let threads = build_threads(vec![
+            comment(1, None),
+            comment(2, Some(1)),
+            comment(3, None),
+            comment(4, Some(2)),
+            comment(5, Some(1)),
+        ]);
+
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].id, 1);
+        assert_eq!(threads[0].replies.iter().map(|r| r.id).collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(threads[0].replies[0].replies[0].id, 4);
+        assert_eq!(threads[1].id, 3);
+        assert!(threads[1].replies.is_empty());
+    }
+
+    #[test]
+    fn test_can_view_comments_follows_annotation_sharing() {
+        // 공유된 어노테이션은 프로젝트 멤버 모두 조회 가능
+        assert!(can_view_comments(&annotation(1, true), 2, true));
+        // 공유되지 않은 어노테이션은 작성자만 조회 가능
+        assert!(can_view_comments(&annotation(1, false), 1, true));
+        assert!(!can_view_comments(&annotation(1, false), 2, true));
+        // 프로젝트 멤버가 아니면 조회 불가
+        assert!(!can_view_comments(&annotation(1, true), 2, false));
+    }
+}
@@ -0,0 +1,432 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use crate::application::dto::dataset_export_dto::{
+    CreateDatasetExportRequest, DatasetExportJobListResponse, DatasetExportJobResponse,
+};
+use crate::application::services::{ObjectStorageError, ObjectStorageService, SignedUrlRequest, SignedUrlService};
+use crate::domain::entities::{
+    annotation_polygon, polygon_area, polygon_bbox, CocoAnnotation, CocoDatasetBuilder, CocoRle,
+    CocoSegmentation, DatasetExportCounts, DatasetExportFormat, DatasetExportJob, DatasetExportStatus,
+    DatasetManifestRow, DatasetMaskRow, NewDatasetExportJob,
+};
+use crate::domain::repositories::DatasetExportRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::imaging;
+
+/// ML 학습용 데이터셋 내보내기 유스케이스
+///
+/// 내보내기 요청 시 작업을 생성하고 즉시 반환하며, 실제 처리는 백그라운드 태스크에서 수행합니다.
+/// 처리 과정:
+/// 1. 필터(라벨, 모델 이름, 검토 상태)에 맞는 어노테이션과 마스크 슬라이스 조회
+/// 2. COCO JSON 또는 슬라이스 단위 CSV/JSON 매니페스트 생성
+///    (COCO는 어노테이션 `data`를 폴리곤으로, 마스크 PNG를 RLE로 변환)
+/// 3. 결과 파일을 Object Storage에 업로드하고 작업 레코드에 경로 기록
+pub struct DatasetExportUseCase<SUS, DER>
+where
+    SUS: SignedUrlService + Send + Sync,
+    DER: DatasetExportRepository + Send + Sync,
+{
+    signed_url_service: Arc<SUS>,
+    export_repository: Arc<DER>,
+    object_storage: Arc<dyn ObjectStorageService>,
+}
+
+impl<SUS, DER> Clone for DatasetExportUseCase<SUS, DER>
+where
+    SUS: SignedUrlService + Send + Sync,
+    DER: DatasetExportRepository + Send + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            signed_url_service: self.signed_url_service.clone(),
+            export_repository: self.export_repository.clone(),
+            object_storage: self.object_storage.clone(),
+        }
+    }
+}
+
+impl<SUS, DER> DatasetExportUseCase<SUS, DER>
+where
+    SUS: SignedUrlService + Send + Sync + 'static,
+    DER: DatasetExportRepository + Send + Sync + 'static,
+{
+    pub fn new(
+        signed_url_service: Arc<SUS>,
+        export_repository: Arc<DER>,
+        object_storage: Arc<dyn ObjectStorageService>,
+    ) -> Self {
+        Self {
+            signed_url_service,
+            export_repository,
+            object_storage,
+        }
+    }
+
+    /// 프로젝트 존재 여부와 사용자의 프로젝트 멤버 여부 확인
+    async fn ensure_can_export(&self, project_id: i32, user_id: i32) -> Result<String, ServiceError> {
+        let project_name = self.export_repository
+            .find_project_name(project_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Project with ID {} not found", project_id)))?;
+
+        if !self.export_repository.is_project_member(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} cannot export datasets of project {}",
+                user_id, project_id
+            )));
+        }
+
+        Ok(project_name)
+    }
+
+    /// 내보내기 작업 생성 및 백그라운드 실행
+    pub async fn start_export(
+        &self,
+        project_id: i32,
+        request: CreateDatasetExportRequest,
+        user_id: i32,
+    ) -> Result<DatasetExportJobResponse, ServiceError> {
+        let project_name = self.ensure_can_export(project_id, user_id).await?;
+
+        let url_ttl_seconds = request
+            .url_ttl_seconds
+            .map(|ttl| match i32::try_from(ttl) {
+                Ok(ttl) if ttl > 0 => Ok(ttl),
+                _ => Err(ServiceError::ValidationError(format!("Invalid URL TTL: {}", ttl))),
+            })
+            .transpose()?;
+
+        let job = self.export_repository
+            .create(&NewDatasetExportJob {
+                project_id,
+                format: request.format,
+                filter: request.filter(),
+                include_signed_urls: request.include_signed_urls.unwrap_or(false),
+                url_ttl_seconds,
+                requested_by: Some(user_id),
+            })
+            .await?;
+
+        let worker = self.clone();
+        let background_job = job.clone();
+        tokio::spawn(async move {
+            worker.run_export(background_job, project_name).await;
+        });
+
+        Ok(job.into())
+    }
+
+    /// 내보내기 작업 조회. 완료된 작업은 결과 파일 다운로드 URL을 포함합니다.
+    pub async fn get_export_job(
+        &self,
+        project_id: i32,
+        job_id: i32,
+        user_id: i32,
+    ) -> Result<DatasetExportJobResponse, ServiceError> {
+        self.ensure_can_export(project_id, user_id).await?;
+
+        let job = self.export_repository
+            .get_by_id(job_id)
+            .await?
+            .filter(|job| job.project_id == project_id)
+            .ok_or_else(|| ServiceError::NotFound(format!("Dataset export job with ID {} not found", job_id)))?;
+
+        let download_url = match (&job.status, &job.output_path) {
+            (DatasetExportStatus::Completed, Some(output_path)) => {
+                let request = SignedUrlRequest::new(output_path.clone());
+                Some(self.signed_url_service.generate_download_url(request).await?.url)
+            }
+            _ => None,
+        };
+
+        let mut response = DatasetExportJobResponse::from(job);
+        response.download_url = download_url;
+        Ok(response)
+    }
+
+    /// 프로젝트의 내보내기 작업 목록 조회
+    pub async fn list_export_jobs(
+        &self,
+        project_id: i32,
+        user_id: i32,
+    ) -> Result<DatasetExportJobListResponse, ServiceError> {
+        self.ensure_can_export(project_id, user_id).await?;
+
+        let jobs: Vec<DatasetExportJobResponse> = self.export_repository
+            .list_by_project(project_id)
+            .await?
+            .into_iter()
+            .map(DatasetExportJobResponse::from)
+            .collect();
+
+        Ok(DatasetExportJobListResponse {
+            total_count: jobs.len() as i64,
+            jobs,
+        })
+    }
+
+    /// 백그라운드 내보내기 실행. 결과는 작업 레코드에 기록됩니다.
+    async fn run_export(&self, job: DatasetExportJob, project_name: String) {
+        if let Err(e) = self.export_repository.mark_running(job.id).await {
+            eprintln!("Failed to start dataset export job {}: {}", job.id, e);
+            return;
+        }
+
+        if let Err(e) = self.process_export(&job, &project_name).await {
+            eprintln!("Dataset export job {} failed: {}", job.id, e);
+            if let Err(e) = self.export_repository.mark_failed(job.id, &e.to_string()).await {
+                eprintln!("Failed to record dataset export job {} failure: {}", job.id, e);
+            }
+        }
+    }
+
+    async fn process_export(&self, job: &DatasetExportJob, project_name: &str) -> Result<(), ServiceError> {
+        let format = DatasetExportFormat::parse(&job.format).map_err(ServiceError::ValidationError)?;
+
+        let (content, counts) = match format {
+            DatasetExportFormat::Coco => self.build_coco(job, project_name).await?,
+            DatasetExportFormat::Csv | DatasetExportFormat::Json => self.build_manifest(job, format).await?,
+        };
+
+        let output_path = format!(
+            "exports/project_{}/dataset_{}.{}",
+            job.project_id,
+            job.id,
+            format.file_extension()
+        );
+        self.object_storage
+            .upload_file(&output_path, content, Some(format.content_type()))
+            .await
+            .map_err(|e| ServiceError::ExternalServiceError(format!("Failed to upload dataset export: {}", e)))?;
+
+        self.export_repository.mark_completed(job.id, &output_path, counts).await?;
+        Ok(())
+    }
+
+    /// 요청한 경우 마스크 다운로드 Signed URL 생성
+    async fn mask_url(&self, job: &DatasetExportJob, file_path: &str) -> Result<Option<String>, ServiceError> {
+        if !job.include_signed_urls {
+            return Ok(None);
+        }
+        let ttl_seconds = job.url_ttl_seconds.map(|ttl| ttl as u64);
+        let signed_url = self.signed_url_service
+            .generate_mask_download_url(file_path.to_string(), ttl_seconds)
+            .await?;
+        Ok(Some(signed_url.url))
+    }
+
+    /// 슬라이스 단위 CSV/JSON 매니페스트 생성
+    async fn build_manifest(
+        &self,
+        job: &DatasetExportJob,
+        format: DatasetExportFormat,
+    ) -> Result<(Vec<u8>, DatasetExportCounts), ServiceError> {
+        let masks = self.export_repository
+            .find_masks(job.project_id, &job.filter(), job.requested_by)
+            .await?;
+
+        let mut rows = Vec::with_capacity(masks.len());
+        for mask in &masks {
+            let download_url = self.mask_url(job, &mask.file_path).await?;
+            rows.push(DatasetManifestRow::from_mask(mask, download_url));
+        }
+
+        let annotation_ids: HashSet<i32> = rows.iter().map(|row| row.annotation_id).collect();
+        let counts = DatasetExportCounts {
+            annotations: annotation_ids.len() as i32,
+            masks: rows.len() as i32,
+            skipped: 0,
+        };
+
+        let content = match format {
+            DatasetExportFormat::Csv => manifest_csv(&rows),
+            _ => serde_json::to_vec(&rows)
+                .map_err(|e| ServiceError::ExternalServiceError(format!("Failed to serialize manifest: {}", e)))?,
+        };
+        Ok((content, counts))
+    }
+
+    /// COCO JSON 생성
+    ///
+    /// 어노테이션은 `instance_uid`와 면적이 있는 도형이어야 하고, 마스크는 `sop_instance_uid`와
+    /// 전경 픽셀이 있어야 합니다. 조건을 만족하지 않는 항목은 제외하고 개수만 기록합니다.
+    async fn build_coco(
+        &self,
+        job: &DatasetExportJob,
+        project_name: &str,
+    ) -> Result<(Vec<u8>, DatasetExportCounts), ServiceError> {
+        let filter = job.filter();
+        let mut builder = CocoDatasetBuilder::new();
+        let mut counts = DatasetExportCounts::default();
+
+        let annotations = self.export_repository
+            .find_annotations(job.project_id, &filter, job.requested_by)
+            .await?;
+        for annotation in &annotations {
+            let (Some(instance_uid), Some(polygon)) = (&annotation.instance_uid, annotation_polygon(&annotation.data)) else {
+                counts.skipped += 1;
+                continue;
+            };
+
+            let image_id = builder.image_id(instance_uid, &annotation.study_uid, annotation.series_uid.as_deref(), None);
+            let category_id = builder.category_id(&annotation.label(), "annotation");
+            builder.push_annotation(CocoAnnotation {
+                id: 0,
+                image_id,
+                category_id,
+                area: polygon_area(&polygon),
+                bbox: polygon_bbox(&polygon),
+                segmentation: CocoSegmentation::Polygon(vec![polygon]),
+                iscrowd: 0,
+                annotation_id: annotation.annotation_id,
+                mask_group_id: None,
+                mask_id: None,
+                model_name: None,
+                mask_object_key: None,
+                mask_url: None,
+            });
+            counts.annotations += 1;
+        }
+
+        let masks = self.export_repository
+            .find_masks(job.project_id, &filter, job.requested_by)
+            .await?;
+        for mask in &masks {
+            let Some(sop_instance_uid) = mask.sop_instance_uid.clone() else {
+                counts.skipped += 1;
+                continue;
+            };
+            let Some((width, height, pixels)) = self.load_mask_pixels(mask).await? else {
+                counts.skipped += 1;
+                continue;
+            };
+            let Some((bbox, area)) = imaging::mask_bounding_box(width, height, &pixels) else {
+                counts.skipped += 1;
+                continue;
+            };
+
+            let image_id = builder.image_id(
+                &sop_instance_uid,
+                &mask.study_uid,
+                mask.series_uid.as_deref(),
+                Some((width as i32, height as i32)),
+            );
+            let category_id = builder.category_id(&mask.label(), "mask");
+            let mask_url = self.mask_url(job, &mask.file_path).await?;
+            builder.push_annotation(CocoAnnotation {
+                id: 0,
+                image_id,
+                category_id,
+                segmentation: CocoSegmentation::Rle(CocoRle {
+                    counts: imaging::encode_column_major_rle(width, height, &pixels),
+                    size: [height, width],
+                }),
+                area: area as f64,
+                bbox,
+                iscrowd: 1,
+                annotation_id: mask.annotation_id,
+                mask_group_id: Some(mask.mask_group_id),
+                mask_id: Some(mask.mask_id),
+                model_name: mask.model_name.clone(),
+                mask_object_key: Some(mask.file_path.clone()),
+                mask_url,
+            });
+            counts.masks += 1;
+        }
+
+        let dataset = builder.build(format!("{} dataset export #{}", project_name, job.id));
+        let content = serde_json::to_vec(&dataset)
+            .map_err(|e| ServiceError::ExternalServiceError(format!("Failed to serialize COCO dataset: {}", e)))?;
+        Ok((content, counts))
+    }
+
+    /// 마스크 PNG를 내려받아 이진 마스크로 디코딩합니다.
+    /// 스토리지에 없거나 디코딩할 수 없는 파일은 `None`을 반환하고, 그 밖의 스토리지 오류는 전파합니다.
+    async fn load_mask_pixels(&self, mask: &DatasetMaskRow) -> Result<Option<(u32, u32, Vec<u8>)>, ServiceError> {
+        let data = match self.object_storage.download_file(&mask.file_path).await {
+            Ok(data) => data,
+            Err(ObjectStorageError::FileNotFound(_)) => {
+                eprintln!("Skipping mask {} in dataset export: file not found: {}", mask.mask_id, mask.file_path);
+                return Ok(None);
+            }
+            Err(e) => {
+                return Err(ServiceError::ExternalServiceError(format!(
+                    "Failed to download mask {}: {}",
+                    mask.file_path, e
+                )));
+            }
+        };
+
+        let decoded = tokio::task::spawn_blocking(move || imaging::decode_binary_mask_png(&data))
+            .await
+            .map_err(|e| ServiceError::ExternalServiceError(format!("Mask decoding task failed: {}", e)))?;
+
+        match decoded {
+            Ok(mask_pixels) => Ok(Some(mask_pixels)),
+            Err(e) => {
+                eprintln!("Skipping mask {} in dataset export: {}", mask.mask_id, e);
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// 매니페스트 행을 CSV로 직렬화합니다 (RFC 4180 인용 규칙).
+fn manifest_csv(rows: &[DatasetManifestRow]) -> Vec<u8> {
+    let mut out = String::new();
+    push_csv_line(&mut out, DatasetManifestRow::CSV_COLUMNS.iter().map(|c| c.to_string()));
+    for row in rows {
+        push_csv_line(&mut out, row.csv_fields().into_iter());
+    }
+    out.into_bytes()
+}
+
+fn push_csv_line(out: &mut String, fields: impl Iterator<Item = String>) {
+    let line: Vec<String> = fields.map(|field| escape_csv_field(&field)).collect();
+    out.push_str(&line.join(","));
+    out.push_str("\r\n");
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::AnnotationReviewStatus;
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("liver"), "liver");
+        assert_eq!(escape_csv_field("left, right"), "\"left, right\"");
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_manifest_csv_has_header_and_rows() {
+        let row = DatasetManifestRow {
+            annotation_id: 1,
+            mask_group_id: 2,
+            mask_id: 3,
+            study_uid: "1.2.3".to_string(),
+            series_uid: Some("1.2.3.1".to_string()),
+            sop_instance_uid: Some("1.2.3.1.1".to_string()),
+            slice_index: Some(0),
+            label: "kidney, left".to_string(),
+            model_name: None,
+            review_status: AnnotationReviewStatus::Draft,
+            object_key: "masks/a.png".to_string(),
+            download_url: None,
+        };
+
+        let csv = String::from_utf8(manifest_csv(&[row])).unwrap();
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], DatasetManifestRow::CSV_COLUMNS.join(","));
+        assert_eq!(lines[1], "1,2,3,1.2.3,1.2.3.1,1.2.3.1.1,0,\"kidney, left\",,DRAFT,masks/a.png,");
+    }
+}
@@ -0,0 +1,322 @@
+use std::sync::Arc;
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use crate::application::dto::dataset_release_dto::{
+    CreateDatasetReleaseRequest, DatasetReleaseDiffResponse, DatasetReleaseListResponse, DatasetReleaseResponse,
+};
+use crate::application::services::{ObjectStorageService, SignedUrlRequest, SignedUrlService};
+use crate::domain::entities::{
+    DatasetRelease, DatasetReleaseManifest, NewDatasetRelease, ReleaseItemDiff, ReleaseMaskSnapshot,
+    ReleaseMaskSource, RELEASE_MANIFEST_VERSION,
+};
+use crate::domain::repositories::DatasetReleaseRepository;
+use crate::domain::ServiceError;
+
+/// 릴리스 이름 최대 길이
+const MAX_RELEASE_NAME_LENGTH: usize = 200;
+
+/// 데이터셋 릴리스(불변 스냅샷) 유스케이스
+///
+/// 릴리스 생성 과정:
+/// 1. 선택 조건에 맞는 어노테이션의 현재 버전을 히스토리 ID로 고정 (필요한 스냅샷 히스토리는 ID만 예약)
+/// 2. 마스크 파일을 릴리스 전용 경로로 복사하고 체크섬으로 고정
+/// 3. 매니페스트 JSON을 Object Storage에 업로드
+/// 4. 스냅샷 히스토리, 릴리스, 고정 항목을 한 트랜잭션으로 저장
+///
+/// 2~4단계 중 하나라도 실패하면 릴리스 경로에 올린 오브젝트를 삭제하므로, 실패한 생성은 흔적을 남기지 않습니다.
+///
+/// 릴리스는 생성 후 수정할 수 없으며, 두 릴리스의 고정 항목을 비교할 수 있습니다.
+pub struct DatasetReleaseUseCase<SUS, DRR>
+where
+    SUS: SignedUrlService + Send + Sync,
+    DRR: DatasetReleaseRepository + Send + Sync,
+{
+    signed_url_service: Arc<SUS>,
+    release_repository: Arc<DRR>,
+    object_storage: Arc<dyn ObjectStorageService>,
+}
+
+impl<SUS, DRR> DatasetReleaseUseCase<SUS, DRR>
+where
+    SUS: SignedUrlService + Send + Sync,
+    DRR: DatasetReleaseRepository + Send + Sync,
+{
+    pub fn new(
+        signed_url_service: Arc<SUS>,
+        release_repository: Arc<DRR>,
+        object_storage: Arc<dyn ObjectStorageService>,
+    ) -> Self {
+        Self {
+            signed_url_service,
+            release_repository,
+            object_storage,
+        }
+    }
+
+    /// 프로젝트 존재 여부와 사용자의 프로젝트 멤버 여부 확인
+    async fn ensure_member(&self, project_id: i32, user_id: i32) -> Result<String, ServiceError> {
+        let project_name = self.release_repository
+            .find_project_name(project_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Project with ID {} not found", project_id)))?;
+
+        if !self.release_repository.is_project_member(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+
+        Ok(project_name)
+    }
+
+    /// 프로젝트에 속한 릴리스 조회
+    async fn find_release(&self, project_id: i32, release_id: i32) -> Result<DatasetRelease, ServiceError> {
+        self.release_repository
+            .get_by_id(release_id)
+            .await?
+            .filter(|release| release.project_id == project_id)
+            .ok_or_else(|| ServiceError::NotFound(format!("Dataset release with ID {} not found", release_id)))
+    }
+
+    /// 릴리스 생성
+    pub async fn create_release(
+        &self,
+        project_id: i32,
+        request: CreateDatasetReleaseRequest,
+        user_id: i32,
+    ) -> Result<DatasetReleaseResponse, ServiceError> {
+        let name = request.name.trim().to_string();
+        if name.is_empty() || name.len() > MAX_RELEASE_NAME_LENGTH {
+            return Err(ServiceError::ValidationError(format!(
+                "Release name must be between 1 and {} characters",
+                MAX_RELEASE_NAME_LENGTH
+            )));
+        }
+
+        let project_name = self.ensure_member(project_id, user_id).await?;
+        if self.release_repository.find_by_name(project_id, &name).await?.is_some() {
+            return Err(ServiceError::AlreadyExists(format!(
+                "Dataset release '{}' already exists in project {}",
+                name, project_id
+            )));
+        }
+
+        let filter = request.filter();
+        let annotation_ids = request.annotation_ids.as_deref();
+        let annotations = self.release_repository
+            .pin_annotations(project_id, &filter, annotation_ids, user_id)
+            .await?;
+        let mask_sources = self.release_repository
+            .find_masks(project_id, &filter, annotation_ids, user_id)
+            .await?;
+        if annotations.is_empty() && mask_sources.is_empty() {
+            return Err(ServiceError::ValidationError(
+                "No annotations or masks match the release selection".to_string(),
+            ));
+        }
+
+        let release_key = Uuid::new_v4();
+        let release_prefix = format!("releases/project_{}/{}", project_id, release_key);
+        let mut staged_keys = Vec::new();
+        let staged = self
+            .stage_release(
+                NewDatasetRelease {
+                    project_id,
+                    name,
+                    description: request.description,
+                    release_key,
+                    manifest_path: format!("{}/manifest.json", release_prefix),
+                    filter,
+                    created_by: Some(user_id),
+                    annotations,
+                    masks: Vec::new(),
+                },
+                project_name,
+                &release_prefix,
+                mask_sources,
+                &mut staged_keys,
+            )
+            .await;
+
+        match staged {
+            Ok(release) => Ok(release.into()),
+            Err(e) => {
+                self.discard_staged_objects(&staged_keys).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// 마스크 사본과 매니페스트를 릴리스 경로에 올린 뒤 릴리스를 저장합니다.
+    ///
+    /// 업로드에 성공한 오브젝트 키는 실패 시 정리할 수 있도록 `staged_keys`에 기록됩니다.
+    async fn stage_release(
+        &self,
+        mut release: NewDatasetRelease,
+        project_name: String,
+        release_prefix: &str,
+        mask_sources: Vec<ReleaseMaskSource>,
+        staged_keys: &mut Vec<String>,
+    ) -> Result<DatasetRelease, ServiceError> {
+        for source in mask_sources {
+            let mask = self.copy_mask(release_prefix, source).await?;
+            staged_keys.push(mask.object_key.clone());
+            release.masks.push(mask);
+        }
+
+        let manifest = DatasetReleaseManifest {
+            format_version: RELEASE_MANIFEST_VERSION,
+            project_id: release.project_id,
+            project_name,
+            name: release.name.clone(),
+            description: release.description.clone(),
+            release_key: release.release_key,
+            filter: release.filter.clone(),
+            created_by: release.created_by,
+            created_at: Utc::now(),
+            annotations: release.annotations.clone(),
+            masks: release.masks.clone(),
+        };
+        let manifest_json = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| ServiceError::ExternalServiceError(format!("Failed to serialize release manifest: {}", e)))?;
+        self.object_storage
+            .upload_file(&release.manifest_path, manifest_json, Some("application/json"))
+            .await
+            .map_err(|e| ServiceError::ExternalServiceError(format!("Failed to upload release manifest: {}", e)))?;
+        staged_keys.push(release.manifest_path.clone());
+
+        self.release_repository.create(&release).await
+    }
+
+    /// 실패한 릴리스 생성에서 올린 오브젝트 삭제 (삭제 실패는 로그만 남김)
+    async fn discard_staged_objects(&self, staged_keys: &[String]) {
+        for key in staged_keys {
+            if let Err(e) = self.object_storage.delete_file(key).await {
+                eprintln!("Failed to delete staged release object {}: {}", key, e);
+            }
+        }
+    }
+
+    /// 마스크 파일을 릴리스 전용 경로로 복사합니다.
+    ///
+    /// 체크섬이 기록된 마스크는 스토리지 내에서 복사하고, 없으면 내려받아 체크섬을 계산한 뒤 업로드합니다.
+    async fn copy_mask(&self, release_prefix: &str, source: ReleaseMaskSource) -> Result<ReleaseMaskSnapshot, ServiceError> {
+        let file_name = source.file_path.rsplit('/').next().unwrap_or("mask");
+        let object_key = format!("{}/masks/{}_{}", release_prefix, source.mask_id, file_name);
+
+        let checksum = match source.checksum.clone().filter(|c| !c.is_empty()) {
+            Some(checksum) => {
+                self.object_storage
+                    .copy_file(&source.file_path, &object_key)
+                    .await
+                    .map_err(|e| ServiceError::ExternalServiceError(format!(
+                        "Failed to copy mask {} into release: {}",
+                        source.file_path, e
+                    )))?;
+                checksum
+            }
+            None => {
+                let data = self.object_storage
+                    .download_file(&source.file_path)
+                    .await
+                    .map_err(|e| ServiceError::ExternalServiceError(format!(
+                        "Failed to download mask {}: {}",
+                        source.file_path, e
+                    )))?;
+                let checksum = hex::encode(Sha256::digest(&data));
+                self.object_storage
+                    .upload_file(&object_key, data, Some("image/png"))
+                    .await
+                    .map_err(|e| ServiceError::ExternalServiceError(format!(
+                        "Failed to store mask {} into release: {}",
+                        source.file_path, e
+                    )))?;
+                checksum
+            }
+        };
+
+        Ok(ReleaseMaskSnapshot {
+            mask_id: source.mask_id,
+            mask_group_id: source.mask_group_id,
+            annotation_id: source.annotation_id,
+            sop_instance_uid: source.sop_instance_uid,
+            slice_index: source.slice_index,
+            label_name: source.label_name,
+            checksum,
+            source_path: source.file_path,
+            object_key,
+        })
+    }
+
+    /// 릴리스 조회 (매니페스트 다운로드 URL 포함)
+    pub async fn get_release(
+        &self,
+        project_id: i32,
+        release_id: i32,
+        user_id: i32,
+    ) -> Result<DatasetReleaseResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        let release = self.find_release(project_id, release_id).await?;
+
+        let manifest_url = self.signed_url_service
+            .generate_download_url(SignedUrlRequest::new(release.manifest_path.clone()))
+            .await?
+            .url;
+
+        let mut response = DatasetReleaseResponse::from(release);
+        response.manifest_url = Some(manifest_url);
+        Ok(response)
+    }
+
+    /// 프로젝트의 릴리스 목록 조회
+    pub async fn list_releases(
+        &self,
+        project_id: i32,
+        user_id: i32,
+    ) -> Result<DatasetReleaseListResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let releases: Vec<DatasetReleaseResponse> = self.release_repository
+            .list_by_project(project_id)
+            .await?
+            .into_iter()
+            .map(DatasetReleaseResponse::from)
+            .collect();
+
+        Ok(DatasetReleaseListResponse {
+            total_count: releases.len() as i64,
+            releases,
+        })
+    }
+
+    /// 기준 릴리스(`base_release_id`) 대비 릴리스(`release_id`)의 변화 비교
+    pub async fn diff_releases(
+        &self,
+        project_id: i32,
+        release_id: i32,
+        base_release_id: i32,
+        user_id: i32,
+    ) -> Result<DatasetReleaseDiffResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        let release = self.find_release(project_id, release_id).await?;
+        let base = self.find_release(project_id, base_release_id).await?;
+
+        let annotations = ReleaseItemDiff::compute(
+            &self.release_repository.get_annotation_pins(base.id).await?,
+            &self.release_repository.get_annotation_pins(release.id).await?,
+        );
+        let masks = ReleaseItemDiff::compute(
+            &self.release_repository.get_mask_pins(base.id).await?,
+            &self.release_repository.get_mask_pins(release.id).await?,
+        );
+
+        Ok(DatasetReleaseDiffResponse {
+            base_release_id: base.id,
+            release_id: release.id,
+            annotations,
+            masks,
+        })
+    }
+}
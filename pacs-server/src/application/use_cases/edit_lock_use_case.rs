@@ -0,0 +1,192 @@
+use std::sync::Arc;
+use crate::application::dto::edit_lock_dto::{
+    AcquireEditLockRequest, EditLockHeartbeatRequest, EditLockListQuery, EditLockListResponse, EditLockResponse,
+};
+use crate::domain::entities::edit_lock::{validate_lock_ttl, EditLock, EditLockAttempt, NewEditLock};
+use crate::domain::entities::NewAccessLog;
+use crate::domain::repositories::{AccessLogRepository, EditLockRepository, ProjectRepository};
+use crate::domain::services::ensure_project_admin;
+use crate::domain::ServiceError;
+
+/// 접근 로그 동작 이름
+const LOG_ACQUIRE: &str = "EDIT_LOCK_ACQUIRE";
+const LOG_RELEASE: &str = "EDIT_LOCK_RELEASE";
+const LOG_FORCE_RELEASE: &str = "EDIT_LOCK_FORCE_RELEASE";
+
+/// Study / 어노테이션 편집 잠금 유스케이스
+///
+/// 프로젝트 멤버는 잠금을 획득하고 heartbeat로 유지하거나 해제할 수 있고, 다른 멤버의 잠금을 조회할 수 있습니다.
+/// 프로젝트 관리자는 다른 사용자의 잠금을 강제로 해제할 수 있습니다.
+/// 잠금 획득(거부 포함)과 해제는 접근 로그에 기록됩니다.
+pub struct EditLockUseCase<ELR, ALR>
+where
+    ELR: EditLockRepository + Send + Sync,
+    ALR: AccessLogRepository + Send + Sync,
+{
+    edit_lock_repository: Arc<ELR>,
+    access_log_repository: Arc<ALR>,
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl<ELR, ALR> EditLockUseCase<ELR, ALR>
+where
+    ELR: EditLockRepository + Send + Sync,
+    ALR: AccessLogRepository + Send + Sync,
+{
+    pub fn new(
+        edit_lock_repository: Arc<ELR>,
+        access_log_repository: Arc<ALR>,
+        project_repository: Arc<dyn ProjectRepository>,
+    ) -> Self {
+        Self { edit_lock_repository, access_log_repository, project_repository }
+    }
+
+    /// 사용자의 프로젝트 멤버 여부 확인
+    async fn ensure_member(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        if !self.edit_lock_repository.is_project_member(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// 사용자가 프로젝트 관리자인지 확인
+    async fn ensure_admin(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        ensure_project_admin(self.project_repository.as_ref(), project_id, user_id, "force-release edit locks").await
+    }
+
+    /// 잠금 동작을 접근 로그에 기록 (기록 실패는 잠금 동작에 영향을 주지 않음)
+    async fn log_lock_action(&self, user_id: i32, lock: &EditLock, action: &str, result: &str) {
+        let new_log = NewAccessLog {
+            user_id,
+            project_id: Some(lock.project_id),
+            resource_type: lock.target_type.resource_type().to_string(),
+            study_uid: Some(lock.study_uid.clone()),
+            series_uid: None,
+            instance_uid: None,
+            action: action.to_string(),
+            result: result.to_string(),
+            dicom_tag_check: None,
+            ae_title: None,
+            ip_address: None,
+            session_id: Some(lock.session_id()),
+            via_group_id: None,
+        };
+        if let Err(e) = self.access_log_repository.create(new_log).await {
+            eprintln!("Failed to write edit lock access log: {}", e);
+        }
+    }
+
+    /// 프로젝트의 유효한 잠금 목록
+    pub async fn list_locks(&self, project_id: i32, query: EditLockListQuery, user_id: i32) -> Result<EditLockListResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let locks = self.edit_lock_repository
+            .list_active(project_id, query.study_uid.as_deref())
+            .await?;
+        Ok(EditLockListResponse {
+            locks: locks.into_iter().map(EditLockResponse::from).collect(),
+        })
+    }
+
+    /// 잠금 획득 (이미 가진 잠금이면 갱신)
+    ///
+    /// 다른 사용자의 잠금과 충돌하면 `AlreadyExists`를 반환합니다.
+    pub async fn acquire_lock(
+        &self,
+        project_id: i32,
+        request: AcquireEditLockRequest,
+        user_id: i32,
+    ) -> Result<EditLockResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        let ttl_seconds = validate_lock_ttl(request.ttl_seconds).map_err(ServiceError::ValidationError)?;
+
+        let study_uid = match (request.study_uid, request.annotation_id) {
+            (Some(study_uid), None) if !study_uid.trim().is_empty() => study_uid.trim().to_string(),
+            (None, Some(annotation_id)) => self.edit_lock_repository
+                .find_annotation_study(project_id, annotation_id)
+                .await?
+                .ok_or_else(|| ServiceError::NotFound(format!(
+                    "Annotation with ID {} not found in project {}",
+                    annotation_id, project_id
+                )))?,
+            _ => {
+                return Err(ServiceError::ValidationError(
+                    "Exactly one of study_uid or annotation_id must be given".to_string(),
+                ))
+            }
+        };
+
+        let new_lock = NewEditLock {
+            project_id,
+            study_uid,
+            annotation_id: request.annotation_id,
+            holder_id: user_id,
+            ttl_seconds,
+        };
+        match self.edit_lock_repository.acquire(&new_lock).await? {
+            EditLockAttempt::Acquired(lock) => {
+                self.log_lock_action(user_id, &lock, LOG_ACQUIRE, "SUCCESS").await;
+                Ok(lock.into())
+            }
+            EditLockAttempt::Renewed(lock) => Ok(lock.into()),
+            EditLockAttempt::Conflict(lock) => {
+                self.log_lock_action(user_id, &lock, LOG_ACQUIRE, "DENIED").await;
+                Err(ServiceError::AlreadyExists(lock.describe()))
+            }
+        }
+    }
+
+    /// 본인 잠금의 만료 시간 연장 (만료된 잠금은 다시 획득해야 함)
+    pub async fn heartbeat(
+        &self,
+        project_id: i32,
+        lock_id: i32,
+        request: EditLockHeartbeatRequest,
+        user_id: i32,
+    ) -> Result<EditLockResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        let ttl_seconds = validate_lock_ttl(request.ttl_seconds).map_err(ServiceError::ValidationError)?;
+
+        match self.edit_lock_repository.heartbeat(project_id, lock_id, user_id, ttl_seconds).await? {
+            Some(lock) => Ok(lock.into()),
+            None => Err(self.not_held_error(project_id, lock_id, user_id).await),
+        }
+    }
+
+    /// 본인 잠금 해제
+    pub async fn release_lock(&self, project_id: i32, lock_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        match self.edit_lock_repository.release(project_id, lock_id, Some(user_id), user_id).await? {
+            Some(lock) => {
+                self.log_lock_action(user_id, &lock, LOG_RELEASE, "SUCCESS").await;
+                Ok(())
+            }
+            None => Err(self.not_held_error(project_id, lock_id, user_id).await),
+        }
+    }
+
+    /// 다른 사용자의 잠금 강제 해제 (프로젝트 관리자)
+    pub async fn force_release_lock(&self, project_id: i32, lock_id: i32, user_id: i32) -> Result<EditLockResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        let lock = self.edit_lock_repository
+            .release(project_id, lock_id, None, user_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Active edit lock with ID {} not found", lock_id)))?;
+        self.log_lock_action(user_id, &lock, LOG_FORCE_RELEASE, "SUCCESS").await;
+        Ok(lock.into())
+    }
+
+    /// 갱신/해제할 잠금이 없거나 다른 사용자의 잠금일 때의 오류
+    async fn not_held_error(&self, project_id: i32, lock_id: i32, user_id: i32) -> ServiceError {
+        match self.edit_lock_repository.find_active(project_id, lock_id).await {
+            Ok(Some(lock)) if lock.holder_id != user_id => ServiceError::Unauthorized(lock.describe()),
+            Ok(_) => ServiceError::NotFound(format!("Active edit lock with ID {} not found or expired", lock_id)),
+            Err(e) => e,
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::sync::Arc;
+use crate::application::dto::label_dto::{
+    AppliedLabelMapping, ApplyLabelMigrationRequest, LabelImportResponse, LabelListResponse, LabelMigrationEntry,
+    LabelMigrationPreviewResponse, LabelMigrationResponse, LabelMigrationStatus, LabelRequest, LabelResponse,
+};
+use crate::domain::entities::label::{parse_label_csv, LabelTaxonomy, NewLabel};
+use crate::domain::repositories::{LabelRepository, ProjectRepository};
+use crate::domain::services::ensure_project_admin;
+use crate::domain::ServiceError;
+
+/// 프로젝트 라벨 분류 체계 유스케이스
+///
+/// 프로젝트 멤버는 분류 체계를 조회할 수 있고, 프로젝트 관리자는 라벨을 추가/수정/삭제하거나
+/// CSV로 가져오고, 기존 자유 입력 라벨을 정식 라벨로 일괄 변경할 수 있습니다.
+pub struct LabelUseCase<LR>
+where
+    LR: LabelRepository + Send + Sync,
+{
+    label_repository: Arc<LR>,
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl<LR> LabelUseCase<LR>
+where
+    LR: LabelRepository + Send + Sync,
+{
+    pub fn new(label_repository: Arc<LR>, project_repository: Arc<dyn ProjectRepository>) -> Self {
+        Self { label_repository, project_repository }
+    }
+
+    /// 사용자의 프로젝트 멤버 여부 확인
+    async fn ensure_member(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        if !self.label_repository.is_project_member(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// 사용자가 프로젝트 관리자인지 확인
+    async fn ensure_admin(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        ensure_project_admin(self.project_repository.as_ref(), project_id, user_id, "manage the label taxonomy").await
+    }
+
+    async fn load_taxonomy(&self, project_id: i32) -> Result<LabelTaxonomy, ServiceError> {
+        Ok(LabelTaxonomy::new(self.label_repository.list_by_project(project_id).await?))
+    }
+
+    async fn label_list(&self, project_id: i32) -> Result<Vec<LabelResponse>, ServiceError> {
+        Ok(self.label_repository
+            .list_by_project(project_id)
+            .await?
+            .into_iter()
+            .map(LabelResponse::from)
+            .collect())
+    }
+
+    /// 프로젝트 라벨 목록 조회
+    pub async fn list_labels(&self, project_id: i32, user_id: i32) -> Result<LabelListResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        Ok(LabelListResponse { labels: self.label_list(project_id).await? })
+    }
+
+    /// 라벨 추가
+    pub async fn create_label(&self, project_id: i32, request: LabelRequest, user_id: i32) -> Result<LabelResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+        let (definition, parent_id) = request.into_parts();
+        let definition = definition.normalize().map_err(ServiceError::ValidationError)?;
+
+        self.load_taxonomy(project_id)
+            .await?
+            .check_create(&definition, parent_id)
+            .map_err(ServiceError::ValidationError)?;
+
+        let label = self.label_repository
+            .create(&NewLabel { project_id, parent_id, definition })
+            .await?;
+        Ok(label.into())
+    }
+
+    /// 라벨 수정 (전체 필드 교체)
+    pub async fn update_label(
+        &self,
+        project_id: i32,
+        label_id: i32,
+        request: LabelRequest,
+        user_id: i32,
+    ) -> Result<LabelResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+        let (definition, parent_id) = request.into_parts();
+        let definition = definition.normalize().map_err(ServiceError::ValidationError)?;
+
+        let taxonomy = self.load_taxonomy(project_id).await?;
+        if taxonomy.get(label_id).is_none() {
+            return Err(ServiceError::NotFound(format!("Label with ID {} not found", label_id)));
+        }
+        taxonomy
+            .check_update(label_id, &definition, parent_id)
+            .map_err(ServiceError::ValidationError)?;
+
+        self.label_repository
+            .update(project_id, label_id, &definition, parent_id)
+            .await?
+            .map(LabelResponse::from)
+            .ok_or_else(|| ServiceError::NotFound(format!("Label with ID {} not found", label_id)))
+    }
+
+    /// 라벨 삭제 (하위 라벨이 있으면 삭제할 수 없음)
+    ///
+    /// 이미 이 라벨을 사용하는 마스크/어노테이션의 라벨 값은 그대로 남습니다.
+    pub async fn delete_label(&self, project_id: i32, label_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        let taxonomy = self.load_taxonomy(project_id).await?;
+        let label = taxonomy
+            .get(label_id)
+            .ok_or_else(|| ServiceError::NotFound(format!("Label with ID {} not found", label_id)))?;
+        if taxonomy.has_children(label_id) {
+            return Err(ServiceError::ValidationError(format!(
+                "Label '{}' has child labels; delete or move them first",
+                label.name
+            )));
+        }
+
+        if !self.label_repository.delete(project_id, label_id).await? {
+            return Err(ServiceError::NotFound(format!("Label with ID {} not found", label_id)));
+        }
+        Ok(())
+    }
+
+    /// CSV로 라벨 가져오기
+    ///
+    /// 이름이 같은 기존 라벨(대소문자/구분자 무시)은 CSV 값으로 덮어쓰고, 나머지는 새로 추가합니다.
+    /// 가져온 뒤의 분류 체계가 유효하지 않으면 아무것도 저장하지 않습니다.
+    pub async fn import_csv(&self, project_id: i32, csv: &str, user_id: i32) -> Result<LabelImportResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        let rows = parse_label_csv(csv).map_err(ServiceError::ValidationError)?;
+        if rows.is_empty() {
+            return Err(ServiceError::ValidationError("CSV contains no labels".to_string()));
+        }
+
+        let plan = self.load_taxonomy(project_id)
+            .await?
+            .plan_import(rows)
+            .map_err(ServiceError::ValidationError)?;
+        self.label_repository.import(project_id, &plan.items).await?;
+
+        Ok(LabelImportResponse {
+            created: plan.summary.created,
+            updated: plan.summary.updated,
+            labels: self.label_list(project_id).await?,
+        })
+    }
+
+    /// 사용 중인 라벨과 분류 체계 매핑 제안 조회
+    pub async fn preview_migration(&self, project_id: i32, user_id: i32) -> Result<LabelMigrationPreviewResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        let taxonomy = self.load_taxonomy(project_id).await?;
+
+        let entries = self.label_repository
+            .list_usage(project_id)
+            .await?
+            .into_iter()
+            .map(|usage| {
+                let suggestion = taxonomy.suggest(&usage.label);
+                let status = match suggestion {
+                    Some(label) if label.name == usage.label => LabelMigrationStatus::Canonical,
+                    Some(_) => LabelMigrationStatus::Suggested,
+                    None => LabelMigrationStatus::Unmapped,
+                };
+                LabelMigrationEntry {
+                    label: usage.label,
+                    mask_count: usage.mask_count,
+                    annotation_count: usage.annotation_count,
+                    status,
+                    suggested_label_id: suggestion.map(|label| label.id),
+                    suggested_label: suggestion.map(|label| label.name.clone()),
+                }
+            })
+            .collect();
+
+        Ok(LabelMigrationPreviewResponse { entries })
+    }
+
+    /// 자유 입력 라벨을 정식 라벨 이름으로 일괄 변경
+    pub async fn apply_migration(
+        &self,
+        project_id: i32,
+        request: ApplyLabelMigrationRequest,
+        user_id: i32,
+    ) -> Result<LabelMigrationResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+        let taxonomy = self.load_taxonomy(project_id).await?;
+        if taxonomy.is_empty() {
+            return Err(ServiceError::ValidationError(format!(
+                "Project {} has no label taxonomy",
+                project_id
+            )));
+        }
+
+        let mut mappings: Vec<(String, String)> = Vec::new();
+        for mapping in request.mappings {
+            if mapping.from.is_empty() {
+                return Err(ServiceError::ValidationError("Mapping source label cannot be empty".to_string()));
+            }
+            if mappings.iter().any(|(from, _)| *from == mapping.from) {
+                return Err(ServiceError::ValidationError(format!(
+                    "Label '{}' is mapped more than once",
+                    mapping.from
+                )));
+            }
+            let label = taxonomy
+                .get(mapping.label_id)
+                .ok_or_else(|| ServiceError::NotFound(format!("Label with ID {} not found", mapping.label_id)))?;
+            mappings.push((mapping.from, label.name.clone()));
+        }
+
+        if request.apply_suggestions {
+            for usage in self.label_repository.list_usage(project_id).await? {
+                if mappings.iter().any(|(from, _)| *from == usage.label) {
+                    continue;
+                }
+                if let Some(label) = taxonomy.suggest(&usage.label) {
+                    mappings.push((usage.label, label.name.clone()));
+                }
+            }
+        }
+        mappings.retain(|(from, to)| from != to);
+
+        let result = self.label_repository
+            .apply_mappings(project_id, &mappings, user_id)
+            .await?;
+
+        Ok(LabelMigrationResponse {
+            mappings: mappings
+                .into_iter()
+                .map(|(from, to)| AppliedLabelMapping { from, to })
+                .collect(),
+            masks_updated: result.masks_updated,
+            annotations_updated: result.annotations_updated,
+        })
+    }
+}
@@ -0,0 +1,312 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+use chrono::Utc;
+use futures::stream::{self, BoxStream, StreamExt};
+use crate::application::dto::mask_group_archive_dto::{MaskArchiveEntry, MaskGroupArchiveManifest, MaskGroupArchiveQuery};
+use crate::application::services::{ObjectStorageError, ObjectStorageService};
+use crate::application::use_cases::mask_import_use_case::sanitize_path_segment;
+use crate::domain::entities::{Annotation, Mask, MaskGroup, NewAccessLog};
+use crate::domain::repositories::{AccessLogRepository, AnnotationRepository};
+use crate::domain::services::MaskGroupService;
+use crate::domain::ServiceError;
+
+/// 한 아카이브에 담을 수 있는 마스크 수 (ZIP64 없이 manifest.json 포함)
+pub const MAX_ARCHIVE_MASKS: usize = u16::MAX as usize - 1;
+
+/// 아카이브 안의 manifest 파일명
+pub const ARCHIVE_MANIFEST_NAME: &str = "manifest.json";
+
+/// 접근 로그 리소스/동작 이름
+const LOG_RESOURCE_TYPE: &str = "MASK_GROUP";
+const LOG_ARCHIVE_DOWNLOAD: &str = "MASK_GROUP_ARCHIVE_DOWNLOAD";
+
+/// 아카이브 항목 (ZIP 안의 경로, 데이터)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchiveEntry {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+/// 마스크 그룹 아카이브
+///
+/// `entries`는 마스크를 하나씩 스토리지에서 읽어 내보내고, 마지막에 `manifest.json`을 내보냅니다.
+pub struct MaskGroupArchive {
+    pub file_name: String,
+    pub mask_count: usize,
+    pub entries: BoxStream<'static, Result<ArchiveEntry, ServiceError>>,
+}
+
+/// 마스크 그룹 ZIP 다운로드 유스케이스
+///
+/// 권한은 그룹 단위로 한 번 확인하고, 다운로드 요청(거부 포함)은 접근 로그에 기록합니다.
+/// 필터에 해당하지만 스토리지에 없는 마스크는 건너뛰고 manifest의 `missing`에 기록합니다.
+pub struct MaskGroupArchiveUseCase<MGS, AR, ALR>
+where
+    MGS: MaskGroupService + Send + Sync,
+    AR: AnnotationRepository + Send + Sync,
+    ALR: AccessLogRepository + Send + Sync,
+{
+    mask_group_service: Arc<MGS>,
+    annotation_repository: Arc<AR>,
+    access_log_repository: Arc<ALR>,
+    object_storage: Arc<dyn ObjectStorageService>,
+}
+
+impl<MGS, AR, ALR> MaskGroupArchiveUseCase<MGS, AR, ALR>
+where
+    MGS: MaskGroupService + Send + Sync,
+    AR: AnnotationRepository + Send + Sync,
+    ALR: AccessLogRepository + Send + Sync,
+{
+    pub fn new(
+        mask_group_service: Arc<MGS>,
+        annotation_repository: Arc<AR>,
+        access_log_repository: Arc<ALR>,
+        object_storage: Arc<dyn ObjectStorageService>,
+    ) -> Self {
+        Self {
+            mask_group_service,
+            annotation_repository,
+            access_log_repository,
+            object_storage,
+        }
+    }
+
+    /// 다운로드 요청을 접근 로그에 기록 (기록 실패는 다운로드에 영향을 주지 않음)
+    async fn log_download(&self, user_id: i32, annotation: &Annotation, result: &str) {
+        let new_log = NewAccessLog {
+            user_id,
+            project_id: Some(annotation.project_id),
+            resource_type: LOG_RESOURCE_TYPE.to_string(),
+            study_uid: Some(annotation.study_uid.clone()),
+            series_uid: annotation.series_uid.clone(),
+            instance_uid: None,
+            action: LOG_ARCHIVE_DOWNLOAD.to_string(),
+            result: result.to_string(),
+            dicom_tag_check: None,
+            ae_title: None,
+            ip_address: None,
+            session_id: None,
+            via_group_id: None,
+        };
+        if let Err(e) = self.access_log_repository.create(new_log).await {
+            eprintln!("Failed to write mask group archive access log: {}", e);
+        }
+    }
+
+    /// 마스크 그룹 아카이브 준비
+    ///
+    /// 권한과 필터를 확인하고 대상 마스크를 정한 뒤, 스토리지 읽기는 반환된 스트림을 소비할 때 이루어집니다.
+    pub async fn prepare_archive(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        query: MaskGroupArchiveQuery,
+        user_id: i32,
+    ) -> Result<MaskGroupArchive, ServiceError> {
+        let not_found = || ServiceError::NotFound(format!(
+            "Mask group {} not found in annotation {}",
+            mask_group_id, annotation_id
+        ));
+        let mask_group = self.mask_group_service
+            .get_mask_group_by_id(mask_group_id)
+            .await?
+            .filter(|group| group.annotation_id == annotation_id)
+            .ok_or_else(not_found)?;
+        let annotation = self.annotation_repository
+            .find_by_id(annotation_id)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to find annotation: {}", e)))?
+            .ok_or_else(not_found)?;
+
+        match self.mask_group_service.can_access_mask_group(user_id, mask_group_id).await {
+            Ok(true) => {}
+            Ok(false) => return Err(not_found()),
+            Err(e) => {
+                if matches!(e, ServiceError::Unauthorized(_)) {
+                    self.log_download(user_id, &annotation, "DENIED").await;
+                }
+                return Err(e);
+            }
+        }
+
+        let labels = parse_labels(query.labels.as_deref());
+        if let (Some(start), Some(end)) = (query.slice_start, query.slice_end) {
+            if start > end {
+                return Err(ServiceError::ValidationError(format!(
+                    "slice_start ({}) must not be greater than slice_end ({})",
+                    start, end
+                )));
+            }
+        }
+
+        let masks: VecDeque<Mask> = self.mask_group_service
+            .get_masks_in_group(mask_group_id)
+            .await?
+            .into_iter()
+            .filter(|mask| matches_filter(mask, &query, &labels))
+            .collect();
+        if masks.len() > MAX_ARCHIVE_MASKS {
+            return Err(ServiceError::ValidationError(format!(
+                "At most {} masks can be archived at once; narrow the slice range or labels",
+                MAX_ARCHIVE_MASKS
+            )));
+        }
+
+        self.log_download(user_id, &annotation, "SUCCESS").await;
+
+        let mask_count = masks.len();
+        let state = ArchiveState {
+            masks,
+            object_storage: self.object_storage.clone(),
+            entry_names: HashSet::new(),
+            manifest: new_manifest(&mask_group, query, user_id),
+        };
+        Ok(MaskGroupArchive {
+            file_name: format!("mask_group_{}.zip", mask_group_id),
+            mask_count,
+            entries: archive_entries(state),
+        })
+    }
+}
+
+/// 쉼표로 구분된 라벨 이름 목록 (비어 있으면 라벨로 거르지 않음)
+fn parse_labels(labels: Option<&str>) -> HashSet<String> {
+    labels
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|label| !label.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 슬라이스 범위를 지정하면 슬라이스 인덱스가 없는 마스크는 제외
+fn matches_filter(mask: &Mask, query: &MaskGroupArchiveQuery, labels: &HashSet<String>) -> bool {
+    let in_range = match (query.slice_start, query.slice_end) {
+        (None, None) => true,
+        (start, end) => mask.slice_index.is_some_and(|index| {
+            start.is_none_or(|start| index >= start) && end.is_none_or(|end| index <= end)
+        }),
+    };
+    let has_label = labels.is_empty() || mask.label_name.as_ref().is_some_and(|label| labels.contains(label));
+    in_range && has_label
+}
+
+fn new_manifest(mask_group: &MaskGroup, filter: MaskGroupArchiveQuery, user_id: i32) -> MaskGroupArchiveManifest {
+    MaskGroupArchiveManifest {
+        mask_group_id: mask_group.id,
+        annotation_id: mask_group.annotation_id,
+        group_name: mask_group.group_name.clone(),
+        modality: mask_group.modality.clone(),
+        mask_type: mask_group.mask_type.clone(),
+        filter,
+        generated_by: user_id,
+        generated_at: Utc::now().to_rfc3339(),
+        masks: Vec::new(),
+        missing: Vec::new(),
+    }
+}
+
+/// 아카이브 스트림 상태
+struct ArchiveState {
+    masks: VecDeque<Mask>,
+    object_storage: Arc<dyn ObjectStorageService>,
+    entry_names: HashSet<String>,
+    manifest: MaskGroupArchiveManifest,
+}
+
+impl ArchiveState {
+    /// 아카이브 안에서 겹치지 않는 마스크 경로
+    fn entry_name(&mut self, mask: &Mask) -> String {
+        let file_name = sanitize_path_segment(mask.file_path.rsplit('/').next().unwrap_or_default());
+        let mut name = if file_name.is_empty() {
+            format!("masks/mask_{}", mask.id)
+        } else {
+            format!("masks/{}", file_name)
+        };
+        if self.entry_names.contains(&name) {
+            name = format!("masks/{}_{}", mask.id, file_name);
+        }
+        self.entry_names.insert(name.clone());
+        name
+    }
+}
+
+/// 마스크를 하나씩 읽어 내보내고 마지막에 manifest를 내보내는 스트림
+fn archive_entries(state: ArchiveState) -> BoxStream<'static, Result<ArchiveEntry, ServiceError>> {
+    stream::unfold(Some(state), |state| async move {
+        let mut state = state?;
+        while let Some(mask) = state.masks.pop_front() {
+            let mut entry = MaskArchiveEntry::from(&mask);
+            match state.object_storage.download_file(&mask.file_path).await {
+                Ok(data) => {
+                    let name = state.entry_name(&mask);
+                    entry.archive_path = Some(name.clone());
+                    state.manifest.masks.push(entry);
+                    return Some((Ok(ArchiveEntry { name, data }), Some(state)));
+                }
+                Err(ObjectStorageError::FileNotFound(_)) => state.manifest.missing.push(entry),
+                Err(e) => {
+                    let error = ServiceError::ExternalServiceError(format!("Failed to read {}: {}", mask.file_path, e));
+                    return Some((Err(error), None));
+                }
+            }
+        }
+
+        let manifest = serde_json::to_vec_pretty(&state.manifest)
+            .map(|data| ArchiveEntry { name: ARCHIVE_MANIFEST_NAME.to_string(), data })
+            .map_err(|e| ServiceError::ExternalServiceError(format!("Failed to encode manifest: {}", e)));
+        Some((manifest, None))
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mask(id: i32, slice_index: Option<i32>, label_name: Option<&str>) -> Mask {
+        Mask {
+            id,
+            mask_group_id: 1,
+            slice_index,
+            sop_instance_uid: None,
+            label_name: label_name.map(str::to_string),
+            file_path: format!("masks/annotation_1/group_1/{}.png", id),
+            mime_type: Some("image/png".to_string()),
+            file_size: None,
+            checksum: None,
+            width: None,
+            height: None,
+            created_at: Utc::now(),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_filters_by_slice_range_and_labels() {
+        let query = MaskGroupArchiveQuery {
+            slice_start: Some(2),
+            slice_end: Some(4),
+            labels: Some(" liver, spleen ,".to_string()),
+        };
+        let labels = parse_labels(query.labels.as_deref());
+        assert_eq!(labels.len(), 2);
+
+        assert!(matches_filter(&mask(1, Some(2), Some("liver")), &query, &labels));
+        assert!(matches_filter(&mask(2, Some(4), Some("spleen")), &query, &labels));
+        assert!(!matches_filter(&mask(3, Some(5), Some("liver")), &query, &labels));
+        assert!(!matches_filter(&mask(4, Some(3), Some("kidney")), &query, &labels));
+        assert!(!matches_filter(&mask(5, None, Some("liver")), &query, &labels));
+
+        // 필터가 없으면 모든 마스크 포함
+        let all = MaskGroupArchiveQuery::default();
+        assert!(matches_filter(&mask(6, None, None), &all, &parse_labels(None)));
+
+        // 한쪽 경계만 지정할 수 있음
+        let from = MaskGroupArchiveQuery { slice_start: Some(3), ..Default::default() };
+        assert!(matches_filter(&mask(7, Some(100), None), &from, &HashSet::new()));
+        assert!(!matches_filter(&mask(8, Some(2), None), &from, &HashSet::new()));
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use crate::application::dto::mask_group_dto::MaskGroupResponse;
+use crate::application::dto::mask_group_lineage_dto::{
+    LabelComparisonResponse, MaskGroupComparisonQuery, MaskGroupComparisonResponse, MaskGroupLineageNodeResponse,
+    MaskGroupLineageResponse, SetDerivedFromRequest,
+};
+use crate::application::services::{ObjectStorageError, ObjectStorageService};
+use crate::domain::entities::{LabelOverlap, Mask, MaskGroup, MaskGroupLineageNode, MaskSliceKey};
+use crate::domain::services::MaskGroupService;
+use crate::domain::ServiceError;
+use crate::infrastructure::imaging;
+
+/// 비교할 수 있는 그룹당 최대 마스크 수
+const MAX_COMPARISON_MASKS: usize = 2000;
+
+/// 한 슬라이스에서 라벨별로 합친 전경 (0 또는 1)
+struct SliceLayers {
+    width: u32,
+    height: u32,
+    labels: BTreeMap<Option<String>, Vec<u8>>,
+}
+
+/// 마스크 그룹 계보 유스케이스
+///
+/// 한 어노테이션 안의 마스크 그룹을 `derived_from_id` 관계로 묶어 보여주고,
+/// 두 그룹을 같은 슬라이스끼리 맞춰 라벨별 Dice, Jaccard, 부피 차이, Hausdorff 거리를 계산합니다.
+/// 비교는 슬라이스 하나씩 내려받아 처리하므로 메모리 사용량은 슬라이스 크기에 비례합니다.
+pub struct MaskGroupLineageUseCase<MGS>
+where
+    MGS: MaskGroupService + Send + Sync,
+{
+    mask_group_service: Arc<MGS>,
+    object_storage: Arc<dyn ObjectStorageService>,
+}
+
+impl<MGS> MaskGroupLineageUseCase<MGS>
+where
+    MGS: MaskGroupService + Send + Sync,
+{
+    pub fn new(mask_group_service: Arc<MGS>, object_storage: Arc<dyn ObjectStorageService>) -> Self {
+        Self {
+            mask_group_service,
+            object_storage,
+        }
+    }
+
+    /// 어노테이션의 마스크 그룹 계보
+    ///
+    /// 어노테이션 소유자는 모든 그룹을, 그 밖의 사용자는 자신이 만든 그룹만 봅니다.
+    pub async fn get_lineage(&self, annotation_id: i32, user_id: i32) -> Result<MaskGroupLineageResponse, ServiceError> {
+        let is_owner = self.mask_group_service.can_create_mask_group(user_id, annotation_id).await?;
+        let nodes: Vec<MaskGroupLineageNode> = self.mask_group_service
+            .get_mask_group_lineage(annotation_id)
+            .await?
+            .into_iter()
+            .filter(|node| is_owner || node.created_by == Some(user_id))
+            .collect();
+        if !is_owner && nodes.is_empty() {
+            return Err(ServiceError::Unauthorized("Access denied to annotation mask groups".to_string()));
+        }
+
+        Ok(MaskGroupLineageResponse {
+            annotation_id,
+            groups: order_lineage(nodes),
+        })
+    }
+
+    /// 마스크 그룹의 파생 원본 설정 또는 해제
+    pub async fn set_derived_from(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        user_id: i32,
+        request: SetDerivedFromRequest,
+    ) -> Result<MaskGroupResponse, ServiceError> {
+        self.accessible_group(annotation_id, mask_group_id, user_id).await?;
+        if let Some(derived_from_id) = request.derived_from_id {
+            self.accessible_group(annotation_id, derived_from_id, user_id).await?;
+        }
+
+        let mask_group = self.mask_group_service
+            .set_mask_group_derived_from(mask_group_id, request.derived_from_id, user_id)
+            .await?;
+
+        Ok(MaskGroupResponse {
+            id: mask_group.id,
+            annotation_id: mask_group.annotation_id,
+            group_name: mask_group.group_name,
+            model_name: mask_group.model_name,
+            version: mask_group.version,
+            modality: mask_group.modality,
+            slice_count: mask_group.slice_count.unwrap_or(0),
+            mask_type: mask_group.mask_type.unwrap_or_default(),
+            description: mask_group.description,
+            created_by: mask_group.created_by,
+            derived_from_id: mask_group.derived_from_id,
+            created_at: mask_group.created_at.to_string(),
+            updated_at: mask_group.updated_at.to_string(),
+        })
+    }
+
+    /// 두 마스크 그룹 비교
+    ///
+    /// 기준 그룹을 지정하지 않으면 비교 대상 그룹의 파생 원본과 비교합니다.
+    /// 양쪽에 모두 있는 슬라이스만 비교하며, 읽을 수 없는 마스크는 경고와 함께 건너뜁니다.
+    pub async fn compare(
+        &self,
+        annotation_id: i32,
+        candidate_id: i32,
+        user_id: i32,
+        query: MaskGroupComparisonQuery,
+    ) -> Result<MaskGroupComparisonResponse, ServiceError> {
+        let candidate = self.accessible_group(annotation_id, candidate_id, user_id).await?;
+        let reference_id = query.reference_id.or(candidate.derived_from_id).ok_or_else(|| {
+            ServiceError::ValidationError(format!(
+                "Mask group {} is not derived from another group; specify reference_id",
+                candidate_id
+            ))
+        })?;
+        if reference_id == candidate_id {
+            return Err(ServiceError::ValidationError("Cannot compare a mask group with itself".to_string()));
+        }
+        let reference = self.accessible_group(annotation_id, reference_id, user_id).await?;
+
+        let label_filter: Option<HashSet<String>> = query.labels.as_deref().map(|labels| {
+            labels
+                .split(',')
+                .map(str::trim)
+                .filter(|label| !label.is_empty())
+                .map(str::to_string)
+                .collect()
+        });
+
+        let mut warnings = Vec::new();
+        let reference_slices = self.slices_of(&reference, label_filter.as_ref(), &mut warnings).await?;
+        let candidate_slices = self.slices_of(&candidate, label_filter.as_ref(), &mut warnings).await?;
+
+        let mut overlaps: BTreeMap<Option<String>, LabelOverlap> = BTreeMap::new();
+        let mut matched_slices = 0;
+        for (key, reference_masks) in &reference_slices {
+            let Some(candidate_masks) = candidate_slices.get(key) else {
+                continue;
+            };
+            matched_slices += 1;
+
+            let reference_layers = self.load_slice(reference_masks, &mut warnings).await?;
+            let candidate_layers = self.load_slice(candidate_masks, &mut warnings).await?;
+            let (Some(reference_layers), Some(candidate_layers)) = (reference_layers, candidate_layers) else {
+                warnings.push(format!("Slice {} skipped: no readable masks on one side", describe_slice(key)));
+                continue;
+            };
+            if (reference_layers.width, reference_layers.height) != (candidate_layers.width, candidate_layers.height) {
+                warnings.push(format!(
+                    "Slice {} skipped: reference is {}x{} but candidate is {}x{}",
+                    describe_slice(key),
+                    reference_layers.width,
+                    reference_layers.height,
+                    candidate_layers.width,
+                    candidate_layers.height
+                ));
+                continue;
+            }
+
+            let (width, height) = (reference_layers.width, reference_layers.height);
+            let empty = vec![0u8; (width * height) as usize];
+            let labels: HashSet<&Option<String>> =
+                reference_layers.labels.keys().chain(candidate_layers.labels.keys()).collect();
+            for label in labels {
+                let first = reference_layers.labels.get(label).unwrap_or(&empty);
+                let second = candidate_layers.labels.get(label).unwrap_or(&empty);
+                let counts = imaging::overlap_counts(first, second);
+                let hausdorff = imaging::hausdorff_distance(width, height, first, second);
+                overlaps
+                    .entry(label.clone())
+                    .or_default()
+                    .add_slice(counts.first, counts.second, counts.intersection, hausdorff);
+            }
+        }
+
+        let labels = overlaps
+            .into_iter()
+            .map(|(label_name, overlap)| LabelComparisonResponse {
+                label_name,
+                reference_voxels: overlap.reference_voxels,
+                candidate_voxels: overlap.candidate_voxels,
+                intersection_voxels: overlap.intersection_voxels,
+                dice: overlap.dice(),
+                jaccard: overlap.jaccard(),
+                volume_difference_voxels: overlap.volume_difference(),
+                relative_volume_difference: overlap.relative_volume_difference(),
+                hausdorff_distance_pixels: overlap.hausdorff_distance,
+                compared_slices: overlap.compared_slices,
+                unmatched_slices: overlap.unmatched_slices,
+            })
+            .collect();
+
+        Ok(MaskGroupComparisonResponse {
+            annotation_id,
+            reference_id,
+            reference_model_name: reference.model_name,
+            reference_version: reference.version,
+            candidate_id,
+            candidate_model_name: candidate.model_name,
+            candidate_version: candidate.version,
+            matched_slices,
+            reference_only_slices: reference_slices.keys().filter(|key| !candidate_slices.contains_key(*key)).count(),
+            candidate_only_slices: candidate_slices.keys().filter(|key| !reference_slices.contains_key(*key)).count(),
+            labels,
+            warnings,
+        })
+    }
+
+    /// 어노테이션에 속하고 사용자가 접근할 수 있는 마스크 그룹
+    async fn accessible_group(&self, annotation_id: i32, mask_group_id: i32, user_id: i32) -> Result<MaskGroup, ServiceError> {
+        let not_found = || ServiceError::NotFound(format!(
+            "Mask group {} not found in annotation {}",
+            mask_group_id, annotation_id
+        ));
+        let mask_group = self.mask_group_service
+            .get_mask_group_by_id(mask_group_id)
+            .await?
+            .filter(|group| group.annotation_id == annotation_id)
+            .ok_or_else(not_found)?;
+        if !self.mask_group_service.can_access_mask_group(user_id, mask_group_id).await? {
+            return Err(not_found());
+        }
+        Ok(mask_group)
+    }
+
+    /// 그룹의 마스크를 슬라이스별로 묶음
+    async fn slices_of(
+        &self,
+        mask_group: &MaskGroup,
+        label_filter: Option<&HashSet<String>>,
+        warnings: &mut Vec<String>,
+    ) -> Result<BTreeMap<MaskSliceKey, Vec<Mask>>, ServiceError> {
+        let masks = self.mask_group_service.get_masks_in_group(mask_group.id).await?;
+        if masks.len() > MAX_COMPARISON_MASKS {
+            return Err(ServiceError::ValidationError(format!(
+                "Mask group {} has {} masks; at most {} can be compared",
+                mask_group.id,
+                masks.len(),
+                MAX_COMPARISON_MASKS
+            )));
+        }
+
+        let mut slices: BTreeMap<MaskSliceKey, Vec<Mask>> = BTreeMap::new();
+        for mask in masks {
+            if let Some(filter) = label_filter {
+                if !mask.label_name.as_ref().is_some_and(|label| filter.contains(label)) {
+                    continue;
+                }
+            }
+            match MaskSliceKey::of(&mask) {
+                Some(key) => slices.entry(key).or_default().push(mask),
+                None => warnings.push(format!("Mask {} skipped: no slice index or SOP instance UID", mask.id)),
+            }
+        }
+        Ok(slices)
+    }
+
+    /// 한 슬라이스의 마스크를 내려받아 라벨별로 합침 (읽을 수 있는 마스크가 없으면 None)
+    async fn load_slice(&self, masks: &[Mask], warnings: &mut Vec<String>) -> Result<Option<SliceLayers>, ServiceError> {
+        let mut layers: Option<SliceLayers> = None;
+        for mask in masks {
+            let data = match self.object_storage.download_file(&mask.file_path).await {
+                Ok(data) => data,
+                Err(ObjectStorageError::FileNotFound(_)) => {
+                    warnings.push(format!("Mask {} skipped: file {} not found", mask.id, mask.file_path));
+                    continue;
+                }
+                Err(e) => {
+                    return Err(ServiceError::ExternalServiceError(format!(
+                        "Failed to download mask {}: {}",
+                        mask.id, e
+                    )))
+                }
+            };
+            let (width, height, pixels) = match imaging::decode_label_mask_png(&data) {
+                Ok(decoded) => decoded,
+                Err(e) => {
+                    warnings.push(format!("Mask {} skipped: {}", mask.id, e));
+                    continue;
+                }
+            };
+
+            let layers = layers.get_or_insert_with(|| SliceLayers { width, height, labels: BTreeMap::new() });
+            if (layers.width, layers.height) != (width, height) {
+                warnings.push(format!(
+                    "Mask {} skipped: {}x{} does not match {}x{} of the slice",
+                    mask.id, width, height, layers.width, layers.height
+                ));
+                continue;
+            }
+            let layer = layers
+                .labels
+                .entry(mask.label_name.clone())
+                .or_insert_with(|| vec![0; pixels.len()]);
+            for (target, value) in layer.iter_mut().zip(&pixels) {
+                *target |= (*value != 0) as u8;
+            }
+        }
+        Ok(layers)
+    }
+}
+
+fn describe_slice(key: &MaskSliceKey) -> String {
+    match key {
+        MaskSliceKey::Index(index) => index.to_string(),
+        MaskSliceKey::Instance(uid) => uid.clone(),
+    }
+}
+
+/// 계보를 루트부터 깊이 우선 순서로 정렬
+///
+/// 원본이 목록에 없는 그룹은 루트로 취급합니다.
+fn order_lineage(nodes: Vec<MaskGroupLineageNode>) -> Vec<MaskGroupLineageNodeResponse> {
+    let ids: HashSet<i32> = nodes.iter().map(|node| node.id).collect();
+    let mut children: HashMap<i32, Vec<i32>> = HashMap::new();
+    let mut roots = Vec::new();
+    for node in &nodes {
+        match node.derived_from_id.filter(|parent| ids.contains(parent)) {
+            Some(parent) => children.entry(parent).or_default().push(node.id),
+            None => roots.push(node.id),
+        }
+    }
+    let mut by_id: HashMap<i32, MaskGroupLineageNode> = nodes.into_iter().map(|node| (node.id, node)).collect();
+
+    let mut ordered = Vec::with_capacity(by_id.len());
+    let mut stack: Vec<(i32, u32)> = roots.into_iter().rev().map(|id| (id, 0)).collect();
+    while let Some((id, depth)) = stack.pop() {
+        let Some(node) = by_id.remove(&id) else {
+            continue;
+        };
+        let derived_ids = children.remove(&id).unwrap_or_default();
+        stack.extend(derived_ids.iter().rev().map(|child| (*child, depth + 1)));
+        ordered.push(MaskGroupLineageNodeResponse {
+            id: node.id,
+            group_name: node.group_name,
+            model_name: node.model_name,
+            version: node.version,
+            mask_type: node.mask_type,
+            created_by: node.created_by,
+            derived_from_id: node.derived_from_id,
+            derived_ids,
+            depth,
+            mask_count: node.mask_count,
+            created_at: node.created_at.to_rfc3339(),
+        });
+    }
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn node(id: i32, derived_from_id: Option<i32>) -> MaskGroupLineageNode {
+        MaskGroupLineageNode {
+            id,
+            annotation_id: 1,
+            group_name: None,
+            model_name: None,
+            version: None,
+            mask_type: None,
+            created_by: Some(1),
+            derived_from_id,
+            created_at: Utc::now(),
+            mask_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_order_lineage_depth_first() {
+        // 1 -> {2 -> 4, 3}, 5의 원본 9는 목록에 없어 루트
+        let ordered = order_lineage(vec![node(1, None), node(2, Some(1)), node(3, Some(1)), node(4, Some(2)), node(5, Some(9))]);
+        let summary: Vec<(i32, u32)> = ordered.iter().map(|node| (node.id, node.depth)).collect();
+        assert_eq!(summary, vec![(1, 0), (2, 1), (4, 2), (3, 1), (5, 0)]);
+        assert_eq!(ordered[0].derived_ids, vec![2, 3]);
+        assert_eq!(ordered[4].derived_from_id, Some(9));
+    }
+}
@@ -1,14 +1,29 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 use crate::application::dto::mask_group_dto::{
     CreateMaskGroupRequest, UpdateMaskGroupRequest, MaskGroupResponse, 
     MaskGroupListResponse, MaskGroupDetailResponse, SignedUrlRequest, 
-    SignedUrlResponse, CompleteUploadRequest, CompleteUploadResponse
+    SignedUrlResponse, CompleteUploadRequest, CompleteUploadResponse,
+    BatchUploadUrlRequest, BatchDownloadUrlRequest, BatchSignedUrlItem, BatchSignedUrlResponse
 };
 use crate::domain::services::MaskGroupService;
 use crate::domain::ServiceError;
-use crate::application::services::SignedUrlService;
+use crate::application::services::{MaskUploadFile, SignedUrlError, SignedUrlService};
 use crate::domain::entities::{NewMaskGroup, UpdateMaskGroup, MaskGroup};
 
+/// 한 번에 발급할 수 있는 Signed URL 수
+pub const MAX_BATCH_SIGNED_URLS: usize = 1000;
+
+/// 일괄 발급 중 Signed URL 에러 변환 (요청 값 문제는 400)
+fn batch_signed_url_error(error: SignedUrlError) -> ServiceError {
+    match error {
+        SignedUrlError::InvalidTtl(_) | SignedUrlError::InvalidFilePath(_) | SignedUrlError::InvalidRequest(_) => {
+            ServiceError::ValidationError(error.to_string())
+        }
+        other => ServiceError::ExternalServiceError(other.to_string()),
+    }
+}
+
 /// Mask Group 관리 유스케이스
 pub struct MaskGroupUseCase<MGS, SUS> 
 where
@@ -54,6 +69,10 @@ where
             request.description,
             Some(user_id),
         );
+        let new_mask_group = match request.derived_from_id {
+            Some(derived_from_id) => new_mask_group.with_derived_from(derived_from_id),
+            None => new_mask_group,
+        };
 
         let mask_group = self.mask_group_service.create_mask_group(&new_mask_group).await?;
 
@@ -68,6 +87,7 @@ where
             mask_type: mask_group.mask_type.unwrap_or_default(),
             description: mask_group.description,
             created_by: mask_group.created_by,
+            derived_from_id: mask_group.derived_from_id,
             created_at: mask_group.created_at.to_string(),
             updated_at: mask_group.updated_at.to_string(),
         })
@@ -99,6 +119,7 @@ where
             mask_type: mask_group.mask_type,
             description: mask_group.description,
             created_by: mask_group.created_by,
+            derived_from_id: mask_group.derived_from_id,
             created_at: mask_group.created_at.to_string(),
             updated_at: mask_group.updated_at.to_string(),
             stats,
@@ -141,6 +162,7 @@ where
                 mask_type: mg.mask_type.unwrap_or_default(),
                 description: mg.description,
                 created_by: mg.created_by,
+                derived_from_id: mg.derived_from_id,
                 created_at: mg.created_at.to_string(),
                 updated_at: mg.updated_at.to_string(),
             })
@@ -188,7 +210,7 @@ where
         }
 
         let mask_group = self.mask_group_service
-            .update_mask_group(id, &update_mask_group)
+            .update_mask_group(id, &update_mask_group, user_id)
             .await?;
 
         Ok(MaskGroupResponse {
@@ -202,6 +224,7 @@ where
             mask_type: mask_group.mask_type.unwrap_or_default(),
             description: mask_group.description,
             created_by: mask_group.created_by,
+            derived_from_id: mask_group.derived_from_id,
             created_at: mask_group.created_at.to_string(),
             updated_at: mask_group.updated_at.to_string(),
         })
@@ -212,7 +235,7 @@ where
         // 권한 확인
         self.mask_group_service.can_access_mask_group(user_id, id).await?;
 
-        self.mask_group_service.delete_mask_group(id).await?;
+        self.mask_group_service.delete_mask_group(id, user_id).await?;
         Ok(())
     }
 
@@ -231,6 +254,11 @@ where
             .await?
             .ok_or_else(|| ServiceError::NotFound(format!("Mask group with ID {} not found", request.mask_group_id)))?;
 
+        // 업로드 한도를 넘으면 URL을 발급하지 않음
+        let quota_warning = self.mask_group_service
+            .check_storage_quota(request.mask_group_id, request.file_size)
+            .await?;
+
         let signed_url = self.signed_url_service
             .generate_mask_upload_url(
                 mask_group.annotation_id, // 실제 annotation_id 사용
@@ -249,6 +277,196 @@ where
             file_path: signed_url.file_path,
             expires_in: signed_url.ttl_seconds,
             expires_at: signed_url.expires_at.to_string(),
+            quota_warning,
+        })
+    }
+
+    /// 그룹 단위 권한 확인 후 마스크 그룹 조회 (일괄 발급용)
+    async fn get_accessible_mask_group(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        user_id: i32,
+    ) -> Result<MaskGroup, ServiceError> {
+        let not_found = || ServiceError::NotFound(format!("Mask group with ID {} not found", mask_group_id));
+
+        // 권한은 URL마다가 아니라 그룹에 대해 한 번만 확인
+        if !self.mask_group_service.can_access_mask_group(user_id, mask_group_id).await? {
+            return Err(not_found());
+        }
+        let mask_group = self.mask_group_service
+            .get_mask_group_by_id(mask_group_id)
+            .await?
+            .ok_or_else(not_found)?;
+        if mask_group.annotation_id != annotation_id {
+            return Err(not_found());
+        }
+        Ok(mask_group)
+    }
+
+    /// 업로드용 Signed URL 일괄 생성
+    pub async fn generate_upload_urls(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        request: BatchUploadUrlRequest,
+        user_id: i32,
+    ) -> Result<BatchSignedUrlResponse, ServiceError> {
+        if request.files.is_empty() {
+            return Err(ServiceError::ValidationError("At least one file is required".to_string()));
+        }
+        if request.files.len() > MAX_BATCH_SIGNED_URLS {
+            return Err(ServiceError::ValidationError(format!(
+                "At most {} signed URLs can be generated at once",
+                MAX_BATCH_SIGNED_URLS
+            )));
+        }
+        let mut file_names = HashSet::new();
+        for file in &request.files {
+            if file.filename.is_empty() || file.filename.contains('/') || file.filename.contains("..") {
+                return Err(ServiceError::ValidationError(format!("Invalid file name '{}'", file.filename)));
+            }
+            if !file_names.insert(file.filename.as_str()) {
+                return Err(ServiceError::ValidationError(format!("Duplicate file name '{}'", file.filename)));
+            }
+        }
+
+        let mask_group = self.get_accessible_mask_group(annotation_id, mask_group_id, user_id).await?;
+
+        // 크기를 모두 알면 합계로, 하나라도 모르면 현재 사용량으로 한도 확인
+        let upload_bytes = request.files
+            .iter()
+            .try_fold(0i64, |total, file| file.file_size.map(|size| total.saturating_add(size)));
+        let quota_warning = self.mask_group_service
+            .check_storage_quota(mask_group_id, upload_bytes)
+            .await?;
+
+        let files = request.files
+            .iter()
+            .map(|file| MaskUploadFile {
+                file_name: file.filename.clone(),
+                content_type: file.mime_type.clone(),
+            })
+            .collect();
+        let signed_urls = self.signed_url_service
+            .generate_mask_upload_urls(mask_group.annotation_id, mask_group_id, files, request.ttl_seconds, Some(user_id))
+            .await
+            .map_err(batch_signed_url_error)?;
+
+        let (expires_in, expires_at) = signed_urls
+            .first()
+            .map(|url| (url.ttl_seconds, url.expires_at.to_rfc3339()))
+            .unwrap_or_default();
+        let urls = request.files
+            .iter()
+            .zip(signed_urls)
+            .map(|(file, signed_url)| BatchSignedUrlItem {
+                mask_id: None,
+                slice_index: file.slice_index,
+                file_path: signed_url.file_path,
+                url: signed_url.url,
+            })
+            .collect();
+
+        Ok(BatchSignedUrlResponse {
+            mask_group_id,
+            method: "PUT".to_string(),
+            urls,
+            missing_slice_indices: Vec::new(),
+            missing_file_names: Vec::new(),
+            expires_in,
+            expires_at,
+            quota_warning,
+        })
+    }
+
+    /// 다운로드용 Signed URL 일괄 생성
+    ///
+    /// 슬라이스 인덱스나 파일명을 지정하지 않으면 그룹의 모든 마스크 URL을 생성합니다.
+    pub async fn generate_download_urls(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        request: BatchDownloadUrlRequest,
+        user_id: i32,
+    ) -> Result<BatchSignedUrlResponse, ServiceError> {
+        self.get_accessible_mask_group(annotation_id, mask_group_id, user_id).await?;
+        let masks = self.mask_group_service.get_masks_in_group(mask_group_id).await?;
+
+        let selected = if request.slice_indices.is_none() && request.file_names.is_none() {
+            masks.iter().collect::<Vec<_>>()
+        } else {
+            let slice_indices: HashSet<i32> = request.slice_indices.iter().flatten().copied().collect();
+            let file_names: HashSet<&str> = request.file_names.iter().flatten().map(String::as_str).collect();
+            masks
+                .iter()
+                .filter(|mask| {
+                    let file_name = mask.file_path.rsplit('/').next().unwrap_or(&mask.file_path);
+                    mask.slice_index.is_some_and(|index| slice_indices.contains(&index))
+                        || file_names.contains(file_name)
+                        || file_names.contains(mask.file_path.as_str())
+                })
+                .collect()
+        };
+        if selected.len() > MAX_BATCH_SIGNED_URLS {
+            return Err(ServiceError::ValidationError(format!(
+                "At most {} signed URLs can be generated at once; select slice indices or file names",
+                MAX_BATCH_SIGNED_URLS
+            )));
+        }
+
+        let mut missing_slice_indices: Vec<i32> = request.slice_indices
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|index| !masks.iter().any(|mask| mask.slice_index == Some(*index)))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        missing_slice_indices.sort_unstable();
+        let mut missing_file_names: Vec<String> = request.file_names
+            .iter()
+            .flatten()
+            .filter(|name| {
+                !masks.iter().any(|mask| {
+                    mask.file_path == **name || mask.file_path.rsplit('/').next() == Some(name.as_str())
+                })
+            })
+            .cloned()
+            .collect();
+        missing_file_names.sort();
+        missing_file_names.dedup();
+
+        let file_paths = selected.iter().map(|mask| mask.file_path.clone()).collect();
+        let signed_urls = self.signed_url_service
+            .generate_mask_download_urls(file_paths, request.ttl_seconds)
+            .await
+            .map_err(batch_signed_url_error)?;
+
+        let (expires_in, expires_at) = signed_urls
+            .first()
+            .map(|url| (url.ttl_seconds, url.expires_at.to_rfc3339()))
+            .unwrap_or_default();
+        let urls = selected
+            .iter()
+            .zip(signed_urls)
+            .map(|(mask, signed_url)| BatchSignedUrlItem {
+                mask_id: Some(mask.id),
+                slice_index: mask.slice_index,
+                file_path: signed_url.file_path,
+                url: signed_url.url,
+            })
+            .collect();
+
+        Ok(BatchSignedUrlResponse {
+            mask_group_id,
+            method: "GET".to_string(),
+            urls,
+            missing_slice_indices,
+            missing_file_names,
+            expires_in,
+            expires_at,
+            quota_warning: None,
         })
     }
 
@@ -261,6 +479,9 @@ where
         // 권한 확인
         self.mask_group_service.can_access_mask_group(user_id, request.mask_group_id).await?;
 
+        // 프로젝트 구독자에게 업로드 완료 알림
+        self.mask_group_service.complete_upload(request.mask_group_id, user_id).await?;
+
         // 여기서는 단순히 성공 응답을 반환
         // 실제로는 업로드된 파일의 메타데이터를 검증하고 데이터베이스에 기록하는 로직이 필요
         Ok(CompleteUploadResponse {
@@ -0,0 +1,469 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use sha2::{Digest, Sha256};
+use crate::application::dto::mask_import_dto::{
+    CreateMaskImportRequest, MaskImportJobListResponse, MaskImportJobResponse,
+    MaskImportUploadUrlRequest, MaskImportUploadUrlResponse,
+};
+use crate::application::services::{ObjectStorageService, SignedUrlRequest, SignedUrlService};
+use crate::domain::entities::{MaskGroup, NewMask, NewMaskGroup, NewMaskImportJob, UnmappedFrame};
+use crate::domain::repositories::MaskImportRepository;
+use crate::domain::services::{MaskGroupService, MaskService};
+use crate::domain::ServiceError;
+use crate::infrastructure::imaging::{self, LabelFrame, LabelVolume, LabelVolumeFormat};
+
+/// 라벨 볼륨(DICOM SEG / NIfTI) 임포트 유스케이스
+///
+/// 임포트 요청 시 작업을 생성하고 즉시 반환하며, 실제 처리는 백그라운드 태스크에서 수행합니다.
+/// 처리 과정:
+/// 1. Object Storage에서 원본 볼륨 다운로드
+/// 2. 슬라이스/라벨 단위 이진 마스크로 분해
+/// 3. 마스크 그룹 생성 후 각 프레임을 PNG로 인코딩하여 업로드하고 마스크 레코드 생성
+/// 4. SOP Instance UID에 매핑되지 않은 프레임을 작업 결과에 기록
+///
+/// 처리 중 실패하면 만든 마스크 그룹을 삭제하고 업로드된 슬라이스 객체의 삭제를 예약합니다.
+pub struct MaskImportUseCase<MGS, MS, SUS, MIR>
+where
+    MGS: MaskGroupService + Send + Sync,
+    MS: MaskService + Send + Sync,
+    SUS: SignedUrlService + Send + Sync,
+    MIR: MaskImportRepository + Send + Sync,
+{
+    mask_group_service: Arc<MGS>,
+    mask_service: Arc<MS>,
+    signed_url_service: Arc<SUS>,
+    import_repository: Arc<MIR>,
+    object_storage: Arc<dyn ObjectStorageService>,
+}
+
+impl<MGS, MS, SUS, MIR> Clone for MaskImportUseCase<MGS, MS, SUS, MIR>
+where
+    MGS: MaskGroupService + Send + Sync,
+    MS: MaskService + Send + Sync,
+    SUS: SignedUrlService + Send + Sync,
+    MIR: MaskImportRepository + Send + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            mask_group_service: self.mask_group_service.clone(),
+            mask_service: self.mask_service.clone(),
+            signed_url_service: self.signed_url_service.clone(),
+            import_repository: self.import_repository.clone(),
+            object_storage: self.object_storage.clone(),
+        }
+    }
+}
+
+impl<MGS, MS, SUS, MIR> MaskImportUseCase<MGS, MS, SUS, MIR>
+where
+    MGS: MaskGroupService + Send + Sync + 'static,
+    MS: MaskService + Send + Sync + 'static,
+    SUS: SignedUrlService + Send + Sync + 'static,
+    MIR: MaskImportRepository + Send + Sync + 'static,
+{
+    pub fn new(
+        mask_group_service: Arc<MGS>,
+        mask_service: Arc<MS>,
+        signed_url_service: Arc<SUS>,
+        import_repository: Arc<MIR>,
+        object_storage: Arc<dyn ObjectStorageService>,
+    ) -> Self {
+        Self {
+            mask_group_service,
+            mask_service,
+            signed_url_service,
+            import_repository,
+            object_storage,
+        }
+    }
+
+    /// 어노테이션에 마스크를 임포트할 권한 확인
+    async fn ensure_can_import(&self, user_id: i32, annotation_id: i32) -> Result<(), ServiceError> {
+        if self.mask_group_service.can_create_mask_group(user_id, annotation_id).await? {
+            Ok(())
+        } else {
+            Err(ServiceError::Unauthorized(format!(
+                "User {} cannot import masks into annotation {}",
+                user_id, annotation_id
+            )))
+        }
+    }
+
+    /// 원본 라벨 볼륨 업로드용 Signed URL 생성
+    pub async fn generate_source_upload_url(
+        &self,
+        annotation_id: i32,
+        request: MaskImportUploadUrlRequest,
+        user_id: i32,
+    ) -> Result<MaskImportUploadUrlResponse, ServiceError> {
+        self.ensure_can_import(user_id, annotation_id).await?;
+
+        let filename = sanitize_path_segment(&request.filename);
+        if filename.is_empty() {
+            return Err(ServiceError::ValidationError("Filename cannot be empty".to_string()));
+        }
+        LabelVolumeFormat::resolve(None, &filename)?;
+
+        let file_path = format!(
+            "{}{}_{}",
+            import_source_prefix(annotation_id),
+            uuid::Uuid::new_v4(),
+            filename
+        );
+        let mut url_request = SignedUrlRequest::new(file_path)
+            .with_annotation_id(annotation_id)
+            .with_user_id(user_id);
+        url_request.ttl_seconds = request.ttl_seconds;
+
+        let signed_url = self.signed_url_service.generate_upload_url(url_request).await?;
+
+        Ok(MaskImportUploadUrlResponse {
+            upload_url: signed_url.url,
+            source_path: signed_url.file_path,
+            expires_in: signed_url.ttl_seconds,
+            expires_at: signed_url.expires_at.to_rfc3339(),
+        })
+    }
+
+    /// 임포트 작업 생성 및 백그라운드 실행
+    pub async fn start_import(
+        &self,
+        annotation_id: i32,
+        request: CreateMaskImportRequest,
+        user_id: i32,
+    ) -> Result<MaskImportJobResponse, ServiceError> {
+        self.ensure_can_import(user_id, annotation_id).await?;
+
+        // 이 어노테이션용으로 발급한 업로드 경로만 허용 (다른 프로젝트의 객체를 가져오지 못하도록)
+        if !is_import_source_path(annotation_id, &request.source_path) {
+            return Err(ServiceError::ValidationError(format!(
+                "Invalid source path: must be an object under {} issued by the upload URL endpoint",
+                import_source_prefix(annotation_id)
+            )));
+        }
+        let format = LabelVolumeFormat::resolve(request.format.as_deref(), &request.source_path)?;
+
+        let job = self.import_repository
+            .create(&NewMaskImportJob::new(
+                annotation_id,
+                request.source_path.clone(),
+                format.as_str().to_string(),
+                Some(user_id),
+            ))
+            .await?;
+
+        let worker = self.clone();
+        let job_id = job.id;
+        tokio::spawn(async move {
+            worker.run_import(job_id, annotation_id, format, request, user_id).await;
+        });
+
+        Ok(job.into())
+    }
+
+    /// 임포트 작업 조회
+    pub async fn get_import_job(
+        &self,
+        annotation_id: i32,
+        job_id: i32,
+        user_id: i32,
+    ) -> Result<MaskImportJobResponse, ServiceError> {
+        self.ensure_can_import(user_id, annotation_id).await?;
+
+        let job = self.import_repository
+            .get_by_id(job_id)
+            .await?
+            .filter(|job| job.annotation_id == annotation_id)
+            .ok_or_else(|| ServiceError::NotFound(format!("Mask import job with ID {} not found", job_id)))?;
+
+        Ok(job.into())
+    }
+
+    /// 어노테이션의 임포트 작업 목록 조회
+    pub async fn list_import_jobs(
+        &self,
+        annotation_id: i32,
+        user_id: i32,
+    ) -> Result<MaskImportJobListResponse, ServiceError> {
+        self.ensure_can_import(user_id, annotation_id).await?;
+
+        let jobs: Vec<MaskImportJobResponse> = self.import_repository
+            .list_by_annotation(annotation_id)
+            .await?
+            .into_iter()
+            .map(MaskImportJobResponse::from)
+            .collect();
+
+        Ok(MaskImportJobListResponse {
+            total_count: jobs.len() as i64,
+            jobs,
+        })
+    }
+
+    /// 백그라운드 임포트 실행. 결과는 작업 레코드에 기록됩니다.
+    async fn run_import(
+        &self,
+        job_id: i32,
+        annotation_id: i32,
+        format: LabelVolumeFormat,
+        request: CreateMaskImportRequest,
+        user_id: i32,
+    ) {
+        if let Err(e) = self.import_repository.mark_running(job_id).await {
+            eprintln!("Failed to start mask import job {}: {}", job_id, e);
+            return;
+        }
+
+        let mut created_group: Option<MaskGroup> = None;
+        let result = self
+            .process_import(job_id, annotation_id, format, &request, user_id, &mut created_group)
+            .await;
+
+        if let Err(e) = result {
+            eprintln!("Mask import job {} failed: {}", job_id, e);
+            let group_id = created_group.map(|g| g.id);
+            if let Err(e) = self.import_repository.mark_failed(job_id, group_id, &e.to_string()).await {
+                eprintln!("Failed to record mask import job {} failure: {}", job_id, e);
+            }
+        }
+    }
+
+    async fn process_import(
+        &self,
+        job_id: i32,
+        annotation_id: i32,
+        format: LabelVolumeFormat,
+        request: &CreateMaskImportRequest,
+        user_id: i32,
+        created_group: &mut Option<MaskGroup>,
+    ) -> Result<(), ServiceError> {
+        let data = self.object_storage
+            .download_file(&request.source_path)
+            .await
+            .map_err(|e| ServiceError::ExternalServiceError(format!("Failed to download source volume: {}", e)))?;
+
+        let volume = tokio::task::spawn_blocking(move || imaging::read_label_volume(format, &data))
+            .await
+            .map_err(|e| ServiceError::ExternalServiceError(format!("Volume decoding task failed: {}", e)))??;
+
+        let new_mask_group = NewMaskGroup::new(
+            annotation_id,
+            request.group_name.clone(),
+            request.model_name.clone(),
+            request.version.clone(),
+            request.modality.clone(),
+            volume.slice_count() as i32,
+            request.mask_type.clone().unwrap_or_else(|| "segmentation".to_string()),
+            request.description.clone(),
+            Some(user_id),
+        );
+        let mask_group = self.mask_group_service.create_mask_group(&new_mask_group).await?;
+        *created_group = Some(mask_group.clone());
+
+        let label_names = request.label_names.clone().unwrap_or_default();
+        let instance_uids = request.instance_uids.as_deref();
+        let mut unmapped_frames = Vec::new();
+        let mut imported_masks = 0;
+
+        for frame in &volume.frames {
+            let label_name = resolve_label_name(frame, &label_names);
+            let (sop_instance_uid, unmapped_reason) = map_instance_uid(frame, instance_uids);
+            if let Some(reason) = unmapped_reason {
+                unmapped_frames.push(UnmappedFrame {
+                    frame_index: frame.frame_index as i32,
+                    slice_index: frame.slice_index,
+                    label_name: Some(label_name.clone()),
+                    sop_instance_uid: frame.sop_instance_uid.clone(),
+                    reason,
+                });
+            }
+
+            self.store_frame(&mask_group, &volume, frame, label_name, sop_instance_uid).await?;
+            imported_masks += 1;
+        }
+
+        self.import_repository
+            .mark_completed(
+                job_id,
+                mask_group.id,
+                volume.frames.len() as i32,
+                imported_masks,
+                serde_json::to_value(&unmapped_frames).unwrap_or_default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// 프레임 하나를 PNG로 인코딩해 업로드하고 마스크 레코드를 생성합니다.
+    async fn store_frame(
+        &self,
+        mask_group: &MaskGroup,
+        volume: &LabelVolume,
+        frame: &LabelFrame,
+        label_name: String,
+        sop_instance_uid: Option<String>,
+    ) -> Result<(), ServiceError> {
+        let png = imaging::encode_binary_mask_png(volume.columns, volume.rows, &frame.pixels)?;
+        let checksum = hex::encode(Sha256::digest(&png));
+        let file_size = png.len() as i64;
+        let file_path = format!(
+            "masks/annotation_{}/group_{}/slice_{:04}_{}.png",
+            mask_group.annotation_id,
+            mask_group.id,
+            frame.slice_index,
+            sanitize_path_segment(&label_name)
+        );
+
+        self.object_storage
+            .upload_file(&file_path, png, Some("image/png"))
+            .await
+            .map_err(|e| ServiceError::ExternalServiceError(format!("Failed to upload mask {}: {}", file_path, e)))?;
+
+        let new_mask = NewMask::new(
+            mask_group.id,
+            file_path,
+            "image/png".to_string(),
+            Some(frame.slice_index),
+            sop_instance_uid,
+            Some(label_name),
+            Some(file_size),
+            Some(checksum),
+            Some(volume.columns as i32),
+            Some(volume.rows as i32),
+        );
+        self.mask_service.create_mask(&new_mask).await?;
+        Ok(())
+    }
+}
+
+/// 프레임의 라벨 이름을 결정합니다. 요청의 매핑 → 원본 라벨 → `label_{값}` 순서로 사용합니다.
+fn resolve_label_name(frame: &LabelFrame, label_names: &HashMap<String, String>) -> String {
+    label_names
+        .get(&frame.label_value.to_string())
+        .cloned()
+        .or_else(|| frame.label_name.clone())
+        .unwrap_or_else(|| format!("label_{}", frame.label_value))
+}
+
+/// 프레임을 SOP Instance UID에 매핑합니다.
+///
+/// 원본이 UID를 참조하면 그대로 사용하고, 없으면 `instance_uids[slice_index]`를 사용합니다.
+/// 매핑하지 못했거나 참조 UID가 대상 시리즈에 없으면 사유를 함께 반환합니다.
+fn map_instance_uid(frame: &LabelFrame, instance_uids: Option<&[String]>) -> (Option<String>, Option<String>) {
+    match (&frame.sop_instance_uid, instance_uids) {
+        (Some(uid), Some(uids)) if !uids.contains(uid) => (
+            Some(uid.clone()),
+            Some("Referenced SOP instance is not in the provided instance list".to_string()),
+        ),
+        (Some(uid), _) => (Some(uid.clone()), None),
+        (None, Some(uids)) => match usize::try_from(frame.slice_index).ok().and_then(|i| uids.get(i)) {
+            Some(uid) => (Some(uid.clone()), None),
+            None => (
+                None,
+                Some(format!("No instance UID for slice index {}", frame.slice_index)),
+            ),
+        },
+        (None, None) => (None, Some("Frame has no SOP instance reference".to_string())),
+    }
+}
+
+/// 어노테이션의 원본 볼륨 업로드 경로 prefix
+fn import_source_prefix(annotation_id: i32) -> String {
+    format!("imports/annotation_{}/", annotation_id)
+}
+
+/// 어노테이션용 업로드 경로 아래의 객체인지 확인합니다.
+fn is_import_source_path(annotation_id: i32, source_path: &str) -> bool {
+    source_path
+        .strip_prefix(&import_source_prefix(annotation_id))
+        .is_some_and(|name| !name.is_empty() && !name.contains('/') && !source_path.contains(".."))
+}
+
+/// 스토리지 경로에 안전한 이름으로 변환합니다.
+pub(crate) fn sanitize_path_segment(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_' { c } else { '_' })
+        .collect::<String>()
+        .trim_matches('.')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(slice_index: i32, label_value: i32, uid: Option<&str>) -> LabelFrame {
+        LabelFrame {
+            frame_index: 0,
+            slice_index,
+            label_value,
+            label_name: None,
+            sop_instance_uid: uid.map(|s| s.to_string()),
+            pixels: vec![1],
+        }
+    }
+
+    #[test]
+    fn test_map_instance_uid_prefers_source_reference() {
+        let uids = vec!["1.2.3.1".to_string(), "1.2.3.2".to_string()];
+        let (uid, reason) = map_instance_uid(&frame(1, 1, Some("1.2.3.1")), Some(&uids));
+        assert_eq!(uid.as_deref(), Some("1.2.3.1"));
+        assert!(reason.is_none());
+    }
+
+    #[test]
+    fn test_map_instance_uid_by_slice_index() {
+        let uids = vec!["1.2.3.1".to_string(), "1.2.3.2".to_string()];
+        let (uid, reason) = map_instance_uid(&frame(1, 1, None), Some(&uids));
+        assert_eq!(uid.as_deref(), Some("1.2.3.2"));
+        assert!(reason.is_none());
+
+        let (uid, reason) = map_instance_uid(&frame(5, 1, None), Some(&uids));
+        assert!(uid.is_none());
+        assert!(reason.is_some());
+    }
+
+    #[test]
+    fn test_map_instance_uid_reports_foreign_reference() {
+        let uids = vec!["1.2.3.1".to_string()];
+        let (uid, reason) = map_instance_uid(&frame(0, 1, Some("9.9.9")), Some(&uids));
+        assert_eq!(uid.as_deref(), Some("9.9.9"));
+        assert!(reason.is_some());
+    }
+
+    #[test]
+    fn test_map_instance_uid_without_any_reference() {
+        let (uid, reason) = map_instance_uid(&frame(0, 1, None), None);
+        assert!(uid.is_none());
+        assert!(reason.is_some());
+    }
+
+    #[test]
+    fn test_resolve_label_name() {
+        let mut names = HashMap::new();
+        names.insert("2".to_string(), "tumor".to_string());
+
+        assert_eq!(resolve_label_name(&frame(0, 2, None), &names), "tumor");
+        assert_eq!(resolve_label_name(&frame(0, 3, None), &names), "label_3");
+
+        let mut named = frame(0, 1, None);
+        named.label_name = Some("liver".to_string());
+        assert_eq!(resolve_label_name(&named, &names), "liver");
+    }
+
+    #[test]
+    fn test_is_import_source_path() {
+        assert!(is_import_source_path(7, "imports/annotation_7/abc_seg.nii.gz"));
+        assert!(!is_import_source_path(7, "imports/annotation_70/abc_seg.nii.gz"));
+        assert!(!is_import_source_path(7, "imports/annotation_7/"));
+        assert!(!is_import_source_path(7, "imports/annotation_7/../annotation_8/seg.nii.gz"));
+        assert!(!is_import_source_path(7, "masks/annotation_8/group_1/liver_000.png"));
+    }
+
+    #[test]
+    fn test_sanitize_path_segment() {
+        assert_eq!(sanitize_path_segment("left kidney/1"), "left_kidney_1");
+        assert_eq!(sanitize_path_segment("../seg.nii.gz"), "_seg.nii.gz");
+    }
+}
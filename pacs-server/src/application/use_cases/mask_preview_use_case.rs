@@ -0,0 +1,328 @@
+use std::sync::Arc;
+use std::time::Duration;
+use crate::application::dto::mask_preview_dto::MaskGroupContactSheetResponse;
+use crate::application::services::{ObjectStorageError, ObjectStorageService, SignedUrlService};
+use crate::domain::entities::{
+    contact_sheet_key, mask_preview_key, mask_thumbnail_key, ContactSheetTask, MaskPreviewRunResult, MaskPreviewTask,
+    RetryPolicy,
+};
+use crate::domain::repositories::MaskPreviewRepository;
+use crate::domain::services::MaskGroupService;
+use crate::domain::ServiceError;
+use crate::infrastructure::imaging;
+
+/// 한 번에 처리하는 마스크/마스크 그룹 수
+const PREVIEW_BATCH_SIZE: i64 = 50;
+
+/// 처리 중인 마스크/마스크 그룹을 다른 작업이 가져가지 못하는 시간 (초)
+const PREVIEW_LEASE_SECONDS: i64 = 300;
+
+/// 썸네일의 긴 변 (픽셀)
+pub const THUMBNAIL_MAX_SIZE: u32 = 128;
+
+/// 컬러 미리보기의 긴 변 (픽셀)
+pub const PREVIEW_MAX_SIZE: u32 = 512;
+
+/// contact sheet에 담는 최대 썸네일 수 (슬라이스 순서)
+const CONTACT_SHEET_MAX_TILES: i64 = 100;
+
+const PNG_CONTENT_TYPE: &str = "image/png";
+
+/// 마스크 미리보기 유스케이스
+///
+/// 검증을 통과한 마스크마다 썸네일과 컬러 미리보기를 만들어 `derived/` 아래에 저장하고,
+/// 미리보기를 기다리는 마스크가 없는 마스크 그룹의 contact sheet를 만듭니다.
+/// 스토리지 오류는 재시도 정책에 따라 다시 시도하고, 디코딩할 수 없는 마스크는 바로 `FAILED`로 표시합니다.
+pub struct MaskPreviewUseCase<R, MGS, SUS>
+where
+    R: MaskPreviewRepository + Send + Sync,
+    MGS: MaskGroupService + Send + Sync,
+    SUS: SignedUrlService + Send + Sync,
+{
+    mask_preview_repository: Arc<R>,
+    mask_group_service: Arc<MGS>,
+    signed_url_service: Arc<SUS>,
+    object_storage: Arc<dyn ObjectStorageService>,
+    retry_policy: RetryPolicy,
+}
+
+impl<R, MGS, SUS> MaskPreviewUseCase<R, MGS, SUS>
+where
+    R: MaskPreviewRepository + Send + Sync,
+    MGS: MaskGroupService + Send + Sync,
+    SUS: SignedUrlService + Send + Sync,
+{
+    pub fn new(
+        mask_preview_repository: Arc<R>,
+        mask_group_service: Arc<MGS>,
+        signed_url_service: Arc<SUS>,
+        object_storage: Arc<dyn ObjectStorageService>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            mask_preview_repository,
+            mask_group_service,
+            signed_url_service,
+            object_storage,
+            retry_policy,
+        }
+    }
+
+    /// 마스크 그룹의 contact sheet와 서명된 다운로드 URL
+    pub async fn get_contact_sheet(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        user_id: i32,
+        expires_in: Option<u64>,
+    ) -> Result<MaskGroupContactSheetResponse, ServiceError> {
+        let not_found = || ServiceError::NotFound(format!(
+            "Mask group {} not found in annotation {}",
+            mask_group_id, annotation_id
+        ));
+        self.mask_group_service
+            .get_mask_group_by_id(mask_group_id)
+            .await?
+            .filter(|group| group.annotation_id == annotation_id)
+            .ok_or_else(not_found)?;
+        if !self.mask_group_service.can_access_mask_group(user_id, mask_group_id).await? {
+            return Err(not_found());
+        }
+
+        let sheet = self.mask_preview_repository
+            .find_contact_sheet(mask_group_id)
+            .await?
+            .ok_or_else(not_found)?;
+
+        let mut response = MaskGroupContactSheetResponse {
+            mask_group_id,
+            status: sheet.contact_sheet_status,
+            mask_count: sheet.contact_sheet_mask_count,
+            url: None,
+            expires_in: None,
+            expires_at: None,
+            error: sheet.contact_sheet_error,
+            generated_at: sheet.contact_sheet_generated_at.map(|at| at.to_rfc3339()),
+        };
+        // 다시 만드는 중에도 이전 contact sheet는 내려받을 수 있음
+        if let Some(key) = sheet.contact_sheet_key {
+            let signed_url = self.signed_url_service.generate_mask_download_url(key, expires_in).await?;
+            response.url = Some(signed_url.url);
+            response.expires_in = Some(signed_url.ttl_seconds);
+            response.expires_at = Some(signed_url.expires_at.to_rfc3339());
+        }
+        Ok(response)
+    }
+
+    /// 미리보기를 만들 때가 된 마스크와 contact sheet를 처리합니다.
+    pub async fn process_pending(&self) -> Result<MaskPreviewRunResult, ServiceError> {
+        let mut result = MaskPreviewRunResult::default();
+
+        let claimed = self.mask_preview_repository
+            .claim_pending_masks(PREVIEW_BATCH_SIZE, PREVIEW_LEASE_SECONDS)
+            .await?;
+        for task in claimed {
+            self.generate_mask_previews(&task, &mut result).await;
+        }
+
+        // 마스크 미리보기를 먼저 처리해야 같은 실행에서 갱신된 썸네일로 contact sheet를 만듦
+        let claimed = self.mask_preview_repository
+            .claim_contact_sheets(PREVIEW_BATCH_SIZE, PREVIEW_LEASE_SECONDS)
+            .await?;
+        for task in claimed {
+            self.generate_contact_sheet(&task, &mut result).await;
+        }
+        Ok(result)
+    }
+
+    /// 기록에 실패한 마스크는 임대가 끝난 뒤 다시 처리됨
+    async fn generate_mask_previews(&self, task: &MaskPreviewTask, result: &mut MaskPreviewRunResult) {
+        let data = match self.object_storage.download_file(&task.file_path).await {
+            Ok(data) => data,
+            Err(ObjectStorageError::FileNotFound(_)) => {
+                let error = format!("Object {} not found in storage", task.file_path);
+                self.record_mask_failure(task, &error, None, result).await;
+                return;
+            }
+            Err(e) => {
+                let error = format!("Failed to read {}: {}", task.file_path, e);
+                let retry_in_seconds = self.retry_policy.retry_delay(task.preview_attempts + 1);
+                self.record_mask_failure(task, &error, retry_in_seconds, result).await;
+                return;
+            }
+        };
+
+        let (thumbnail, preview) = match render_mask_previews(&data) {
+            Ok(rendered) => rendered,
+            Err(error) => {
+                self.record_mask_failure(task, &error, None, result).await;
+                return;
+            }
+        };
+
+        let thumbnail_key = mask_thumbnail_key(&task.file_path);
+        let preview_key = mask_preview_key(&task.file_path);
+        for (key, data) in [(&thumbnail_key, thumbnail), (&preview_key, preview)] {
+            if let Err(e) = self.object_storage.upload_file(key, data, Some(PNG_CONTENT_TYPE)).await {
+                let error = format!("Failed to write {}: {}", key, e);
+                let retry_in_seconds = self.retry_policy.retry_delay(task.preview_attempts + 1);
+                self.record_mask_failure(task, &error, retry_in_seconds, result).await;
+                return;
+            }
+        }
+
+        match self.mask_preview_repository.mark_mask_ready(task, &thumbnail_key, &preview_key).await {
+            Ok(true) => result.generated += 1,
+            Ok(false) => {}
+            Err(e) => eprintln!("Failed to record mask preview {}: {}", task.mask_id, e),
+        }
+    }
+
+    async fn record_mask_failure(
+        &self,
+        task: &MaskPreviewTask,
+        error: &str,
+        retry_in_seconds: Option<i64>,
+        result: &mut MaskPreviewRunResult,
+    ) {
+        if let Err(e) = self.mask_preview_repository
+            .record_mask_failure(task.mask_id, task.lease_until, error, retry_in_seconds)
+            .await
+        {
+            eprintln!("Failed to record mask preview {}: {}", task.mask_id, e);
+            return;
+        }
+        eprintln!("Failed to generate mask preview {} (attempt {}): {}", task.mask_id, task.preview_attempts + 1, error);
+        match retry_in_seconds {
+            Some(_) => result.retrying += 1,
+            None => result.failed += 1,
+        }
+    }
+
+    async fn generate_contact_sheet(&self, task: &ContactSheetTask, result: &mut MaskPreviewRunResult) {
+        let keys = match self.mask_preview_repository
+            .find_thumbnail_keys(task.mask_group_id, CONTACT_SHEET_MAX_TILES)
+            .await
+        {
+            Ok(keys) => keys,
+            Err(e) => {
+                eprintln!("Failed to generate contact sheet for mask group {}: {}", task.mask_group_id, e);
+                return;
+            }
+        };
+
+        let mut tiles = Vec::with_capacity(keys.len());
+        for key in &keys {
+            match self.object_storage.download_file(key).await {
+                Ok(data) => match imaging::decode_label_mask_png(&data) {
+                    Ok(tile) => tiles.push(tile),
+                    Err(e) => eprintln!("Skipping unreadable thumbnail {}: {}", key, e),
+                },
+                // 그 사이 삭제된 마스크의 썸네일은 빼고 만듦 (삭제되면 다시 만들도록 표시됨)
+                Err(ObjectStorageError::FileNotFound(_)) => {}
+                Err(e) => {
+                    let error = format!("Failed to read {}: {}", key, e);
+                    self.record_contact_sheet_failure(task, &error, result).await;
+                    return;
+                }
+            }
+        }
+
+        let key = if tiles.is_empty() {
+            None
+        } else {
+            let sheet = match imaging::encode_contact_sheet_png(&tiles, THUMBNAIL_MAX_SIZE) {
+                Ok(sheet) => sheet,
+                Err(e) => {
+                    self.record_contact_sheet_failure(task, &e.to_string(), result).await;
+                    return;
+                }
+            };
+            let key = contact_sheet_key(task.annotation_id, task.mask_group_id);
+            if let Err(e) = self.object_storage.upload_file(&key, sheet, Some(PNG_CONTENT_TYPE)).await {
+                let error = format!("Failed to write {}: {}", key, e);
+                self.record_contact_sheet_failure(task, &error, result).await;
+                return;
+            }
+            Some(key)
+        };
+
+        match self.mask_preview_repository
+            .mark_contact_sheet_ready(task, key.as_deref(), tiles.len() as i32)
+            .await
+        {
+            Ok(true) => result.contact_sheets += 1,
+            Ok(false) => {}
+            Err(e) => eprintln!("Failed to record contact sheet for mask group {}: {}", task.mask_group_id, e),
+        }
+    }
+
+    async fn record_contact_sheet_failure(&self, task: &ContactSheetTask, error: &str, result: &mut MaskPreviewRunResult) {
+        let retry_in_seconds = self.retry_policy.retry_delay(task.contact_sheet_attempts + 1);
+        if let Err(e) = self.mask_preview_repository
+            .record_contact_sheet_failure(task.mask_group_id, task.lease_until, error, retry_in_seconds)
+            .await
+        {
+            eprintln!("Failed to record contact sheet for mask group {}: {}", task.mask_group_id, e);
+            return;
+        }
+        eprintln!(
+            "Failed to generate contact sheet for mask group {} (attempt {}): {}",
+            task.mask_group_id, task.contact_sheet_attempts + 1, error
+        );
+        match retry_in_seconds {
+            Some(_) => result.retrying += 1,
+            None => result.failed += 1,
+        }
+    }
+
+    /// `interval`마다 미리보기를 기다리는 마스크와 contact sheet를 처리합니다. (서버 시작 시 백그라운드 작업으로 실행)
+    pub async fn run_preview_loop(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.process_pending().await {
+                Ok(result) if result.generated + result.contact_sheets + result.failed > 0 => {
+                    println!(
+                        "🖼️ Mask previews: {} generated, {} contact sheets, {} retrying, {} failed",
+                        result.generated, result.contact_sheets, result.retrying, result.failed
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to process mask previews: {}", e),
+            }
+        }
+    }
+}
+
+/// 마스크 PNG를 `(썸네일, 컬러 미리보기)` PNG로 렌더링 (디코딩할 수 없으면 재시도하지 않는 오류)
+pub fn render_mask_previews(data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let (width, height, labels) = imaging::decode_label_mask_png(data).map_err(|e| e.to_string())?;
+    let thumbnail = imaging::encode_thumbnail_png(width, height, &labels, THUMBNAIL_MAX_SIZE)
+        .map_err(|e| e.to_string())?;
+    let preview = imaging::encode_preview_png(width, height, &labels, PREVIEW_MAX_SIZE)
+        .map_err(|e| e.to_string())?;
+    Ok((thumbnail, preview))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_mask_previews() {
+        // 긴 변이 썸네일 크기를 넘는 마스크는 축소됨
+        let (width, height) = (THUMBNAIL_MAX_SIZE * 2, 4);
+        let mut labels = vec![0u8; (width * height) as usize];
+        labels[0] = 1;
+        let png = imaging::encode_binary_mask_png(width, height, &labels).unwrap();
+
+        let (thumbnail, preview) = render_mask_previews(&png).unwrap();
+        let (thumb_width, thumb_height, pixels) = imaging::decode_label_mask_png(&thumbnail).unwrap();
+        assert_eq!((thumb_width, thumb_height), (THUMBNAIL_MAX_SIZE, 2));
+        assert_eq!(pixels[0], 255);
+        assert!(!preview.is_empty());
+
+        assert!(render_mask_previews(b"not a png").is_err());
+    }
+}
@@ -6,7 +6,7 @@ use crate::application::dto::mask_dto::{
 use crate::domain::services::{MaskService, MaskGroupService};
 use crate::domain::ServiceError;
 use crate::application::services::SignedUrlService;
-use crate::domain::entities::{NewMask, UpdateMask, Mask};
+use crate::domain::entities::{NewMask, UpdateMask, Mask, MaskPreviewStatus};
 
 /// Mask 관리 유스케이스
 pub struct MaskUseCase<MS, MGS, SUS> 
@@ -38,6 +38,37 @@ where
         }
     }
 
+    /// 미리보기가 생성된 마스크에 썸네일/컬러 미리보기의 서명된 URL을 채움
+    async fn attach_preview_urls(&self, responses: &mut [MaskResponse]) -> Result<(), ServiceError> {
+        let mask_ids: Vec<i32> = responses.iter().map(|response| response.id).collect();
+        let previews = self.mask_service.get_mask_previews(&mask_ids).await?;
+
+        let mut targets = Vec::new();
+        let mut keys = Vec::new();
+        for preview in previews {
+            if preview.preview_status != MaskPreviewStatus::Ready {
+                continue;
+            }
+            if let (Some(thumbnail_key), Some(preview_key)) = (preview.thumbnail_key, preview.preview_key) {
+                targets.push(preview.mask_id);
+                keys.push(thumbnail_key);
+                keys.push(preview_key);
+            }
+        }
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let urls = self.signed_url_service.generate_mask_download_urls(keys, None).await?;
+        for (mask_id, pair) in targets.into_iter().zip(urls.chunks(2)) {
+            if let Some(response) = responses.iter_mut().find(|response| response.id == mask_id) {
+                response.thumbnail_url = Some(pair[0].url.clone());
+                response.preview_url = Some(pair[1].url.clone());
+            }
+        }
+        Ok(())
+    }
+
     /// Mask 생성
     pub async fn create_mask(
         &self,
@@ -76,6 +107,8 @@ where
             checksum: mask.checksum,
             width: mask.width,
             height: mask.height,
+            thumbnail_url: None,
+            preview_url: None,
             created_at: mask.created_at.to_string(),
             updated_at: mask.updated_at.map(|dt| dt.to_string())
                                         .unwrap_or("".to_string()),
@@ -92,7 +125,7 @@ where
             .await?
             .ok_or_else(|| ServiceError::NotFound(format!("Mask with ID {} not found", id)))?;
 
-        Ok(MaskResponse {
+        let mut response = MaskResponse {
             id: mask.id,
             mask_group_id: mask.mask_group_id,
             slice_index: mask.slice_index,
@@ -104,10 +137,15 @@ where
             checksum: mask.checksum,
             width: mask.width,
             height: mask.height,
+            thumbnail_url: None,
+            preview_url: None,
             created_at: mask.created_at.to_string(),
             updated_at: mask.updated_at.map(|dt| dt.to_string())
             .unwrap_or("".to_string()),
-        })
+        };
+        self.attach_preview_urls(std::slice::from_mut(&mut response)).await?;
+
+        Ok(response)
     }
 
     /// Mask 목록 조회
@@ -140,7 +178,7 @@ where
             .count_masks(mask_group_id, None, None, None)
             .await?;
 
-        let mask_responses: Vec<MaskResponse> = masks
+        let mut mask_responses: Vec<MaskResponse> = masks
             .into_iter()
             .map(|mask| MaskResponse {
                 id: mask.id,
@@ -154,12 +192,15 @@ where
                 checksum: mask.checksum,
                 width: mask.width,
                 height: mask.height,
+                thumbnail_url: None,
+                preview_url: None,
                 created_at: mask.created_at.to_string(),
                 updated_at: mask.updated_at
                 .map(|dt| dt.to_string())
                 .unwrap_or("".to_string()),
             })
             .collect();
+        self.attach_preview_urls(&mut mask_responses).await?;
 
         let page_size = limit.unwrap_or(50) as i32;
         let current_page = (offset.unwrap_or(0) / page_size as i64) as i32 + 1;
@@ -212,7 +253,7 @@ where
 
         let mask = self.mask_service.update_mask(id, &update_mask).await?;
 
-        Ok(MaskResponse {
+        let mut response = MaskResponse {
             id: mask.id,
             mask_group_id: mask.mask_group_id,
             slice_index: mask.slice_index,
@@ -224,10 +265,15 @@ where
             checksum: mask.checksum,
             width: mask.width,
             height: mask.height,
+            thumbnail_url: None,
+            preview_url: None,
             created_at: mask.created_at.to_string(),
             updated_at: mask.updated_at.map(|dt| dt.to_string())
             .unwrap_or("".to_string()),
-        })
+        };
+        self.attach_preview_urls(std::slice::from_mut(&mut response)).await?;
+
+        Ok(response)
     }
 
     /// Mask 삭제
@@ -0,0 +1,346 @@
+use std::sync::Arc;
+use std::time::Duration;
+use sha2::{Digest, Sha256};
+use crate::application::dto::mask_validation_dto::{
+    MaskGroupValidationResponse, MaskValidationCounts, MaskValidationRequeueResponse, MaskValidationResponse,
+};
+use crate::application::services::{ObjectStorageError, ObjectStorageService};
+use crate::domain::entities::{
+    checksums_differ, MaskLabelStats, MaskValidationOutcome, MaskValidationRunResult, MaskValidationStatus,
+    MaskValidationTask, RetryPolicy,
+};
+use crate::domain::repositories::MaskValidationRepository;
+use crate::domain::services::MaskGroupService;
+use crate::domain::ServiceError;
+use crate::infrastructure::imaging;
+
+/// 한 번에 검증하는 마스크 수
+const VALIDATION_BATCH_SIZE: i64 = 50;
+
+/// 검증 중인 마스크를 다른 작업이 가져가지 못하는 시간 (초)
+const VALIDATION_LEASE_SECONDS: i64 = 300;
+
+/// 마스크 서버 검증 유스케이스
+///
+/// 검증 대기 중인 마스크의 객체를 내려받아 체크섬, 크기, PNG 디코딩, 참조 인스턴스 크기를 확인하고
+/// 라벨 값별 통계를 기록합니다. 객체가 없거나 검사에 실패한 마스크는 `INVALID`로 표시하고,
+/// 스토리지 오류로 읽지 못한 마스크는 재시도 정책에 따라 다시 시도하거나 `ERROR`로 남깁니다.
+pub struct MaskValidationUseCase<R, MGS>
+where
+    R: MaskValidationRepository + Send + Sync,
+    MGS: MaskGroupService + Send + Sync,
+{
+    mask_validation_repository: Arc<R>,
+    mask_group_service: Arc<MGS>,
+    object_storage: Arc<dyn ObjectStorageService>,
+    retry_policy: RetryPolicy,
+}
+
+impl<R, MGS> MaskValidationUseCase<R, MGS>
+where
+    R: MaskValidationRepository + Send + Sync,
+    MGS: MaskGroupService + Send + Sync,
+{
+    pub fn new(
+        mask_validation_repository: Arc<R>,
+        mask_group_service: Arc<MGS>,
+        object_storage: Arc<dyn ObjectStorageService>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            mask_validation_repository,
+            mask_group_service,
+            object_storage,
+            retry_policy,
+        }
+    }
+
+    /// 마스크 그룹이 어노테이션에 속하고 사용자가 접근할 수 있는지 확인
+    async fn ensure_accessible(&self, annotation_id: i32, mask_group_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        let not_found = || ServiceError::NotFound(format!(
+            "Mask group {} not found in annotation {}",
+            mask_group_id, annotation_id
+        ));
+        self.mask_group_service
+            .get_mask_group_by_id(mask_group_id)
+            .await?
+            .filter(|group| group.annotation_id == annotation_id)
+            .ok_or_else(not_found)?;
+        match self.mask_group_service.can_access_mask_group(user_id, mask_group_id).await? {
+            true => Ok(()),
+            false => Err(not_found()),
+        }
+    }
+
+    /// 마스크 그룹의 검증 결과와 라벨별 합계
+    pub async fn get_validation(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        user_id: i32,
+    ) -> Result<MaskGroupValidationResponse, ServiceError> {
+        self.ensure_accessible(annotation_id, mask_group_id, user_id).await?;
+
+        let masks: Vec<MaskValidationResponse> = self.mask_validation_repository
+            .find_by_mask_group(mask_group_id)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        let labels = self.mask_validation_repository
+            .label_volumes(mask_group_id)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        let mut counts = MaskValidationCounts { total: masks.len(), ..Default::default() };
+        for mask in &masks {
+            match mask.status {
+                MaskValidationStatus::Pending => counts.pending += 1,
+                MaskValidationStatus::Valid => counts.valid += 1,
+                MaskValidationStatus::Invalid => counts.invalid += 1,
+                MaskValidationStatus::Error => counts.error += 1,
+            }
+        }
+
+        Ok(MaskGroupValidationResponse {
+            mask_group_id,
+            counts,
+            labels,
+            masks,
+        })
+    }
+
+    /// 마스크 그룹의 모든 마스크를 다시 검증하도록 요청
+    pub async fn requeue(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        user_id: i32,
+    ) -> Result<MaskValidationRequeueResponse, ServiceError> {
+        self.ensure_accessible(annotation_id, mask_group_id, user_id).await?;
+        let requeued = self.mask_validation_repository.requeue_mask_group(mask_group_id).await?;
+        Ok(MaskValidationRequeueResponse { mask_group_id, requeued })
+    }
+
+    /// 검증할 때가 된 마스크를 처리합니다.
+    pub async fn process_pending(&self) -> Result<MaskValidationRunResult, ServiceError> {
+        let mut result = MaskValidationRunResult::default();
+        let claimed = self.mask_validation_repository
+            .claim_pending(VALIDATION_BATCH_SIZE, VALIDATION_LEASE_SECONDS)
+            .await?;
+
+        // 기록에 실패한 마스크는 임대가 끝난 뒤 다시 처리됨
+        for task in claimed {
+            let outcome = match self.object_storage.download_file(&task.file_path).await {
+                Ok(data) => {
+                    let instance_dimensions = match task.sop_instance_uid.as_deref() {
+                        Some(uid) => match self.mask_validation_repository.find_instance_dimensions(task.project_id, uid).await {
+                            Ok(dimensions) => dimensions,
+                            Err(e) => {
+                                eprintln!("Failed to validate mask {}: {}", task.mask_id, e);
+                                continue;
+                            }
+                        },
+                        None => None,
+                    };
+                    inspect_mask_object(&task, &data, instance_dimensions)
+                }
+                Err(ObjectStorageError::FileNotFound(_)) => {
+                    MaskValidationOutcome::invalid(format!("Object {} not found in storage", task.file_path))
+                }
+                Err(e) => {
+                    let error = format!("Failed to read {}: {}", task.file_path, e);
+                    let retry_in_seconds = self.retry_policy.retry_delay(task.validation_attempts + 1);
+                    if let Err(e) = self.mask_validation_repository
+                        .record_failure(task.mask_id, task.lease_until, &error, retry_in_seconds)
+                        .await
+                    {
+                        eprintln!("Failed to record mask validation {}: {}", task.mask_id, e);
+                        continue;
+                    }
+                    eprintln!("Failed to validate mask {} (attempt {}): {}", task.mask_id, task.validation_attempts + 1, error);
+                    match retry_in_seconds {
+                        Some(_) => result.retrying += 1,
+                        None => result.errored += 1,
+                    }
+                    continue;
+                }
+            };
+
+            match self.mask_validation_repository.complete(&task, &outcome).await {
+                Ok(true) => match outcome.status {
+                    MaskValidationStatus::Valid => result.valid += 1,
+                    _ => result.invalid += 1,
+                },
+                Ok(false) => {}
+                Err(e) => eprintln!("Failed to record mask validation {}: {}", task.mask_id, e),
+            }
+        }
+        Ok(result)
+    }
+
+    /// `interval`마다 검증 대기 중인 마스크를 처리합니다. (서버 시작 시 백그라운드 작업으로 실행)
+    pub async fn run_validation_loop(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.process_pending().await {
+                Ok(result) if result.valid + result.invalid + result.errored > 0 => {
+                    println!(
+                        "🩻 Mask validation: {} valid, {} invalid, {} retrying, {} errored",
+                        result.valid, result.invalid, result.retrying, result.errored
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to process mask validations: {}", e),
+            }
+        }
+    }
+}
+
+/// 내려받은 마스크 객체 검사
+///
+/// 보고된 체크섬(SHA-256일 때)과 크기를 비교하고, PNG를 디코딩해 보고된 크기와 참조 인스턴스의
+/// `(rows, columns)`와 비교한 뒤 라벨 값별 복셀 수와 경계 상자를 계산합니다.
+pub fn inspect_mask_object(
+    task: &MaskValidationTask,
+    data: &[u8],
+    instance_dimensions: Option<(i32, i32)>,
+) -> MaskValidationOutcome {
+    let content_sha256 = hex::encode(Sha256::digest(data));
+    let mut errors = Vec::new();
+    if let Some(checksum) = task.checksum.as_deref() {
+        if checksums_differ(checksum, &content_sha256) {
+            errors.push(format!(
+                "Checksum mismatch: reported {}, computed {}",
+                checksum, content_sha256
+            ));
+        }
+    }
+
+    let mut outcome = MaskValidationOutcome {
+        status: MaskValidationStatus::Invalid,
+        errors: Vec::new(),
+        label_stats: Vec::new(),
+        content_sha256: Some(content_sha256),
+        file_size: Some(data.len() as i64),
+        width: None,
+        height: None,
+    };
+
+    let (width, height, labels) = match imaging::decode_label_mask_png(data) {
+        Ok(decoded) => decoded,
+        Err(e) => {
+            errors.push(e.to_string());
+            outcome.errors = errors;
+            return outcome;
+        }
+    };
+    let (width, height) = (width as i32, height as i32);
+
+    if task.width.is_some_and(|w| w != width) || task.height.is_some_and(|h| h != height) {
+        errors.push(format!(
+            "Reported size {}x{} does not match PNG size {}x{}",
+            task.width.map_or("?".to_string(), |w| w.to_string()),
+            task.height.map_or("?".to_string(), |h| h.to_string()),
+            width,
+            height
+        ));
+    }
+    if let Some((rows, columns)) = instance_dimensions {
+        if (rows, columns) != (height, width) {
+            errors.push(format!(
+                "PNG size {}x{} does not match instance {} ({} columns x {} rows)",
+                width,
+                height,
+                task.sop_instance_uid.as_deref().unwrap_or_default(),
+                columns,
+                rows
+            ));
+        }
+    }
+
+    let regions = imaging::label_regions(width as u32, height as u32, &labels);
+    let single_label = regions.len() == 1;
+    outcome.label_stats = regions
+        .into_iter()
+        .map(|region| MaskLabelStats {
+            label_value: region.label_value as i32,
+            label_name: if single_label { task.label_name.clone() } else { None },
+            voxel_count: region.voxel_count as i64,
+            bounding_box: region.bounding_box.map(|v| v as i32),
+        })
+        .collect();
+    outcome.width = Some(width);
+    outcome.height = Some(height);
+    if errors.is_empty() {
+        outcome.status = MaskValidationStatus::Valid;
+    }
+    outcome.errors = errors;
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn task(width: Option<i32>, height: Option<i32>, checksum: Option<String>) -> MaskValidationTask {
+        MaskValidationTask {
+            mask_id: 1,
+            mask_group_id: 2,
+            project_id: 3,
+            sop_instance_uid: Some("1.2.3.4".to_string()),
+            label_name: Some("liver".to_string()),
+            file_path: "masks/annotation_1/group_2/liver_000.png".to_string(),
+            checksum,
+            width,
+            height,
+            validation_attempts: 0,
+            lease_until: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_inspect_valid_mask() {
+        // 3x2 이진 마스크: 전경 (1,0), (1,1), (2,1)
+        let png = imaging::encode_binary_mask_png(3, 2, &[0, 1, 0, 0, 1, 1]).unwrap();
+        let sha256 = hex::encode(Sha256::digest(&png));
+
+        let outcome = inspect_mask_object(&task(Some(3), Some(2), Some(sha256.clone())), &png, Some((2, 3)));
+        assert_eq!(outcome.status, MaskValidationStatus::Valid, "{:?}", outcome.errors);
+        assert_eq!(outcome.file_size, Some(png.len() as i64));
+        assert_eq!(outcome.content_sha256, Some(sha256));
+        assert_eq!(
+            outcome.label_stats,
+            vec![MaskLabelStats {
+                label_value: 255,
+                label_name: Some("liver".to_string()),
+                voxel_count: 3,
+                bounding_box: [1, 0, 2, 2],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_inspect_flags_mismatches() {
+        let png = imaging::encode_binary_mask_png(3, 2, &[0, 1, 0, 0, 1, 1]).unwrap();
+
+        let outcome = inspect_mask_object(&task(Some(4), Some(2), Some("a".repeat(64))), &png, Some((3, 3)));
+        assert_eq!(outcome.status, MaskValidationStatus::Invalid);
+        assert_eq!(outcome.errors.len(), 3);
+        // 실제 크기는 그대로 기록
+        assert_eq!((outcome.width, outcome.height), (Some(3), Some(2)));
+
+        // 다른 알고리즘의 체크섬(MD5)은 비교하지 않음
+        let outcome = inspect_mask_object(&task(None, None, Some("b".repeat(32))), &png, None);
+        assert_eq!(outcome.status, MaskValidationStatus::Valid);
+
+        let outcome = inspect_mask_object(&task(None, None, None), b"not a png", None);
+        assert_eq!(outcome.status, MaskValidationStatus::Invalid);
+        assert_eq!(outcome.file_size, Some(9));
+        assert!(outcome.label_stats.is_empty());
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use crate::application::dto::measurement_dto::{
+    AnnotationMeasurementListResponse, AnnotationMeasurementResponse, InstanceMetadataListResponse, InstanceMetadataQuery,
+    InstanceMetadataResponse, MeasurementDiscrepancyQuery, RecomputeMeasurementsRequest, RecomputeMeasurementsResponse,
+    UpsertInstanceMetadataRequest,
+};
+use crate::domain::entities::measurement::{NewInstanceMetadata, PixelSpacing};
+use crate::domain::repositories::{MeasurementRepository, ProjectRepository};
+use crate::domain::services::ensure_project_admin;
+use crate::domain::ServiceError;
+
+/// 한 번에 등록할 수 있는 인스턴스 메타데이터 수
+pub const MAX_INSTANCE_METADATA_BATCH: usize = 1000;
+
+/// 프로젝트 전체 재계산에서 한 번에 가져오는 어노테이션 수
+const RECOMPUTE_BATCH_SIZE: i64 = 200;
+
+const DEFAULT_METADATA_LIST_LIMIT: i64 = 100;
+const MAX_METADATA_LIST_LIMIT: i64 = 1000;
+const DEFAULT_DISCREPANCY_LIST_LIMIT: i64 = 50;
+const MAX_DISCREPANCY_LIST_LIMIT: i64 = 500;
+
+/// 측정값 계산 유스케이스
+///
+/// 프로젝트 관리자는 인스턴스 메타데이터(픽셀 간격)를 등록하고 프로젝트 전체 측정값을 다시 계산할 수 있습니다.
+/// 프로젝트 멤버는 어노테이션의 계산된 측정값과 클라이언트 측정값과의 불일치를 조회하고 다시 계산할 수 있습니다.
+pub struct MeasurementUseCase<R>
+where
+    R: MeasurementRepository + Send + Sync,
+{
+    measurement_repository: Arc<R>,
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl<R> MeasurementUseCase<R>
+where
+    R: MeasurementRepository + Send + Sync,
+{
+    pub fn new(measurement_repository: Arc<R>, project_repository: Arc<dyn ProjectRepository>) -> Self {
+        Self { measurement_repository, project_repository }
+    }
+
+    async fn ensure_member(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        if !self.measurement_repository.is_project_member(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn ensure_admin(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        ensure_project_admin(self.project_repository.as_ref(), project_id, user_id, "manage instance metadata and recompute measurements").await
+    }
+
+    /// 인스턴스 메타데이터 등록 (요청하면 해당 Series의 어노테이션 측정값을 다시 계산)
+    pub async fn upsert_instance_metadata(
+        &self,
+        project_id: i32,
+        request: UpsertInstanceMetadataRequest,
+        user_id: i32,
+    ) -> Result<InstanceMetadataListResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        if request.instances.is_empty() {
+            return Err(ServiceError::ValidationError("At least one instance is required".to_string()));
+        }
+        if request.instances.len() > MAX_INSTANCE_METADATA_BATCH {
+            return Err(ServiceError::ValidationError(format!(
+                "At most {} instances can be registered at once",
+                MAX_INSTANCE_METADATA_BATCH
+            )));
+        }
+        let instances = request.instances
+            .into_iter()
+            .map(|item| {
+                let study_uid = required_uid("study_uid", item.study_uid)?;
+                let series_uid = required_uid("series_uid", item.series_uid)?;
+                let instance_uid = required_uid("instance_uid", item.instance_uid)?;
+                let pixel_spacing = parse_pixel_spacing(&item.pixel_spacing)
+                    .map_err(|e| ServiceError::ValidationError(format!("Instance {}: {}", instance_uid, e)))?;
+                Ok(NewInstanceMetadata {
+                    study_uid,
+                    series_uid,
+                    instance_uid,
+                    pixel_spacing,
+                    rows: item.rows,
+                    columns: item.columns,
+                })
+            })
+            .collect::<Result<Vec<_>, ServiceError>>()?;
+
+        let saved = self.measurement_repository
+            .upsert_instance_metadata(project_id, &instances, user_id)
+            .await?;
+
+        let recomputed_annotations = if request.recompute.unwrap_or(true) {
+            let series_uids: Vec<String> = instances
+                .iter()
+                .map(|instance| instance.series_uid.clone())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            let annotations = self.measurement_repository
+                .list_annotations_by_series(project_id, &series_uids)
+                .await?;
+            for annotation in &annotations {
+                self.measurement_repository.recompute(annotation).await?;
+            }
+            Some(annotations.len())
+        } else {
+            None
+        };
+
+        Ok(InstanceMetadataListResponse {
+            instances: saved.into_iter().map(InstanceMetadataResponse::from).collect(),
+            recomputed_annotations,
+        })
+    }
+
+    pub async fn list_instance_metadata(
+        &self,
+        project_id: i32,
+        query: InstanceMetadataQuery,
+        user_id: i32,
+    ) -> Result<InstanceMetadataListResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let limit = query.limit.unwrap_or(DEFAULT_METADATA_LIST_LIMIT).clamp(1, MAX_METADATA_LIST_LIMIT);
+        let instances = self.measurement_repository
+            .list_instance_metadata(project_id, query.study_uid.as_deref(), query.series_uid.as_deref(), limit)
+            .await?;
+        Ok(InstanceMetadataListResponse {
+            instances: instances.into_iter().map(InstanceMetadataResponse::from).collect(),
+            recomputed_annotations: None,
+        })
+    }
+
+    /// 어노테이션의 측정값 (아직 계산하지 않았으면 계산)
+    pub async fn get_measurement(
+        &self,
+        project_id: i32,
+        annotation_id: i32,
+        user_id: i32,
+    ) -> Result<AnnotationMeasurementResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let annotation = self.measurement_repository
+            .find_annotation(project_id, annotation_id)
+            .await?
+            .ok_or_else(|| annotation_not_found(annotation_id))?;
+        let measurement = match self.measurement_repository.find_measurement(annotation.id).await? {
+            Some(measurement) => measurement,
+            None => self.measurement_repository.recompute(&annotation).await?,
+        };
+        Ok(measurement.into())
+    }
+
+    /// 어노테이션의 측정값을 다시 계산
+    pub async fn recompute_measurement(
+        &self,
+        project_id: i32,
+        annotation_id: i32,
+        user_id: i32,
+    ) -> Result<AnnotationMeasurementResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let annotation = self.measurement_repository
+            .find_annotation(project_id, annotation_id)
+            .await?
+            .ok_or_else(|| annotation_not_found(annotation_id))?;
+        let measurement = self.measurement_repository.recompute(&annotation).await?;
+        Ok(measurement.into())
+    }
+
+    /// 프로젝트의 어노테이션 측정값을 다시 계산 (기본값은 계산 결과가 없는 어노테이션만)
+    pub async fn recompute_project(
+        &self,
+        project_id: i32,
+        request: RecomputeMeasurementsRequest,
+        user_id: i32,
+    ) -> Result<RecomputeMeasurementsResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        let only_missing = request.only_missing.unwrap_or(true);
+        let mut result = RecomputeMeasurementsResponse::default();
+        let mut after_id = 0;
+        loop {
+            let annotations = self.measurement_repository
+                .list_annotations_for_recompute(project_id, only_missing, after_id, RECOMPUTE_BATCH_SIZE)
+                .await?;
+            let Some(last) = annotations.last() else {
+                break;
+            };
+            after_id = last.id;
+
+            for annotation in &annotations {
+                let measurement = self.measurement_repository.recompute(annotation).await?;
+                result.processed += 1;
+                if measurement.has_discrepancy {
+                    result.with_discrepancy += 1;
+                }
+                if measurement.error.is_some() {
+                    result.failed += 1;
+                }
+            }
+            if (annotations.len() as i64) < RECOMPUTE_BATCH_SIZE {
+                break;
+            }
+        }
+        Ok(result)
+    }
+
+    /// 클라이언트 측정값과 차이가 있는 어노테이션 목록
+    pub async fn list_discrepancies(
+        &self,
+        project_id: i32,
+        query: MeasurementDiscrepancyQuery,
+        user_id: i32,
+    ) -> Result<AnnotationMeasurementListResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let limit = query.limit.unwrap_or(DEFAULT_DISCREPANCY_LIST_LIMIT).clamp(1, MAX_DISCREPANCY_LIST_LIMIT);
+        let measurements = self.measurement_repository.list_discrepancies(project_id, limit).await?;
+        Ok(AnnotationMeasurementListResponse {
+            measurements: measurements.into_iter().map(AnnotationMeasurementResponse::from).collect(),
+        })
+    }
+}
+
+fn annotation_not_found(annotation_id: i32) -> ServiceError {
+    ServiceError::NotFound(format!("Annotation with ID {} not found", annotation_id))
+}
+
+fn required_uid(field: &str, value: String) -> Result<String, ServiceError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ServiceError::ValidationError(format!("{} must not be empty", field)));
+    }
+    Ok(value.to_string())
+}
+
+/// DICOM Pixel Spacing 형식 ([행 간격, 열 간격])
+fn parse_pixel_spacing(values: &[f64]) -> Result<PixelSpacing, String> {
+    let [row, column] = values else {
+        return Err(format!("pixel_spacing must have 2 values [row, column], got {}", values.len()));
+    };
+    let spacing = PixelSpacing { row: *row, column: *column };
+    spacing.validate()?;
+    Ok(spacing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pixel_spacing() {
+        assert_eq!(parse_pixel_spacing(&[0.5, 0.7]), Ok(PixelSpacing { row: 0.5, column: 0.7 }));
+        assert!(parse_pixel_spacing(&[0.5]).is_err());
+        assert!(parse_pixel_spacing(&[0.5, 0.7, 1.0]).is_err());
+        assert!(parse_pixel_spacing(&[0.0, 0.7]).is_err());
+        assert!(parse_pixel_spacing(&[f64::NAN, 0.7]).is_err());
+    }
+}
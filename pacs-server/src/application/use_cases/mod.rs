@@ -4,8 +4,29 @@ pub mod project_use_case;
 pub mod permission_use_case;
 pub mod access_control_use_case;
 pub mod annotation_use_case;
+pub mod annotation_bundle_use_case;
+pub mod dataset_export_use_case;
+pub mod dataset_release_use_case;
+pub mod trash_use_case;
+pub mod label_use_case;
+pub mod worklist_use_case;
+pub mod edit_lock_use_case;
+pub mod realtime_use_case;
+pub mod webhook_use_case;
+pub mod measurement_use_case;
+pub mod annotation_propagation_use_case;
+pub mod multipart_upload_use_case;
+pub mod storage_deletion_use_case;
+pub mod storage_reconciliation_use_case;
+pub mod storage_quota_use_case;
+pub mod mask_group_archive_use_case;
+pub mod mask_validation_use_case;
+pub mod mask_preview_use_case;
+pub mod mask_group_lineage_use_case;
 pub mod mask_group_use_case;
 pub mod mask_use_case;
+pub mod mask_import_use_case;
+pub mod comment_use_case;
 pub mod project_user_use_case;
 pub mod project_user_matrix_use_case;
 pub mod user_project_matrix_use_case;
@@ -20,8 +41,29 @@ pub use project_use_case::ProjectUseCase;
 pub use permission_use_case::PermissionUseCase;
 pub use access_control_use_case::AccessControlUseCase;
 pub use annotation_use_case::AnnotationUseCase;
+pub use annotation_bundle_use_case::AnnotationBundleUseCase;
+pub use dataset_export_use_case::DatasetExportUseCase;
+pub use dataset_release_use_case::DatasetReleaseUseCase;
+pub use trash_use_case::TrashUseCase;
+pub use label_use_case::LabelUseCase;
+pub use worklist_use_case::WorklistUseCase;
+pub use edit_lock_use_case::EditLockUseCase;
+pub use realtime_use_case::RealtimeUseCase;
+pub use webhook_use_case::WebhookUseCase;
+pub use measurement_use_case::MeasurementUseCase;
+pub use annotation_propagation_use_case::AnnotationPropagationUseCase;
+pub use multipart_upload_use_case::MultipartUploadUseCase;
+pub use storage_deletion_use_case::StorageDeletionUseCase;
+pub use storage_reconciliation_use_case::StorageReconciliationUseCase;
+pub use storage_quota_use_case::StorageQuotaUseCase;
+pub use mask_group_archive_use_case::MaskGroupArchiveUseCase;
+pub use mask_validation_use_case::MaskValidationUseCase;
+pub use mask_preview_use_case::MaskPreviewUseCase;
+pub use mask_group_lineage_use_case::MaskGroupLineageUseCase;
 pub use mask_group_use_case::MaskGroupUseCase;
 pub use mask_use_case::MaskUseCase;
+pub use mask_import_use_case::MaskImportUseCase;
+pub use comment_use_case::CommentUseCase;
 pub use project_user_use_case::ProjectUserUseCase;
 pub use project_user_matrix_use_case::ProjectUserMatrixUseCase;
 pub use user_project_matrix_use_case::UserProjectMatrixUseCase;
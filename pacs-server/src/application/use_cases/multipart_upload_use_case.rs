@@ -0,0 +1,537 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use crate::application::dto::multipart_upload_dto::{
+    CompleteMultipartUploadRequest, InitiateMultipartUploadRequest, MultipartPartUrlResponse, MultipartPartUrlsRequest,
+    MultipartPartUrlsResponse, MultipartUploadDetailResponse, MultipartUploadListResponse, MultipartUploadResponse,
+    UploadedPartResponse,
+};
+use crate::application::services::object_storage_service::{
+    MAX_MULTIPART_PARTS, MAX_MULTIPART_PART_SIZE, MIN_MULTIPART_PART_SIZE,
+};
+use crate::application::services::{CompletedPart, ObjectStorageError, ObjectStorageService, UploadedPart};
+use crate::application::use_cases::mask_import_use_case::sanitize_path_segment;
+use crate::domain::entities::{
+    MaskGroup, MaskGroupMultipartUpload, MultipartUploadStatus, NewMaskGroupMultipartUpload, DEFAULT_MULTIPART_PART_SIZE,
+};
+use crate::domain::repositories::MultipartUploadRepository;
+use crate::domain::services::MaskGroupService;
+use crate::domain::ServiceError;
+
+/// 한 번에 발급할 수 있는 파트 업로드 URL 수
+pub const MAX_PART_URLS_PER_REQUEST: usize = 1000;
+
+/// 정리 작업이 한 번에 처리하는 만료 세션 수
+const CLEANUP_BATCH_SIZE: i64 = 100;
+
+/// 만료 세션 정리 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MultipartCleanupResult {
+    pub aborted: usize,
+    pub failures: usize,
+}
+
+/// 마스크 그룹 멀티파트 업로드 유스케이스
+///
+/// 큰 마스크 파일을 파트 단위로 업로드하고, 중단되면 스토리지에 올라간 파트를 확인해 빠진 파트만 다시 올릴 수 있게 합니다.
+/// 파트 URL을 발급할 때마다 세션 만료 시각이 연장되며, 만료된 세션은 `run_cleanup_loop`가 스토리지에서 중단합니다.
+pub struct MultipartUploadUseCase<MGS, R>
+where
+    MGS: MaskGroupService + Send + Sync,
+    R: MultipartUploadRepository + Send + Sync,
+{
+    mask_group_service: Arc<MGS>,
+    upload_repository: Arc<R>,
+    object_storage: Arc<dyn ObjectStorageService>,
+    default_ttl: u64,
+    max_ttl: u64,
+    expiry: chrono::Duration,
+}
+
+impl<MGS, R> MultipartUploadUseCase<MGS, R>
+where
+    MGS: MaskGroupService + Send + Sync,
+    R: MultipartUploadRepository + Send + Sync,
+{
+    pub fn new(
+        mask_group_service: Arc<MGS>,
+        upload_repository: Arc<R>,
+        object_storage: Arc<dyn ObjectStorageService>,
+        default_ttl: u64,
+        max_ttl: u64,
+        expiry: chrono::Duration,
+    ) -> Self {
+        Self {
+            mask_group_service,
+            upload_repository,
+            object_storage,
+            default_ttl,
+            max_ttl,
+            expiry,
+        }
+    }
+
+    /// 어노테이션에 속한 마스크 그룹이고 사용자가 접근할 수 있는지 확인
+    async fn ensure_mask_group(&self, annotation_id: i32, mask_group_id: i32, user_id: i32) -> Result<MaskGroup, ServiceError> {
+        let mask_group = self.mask_group_service
+            .get_mask_group_by_id(mask_group_id)
+            .await?
+            .filter(|group| group.annotation_id == annotation_id)
+            .ok_or_else(|| ServiceError::NotFound(format!(
+                "Mask group {} not found in annotation {}",
+                mask_group_id, annotation_id
+            )))?;
+        if !self.mask_group_service.can_access_mask_group(user_id, mask_group_id).await? {
+            return Err(ServiceError::Unauthorized("Access denied to mask group".to_string()));
+        }
+        Ok(mask_group)
+    }
+
+    async fn find_upload(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        upload_id: i32,
+        user_id: i32,
+    ) -> Result<MaskGroupMultipartUpload, ServiceError> {
+        self.ensure_mask_group(annotation_id, mask_group_id, user_id).await?;
+        self.upload_repository
+            .find(mask_group_id, upload_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!(
+                "Multipart upload {} not found in mask group {}",
+                upload_id, mask_group_id
+            )))
+    }
+
+    fn ensure_in_progress(upload: &MaskGroupMultipartUpload) -> Result<(), ServiceError> {
+        if upload.status != MultipartUploadStatus::InProgress {
+            return Err(ServiceError::ValidationError(format!(
+                "Multipart upload {} is {:?}",
+                upload.id, upload.status
+            )));
+        }
+        Ok(())
+    }
+
+    /// 멀티파트 업로드 시작
+    pub async fn initiate(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        request: InitiateMultipartUploadRequest,
+        user_id: i32,
+    ) -> Result<MultipartUploadResponse, ServiceError> {
+        self.ensure_mask_group(annotation_id, mask_group_id, user_id).await?;
+
+        let filename = sanitize_path_segment(&request.filename);
+        if filename.is_empty() {
+            return Err(ServiceError::ValidationError("Filename cannot be empty".to_string()));
+        }
+        let part_size = request.part_size.unwrap_or(DEFAULT_MULTIPART_PART_SIZE);
+        let part_count = plan_multipart_parts(request.total_size, part_size)?;
+
+        // 업로드 한도를 넘으면 세션을 시작하지 않음
+        let quota_warning = self.mask_group_service
+            .check_storage_quota(mask_group_id, Some(request.total_size))
+            .await?;
+
+        // 마스크 업로드 URL과 같은 경로 규칙 사용
+        let file_path = format!("masks/annotation_{}/group_{}/{}", annotation_id, mask_group_id, filename);
+        let existing = self.upload_repository.list_by_mask_group(mask_group_id).await?;
+        if let Some(active) = existing
+            .iter()
+            .find(|upload| upload.file_path == file_path && upload.status == MultipartUploadStatus::InProgress)
+        {
+            return Err(ServiceError::AlreadyExists(format!(
+                "Multipart upload {} for {} is in progress; resume or abort it",
+                active.id, filename
+            )));
+        }
+
+        let storage_upload_id = self.object_storage
+            .initiate_multipart_upload(&file_path, request.mime_type.as_deref())
+            .await
+            .map_err(|e| storage_error("start multipart upload", e))?;
+
+        let created = self.upload_repository
+            .create(&NewMaskGroupMultipartUpload {
+                mask_group_id,
+                file_path: file_path.clone(),
+                upload_id: storage_upload_id.clone(),
+                content_type: request.mime_type,
+                part_size,
+                total_size: request.total_size,
+                part_count,
+                created_by: user_id,
+                expires_at: Utc::now() + self.expiry,
+            })
+            .await;
+        match created {
+            Ok(upload) => Ok(MultipartUploadResponse {
+                quota_warning,
+                ..MultipartUploadResponse::from(upload)
+            }),
+            Err(e) => {
+                // 세션을 기록하지 못하면 스토리지 업로드도 남기지 않음
+                if let Err(abort_error) = self.object_storage.abort_multipart_upload(&file_path, &storage_upload_id).await {
+                    eprintln!("Failed to abort multipart upload {}: {}", storage_upload_id, abort_error);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// 마스크 그룹의 멀티파트 업로드 목록
+    pub async fn list(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        user_id: i32,
+    ) -> Result<MultipartUploadListResponse, ServiceError> {
+        self.ensure_mask_group(annotation_id, mask_group_id, user_id).await?;
+        let uploads = self.upload_repository.list_by_mask_group(mask_group_id).await?;
+        Ok(MultipartUploadListResponse {
+            uploads: uploads.into_iter().map(MultipartUploadResponse::from).collect(),
+        })
+    }
+
+    /// 업로드 진행 상태 (이어받기 시 업로드된 파트와 빠진 파트 확인)
+    pub async fn get(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        upload_id: i32,
+        user_id: i32,
+    ) -> Result<MultipartUploadDetailResponse, ServiceError> {
+        let upload = self.find_upload(annotation_id, mask_group_id, upload_id, user_id).await?;
+
+        let uploaded_parts = if upload.status == MultipartUploadStatus::InProgress {
+            self.list_parts(&upload).await?
+        } else {
+            Vec::new()
+        };
+        let missing_parts = if upload.status == MultipartUploadStatus::InProgress {
+            upload.missing_parts(&uploaded_parts.iter().map(|part| part.part_number).collect::<Vec<_>>())
+        } else {
+            Vec::new()
+        };
+        let uploaded_bytes = uploaded_parts.iter().map(|part| part.size).sum();
+
+        Ok(MultipartUploadDetailResponse {
+            upload: MultipartUploadResponse::from(upload),
+            uploaded_parts: uploaded_parts
+                .into_iter()
+                .map(|part| UploadedPartResponse { part_number: part.part_number, etag: part.etag, size: part.size })
+                .collect(),
+            missing_parts,
+            uploaded_bytes,
+        })
+    }
+
+    /// 파트 업로드용 Signed URL 발급 (세션 만료 시각 연장)
+    pub async fn generate_part_urls(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        upload_id: i32,
+        request: MultipartPartUrlsRequest,
+        user_id: i32,
+    ) -> Result<MultipartPartUrlsResponse, ServiceError> {
+        let upload = self.find_upload(annotation_id, mask_group_id, upload_id, user_id).await?;
+        Self::ensure_in_progress(&upload)?;
+
+        let part_numbers: Vec<i32> = match request.part_numbers {
+            Some(numbers) => numbers.into_iter().collect::<BTreeSet<_>>().into_iter().collect(),
+            None => {
+                let uploaded: Vec<i32> = self.list_parts(&upload).await?.iter().map(|part| part.part_number).collect();
+                upload.missing_parts(&uploaded)
+            }
+        };
+        if let Some(invalid) = part_numbers.iter().find(|n| upload.expected_part_size(**n).is_none()) {
+            return Err(ServiceError::ValidationError(format!(
+                "Part number {} is out of range 1..={}",
+                invalid, upload.part_count
+            )));
+        }
+        if part_numbers.len() > MAX_PART_URLS_PER_REQUEST {
+            return Err(ServiceError::ValidationError(format!(
+                "At most {} part URLs can be requested at once",
+                MAX_PART_URLS_PER_REQUEST
+            )));
+        }
+
+        let upload = self.upload_repository
+            .extend_expiry(upload.id, Utc::now() + self.expiry)
+            .await?
+            .ok_or_else(|| ServiceError::ValidationError(format!("Multipart upload {} is no longer in progress", upload_id)))?;
+
+        let ttl_seconds = request.ttl_seconds.unwrap_or(self.default_ttl).clamp(1, self.max_ttl);
+        let mut parts = Vec::with_capacity(part_numbers.len());
+        for part_number in part_numbers {
+            let upload_url = self.object_storage
+                .generate_upload_part_url(&upload.file_path, &upload.upload_id, part_number, ttl_seconds)
+                .await
+                .map_err(|e| storage_error("generate part upload URL", e))?;
+            parts.push(MultipartPartUrlResponse {
+                part_number,
+                upload_url,
+                size: upload.expected_part_size(part_number).unwrap_or_default(),
+            });
+        }
+
+        Ok(MultipartPartUrlsResponse {
+            upload_id: upload.id,
+            parts,
+            expires_in: ttl_seconds,
+            expires_at: (Utc::now() + chrono::Duration::seconds(ttl_seconds as i64)).to_rfc3339(),
+        })
+    }
+
+    /// 업로드된 파트를 조립해 업로드 완료
+    ///
+    /// 스토리지에 올라간 파트를 기준으로 모든 파트가 올바른 크기로 업로드되었는지 확인합니다.
+    /// 요청에 파트 목록이 있으면 ETag가 스토리지의 값과 같아야 합니다.
+    pub async fn complete(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        upload_id: i32,
+        request: CompleteMultipartUploadRequest,
+        user_id: i32,
+    ) -> Result<MultipartUploadResponse, ServiceError> {
+        let upload = self.find_upload(annotation_id, mask_group_id, upload_id, user_id).await?;
+        Self::ensure_in_progress(&upload)?;
+
+        let uploaded = self.list_parts(&upload).await?;
+        let parts = verify_parts(&upload, &uploaded, request.parts.as_deref())?;
+
+        self.object_storage
+            .complete_multipart_upload(&upload.file_path, &upload.upload_id, &parts)
+            .await
+            .map_err(|e| storage_error("complete multipart upload", e))?;
+
+        let completed = self.upload_repository
+            .mark_completed(upload.id)
+            .await?
+            .ok_or_else(|| ServiceError::ValidationError(format!("Multipart upload {} is no longer in progress", upload_id)))?;
+        Ok(MultipartUploadResponse::from(completed))
+    }
+
+    /// 업로드를 중단하고 업로드된 파트 삭제
+    pub async fn abort(
+        &self,
+        annotation_id: i32,
+        mask_group_id: i32,
+        upload_id: i32,
+        user_id: i32,
+    ) -> Result<MultipartUploadResponse, ServiceError> {
+        let upload = self.find_upload(annotation_id, mask_group_id, upload_id, user_id).await?;
+        Self::ensure_in_progress(&upload)?;
+
+        self.object_storage
+            .abort_multipart_upload(&upload.file_path, &upload.upload_id)
+            .await
+            .map_err(|e| storage_error("abort multipart upload", e))?;
+
+        let aborted = self.upload_repository
+            .mark_aborted(upload.id)
+            .await?
+            .ok_or_else(|| ServiceError::ValidationError(format!("Multipart upload {} is no longer in progress", upload_id)))?;
+        Ok(MultipartUploadResponse::from(aborted))
+    }
+
+    async fn list_parts(&self, upload: &MaskGroupMultipartUpload) -> Result<Vec<UploadedPart>, ServiceError> {
+        self.object_storage
+            .list_uploaded_parts(&upload.file_path, &upload.upload_id)
+            .await
+            .map_err(|e| storage_error("list uploaded parts", e))
+    }
+
+    /// 만료 시각이 지난 진행 중인 업로드를 스토리지에서 중단
+    pub async fn cleanup_expired(&self) -> Result<MultipartCleanupResult, ServiceError> {
+        let mut result = MultipartCleanupResult::default();
+        let expired = self.upload_repository.list_expired(Utc::now(), CLEANUP_BATCH_SIZE).await?;
+        for upload in expired {
+            match self.object_storage.abort_multipart_upload(&upload.file_path, &upload.upload_id).await {
+                Ok(()) => {
+                    if self.upload_repository.mark_aborted(upload.id).await?.is_some() {
+                        result.aborted += 1;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Failed to abort stale multipart upload {}: {}", upload.id, e);
+                    result.failures += 1;
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// `interval`마다 만료된 멀티파트 업로드를 정리합니다. (서버 시작 시 백그라운드 작업으로 실행)
+    pub async fn run_cleanup_loop(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.cleanup_expired().await {
+                Ok(result) if result != MultipartCleanupResult::default() => {
+                    println!(
+                        "📦 Multipart upload cleanup: {} stale uploads aborted ({} failed)",
+                        result.aborted, result.failures
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to clean up multipart uploads: {}", e),
+            }
+        }
+    }
+}
+
+/// 스토리지 에러를 서비스 에러로 변환
+fn storage_error(context: &str, e: ObjectStorageError) -> ServiceError {
+    match e {
+        ObjectStorageError::InvalidRequest(message) => ServiceError::ValidationError(message),
+        ObjectStorageError::FileNotFound(message) => ServiceError::NotFound(message),
+        e => ServiceError::ExternalServiceError(format!("Failed to {}: {}", context, e)),
+    }
+}
+
+/// 전체 크기와 파트 크기로 파트 수를 계산
+///
+/// 파트 크기는 S3 규격(5 MiB ~ 5 GiB, 최대 10,000 파트)을 따라야 합니다.
+/// 파일이 파트 크기보다 작으면 파트 하나로 업로드합니다.
+fn plan_multipart_parts(total_size: i64, part_size: i64) -> Result<i32, ServiceError> {
+    if total_size <= 0 {
+        return Err(ServiceError::ValidationError("total_size must be positive".to_string()));
+    }
+    if !(MIN_MULTIPART_PART_SIZE..=MAX_MULTIPART_PART_SIZE).contains(&part_size) {
+        return Err(ServiceError::ValidationError(format!(
+            "part_size must be between {} and {} bytes",
+            MIN_MULTIPART_PART_SIZE, MAX_MULTIPART_PART_SIZE
+        )));
+    }
+    let part_count = (total_size + part_size - 1) / part_size;
+    if part_count > MAX_MULTIPART_PARTS as i64 {
+        return Err(ServiceError::ValidationError(format!(
+            "File needs {} parts of {} bytes; at most {} parts are allowed, use a larger part_size",
+            part_count, part_size, MAX_MULTIPART_PARTS
+        )));
+    }
+    Ok(part_count as i32)
+}
+
+/// 스토리지에 업로드된 파트로 완료할 파트 목록을 만듦
+fn verify_parts(
+    upload: &MaskGroupMultipartUpload,
+    uploaded: &[UploadedPart],
+    requested: Option<&[crate::application::dto::multipart_upload_dto::CompletedPartRequest]>,
+) -> Result<Vec<CompletedPart>, ServiceError> {
+    let uploaded_numbers: Vec<i32> = uploaded.iter().map(|part| part.part_number).collect();
+    let missing = upload.missing_parts(&uploaded_numbers);
+    if !missing.is_empty() {
+        return Err(ServiceError::ValidationError(format!("Parts {:?} have not been uploaded", missing)));
+    }
+
+    let wrong_size: Vec<i32> = uploaded
+        .iter()
+        .filter(|part| upload.expected_part_size(part.part_number) != Some(part.size))
+        .map(|part| part.part_number)
+        .collect();
+    if !wrong_size.is_empty() {
+        return Err(ServiceError::ValidationError(format!(
+            "Parts {:?} do not match the planned part size; upload them again",
+            wrong_size
+        )));
+    }
+
+    if let Some(requested) = requested {
+        let etags: HashMap<i32, &str> = uploaded.iter().map(|part| (part.part_number, part.etag.as_str())).collect();
+        let mismatched: Vec<i32> = requested
+            .iter()
+            .filter(|part| etags.get(&part.part_number).map(|etag| normalize_etag(etag)) != Some(normalize_etag(&part.etag)))
+            .map(|part| part.part_number)
+            .collect();
+        if !mismatched.is_empty() {
+            return Err(ServiceError::ValidationError(format!(
+                "Parts {:?} do not match the uploaded parts",
+                mismatched
+            )));
+        }
+    }
+
+    Ok(uploaded
+        .iter()
+        .filter(|part| part.part_number <= upload.part_count)
+        .map(|part| CompletedPart { part_number: part.part_number, etag: part.etag.clone() })
+        .collect())
+}
+
+/// ETag 비교용 (따옴표 유무 무시)
+fn normalize_etag(etag: &str) -> &str {
+    etag.trim().trim_matches('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::multipart_upload_dto::CompletedPartRequest;
+
+    const MIB: i64 = 1024 * 1024;
+
+    fn upload(total_size: i64, part_size: i64) -> MaskGroupMultipartUpload {
+        let now = Utc::now();
+        MaskGroupMultipartUpload {
+            id: 1,
+            mask_group_id: 1,
+            file_path: "masks/annotation_1/group_1/volume.nii.gz".to_string(),
+            upload_id: "upload".to_string(),
+            content_type: None,
+            part_size,
+            total_size,
+            part_count: plan_multipart_parts(total_size, part_size).unwrap(),
+            status: MultipartUploadStatus::InProgress,
+            created_by: Some(1),
+            created_at: now,
+            updated_at: now,
+            expires_at: now,
+            completed_at: None,
+        }
+    }
+
+    fn part(part_number: i32, size: i64) -> UploadedPart {
+        UploadedPart { part_number, etag: format!("\"etag-{}\"", part_number), size }
+    }
+
+    #[test]
+    fn test_plan_multipart_parts() {
+        assert_eq!(plan_multipart_parts(MIB, 5 * MIB).unwrap(), 1);
+        assert_eq!(plan_multipart_parts(10 * MIB, 5 * MIB).unwrap(), 2);
+        assert_eq!(plan_multipart_parts(10 * MIB + 1, 5 * MIB).unwrap(), 3);
+        assert!(plan_multipart_parts(0, 5 * MIB).is_err());
+        assert!(plan_multipart_parts(10 * MIB, MIB).is_err());
+        assert!(plan_multipart_parts(10_001 * 5 * MIB, 5 * MIB).is_err());
+    }
+
+    #[test]
+    fn test_verify_parts() {
+        let upload = upload(12 * MIB, 5 * MIB);
+
+        let missing = verify_parts(&upload, &[part(1, 5 * MIB), part(3, 2 * MIB)], None);
+        assert!(matches!(missing, Err(ServiceError::ValidationError(message)) if message.contains("[2]")));
+
+        let wrong_size = verify_parts(&upload, &[part(1, 5 * MIB), part(2, 4 * MIB), part(3, 2 * MIB)], None);
+        assert!(matches!(wrong_size, Err(ServiceError::ValidationError(message)) if message.contains("[2]")));
+
+        let uploaded = [part(1, 5 * MIB), part(2, 5 * MIB), part(3, 2 * MIB)];
+        let parts = verify_parts(&upload, &uploaded, None).unwrap();
+        assert_eq!(parts.iter().map(|p| p.part_number).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let requested = vec![
+            CompletedPartRequest { part_number: 1, etag: "etag-1".to_string() },
+            CompletedPartRequest { part_number: 2, etag: "stale".to_string() },
+        ];
+        let mismatched = verify_parts(&upload, &uploaded, Some(&requested));
+        assert!(matches!(mismatched, Err(ServiceError::ValidationError(message)) if message.contains("[2]")));
+    }
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use crate::application::dto::realtime_dto::RealtimeEventQuery;
+use crate::domain::entities::realtime::{RealtimeEvent, RealtimeSubscription};
+use crate::domain::repositories::{ProjectRepository, RealtimeRepository};
+use crate::domain::services::PROJECT_ADMIN_ROLE;
+use crate::domain::ServiceError;
+
+/// 워커당 보관하는 이벤트 수 (이보다 뒤처진 구독자는 RESYNC를 받음)
+pub const DEFAULT_REALTIME_BUFFER: usize = 1024;
+
+/// 구독 권한과 이벤트 수신기
+pub struct RealtimeFeed {
+    pub subscription: RealtimeSubscription,
+    pub receiver: broadcast::Receiver<RealtimeEvent>,
+}
+
+/// 실시간 변경 피드 유스케이스
+///
+/// 리스너 작업이 모든 서버 워커에서 발행된 이벤트를 받아 이 워커의 구독자에게 나눠주고,
+/// 구독자는 구독 시점에 확인한 권한에 따라 받을 이벤트를 거릅니다.
+pub struct RealtimeUseCase<R>
+where
+    R: RealtimeRepository + Send + Sync,
+{
+    realtime_repository: Arc<R>,
+    project_repository: Arc<dyn ProjectRepository>,
+    sender: broadcast::Sender<RealtimeEvent>,
+}
+
+impl<R> RealtimeUseCase<R>
+where
+    R: RealtimeRepository + Send + Sync,
+{
+    pub fn new(realtime_repository: Arc<R>, project_repository: Arc<dyn ProjectRepository>, buffer: usize) -> Self {
+        let (sender, _) = broadcast::channel(buffer);
+        Self { realtime_repository, project_repository, sender }
+    }
+
+    /// 프로젝트(또는 Study) 이벤트 구독
+    pub async fn subscribe(&self, project_id: i32, query: RealtimeEventQuery, user_id: i32) -> Result<RealtimeFeed, ServiceError> {
+        if !self.realtime_repository.is_project_member(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+        let role = self.project_repository
+            .find_member_role(project_id, user_id)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to get project role: {}", e)))?;
+
+        Ok(RealtimeFeed {
+            subscription: RealtimeSubscription {
+                project_id,
+                study_uid: query.study_uid.filter(|uid| !uid.trim().is_empty()),
+                user_id,
+                is_project_admin: role.as_deref() == Some(PROJECT_ADMIN_ROLE),
+            },
+            receiver: self.sender.subscribe(),
+        })
+    }
+
+    /// 구독자가 아직 프로젝트 멤버인지 확인 (멤버에서 빠지면 구독을 끊음)
+    pub async fn is_still_member(&self, subscription: &RealtimeSubscription) -> Result<bool, ServiceError> {
+        self.realtime_repository
+            .is_project_member(subscription.project_id, subscription.user_id)
+            .await
+    }
+
+    /// 이벤트를 받아 구독자에게 전달합니다. (서버 시작 시 백그라운드 작업으로 실행)
+    ///
+    /// 수신이 끊기면 `retry_delay` 후에 다시 연결합니다.
+    pub async fn run_listener_loop(&self, retry_delay: Duration) {
+        loop {
+            if let Err(e) = self.realtime_repository.listen(&self.sender).await {
+                eprintln!("Realtime listener stopped: {}", e);
+            }
+            tokio::time::sleep(retry_delay).await;
+        }
+    }
+}
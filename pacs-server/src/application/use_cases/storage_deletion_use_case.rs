@@ -0,0 +1,150 @@
+use std::sync::Arc;
+use std::time::Duration;
+use crate::application::services::{ObjectStorageError, ObjectStorageService};
+use crate::domain::entities::{RetryPolicy, StorageDeletion, StorageDeletionRunResult};
+use crate::domain::repositories::StorageDeletionRepository;
+use crate::domain::ServiceError;
+
+/// 한 번에 처리하는 대기열 항목 수
+const DELETION_BATCH_SIZE: i64 = 100;
+
+/// 처리 중인 항목을 다른 작업이 가져가지 못하는 시간 (초)
+const DELETION_LEASE_SECONDS: i64 = 300;
+
+/// prefix 항목을 한 번 나열할 때의 객체 수
+const PREFIX_PAGE_SIZE: i32 = 1000;
+
+/// prefix 항목 하나를 한 번에 처리할 때 나열하는 최대 페이지 수 (남으면 재시도로 이어서 처리)
+const MAX_PREFIX_PAGES: usize = 100;
+
+/// Object Storage 삭제 대기열 유스케이스
+///
+/// 마스크 삭제와 휴지통 영구 삭제가 기록한 객체를 지웁니다. 이미 없는 객체는 삭제된 것으로 보고,
+/// 실패한 항목은 재시도 정책에 따라 다시 시도하거나 dead-letter로 남깁니다.
+pub struct StorageDeletionUseCase<R>
+where
+    R: StorageDeletionRepository + Send + Sync,
+{
+    storage_deletion_repository: Arc<R>,
+    object_storage: Arc<dyn ObjectStorageService>,
+    retry_policy: RetryPolicy,
+}
+
+impl<R> StorageDeletionUseCase<R>
+where
+    R: StorageDeletionRepository + Send + Sync,
+{
+    pub fn new(
+        storage_deletion_repository: Arc<R>,
+        object_storage: Arc<dyn ObjectStorageService>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            storage_deletion_repository,
+            object_storage,
+            retry_policy,
+        }
+    }
+
+    /// 시도할 때가 된 대기열 항목을 처리합니다.
+    pub async fn process_due(&self) -> Result<StorageDeletionRunResult, ServiceError> {
+        let mut result = StorageDeletionRunResult::default();
+        let claimed = self.storage_deletion_repository
+            .claim_due(DELETION_BATCH_SIZE, DELETION_LEASE_SECONDS)
+            .await?;
+
+        for deletion in claimed {
+            let outcome = if deletion.is_prefix {
+                self.delete_prefix(&deletion.object_key).await
+            } else {
+                self.delete_object(&deletion.object_key).await.map(|()| 1)
+            };
+
+            // 기록에 실패한 항목은 임대가 끝난 뒤 다시 처리됨
+            match outcome {
+                Ok(objects_deleted) => {
+                    if let Err(e) = self.storage_deletion_repository.mark_deleted(deletion.id).await {
+                        eprintln!("Failed to record storage deletion {}: {}", deletion.id, e);
+                        continue;
+                    }
+                    result.completed += 1;
+                    result.objects_deleted += objects_deleted;
+                }
+                Err(error) => {
+                    let retry_in_seconds = self.retry_policy.retry_delay(deletion.attempt_count + 1);
+                    if let Err(e) = self.storage_deletion_repository
+                        .record_failure(deletion.id, &error, retry_in_seconds)
+                        .await
+                    {
+                        eprintln!("Failed to record storage deletion {}: {}", deletion.id, e);
+                        continue;
+                    }
+                    Self::log_failure(&deletion, &error, retry_in_seconds);
+                    match retry_in_seconds {
+                        Some(_) => result.retrying += 1,
+                        None => result.dead += 1,
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// 객체 하나 삭제 (이미 없으면 성공)
+    async fn delete_object(&self, object_key: &str) -> Result<(), String> {
+        match self.object_storage.delete_file(object_key).await {
+            Ok(()) | Err(ObjectStorageError::FileNotFound(_)) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// prefix로 시작하는 객체를 모두 삭제하고 삭제한 객체 수를 반환
+    async fn delete_prefix(&self, prefix: &str) -> Result<usize, String> {
+        let mut deleted = 0;
+        for _ in 0..MAX_PREFIX_PAGES {
+            let object_keys = self.object_storage
+                .list_files(prefix, Some(PREFIX_PAGE_SIZE))
+                .await
+                .map_err(|e| e.to_string())?;
+            for object_key in &object_keys {
+                self.delete_object(object_key).await?;
+                deleted += 1;
+            }
+            if object_keys.len() < PREFIX_PAGE_SIZE as usize {
+                return Ok(deleted);
+            }
+        }
+        Err(format!("{} still has objects after deleting {}", prefix, deleted))
+    }
+
+    fn log_failure(deletion: &StorageDeletion, error: &str, retry_in_seconds: Option<i64>) {
+        match retry_in_seconds {
+            Some(seconds) => eprintln!(
+                "Failed to delete storage object {} (attempt {}, retrying in {}s): {}",
+                deletion.object_key, deletion.attempt_count + 1, seconds, error
+            ),
+            None => eprintln!(
+                "Failed to delete storage object {} after {} attempts, moved to dead letters: {}",
+                deletion.object_key, deletion.attempt_count + 1, error
+            ),
+        }
+    }
+
+    /// `interval`마다 삭제 대기열을 처리합니다. (서버 시작 시 백그라운드 작업으로 실행)
+    pub async fn run_deletion_loop(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.process_due().await {
+                Ok(result) if result.completed > 0 => {
+                    println!(
+                        "🧹 Storage deletion: {} objects deleted ({} entries completed, {} retrying, {} dead)",
+                        result.objects_deleted, result.completed, result.retrying, result.dead
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to process storage deletions: {}", e),
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+use crate::application::dto::storage_quota_dto::{
+    ProjectStorageUsageResponse, StorageQuotaResponse, StorageUsageEntryResponse, StorageUsageRecalculationResponse,
+    UpdateStorageQuotaRequest, UserStorageUsageResponse,
+};
+use crate::domain::entities::storage_quota::{
+    validate_quota_limits, StorageQuota, StorageQuotaLevel, StorageQuotaScope, StorageQuotaStatus, StorageUsageTotals,
+};
+use crate::domain::repositories::{ProjectRepository, StorageQuotaRepository};
+use crate::domain::services::ensure_project_admin;
+use crate::domain::ServiceError;
+
+/// 저장 용량 사용량/한도 유스케이스
+///
+/// 프로젝트 멤버는 프로젝트 사용량을, 사용자는 자신의 사용량을 조회할 수 있습니다.
+/// 프로젝트 한도는 프로젝트 관리자가, 사용자 한도는 관리자 API로 설정합니다.
+/// 한도 적용은 업로드 URL 발급 시 `MaskGroupService::check_storage_quota`에서 이루어집니다.
+pub struct StorageQuotaUseCase<R>
+where
+    R: StorageQuotaRepository + Send + Sync,
+{
+    quota_repository: Arc<R>,
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl<R> StorageQuotaUseCase<R>
+where
+    R: StorageQuotaRepository + Send + Sync,
+{
+    pub fn new(quota_repository: Arc<R>, project_repository: Arc<dyn ProjectRepository>) -> Self {
+        Self { quota_repository, project_repository }
+    }
+
+    pub async fn get_project_usage(&self, project_id: i32, user_id: i32) -> Result<ProjectStorageUsageResponse, ServiceError> {
+        let role = self.project_repository
+            .find_member_role(project_id, user_id)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to get project role: {}", e)))?;
+        if role.is_none() {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+
+        let scope = StorageQuotaScope::Project;
+        let totals = self.quota_repository.get_usage_totals(scope, project_id).await?;
+        let quota = self.quota_repository.find_quota(scope, project_id).await?;
+        let users = self.quota_repository.list_usage(scope, project_id).await?;
+
+        Ok(ProjectStorageUsageResponse {
+            project_id,
+            bytes_used: totals.bytes_used,
+            object_count: totals.object_count,
+            quota_level: current_level(scope, project_id, &totals, quota.as_ref()),
+            quota: quota.map(StorageQuotaResponse::from),
+            users: users.into_iter().map(StorageUsageEntryResponse::from).collect(),
+        })
+    }
+
+    /// 프로젝트 한도 설정 (프로젝트 관리자만)
+    pub async fn set_project_quota(
+        &self,
+        project_id: i32,
+        request: UpdateStorageQuotaRequest,
+        user_id: i32,
+    ) -> Result<StorageQuotaResponse, ServiceError> {
+        ensure_project_admin(self.project_repository.as_ref(), project_id, user_id, "set the storage quota").await?;
+        self.set_quota(StorageQuotaScope::Project, project_id, request, user_id).await
+    }
+
+    /// 사용자 사용량 (본인만)
+    pub async fn get_user_usage(&self, target_user_id: i32, user_id: i32) -> Result<UserStorageUsageResponse, ServiceError> {
+        if target_user_id != user_id {
+            return Err(ServiceError::Unauthorized("Users can only view their own storage usage".to_string()));
+        }
+
+        let scope = StorageQuotaScope::User;
+        let totals = self.quota_repository.get_usage_totals(scope, target_user_id).await?;
+        let quota = self.quota_repository.find_quota(scope, target_user_id).await?;
+        let projects = self.quota_repository.list_usage(scope, target_user_id).await?;
+
+        Ok(UserStorageUsageResponse {
+            user_id: target_user_id,
+            bytes_used: totals.bytes_used,
+            object_count: totals.object_count,
+            quota_level: current_level(scope, target_user_id, &totals, quota.as_ref()),
+            quota: quota.map(StorageQuotaResponse::from),
+            projects: projects.into_iter().map(StorageUsageEntryResponse::from).collect(),
+        })
+    }
+
+    /// 사용자 한도 설정 (관리자 API)
+    pub async fn set_user_quota(
+        &self,
+        target_user_id: i32,
+        request: UpdateStorageQuotaRequest,
+        admin_id: i32,
+    ) -> Result<StorageQuotaResponse, ServiceError> {
+        self.set_quota(StorageQuotaScope::User, target_user_id, request, admin_id).await
+    }
+
+    /// 마스크 행으로 사용량 전체를 다시 계산 (관리자 API)
+    pub async fn recalculate_usage(&self) -> Result<StorageUsageRecalculationResponse, ServiceError> {
+        let usage_rows = self.quota_repository.recalculate_usage().await?;
+        Ok(StorageUsageRecalculationResponse { usage_rows })
+    }
+
+    async fn set_quota(
+        &self,
+        scope: StorageQuotaScope,
+        scope_id: i32,
+        request: UpdateStorageQuotaRequest,
+        updated_by: i32,
+    ) -> Result<StorageQuotaResponse, ServiceError> {
+        validate_quota_limits(request.soft_limit_bytes, request.hard_limit_bytes).map_err(ServiceError::ValidationError)?;
+        if !self.quota_repository.scope_exists(scope, scope_id).await? {
+            let kind = match scope {
+                StorageQuotaScope::Project => "Project",
+                StorageQuotaScope::User => "User",
+            };
+            return Err(ServiceError::NotFound(format!("{} with ID {} not found", kind, scope_id)));
+        }
+
+        let quota = self.quota_repository
+            .upsert_quota(scope, scope_id, request.soft_limit_bytes, request.hard_limit_bytes, updated_by)
+            .await?;
+        Ok(StorageQuotaResponse::from(quota))
+    }
+}
+
+/// 현재 사용량의 한도 상태
+fn current_level(
+    scope: StorageQuotaScope,
+    scope_id: i32,
+    totals: &StorageUsageTotals,
+    quota: Option<&StorageQuota>,
+) -> StorageQuotaLevel {
+    let status = StorageQuotaStatus {
+        scope,
+        scope_id,
+        bytes_used: totals.bytes_used,
+        soft_limit_bytes: quota.and_then(|quota| quota.soft_limit_bytes),
+        hard_limit_bytes: quota.and_then(|quota| quota.hard_limit_bytes),
+    };
+    status.level(Some(0))
+}
@@ -0,0 +1,368 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use crate::application::dto::storage_reconciliation_dto::{
+    StartStorageReconciliationRequest, StorageReconciliationReportResponse, StorageReconciliationRunListResponse,
+    StorageReconciliationRunResponse,
+};
+use crate::application::services::{ObjectStorageError, ObjectStorageService};
+use crate::domain::entities::{
+    checksums_differ, parse_last_modified, quarantine_key, NewStorageReconciliationFinding, StorageOrphanAction,
+    StorageReconciliationCounts, StorageReconciliationFindingKind, StorageReconciliationRun,
+    DEFAULT_RECONCILIATION_GRACE_HOURS, RECONCILED_PREFIXES,
+};
+use crate::domain::repositories::StorageReconciliationRepository;
+use crate::domain::ServiceError;
+
+/// 객체 목록을 한 번에 나열하는 수
+const OBJECT_PAGE_SIZE: i32 = 1000;
+
+/// 마스크 행을 한 번에 읽는 수
+const MASK_PAGE_SIZE: i64 = 500;
+
+/// 실행 하나에 기록하는 최대 문제 수 (집계는 모두 셈)
+const MAX_RECORDED_FINDINGS: usize = 10_000;
+
+/// 이 시간이 지나도 끝나지 않은 실행은 중단된 것으로 보고 새 실행을 허용 (초)
+const RECONCILIATION_STALE_SECONDS: i64 = 6 * 3600;
+
+/// 실행 목록에서 반환하는 최대 실행 수
+const RUN_LIST_LIMIT: i64 = 50;
+
+/// 보고서에서 반환하는 문제 수 (기본값 / 최대)
+const DEFAULT_FINDINGS_LIMIT: i64 = 1000;
+const MAX_FINDINGS_LIMIT: i64 = MAX_RECORDED_FINDINGS as i64;
+
+/// 고아 객체를 격리/삭제할 때의 최소 유예 기간 (업로드 중인 객체 보호)
+const MIN_ACTION_GRACE_HOURS: i32 = 1;
+
+/// 실행 중 모은 집계와 아직 저장하지 않은 문제
+#[derive(Default)]
+struct ReconciliationScan {
+    counts: StorageReconciliationCounts,
+    pending_findings: Vec<NewStorageReconciliationFinding>,
+    recorded_findings: usize,
+}
+
+impl ReconciliationScan {
+    fn record(&mut self, finding: NewStorageReconciliationFinding) {
+        if self.recorded_findings < MAX_RECORDED_FINDINGS {
+            self.recorded_findings += 1;
+            self.pending_findings.push(finding);
+        }
+    }
+}
+
+/// Object Storage 정합성 점검 유스케이스
+///
+/// `masks/`, `annotations/` 아래의 객체를 마스크 행과 비교해 고아 객체, 객체가 없는 행,
+/// 크기/체크섬 불일치를 보고합니다. 유예 기간보다 최근의 객체/행은 업로드 중일 수 있어 제외하고,
+/// 설정에 따라 고아 객체를 `quarantine/` 아래로 옮기거나 삭제 대기열에 예약합니다.
+pub struct StorageReconciliationUseCase<R>
+where
+    R: StorageReconciliationRepository + Send + Sync,
+{
+    storage_reconciliation_repository: Arc<R>,
+    object_storage: Arc<dyn ObjectStorageService>,
+}
+
+impl<R> StorageReconciliationUseCase<R>
+where
+    R: StorageReconciliationRepository + Send + Sync,
+{
+    pub fn new(storage_reconciliation_repository: Arc<R>, object_storage: Arc<dyn ObjectStorageService>) -> Self {
+        Self {
+            storage_reconciliation_repository,
+            object_storage,
+        }
+    }
+
+    /// 관리자 요청으로 점검 실행 (끝날 때까지 기다려 결과를 반환)
+    pub async fn start(
+        &self,
+        request: StartStorageReconciliationRequest,
+        user_id: i32,
+    ) -> Result<StorageReconciliationRunResponse, ServiceError> {
+        let orphan_action = request.orphan_action.unwrap_or_default();
+        let grace_period_hours = request.grace_period_hours.unwrap_or(DEFAULT_RECONCILIATION_GRACE_HOURS);
+        if grace_period_hours < 0 {
+            return Err(ServiceError::ValidationError("grace_period_hours must not be negative".to_string()));
+        }
+        if orphan_action != StorageOrphanAction::Report && grace_period_hours < MIN_ACTION_GRACE_HOURS {
+            return Err(ServiceError::ValidationError(format!(
+                "grace_period_hours must be at least {} to quarantine or delete orphans",
+                MIN_ACTION_GRACE_HOURS
+            )));
+        }
+
+        self.reconcile(Some(user_id), orphan_action, grace_period_hours)
+            .await?
+            .map(StorageReconciliationRunResponse::from)
+            .ok_or_else(|| ServiceError::AlreadyExists("A storage reconciliation is already running".to_string()))
+    }
+
+    /// 최근 점검 실행 목록
+    pub async fn list_runs(&self) -> Result<StorageReconciliationRunListResponse, ServiceError> {
+        let runs = self.storage_reconciliation_repository.list_runs(RUN_LIST_LIMIT).await?;
+        Ok(StorageReconciliationRunListResponse {
+            runs: runs.into_iter().map(StorageReconciliationRunResponse::from).collect(),
+        })
+    }
+
+    /// 점검 보고서 (실행 집계와 기록된 문제)
+    pub async fn get_report(&self, run_id: i32, limit: Option<i64>) -> Result<StorageReconciliationReportResponse, ServiceError> {
+        let run = self.storage_reconciliation_repository
+            .find_run(run_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Reconciliation run with ID {} not found", run_id)))?;
+        let limit = limit.unwrap_or(DEFAULT_FINDINGS_LIMIT).clamp(1, MAX_FINDINGS_LIMIT);
+        let findings = self.storage_reconciliation_repository.list_findings(run_id, limit).await?;
+
+        Ok(StorageReconciliationReportResponse {
+            run: run.into(),
+            findings: findings.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// 점검 실행 (다른 점검이 진행 중이면 None)
+    ///
+    /// 스토리지 오류로 중단되면 실행은 실패로 기록되고, 그때까지의 집계와 문제는 남습니다.
+    pub async fn reconcile(
+        &self,
+        triggered_by: Option<i32>,
+        orphan_action: StorageOrphanAction,
+        grace_period_hours: i32,
+    ) -> Result<Option<StorageReconciliationRun>, ServiceError> {
+        let Some(run) = self.storage_reconciliation_repository
+            .start_run(triggered_by, orphan_action, grace_period_hours, RECONCILIATION_STALE_SECONDS)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let cutoff = Utc::now() - chrono::Duration::hours(grace_period_hours as i64);
+        let mut scan = ReconciliationScan::default();
+        let mut outcome = self.scan_objects(run.id, orphan_action, cutoff, &mut scan).await;
+        if outcome.is_ok() {
+            outcome = self.scan_rows(run.id, cutoff, &mut scan).await;
+        }
+        if let Err(e) = self.flush_findings(run.id, &mut scan).await {
+            outcome = outcome.and(Err(e));
+        }
+
+        let error = outcome.err().map(|e| e.to_string());
+        let run = self.storage_reconciliation_repository
+            .finish_run(run.id, &scan.counts, error.as_deref())
+            .await?;
+        Ok(Some(run))
+    }
+
+    async fn flush_findings(&self, run_id: i32, scan: &mut ReconciliationScan) -> Result<(), ServiceError> {
+        let findings = std::mem::take(&mut scan.pending_findings);
+        self.storage_reconciliation_repository.insert_findings(run_id, &findings).await
+    }
+
+    /// 점검 대상 prefix의 객체를 페이지 단위로 나열해 행이 없는 객체를 찾음
+    async fn scan_objects(
+        &self,
+        run_id: i32,
+        orphan_action: StorageOrphanAction,
+        cutoff: DateTime<Utc>,
+        scan: &mut ReconciliationScan,
+    ) -> Result<(), ServiceError> {
+        for prefix in RECONCILED_PREFIXES {
+            let mut start_after: Option<String> = None;
+            loop {
+                let object_keys = self.object_storage
+                    .list_files_after(prefix, start_after.as_deref(), Some(OBJECT_PAGE_SIZE))
+                    .await
+                    .map_err(|e| storage_error("list", prefix, e))?;
+                scan.counts.objects_scanned += object_keys.len() as i64;
+
+                let unreferenced = self.storage_reconciliation_repository
+                    .find_unreferenced_objects(&object_keys)
+                    .await?;
+                let mut orphans = Vec::new();
+                for object_key in unreferenced {
+                    if let Some(finding) = self.inspect_orphan(object_key, cutoff, scan).await? {
+                        orphans.push(finding);
+                    }
+                }
+                self.apply_orphan_action(run_id, orphan_action, &mut orphans, scan).await?;
+                for finding in orphans {
+                    scan.record(finding);
+                }
+                self.flush_findings(run_id, scan).await?;
+
+                if object_keys.len() < OBJECT_PAGE_SIZE as usize {
+                    break;
+                }
+                start_after = object_keys.last().cloned();
+            }
+        }
+        Ok(())
+    }
+
+    /// 행이 없는 객체가 유예 기간을 지났으면 고아 객체로 반환
+    async fn inspect_orphan(
+        &self,
+        object_key: String,
+        cutoff: DateTime<Utc>,
+        scan: &mut ReconciliationScan,
+    ) -> Result<Option<NewStorageReconciliationFinding>, ServiceError> {
+        let metadata = match self.object_storage.get_file_metadata(&object_key).await {
+            Ok(metadata) => metadata,
+            // 나열한 뒤 삭제된 객체
+            Err(ObjectStorageError::FileNotFound(_)) => return Ok(None),
+            Err(e) => return Err(storage_error("inspect", &object_key, e)),
+        };
+
+        // 수정 시각을 알 수 없으면 오래된 객체로 봄
+        let last_modified = metadata.last_modified.as_deref().and_then(parse_last_modified);
+        if last_modified.is_some_and(|modified| modified > cutoff) {
+            scan.counts.recent_skipped += 1;
+            return Ok(None);
+        }
+
+        scan.counts.orphan_objects += 1;
+        let mut finding = NewStorageReconciliationFinding::new(StorageReconciliationFindingKind::OrphanObject, object_key);
+        finding.actual = Some(metadata.file_size.to_string());
+        Ok(Some(finding))
+    }
+
+    /// 고아 객체를 격리하거나 삭제 대기열에 예약하고 결과를 문제에 기록
+    async fn apply_orphan_action(
+        &self,
+        run_id: i32,
+        orphan_action: StorageOrphanAction,
+        orphans: &mut [NewStorageReconciliationFinding],
+        scan: &mut ReconciliationScan,
+    ) -> Result<(), ServiceError> {
+        match orphan_action {
+            StorageOrphanAction::Report => {}
+            StorageOrphanAction::Quarantine => {
+                for finding in orphans.iter_mut() {
+                    let destination = quarantine_key(run_id, &finding.object_key);
+                    // 격리에 실패한 객체는 보고만 하고 다음 실행에서 다시 시도
+                    match self.object_storage.move_file(&finding.object_key, &destination).await {
+                        Ok(()) => {
+                            finding.action = StorageOrphanAction::Quarantine;
+                            finding.quarantine_key = Some(destination);
+                            scan.counts.orphans_quarantined += 1;
+                        }
+                        Err(e) => eprintln!("Failed to quarantine orphan object {}: {}", finding.object_key, e),
+                    }
+                }
+            }
+            StorageOrphanAction::Delete => {
+                let object_keys: Vec<String> = orphans.iter().map(|f| f.object_key.clone()).collect();
+                let queued = self.storage_reconciliation_repository
+                    .enqueue_orphan_deletions(&object_keys)
+                    .await?;
+                for finding in orphans.iter_mut() {
+                    finding.action = StorageOrphanAction::Delete;
+                }
+                scan.counts.orphans_queued_for_deletion += queued as i64;
+            }
+        }
+        Ok(())
+    }
+
+    /// 마스크 행을 ID 순서로 읽어 객체가 없거나 메타데이터가 다른 행을 찾음
+    async fn scan_rows(&self, run_id: i32, cutoff: DateTime<Utc>, scan: &mut ReconciliationScan) -> Result<(), ServiceError> {
+        let mut after_id = 0;
+        loop {
+            let masks = self.storage_reconciliation_repository
+                .list_masks_after(after_id, MASK_PAGE_SIZE)
+                .await?;
+            scan.counts.rows_scanned += masks.len() as i64;
+
+            for mask in &masks {
+                if mask.created_at > cutoff {
+                    scan.counts.recent_skipped += 1;
+                    continue;
+                }
+
+                let metadata = match self.object_storage.get_file_metadata(&mask.file_path).await {
+                    Ok(metadata) => metadata,
+                    // 스토리지에 둘 수 없는 경로도 객체가 없는 행
+                    Err(ObjectStorageError::FileNotFound(_)) | Err(ObjectStorageError::InvalidRequest(_)) => {
+                        scan.counts.dangling_rows += 1;
+                        let mut finding = NewStorageReconciliationFinding::new(
+                            StorageReconciliationFindingKind::DanglingRow,
+                            mask.file_path.clone(),
+                        );
+                        finding.mask_id = Some(mask.id);
+                        scan.record(finding);
+                        continue;
+                    }
+                    Err(e) => return Err(storage_error("inspect", &mask.file_path, e)),
+                };
+
+                if let Some(expected_size) = mask.file_size.filter(|size| *size != metadata.file_size) {
+                    scan.counts.size_mismatches += 1;
+                    let mut finding = NewStorageReconciliationFinding::new(
+                        StorageReconciliationFindingKind::SizeMismatch,
+                        mask.file_path.clone(),
+                    );
+                    finding.mask_id = Some(mask.id);
+                    finding.expected = Some(expected_size.to_string());
+                    finding.actual = Some(metadata.file_size.to_string());
+                    scan.record(finding);
+                }
+
+                if let (Some(expected), Some(actual)) = (&mask.checksum, &metadata.checksum) {
+                    if checksums_differ(expected, actual) {
+                        scan.counts.checksum_mismatches += 1;
+                        let mut finding = NewStorageReconciliationFinding::new(
+                            StorageReconciliationFindingKind::ChecksumMismatch,
+                            mask.file_path.clone(),
+                        );
+                        finding.mask_id = Some(mask.id);
+                        finding.expected = Some(expected.clone());
+                        finding.actual = Some(actual.clone());
+                        scan.record(finding);
+                    }
+                }
+            }
+            self.flush_findings(run_id, scan).await?;
+
+            match masks.last() {
+                Some(last) if masks.len() as i64 == MASK_PAGE_SIZE => after_id = last.id,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// `interval`마다 정합성 점검을 실행합니다. (서버 시작 시 백그라운드 작업으로 실행)
+    pub async fn run_reconciliation_loop(
+        &self,
+        interval: Duration,
+        orphan_action: StorageOrphanAction,
+        grace_period_hours: i32,
+    ) {
+        let mut ticker = tokio::time::interval(interval);
+        // 서버 시작 직후에는 실행하지 않음
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            match self.reconcile(None, orphan_action, grace_period_hours).await {
+                Ok(Some(run)) => println!(
+                    "🔍 Storage reconciliation {}: {} orphan objects, {} dangling rows, {} size / {} checksum mismatches{}",
+                    run.id,
+                    run.orphan_objects,
+                    run.dangling_rows,
+                    run.size_mismatches,
+                    run.checksum_mismatches,
+                    run.error.map(|e| format!(" (failed: {})", e)).unwrap_or_default()
+                ),
+                Ok(None) => {}
+                Err(e) => eprintln!("Failed to run storage reconciliation: {}", e),
+            }
+        }
+    }
+}
+
+fn storage_error(context: &str, object_key: &str, e: ObjectStorageError) -> ServiceError {
+    ServiceError::ExternalServiceError(format!("Failed to {} {}: {}", context, object_key, e))
+}
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use crate::application::dto::trash_dto::{TrashListResponse, TrashedAnnotationResponse, TrashedMaskGroupResponse};
+use crate::domain::entities::{purge_cutoff, TrashPurgeResult};
+use crate::domain::repositories::TrashRepository;
+use crate::domain::ServiceError;
+
+/// 정리 작업 한 번에 영구 삭제하는 항목 수 (종류별)
+const PURGE_BATCH_SIZE: i64 = 100;
+
+/// 어노테이션 / 마스크 그룹 휴지통 유스케이스
+///
+/// 삭제된 항목은 보관 기간 동안 프로젝트 휴지통에서 조회/복원할 수 있고,
+/// 보관 기간이 지나면 정리 작업이 행을 영구 삭제하고 마스크 파일 삭제를 삭제 대기열에 예약합니다.
+pub struct TrashUseCase<TR>
+where
+    TR: TrashRepository + Send + Sync,
+{
+    trash_repository: Arc<TR>,
+    retention_days: i64,
+}
+
+impl<TR> TrashUseCase<TR>
+where
+    TR: TrashRepository + Send + Sync,
+{
+    pub fn new(trash_repository: Arc<TR>, retention_days: i64) -> Self {
+        Self {
+            trash_repository,
+            retention_days,
+        }
+    }
+
+    /// 사용자의 프로젝트 멤버 여부 확인
+    async fn ensure_member(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        if !self.trash_repository.is_project_member(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// 프로젝트 휴지통 목록 조회
+    pub async fn list_trash(&self, project_id: i32, user_id: i32) -> Result<TrashListResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let annotations = self.trash_repository
+            .list_annotations(project_id, user_id)
+            .await?
+            .into_iter()
+            .map(|a| TrashedAnnotationResponse::new(a, self.retention_days))
+            .collect();
+        let mask_groups = self.trash_repository
+            .list_mask_groups(project_id, user_id)
+            .await?
+            .into_iter()
+            .map(|g| TrashedMaskGroupResponse::new(g, self.retention_days))
+            .collect();
+
+        Ok(TrashListResponse {
+            retention_days: self.retention_days,
+            annotations,
+            mask_groups,
+        })
+    }
+
+    /// 휴지통의 어노테이션 복원 (함께 삭제된 마스크 그룹 포함)
+    pub async fn restore_annotation(&self, project_id: i32, annotation_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        self.trash_repository
+            .find_annotation(project_id, annotation_id, user_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Annotation {} not found in trash", annotation_id)))?;
+
+        if !self.trash_repository.restore_annotation(annotation_id, user_id).await? {
+            return Err(ServiceError::NotFound(format!("Annotation {} not found in trash", annotation_id)));
+        }
+        Ok(())
+    }
+
+    /// 휴지통의 마스크 그룹 복원 (어노테이션이 휴지통에 있으면 어노테이션을 먼저 복원해야 함)
+    pub async fn restore_mask_group(&self, project_id: i32, mask_group_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        let mask_group = self.trash_repository
+            .find_mask_group(project_id, mask_group_id, user_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Mask group {} not found in trash", mask_group_id)))?;
+
+        if self.trash_repository
+            .find_annotation(project_id, mask_group.annotation_id, user_id)
+            .await?
+            .is_some()
+        {
+            return Err(ServiceError::ValidationError(format!(
+                "Annotation {} is in trash; restore the annotation first",
+                mask_group.annotation_id
+            )));
+        }
+
+        if !self.trash_repository.restore_mask_group(mask_group_id).await? {
+            return Err(ServiceError::NotFound(format!("Mask group {} not found in trash", mask_group_id)));
+        }
+        Ok(())
+    }
+
+    /// 보관 기간이 지난 휴지통 항목을 영구 삭제합니다.
+    ///
+    /// 행을 잠근 채 삭제하면서 같은 트랜잭션에서 마스크 파일 삭제를 예약하므로,
+    /// 정리 도중 복원된 항목의 파일은 지워지지 않고 삭제된 항목의 파일은 반드시 지워집니다.
+    pub async fn purge_expired(&self) -> Result<TrashPurgeResult, ServiceError> {
+        let cutoff = purge_cutoff(Utc::now(), self.retention_days);
+        let mut result = TrashPurgeResult::default();
+
+        for annotation_id in self.trash_repository.find_expired_annotations(cutoff, PURGE_BATCH_SIZE).await? {
+            if let Some(queued) = self.trash_repository.purge_annotation(annotation_id, cutoff).await? {
+                result.annotations += 1;
+                result.objects_queued += queued;
+            }
+        }
+
+        for mask_group_id in self.trash_repository.find_expired_mask_groups(cutoff, PURGE_BATCH_SIZE).await? {
+            if let Some(queued) = self.trash_repository.purge_mask_group(mask_group_id, cutoff).await? {
+                result.mask_groups += 1;
+                result.objects_queued += queued;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// `interval`마다 휴지통 정리 작업을 실행합니다. (서버 시작 시 백그라운드 작업으로 실행)
+    pub async fn run_purge_loop(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.purge_expired().await {
+                Ok(result) if result != TrashPurgeResult::default() => {
+                    println!(
+                        "🗑️  Trash purge: {} annotations, {} mask groups, {} objects queued for deletion",
+                        result.annotations, result.mask_groups, result.objects_queued
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to purge trash: {}", e),
+            }
+        }
+    }
+}
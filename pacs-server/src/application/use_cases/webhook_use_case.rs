@@ -0,0 +1,352 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use crate::application::dto::webhook_dto::{
+    CreateWebhookRequest, UpdateWebhookRequest, WebhookDeliveryAttemptResponse, WebhookDeliveryListResponse,
+    WebhookDeliveryLogResponse, WebhookDeliveryQuery, WebhookDeliveryResponse, WebhookListResponse, WebhookResponse,
+};
+use crate::domain::entities::webhook::{
+    sign_webhook_payload, ClaimedWebhookDelivery, NewWebhookSubscription, UpdateWebhookSubscription, WebhookAttemptOutcome,
+    WebhookDeliveryStatus, WEBHOOK_DELIVERY_HEADER, WEBHOOK_EVENT_HEADER, WEBHOOK_SIGNATURE_HEADER,
+    WEBHOOK_TIMESTAMP_HEADER,
+};
+use crate::domain::entities::RetryPolicy;
+use crate::domain::repositories::{ProjectRepository, WebhookRepository};
+use crate::domain::services::ensure_project_admin;
+use crate::domain::ServiceError;
+
+/// 전송 요청 시간 제한
+pub const WEBHOOK_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 한 번에 나누는 아웃박스 이벤트 / 전송하는 작업 수
+const DISPATCH_BATCH_SIZE: i64 = 100;
+
+/// 가져간 전송 작업을 다른 디스패처가 가져가지 않는 시간 (초, 요청 시간 제한보다 길어야 함)
+const DELIVERY_LEASE_SECONDS: i64 = 60;
+
+const DEFAULT_DELIVERY_LIST_LIMIT: i64 = 50;
+const MAX_DELIVERY_LIST_LIMIT: i64 = 500;
+
+/// 오류 메시지에 남기는 응답 본문 길이
+const MAX_ERROR_BODY_CHARS: usize = 500;
+
+/// 디스패처 한 번 실행 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WebhookDispatchResult {
+    /// 구독별 전송 작업으로 나눈 이벤트 수
+    pub events: u64,
+    pub succeeded: usize,
+    /// 실패 후 재시도 대기
+    pub retrying: usize,
+    /// 재시도 횟수를 모두 소진해 dead-letter가 된 전송
+    pub dead: usize,
+}
+
+/// Webhook 유스케이스
+///
+/// 프로젝트 관리자는 Webhook 구독을 관리하고 전송 로그와 dead-letter를 조회하거나 다시 전송할 수 있습니다.
+/// 디스패처는 아웃박스 이벤트를 구독별 전송 작업으로 나누고, 전송할 차례가 된 작업을 서명해서 전송합니다.
+pub struct WebhookUseCase<R>
+where
+    R: WebhookRepository + Send + Sync,
+{
+    webhook_repository: Arc<R>,
+    project_repository: Arc<dyn ProjectRepository>,
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+}
+
+impl<R> WebhookUseCase<R>
+where
+    R: WebhookRepository + Send + Sync,
+{
+    pub fn new(
+        webhook_repository: Arc<R>,
+        project_repository: Arc<dyn ProjectRepository>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(WEBHOOK_REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self { webhook_repository, project_repository, http_client, retry_policy }
+    }
+
+    /// 사용자가 프로젝트 관리자인지 확인
+    async fn ensure_admin(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        ensure_project_admin(self.project_repository.as_ref(), project_id, user_id, "manage webhooks").await
+    }
+
+    pub async fn list_webhooks(&self, project_id: i32, user_id: i32) -> Result<WebhookListResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        let subscriptions = self.webhook_repository.list_subscriptions(project_id).await?;
+        Ok(WebhookListResponse {
+            webhooks: subscriptions.into_iter().map(WebhookResponse::from).collect(),
+        })
+    }
+
+    /// Webhook 생성 (서명 키는 생성 응답에서만 확인할 수 있음)
+    pub async fn create_webhook(&self, project_id: i32, request: CreateWebhookRequest, user_id: i32) -> Result<WebhookResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        let new_subscription = NewWebhookSubscription {
+            project_id,
+            name: validate_name(&request.name)?,
+            url: validate_url(&request.url)?,
+            secret: generate_secret(),
+            event_types: request.event_types.unwrap_or_default(),
+            created_by: user_id,
+        };
+        let subscription = self.webhook_repository.create_subscription(&new_subscription).await?;
+        let secret = subscription.secret.clone();
+        Ok(WebhookResponse {
+            secret: Some(secret),
+            ..subscription.into()
+        })
+    }
+
+    pub async fn get_webhook(&self, project_id: i32, webhook_id: i32, user_id: i32) -> Result<WebhookResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        self.webhook_repository
+            .find_subscription(project_id, webhook_id)
+            .await?
+            .map(WebhookResponse::from)
+            .ok_or_else(|| webhook_not_found(webhook_id))
+    }
+
+    pub async fn update_webhook(
+        &self,
+        project_id: i32,
+        webhook_id: i32,
+        request: UpdateWebhookRequest,
+        user_id: i32,
+    ) -> Result<WebhookResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        let update = UpdateWebhookSubscription {
+            name: request.name.as_deref().map(validate_name).transpose()?,
+            url: request.url.as_deref().map(validate_url).transpose()?,
+            event_types: request.event_types,
+            is_active: request.is_active,
+        };
+        self.webhook_repository
+            .update_subscription(project_id, webhook_id, &update)
+            .await?
+            .map(WebhookResponse::from)
+            .ok_or_else(|| webhook_not_found(webhook_id))
+    }
+
+    pub async fn delete_webhook(&self, project_id: i32, webhook_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        if !self.webhook_repository.delete_subscription(project_id, webhook_id).await? {
+            return Err(webhook_not_found(webhook_id));
+        }
+        Ok(())
+    }
+
+    /// 프로젝트의 전송 목록 (최신순)
+    pub async fn list_deliveries(
+        &self,
+        project_id: i32,
+        query: WebhookDeliveryQuery,
+        user_id: i32,
+    ) -> Result<WebhookDeliveryListResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        let limit = query.limit.unwrap_or(DEFAULT_DELIVERY_LIST_LIMIT).clamp(1, MAX_DELIVERY_LIST_LIMIT);
+        let deliveries = self.webhook_repository
+            .list_deliveries(project_id, query.webhook_id, query.status, limit)
+            .await?;
+        Ok(WebhookDeliveryListResponse {
+            deliveries: deliveries.into_iter().map(WebhookDeliveryResponse::from).collect(),
+        })
+    }
+
+    /// 재시도 횟수를 모두 소진한 전송 목록
+    pub async fn list_dead_letters(
+        &self,
+        project_id: i32,
+        query: WebhookDeliveryQuery,
+        user_id: i32,
+    ) -> Result<WebhookDeliveryListResponse, ServiceError> {
+        let query = WebhookDeliveryQuery {
+            status: Some(WebhookDeliveryStatus::Dead),
+            ..query
+        };
+        self.list_deliveries(project_id, query, user_id).await
+    }
+
+    /// 전송과 시도 로그
+    pub async fn get_delivery_log(&self, project_id: i32, delivery_id: i64, user_id: i32) -> Result<WebhookDeliveryLogResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        let delivery = self.webhook_repository
+            .find_delivery(project_id, delivery_id)
+            .await?
+            .ok_or_else(|| delivery_not_found(delivery_id))?;
+        let attempts = self.webhook_repository.list_attempts(delivery.id).await?;
+        Ok(WebhookDeliveryLogResponse {
+            delivery: delivery.into(),
+            attempts: attempts.into_iter().map(WebhookDeliveryAttemptResponse::from).collect(),
+        })
+    }
+
+    /// dead-letter 전송을 다시 전송 대기로 되돌림
+    pub async fn retry_delivery(&self, project_id: i32, delivery_id: i64, user_id: i32) -> Result<WebhookDeliveryResponse, ServiceError> {
+        self.ensure_admin(project_id, user_id).await?;
+
+        if let Some(delivery) = self.webhook_repository.retry_dead_delivery(project_id, delivery_id).await? {
+            return Ok(delivery.into());
+        }
+        match self.webhook_repository.find_delivery(project_id, delivery_id).await? {
+            Some(delivery) => Err(ServiceError::ValidationError(format!(
+                "Webhook delivery {} is {:?}; only dead deliveries can be retried",
+                delivery.id, delivery.status
+            ))),
+            None => Err(delivery_not_found(delivery_id)),
+        }
+    }
+
+    /// 아웃박스 이벤트를 나누고 전송할 차례가 된 작업을 전송합니다.
+    pub async fn dispatch_once(&self) -> Result<WebhookDispatchResult, ServiceError> {
+        let mut result = WebhookDispatchResult {
+            events: self.webhook_repository.fan_out_events(DISPATCH_BATCH_SIZE).await?,
+            ..Default::default()
+        };
+
+        let claimed = self.webhook_repository
+            .claim_due_deliveries(DISPATCH_BATCH_SIZE, DELIVERY_LEASE_SECONDS)
+            .await?;
+        let outcomes = futures::future::join_all(claimed.iter().map(|delivery| self.deliver(delivery))).await;
+
+        for (delivery, outcome) in claimed.iter().zip(outcomes) {
+            // 기록에 실패한 전송은 임대가 끝난 뒤 다시 전송됨
+            if let Err(e) = self.webhook_repository.record_attempt(delivery.id, &outcome).await {
+                eprintln!("Failed to record webhook delivery {}: {}", delivery.id, e);
+                continue;
+            }
+            match (outcome.succeeded(), outcome.retry_in_seconds) {
+                (true, _) => result.succeeded += 1,
+                (false, Some(_)) => result.retrying += 1,
+                (false, None) => result.dead += 1,
+            }
+        }
+        Ok(result)
+    }
+
+    /// 서명한 페이로드 전송 (2xx 응답이면 성공)
+    async fn deliver(&self, delivery: &ClaimedWebhookDelivery) -> WebhookAttemptOutcome {
+        let body = delivery.body();
+        let timestamp = chrono::Utc::now().timestamp();
+        let started = Instant::now();
+
+        let response = self.http_client
+            .post(&delivery.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_EVENT_HEADER, &delivery.event_type)
+            .header(WEBHOOK_DELIVERY_HEADER, delivery.id.to_string())
+            .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+            .header(WEBHOOK_SIGNATURE_HEADER, sign_webhook_payload(&delivery.secret, timestamp, &body))
+            .body(body)
+            .send()
+            .await;
+
+        let (status_code, error) = match response {
+            Ok(response) if response.status().is_success() => (Some(response.status().as_u16() as i32), None),
+            Ok(response) => {
+                let status = response.status();
+                let text = response.text().await.unwrap_or_default();
+                let message = match text.trim() {
+                    "" => format!("HTTP {}", status),
+                    text => format!("HTTP {}: {}", status, text.chars().take(MAX_ERROR_BODY_CHARS).collect::<String>()),
+                };
+                (Some(status.as_u16() as i32), Some(message))
+            }
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        let attempt_number = delivery.attempt_count + 1;
+        WebhookAttemptOutcome {
+            status_code,
+            retry_in_seconds: error.as_ref().and_then(|_| self.retry_policy.retry_delay(attempt_number)),
+            error,
+            duration_ms: started.elapsed().as_millis().min(i32::MAX as u128) as i32,
+        }
+    }
+
+    /// `interval`마다 디스패처를 실행합니다. (서버 시작 시 백그라운드 작업으로 실행)
+    pub async fn run_dispatch_loop(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.dispatch_once().await {
+                Ok(result) if result.dead > 0 => {
+                    eprintln!(
+                        "Webhook dispatch: {} deliveries moved to dead letters ({} succeeded, {} retrying)",
+                        result.dead, result.succeeded, result.retrying
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to dispatch webhooks: {}", e),
+            }
+        }
+    }
+}
+
+fn webhook_not_found(webhook_id: i32) -> ServiceError {
+    ServiceError::NotFound(format!("Webhook with ID {} not found", webhook_id))
+}
+
+fn delivery_not_found(delivery_id: i64) -> ServiceError {
+    ServiceError::NotFound(format!("Webhook delivery with ID {} not found", delivery_id))
+}
+
+fn validate_name(name: &str) -> Result<String, ServiceError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ServiceError::ValidationError("Webhook name must not be empty".to_string()));
+    }
+    Ok(name.to_string())
+}
+
+/// 이벤트를 받을 URL 확인 (http/https)
+fn validate_url(url: &str) -> Result<String, ServiceError> {
+    let url = url.trim();
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|e| ServiceError::ValidationError(format!("Invalid webhook URL '{}': {}", url, e)))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(ServiceError::ValidationError(format!(
+            "Webhook URL must be an http or https URL: {}",
+            url
+        )));
+    }
+    Ok(url.to_string())
+}
+
+/// 서명 키 생성 (256비트 난수)
+fn generate_secret() -> String {
+    format!("whsec_{}{}", uuid::Uuid::new_v4().simple(), uuid::Uuid::new_v4().simple())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_url() {
+        assert_eq!(validate_url(" https://example.com/hook ").unwrap(), "https://example.com/hook");
+        assert!(validate_url("http://10.0.0.1:8080/hook").is_ok());
+        assert!(matches!(validate_url("ftp://example.com/hook"), Err(ServiceError::ValidationError(_))));
+        assert!(matches!(validate_url("not a url"), Err(ServiceError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_generated_secrets_are_unique() {
+        let secret = generate_secret();
+        assert!(secret.starts_with("whsec_"));
+        assert_eq!(secret.len(), "whsec_".len() + 64);
+        assert_ne!(secret, generate_secret());
+    }
+}
@@ -0,0 +1,309 @@
+use std::sync::Arc;
+use crate::application::dto::worklist_dto::{
+    AssignWorklistTaskRequest, CompleteWorklistTaskRequest, CreateWorklistTasksRequest, CreateWorklistTasksResponse,
+    SkipWorklistTaskRequest, WorklistProgressResponse, WorklistProjectProgressResponse, WorklistTaskListQuery,
+    WorklistTaskListResponse, WorklistTaskResponse,
+};
+use crate::domain::entities::worklist::{
+    distribute_round_robin, WorklistProgress, WorklistStudyFilter, WorklistTask, WorklistTaskQuery,
+    WorklistTaskResults, WorklistTaskStatus,
+};
+use crate::domain::repositories::{ProjectRepository, WorklistRepository};
+use crate::domain::services::ensure_project_admin;
+use crate::domain::ServiceError;
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const MAX_PAGE_SIZE: i64 = 500;
+
+/// 어노테이션 작업 목록 유스케이스
+///
+/// 프로젝트 관리자는 Study 단위 작업을 만들고 판독자에게 배정하거나 종료된 작업을 다시 열 수 있고,
+/// 프로젝트 멤버는 작업을 가져가 진행한 뒤 결과물과 함께 완료하거나 건너뛸 수 있습니다.
+pub struct WorklistUseCase<WR>
+where
+    WR: WorklistRepository + Send + Sync,
+{
+    worklist_repository: Arc<WR>,
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl<WR> WorklistUseCase<WR>
+where
+    WR: WorklistRepository + Send + Sync,
+{
+    pub fn new(worklist_repository: Arc<WR>, project_repository: Arc<dyn ProjectRepository>) -> Self {
+        Self { worklist_repository, project_repository }
+    }
+
+    /// 사용자의 프로젝트 멤버 여부 확인
+    async fn ensure_member(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        if !self.worklist_repository.is_project_member(project_id, user_id).await? {
+            return Err(ServiceError::Unauthorized(format!(
+                "User {} is not a member of project {}",
+                user_id, project_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// 사용자가 프로젝트 관리자인지 확인
+    async fn ensure_manager(&self, project_id: i32, user_id: i32) -> Result<(), ServiceError> {
+        ensure_project_admin(self.project_repository.as_ref(), project_id, user_id, "manage the worklist").await
+    }
+
+    /// 담당자로 지정할 사용자가 프로젝트 멤버인지 확인
+    async fn ensure_assignable(&self, project_id: i32, assignee_id: i32) -> Result<(), ServiceError> {
+        if !self.worklist_repository.is_project_member(project_id, assignee_id).await? {
+            return Err(ServiceError::ValidationError(format!(
+                "User {} is not a member of project {} and cannot be assigned",
+                assignee_id, project_id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn find_task(&self, project_id: i32, task_id: i32) -> Result<WorklistTask, ServiceError> {
+        self.worklist_repository
+            .find_task(project_id, task_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Worklist task with ID {} not found", task_id)))
+    }
+
+    /// 상태 전환이 적용되지 않았을 때 이유를 설명하는 오류
+    async fn transition_error(&self, project_id: i32, task_id: i32, action: &str) -> ServiceError {
+        match self.find_task(project_id, task_id).await {
+            Ok(task) => ServiceError::ValidationError(format!(
+                "Cannot {} worklist task {} in {} state{}",
+                action,
+                task_id,
+                task.status,
+                task.assignee_id.map(|id| format!(" assigned to user {}", id)).unwrap_or_default()
+            )),
+            Err(e) => e,
+        }
+    }
+
+    async fn task_response(&self, task: WorklistTask) -> Result<WorklistTaskResponse, ServiceError> {
+        let results = self.worklist_repository.find_results(task.id).await?;
+        Ok(WorklistTaskResponse::new(task, results))
+    }
+
+    /// Study에서 작업 생성 (직접 지정 또는 필터)
+    pub async fn create_tasks(
+        &self,
+        project_id: i32,
+        request: CreateWorklistTasksRequest,
+        user_id: i32,
+    ) -> Result<CreateWorklistTasksResponse, ServiceError> {
+        self.ensure_manager(project_id, user_id).await?;
+        if let (Some(from), Some(to)) = (request.study_date_from, request.study_date_to) {
+            if from > to {
+                return Err(ServiceError::ValidationError("study_date_from must not be after study_date_to".to_string()));
+            }
+        }
+
+        let mut assignee_ids = request.assignee_ids;
+        assignee_ids.dedup();
+        for &assignee_id in &assignee_ids {
+            self.ensure_assignable(project_id, assignee_id).await?;
+        }
+
+        let requested_ids = request.study_ids.clone();
+        let filter = WorklistStudyFilter {
+            study_ids: request.study_ids,
+            study_date_from: request.study_date_from,
+            study_date_to: request.study_date_to,
+            modality: request.modality.filter(|s| !s.trim().is_empty()),
+            study_description: request.study_description.filter(|s| !s.trim().is_empty()),
+            patient_id: request.patient_id.filter(|s| !s.trim().is_empty()),
+        };
+        let study_ids = self.worklist_repository.find_untasked_studies(project_id, &filter).await?;
+
+        let new_tasks = distribute_round_robin(&study_ids, &assignee_ids);
+        let tasks = if new_tasks.is_empty() {
+            Vec::new()
+        } else {
+            self.worklist_repository
+                .create_tasks(project_id, &new_tasks, request.priority, user_id)
+                .await?
+        };
+
+        let mut skipped_study_ids: Vec<i32> = requested_ids
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|id| !tasks.iter().any(|task| task.study_id == *id))
+            .collect();
+        skipped_study_ids.sort_unstable();
+        skipped_study_ids.dedup();
+
+        Ok(CreateWorklistTasksResponse {
+            tasks: tasks.into_iter().map(WorklistTaskResponse::from).collect(),
+            skipped_study_ids,
+        })
+    }
+
+    /// 작업 목록 조회
+    pub async fn list_tasks(
+        &self,
+        project_id: i32,
+        query: WorklistTaskListQuery,
+        user_id: i32,
+    ) -> Result<WorklistTaskListResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        let status = query
+            .status
+            .as_deref()
+            .map(str::parse::<WorklistTaskStatus>)
+            .transpose()
+            .map_err(ServiceError::ValidationError)?;
+        let page = query.page.unwrap_or(1).max(1);
+        let page_size = query.page_size.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+
+        let tasks = self.worklist_repository
+            .list_tasks(project_id, &WorklistTaskQuery {
+                status,
+                assignee_id: query.assignee_id,
+                unassigned: query.unassigned.unwrap_or(false),
+                limit: page_size,
+                offset: (page - 1) * page_size,
+            })
+            .await?;
+
+        Ok(WorklistTaskListResponse {
+            tasks: tasks.into_iter().map(WorklistTaskResponse::from).collect(),
+            page,
+            page_size,
+        })
+    }
+
+    /// 작업 조회 (결과물 포함)
+    pub async fn get_task(&self, project_id: i32, task_id: i32, user_id: i32) -> Result<WorklistTaskResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        let task = self.find_task(project_id, task_id).await?;
+        self.task_response(task).await
+    }
+
+    /// 대기 중인 작업의 담당자 변경 (프로젝트 관리자)
+    pub async fn assign_task(
+        &self,
+        project_id: i32,
+        task_id: i32,
+        request: AssignWorklistTaskRequest,
+        user_id: i32,
+    ) -> Result<WorklistTaskResponse, ServiceError> {
+        self.ensure_manager(project_id, user_id).await?;
+        if let Some(assignee_id) = request.assignee_id {
+            self.ensure_assignable(project_id, assignee_id).await?;
+        }
+
+        match self.worklist_repository.assign(project_id, task_id, request.assignee_id).await? {
+            Some(task) => self.task_response(task).await,
+            None => Err(self.transition_error(project_id, task_id, "assign").await),
+        }
+    }
+
+    /// 작업 가져가기 (배정되지 않았거나 본인에게 배정된 대기 작업)
+    pub async fn claim_task(&self, project_id: i32, task_id: i32, user_id: i32) -> Result<WorklistTaskResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        match self.worklist_repository.claim(project_id, task_id, user_id).await? {
+            Some(task) => self.task_response(task).await,
+            None => Err(self.transition_error(project_id, task_id, "claim").await),
+        }
+    }
+
+    /// 진행 중인 본인 작업 내려놓기
+    pub async fn release_task(&self, project_id: i32, task_id: i32, user_id: i32) -> Result<WorklistTaskResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        match self.worklist_repository.release(project_id, task_id, user_id).await? {
+            Some(task) => self.task_response(task).await,
+            None => Err(self.transition_error(project_id, task_id, "release").await),
+        }
+    }
+
+    /// 진행 중인 본인 작업 완료 (작업 Study의 어노테이션/마스크 그룹 연결)
+    pub async fn complete_task(
+        &self,
+        project_id: i32,
+        task_id: i32,
+        request: CompleteWorklistTaskRequest,
+        user_id: i32,
+    ) -> Result<WorklistTaskResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        let task = self.find_task(project_id, task_id).await?;
+
+        let results = WorklistTaskResults {
+            annotation_ids: request.annotation_ids,
+            mask_group_ids: request.mask_group_ids,
+        }
+        .normalized();
+        let valid = self.worklist_repository
+            .filter_study_results(project_id, &task.study_uid, &results)
+            .await?;
+        if valid != results {
+            let invalid: Vec<String> = results.annotation_ids.iter()
+                .filter(|id| !valid.annotation_ids.contains(id))
+                .map(|id| format!("annotation {}", id))
+                .chain(results.mask_group_ids.iter()
+                    .filter(|id| !valid.mask_group_ids.contains(id))
+                    .map(|id| format!("mask group {}", id)))
+                .collect();
+            return Err(ServiceError::ValidationError(format!(
+                "Results do not belong to study {} of this task: {}",
+                task.study_uid,
+                invalid.join(", ")
+            )));
+        }
+
+        match self.worklist_repository
+            .close(project_id, task_id, user_id, WorklistTaskStatus::Done, request.note.as_deref(), &results)
+            .await?
+        {
+            Some(task) => self.task_response(task).await,
+            None => Err(self.transition_error(project_id, task_id, "complete").await),
+        }
+    }
+
+    /// 본인 작업 또는 배정되지 않은 작업 건너뛰기
+    pub async fn skip_task(
+        &self,
+        project_id: i32,
+        task_id: i32,
+        request: SkipWorklistTaskRequest,
+        user_id: i32,
+    ) -> Result<WorklistTaskResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+
+        match self.worklist_repository
+            .close(project_id, task_id, user_id, WorklistTaskStatus::Skipped, request.note.as_deref(), &WorklistTaskResults::default())
+            .await?
+        {
+            Some(task) => self.task_response(task).await,
+            None => Err(self.transition_error(project_id, task_id, "skip").await),
+        }
+    }
+
+    /// 완료되었거나 건너뛴 작업을 다시 대기 상태로 (프로젝트 관리자)
+    pub async fn reopen_task(&self, project_id: i32, task_id: i32, user_id: i32) -> Result<WorklistTaskResponse, ServiceError> {
+        self.ensure_manager(project_id, user_id).await?;
+
+        match self.worklist_repository.reopen(project_id, task_id).await? {
+            Some(task) => self.task_response(task).await,
+            None => Err(self.transition_error(project_id, task_id, "reopen").await),
+        }
+    }
+
+    /// 프로젝트 전체 및 담당자별 진행 현황
+    pub async fn get_progress(&self, project_id: i32, user_id: i32) -> Result<WorklistProjectProgressResponse, ServiceError> {
+        self.ensure_member(project_id, user_id).await?;
+        let rows = self.worklist_repository.progress(project_id).await?;
+
+        Ok(WorklistProjectProgressResponse {
+            project_id,
+            project: WorklistProgress::sum(&rows).into(),
+            users: rows.into_iter().map(WorklistProgressResponse::from).collect(),
+        })
+    }
+}
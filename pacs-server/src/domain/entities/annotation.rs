@@ -13,6 +13,78 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 // SQLx를 통한 데이터베이스 행 매핑을 위한 트레이트
 use sqlx::FromRow;
+// OpenAPI 스키마 생성을 위한 utoipa 라이브러리
+use utoipa::ToSchema;
+
+/// 어노테이션 검토 상태
+///
+/// 검토 워크플로우는 다음 순서로 진행됩니다.
+/// `DRAFT → SUBMITTED → APPROVED / REJECTED → LOCKED`
+/// 반려된 어노테이션은 다시 DRAFT로 돌아가 수정하거나 바로 재제출할 수 있으며,
+/// LOCKED 상태의 어노테이션은 더 이상 수정할 수 없습니다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "annotation_review_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AnnotationReviewStatus {
+    /// 작성 중
+    Draft,
+    /// 검토 요청됨
+    Submitted,
+    /// 검토자가 승인함
+    Approved,
+    /// 검토자가 반려함
+    Rejected,
+    /// 승인 후 잠김 (수정 불가)
+    Locked,
+}
+
+impl AnnotationReviewStatus {
+    /// 현재 상태에서 `next` 상태로 전환할 수 있는지 확인
+    pub fn can_transition_to(&self, next: AnnotationReviewStatus) -> bool {
+        use AnnotationReviewStatus::*;
+        matches!(
+            (self, next),
+            (Draft, Submitted)
+                | (Submitted, Approved)
+                | (Submitted, Rejected)
+                | (Rejected, Draft)
+                | (Rejected, Submitted)
+                | (Approved, Locked)
+        )
+    }
+
+    /// 잠겨서 더 이상 수정할 수 없는 상태인지 확인
+    pub fn is_locked(&self) -> bool {
+        matches!(self, AnnotationReviewStatus::Locked)
+    }
+}
+
+impl std::fmt::Display for AnnotationReviewStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnnotationReviewStatus::Draft => write!(f, "DRAFT"),
+            AnnotationReviewStatus::Submitted => write!(f, "SUBMITTED"),
+            AnnotationReviewStatus::Approved => write!(f, "APPROVED"),
+            AnnotationReviewStatus::Rejected => write!(f, "REJECTED"),
+            AnnotationReviewStatus::Locked => write!(f, "LOCKED"),
+        }
+    }
+}
+
+impl std::str::FromStr for AnnotationReviewStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "DRAFT" => Ok(AnnotationReviewStatus::Draft),
+            "SUBMITTED" => Ok(AnnotationReviewStatus::Submitted),
+            "APPROVED" => Ok(AnnotationReviewStatus::Approved),
+            "REJECTED" => Ok(AnnotationReviewStatus::Rejected),
+            "LOCKED" => Ok(AnnotationReviewStatus::Locked),
+            other => Err(format!("Unknown review status: {}", other)),
+        }
+    }
+}
 
 /// 의료 영상 어노테이션을 나타내는 엔티티
 /// 
@@ -34,6 +106,9 @@ use sqlx::FromRow;
 /// - `updated_at`: 어노테이션이 마지막으로 수정된 시각
 /// - `viewer_software`: 어노테이션 생성에 사용된 뷰어 소프트웨어 (선택사항)
 /// - `description`: 어노테이션에 대한 설명 (선택사항)
+/// - `review_status`: 검토 상태
+/// - `reviewer_id`: 배정된 검토자 ID (선택사항)
+/// - `reviewed_at`: 마지막 승인/반려 시각 (선택사항)
 /// 
 /// # 예시
 /// ```rust
@@ -52,6 +127,10 @@ use sqlx::FromRow;
 ///     updated_at: NaiveDateTime::from_timestamp_opt(1640995200, 0).unwrap(),
 ///     viewer_software: Some("DICOM Viewer Pro".to_string()),
 ///     description: Some("폐 결절 크기 측정".to_string()),
+///     measurement_values: None,
+///     review_status: AnnotationReviewStatus::Draft,
+///     reviewer_id: None,
+///     reviewed_at: None,
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -88,6 +167,12 @@ pub struct Annotation {
     pub description: Option<String>,
     /// 측정값 (JSON 배열 형태의 측정 객체들)
     pub measurement_values: Option<serde_json::Value>,
+    /// 검토 상태
+    pub review_status: AnnotationReviewStatus,
+    /// 배정된 검토자 ID (선택사항)
+    pub reviewer_id: Option<i32>,
+    /// 마지막 승인/반려 시각 (선택사항)
+    pub reviewed_at: Option<DateTime<Utc>>,
 }
 
 /// 어노테이션 변경 이력을 나타내는 엔티티
@@ -135,6 +220,26 @@ pub struct AnnotationHistory {
     pub action_at: DateTime<Utc>,
 }
 
+/// 어노테이션 검토 코멘트를 나타내는 엔티티
+/// 
+/// 이 구조체는 데이터베이스의 `annotation_review_comment` 테이블과 매핑되며,
+/// 검토 요청, 승인, 반려 과정에서 남긴 코멘트를 저장합니다.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AnnotationReviewComment {
+    /// 데이터베이스에서 자동 생성되는 고유 식별자
+    pub id: i32,
+    /// 코멘트가 달린 어노테이션의 ID
+    pub annotation_id: i32,
+    /// 코멘트를 작성한 사용자의 ID
+    pub user_id: i32,
+    /// 코멘트 작성 시점의 검토 상태
+    pub review_status: AnnotationReviewStatus,
+    /// 코멘트 내용
+    pub comment: String,
+    /// 코멘트가 작성된 시각
+    pub created_at: DateTime<Utc>,
+}
+
 /// 새로운 어노테이션 생성을 위한 DTO(Data Transfer Object)
 /// 
 /// 이 구조체는 어노테이션 생성 요청 시 전달되는 데이터를 나타냅니다.
@@ -196,3 +301,35 @@ pub struct NewAnnotation {
     /// 다른 사용자와 공유 여부
     pub is_shared: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_review_status_transitions() {
+        use AnnotationReviewStatus::*;
+
+        assert!(Draft.can_transition_to(Submitted));
+        assert!(Submitted.can_transition_to(Approved));
+        assert!(Submitted.can_transition_to(Rejected));
+        assert!(Rejected.can_transition_to(Draft));
+        assert!(Rejected.can_transition_to(Submitted));
+        assert!(Approved.can_transition_to(Locked));
+
+        assert!(!Draft.can_transition_to(Approved));
+        assert!(!Submitted.can_transition_to(Locked));
+        assert!(!Rejected.can_transition_to(Approved));
+        for next in [Draft, Submitted, Approved, Rejected, Locked] {
+            assert!(!Locked.can_transition_to(next));
+        }
+    }
+
+    #[test]
+    fn test_review_status_from_str() {
+        assert_eq!("submitted".parse::<AnnotationReviewStatus>(), Ok(AnnotationReviewStatus::Submitted));
+        assert_eq!("LOCKED".parse::<AnnotationReviewStatus>(), Ok(AnnotationReviewStatus::Locked));
+        assert!("pending".parse::<AnnotationReviewStatus>().is_err());
+        assert_eq!(AnnotationReviewStatus::Approved.to_string(), "APPROVED");
+    }
+}
@@ -0,0 +1,173 @@
+//! # 어노테이션 번들 엔티티 모듈
+//!
+//! 이 모듈은 프로젝트의 어노테이션, 히스토리, 마스크 그룹 메타데이터를 환경 간에 옮기기 위한
+//! NDJSON 번들 레코드를 정의합니다.
+//!
+//! 번들의 각 줄은 `type` 필드로 구분되는 하나의 레코드이며, 사용자와 프로젝트는 내부 ID 대신
+//! 사용자명과 프로젝트 이름으로 기록됩니다. 어노테이션과 마스크 그룹은 `external_id`로 식별되어
+//! 같은 번들을 여러 번 임포트해도 중복 생성되지 않습니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::annotation::AnnotationReviewStatus;
+
+/// 현재 번들 포맷 버전
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// 번들 헤더 레코드 (번들의 첫 줄)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BundleHeader {
+    pub format_version: u32,
+    /// 내보낸 프로젝트 이름
+    pub project_name: String,
+    pub exported_at: DateTime<Utc>,
+}
+
+/// 어노테이션 레코드
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct BundleAnnotation {
+    /// 내보내기 시 페이지네이션에 사용하는 원본 환경의 ID (번들에는 기록되지 않음)
+    #[serde(skip)]
+    pub source_id: i32,
+    pub external_id: Uuid,
+    /// 작성자 사용자명
+    pub username: String,
+    pub study_uid: String,
+    pub series_uid: Option<String>,
+    pub instance_uid: Option<String>,
+    pub tool_name: String,
+    pub tool_version: Option<String>,
+    pub viewer_software: Option<String>,
+    pub description: Option<String>,
+    pub data: serde_json::Value,
+    pub measurement_values: Option<serde_json::Value>,
+    pub is_shared: bool,
+    pub review_status: AnnotationReviewStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 어노테이션 히스토리 레코드
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct BundleHistory {
+    pub annotation_external_id: Uuid,
+    /// 작업을 수행한 사용자명
+    pub username: String,
+    pub action: String,
+    pub data_before: Option<serde_json::Value>,
+    pub data_after: Option<serde_json::Value>,
+    pub action_at: DateTime<Utc>,
+}
+
+/// 마스크 그룹 메타데이터 레코드
+///
+/// 마스크 파일 자체는 오브젝트 스토리지에 있으므로 번들에는 메타데이터만 포함됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct BundleMaskGroup {
+    pub external_id: Uuid,
+    pub annotation_external_id: Uuid,
+    pub group_name: Option<String>,
+    pub model_name: Option<String>,
+    pub version: Option<String>,
+    pub modality: Option<String>,
+    pub slice_count: Option<i32>,
+    pub mask_type: Option<String>,
+    pub description: Option<String>,
+    /// 생성자 사용자명
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 번들의 한 줄에 해당하는 레코드
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BundleRecord {
+    Header(BundleHeader),
+    Annotation(BundleAnnotation),
+    History(BundleHistory),
+    MaskGroup(BundleMaskGroup),
+}
+
+impl BundleRecord {
+    /// 레코드 타입 이름
+    pub fn kind(&self) -> &'static str {
+        match self {
+            BundleRecord::Header(_) => "header",
+            BundleRecord::Annotation(_) => "annotation",
+            BundleRecord::History(_) => "history",
+            BundleRecord::MaskGroup(_) => "mask_group",
+        }
+    }
+}
+
+/// 사용자 ID가 확정된 임포트 대상 어노테이션
+#[derive(Debug, Clone)]
+pub struct ResolvedBundleAnnotation {
+    pub record: BundleAnnotation,
+    pub user_id: i32,
+}
+
+/// 사용자 ID가 확정된 임포트 대상 히스토리
+#[derive(Debug, Clone)]
+pub struct ResolvedBundleHistory {
+    pub record: BundleHistory,
+    pub user_id: i32,
+}
+
+/// 사용자 ID가 확정된 임포트 대상 마스크 그룹
+#[derive(Debug, Clone)]
+pub struct ResolvedBundleMaskGroup {
+    pub record: BundleMaskGroup,
+    pub created_by: Option<i32>,
+}
+
+/// 충돌 검사를 통과하여 실제로 생성할 레코드 목록
+#[derive(Debug, Clone, Default)]
+pub struct BundleImportPlan {
+    pub project_id: i32,
+    pub annotations: Vec<ResolvedBundleAnnotation>,
+    pub history: Vec<ResolvedBundleHistory>,
+    pub mask_groups: Vec<ResolvedBundleMaskGroup>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_record_round_trip_omits_source_id() {
+        let now = Utc::now();
+        let record = BundleRecord::Annotation(BundleAnnotation {
+            source_id: 42,
+            external_id: Uuid::new_v4(),
+            username: "alice".to_string(),
+            study_uid: "1.2.3".to_string(),
+            series_uid: None,
+            instance_uid: None,
+            tool_name: "Circle Tool".to_string(),
+            tool_version: None,
+            viewer_software: None,
+            description: None,
+            data: serde_json::json!({"type": "circle"}),
+            measurement_values: None,
+            is_shared: true,
+            review_status: AnnotationReviewStatus::Approved,
+            created_at: now,
+            updated_at: now,
+        });
+
+        let line = serde_json::to_string(&record).unwrap();
+        assert!(line.starts_with(r#"{"type":"annotation""#));
+        assert!(!line.contains("source_id"));
+
+        let BundleRecord::Annotation(parsed) = serde_json::from_str::<BundleRecord>(&line).unwrap() else {
+            panic!("expected annotation record");
+        };
+        assert_eq!(parsed.source_id, 0);
+        assert_eq!(parsed.review_status, AnnotationReviewStatus::Approved);
+    }
+}
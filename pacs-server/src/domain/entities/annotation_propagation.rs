@@ -0,0 +1,181 @@
+//! 후속 Study로의 어노테이션 전파 엔티티
+//!
+//! 종단 연구에서 기준 시점(baseline)의 병변 어노테이션을 같은 환자의 후속 Study로 복사합니다.
+//! 복사본은 DRAFT 상태로 만들어지고 원본과 최초(root) 어노테이션에 연결되어, root 어노테이션 ID를 기준으로
+//! 시점별 측정값을 추적할 수 있습니다.
+//! Series는 Series Description, 없으면 Modality로 대응시키며, 인스턴스는 대상 Series에 인스턴스 메타데이터가
+//! 하나뿐인 경우(단일 영상)에만 지정합니다. 그 외에는 Series 수준으로 복사되므로 판독자가 위치를 조정해야 합니다.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use crate::domain::entities::annotation::{Annotation, AnnotationReviewStatus};
+use crate::domain::entities::project_data::ProjectDataSeries;
+
+/// 후속 Study의 Series를 찾은 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SeriesMatch {
+    /// Series Description이 같은 Series
+    SeriesDescription,
+    /// Modality가 같은 Series
+    Modality,
+    /// 대응하는 Series 없음 (Study 수준으로 복사)
+    Unmatched,
+    /// 원본이 Study 수준 어노테이션
+    StudyLevel,
+}
+
+impl SeriesMatch {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SeriesMatch::SeriesDescription => "SERIES_DESCRIPTION",
+            SeriesMatch::Modality => "MODALITY",
+            SeriesMatch::Unmatched => "UNMATCHED",
+            SeriesMatch::StudyLevel => "STUDY_LEVEL",
+        }
+    }
+}
+
+/// 비교용 Series Description (대소문자와 공백 차이 무시)
+fn normalize_description(description: Option<&str>) -> Option<String> {
+    let normalized = description?
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    (!normalized.is_empty()).then_some(normalized)
+}
+
+fn normalize_modality(modality: Option<&str>) -> Option<String> {
+    let modality = modality?.trim().to_uppercase();
+    (!modality.is_empty()).then_some(modality)
+}
+
+/// 원본 Series에 대응하는 후속 Study의 Series
+///
+/// Series Description이 같은 Series를 우선하고(여러 개면 Modality가 같은 것), 없으면 Modality가 같은 Series를
+/// 고릅니다. 후보가 여러 개면 Series Number가 작은 것을 고릅니다.
+pub fn match_series<'a>(
+    source: &ProjectDataSeries,
+    candidates: &'a [ProjectDataSeries],
+) -> Option<(&'a ProjectDataSeries, SeriesMatch)> {
+    let description = normalize_description(source.series_description.as_deref());
+    let modality = normalize_modality(source.modality.as_deref());
+    let same_modality = |series: &ProjectDataSeries| {
+        modality.is_some() && normalize_modality(series.modality.as_deref()) == modality
+    };
+    let order = |series: &&ProjectDataSeries| {
+        (series.series_number.is_none(), series.series_number, series.series_uid.clone())
+    };
+
+    if description.is_some() {
+        let by_description = candidates
+            .iter()
+            .filter(|series| normalize_description(series.series_description.as_deref()) == description)
+            .min_by_key(|series| (!same_modality(series), order(series)));
+        if let Some(series) = by_description {
+            return Some((series, SeriesMatch::SeriesDescription));
+        }
+    }
+
+    candidates
+        .iter()
+        .filter(|series| same_modality(series))
+        .min_by_key(order)
+        .map(|series| (series, SeriesMatch::Modality))
+}
+
+/// 전파 계획 (원본 어노테이션 하나를 후속 Study로 복사)
+#[derive(Debug, Clone)]
+pub struct AnnotationPropagationPlan {
+    pub source: Annotation,
+    pub series_uid: Option<String>,
+    pub instance_uid: Option<String>,
+    pub series_match: SeriesMatch,
+}
+
+/// 복사한 어노테이션과 원본의 연결
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AnnotationPropagation {
+    pub annotation_id: i32,
+    pub source_annotation_id: i32,
+    /// 병변의 최초 어노테이션
+    pub root_annotation_id: i32,
+    pub source_study_uid: String,
+    pub target_study_uid: String,
+    pub series_match: String,
+    pub propagated_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 병변 추적 행 (시점별 어노테이션 하나)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LesionTimepoint {
+    /// 병변 ID (최초 어노테이션 ID)
+    pub lesion_id: i32,
+    pub annotation_id: i32,
+    pub study_uid: String,
+    pub study_date: Option<NaiveDate>,
+    pub series_uid: Option<String>,
+    pub instance_uid: Option<String>,
+    pub tool_name: String,
+    pub description: Option<String>,
+    pub review_status: AnnotationReviewStatus,
+    /// 복사한 원본 어노테이션 (기준 시점이면 없음)
+    pub source_annotation_id: Option<i32>,
+    /// 클라이언트 측정값
+    pub measurement_values: Option<Value>,
+    /// 서버에서 계산한 측정값 (`ComputedMeasurement` 배열, 아직 계산하지 않았으면 없음)
+    pub computed_measurements: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(uid: &str, description: Option<&str>, modality: Option<&str>, number: Option<i32>) -> ProjectDataSeries {
+        ProjectDataSeries {
+            id: 0,
+            study_id: 0,
+            series_uid: uid.to_string(),
+            series_description: description.map(str::to_string),
+            modality: modality.map(str::to_string),
+            series_number: number,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn matched(source: &ProjectDataSeries, candidates: &[ProjectDataSeries]) -> Option<(String, SeriesMatch)> {
+        match_series(source, candidates).map(|(series, how)| (series.series_uid.clone(), how))
+    }
+
+    #[test]
+    fn test_match_series_prefers_description() {
+        let source = series("s", Some("AX  T1 Post"), Some("MR"), Some(5));
+        let candidates = vec![
+            series("a", Some("ax t2"), Some("MR"), Some(1)),
+            series("b", Some("ax t1 post"), Some("MR"), Some(7)),
+            series("c", Some("AX T1 POST"), Some("MR"), Some(6)),
+        ];
+        assert_eq!(matched(&source, &candidates), Some(("c".to_string(), SeriesMatch::SeriesDescription)));
+    }
+
+    #[test]
+    fn test_match_series_falls_back_to_modality() {
+        let source = series("s", Some("Chest 5mm"), Some("ct"), None);
+        let candidates = vec![
+            series("a", Some("Scout"), Some("CT"), None),
+            series("b", Some("Chest 1.25mm"), Some("CT"), Some(3)),
+            series("c", Some("Dose report"), Some("SR"), Some(1)),
+        ];
+        assert_eq!(matched(&source, &candidates), Some(("b".to_string(), SeriesMatch::Modality)));
+
+        let unknown = series("s", None, None, None);
+        assert_eq!(matched(&unknown, &candidates), None);
+        assert_eq!(matched(&source, &[]), None);
+    }
+}
@@ -0,0 +1,317 @@
+//! # 어노테이션 검색 조건 모듈
+//!
+//! 이 모듈은 어노테이션 통합 검색에 사용되는 필터, 정렬, 키셋(keyset) 페이지네이션 커서를 정의합니다.
+//! 모든 필터는 AND로 결합되며, `data` / `measurement_values` JSONB 컬럼에 대해서는
+//! 경로 기반 조건(`JsonPathPredicate`)을 지정할 수 있습니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::annotation::AnnotationReviewStatus;
+
+/// 검색 결과 기본 페이지 크기
+pub const DEFAULT_SEARCH_LIMIT: i64 = 50;
+/// 검색 결과 최대 페이지 크기
+pub const MAX_SEARCH_LIMIT: i64 = 200;
+/// JSONB 경로 최대 깊이
+pub const MAX_JSON_PATH_DEPTH: usize = 16;
+
+/// 검색 결과 정렬 기준
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationSortField {
+    /// 생성 시간
+    #[default]
+    CreatedAt,
+    /// 수정 시간
+    UpdatedAt,
+    /// 어노테이션 ID
+    Id,
+}
+
+impl std::fmt::Display for AnnotationSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnnotationSortField::CreatedAt => write!(f, "created_at"),
+            AnnotationSortField::UpdatedAt => write!(f, "updated_at"),
+            AnnotationSortField::Id => write!(f, "id"),
+        }
+    }
+}
+
+/// 정렬 방향
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    /// 오름차순
+    Asc,
+    /// 내림차순
+    #[default]
+    Desc,
+}
+
+impl std::fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortDirection::Asc => write!(f, "asc"),
+            SortDirection::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+/// JSONB 경로 조건 연산자
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JsonPredicateOp {
+    /// 경로의 값이 `value`와 같음
+    Eq,
+    /// 경로의 값이 `value`와 다름 (경로가 없는 경우 포함)
+    Ne,
+    /// 경로의 값이 `value`보다 큼 (같은 JSON 타입끼리만 비교)
+    Gt,
+    /// 경로의 값이 `value`보다 크거나 같음
+    Gte,
+    /// 경로의 값이 `value`보다 작음
+    Lt,
+    /// 경로의 값이 `value`보다 작거나 같음
+    Lte,
+    /// 경로가 존재함 (`value` 불필요)
+    Exists,
+    /// 경로의 값이 `value`를 포함함 (JSONB `@>`)
+    Contains,
+}
+
+/// JSONB 컬럼에 대한 경로 기반 조건
+///
+/// `path`는 키(또는 배열 인덱스) 목록이며 PostgreSQL의 `#>` 연산자로 평가됩니다.
+/// 예: `{"path": ["measurements", "area"], "op": "gt", "value": 100}`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct JsonPathPredicate {
+    /// JSON 경로 (키 또는 배열 인덱스 목록)
+    #[schema(example = json!(["measurements", "area"]))]
+    pub path: Vec<String>,
+
+    /// 비교 연산자
+    pub op: JsonPredicateOp,
+
+    /// 비교할 값 (`exists` 연산자에서는 생략)
+    #[schema(value_type = Option<Object>, example = json!(100))]
+    pub value: Option<serde_json::Value>,
+}
+
+impl JsonPathPredicate {
+    /// 조건이 올바른지 검증
+    pub fn validate(&self) -> Result<(), String> {
+        if self.path.is_empty() {
+            return Err("JSON path must not be empty".to_string());
+        }
+        if self.path.len() > MAX_JSON_PATH_DEPTH {
+            return Err(format!("JSON path must not be deeper than {}", MAX_JSON_PATH_DEPTH));
+        }
+        if self.path.iter().any(|segment| segment.is_empty()) {
+            return Err("JSON path segments must not be empty".to_string());
+        }
+        match (self.op, &self.value) {
+            (JsonPredicateOp::Exists, _) => Ok(()),
+            (_, None) => Err(format!("Operator {:?} requires a value", self.op)),
+            (_, Some(_)) => Ok(()),
+        }
+    }
+
+    /// 컬럼 전체에 대한 포함(`@>`) 조건으로 바꾼 문서 (하나라도 포함하면 조건을 만족할 수 있음)
+    ///
+    /// 경로 조건은 GIN 인덱스를 사용할 수 없으므로, 경로를 중첩 객체로 감싼 문서를 만들어
+    /// `column @> document` 조건을 함께 걸어 인덱스로 후보를 좁힙니다.
+    /// `eq` / `contains`만 해당하며, 배열 인덱스로 보이는 경로가 있으면 객체로 감쌀 수 없으므로 빈 목록입니다.
+    pub fn containment_documents(&self) -> Vec<serde_json::Value> {
+        let Some(value) = &self.value else {
+            return Vec::new();
+        };
+        if self.path.iter().any(|segment| segment.parse::<i64>().is_ok()) {
+            return Vec::new();
+        }
+        let wrap = |leaf: serde_json::Value| {
+            self.path.iter().rev().fold(leaf, |inner, key| serde_json::json!({ key: inner }))
+        };
+        match self.op {
+            JsonPredicateOp::Eq => vec![wrap(value.clone())],
+            // 스칼라 값은 경로의 값이 같은 스칼라이거나 그 값을 가진 배열일 때 포함됨
+            JsonPredicateOp::Contains if !value.is_array() && !value.is_object() => {
+                vec![wrap(value.clone()), wrap(serde_json::Value::Array(vec![value.clone()]))]
+            }
+            JsonPredicateOp::Contains => vec![wrap(value.clone())],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// 키셋 페이지네이션 커서
+///
+/// 마지막으로 반환된 행의 정렬 키와 ID를 담으며, 불투명한 16진수 문자열로 인코딩됩니다.
+/// 커서는 발급 당시의 정렬 기준/방향과 함께 사용해야 합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnnotationSearchCursor {
+    pub sort: AnnotationSortField,
+    pub direction: SortDirection,
+    /// 정렬 키 값 (시간 정렬은 UTC 마이크로초, ID 정렬은 ID)
+    pub sort_value: i64,
+    pub id: i32,
+}
+
+impl AnnotationSearchCursor {
+    /// 정렬 기준에 맞춰 행으로부터 커서 생성
+    pub fn from_row(
+        sort: AnnotationSortField,
+        direction: SortDirection,
+        id: i32,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        let sort_value = match sort {
+            AnnotationSortField::CreatedAt => created_at.timestamp_micros(),
+            AnnotationSortField::UpdatedAt => updated_at.timestamp_micros(),
+            AnnotationSortField::Id => id as i64,
+        };
+        Self { sort, direction, sort_value, id }
+    }
+
+    /// 시간 정렬 커서의 정렬 키를 시간으로 변환
+    pub fn sort_timestamp(&self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp_micros(self.sort_value)
+    }
+
+    /// 불투명 문자열로 인코딩
+    pub fn encode(&self) -> String {
+        hex::encode(format!("{}:{}:{}:{}", self.sort, self.direction, self.sort_value, self.id))
+    }
+
+    /// 불투명 문자열에서 디코딩
+    pub fn decode(cursor: &str) -> Result<Self, String> {
+        let invalid = || "Invalid cursor".to_string();
+
+        let raw = hex::decode(cursor).map_err(|_| invalid())?;
+        let raw = String::from_utf8(raw).map_err(|_| invalid())?;
+        let parts: Vec<&str> = raw.split(':').collect();
+        let [sort, direction, sort_value, id] = parts.as_slice() else {
+            return Err(invalid());
+        };
+
+        let sort = match *sort {
+            "created_at" => AnnotationSortField::CreatedAt,
+            "updated_at" => AnnotationSortField::UpdatedAt,
+            "id" => AnnotationSortField::Id,
+            _ => return Err(invalid()),
+        };
+        let direction = match *direction {
+            "asc" => SortDirection::Asc,
+            "desc" => SortDirection::Desc,
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self {
+            sort,
+            direction,
+            sort_value: sort_value.parse().map_err(|_| invalid())?,
+            id: id.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// 어노테이션 검색 조건
+///
+/// 값이 지정된 필터만 AND로 결합되어 적용됩니다.
+#[derive(Debug, Clone, Default)]
+pub struct AnnotationSearchCriteria {
+    pub project_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub study_uid: Option<String>,
+    pub series_uid: Option<String>,
+    pub instance_uid: Option<String>,
+    pub tool_name: Option<String>,
+    pub viewer_software: Option<String>,
+    pub is_shared: Option<bool>,
+    pub review_status: Option<AnnotationReviewStatus>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub updated_from: Option<DateTime<Utc>>,
+    pub updated_to: Option<DateTime<Utc>>,
+    pub data_predicates: Vec<JsonPathPredicate>,
+    pub measurement_predicates: Vec<JsonPathPredicate>,
+    /// 지정된 경우 해당 사용자가 볼 수 있는 어노테이션만 반환
+    /// (프로젝트 멤버이면서 공유되었거나 본인이 작성한 어노테이션)
+    pub visible_to_user_id: Option<i32>,
+    pub sort: AnnotationSortField,
+    pub direction: SortDirection,
+    pub cursor: Option<AnnotationSearchCursor>,
+    /// 조회할 최대 행 수
+    pub limit: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = AnnotationSearchCursor {
+            sort: AnnotationSortField::UpdatedAt,
+            direction: SortDirection::Asc,
+            sort_value: 1_700_000_000_123_456,
+            id: 42,
+        };
+
+        let decoded = AnnotationSearchCursor::decode(&cursor.encode()).unwrap();
+        assert_eq!(decoded, cursor);
+        assert_eq!(decoded.sort_timestamp().unwrap().timestamp_micros(), 1_700_000_000_123_456);
+    }
+
+    #[test]
+    fn test_cursor_rejects_garbage() {
+        assert!(AnnotationSearchCursor::decode("not-hex").is_err());
+        assert!(AnnotationSearchCursor::decode(&hex::encode("created_at:desc:1")).is_err());
+        assert!(AnnotationSearchCursor::decode(&hex::encode("name:desc:1:2")).is_err());
+    }
+
+    #[test]
+    fn test_json_predicate_validation() {
+        let predicate = |path: Vec<&str>, op, value| JsonPathPredicate {
+            path: path.into_iter().map(String::from).collect(),
+            op,
+            value,
+        };
+
+        assert!(predicate(vec!["area"], JsonPredicateOp::Exists, None).validate().is_ok());
+        assert!(predicate(vec!["area"], JsonPredicateOp::Gt, Some(serde_json::json!(1))).validate().is_ok());
+        assert!(predicate(vec!["area"], JsonPredicateOp::Gt, None).validate().is_err());
+        assert!(predicate(vec![], JsonPredicateOp::Exists, None).validate().is_err());
+        assert!(predicate(vec!["a", ""], JsonPredicateOp::Exists, None).validate().is_err());
+    }
+
+    #[test]
+    fn test_json_predicate_containment_documents() {
+        let predicate = |path: Vec<&str>, op, value| JsonPathPredicate {
+            path: path.into_iter().map(String::from).collect(),
+            op,
+            value,
+        };
+        use serde_json::json;
+
+        assert_eq!(
+            predicate(vec!["measurements", "unit"], JsonPredicateOp::Eq, Some(json!("mm"))).containment_documents(),
+            vec![json!({"measurements": {"unit": "mm"}})]
+        );
+        assert_eq!(
+            predicate(vec!["labels"], JsonPredicateOp::Contains, Some(json!("liver"))).containment_documents(),
+            vec![json!({"labels": "liver"}), json!({"labels": ["liver"]})]
+        );
+        assert_eq!(
+            predicate(vec!["style"], JsonPredicateOp::Contains, Some(json!({"color": "red"}))).containment_documents(),
+            vec![json!({"style": {"color": "red"}})]
+        );
+        // 배열 인덱스 경로와 범위 비교는 포함 조건으로 바꿀 수 없음
+        assert!(predicate(vec!["points", "0"], JsonPredicateOp::Eq, Some(json!(1))).containment_documents().is_empty());
+        assert!(predicate(vec!["area"], JsonPredicateOp::Gt, Some(json!(1))).containment_documents().is_empty());
+        assert!(predicate(vec!["area"], JsonPredicateOp::Exists, None).containment_documents().is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+//! # 코멘트 엔티티 모듈
+//!
+//! 이 모듈은 어노테이션과 마스크 그룹에 달리는 스레드형 코멘트를 나타내는 엔티티들을 정의합니다.
+//! 코멘트는 답글(스레드), 프로젝트 멤버 @멘션, 해결/미해결 상태, 수정/삭제 이력을 가집니다.
+
+// UTC 시간대의 날짜/시간 처리를 위한 chrono 라이브러리
+use chrono::{DateTime, Utc};
+// JSON 직렬화/역직렬화를 위한 serde 라이브러리
+use serde::{Deserialize, Serialize};
+// SQLx를 통한 데이터베이스 행 매핑을 위한 트레이트
+use sqlx::FromRow;
+// OpenAPI 스키마 생성을 위한 utoipa 라이브러리
+use utoipa::ToSchema;
+
+/// 코멘트 대상 타입
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "comment_target_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CommentTargetType {
+    /// 어노테이션에 달린 코멘트
+    Annotation,
+    /// 마스크 그룹에 달린 코멘트
+    MaskGroup,
+}
+
+impl std::fmt::Display for CommentTargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommentTargetType::Annotation => write!(f, "ANNOTATION"),
+            CommentTargetType::MaskGroup => write!(f, "MASK_GROUP"),
+        }
+    }
+}
+
+/// 코멘트를 나타내는 엔티티
+///
+/// 이 구조체는 데이터베이스의 `annotation_comment` 테이블과 매핑됩니다.
+///
+/// # 필드
+/// - `target_type` / `target_id`: 코멘트가 달린 어노테이션 또는 마스크 그룹
+/// - `annotation_id`: 가시성 판단 기준이 되는 어노테이션 ID (마스크 그룹의 경우 상위 어노테이션)
+/// - `parent_id`: 상위 코멘트 ID (최상위 코멘트는 None)
+/// - `mentions`: 멘션된 프로젝트 멤버 ID 목록
+/// - `is_resolved`: 스레드 해결 여부 (최상위 코멘트에만 의미가 있음)
+/// - `is_deleted`: 소프트 삭제 여부 (답글을 유지하기 위해 행은 남겨둠)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct Comment {
+    pub id: i32,
+    pub target_type: CommentTargetType,
+    pub target_id: i32,
+    pub annotation_id: i32,
+    pub parent_id: Option<i32>,
+    pub user_id: i32,
+    pub body: String,
+    pub mentions: Vec<i32>,
+    pub is_resolved: bool,
+    pub resolved_by: Option<i32>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub is_deleted: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 코멘트 변경 이력을 나타내는 엔티티
+///
+/// 이 구조체는 데이터베이스의 `annotation_comment_history` 테이블과 매핑됩니다.
+/// `action`은 UPDATE, DELETE, RESOLVE, REOPEN 중 하나입니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct CommentHistory {
+    pub id: i32,
+    pub comment_id: i32,
+    pub user_id: i32,
+    pub action: String,
+    pub body_before: Option<String>,
+    pub body_after: Option<String>,
+    pub action_at: DateTime<Utc>,
+}
+
+/// 새 코멘트 생성을 위한 구조체
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewComment {
+    pub target_type: CommentTargetType,
+    pub target_id: i32,
+    pub annotation_id: i32,
+    pub parent_id: Option<i32>,
+    pub user_id: i32,
+    pub body: String,
+    pub mentions: Vec<i32>,
+}
+
+/// 코멘트 본문에서 `@username` 형태의 멘션을 추출
+///
+/// `@` 앞이 영숫자인 경우(이메일 주소 등)는 멘션으로 보지 않으며,
+/// 중복된 멘션은 한 번만 반환합니다.
+pub fn extract_mentions(body: &str) -> Vec<String> {
+    let is_username_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-');
+
+    let mut mentions: Vec<String> = Vec::new();
+    let mut prev: Option<char> = None;
+    let mut chars = body.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        if c == '@' && !prev.map(|p| p.is_alphanumeric()).unwrap_or(false) {
+            let start = idx + 1;
+            let mut end = start;
+            while let Some(&(next_idx, next)) = chars.peek() {
+                if !is_username_char(next) {
+                    break;
+                }
+                end = next_idx + next.len_utf8();
+                chars.next();
+            }
+
+            // 문장 끝의 마침표 등은 사용자명에서 제외
+            let username = body[start..end].trim_end_matches(['.', '-']);
+            if !username.is_empty() && !mentions.iter().any(|m| m == username) {
+                mentions.push(username.to_string());
+            }
+            prev = body[..end].chars().last();
+            continue;
+        }
+        prev = Some(c);
+    }
+
+    mentions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_mentions() {
+        let mentions = extract_mentions("@alice 이 병변 확인 부탁드립니다. cc @bob.kim, @alice");
+        assert_eq!(mentions, vec!["alice".to_string(), "bob.kim".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_mentions_ignores_email_and_bare_at() {
+        assert!(extract_mentions("연락처: reader@hospital.org").is_empty());
+        assert!(extract_mentions("@ 혼자 있는 기호").is_empty());
+        assert_eq!(extract_mentions("(@dr_lee) 확인."), vec!["dr_lee".to_string()]);
+        assert_eq!(extract_mentions("확인했습니다 @carol."), vec!["carol".to_string()]);
+    }
+}
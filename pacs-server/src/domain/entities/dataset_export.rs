@@ -0,0 +1,606 @@
+//! # 데이터셋 내보내기 엔티티 모듈
+//!
+//! 이 모듈은 ML 학습용으로 프로젝트의 어노테이션과 마스크를 내보내는 작업과 결과 레코드를 정의합니다.
+//!
+//! 지원하는 포맷은 다음과 같습니다.
+//! - `coco`: COCO JSON. 어노테이션 `data`는 폴리곤으로, 마스크 슬라이스는 RLE로 변환됩니다.
+//! - `csv` / `json`: 마스크 슬라이스 단위의 매니페스트. 각 행은 SOP Instance UID, 마스크 오브젝트 키
+//!   (또는 Signed URL), 라벨을 담습니다.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+use super::annotation::AnnotationReviewStatus;
+
+/// 원을 폴리곤으로 근사할 때 사용하는 꼭짓점 수
+pub const CIRCLE_POLYGON_VERTICES: usize = 32;
+
+/// 데이터셋 내보내기 포맷
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DatasetExportFormat {
+    /// COCO JSON (폴리곤 / RLE)
+    Coco,
+    /// 슬라이스 단위 CSV 매니페스트
+    Csv,
+    /// 슬라이스 단위 JSON 매니페스트
+    Json,
+}
+
+impl DatasetExportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DatasetExportFormat::Coco => "coco",
+            DatasetExportFormat::Csv => "csv",
+            DatasetExportFormat::Json => "json",
+        }
+    }
+
+    /// 저장된 포맷 문자열을 파싱합니다.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "coco" => Ok(DatasetExportFormat::Coco),
+            "csv" => Ok(DatasetExportFormat::Csv),
+            "json" => Ok(DatasetExportFormat::Json),
+            other => Err(format!("Unsupported dataset export format: {}", other)),
+        }
+    }
+
+    /// 결과 파일의 확장자
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            DatasetExportFormat::Coco => "coco.json",
+            DatasetExportFormat::Csv => "csv",
+            DatasetExportFormat::Json => "json",
+        }
+    }
+
+    /// 결과 파일의 MIME 타입
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            DatasetExportFormat::Csv => "text/csv",
+            _ => "application/json",
+        }
+    }
+}
+
+/// 데이터셋 내보내기 작업 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "dataset_export_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DatasetExportStatus {
+    /// 작업이 생성되었고 아직 시작되지 않음
+    Pending,
+    /// 데이터를 수집하고 결과 파일을 만드는 중
+    Running,
+    /// 결과 파일 업로드까지 끝남
+    Completed,
+    /// 처리 중 오류가 발생함
+    Failed,
+}
+
+impl std::fmt::Display for DatasetExportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DatasetExportStatus::Pending => write!(f, "PENDING"),
+            DatasetExportStatus::Running => write!(f, "RUNNING"),
+            DatasetExportStatus::Completed => write!(f, "COMPLETED"),
+            DatasetExportStatus::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// 내보낼 어노테이션/마스크를 고르는 필터
+///
+/// - `label`: 마스크는 `label_name`, 어노테이션은 `data.label`(없으면 `tool_name`)과 비교
+/// - `model_name`: 마스크 그룹의 `model_name`과 비교 (어노테이션은 해당 모델의 마스크 그룹이 있어야 포함)
+/// - `review_status`: 어노테이션의 검토 상태와 비교
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct DatasetExportFilter {
+    pub label: Option<String>,
+    pub model_name: Option<String>,
+    pub review_status: Option<AnnotationReviewStatus>,
+}
+
+/// 데이터셋 내보내기 작업을 나타내는 엔티티
+///
+/// 이 구조체는 데이터베이스의 `annotation_dataset_export_job` 테이블과 매핑됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct DatasetExportJob {
+    pub id: i32,
+    pub project_id: i32,
+    pub format: String,
+    pub filters: serde_json::Value,
+    pub include_signed_urls: bool,
+    pub url_ttl_seconds: Option<i32>,
+    pub status: DatasetExportStatus,
+    pub annotation_count: i32,
+    pub mask_count: i32,
+    pub skipped_count: i32,
+    pub output_path: Option<String>,
+    pub error_message: Option<String>,
+    pub requested_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl DatasetExportJob {
+    /// 저장된 JSON에서 필터를 읽습니다.
+    pub fn filter(&self) -> DatasetExportFilter {
+        serde_json::from_value(self.filters.clone()).unwrap_or_default()
+    }
+}
+
+/// 새로운 데이터셋 내보내기 작업 생성을 위한 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NewDatasetExportJob {
+    pub project_id: i32,
+    pub format: DatasetExportFormat,
+    pub filter: DatasetExportFilter,
+    pub include_signed_urls: bool,
+    pub url_ttl_seconds: Option<i32>,
+    pub requested_by: Option<i32>,
+}
+
+/// 내보내기 작업 결과 집계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DatasetExportCounts {
+    pub annotations: i32,
+    pub masks: i32,
+    pub skipped: i32,
+}
+
+/// 내보내기 대상 어노테이션 행
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct DatasetAnnotationRow {
+    pub annotation_id: i32,
+    pub study_uid: String,
+    pub series_uid: Option<String>,
+    pub instance_uid: Option<String>,
+    pub tool_name: String,
+    pub data: serde_json::Value,
+    pub review_status: AnnotationReviewStatus,
+}
+
+impl DatasetAnnotationRow {
+    /// 어노테이션의 라벨 (`data.label`, 없으면 도구 이름)
+    pub fn label(&self) -> String {
+        self.data
+            .get("label")
+            .and_then(|v| v.as_str())
+            .filter(|label| !label.is_empty())
+            .unwrap_or(&self.tool_name)
+            .to_string()
+    }
+}
+
+/// 내보내기 대상 마스크 슬라이스 행
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct DatasetMaskRow {
+    pub mask_id: i32,
+    pub mask_group_id: i32,
+    pub annotation_id: i32,
+    pub study_uid: String,
+    pub series_uid: Option<String>,
+    pub sop_instance_uid: Option<String>,
+    pub slice_index: Option<i32>,
+    pub label_name: Option<String>,
+    pub group_name: Option<String>,
+    pub model_name: Option<String>,
+    pub review_status: AnnotationReviewStatus,
+    pub file_path: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+impl DatasetMaskRow {
+    /// 마스크의 라벨 (`label_name`, 없으면 그룹 이름)
+    pub fn label(&self) -> String {
+        self.label_name
+            .clone()
+            .or_else(|| self.group_name.clone())
+            .unwrap_or_else(|| "mask".to_string())
+    }
+}
+
+/// 매니페스트(CSV/JSON)의 한 행. 마스크 슬라이스 하나에 해당합니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DatasetManifestRow {
+    pub annotation_id: i32,
+    pub mask_group_id: i32,
+    pub mask_id: i32,
+    pub study_uid: String,
+    pub series_uid: Option<String>,
+    pub sop_instance_uid: Option<String>,
+    pub slice_index: Option<i32>,
+    pub label: String,
+    pub model_name: Option<String>,
+    pub review_status: AnnotationReviewStatus,
+    /// 마스크 파일의 오브젝트 키
+    pub object_key: String,
+    /// 마스크 다운로드 Signed URL (요청한 경우에만)
+    pub download_url: Option<String>,
+}
+
+impl DatasetManifestRow {
+    /// CSV 헤더 컬럼 순서
+    pub const CSV_COLUMNS: [&'static str; 12] = [
+        "annotation_id",
+        "mask_group_id",
+        "mask_id",
+        "study_uid",
+        "series_uid",
+        "sop_instance_uid",
+        "slice_index",
+        "label",
+        "model_name",
+        "review_status",
+        "object_key",
+        "download_url",
+    ];
+
+    /// `CSV_COLUMNS` 순서의 필드 값
+    pub fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.annotation_id.to_string(),
+            self.mask_group_id.to_string(),
+            self.mask_id.to_string(),
+            self.study_uid.clone(),
+            self.series_uid.clone().unwrap_or_default(),
+            self.sop_instance_uid.clone().unwrap_or_default(),
+            self.slice_index.map(|i| i.to_string()).unwrap_or_default(),
+            self.label.clone(),
+            self.model_name.clone().unwrap_or_default(),
+            self.review_status_name(),
+            self.object_key.clone(),
+            self.download_url.clone().unwrap_or_default(),
+        ]
+    }
+
+    fn review_status_name(&self) -> String {
+        serde_json::to_value(self.review_status)
+            .ok()
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_default()
+    }
+
+    pub fn from_mask(row: &DatasetMaskRow, download_url: Option<String>) -> Self {
+        Self {
+            annotation_id: row.annotation_id,
+            mask_group_id: row.mask_group_id,
+            mask_id: row.mask_id,
+            study_uid: row.study_uid.clone(),
+            series_uid: row.series_uid.clone(),
+            sop_instance_uid: row.sop_instance_uid.clone(),
+            slice_index: row.slice_index,
+            label: row.label(),
+            model_name: row.model_name.clone(),
+            review_status: row.review_status,
+            object_key: row.file_path.clone(),
+            download_url,
+        }
+    }
+}
+
+/// COCO 데이터셋 문서
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CocoDataset {
+    pub info: CocoInfo,
+    pub images: Vec<CocoImage>,
+    pub categories: Vec<CocoCategory>,
+    pub annotations: Vec<CocoAnnotation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CocoInfo {
+    pub description: String,
+    pub version: String,
+    pub date_created: DateTime<Utc>,
+}
+
+/// COCO 이미지. DICOM 인스턴스 하나에 해당하며 `file_name`은 SOP Instance UID입니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CocoImage {
+    pub id: i64,
+    pub file_name: String,
+    pub sop_instance_uid: String,
+    pub study_uid: String,
+    pub series_uid: Option<String>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CocoCategory {
+    pub id: i64,
+    pub name: String,
+    pub supercategory: String,
+}
+
+/// COCO 세그멘테이션 (폴리곤 목록 또는 비압축 RLE)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum CocoSegmentation {
+    Polygon(Vec<Vec<f64>>),
+    Rle(CocoRle),
+}
+
+/// 비압축 COCO RLE (열 우선 순서, 배경 길이부터 시작)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CocoRle {
+    pub counts: Vec<u32>,
+    /// `[height, width]`
+    pub size: [u32; 2],
+}
+
+/// COCO 어노테이션. 원본이 어노테이션이면 폴리곤, 마스크 슬라이스면 RLE를 가집니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CocoAnnotation {
+    pub id: i64,
+    pub image_id: i64,
+    pub category_id: i64,
+    pub segmentation: CocoSegmentation,
+    pub area: f64,
+    /// `[x, y, width, height]`
+    pub bbox: [f64; 4],
+    pub iscrowd: u8,
+    /// 원본 어노테이션 ID
+    pub annotation_id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_group_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_object_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_url: Option<String>,
+}
+
+/// 이미지/카테고리 ID를 부여하며 COCO 문서를 조립하는 빌더
+#[derive(Debug, Default)]
+pub struct CocoDatasetBuilder {
+    images: Vec<CocoImage>,
+    image_ids: HashMap<String, i64>,
+    categories: Vec<CocoCategory>,
+    category_ids: HashMap<String, i64>,
+    annotations: Vec<CocoAnnotation>,
+}
+
+impl CocoDatasetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// SOP Instance UID에 해당하는 이미지 ID를 반환합니다. 처음 보는 인스턴스면 이미지를 추가합니다.
+    /// 크기를 모르던 이미지는 나중에 알게 된 크기로 채웁니다.
+    pub fn image_id(
+        &mut self,
+        sop_instance_uid: &str,
+        study_uid: &str,
+        series_uid: Option<&str>,
+        size: Option<(i32, i32)>,
+    ) -> i64 {
+        if let Some(&id) = self.image_ids.get(sop_instance_uid) {
+            let image = &mut self.images[(id - 1) as usize];
+            if image.width.is_none() {
+                if let Some((width, height)) = size {
+                    image.width = Some(width);
+                    image.height = Some(height);
+                }
+            }
+            return id;
+        }
+
+        let id = self.images.len() as i64 + 1;
+        self.images.push(CocoImage {
+            id,
+            file_name: sop_instance_uid.to_string(),
+            sop_instance_uid: sop_instance_uid.to_string(),
+            study_uid: study_uid.to_string(),
+            series_uid: series_uid.map(|s| s.to_string()),
+            width: size.map(|(w, _)| w),
+            height: size.map(|(_, h)| h),
+        });
+        self.image_ids.insert(sop_instance_uid.to_string(), id);
+        id
+    }
+
+    /// 라벨에 해당하는 카테고리 ID를 반환합니다. 처음 보는 라벨이면 카테고리를 추가합니다.
+    pub fn category_id(&mut self, label: &str, supercategory: &str) -> i64 {
+        if let Some(&id) = self.category_ids.get(label) {
+            return id;
+        }
+
+        let id = self.categories.len() as i64 + 1;
+        self.categories.push(CocoCategory {
+            id,
+            name: label.to_string(),
+            supercategory: supercategory.to_string(),
+        });
+        self.category_ids.insert(label.to_string(), id);
+        id
+    }
+
+    /// 어노테이션을 추가합니다. `id`는 빌더가 순서대로 부여합니다.
+    pub fn push_annotation(&mut self, mut annotation: CocoAnnotation) {
+        annotation.id = self.annotations.len() as i64 + 1;
+        self.annotations.push(annotation);
+    }
+
+    pub fn build(self, description: String) -> CocoDataset {
+        CocoDataset {
+            info: CocoInfo {
+                description,
+                version: "1.0".to_string(),
+                date_created: Utc::now(),
+            },
+            images: self.images,
+            categories: self.categories,
+            annotations: self.annotations,
+        }
+    }
+}
+
+/// 어노테이션 `data`를 COCO 폴리곤(`[x1, y1, x2, y2, ...]`)으로 변환합니다.
+///
+/// - `polygon`, `freehand`, `closed_polygon`: `points`의 꼭짓점 (3개 이상)
+/// - `rectangle`: `x`, `y`, `width`, `height` 또는 대각선 두 점의 `points`
+/// - `circle`: `x`, `y`, `radius`를 정다각형으로 근사
+///
+/// 면적이 없는 도형(점, 선, 측정 등)은 `None`을 반환합니다.
+pub fn annotation_polygon(data: &serde_json::Value) -> Option<Vec<f64>> {
+    let shape = data.get("type").and_then(|v| v.as_str())?.to_ascii_lowercase();
+    let number = |key: &str| data.get(key).and_then(|v| v.as_f64());
+    let points = || -> Option<Vec<(f64, f64)>> {
+        data.get("points")?
+            .as_array()?
+            .iter()
+            .map(|p| {
+                let p = p.as_array()?;
+                Some((p.first()?.as_f64()?, p.get(1)?.as_f64()?))
+            })
+            .collect()
+    };
+
+    let vertices: Vec<(f64, f64)> = match shape.as_str() {
+        "polygon" | "freehand" | "closed_polygon" | "closedpolygon" => {
+            let points = points()?;
+            if points.len() < 3 {
+                return None;
+            }
+            points
+        }
+        "rectangle" => {
+            let (x, y, width, height) = match (number("x"), number("y"), number("width"), number("height")) {
+                (Some(x), Some(y), Some(w), Some(h)) => (x, y, w, h),
+                _ => match points()?.as_slice() {
+                    [(x1, y1), (x2, y2)] => (x1.min(*x2), y1.min(*y2), (x2 - x1).abs(), (y2 - y1).abs()),
+                    _ => return None,
+                },
+            };
+            if width <= 0.0 || height <= 0.0 {
+                return None;
+            }
+            vec![(x, y), (x + width, y), (x + width, y + height), (x, y + height)]
+        }
+        "circle" => {
+            let (x, y, radius) = (number("x")?, number("y")?, number("radius")?);
+            if radius <= 0.0 {
+                return None;
+            }
+            (0..CIRCLE_POLYGON_VERTICES)
+                .map(|i| {
+                    let angle = 2.0 * std::f64::consts::PI * i as f64 / CIRCLE_POLYGON_VERTICES as f64;
+                    (x + radius * angle.cos(), y + radius * angle.sin())
+                })
+                .collect()
+        }
+        _ => return None,
+    };
+
+    Some(vertices.into_iter().flat_map(|(x, y)| [x, y]).collect())
+}
+
+/// 폴리곤의 면적 (신발끈 공식)
+pub fn polygon_area(polygon: &[f64]) -> f64 {
+    let points: Vec<(f64, f64)> = polygon.chunks_exact(2).map(|p| (p[0], p[1])).collect();
+    let n = points.len();
+    if n < 3 {
+        return 0.0;
+    }
+    let twice_area: f64 = (0..n)
+        .map(|i| {
+            let (x1, y1) = points[i];
+            let (x2, y2) = points[(i + 1) % n];
+            x1 * y2 - x2 * y1
+        })
+        .sum();
+    twice_area.abs() / 2.0
+}
+
+/// 폴리곤의 경계 상자 `[x, y, width, height]`
+pub fn polygon_bbox(polygon: &[f64]) -> [f64; 4] {
+    let xs = polygon.iter().step_by(2);
+    let ys = polygon.iter().skip(1).step_by(2);
+    let (min_x, max_x) = xs.fold((f64::MAX, f64::MIN), |(lo, hi), &x| (lo.min(x), hi.max(x)));
+    let (min_y, max_y) = ys.fold((f64::MAX, f64::MIN), |(lo, hi), &y| (lo.min(y), hi.max(y)));
+    if min_x > max_x || min_y > max_y {
+        return [0.0; 4];
+    }
+    [min_x, min_y, max_x - min_x, max_y - min_y]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_annotation_polygon_shapes() {
+        let polygon = annotation_polygon(&json!({"type": "polygon", "points": [[0, 0], [10, 0], [10, 10]]})).unwrap();
+        assert_eq!(polygon, vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0]);
+        assert_eq!(polygon_area(&polygon), 50.0);
+
+        let rect = annotation_polygon(&json!({"type": "rectangle", "x": 5, "y": 5, "width": 4, "height": 2})).unwrap();
+        assert_eq!(polygon_bbox(&rect), [5.0, 5.0, 4.0, 2.0]);
+        assert_eq!(polygon_area(&rect), 8.0);
+
+        let circle = annotation_polygon(&json!({"type": "circle", "x": 100, "y": 200, "radius": 50})).unwrap();
+        assert_eq!(circle.len(), CIRCLE_POLYGON_VERTICES * 2);
+        let [x, y, w, h] = polygon_bbox(&circle);
+        assert!((x - 50.0).abs() < 1e-9 && (y - 150.0).abs() < 1e-9);
+        assert!((w - 100.0).abs() < 1e-9 && (h - 100.0).abs() < 1e-9);
+
+        assert!(annotation_polygon(&json!({"type": "line", "points": [[0, 0], [100, 100]]})).is_none());
+        assert!(annotation_polygon(&json!({"type": "polygon", "points": [[0, 0], [1, 1]]})).is_none());
+    }
+
+    #[test]
+    fn test_coco_builder_reuses_images_and_categories() {
+        let mut builder = CocoDatasetBuilder::new();
+        let first = builder.image_id("1.2.3.1", "1.2.3", None, None);
+        let again = builder.image_id("1.2.3.1", "1.2.3", None, Some((512, 256)));
+        let second = builder.image_id("1.2.3.2", "1.2.3", None, None);
+        assert_eq!((first, again, second), (1, 1, 2));
+
+        assert_eq!(builder.category_id("liver", "mask"), 1);
+        assert_eq!(builder.category_id("tumor", "mask"), 2);
+        assert_eq!(builder.category_id("liver", "mask"), 1);
+
+        let dataset = builder.build("test".to_string());
+        assert_eq!(dataset.images[0].width, Some(512));
+        assert_eq!(dataset.images[0].height, Some(256));
+        assert_eq!(dataset.categories.len(), 2);
+    }
+
+    #[test]
+    fn test_manifest_csv_fields_follow_columns() {
+        let row = DatasetManifestRow {
+            annotation_id: 1,
+            mask_group_id: 2,
+            mask_id: 3,
+            study_uid: "1.2.3".to_string(),
+            series_uid: None,
+            sop_instance_uid: Some("1.2.3.4".to_string()),
+            slice_index: Some(7),
+            label: "liver".to_string(),
+            model_name: Some("unet".to_string()),
+            review_status: AnnotationReviewStatus::Approved,
+            object_key: "masks/annotation_1/group_2/slice_0007_liver.png".to_string(),
+            download_url: None,
+        };
+
+        let fields = row.csv_fields();
+        assert_eq!(fields.len(), DatasetManifestRow::CSV_COLUMNS.len());
+        assert_eq!(fields[6], "7");
+        assert_eq!(fields[9], "APPROVED");
+        assert_eq!(fields[11], "");
+    }
+}
@@ -0,0 +1,182 @@
+//! # 데이터셋 릴리스 엔티티 모듈
+//!
+//! 이 모듈은 모델 결과를 발표할 때 사용한 데이터를 정확히 인용할 수 있도록, 프로젝트의 어노테이션과
+//! 마스크를 불변 스냅샷으로 고정하는 데이터셋 릴리스를 정의합니다.
+//!
+//! - 어노테이션은 히스토리 ID로 고정되며, 매니페스트에 당시의 `data`가 함께 기록됩니다.
+//! - 마스크는 체크섬으로 고정되며, 릴리스 전용 경로로 복사되어 원본이 바뀌어도 유지됩니다.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::annotation::AnnotationReviewStatus;
+use super::dataset_export::DatasetExportFilter;
+
+/// 현재 릴리스 매니페스트 포맷 버전
+pub const RELEASE_MANIFEST_VERSION: u32 = 1;
+
+/// 데이터셋 릴리스를 나타내는 엔티티
+///
+/// 이 구조체는 데이터베이스의 `annotation_dataset_release` 테이블과 매핑됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct DatasetRelease {
+    pub id: i32,
+    pub project_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub release_key: Uuid,
+    pub manifest_path: String,
+    pub filters: serde_json::Value,
+    pub annotation_count: i32,
+    pub mask_count: i32,
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl DatasetRelease {
+    /// 저장된 JSON에서 선택 조건을 읽습니다.
+    pub fn filter(&self) -> DatasetExportFilter {
+        serde_json::from_value(self.filters.clone()).unwrap_or_default()
+    }
+}
+
+/// 릴리스에 고정할 어노테이션 버전
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct ReleaseAnnotationSnapshot {
+    pub annotation_id: i32,
+    /// 고정된 `annotation_annotation_history` ID
+    pub history_id: i32,
+    pub study_uid: String,
+    pub series_uid: Option<String>,
+    pub instance_uid: Option<String>,
+    pub tool_name: String,
+    pub data: serde_json::Value,
+    pub review_status: AnnotationReviewStatus,
+    /// 현재 `data`와 일치하는 히스토리가 없어, 릴리스 저장 시 `history_id`로 스냅샷 히스토리를 기록해야 하는지 여부
+    #[serde(skip)]
+    pub snapshot_pending: bool,
+}
+
+/// 릴리스 대상 마스크 원본
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct ReleaseMaskSource {
+    pub mask_id: i32,
+    pub mask_group_id: i32,
+    pub annotation_id: i32,
+    pub sop_instance_uid: Option<String>,
+    pub slice_index: Option<i32>,
+    pub label_name: Option<String>,
+    pub file_path: String,
+    pub checksum: Option<String>,
+}
+
+/// 릴리스에 고정된 마스크
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReleaseMaskSnapshot {
+    pub mask_id: i32,
+    pub mask_group_id: i32,
+    pub annotation_id: i32,
+    pub sop_instance_uid: Option<String>,
+    pub slice_index: Option<i32>,
+    pub label_name: Option<String>,
+    /// 마스크 파일의 SHA-256 체크섬
+    pub checksum: String,
+    /// 릴리스 당시 원본 파일 경로
+    pub source_path: String,
+    /// 릴리스 전용 사본 경로
+    pub object_key: String,
+}
+
+/// 오브젝트 스토리지에 저장되는 릴리스 매니페스트
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DatasetReleaseManifest {
+    pub format_version: u32,
+    pub project_id: i32,
+    pub project_name: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub release_key: Uuid,
+    pub filter: DatasetExportFilter,
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub annotations: Vec<ReleaseAnnotationSnapshot>,
+    pub masks: Vec<ReleaseMaskSnapshot>,
+}
+
+/// 새로운 릴리스 저장을 위한 DTO
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewDatasetRelease {
+    pub project_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub release_key: Uuid,
+    pub manifest_path: String,
+    pub filter: DatasetExportFilter,
+    pub created_by: Option<i32>,
+    /// 고정할 어노테이션 버전 (`snapshot_pending`인 항목은 릴리스와 같은 트랜잭션에서 히스토리로 기록)
+    pub annotations: Vec<ReleaseAnnotationSnapshot>,
+    pub masks: Vec<ReleaseMaskSnapshot>,
+}
+
+/// 두 릴리스 사이의 항목 변화
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ReleaseItemDiff {
+    /// 새 릴리스에만 있는 항목 ID
+    pub added: Vec<i32>,
+    /// 기준 릴리스에만 있는 항목 ID
+    pub removed: Vec<i32>,
+    /// 양쪽에 있지만 고정된 버전(히스토리 ID / 체크섬)이 다른 항목 ID
+    pub changed: Vec<i32>,
+    /// 양쪽에 같은 버전으로 있는 항목 수
+    pub unchanged_count: i64,
+}
+
+impl ReleaseItemDiff {
+    /// 항목 ID → 고정 버전 맵 두 개를 비교합니다. 결과 ID 목록은 오름차순입니다.
+    pub fn compute<V: PartialEq>(base: &HashMap<i32, V>, target: &HashMap<i32, V>) -> Self {
+        let mut diff = ReleaseItemDiff::default();
+
+        for (id, version) in target {
+            match base.get(id) {
+                None => diff.added.push(*id),
+                Some(base_version) if base_version != version => diff.changed.push(*id),
+                Some(_) => diff.unchanged_count += 1,
+            }
+        }
+        diff.removed = base.keys().filter(|id| !target.contains_key(id)).copied().collect();
+
+        diff.added.sort_unstable();
+        diff.removed.sort_unstable();
+        diff.changed.sort_unstable();
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_item_diff() {
+        let base: HashMap<i32, i32> = [(1, 10), (2, 20), (3, 30)].into_iter().collect();
+        let target: HashMap<i32, i32> = [(2, 20), (3, 31), (4, 40)].into_iter().collect();
+
+        let diff = ReleaseItemDiff::compute(&base, &target);
+        assert_eq!(diff.added, vec![4]);
+        assert_eq!(diff.removed, vec![1]);
+        assert_eq!(diff.changed, vec![3]);
+        assert_eq!(diff.unchanged_count, 1);
+    }
+
+    #[test]
+    fn test_release_item_diff_by_checksum() {
+        let base: HashMap<i32, String> = [(7, "abc".to_string())].into_iter().collect();
+        let same = ReleaseItemDiff::compute(&base, &base.clone());
+        assert_eq!(same, ReleaseItemDiff { unchanged_count: 1, ..Default::default() });
+    }
+}
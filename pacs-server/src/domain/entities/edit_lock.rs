@@ -0,0 +1,165 @@
+//! 편집 잠금 엔티티
+//!
+//! 여러 판독자가 같은 Study를 동시에 수정할 때의 충돌을 막기 위한 권고(advisory) 잠금입니다.
+//! 잠금은 Study 전체 또는 어노테이션 하나에 걸 수 있으며, TTL 안에 heartbeat로 갱신하지 않으면 만료됩니다.
+//! 다른 사용자의 유효한 잠금이 걸린 어노테이션/마스크 그룹은 수정하거나 삭제할 수 없습니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// 기본 잠금 유지 시간 (초)
+pub const DEFAULT_EDIT_LOCK_TTL_SECONDS: i64 = 120;
+/// 최소 잠금 유지 시간 (초)
+pub const MIN_EDIT_LOCK_TTL_SECONDS: i64 = 10;
+/// 최대 잠금 유지 시간 (초)
+pub const MAX_EDIT_LOCK_TTL_SECONDS: i64 = 900;
+
+/// 잠금 대상 종류
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "edit_lock_target_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EditLockTarget {
+    /// Study 전체 (Study의 모든 어노테이션/마스크 그룹)
+    Study,
+    /// 어노테이션 하나 (하위 마스크 그룹 포함)
+    Annotation,
+}
+
+impl EditLockTarget {
+    /// 접근 로그의 리소스 종류
+    pub fn resource_type(&self) -> &'static str {
+        match self {
+            EditLockTarget::Study => "STUDY",
+            EditLockTarget::Annotation => "ANNOTATION",
+        }
+    }
+}
+
+/// 편집 잠금 (잠금을 가진 사용자 이름 포함)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct EditLock {
+    pub id: i32,
+    pub project_id: i32,
+    pub target_type: EditLockTarget,
+    pub study_uid: String,
+    pub annotation_id: Option<i32>,
+    pub holder_id: i32,
+    pub holder_username: Option<String>,
+    pub acquired_at: DateTime<Utc>,
+    /// 마지막 heartbeat 시간
+    pub renewed_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl EditLock {
+    /// 잠금 대상 설명 (오류 메시지용)
+    pub fn describe(&self) -> String {
+        let holder = self
+            .holder_username
+            .clone()
+            .unwrap_or_else(|| format!("user {}", self.holder_id));
+        let target = match self.annotation_id {
+            Some(annotation_id) => format!("Annotation {}", annotation_id),
+            None => format!("Study {}", self.study_uid),
+        };
+        format!(
+            "{} is locked for editing by {} (lock {}) until {}",
+            target,
+            holder,
+            self.id,
+            self.expires_at.to_rfc3339()
+        )
+    }
+
+    /// 접근 로그의 세션 식별자
+    pub fn session_id(&self) -> String {
+        format!("edit-lock:{}", self.id)
+    }
+}
+
+/// 새 편집 잠금 (어노테이션 잠금이면 `annotation_id`가 있음)
+#[derive(Debug, Clone)]
+pub struct NewEditLock {
+    pub project_id: i32,
+    pub study_uid: String,
+    pub annotation_id: Option<i32>,
+    pub holder_id: i32,
+    pub ttl_seconds: i64,
+}
+
+impl NewEditLock {
+    pub fn target_type(&self) -> EditLockTarget {
+        if self.annotation_id.is_some() {
+            EditLockTarget::Annotation
+        } else {
+            EditLockTarget::Study
+        }
+    }
+}
+
+/// 잠금 획득 시도 결과
+#[derive(Debug, Clone)]
+pub enum EditLockAttempt {
+    /// 새로 획득함
+    Acquired(EditLock),
+    /// 이미 가진 잠금을 갱신함
+    Renewed(EditLock),
+    /// 다른 사용자의 잠금과 충돌함 (충돌한 잠금)
+    Conflict(EditLock),
+}
+
+/// 요청한 잠금 유지 시간 검증 (없으면 기본값)
+pub fn validate_lock_ttl(ttl_seconds: Option<i64>) -> Result<i64, String> {
+    let ttl = ttl_seconds.unwrap_or(DEFAULT_EDIT_LOCK_TTL_SECONDS);
+    if !(MIN_EDIT_LOCK_TTL_SECONDS..=MAX_EDIT_LOCK_TTL_SECONDS).contains(&ttl) {
+        return Err(format!(
+            "Lock TTL must be between {} and {} seconds",
+            MIN_EDIT_LOCK_TTL_SECONDS, MAX_EDIT_LOCK_TTL_SECONDS
+        ));
+    }
+    Ok(ttl)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_lock_ttl() {
+        assert_eq!(validate_lock_ttl(None), Ok(DEFAULT_EDIT_LOCK_TTL_SECONDS));
+        assert_eq!(validate_lock_ttl(Some(30)), Ok(30));
+        assert!(validate_lock_ttl(Some(1)).is_err());
+        assert!(validate_lock_ttl(Some(3600)).is_err());
+    }
+
+    #[test]
+    fn test_lock_description_and_target() {
+        let now = Utc::now();
+        let lock = EditLock {
+            id: 7,
+            project_id: 1,
+            target_type: EditLockTarget::Annotation,
+            study_uid: "1.2.3".to_string(),
+            annotation_id: Some(42),
+            holder_id: 3,
+            holder_username: None,
+            acquired_at: now,
+            renewed_at: now,
+            expires_at: now,
+        };
+        assert!(lock.describe().starts_with("Annotation 42 is locked for editing by user 3 (lock 7)"));
+        assert_eq!(lock.session_id(), "edit-lock:7");
+
+        let new_lock = NewEditLock {
+            project_id: 1,
+            study_uid: "1.2.3".to_string(),
+            annotation_id: None,
+            holder_id: 3,
+            ttl_seconds: 60,
+        };
+        assert_eq!(new_lock.target_type(), EditLockTarget::Study);
+        assert_eq!(new_lock.target_type().resource_type(), "STUDY");
+    }
+}
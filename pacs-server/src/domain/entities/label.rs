@@ -0,0 +1,758 @@
+//! 라벨 분류 체계 엔티티
+//!
+//! 프로젝트마다 정식 라벨(이름, 색상, SNOMED CT/RadLex 코드, 상위 라벨, 허용 도구)을 정의합니다.
+//! 분류 체계가 있는 프로젝트에서는 마스크의 `label_name`과 어노테이션의 `data.label`이
+//! 정식 라벨 이름 또는 별칭이어야 하며, 저장 시 정식 이름으로 바뀝니다.
+
+use std::collections::{HashMap, HashSet};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// 지원하는 코드 체계 (DICOM Coding Scheme Designator)
+pub const LABEL_CODE_SCHEMES: &[&str] = &["SCT", "RADLEX"];
+
+/// 라벨 이름 / 별칭 최대 길이
+pub const MAX_LABEL_NAME_LENGTH: usize = 100;
+
+/// 마이그레이션 제안 시 무시하는 자유 입력 라벨 접미사 (예: `LIVER_SEG` → `liver`)
+const IGNORED_LABEL_SUFFIXES: &[&str] = &["seg", "segmentation", "mask", "label", "roi"];
+
+/// CSV 가져오기에서 허용하는 컬럼
+pub const LABEL_CSV_COLUMNS: &[&str] = &[
+    "name",
+    "display_name",
+    "color",
+    "code_scheme",
+    "code_value",
+    "code_meaning",
+    "parent",
+    "allowed_tools",
+    "aliases",
+];
+
+/// CSV에서 목록 컬럼(allowed_tools, aliases)의 항목 구분자
+const CSV_LIST_SEPARATOR: char = ';';
+
+/// 프로젝트 분류 체계의 라벨
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Label {
+    pub id: i32,
+    pub project_id: i32,
+    /// 상위 라벨 ID
+    pub parent_id: Option<i32>,
+    /// 정식 라벨 이름
+    pub name: String,
+    pub display_name: Option<String>,
+    /// 표시 색상 (#rrggbb)
+    pub color: Option<String>,
+    /// 코드 체계 (SCT, RADLEX)
+    pub code_scheme: Option<String>,
+    pub code_value: Option<String>,
+    pub code_meaning: Option<String>,
+    /// 사용할 수 있는 어노테이션 도구 (비어 있으면 제한 없음)
+    pub allowed_tools: Vec<String>,
+    /// 정식 이름으로 매핑되는 별칭
+    pub aliases: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 라벨 생성/수정/가져오기에 공통으로 쓰이는 라벨 정의
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabelDefinition {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub color: Option<String>,
+    pub code_scheme: Option<String>,
+    pub code_value: Option<String>,
+    pub code_meaning: Option<String>,
+    pub allowed_tools: Vec<String>,
+    pub aliases: Vec<String>,
+}
+
+impl LabelDefinition {
+    /// 입력값을 정리하고 검증합니다.
+    ///
+    /// - 문자열 앞뒤 공백 제거, 빈 문자열은 None
+    /// - 색상은 `#rrggbb` 형식 (소문자로 저장)
+    /// - 코드 체계는 `SCT`/`RADLEX` (`SNOMED`, `SNOMED-CT`도 `SCT`로 인식), 코드 값과 함께 지정
+    /// - 도구/별칭 중복 제거, 이름과 같은 별칭 제거
+    pub fn normalize(self) -> Result<Self, String> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() || name.chars().count() > MAX_LABEL_NAME_LENGTH {
+            return Err(format!("Label name must be between 1 and {} characters", MAX_LABEL_NAME_LENGTH));
+        }
+        if normalize_label_key(&name).is_empty() {
+            return Err(format!("Label name '{}' must contain a letter or digit", name));
+        }
+
+        let color = non_empty(self.color).map(|c| c.to_ascii_lowercase());
+        if let Some(color) = &color {
+            let valid = color.len() == 7
+                && color.starts_with('#')
+                && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+            if !valid {
+                return Err(format!("Invalid color '{}': expected #RRGGBB", color));
+            }
+        }
+
+        let code_scheme = non_empty(self.code_scheme)
+            .map(|scheme| normalize_code_scheme(&scheme))
+            .transpose()?;
+        let code_value = non_empty(self.code_value);
+        let code_meaning = non_empty(self.code_meaning);
+        if code_scheme.is_some() != code_value.is_some() {
+            return Err(format!("Label '{}': code_scheme and code_value must be given together", name));
+        }
+        if code_meaning.is_some() && code_value.is_none() {
+            return Err(format!("Label '{}': code_meaning requires a code", name));
+        }
+
+        let mut allowed_tools: Vec<String> = Vec::new();
+        for tool in self.allowed_tools.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+            if !allowed_tools.iter().any(|t| t.eq_ignore_ascii_case(tool)) {
+                allowed_tools.push(tool.to_string());
+            }
+        }
+
+        let name_key = normalize_label_key(&name);
+        let mut alias_keys = HashSet::new();
+        let mut aliases = Vec::new();
+        for alias in self.aliases.iter().map(|a| a.trim()).filter(|a| !a.is_empty()) {
+            if alias.chars().count() > MAX_LABEL_NAME_LENGTH {
+                return Err(format!("Alias '{}' is longer than {} characters", alias, MAX_LABEL_NAME_LENGTH));
+            }
+            let key = normalize_label_key(alias);
+            if key.is_empty() {
+                return Err(format!("Alias '{}' must contain a letter or digit", alias));
+            }
+            if key != name_key && alias_keys.insert(key) {
+                aliases.push(alias.to_string());
+            }
+        }
+
+        Ok(Self {
+            name,
+            display_name: non_empty(self.display_name),
+            color,
+            code_scheme,
+            code_value,
+            code_meaning,
+            allowed_tools,
+            aliases,
+        })
+    }
+}
+
+/// 새 라벨
+#[derive(Debug, Clone)]
+pub struct NewLabel {
+    pub project_id: i32,
+    pub parent_id: Option<i32>,
+    pub definition: LabelDefinition,
+}
+
+/// CSV 가져오기의 한 행 (상위 라벨은 이름으로 지정)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelImportRow {
+    pub definition: LabelDefinition,
+    pub parent: Option<String>,
+}
+
+/// CSV 가져오기 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LabelImportSummary {
+    pub created: usize,
+    pub updated: usize,
+}
+
+/// 검증을 마친 가져오기 항목
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelImportItem {
+    /// 덮어쓸 기존 라벨 ID (새 라벨이면 None)
+    pub label_id: Option<i32>,
+    pub definition: LabelDefinition,
+    /// 상위 라벨의 (가져오기 후) 정식 이름
+    pub parent: Option<String>,
+}
+
+/// 라벨 가져오기 계획
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabelImportPlan {
+    pub items: Vec<LabelImportItem>,
+    pub summary: LabelImportSummary,
+}
+
+/// 프로젝트에서 사용 중인 자유 입력 라벨과 사용 횟수
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct LabelUsage {
+    pub label: String,
+    /// 이 라벨을 가진 마스크 수
+    pub mask_count: i64,
+    /// 이 라벨을 가진 어노테이션 수
+    pub annotation_count: i64,
+}
+
+/// 자유 입력 라벨 → 정식 라벨 이름 매핑 적용 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LabelMigrationResult {
+    pub masks_updated: u64,
+    pub annotations_updated: u64,
+}
+
+/// 라벨 비교용 키: 소문자로 바꾸고 영숫자가 아닌 문자열은 `_` 하나로 합칩니다.
+///
+/// `"Left Kidney"`, `"left-kidney"`, `"LEFT_KIDNEY"`는 모두 `left_kidney`가 됩니다.
+pub fn normalize_label_key(label: &str) -> String {
+    let mut key = String::with_capacity(label.len());
+    for c in label.trim().chars() {
+        if c.is_alphanumeric() {
+            key.extend(c.to_lowercase());
+        } else if !key.is_empty() && !key.ends_with('_') {
+            key.push('_');
+        }
+    }
+    while key.ends_with('_') {
+        key.pop();
+    }
+    key
+}
+
+/// 어노테이션 데이터의 라벨 (`data.label`, 비어 있으면 None)
+pub fn annotation_label(data: &serde_json::Value) -> Option<&str> {
+    data.get("label")
+        .and_then(|label| label.as_str())
+        .filter(|label| !label.trim().is_empty())
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+}
+
+fn normalize_code_scheme(scheme: &str) -> Result<String, String> {
+    let upper = scheme.trim().to_ascii_uppercase();
+    let normalized = match upper.as_str() {
+        "SNOMED" | "SNOMED-CT" | "SNOMEDCT" | "SNOMED CT" => "SCT".to_string(),
+        _ => upper,
+    };
+    if LABEL_CODE_SCHEMES.contains(&normalized.as_str()) {
+        Ok(normalized)
+    } else {
+        Err(format!(
+            "Unsupported code scheme '{}': expected one of {}",
+            scheme,
+            LABEL_CODE_SCHEMES.join(", ")
+        ))
+    }
+}
+
+/// 계층/이름 검증에 쓰이는 분류 체계 항목 (상위 라벨은 이름으로 참조)
+struct TaxonomyEntry {
+    name: String,
+    aliases: Vec<String>,
+    parent: Option<String>,
+}
+
+/// 이름/별칭 중복, 상위 라벨 존재 여부, 순환 참조를 검사하고 항목별 상위 라벨 인덱스를 반환합니다.
+fn validate_entries(entries: &[TaxonomyEntry]) -> Result<Vec<Option<usize>>, String> {
+    let mut owners: HashMap<String, usize> = HashMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        for label in std::iter::once(&entry.name).chain(entry.aliases.iter()) {
+            let owner = *owners.entry(normalize_label_key(label)).or_insert(index);
+            if owner != index {
+                return Err(format!(
+                    "'{}' of label '{}' conflicts with label '{}'",
+                    label, entry.name, entries[owner].name
+                ));
+            }
+        }
+    }
+
+    let by_name: HashMap<String, usize> = entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| (normalize_label_key(&entry.name), index))
+        .collect();
+    let mut parents = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let parent = match &entry.parent {
+            Some(parent) => Some(*by_name.get(&normalize_label_key(parent)).ok_or_else(|| {
+                format!("Parent label '{}' of '{}' does not exist", parent, entry.name)
+            })?),
+            None => None,
+        };
+        parents.push(parent);
+    }
+
+    for (index, entry) in entries.iter().enumerate() {
+        let mut current = parents[index];
+        for _ in 0..entries.len() {
+            match current {
+                Some(parent) if parent == index => {
+                    return Err(format!("Label hierarchy of '{}' contains a cycle", entry.name));
+                }
+                Some(parent) => current = parents[parent],
+                None => break,
+            }
+        }
+    }
+
+    Ok(parents)
+}
+
+/// 프로젝트 라벨 분류 체계
+#[derive(Debug, Clone, Default)]
+pub struct LabelTaxonomy {
+    labels: Vec<Label>,
+}
+
+impl LabelTaxonomy {
+    pub fn new(labels: Vec<Label>) -> Self {
+        Self { labels }
+    }
+
+    /// 분류 체계가 비어 있으면 라벨을 검증하지 않음
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn get(&self, id: i32) -> Option<&Label> {
+        self.labels.iter().find(|label| label.id == id)
+    }
+
+    /// 정식 이름 또는 별칭으로 라벨 조회 (대소문자/구분자 무시)
+    pub fn find(&self, label: &str) -> Option<&Label> {
+        let key = normalize_label_key(label);
+        if key.is_empty() {
+            return None;
+        }
+        self.labels.iter().find(|l| {
+            normalize_label_key(&l.name) == key || l.aliases.iter().any(|a| normalize_label_key(a) == key)
+        })
+    }
+
+    /// 자유 입력 라벨에 대응하는 정식 라벨 제안 (`_seg`, `_mask` 같은 접미사 무시)
+    pub fn suggest(&self, label: &str) -> Option<&Label> {
+        self.find(label).or_else(|| {
+            let key = normalize_label_key(label);
+            IGNORED_LABEL_SUFFIXES
+                .iter()
+                .filter_map(|suffix| key.strip_suffix(suffix).and_then(|k| k.strip_suffix('_')))
+                .find_map(|stripped| self.find(stripped))
+        })
+    }
+
+    /// 라벨이 분류 체계에 있고 (도구가 주어지면) 해당 도구에 허용되는지 확인합니다.
+    pub fn validate_usage(&self, label: &str, tool_name: Option<&str>) -> Result<&Label, String> {
+        let found = self.find(label).ok_or_else(|| {
+            format!("Label '{}' is not defined in the project label taxonomy", label)
+        })?;
+
+        if let Some(tool_name) = tool_name {
+            if !found.allowed_tools.is_empty()
+                && !found.allowed_tools.iter().any(|t| t.eq_ignore_ascii_case(tool_name))
+            {
+                return Err(format!(
+                    "Label '{}' cannot be used with tool '{}' (allowed: {})",
+                    found.name,
+                    tool_name,
+                    found.allowed_tools.join(", ")
+                ));
+            }
+        }
+
+        Ok(found)
+    }
+
+    /// 라벨 ID로 상위 라벨 이름 조회 (없는 ID면 오류)
+    fn parent_name(&self, parent_id: Option<i32>, names: &HashMap<i32, String>) -> Result<Option<String>, String> {
+        parent_id
+            .map(|id| {
+                names
+                    .get(&id)
+                    .cloned()
+                    .ok_or_else(|| format!("Parent label {} does not exist in this project", id))
+            })
+            .transpose()
+    }
+
+    fn entries_with(&self, names: &HashMap<i32, String>) -> Result<Vec<TaxonomyEntry>, String> {
+        self.labels
+            .iter()
+            .map(|label| {
+                Ok(TaxonomyEntry {
+                    name: names[&label.id].clone(),
+                    aliases: label.aliases.clone(),
+                    parent: self.parent_name(label.parent_id, names)?,
+                })
+            })
+            .collect()
+    }
+
+    fn names(&self) -> HashMap<i32, String> {
+        self.labels.iter().map(|label| (label.id, label.name.clone())).collect()
+    }
+
+    /// 새 라벨을 추가해도 분류 체계가 유효한지 확인
+    pub fn check_create(&self, definition: &LabelDefinition, parent_id: Option<i32>) -> Result<(), String> {
+        let names = self.names();
+        let mut entries = self.entries_with(&names)?;
+        entries.push(TaxonomyEntry {
+            name: definition.name.clone(),
+            aliases: definition.aliases.clone(),
+            parent: self.parent_name(parent_id, &names)?,
+        });
+        validate_entries(&entries).map(|_| ())
+    }
+
+    /// 라벨을 수정해도 분류 체계가 유효한지 확인 (이름 변경, 상위 라벨 순환 포함)
+    pub fn check_update(&self, label_id: i32, definition: &LabelDefinition, parent_id: Option<i32>) -> Result<(), String> {
+        let mut names = self.names();
+        names.insert(label_id, definition.name.clone());
+        let mut entries = Vec::with_capacity(self.labels.len());
+        for label in &self.labels {
+            let (aliases, parent_id) = if label.id == label_id {
+                (definition.aliases.clone(), parent_id)
+            } else {
+                (label.aliases.clone(), label.parent_id)
+            };
+            entries.push(TaxonomyEntry {
+                name: names[&label.id].clone(),
+                aliases,
+                parent: self.parent_name(parent_id, &names)?,
+            });
+        }
+        validate_entries(&entries).map(|_| ())
+    }
+
+    /// CSV 행을 기존 라벨에 덮어쓰거나(이름이 같은 라벨) 새 라벨로 추가하는 계획을 세웁니다.
+    ///
+    /// 가져온 뒤의 분류 체계 전체가 유효해야 하며, 상위 라벨 이름은 가져오기 후의 정식 이름으로 바뀝니다.
+    pub fn plan_import(&self, rows: Vec<LabelImportRow>) -> Result<LabelImportPlan, String> {
+        let names = self.names();
+        let mut entries = self.entries_with(&names)?;
+        let mut index_by_key: HashMap<String, usize> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (normalize_label_key(&entry.name), index))
+            .collect();
+        let existing = entries.len();
+        let mut row_indexes = Vec::with_capacity(rows.len());
+        let mut summary = LabelImportSummary::default();
+
+        for row in &rows {
+            let key = normalize_label_key(&row.definition.name);
+            let entry = TaxonomyEntry {
+                name: row.definition.name.clone(),
+                aliases: row.definition.aliases.clone(),
+                parent: row.parent.clone(),
+            };
+            let index = match index_by_key.get(&key) {
+                Some(&index) if row_indexes.contains(&index) => {
+                    return Err(format!("Label '{}' appears more than once in the import", row.definition.name));
+                }
+                Some(&index) => {
+                    entries[index] = entry;
+                    summary.updated += 1;
+                    index
+                }
+                None => {
+                    index_by_key.insert(key, entries.len());
+                    entries.push(entry);
+                    summary.created += 1;
+                    entries.len() - 1
+                }
+            };
+            row_indexes.push(index);
+        }
+
+        let parents = validate_entries(&entries)?;
+        let items = rows
+            .into_iter()
+            .zip(row_indexes)
+            .map(|(row, index)| LabelImportItem {
+                label_id: (index < existing).then(|| self.labels[index].id),
+                definition: row.definition,
+                parent: parents[index].map(|parent| entries[parent].name.clone()),
+            })
+            .collect();
+
+        Ok(LabelImportPlan { items, summary })
+    }
+
+    /// 하위 라벨이 있는지 확인
+    pub fn has_children(&self, label_id: i32) -> bool {
+        self.labels.iter().any(|label| label.parent_id == Some(label_id))
+    }
+}
+
+/// CSV 텍스트를 레코드 단위로 나눕니다. (따옴표, `""` 이스케이프, 따옴표 안의 줄바꿈 지원)
+fn parse_csv_records(text: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.trim().is_empty()) {
+                    records.push((record_line, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(format!("line {}: unterminated quoted field", record_line));
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.trim().is_empty()) {
+        records.push((record_line, record));
+    }
+    Ok(records)
+}
+
+/// 라벨 분류 체계 CSV를 파싱합니다.
+///
+/// 첫 행은 헤더이며 `name` 컬럼은 필수입니다. 나머지 컬럼은 [`LABEL_CSV_COLUMNS`] 중에서 선택하고,
+/// `allowed_tools`와 `aliases`는 `;`로 구분합니다.
+pub fn parse_label_csv(text: &str) -> Result<Vec<LabelImportRow>, String> {
+    let mut records = parse_csv_records(text)?.into_iter();
+    let (_, header) = records.next().ok_or_else(|| "CSV is empty".to_string())?;
+
+    let mut columns: HashMap<String, usize> = HashMap::new();
+    for (index, column) in header.iter().enumerate() {
+        let column = column.trim().to_ascii_lowercase();
+        if !LABEL_CSV_COLUMNS.contains(&column.as_str()) {
+            return Err(format!(
+                "Unknown CSV column '{}': expected {}",
+                column,
+                LABEL_CSV_COLUMNS.join(", ")
+            ));
+        }
+        if columns.insert(column.clone(), index).is_some() {
+            return Err(format!("Duplicate CSV column '{}'", column));
+        }
+    }
+    if !columns.contains_key("name") {
+        return Err("CSV header must contain a 'name' column".to_string());
+    }
+
+    records
+        .map(|(line, record)| {
+            if record.len() > header.len() {
+                return Err(format!("line {}: expected {} fields, found {}", line, header.len(), record.len()));
+            }
+            let value = |column: &str| {
+                columns
+                    .get(column)
+                    .and_then(|&index| record.get(index))
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty())
+            };
+            let list = |column: &str| {
+                value(column)
+                    .map(|v| v.split(CSV_LIST_SEPARATOR).map(|item| item.trim().to_string()).collect())
+                    .unwrap_or_default()
+            };
+
+            let definition = LabelDefinition {
+                name: value("name").unwrap_or_default(),
+                display_name: value("display_name"),
+                color: value("color"),
+                code_scheme: value("code_scheme"),
+                code_value: value("code_value"),
+                code_meaning: value("code_meaning"),
+                allowed_tools: list("allowed_tools"),
+                aliases: list("aliases"),
+            }
+            .normalize()
+            .map_err(|e| format!("line {}: {}", line, e))?;
+
+            Ok(LabelImportRow { definition, parent: value("parent") })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(id: i32, name: &str, parent_id: Option<i32>, aliases: &[&str], allowed_tools: &[&str]) -> Label {
+        Label {
+            id,
+            project_id: 1,
+            parent_id,
+            name: name.to_string(),
+            display_name: None,
+            color: None,
+            code_scheme: None,
+            code_value: None,
+            code_meaning: None,
+            allowed_tools: allowed_tools.iter().map(|t| t.to_string()).collect(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn definition(name: &str) -> LabelDefinition {
+        LabelDefinition { name: name.to_string(), ..Default::default() }
+    }
+
+    fn taxonomy() -> LabelTaxonomy {
+        LabelTaxonomy::new(vec![
+            label(1, "abdomen", None, &[], &[]),
+            label(2, "liver", Some(1), &["hepar"], &["Brush Tool", "Polygon Tool"]),
+            label(3, "left_kidney", Some(1), &[], &[]),
+        ])
+    }
+
+    #[test]
+    fn test_normalize_label_key() {
+        assert_eq!(normalize_label_key("  Left Kidney "), "left_kidney");
+        assert_eq!(normalize_label_key("LEFT--KIDNEY"), "left_kidney");
+        assert_eq!(normalize_label_key("__"), "");
+    }
+
+    #[test]
+    fn test_find_suggest_and_validate_usage() {
+        let taxonomy = taxonomy();
+        assert_eq!(taxonomy.find("Liver").map(|l| l.id), Some(2));
+        assert_eq!(taxonomy.find("HEPAR").map(|l| l.id), Some(2));
+        assert!(taxonomy.find("LIVER_SEG").is_none());
+        assert_eq!(taxonomy.suggest("LIVER_SEG").map(|l| l.id), Some(2));
+        assert_eq!(taxonomy.suggest("left kidney mask").map(|l| l.id), Some(3));
+        assert!(taxonomy.suggest("spleen").is_none());
+
+        assert_eq!(taxonomy.validate_usage("liver", Some("brush tool")).unwrap().name, "liver");
+        assert!(taxonomy.validate_usage("liver", Some("Length Tool")).is_err());
+        assert!(taxonomy.validate_usage("liver", None).is_ok());
+        assert!(taxonomy.validate_usage("spleen", None).is_err());
+    }
+
+    #[test]
+    fn test_definition_normalize() {
+        let normalized = LabelDefinition {
+            name: " Liver ".to_string(),
+            color: Some("#FF00aa".to_string()),
+            code_scheme: Some("snomed-ct".to_string()),
+            code_value: Some("10200004".to_string()),
+            aliases: vec!["liver".to_string(), "LIVER_SEG".to_string(), "liver seg".to_string()],
+            ..Default::default()
+        }
+        .normalize()
+        .unwrap();
+        assert_eq!(normalized.name, "Liver");
+        assert_eq!(normalized.color.as_deref(), Some("#ff00aa"));
+        assert_eq!(normalized.code_scheme.as_deref(), Some("SCT"));
+        assert_eq!(normalized.aliases, vec!["LIVER_SEG".to_string()]);
+
+        let bad_color = LabelDefinition { color: Some("red".to_string()), ..definition("liver") };
+        assert!(bad_color.normalize().is_err());
+        let missing_value = LabelDefinition { code_scheme: Some("RADLEX".to_string()), ..definition("liver") };
+        assert!(missing_value.normalize().is_err());
+        let bad_scheme = LabelDefinition {
+            code_scheme: Some("ICD10".to_string()),
+            code_value: Some("K76".to_string()),
+            ..definition("liver")
+        };
+        assert!(bad_scheme.normalize().is_err());
+    }
+
+    #[test]
+    fn test_check_create_and_update() {
+        let taxonomy = taxonomy();
+        assert!(taxonomy.check_create(&definition("spleen"), Some(1)).is_ok());
+        assert!(taxonomy.check_create(&definition("LIVER"), None).is_err());
+        assert!(taxonomy.check_create(&definition("Hepar"), None).is_err());
+        assert!(taxonomy.check_create(&definition("spleen"), Some(99)).is_err());
+
+        // 자기 자신의 하위 라벨을 상위로 지정하면 순환
+        assert!(taxonomy.check_update(1, &definition("abdomen"), Some(2)).is_err());
+        assert!(taxonomy.check_update(2, &definition("Liver"), Some(1)).is_ok());
+        assert!(taxonomy.check_update(2, &definition("left kidney"), Some(1)).is_err());
+    }
+
+    #[test]
+    fn test_plan_import() {
+        let taxonomy = taxonomy();
+        let row = |name: &str, parent: Option<&str>| LabelImportRow {
+            definition: definition(name),
+            parent: parent.map(|p| p.to_string()),
+        };
+
+        let plan = taxonomy
+            .plan_import(vec![row("Liver", Some("ABDOMEN")), row("spleen", Some("organ")), row("organ", None)])
+            .unwrap();
+        assert_eq!(plan.summary, LabelImportSummary { created: 2, updated: 1 });
+        assert_eq!(plan.items[0].label_id, Some(2));
+        assert_eq!(plan.items[0].parent.as_deref(), Some("abdomen"));
+        assert_eq!(plan.items[1].label_id, None);
+        assert_eq!(plan.items[1].parent.as_deref(), Some("organ"));
+
+        // 구분자만 다른 이름은 기존 라벨을 덮어씀
+        let plan = taxonomy.plan_import(vec![row("Left Kidney", None)]).unwrap();
+        assert_eq!(plan.items[0].label_id, Some(3));
+
+        assert!(taxonomy.plan_import(vec![row("spleen", Some("missing"))]).is_err());
+        assert!(taxonomy.plan_import(vec![row("spleen", None), row("SPLEEN", None)]).is_err());
+        assert!(taxonomy.plan_import(vec![row("abdomen", Some("liver"))]).is_err());
+    }
+
+    #[test]
+    fn test_parse_label_csv() {
+        let csv = "\u{feff}name,color,code_scheme,code_value,code_meaning,parent,allowed_tools,aliases\r\n\
+                   abdomen,,,,,,,\r\n\
+                   liver,#aa0000,SCT,10200004,\"Liver, structure\",abdomen,Brush Tool;Polygon Tool,LIVER_SEG; hepar\r\n\
+                   \r\n";
+        let rows = parse_label_csv(csv).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].definition.code_meaning.as_deref(), Some("Liver, structure"));
+        assert_eq!(rows[1].parent.as_deref(), Some("abdomen"));
+        assert_eq!(rows[1].definition.allowed_tools, vec!["Brush Tool".to_string(), "Polygon Tool".to_string()]);
+        assert_eq!(rows[1].definition.aliases, vec!["LIVER_SEG".to_string(), "hepar".to_string()]);
+
+        assert!(parse_label_csv("label,color\nliver,").is_err());
+        assert!(parse_label_csv("color\n#ffffff").is_err());
+        let err = parse_label_csv("name,color\nliver,#ff0000\nspleen,blue").unwrap_err();
+        assert!(err.starts_with("line 3:"), "{}", err);
+        assert!(parse_label_csv("name\n\"liver").is_err());
+    }
+
+    #[test]
+    fn test_annotation_label() {
+        assert_eq!(annotation_label(&serde_json::json!({"label": "liver"})), Some("liver"));
+        assert_eq!(annotation_label(&serde_json::json!({"label": " "})), None);
+        assert_eq!(annotation_label(&serde_json::json!({"points": []})), None);
+    }
+}
@@ -30,6 +30,7 @@ use sqlx::FromRow;
 /// - `mask_type`: 마스크 타입 (segmentation, bounding_box, manual 등)
 /// - `description`: 마스크 그룹에 대한 설명 (선택사항)
 /// - `created_by`: 마스크 그룹을 생성한 사용자의 ID (선택사항)
+/// - `derived_from_id`: 파생 원본 마스크 그룹의 ID (예: AI 결과를 사람이 수정한 그룹의 원본, 선택사항)
 /// - `created_at`: 마스크 그룹이 생성된 시각
 /// - `updated_at`: 마스크 그룹이 마지막으로 수정된 시각
 /// 
@@ -46,6 +47,7 @@ use sqlx::FromRow;
 ///     mask_type: Some("segmentation".to_string()),
 ///     description: Some("간 분할을 위한 AI 모델 결과".to_string()),
 ///     created_by: Some(1),
+///     derived_from_id: None,
 ///     created_at: Utc::now(),
 ///     updated_at: Utc::now(),
 /// };
@@ -72,6 +74,8 @@ pub struct MaskGroup {
     pub description: Option<String>,
     /// 마스크 그룹을 생성한 사용자의 ID (선택사항)
     pub created_by: Option<i32>,
+    /// 이 마스크 그룹이 파생된 원본 마스크 그룹의 ID (선택사항, 같은 어노테이션)
+    pub derived_from_id: Option<i32>,
     /// 마스크 그룹이 생성된 시각
     pub created_at: DateTime<Utc>,
     /// 마스크 그룹이 마지막으로 수정된 시각
@@ -93,6 +97,7 @@ pub struct MaskGroup {
 /// - `mask_type`: 마스크 타입 (선택사항)
 /// - `description`: 마스크 그룹에 대한 설명 (선택사항)
 /// - `created_by`: 마스크 그룹을 생성할 사용자의 ID (선택사항)
+/// - `derived_from_id`: 파생 원본 마스크 그룹의 ID (선택사항)
 /// 
 /// # 예시
 /// ```rust
@@ -106,6 +111,7 @@ pub struct MaskGroup {
 ///     mask_type: Some("segmentation".to_string()),
 ///     description: Some("간 분할을 위한 AI 모델 결과".to_string()),
 ///     created_by: Some(1),
+///     derived_from_id: None,
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -128,6 +134,8 @@ pub struct NewMaskGroup {
     pub description: Option<String>,
     /// 마스크 그룹을 생성할 사용자의 ID (선택사항)
     pub created_by: Option<i32>,
+    /// 파생 원본 마스크 그룹의 ID (선택사항, 같은 어노테이션)
+    pub derived_from_id: Option<i32>,
 }
 
 impl NewMaskGroup {
@@ -167,6 +175,7 @@ impl NewMaskGroup {
             mask_type: Some(mask_type),
             description,
             created_by,
+            derived_from_id: None,
         }
     }
 
@@ -194,6 +203,7 @@ impl NewMaskGroup {
             mask_type: Some("segmentation".to_string()),
             description: None,
             created_by,
+            derived_from_id: None,
         }
     }
 
@@ -231,6 +241,7 @@ impl NewMaskGroup {
             mask_type: Some("segmentation".to_string()),
             description: None,
             created_by,
+            derived_from_id: None,
         }
     }
 
@@ -266,8 +277,21 @@ impl NewMaskGroup {
             mask_type: Some("manual".to_string()),
             description,
             created_by,
+            derived_from_id: None,
         }
     }
+
+    /// 파생 원본 마스크 그룹을 설정합니다.
+    /// 
+    /// # 매개변수
+    /// - `derived_from_id`: 원본 마스크 그룹의 ID (같은 어노테이션에 속해야 함)
+    /// 
+    /// # 반환값
+    /// 파생 원본이 설정된 `NewMaskGroup` 인스턴스
+    pub fn with_derived_from(mut self, derived_from_id: i32) -> Self {
+        self.derived_from_id = Some(derived_from_id);
+        self
+    }
 }
 
 /// `MaskGroup`에서 `NewMaskGroup`으로의 변환을 위한 From 트레이트 구현
@@ -293,6 +317,7 @@ impl From<MaskGroup> for NewMaskGroup {
             mask_type: mask_group.mask_type,
             description: mask_group.description,
             created_by: mask_group.created_by,
+            derived_from_id: mask_group.derived_from_id,
         }
     }
 }
@@ -724,6 +749,7 @@ mod tests {
             mask_type: Some("segmentation".to_string()),
             description: Some("Test description".to_string()),
             created_by: Some(456),
+            derived_from_id: Some(7),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -739,5 +765,6 @@ mod tests {
         assert_eq!(new_mask_group.mask_type, Some("segmentation".to_string()));
         assert_eq!(new_mask_group.description, Some("Test description".to_string()));
         assert_eq!(new_mask_group.created_by, Some(456));
+        assert_eq!(new_mask_group.derived_from_id, Some(7));
     }
 }
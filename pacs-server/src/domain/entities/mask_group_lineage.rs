@@ -0,0 +1,132 @@
+//! 마스크 그룹 계보와 비교 엔티티
+//!
+//! AI가 만든 마스크 그룹과 그 결과를 사람이 수정한 그룹은 `derived_from_id`로 이어지며,
+//! 한 어노테이션 안의 그룹들은 이 관계로 숲(forest)을 이룹니다. 두 그룹을 비교할 때는
+//! 양쪽에 모두 있는 슬라이스만 대상으로 라벨별 복셀 수, 교집합, 최대 Hausdorff 거리를 누적합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use crate::domain::entities::mask::Mask;
+
+/// 계보 조회에 쓰이는 마스크 그룹 요약
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MaskGroupLineageNode {
+    pub id: i32,
+    pub annotation_id: i32,
+    pub group_name: Option<String>,
+    pub model_name: Option<String>,
+    pub version: Option<String>,
+    pub mask_type: Option<String>,
+    pub created_by: Option<i32>,
+    pub derived_from_id: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    /// 그룹에 속한 마스크 수
+    pub mask_count: i64,
+}
+
+/// 두 그룹에서 같은 슬라이스를 찾기 위한 키
+///
+/// `slice_index`가 있으면 그것을, 없으면 `sop_instance_uid`를 사용합니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MaskSliceKey {
+    Index(i32),
+    Instance(String),
+}
+
+impl MaskSliceKey {
+    /// 마스크의 슬라이스 키 (둘 다 없으면 None)
+    pub fn of(mask: &Mask) -> Option<Self> {
+        match (mask.slice_index, &mask.sop_instance_uid) {
+            (Some(index), _) => Some(Self::Index(index)),
+            (None, Some(uid)) => Some(Self::Instance(uid.clone())),
+            (None, None) => None,
+        }
+    }
+}
+
+/// 한 라벨에 대한 기준(reference) 그룹과 비교(candidate) 그룹의 누적 측정값
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabelOverlap {
+    pub reference_voxels: u64,
+    pub candidate_voxels: u64,
+    pub intersection_voxels: u64,
+    /// 이 라벨이 한쪽이라도 있는 비교 슬라이스 수
+    pub compared_slices: u32,
+    /// 이 라벨이 한쪽에만 있어 Hausdorff 거리를 계산할 수 없었던 슬라이스 수
+    pub unmatched_slices: u32,
+    /// 양쪽 모두 전경이 있는 슬라이스의 2D Hausdorff 거리 최댓값 (픽셀, 그런 슬라이스가 없으면 None)
+    pub hausdorff_distance: Option<f64>,
+}
+
+impl LabelOverlap {
+    /// 슬라이스 하나의 측정값을 누적
+    pub fn add_slice(&mut self, reference: u64, candidate: u64, intersection: u64, hausdorff: Option<f64>) {
+        self.reference_voxels += reference;
+        self.candidate_voxels += candidate;
+        self.intersection_voxels += intersection;
+        self.compared_slices += 1;
+        if (reference > 0) != (candidate > 0) {
+            self.unmatched_slices += 1;
+        }
+        if let Some(distance) = hausdorff {
+            self.hausdorff_distance = Some(self.hausdorff_distance.map_or(distance, |max| max.max(distance)));
+        }
+    }
+
+    /// Dice 계수 2|A∩B| / (|A| + |B|) (양쪽 모두 비어 있으면 None)
+    pub fn dice(&self) -> Option<f64> {
+        let total = self.reference_voxels + self.candidate_voxels;
+        (total > 0).then(|| 2.0 * self.intersection_voxels as f64 / total as f64)
+    }
+
+    /// Jaccard 지수 |A∩B| / |A∪B| (양쪽 모두 비어 있으면 None)
+    pub fn jaccard(&self) -> Option<f64> {
+        let union = self.reference_voxels + self.candidate_voxels - self.intersection_voxels;
+        (union > 0).then(|| self.intersection_voxels as f64 / union as f64)
+    }
+
+    /// 부피 차이 (candidate - reference, 복셀)
+    pub fn volume_difference(&self) -> i64 {
+        self.candidate_voxels as i64 - self.reference_voxels as i64
+    }
+
+    /// 기준 부피 대비 부피 차이 비율 (기준이 비어 있으면 None)
+    pub fn relative_volume_difference(&self) -> Option<f64> {
+        (self.reference_voxels > 0).then(|| self.volume_difference() as f64 / self.reference_voxels as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_overlap_metrics() {
+        let mut overlap = LabelOverlap::default();
+        assert_eq!(overlap.dice(), None);
+        assert_eq!(overlap.jaccard(), None);
+
+        overlap.add_slice(3, 2, 2, Some(1.0));
+        overlap.add_slice(1, 2, 1, None);
+        overlap.add_slice(0, 0, 0, Some(2.5));
+        assert_eq!(overlap.compared_slices, 3);
+        assert_eq!(overlap.unmatched_slices, 0);
+        assert_eq!(overlap.dice(), Some(0.75));
+        assert_eq!(overlap.jaccard(), Some(0.6));
+        assert_eq!(overlap.volume_difference(), 0);
+        assert_eq!(overlap.relative_volume_difference(), Some(0.0));
+        assert_eq!(overlap.hausdorff_distance, Some(2.5));
+
+        let missed = LabelOverlap { candidate_voxels: 4, ..Default::default() };
+        assert_eq!(missed.relative_volume_difference(), None);
+        assert_eq!(missed.volume_difference(), 4);
+
+        // 한쪽에만 있는 슬라이스는 Hausdorff 거리 대신 unmatched로 집계
+        let mut one_sided = LabelOverlap::default();
+        one_sided.add_slice(0, 2, 0, None);
+        one_sided.add_slice(3, 0, 0, None);
+        assert_eq!((one_sided.compared_slices, one_sided.unmatched_slices), (2, 2));
+        assert_eq!(one_sided.hausdorff_distance, None);
+    }
+}
@@ -0,0 +1,161 @@
+//! # 마스크 임포트 엔티티 모듈
+//!
+//! 이 모듈은 DICOM SEG / NIfTI 라벨 볼륨을 마스크 그룹으로 가져오는 임포트 작업을 나타내는 엔티티들을 정의합니다.
+//! 임포트 작업은 백그라운드에서 실행되며, 진행 상태와 매핑되지 않은 프레임 목록을 기록합니다.
+
+// UTC 시간대의 날짜/시간 처리를 위한 chrono 라이브러리
+use chrono::{DateTime, Utc};
+// JSON 직렬화/역직렬화를 위한 serde 라이브러리
+use serde::{Deserialize, Serialize};
+// SQLx를 통한 데이터베이스 행 매핑을 위한 트레이트
+use sqlx::FromRow;
+// OpenAPI 스키마 생성을 위한 utoipa 라이브러리
+use utoipa::ToSchema;
+
+/// 마스크 임포트 작업 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "mask_import_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MaskImportStatus {
+    /// 작업이 생성되었고 아직 시작되지 않음
+    Pending,
+    /// 볼륨을 읽고 마스크를 생성하는 중
+    Running,
+    /// 모든 프레임 처리가 끝남
+    Completed,
+    /// 처리 중 오류가 발생함
+    Failed,
+}
+
+impl std::fmt::Display for MaskImportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaskImportStatus::Pending => write!(f, "PENDING"),
+            MaskImportStatus::Running => write!(f, "RUNNING"),
+            MaskImportStatus::Completed => write!(f, "COMPLETED"),
+            MaskImportStatus::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// 마스크 임포트 작업을 나타내는 엔티티
+///
+/// 이 구조체는 데이터베이스의 `annotation_mask_import_job` 테이블과 매핑됩니다.
+///
+/// # 필드
+/// - `id`: 작업 고유 식별자
+/// - `annotation_id`: 대상 어노테이션 ID
+/// - `mask_group_id`: 임포트로 생성된 마스크 그룹 ID (생성 전에는 None)
+/// - `source_path`: 원본 볼륨 파일의 스토리지 경로
+/// - `source_format`: 원본 포맷 (`nifti`, `dicom_seg`)
+/// - `status`: 작업 상태
+/// - `total_frames`: 볼륨에서 추출된 프레임 수
+/// - `imported_masks`: 생성된 마스크 수
+/// - `unmapped_frames`: SOP Instance UID에 매핑되지 않은 프레임 목록 (JSON 배열)
+/// - `error_message`: 실패 시 에러 메시지
+/// - `requested_by`: 작업을 요청한 사용자 ID
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct MaskImportJob {
+    pub id: i32,
+    pub annotation_id: i32,
+    pub mask_group_id: Option<i32>,
+    pub source_path: String,
+    pub source_format: String,
+    pub status: MaskImportStatus,
+    pub total_frames: i32,
+    pub imported_masks: i32,
+    pub unmapped_frames: serde_json::Value,
+    pub error_message: Option<String>,
+    pub requested_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl MaskImportJob {
+    /// 저장된 JSON에서 매핑되지 않은 프레임 목록을 읽습니다.
+    pub fn unmapped_frame_list(&self) -> Vec<UnmappedFrame> {
+        serde_json::from_value(self.unmapped_frames.clone()).unwrap_or_default()
+    }
+}
+
+/// 새로운 마스크 임포트 작업 생성을 위한 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NewMaskImportJob {
+    pub annotation_id: i32,
+    pub source_path: String,
+    pub source_format: String,
+    pub requested_by: Option<i32>,
+}
+
+impl NewMaskImportJob {
+    /// 새로운 임포트 작업을 생성합니다.
+    pub fn new(annotation_id: i32, source_path: String, source_format: String, requested_by: Option<i32>) -> Self {
+        Self {
+            annotation_id,
+            source_path,
+            source_format,
+            requested_by,
+        }
+    }
+}
+
+/// SOP Instance UID에 매핑되지 않은 프레임
+///
+/// 마스크는 `slice_index`만으로 생성되며, 사용자가 나중에 확인할 수 있도록 사유를 기록합니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct UnmappedFrame {
+    /// 원본 파일 내 프레임 순번
+    pub frame_index: i32,
+    /// 볼륨 내 슬라이스 인덱스
+    pub slice_index: i32,
+    /// 라벨 이름
+    pub label_name: Option<String>,
+    /// 원본이 참조한 SOP Instance UID (있는 경우)
+    pub sop_instance_uid: Option<String>,
+    /// 매핑 실패 사유
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_mask_import_job() {
+        let job = NewMaskImportJob::new(1, "imports/seg.nii.gz".to_string(), "nifti".to_string(), Some(7));
+        assert_eq!(job.annotation_id, 1);
+        assert_eq!(job.source_format, "nifti");
+        assert_eq!(job.requested_by, Some(7));
+    }
+
+    #[test]
+    fn test_unmapped_frame_list_roundtrip() {
+        let frames = vec![UnmappedFrame {
+            frame_index: 3,
+            slice_index: 2,
+            label_name: Some("liver".to_string()),
+            sop_instance_uid: None,
+            reason: "no instance UID for slice".to_string(),
+        }];
+        let job = MaskImportJob {
+            id: 1,
+            annotation_id: 1,
+            mask_group_id: None,
+            source_path: "imports/seg.dcm".to_string(),
+            source_format: "dicom_seg".to_string(),
+            status: MaskImportStatus::Completed,
+            total_frames: 4,
+            imported_masks: 4,
+            unmapped_frames: serde_json::to_value(&frames).unwrap(),
+            error_message: None,
+            requested_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            completed_at: Some(Utc::now()),
+        };
+
+        assert_eq!(job.unmapped_frame_list(), frames);
+        assert_eq!(MaskImportStatus::Completed.to_string(), "COMPLETED");
+    }
+}
@@ -0,0 +1,140 @@
+//! 마스크 미리보기 엔티티
+//!
+//! 검증을 통과한 마스크마다 백그라운드 작업이 축소 썸네일과 영상 위에 겹쳐 그리는 컬러 미리보기를 만들고,
+//! 마스크 그룹마다 썸네일을 격자로 모은 contact sheet를 만듭니다. 파생 객체는 원본 경로에서 정해지는
+//! `derived/` 아래 경로에 저장되므로 정합성 점검 대상이 아니며 저장 용량 사용량에도 포함되지 않습니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use crate::domain::entities::mask_validation::MASK_VALIDATION_RETRY_POLICY;
+use crate::domain::entities::retry_policy::RetryPolicy;
+use crate::domain::entities::storage_deletion::{annotation_mask_prefix, mask_group_mask_prefix};
+
+/// 파생 객체 경로 prefix
+pub const DERIVED_PREFIX: &str = "derived/";
+
+/// 미리보기 생성 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "mask_preview_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MaskPreviewStatus {
+    /// 생성 대기 또는 재시도 대기
+    Pending,
+    Ready,
+    /// 재시도 횟수를 모두 소진함
+    Failed,
+}
+
+/// 미리보기 생성 재시도 정책 기본값 (검증과 같은 지수 백오프)
+pub const MASK_PREVIEW_RETRY_POLICY: RetryPolicy = MASK_VALIDATION_RETRY_POLICY;
+
+/// 마스크의 미리보기 객체
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MaskPreview {
+    pub mask_id: i32,
+    pub preview_status: MaskPreviewStatus,
+    pub thumbnail_key: Option<String>,
+    pub preview_key: Option<String>,
+}
+
+/// 미리보기 작업이 가져간 마스크
+#[derive(Debug, Clone, FromRow)]
+pub struct MaskPreviewTask {
+    pub mask_id: i32,
+    pub mask_group_id: i32,
+    pub file_path: String,
+    pub preview_attempts: i32,
+    /// 가져갈 때 설정한 임대 만료 시간 (그 사이 마스크가 바뀌면 결과를 기록하지 않음)
+    pub lease_until: DateTime<Utc>,
+}
+
+/// contact sheet 작업이 가져간 마스크 그룹
+#[derive(Debug, Clone, FromRow)]
+pub struct ContactSheetTask {
+    pub mask_group_id: i32,
+    pub annotation_id: i32,
+    pub contact_sheet_attempts: i32,
+    pub lease_until: DateTime<Utc>,
+}
+
+/// 마스크 그룹의 contact sheet
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MaskGroupContactSheet {
+    pub mask_group_id: i32,
+    pub contact_sheet_status: MaskPreviewStatus,
+    /// 썸네일이 하나도 없으면 None
+    pub contact_sheet_key: Option<String>,
+    pub contact_sheet_mask_count: i32,
+    pub contact_sheet_error: Option<String>,
+    pub contact_sheet_generated_at: Option<DateTime<Utc>>,
+}
+
+/// 미리보기 작업 한 번의 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct MaskPreviewRunResult {
+    /// 썸네일/미리보기를 만든 마스크 수
+    pub generated: usize,
+    /// 만든 contact sheet 수
+    pub contact_sheets: usize,
+    /// 실패하여 재시도 예정인 마스크/그룹 수
+    pub retrying: usize,
+    /// 재시도 횟수를 모두 소진한 마스크/그룹 수
+    pub failed: usize,
+}
+
+/// 원본 경로에서 확장자를 뗀 파생 객체 경로
+fn derived_key(file_path: &str, suffix: &str) -> String {
+    let stem = match file_path.rsplit_once('.') {
+        Some((stem, extension)) if !extension.contains('/') => stem,
+        _ => file_path,
+    };
+    format!("{}{}.{}.png", DERIVED_PREFIX, stem, suffix)
+}
+
+/// 마스크 썸네일 경로
+pub fn mask_thumbnail_key(file_path: &str) -> String {
+    derived_key(file_path, "thumb")
+}
+
+/// 마스크 컬러 미리보기 경로
+pub fn mask_preview_key(file_path: &str) -> String {
+    derived_key(file_path, "preview")
+}
+
+/// 마스크 그룹 contact sheet 경로
+pub fn contact_sheet_key(annotation_id: i32, mask_group_id: i32) -> String {
+    format!("{}{}contact_sheet.png", DERIVED_PREFIX, mask_group_mask_prefix(annotation_id, mask_group_id))
+}
+
+/// 어노테이션의 파생 객체 경로 prefix
+pub fn annotation_derived_prefix(annotation_id: i32) -> String {
+    format!("{}{}", DERIVED_PREFIX, annotation_mask_prefix(annotation_id))
+}
+
+/// 마스크 그룹의 파생 객체 경로 prefix
+pub fn mask_group_derived_prefix(annotation_id: i32, mask_group_id: i32) -> String {
+    format!("{}{}", DERIVED_PREFIX, mask_group_mask_prefix(annotation_id, mask_group_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::storage_reconciliation::RECONCILED_PREFIXES;
+
+    #[test]
+    fn test_derived_keys() {
+        let file_path = "masks/annotation_3/group_12/liver_001.png";
+        assert_eq!(mask_thumbnail_key(file_path), "derived/masks/annotation_3/group_12/liver_001.thumb.png");
+        assert_eq!(mask_preview_key(file_path), "derived/masks/annotation_3/group_12/liver_001.preview.png");
+        // 확장자가 없거나 디렉터리 이름에 점이 있어도 파일명 뒤에 붙임
+        assert_eq!(mask_thumbnail_key("masks/a.b/mask"), "derived/masks/a.b/mask.thumb.png");
+
+        let sheet = contact_sheet_key(3, 12);
+        assert!(sheet.starts_with(&mask_group_derived_prefix(3, 12)));
+        assert!(sheet.starts_with(&annotation_derived_prefix(3)));
+        // 파생 객체는 정합성 점검에서 고아 객체로 보고되지 않음
+        assert!(RECONCILED_PREFIXES.iter().all(|prefix| !sheet.starts_with(prefix)));
+    }
+}
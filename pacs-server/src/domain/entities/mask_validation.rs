@@ -0,0 +1,148 @@
+//! 마스크 서버 검증 엔티티
+//!
+//! 마스크의 `width`, `height`, `file_size`, `checksum`은 클라이언트가 보고한 값이므로, 업로드 후
+//! 백그라운드 작업이 객체를 내려받아 체크섬과 크기를 확인하고 PNG를 디코딩해 참조 인스턴스와 크기를 비교합니다.
+//! 통과한 마스크에는 라벨 값별 복셀 수와 경계 상자를 저장하고, 실패한 마스크는 `INVALID`로 표시합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use crate::domain::entities::retry_policy::RetryPolicy;
+
+/// 검증 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "mask_validation_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MaskValidationStatus {
+    /// 검증 대기 또는 재시도 대기
+    Pending,
+    Valid,
+    /// 객체가 없거나 체크섬/크기/PNG가 올바르지 않음
+    Invalid,
+    /// 객체를 읽지 못해 재시도 횟수를 모두 소진함
+    Error,
+}
+
+/// 마스크 안의 라벨 값 하나에 대한 통계
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MaskLabelStats {
+    /// PNG 픽셀 값 (이진 마스크는 255)
+    pub label_value: i32,
+    /// 라벨 이름 (마스크에 라벨 값이 하나뿐이면 마스크의 `label_name`)
+    pub label_name: Option<String>,
+    pub voxel_count: i64,
+    /// `[x, y, width, height]` (픽셀)
+    pub bounding_box: [i32; 4],
+}
+
+/// 마스크의 검증 결과 (`annotation_mask`의 검증 컬럼)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MaskValidation {
+    pub mask_id: i32,
+    pub mask_group_id: i32,
+    pub slice_index: Option<i32>,
+    pub label_name: Option<String>,
+    pub file_path: String,
+    pub validation_status: MaskValidationStatus,
+    /// 검증 실패 사유 목록 (JSON 문자열 배열)
+    pub validation_errors: serde_json::Value,
+    /// [`MaskLabelStats`] 배열 (검증 전이면 null)
+    pub label_stats: Option<serde_json::Value>,
+    pub content_sha256: Option<String>,
+    pub validation_attempts: i32,
+    pub validated_at: Option<DateTime<Utc>>,
+}
+
+/// 검증 작업이 가져간 마스크
+#[derive(Debug, Clone, FromRow)]
+pub struct MaskValidationTask {
+    pub mask_id: i32,
+    pub mask_group_id: i32,
+    /// 참조 인스턴스 조회에 쓰는 어노테이션의 프로젝트
+    pub project_id: i32,
+    pub sop_instance_uid: Option<String>,
+    pub label_name: Option<String>,
+    pub file_path: String,
+    pub checksum: Option<String>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub validation_attempts: i32,
+    /// 가져갈 때 설정한 임대 만료 시간 (그 사이 마스크가 바뀌면 결과를 기록하지 않음)
+    pub lease_until: DateTime<Utc>,
+}
+
+/// 객체를 읽어 검사한 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaskValidationOutcome {
+    /// `Valid` 또는 `Invalid`
+    pub status: MaskValidationStatus,
+    pub errors: Vec<String>,
+    pub label_stats: Vec<MaskLabelStats>,
+    /// 객체를 읽었으면 계산한 SHA-256
+    pub content_sha256: Option<String>,
+    /// 객체의 실제 크기 (바이트)
+    pub file_size: Option<i64>,
+    /// 디코딩한 PNG 크기 (픽셀)
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+impl MaskValidationOutcome {
+    /// 객체를 읽기 전에 실패한 결과
+    pub fn invalid(error: impl Into<String>) -> Self {
+        Self {
+            status: MaskValidationStatus::Invalid,
+            errors: vec![error.into()],
+            label_stats: Vec::new(),
+            content_sha256: None,
+            file_size: None,
+            width: None,
+            height: None,
+        }
+    }
+}
+
+/// 스토리지 오류로 객체를 읽지 못했을 때의 재시도 정책 기본값 (최대 5회, 1분부터 두 배씩 최대 1시간)
+pub const MASK_VALIDATION_RETRY_POLICY: RetryPolicy = RetryPolicy::new(5, 60, 3600);
+
+/// 마스크 그룹의 라벨별 합계 (검증을 통과한 마스크만)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct MaskGroupLabelVolume {
+    pub label_value: i32,
+    pub label_name: Option<String>,
+    pub voxel_count: i64,
+    /// 이 라벨이 있는 마스크 수
+    pub mask_count: i64,
+    pub slice_start: Option<i32>,
+    pub slice_end: Option<i32>,
+}
+
+/// 검증 작업 한 번의 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct MaskValidationRunResult {
+    pub valid: usize,
+    pub invalid: usize,
+    /// 객체를 읽지 못해 재시도 예정인 마스크 수
+    pub retrying: usize,
+    /// 재시도 횟수를 모두 소진한 마스크 수
+    pub errored: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_stats_json_shape() {
+        let stats = MaskLabelStats {
+            label_value: 255,
+            label_name: Some("liver".to_string()),
+            voxel_count: 12,
+            bounding_box: [1, 2, 3, 4],
+        };
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["bounding_box"], serde_json::json!([1, 2, 3, 4]));
+        assert_eq!(serde_json::from_value::<MaskLabelStats>(json).unwrap(), stats);
+    }
+}
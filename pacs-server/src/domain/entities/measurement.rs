@@ -0,0 +1,651 @@
+//! 서버 측 측정값 계산 엔티티
+//!
+//! 뷰어마다 계산한 `measurement_values`가 달라, 서버가 어노테이션 `data`의 도형에서 길이, 면적, 둘레,
+//! 타원 축 길이와 RECIST 양방향(bidimensional) 측정값을 직접 계산합니다.
+//! 픽셀 간격은 프로젝트에 등록된 인스턴스 메타데이터(없으면 같은 Series의 메타데이터)에서 가져오며,
+//! 메타데이터가 없으면 픽셀 단위로 계산합니다. 계산 결과는 클라이언트 측정값과 비교해 허용 오차를 넘는 항목을 표시합니다.
+//! 밝기 통계(평균/표준편차 등)는 픽셀 데이터가 필요하므로 계산하지 않습니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// 클라이언트 측정값과의 상대 오차 허용 범위
+pub const MEASUREMENT_DISCREPANCY_TOLERANCE: f64 = 0.05;
+
+/// 측정값 소수점 자리수
+const MEASUREMENT_PRECISION: f64 = 10_000.0;
+
+/// DICOM Pixel Spacing (0028,0030) (mm)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PixelSpacing {
+    /// 행 간격 (세로, y 방향)
+    pub row: f64,
+    /// 열 간격 (가로, x 방향)
+    pub column: f64,
+}
+
+impl PixelSpacing {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.row.is_finite() && self.row > 0.0 && self.column.is_finite() && self.column > 0.0) {
+            return Err(format!(
+                "Pixel spacing must be positive (row {}, column {})",
+                self.row, self.column
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 픽셀 간격 출처
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SpacingSource {
+    /// 어노테이션 인스턴스의 메타데이터
+    Instance,
+    /// 같은 Series의 다른 인스턴스 메타데이터
+    Series,
+    /// 메타데이터 없음 (픽셀 단위)
+    None,
+}
+
+impl SpacingSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpacingSource::Instance => "INSTANCE",
+            SpacingSource::Series => "SERIES",
+            SpacingSource::None => "NONE",
+        }
+    }
+}
+
+/// 인식한 도형 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GeometryType {
+    Length,
+    Polyline,
+    Polygon,
+    Rectangle,
+    Ellipse,
+    /// RECIST 장축/단축
+    Bidirectional,
+}
+
+impl GeometryType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GeometryType::Length => "LENGTH",
+            GeometryType::Polyline => "POLYLINE",
+            GeometryType::Polygon => "POLYGON",
+            GeometryType::Rectangle => "RECTANGLE",
+            GeometryType::Ellipse => "ELLIPSE",
+            GeometryType::Bidirectional => "BIDIRECTIONAL",
+        }
+    }
+
+    /// 도형 이름 (`data.type` 또는 도구 이름)에서 종류 추론
+    fn from_name(name: &str) -> Option<Self> {
+        let mut key: String = name.to_lowercase().chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+        for suffix in ["tool", "roi"] {
+            if key.len() > suffix.len() && key.ends_with(suffix) {
+                key.truncate(key.len() - suffix.len());
+            }
+        }
+        match key.as_str() {
+            "line" | "length" | "ruler" | "distance" => Some(GeometryType::Length),
+            "polyline" | "openpolygon" => Some(GeometryType::Polyline),
+            "polygon" | "closedpolygon" | "freehand" | "planarfreehand" => Some(GeometryType::Polygon),
+            "rectangle" | "rectangular" | "rect" => Some(GeometryType::Rectangle),
+            "ellipse" | "elliptical" | "circle" | "circular" | "oval" => Some(GeometryType::Ellipse),
+            "bidirectional" | "recist" => Some(GeometryType::Bidirectional),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+/// 어노테이션 도형 (이미지 픽셀 좌표)
+#[derive(Debug, Clone, PartialEq)]
+enum AnnotationGeometry {
+    Length(Point, Point),
+    Polyline(Vec<Point>),
+    Polygon(Vec<Point>),
+    Rectangle { width: f64, height: f64 },
+    Ellipse { radius_x: f64, radius_y: f64 },
+    Bidirectional { long_axis: (Point, Point), short_axis: (Point, Point) },
+}
+
+fn number(value: &Value, field: &str) -> Result<f64, String> {
+    value
+        .get(field)
+        .and_then(Value::as_f64)
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| format!("Geometry field '{}' must be a number", field))
+}
+
+/// `[x, y]` 또는 `{"x": .., "y": ..}`
+fn point(value: &Value) -> Result<Point, String> {
+    let (x, y) = match value {
+        Value::Array(coords) if coords.len() >= 2 => (coords[0].as_f64(), coords[1].as_f64()),
+        Value::Object(_) => (value.get("x").and_then(Value::as_f64), value.get("y").and_then(Value::as_f64)),
+        _ => (None, None),
+    };
+    match (x, y) {
+        (Some(x), Some(y)) if x.is_finite() && y.is_finite() => Ok(Point { x, y }),
+        _ => Err(format!("Invalid point: {}", value)),
+    }
+}
+
+fn points(value: Option<&Value>, field: &str) -> Result<Vec<Point>, String> {
+    value
+        .and_then(Value::as_array)
+        .ok_or_else(|| format!("Geometry field '{}' must be an array of points", field))?
+        .iter()
+        .map(point)
+        .collect()
+}
+
+fn segment(value: Option<&Value>, field: &str) -> Result<(Point, Point), String> {
+    match points(value, field)?.as_slice() {
+        [a, b] => Ok((*a, *b)),
+        _ => Err(format!("Geometry field '{}' must have exactly 2 points", field)),
+    }
+}
+
+/// `{x, y, width, height}` 또는 대각선 꼭짓점 두 개의 `points`에서 가로/세로 크기
+fn bounding_size(data: &Value) -> Result<(f64, f64), String> {
+    if data.get("width").is_some() || data.get("height").is_some() {
+        return Ok((number(data, "width")?.abs(), number(data, "height")?.abs()));
+    }
+    let (a, b) = segment(data.get("points"), "points")?;
+    Ok(((b.x - a.x).abs(), (b.y - a.y).abs()))
+}
+
+impl AnnotationGeometry {
+    /// 어노테이션 데이터에서 도형 해석 (`data.type`이 없으면 도구 이름으로 종류 추론)
+    fn parse(tool_name: &str, data: &Value) -> Result<Self, String> {
+        let geometry_type = match data.get("type").and_then(Value::as_str) {
+            Some(name) => GeometryType::from_name(name).ok_or_else(|| format!("Unsupported geometry type '{}'", name))?,
+            None => GeometryType::from_name(tool_name)
+                .ok_or_else(|| format!("Cannot infer geometry from tool '{}' without data.type", tool_name))?,
+        };
+
+        match geometry_type {
+            GeometryType::Length => {
+                if let (Some(start), Some(end)) = (data.get("start"), data.get("end")) {
+                    return Ok(AnnotationGeometry::Length(point(start)?, point(end)?));
+                }
+                let (a, b) = segment(data.get("points"), "points")?;
+                Ok(AnnotationGeometry::Length(a, b))
+            }
+            GeometryType::Polyline => {
+                let points = points(data.get("points"), "points")?;
+                if points.len() < 2 {
+                    return Err("Polyline needs at least 2 points".to_string());
+                }
+                Ok(AnnotationGeometry::Polyline(points))
+            }
+            GeometryType::Polygon => {
+                let mut points = points(data.get("points"), "points")?;
+                if points.len() > 1 && points.first() == points.last() {
+                    points.pop();
+                }
+                if points.len() < 3 {
+                    return Err("Polygon needs at least 3 distinct points".to_string());
+                }
+                Ok(AnnotationGeometry::Polygon(points))
+            }
+            GeometryType::Rectangle => {
+                let (width, height) = bounding_size(data)?;
+                Ok(AnnotationGeometry::Rectangle { width, height })
+            }
+            GeometryType::Ellipse => {
+                let (radius_x, radius_y) = if data.get("center").is_some() {
+                    point(&data["center"])?;
+                    match data.get("radius") {
+                        Some(_) => {
+                            let radius = number(data, "radius")?.abs();
+                            (radius, radius)
+                        }
+                        None => (number(data, "radius_x")?.abs(), number(data, "radius_y")?.abs()),
+                    }
+                } else {
+                    let (width, height) = bounding_size(data)?;
+                    (width / 2.0, height / 2.0)
+                };
+                Ok(AnnotationGeometry::Ellipse { radius_x, radius_y })
+            }
+            GeometryType::Bidirectional => {
+                if data.get("long_axis").is_some() || data.get("short_axis").is_some() {
+                    return Ok(AnnotationGeometry::Bidirectional {
+                        long_axis: segment(data.get("long_axis"), "long_axis")?,
+                        short_axis: segment(data.get("short_axis"), "short_axis")?,
+                    });
+                }
+                match points(data.get("points"), "points")?.as_slice() {
+                    [a, b, c, d] => Ok(AnnotationGeometry::Bidirectional { long_axis: (*a, *b), short_axis: (*c, *d) }),
+                    _ => Err("Bidirectional measurement needs long_axis and short_axis (or 4 points)".to_string()),
+                }
+            }
+        }
+    }
+
+    fn geometry_type(&self) -> GeometryType {
+        match self {
+            AnnotationGeometry::Length(..) => GeometryType::Length,
+            AnnotationGeometry::Polyline(_) => GeometryType::Polyline,
+            AnnotationGeometry::Polygon(_) => GeometryType::Polygon,
+            AnnotationGeometry::Rectangle { .. } => GeometryType::Rectangle,
+            AnnotationGeometry::Ellipse { .. } => GeometryType::Ellipse,
+            AnnotationGeometry::Bidirectional { .. } => GeometryType::Bidirectional,
+        }
+    }
+
+    /// 측정값 계산 (픽셀 간격이 없으면 픽셀 단위)
+    fn measure(&self, spacing: Option<PixelSpacing>) -> Vec<ComputedMeasurement> {
+        let (sx, sy) = spacing.map(|s| (s.column, s.row)).unwrap_or((1.0, 1.0));
+        let (length_unit, area_unit) = if spacing.is_some() { ("mm", "mm2") } else { ("px", "px2") };
+        let distance = |a: &Point, b: &Point| ((b.x - a.x) * sx).hypot((b.y - a.y) * sy);
+        let path_length = |points: &[Point]| points.windows(2).map(|w| distance(&w[0], &w[1])).sum::<f64>();
+        let length = |kind: &str, value: f64| ComputedMeasurement::new(kind, value, length_unit);
+        let area = |kind: &str, value: f64| ComputedMeasurement::new(kind, value, area_unit);
+
+        match self {
+            AnnotationGeometry::Length(a, b) => vec![length("length", distance(a, b))],
+            AnnotationGeometry::Polyline(points) => vec![length("length", path_length(points))],
+            AnnotationGeometry::Polygon(points) => {
+                let twice_area: f64 = points
+                    .iter()
+                    .zip(points.iter().cycle().skip(1))
+                    .map(|(a, b)| a.x * b.y - b.x * a.y)
+                    .sum();
+                let perimeter = path_length(points) + distance(points.last().unwrap(), &points[0]);
+                vec![area("area", twice_area.abs() / 2.0 * sx * sy), length("perimeter", perimeter)]
+            }
+            AnnotationGeometry::Rectangle { width, height } => {
+                let (width, height) = (width * sx, height * sy);
+                vec![
+                    area("area", width * height),
+                    length("perimeter", 2.0 * (width + height)),
+                    length("width", width),
+                    length("height", height),
+                ]
+            }
+            AnnotationGeometry::Ellipse { radius_x, radius_y } => {
+                let (a, b) = (radius_x * sx, radius_y * sy);
+                // Ramanujan 근사
+                let perimeter = std::f64::consts::PI * (3.0 * (a + b) - ((3.0 * a + b) * (a + 3.0 * b)).sqrt());
+                vec![
+                    area("area", std::f64::consts::PI * a * b),
+                    length("perimeter", perimeter),
+                    length("major_axis", 2.0 * a.max(b)),
+                    length("minor_axis", 2.0 * a.min(b)),
+                ]
+            }
+            AnnotationGeometry::Bidirectional { long_axis, short_axis } => {
+                let first = distance(&long_axis.0, &long_axis.1);
+                let second = distance(&short_axis.0, &short_axis.1);
+                let (long, short) = (first.max(second), first.min(second));
+                vec![
+                    length("long_axis", long),
+                    length("short_axis", short),
+                    area("bidimensional_product", long * short),
+                ]
+            }
+        }
+    }
+}
+
+/// 서버가 계산한 측정값 (클라이언트 `measurement_values` 항목과 같은 형식)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ComputedMeasurement {
+    #[serde(rename = "type")]
+    #[schema(example = "area")]
+    pub measurement_type: String,
+    pub value: f64,
+    #[schema(example = "mm2")]
+    pub unit: String,
+}
+
+impl ComputedMeasurement {
+    fn new(measurement_type: &str, value: f64, unit: &str) -> Self {
+        Self {
+            measurement_type: measurement_type.to_string(),
+            value: (value * MEASUREMENT_PRECISION).round() / MEASUREMENT_PRECISION,
+            unit: unit.to_string(),
+        }
+    }
+}
+
+/// 클라이언트 측정값과 서버 계산값의 차이
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MeasurementDiscrepancy {
+    #[serde(rename = "type")]
+    pub measurement_type: String,
+    /// 서버 계산값
+    pub computed_value: f64,
+    /// 서버 계산값 단위
+    pub unit: String,
+    /// 클라이언트 값 (원래 단위)
+    pub client_value: f64,
+    pub client_unit: Option<String>,
+    /// 상대 오차 (|클라이언트 - 서버| / 서버, 단위 변환 후)
+    pub relative_difference: f64,
+}
+
+/// 클라이언트 측정값 항목 (종류, 값, 단위)
+struct ClientMeasurement {
+    measurement_type: String,
+    value: f64,
+    unit: Option<String>,
+}
+
+fn normalize_measurement_type(name: &str) -> String {
+    let key = name.trim().to_lowercase().replace([' ', '-'], "_");
+    match key.as_str() {
+        "distance" => "length".to_string(),
+        "circumference" => "perimeter".to_string(),
+        _ => key,
+    }
+}
+
+/// `[{"type", "value" | "values", "unit"}]` 또는 `{"<type>": value, "unit": ..}` 형식의 클라이언트 측정값
+fn client_measurements(measurement_values: &Value) -> Vec<ClientMeasurement> {
+    match measurement_values {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| {
+                let measurement_type = item.get("type")?.as_str()?;
+                let value = item
+                    .get("value")
+                    .and_then(Value::as_f64)
+                    .or_else(|| item.get("values")?.as_array()?.first()?.as_f64())?;
+                Some(ClientMeasurement {
+                    measurement_type: normalize_measurement_type(measurement_type),
+                    value,
+                    unit: item.get("unit").and_then(Value::as_str).map(str::to_string),
+                })
+            })
+            .collect(),
+        Value::Object(fields) => {
+            let unit = fields.get("unit").and_then(Value::as_str).map(str::to_string);
+            fields
+                .iter()
+                .filter(|(key, _)| key.as_str() != "unit")
+                .filter_map(|(key, value)| {
+                    Some(ClientMeasurement {
+                        measurement_type: normalize_measurement_type(key),
+                        value: value.as_f64()?,
+                        unit: unit.clone(),
+                    })
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// 클라이언트 단위 값을 서버 계산 단위로 바꾸는 배수 (비교할 수 없는 단위면 None)
+fn unit_factor(client_unit: Option<&str>, computed_unit: &str) -> Option<f64> {
+    let Some(client_unit) = client_unit else {
+        return Some(1.0);
+    };
+    let client_unit = client_unit.trim().to_lowercase().replace(['²', '^'], "2").replace(' ', "");
+    let factor = match (client_unit.as_str(), computed_unit) {
+        ("mm", "mm") | ("mm2", "mm2") | ("px", "px") | ("px2", "px2") => 1.0,
+        ("cm", "mm") => 10.0,
+        ("cm2", "mm2") => 100.0,
+        ("m", "mm") => 1000.0,
+        _ => return None,
+    };
+    Some(factor)
+}
+
+/// 클라이언트 측정값 중 서버 계산값과 허용 오차 이상 차이 나는 항목
+fn find_discrepancies(computed: &[ComputedMeasurement], measurement_values: Option<&Value>, tolerance: f64) -> Vec<MeasurementDiscrepancy> {
+    let Some(measurement_values) = measurement_values else {
+        return Vec::new();
+    };
+    client_measurements(measurement_values)
+        .into_iter()
+        .filter_map(|client| {
+            let computed = computed.iter().find(|m| m.measurement_type == client.measurement_type)?;
+            let factor = unit_factor(client.unit.as_deref(), &computed.unit)?;
+            let difference = (client.value * factor - computed.value).abs();
+            let relative_difference = if computed.value.abs() > f64::EPSILON {
+                difference / computed.value.abs()
+            } else if difference > f64::EPSILON {
+                f64::INFINITY
+            } else {
+                0.0
+            };
+            (relative_difference > tolerance).then(|| MeasurementDiscrepancy {
+                measurement_type: computed.measurement_type.clone(),
+                computed_value: computed.value,
+                unit: computed.unit.clone(),
+                client_value: client.value,
+                client_unit: client.unit,
+                relative_difference: if relative_difference.is_finite() {
+                    (relative_difference * MEASUREMENT_PRECISION).round() / MEASUREMENT_PRECISION
+                } else {
+                    f64::MAX
+                },
+            })
+        })
+        .collect()
+}
+
+/// 어노테이션 하나의 측정값 계산 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeasurementComputation {
+    pub geometry_type: Option<GeometryType>,
+    pub measurements: Vec<ComputedMeasurement>,
+    pub pixel_spacing: Option<PixelSpacing>,
+    pub spacing_source: SpacingSource,
+    pub discrepancies: Vec<MeasurementDiscrepancy>,
+    /// 계산할 수 없는 이유
+    pub error: Option<String>,
+}
+
+impl MeasurementComputation {
+    /// 도형에서 측정값을 계산하고 클라이언트 측정값과 비교
+    pub fn compute(
+        tool_name: &str,
+        data: &Value,
+        measurement_values: Option<&Value>,
+        pixel_spacing: Option<PixelSpacing>,
+        spacing_source: SpacingSource,
+    ) -> Self {
+        let pixel_spacing = pixel_spacing.filter(|spacing| spacing.validate().is_ok());
+        let spacing_source = if pixel_spacing.is_some() { spacing_source } else { SpacingSource::None };
+
+        match AnnotationGeometry::parse(tool_name, data) {
+            Ok(geometry) => {
+                let measurements = geometry.measure(pixel_spacing);
+                let discrepancies = find_discrepancies(&measurements, measurement_values, MEASUREMENT_DISCREPANCY_TOLERANCE);
+                Self {
+                    geometry_type: Some(geometry.geometry_type()),
+                    measurements,
+                    pixel_spacing,
+                    spacing_source,
+                    discrepancies,
+                    error: None,
+                }
+            }
+            Err(error) => Self {
+                geometry_type: None,
+                measurements: Vec::new(),
+                pixel_spacing,
+                spacing_source,
+                discrepancies: Vec::new(),
+                error: Some(error),
+            },
+        }
+    }
+}
+
+/// 저장된 측정값 계산 결과
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AnnotationMeasurement {
+    pub annotation_id: i32,
+    pub geometry_type: Option<String>,
+    /// `ComputedMeasurement` 배열
+    pub measurements: Value,
+    pub pixel_spacing_row: Option<f64>,
+    pub pixel_spacing_column: Option<f64>,
+    pub spacing_source: String,
+    /// `MeasurementDiscrepancy` 배열
+    pub discrepancies: Value,
+    pub has_discrepancy: bool,
+    pub error: Option<String>,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// 측정값 계산에 사용하는 DICOM 인스턴스 메타데이터
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct InstanceMetadata {
+    pub id: i32,
+    pub project_id: i32,
+    pub study_uid: String,
+    pub series_uid: String,
+    pub instance_uid: String,
+    pub pixel_spacing_row: f64,
+    pub pixel_spacing_column: f64,
+    pub rows: Option<i32>,
+    pub columns: Option<i32>,
+    pub updated_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 새 (또는 갱신할) 인스턴스 메타데이터
+#[derive(Debug, Clone)]
+pub struct NewInstanceMetadata {
+    pub study_uid: String,
+    pub series_uid: String,
+    pub instance_uid: String,
+    pub pixel_spacing: PixelSpacing,
+    pub rows: Option<i32>,
+    pub columns: Option<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SPACING: PixelSpacing = PixelSpacing { row: 0.5, column: 0.25 };
+
+    fn compute(tool_name: &str, data: Value, measurement_values: Option<Value>, spacing: Option<PixelSpacing>) -> MeasurementComputation {
+        let source = if spacing.is_some() { SpacingSource::Instance } else { SpacingSource::None };
+        MeasurementComputation::compute(tool_name, &data, measurement_values.as_ref(), spacing, source)
+    }
+
+    fn values(computation: &MeasurementComputation) -> Vec<(&str, f64, &str)> {
+        computation
+            .measurements
+            .iter()
+            .map(|m| (m.measurement_type.as_str(), m.value, m.unit.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_length_uses_anisotropic_pixel_spacing() {
+        // 가로 40px * 0.25mm = 10mm, 세로 30px * 0.5mm = 15mm
+        let result = compute("Length Tool", json!({"points": [[0, 0], [40, 30]]}), None, Some(SPACING));
+        assert_eq!(result.geometry_type, Some(GeometryType::Length));
+        assert_eq!(values(&result), vec![("length", 18.0278, "mm")]);
+
+        let result = compute("Length Tool", json!({"start": {"x": 0, "y": 0}, "end": {"x": 3, "y": 4}}), None, None);
+        assert_eq!(result.spacing_source, SpacingSource::None);
+        assert_eq!(values(&result), vec![("length", 5.0, "px")]);
+    }
+
+    #[test]
+    fn test_polygon_area_and_perimeter() {
+        // 닫는 점이 중복되어도 같은 결과
+        let square = json!({"type": "polygon", "points": [[0, 0], [10, 0], [10, 10], [0, 10], [0, 0]]});
+        let result = compute("Freehand", square, None, Some(PixelSpacing { row: 2.0, column: 2.0 }));
+        assert_eq!(values(&result), vec![("area", 400.0, "mm2"), ("perimeter", 80.0, "mm")]);
+
+        let polyline = json!({"type": "polyline", "points": [[0, 0], [3, 4], [3, 10]]});
+        assert_eq!(values(&compute("Polyline", polyline, None, None)), vec![("length", 11.0, "px")]);
+    }
+
+    #[test]
+    fn test_rectangle_ellipse_and_bidirectional() {
+        let rectangle = json!({"type": "rectangle", "x": 50, "y": 50, "width": 40, "height": -20});
+        assert_eq!(
+            values(&compute("Rectangle Tool", rectangle, None, Some(SPACING))),
+            vec![("area", 100.0, "mm2"), ("perimeter", 40.0, "mm"), ("width", 10.0, "mm"), ("height", 10.0, "mm")]
+        );
+
+        let circle = json!({"type": "circle", "center": [10, 10], "radius": 10});
+        let result = compute("EllipticalROI", circle, None, None);
+        assert_eq!(result.geometry_type, Some(GeometryType::Ellipse));
+        assert_eq!(
+            values(&result),
+            vec![("area", 314.1593, "px2"), ("perimeter", 62.8319, "px"), ("major_axis", 20.0, "px"), ("minor_axis", 20.0, "px")]
+        );
+
+        // 단축이 더 길게 그려져도 장축/단축을 바로잡음
+        let recist = json!({"long_axis": [[0, 0], [0, 10]], "short_axis": [[0, 0], [0, 30]]});
+        assert_eq!(
+            values(&compute("Bidirectional", recist, None, Some(SPACING))),
+            vec![("long_axis", 15.0, "mm"), ("short_axis", 5.0, "mm"), ("bidimensional_product", 75.0, "mm2")]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_geometry_is_reported() {
+        let result = compute("Arrow Tool", json!({"points": [[0, 0], [1, 1]]}), None, None);
+        assert!(result.geometry_type.is_none());
+        assert!(result.error.unwrap().contains("Arrow Tool"));
+
+        let result = compute("Polygon Tool", json!({"points": [[0, 0], [1, 1]]}), None, None);
+        assert!(result.error.unwrap().contains("at least 3"));
+        let result = compute("Polygon Tool", json!({"type": "spline"}), None, None);
+        assert!(result.error.unwrap().contains("spline"));
+    }
+
+    #[test]
+    fn test_discrepancies_compare_in_computed_units() {
+        let data = json!({"type": "rectangle", "width": 40, "height": 20});
+        let client = json!([
+            {"id": "m1", "type": "area", "values": [1.0], "unit": "cm2"},
+            {"id": "m2", "type": "Perimeter", "value": 52.0, "unit": "mm"},
+            {"id": "m3", "type": "width", "value": 10.2, "unit": "mm"},
+            {"id": "m4", "type": "height", "value": 1.0, "unit": "px"},
+            {"id": "m5", "type": "mean", "value": 30.5, "unit": "HU"}
+        ]);
+        let result = compute("Rectangle Tool", data, Some(client), Some(SPACING));
+        // 면적 1cm2 = 100mm2, 너비 2% 차이는 허용, 픽셀 단위 값과 알 수 없는 종류는 비교하지 않음
+        assert_eq!(result.discrepancies.len(), 1);
+        let discrepancy = &result.discrepancies[0];
+        assert_eq!(discrepancy.measurement_type, "perimeter");
+        assert_eq!((discrepancy.computed_value, discrepancy.client_value), (40.0, 52.0));
+        assert_eq!(discrepancy.relative_difference, 0.3);
+
+        let object_form = json!({"area": 300, "unit": "mm2"});
+        let result = compute("Rectangle Tool", json!({"type": "rectangle", "width": 40, "height": 20}), Some(object_form), Some(SPACING));
+        assert_eq!(result.discrepancies.len(), 1);
+        assert_eq!(result.discrepancies[0].relative_difference, 2.0);
+    }
+
+    #[test]
+    fn test_invalid_spacing_falls_back_to_pixels() {
+        let spacing = PixelSpacing { row: 0.0, column: 0.5 };
+        assert!(spacing.validate().is_err());
+        let result = compute("Length Tool", json!({"points": [[0, 0], [3, 4]]}), None, Some(spacing));
+        assert_eq!(result.spacing_source, SpacingSource::None);
+        assert_eq!(values(&result), vec![("length", 5.0, "px")]);
+    }
+}
@@ -9,8 +9,30 @@ pub mod relations;
 pub mod logs;
 pub mod viewer;
 pub mod annotation;
+pub mod annotation_search;
+pub mod annotation_bundle;
 pub mod mask_group;
+pub mod mask_group_lineage;
 pub mod mask;
+pub mod mask_import;
+pub mod comment;
+pub mod dataset_export;
+pub mod dataset_release;
+pub mod trash;
+pub mod label;
+pub mod worklist;
+pub mod edit_lock;
+pub mod realtime;
+pub mod retry_policy;
+pub mod webhook;
+pub mod measurement;
+pub mod annotation_propagation;
+pub mod multipart_upload;
+pub mod storage_deletion;
+pub mod storage_reconciliation;
+pub mod storage_quota;
+pub mod mask_validation;
+pub mod mask_preview;
 pub mod project_data;
 
 pub use user::*;
@@ -24,6 +46,28 @@ pub use relations::*;
 pub use logs::*;
 pub use viewer::*;
 pub use annotation::*;
+pub use annotation_search::*;
+pub use annotation_bundle::*;
 pub use mask_group::*;
+pub use mask_group_lineage::*;
 pub use mask::*;
+pub use mask_import::*;
+pub use comment::*;
+pub use dataset_export::*;
+pub use dataset_release::*;
+pub use trash::*;
+pub use label::*;
+pub use worklist::*;
+pub use edit_lock::*;
+pub use realtime::*;
+pub use retry_policy::*;
+pub use webhook::*;
+pub use measurement::*;
+pub use annotation_propagation::*;
+pub use multipart_upload::*;
+pub use storage_deletion::*;
+pub use storage_reconciliation::*;
+pub use storage_quota::*;
+pub use mask_validation::*;
+pub use mask_preview::*;
 pub use project_data::*;
@@ -0,0 +1,119 @@
+//! 마스크 그룹 멀티파트 업로드 엔티티
+//!
+//! 큰 마스크 볼륨(전체 해상도 CT 마스크, NIfTI 등)을 여러 파트로 나누어 업로드하는 세션을 나타냅니다.
+//! 파트 목록은 Object Storage가 관리하므로 세션에는 파트 분할 정보만 기록하고, 업로드가 중단되면
+//! 클라이언트는 스토리지에 올라간 파트를 조회해 빠진 파트만 다시 올립니다.
+//! 만료 시각까지 완료되지 않은 세션은 백그라운드 작업이 스토리지 업로드를 중단(abort)합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// 파트 크기를 지정하지 않았을 때 사용할 기본 파트 크기 (16 MiB)
+pub const DEFAULT_MULTIPART_PART_SIZE: i64 = 16 * 1024 * 1024;
+
+/// 완료되지 않은 멀티파트 업로드의 기본 보관 시간
+pub const DEFAULT_MULTIPART_UPLOAD_EXPIRY_HOURS: i64 = 24;
+
+/// 멀티파트 업로드 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "multipart_upload_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MultipartUploadStatus {
+    /// 파트 업로드 중 (이어받기 가능)
+    InProgress,
+    /// 파트를 조립해 객체가 만들어짐
+    Completed,
+    /// 사용자 또는 정리 작업이 중단함
+    Aborted,
+}
+
+/// 마스크 그룹 멀티파트 업로드 세션
+///
+/// 이 구조체는 데이터베이스의 `annotation_mask_group_multipart_upload` 테이블과 매핑됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, FromRow)]
+pub struct MaskGroupMultipartUpload {
+    pub id: i32,
+    pub mask_group_id: i32,
+    pub file_path: String,
+    /// Object Storage가 발급한 업로드 ID
+    pub upload_id: String,
+    pub content_type: Option<String>,
+    /// 마지막 파트를 제외한 파트 크기 (bytes)
+    pub part_size: i64,
+    pub total_size: i64,
+    pub part_count: i32,
+    pub status: MultipartUploadStatus,
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// 이 시각까지 완료되지 않으면 정리 대상
+    pub expires_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl MaskGroupMultipartUpload {
+    /// 파트 번호(1부터)의 예상 크기
+    pub fn expected_part_size(&self, part_number: i32) -> Option<i64> {
+        if part_number < 1 || part_number > self.part_count {
+            return None;
+        }
+        if part_number < self.part_count {
+            Some(self.part_size)
+        } else {
+            Some(self.total_size - self.part_size * (self.part_count as i64 - 1))
+        }
+    }
+
+    /// 업로드된 파트 번호 목록을 기준으로 아직 올리지 않은 파트 번호
+    pub fn missing_parts(&self, uploaded: &[i32]) -> Vec<i32> {
+        (1..=self.part_count).filter(|n| !uploaded.contains(n)).collect()
+    }
+}
+
+/// 새 멀티파트 업로드 세션
+#[derive(Debug, Clone)]
+pub struct NewMaskGroupMultipartUpload {
+    pub mask_group_id: i32,
+    pub file_path: String,
+    pub upload_id: String,
+    pub content_type: Option<String>,
+    pub part_size: i64,
+    pub total_size: i64,
+    pub part_count: i32,
+    pub created_by: i32,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIB: i64 = 1024 * 1024;
+
+    #[test]
+    fn test_expected_part_size_and_missing_parts() {
+        let now = Utc::now();
+        let upload = MaskGroupMultipartUpload {
+            id: 1,
+            mask_group_id: 1,
+            file_path: "masks/annotation_1/group_1/volume.nii.gz".to_string(),
+            upload_id: "upload".to_string(),
+            content_type: None,
+            part_size: 5 * MIB,
+            total_size: 12 * MIB,
+            part_count: 3,
+            status: MultipartUploadStatus::InProgress,
+            created_by: Some(1),
+            created_at: now,
+            updated_at: now,
+            expires_at: now,
+            completed_at: None,
+        };
+        assert_eq!(upload.expected_part_size(1), Some(5 * MIB));
+        assert_eq!(upload.expected_part_size(3), Some(2 * MIB));
+        assert_eq!(upload.expected_part_size(4), None);
+        assert_eq!(upload.missing_parts(&[2]), vec![1, 3]);
+    }
+}
@@ -0,0 +1,206 @@
+//! 실시간 변경 이벤트 엔티티
+//!
+//! 어노테이션 생성/수정/삭제, 마스크 그룹 업로드 완료, 데이터 접근 승인/거부, 편집 잠금 변경을
+//! 구독 중인 클라이언트에게 전달합니다. 이벤트는 Postgres `NOTIFY`로 발행되므로 모든 서버 워커가 같은 이벤트를 받습니다.
+//! 페이로드에는 식별자만 담고, 클라이언트는 필요한 리소스를 기존 API로 다시 조회합니다.
+//! 공유되지 않은 어노테이션의 이벤트는 작성자와 프로젝트 관리자에게만 전달합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 이벤트를 발행하는 Postgres NOTIFY 채널
+pub const REALTIME_CHANNEL: &str = "pacs_realtime_events";
+
+/// 구독자가 따라가지 못해 놓친 이벤트가 있을 때 보내는 SSE 이벤트 이름 (클라이언트는 목록을 다시 조회해야 함)
+pub const REALTIME_RESYNC_EVENT: &str = "RESYNC";
+
+/// 이벤트 종류
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RealtimeEventType {
+    AnnotationCreated,
+    AnnotationUpdated,
+    AnnotationDeleted,
+    /// 마스크 그룹 업로드 완료
+    MaskGroupUploaded,
+    DataAccessApproved,
+    DataAccessDenied,
+    EditLockAcquired,
+    EditLockReleased,
+}
+
+impl RealtimeEventType {
+    /// SSE `event:` 필드 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RealtimeEventType::AnnotationCreated => "ANNOTATION_CREATED",
+            RealtimeEventType::AnnotationUpdated => "ANNOTATION_UPDATED",
+            RealtimeEventType::AnnotationDeleted => "ANNOTATION_DELETED",
+            RealtimeEventType::MaskGroupUploaded => "MASK_GROUP_UPLOADED",
+            RealtimeEventType::DataAccessApproved => "DATA_ACCESS_APPROVED",
+            RealtimeEventType::DataAccessDenied => "DATA_ACCESS_DENIED",
+            RealtimeEventType::EditLockAcquired => "EDIT_LOCK_ACQUIRED",
+            RealtimeEventType::EditLockReleased => "EDIT_LOCK_RELEASED",
+        }
+    }
+
+    /// 대상 사용자 본인과 프로젝트 관리자만 받을 수 있는 이벤트인지
+    fn is_restricted(&self) -> bool {
+        matches!(self, RealtimeEventType::DataAccessApproved | RealtimeEventType::DataAccessDenied)
+    }
+}
+
+/// 실시간 변경 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct RealtimeEvent {
+    pub event_type: RealtimeEventType,
+    pub project_id: i32,
+    /// 이벤트가 속한 Study Instance UID
+    pub study_uid: Option<String>,
+    /// 변경된 리소스 ID (어노테이션, 마스크 그룹, 데이터 접근, 편집 잠금)
+    pub resource_id: i32,
+    /// 변경한 사용자 ID
+    pub actor_id: Option<i32>,
+    /// 변경 대상 사용자 ID (데이터 접근 이벤트의 접근 권한을 받은 사용자)
+    pub subject_user_id: Option<i32>,
+    /// 이벤트가 가리키는 어노테이션의 작성자 ID
+    #[serde(default)]
+    pub owner_id: Option<i32>,
+    /// 프로젝트 멤버 모두가 받을 수 있는 이벤트인지 (공유되지 않은 어노테이션의 이벤트는 false)
+    #[serde(default)]
+    pub is_shared: bool,
+    #[schema(value_type = String, example = "2024-01-01T00:00:00Z")]
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl RealtimeEvent {
+    pub fn new(event_type: RealtimeEventType, project_id: i32, study_uid: Option<String>, resource_id: i32, actor_id: Option<i32>) -> Self {
+        Self {
+            event_type,
+            project_id,
+            study_uid,
+            resource_id,
+            actor_id,
+            subject_user_id: None,
+            owner_id: None,
+            is_shared: true,
+            occurred_at: Utc::now(),
+        }
+    }
+
+    pub fn with_subject_user(mut self, user_id: i32) -> Self {
+        self.subject_user_id = Some(user_id);
+        self
+    }
+
+    /// 어노테이션 작성자와 공유 여부 (어노테이션이 없으면 `owner_id`를 None으로 두어 관리자만 받게 함)
+    pub fn with_annotation_visibility(mut self, owner_id: Option<i32>, is_shared: bool) -> Self {
+        self.owner_id = owner_id;
+        self.is_shared = is_shared && owner_id.is_some();
+        self
+    }
+}
+
+/// 구독 (구독 시점에 확인한 사용자 권한 포함)
+#[derive(Debug, Clone)]
+pub struct RealtimeSubscription {
+    pub project_id: i32,
+    /// 지정하면 이 Study의 이벤트만 받음
+    pub study_uid: Option<String>,
+    pub user_id: i32,
+    /// 프로젝트 관리자 여부 (다른 사용자의 데이터 접근 이벤트와 공유되지 않은 어노테이션 이벤트 수신)
+    pub is_project_admin: bool,
+}
+
+impl RealtimeSubscription {
+    /// 구독자가 받을 수 있는 이벤트인지
+    pub fn accepts(&self, event: &RealtimeEvent) -> bool {
+        if event.project_id != self.project_id {
+            return false;
+        }
+        if let Some(study_uid) = &self.study_uid {
+            if event.study_uid.as_deref() != Some(study_uid.as_str()) {
+                return false;
+            }
+        }
+        if self.is_project_admin {
+            return true;
+        }
+        if event.event_type.is_restricted() {
+            return event.subject_user_id == Some(self.user_id);
+        }
+        event.is_shared || event.owner_id == Some(self.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscription(study_uid: Option<&str>, is_project_admin: bool) -> RealtimeSubscription {
+        RealtimeSubscription {
+            project_id: 1,
+            study_uid: study_uid.map(str::to_string),
+            user_id: 10,
+            is_project_admin,
+        }
+    }
+
+    #[test]
+    fn test_subscription_filters_by_project_and_study() {
+        let event = RealtimeEvent::new(RealtimeEventType::AnnotationUpdated, 1, Some("1.2.3".to_string()), 5, Some(11));
+        assert!(subscription(None, false).accepts(&event));
+        assert!(subscription(Some("1.2.3"), false).accepts(&event));
+        assert!(!subscription(Some("1.2.4"), false).accepts(&event));
+
+        let other_project = RealtimeEvent { project_id: 2, ..event.clone() };
+        assert!(!subscription(None, true).accepts(&other_project));
+        let no_study = RealtimeEvent { study_uid: None, ..event };
+        assert!(!subscription(Some("1.2.3"), false).accepts(&no_study));
+    }
+
+    #[test]
+    fn test_data_access_events_only_for_subject_and_admin() {
+        let granted_to_subscriber = RealtimeEvent::new(RealtimeEventType::DataAccessApproved, 1, Some("1.2.3".to_string()), 8, Some(1))
+            .with_subject_user(10);
+        let granted_to_other = granted_to_subscriber.clone().with_subject_user(12);
+        assert!(subscription(None, false).accepts(&granted_to_subscriber));
+        assert!(!subscription(None, false).accepts(&granted_to_other));
+        assert!(subscription(None, true).accepts(&granted_to_other));
+    }
+
+    #[test]
+    fn test_private_annotation_events_only_for_owner_and_admin() {
+        let private = RealtimeEvent::new(RealtimeEventType::AnnotationUpdated, 1, Some("1.2.3".to_string()), 5, Some(10))
+            .with_annotation_visibility(Some(10), false);
+        assert!(subscription(None, false).accepts(&private));
+        assert!(subscription(None, true).accepts(&RealtimeEvent { owner_id: Some(11), ..private.clone() }));
+        assert!(!subscription(None, false).accepts(&RealtimeEvent { owner_id: Some(11), ..private.clone() }));
+
+        let shared = private.clone().with_annotation_visibility(Some(11), true);
+        assert!(subscription(None, false).accepts(&shared));
+
+        // 어노테이션을 찾지 못한 잠금 이벤트는 관리자만 받음
+        let unknown = RealtimeEvent::new(RealtimeEventType::EditLockReleased, 1, Some("1.2.3".to_string()), 3, Some(10))
+            .with_annotation_visibility(None, true);
+        assert!(!subscription(None, false).accepts(&unknown));
+        assert!(subscription(None, true).accepts(&unknown));
+
+        // 공유 여부가 빠진 페이로드는 비공개로 취급
+        let mut payload = serde_json::to_value(&shared).unwrap();
+        payload.as_object_mut().unwrap().remove("is_shared");
+        let parsed: RealtimeEvent = serde_json::from_value(payload).unwrap();
+        assert!(!parsed.is_shared);
+    }
+
+    #[test]
+    fn test_event_payload_format() {
+        let event = RealtimeEvent::new(RealtimeEventType::EditLockAcquired, 1, Some("1.2.3".to_string()), 3, Some(10));
+        let payload = serde_json::to_value(&event).unwrap();
+        assert_eq!(payload["event_type"], "EDIT_LOCK_ACQUIRED");
+        assert_eq!(payload["event_type"], event.event_type.as_str());
+        let parsed: RealtimeEvent = serde_json::from_value(payload).unwrap();
+        assert_eq!(parsed, event);
+    }
+}
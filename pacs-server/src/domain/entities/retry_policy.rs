@@ -0,0 +1,66 @@
+//! 백그라운드 작업 재시도 정책
+//!
+//! Webhook 전송, Object Storage 삭제, 마스크 검증/미리보기 생성처럼 실패하면 다시 시도하는 작업이
+//! 같은 지수 백오프 규칙을 사용합니다. 재시도 횟수를 모두 소진하면 작업별 종료 상태로 남습니다.
+
+/// 지수 백오프 재시도 정책
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 최대 시도 횟수 (첫 시도 포함)
+    pub max_attempts: i32,
+    /// 첫 재시도까지의 시간 (초, 이후 두 배씩 증가)
+    pub base_delay_seconds: i64,
+    /// 재시도 간격 상한 (초)
+    pub max_delay_seconds: i64,
+}
+
+impl RetryPolicy {
+    pub const fn new(max_attempts: i32, base_delay_seconds: i64, max_delay_seconds: i64) -> Self {
+        Self {
+            max_attempts,
+            base_delay_seconds,
+            max_delay_seconds,
+        }
+    }
+
+    /// `attempt_number`번째 시도가 실패했을 때 다음 시도까지의 시간 (초, 더 시도하지 않으면 None)
+    pub fn retry_delay(&self, attempt_number: i32) -> Option<i64> {
+        if attempt_number >= self.max_attempts {
+            return None;
+        }
+        let exponent = (attempt_number - 1).clamp(0, 30) as u32;
+        Some(
+            self.base_delay_seconds
+                .saturating_mul(2_i64.saturating_pow(exponent))
+                .min(self.max_delay_seconds),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_backs_off_exponentially() {
+        let policy = RetryPolicy::new(8, 30, 3600);
+        assert_eq!(policy.retry_delay(1), Some(30));
+        assert_eq!(policy.retry_delay(2), Some(60));
+        assert_eq!(policy.retry_delay(4), Some(240));
+        assert_eq!(policy.retry_delay(7), Some(1920));
+        assert_eq!(policy.retry_delay(8), None);
+    }
+
+    #[test]
+    fn test_retry_delay_is_capped() {
+        let policy = RetryPolicy::new(4, 60, 150);
+        assert_eq!(policy.retry_delay(1), Some(60));
+        assert_eq!(policy.retry_delay(2), Some(120));
+        assert_eq!(policy.retry_delay(3), Some(150));
+        assert_eq!(policy.retry_delay(4), None);
+
+        let long = RetryPolicy::new(40, 30, 3600);
+        assert_eq!(long.retry_delay(20), Some(3600));
+        assert_eq!(long.retry_delay(39), Some(3600));
+    }
+}
@@ -0,0 +1,107 @@
+//! Object Storage 삭제 대기열 엔티티
+//!
+//! 마스크 삭제와 휴지통 영구 삭제는 행을 지우는 트랜잭션에서 지워야 할 객체를 대기열에 기록합니다.
+//! 백그라운드 작업이 대기열의 객체를 삭제하고, 실패하면 지수 백오프로 재시도하며
+//! 재시도 횟수를 모두 소진하면 dead-letter(`DEAD`)로 남깁니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use crate::domain::entities::retry_policy::RetryPolicy;
+
+/// 삭제 사유
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StorageDeletionReason {
+    /// 마스크 삭제
+    MaskDeleted,
+    /// 휴지통의 마스크 그룹 영구 삭제
+    MaskGroupPurged,
+    /// 휴지통의 어노테이션 영구 삭제 (마스크 그룹 포함)
+    AnnotationPurged,
+    /// 정합성 점검에서 발견한 고아 객체
+    ReconciliationOrphan,
+    /// 실패한 마스크 임포트가 남긴 마스크 그룹의 객체
+    MaskImportFailed,
+}
+
+impl StorageDeletionReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageDeletionReason::MaskDeleted => "MASK_DELETED",
+            StorageDeletionReason::MaskGroupPurged => "MASK_GROUP_PURGED",
+            StorageDeletionReason::AnnotationPurged => "ANNOTATION_PURGED",
+            StorageDeletionReason::ReconciliationOrphan => "RECONCILIATION_ORPHAN",
+            StorageDeletionReason::MaskImportFailed => "MASK_IMPORT_FAILED",
+        }
+    }
+}
+
+/// 삭제 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq)]
+#[sqlx(type_name = "storage_deletion_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StorageDeletionStatus {
+    /// 삭제 대기 또는 재시도 대기
+    Pending,
+    Deleted,
+    /// 재시도 횟수를 모두 소진한 dead-letter
+    Dead,
+}
+
+/// 삭제 대기열 항목
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StorageDeletion {
+    pub id: i64,
+    /// 객체 경로 (`is_prefix`이면 이 경로로 시작하는 모든 객체)
+    pub object_key: String,
+    pub is_prefix: bool,
+    pub reason: String,
+    pub status: StorageDeletionStatus,
+    pub attempt_count: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// 삭제 재시도 정책 기본값 (최대 10회, 1분부터 두 배씩 최대 6시간)
+pub const STORAGE_DELETION_RETRY_POLICY: RetryPolicy = RetryPolicy::new(10, 60, 6 * 3600);
+
+/// 삭제 작업 한 번의 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct StorageDeletionRunResult {
+    /// 삭제를 완료한 대기열 항목 수
+    pub completed: usize,
+    /// 삭제한 객체 수 (prefix 항목은 포함된 객체 수만큼)
+    pub objects_deleted: usize,
+    /// 실패하여 재시도 예정인 항목 수
+    pub retrying: usize,
+    /// 재시도 횟수를 모두 소진한 항목 수
+    pub dead: usize,
+}
+
+/// 어노테이션의 마스크 파일이 저장되는 경로 prefix
+pub fn annotation_mask_prefix(annotation_id: i32) -> String {
+    format!("masks/annotation_{}/", annotation_id)
+}
+
+/// 마스크 그룹의 마스크 파일이 저장되는 경로 prefix
+pub fn mask_group_mask_prefix(annotation_id: i32, mask_group_id: i32) -> String {
+    format!("masks/annotation_{}/group_{}/", annotation_id, mask_group_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefixes_match_upload_paths() {
+        let group_prefix = mask_group_mask_prefix(3, 12);
+        assert_eq!(group_prefix, "masks/annotation_3/group_12/");
+        assert!(group_prefix.starts_with(&annotation_mask_prefix(3)));
+        // 다른 어노테이션의 경로와 겹치지 않도록 '/'로 끝남
+        assert!(!"masks/annotation_31/group_1/a.png".starts_with(&annotation_mask_prefix(3)));
+    }
+}
@@ -0,0 +1,217 @@
+//! 저장 용량 사용량/한도 엔티티
+//!
+//! 마스크 파일 사용량은 프로젝트와 사용자(마스크 그룹 생성자) 단위로 `storage_usage`에 누적되며,
+//! 마스크 행이 바뀌는 트랜잭션에서 함께 갱신됩니다. 한도는 프로젝트 또는 사용자 단위로 지정하며,
+//! 경고 한도(soft)는 넘어도 업로드를 허용하고 한 번 이벤트를 남기고, 업로드 한도(hard)를 넘는 업로드 URL은 발급하지 않습니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// 한도 적용 범위
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StorageQuotaScope {
+    Project,
+    User,
+}
+
+impl StorageQuotaScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageQuotaScope::Project => "PROJECT",
+            StorageQuotaScope::User => "USER",
+        }
+    }
+}
+
+/// 저장 용량 한도
+///
+/// 이 구조체는 데이터베이스의 `storage_quota` 테이블과 매핑됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StorageQuota {
+    pub id: i32,
+    pub project_id: Option<i32>,
+    pub user_id: Option<i32>,
+    /// 경고 한도 (None이면 없음)
+    pub soft_limit_bytes: Option<i64>,
+    /// 업로드 한도 (None이면 없음)
+    pub hard_limit_bytes: Option<i64>,
+    /// 경고 한도 이벤트를 기록한 시각
+    pub alerted_at: Option<DateTime<Utc>>,
+    pub updated_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 프로젝트/사용자별 사용량
+///
+/// 이 구조체는 데이터베이스의 `storage_usage` 테이블과 매핑됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StorageUsage {
+    pub project_id: i32,
+    pub user_id: i32,
+    pub bytes_used: i64,
+    pub object_count: i64,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 사용량 합계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, FromRow)]
+pub struct StorageUsageTotals {
+    pub bytes_used: i64,
+    pub object_count: i64,
+}
+
+/// 업로드 시 한도 상태 (심각한 순서로 정렬됨)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StorageQuotaLevel {
+    WithinQuota,
+    /// 경고 한도 이상 (업로드 허용)
+    SoftLimitExceeded,
+    /// 업로드 한도 초과 (업로드 거부)
+    HardLimitExceeded,
+}
+
+/// 한 범위의 사용량과 한도
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageQuotaStatus {
+    pub scope: StorageQuotaScope,
+    /// 프로젝트 ID 또는 사용자 ID
+    pub scope_id: i32,
+    pub bytes_used: i64,
+    pub soft_limit_bytes: Option<i64>,
+    pub hard_limit_bytes: Option<i64>,
+}
+
+impl StorageQuotaStatus {
+    /// 업로드 후의 한도 상태
+    ///
+    /// 업로드 크기를 모르면 이미 업로드 한도에 도달한 경우에만 거부합니다.
+    pub fn level(&self, upload_bytes: Option<i64>) -> StorageQuotaLevel {
+        let projected = self.bytes_used.saturating_add(upload_bytes.unwrap_or(0).max(0));
+        if let Some(hard) = self.hard_limit_bytes {
+            if projected > hard || (upload_bytes.is_none() && self.bytes_used >= hard) {
+                return StorageQuotaLevel::HardLimitExceeded;
+            }
+        }
+        match self.soft_limit_bytes {
+            Some(soft) if projected >= soft => StorageQuotaLevel::SoftLimitExceeded,
+            _ => StorageQuotaLevel::WithinQuota,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self.scope {
+            StorageQuotaScope::Project => format!("Project {}", self.scope_id),
+            StorageQuotaScope::User => format!("User {}", self.scope_id),
+        }
+    }
+}
+
+/// 업로드 URL 발급 전 한도 확인 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageQuotaCheck {
+    pub project: StorageQuotaStatus,
+    pub user: StorageQuotaStatus,
+    pub upload_bytes: Option<i64>,
+}
+
+impl StorageQuotaCheck {
+    /// 두 범위 중 더 심각한 상태
+    pub fn level(&self) -> StorageQuotaLevel {
+        self.project.level(self.upload_bytes).max(self.user.level(self.upload_bytes))
+    }
+
+    /// 업로드 한도를 넘으면 거부 사유, 경고 한도 이상이면 경고 메시지
+    pub fn message(&self) -> Option<String> {
+        let level = self.level();
+        let status = [&self.project, &self.user]
+            .into_iter()
+            .find(|status| status.level(self.upload_bytes) == level)?;
+        match level {
+            StorageQuotaLevel::WithinQuota => None,
+            StorageQuotaLevel::SoftLimitExceeded => Some(format!(
+                "{} storage usage ({} bytes) has reached the soft limit of {} bytes",
+                status.describe(),
+                status.bytes_used,
+                status.soft_limit_bytes.unwrap_or_default()
+            )),
+            StorageQuotaLevel::HardLimitExceeded => Some(format!(
+                "{} storage quota exceeded: {} bytes used{}, hard limit is {} bytes",
+                status.describe(),
+                status.bytes_used,
+                self.upload_bytes.map(|bytes| format!(" + {} bytes to upload", bytes)).unwrap_or_default(),
+                status.hard_limit_bytes.unwrap_or_default()
+            )),
+        }
+    }
+}
+
+/// 한도 값 검증
+pub fn validate_quota_limits(soft_limit_bytes: Option<i64>, hard_limit_bytes: Option<i64>) -> Result<(), String> {
+    if soft_limit_bytes.is_some_and(|bytes| bytes < 0) || hard_limit_bytes.is_some_and(|bytes| bytes < 0) {
+        return Err("Storage quota limits must not be negative".to_string());
+    }
+    if let (Some(soft), Some(hard)) = (soft_limit_bytes, hard_limit_bytes) {
+        if soft > hard {
+            return Err(format!("Soft limit ({} bytes) cannot exceed hard limit ({} bytes)", soft, hard));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(scope: StorageQuotaScope, bytes_used: i64, soft: Option<i64>, hard: Option<i64>) -> StorageQuotaStatus {
+        StorageQuotaStatus { scope, scope_id: 1, bytes_used, soft_limit_bytes: soft, hard_limit_bytes: hard }
+    }
+
+    #[test]
+    fn test_level_accounts_for_upload_size() {
+        let quota = status(StorageQuotaScope::Project, 700, Some(800), Some(1000));
+        assert_eq!(quota.level(Some(50)), StorageQuotaLevel::WithinQuota);
+        assert_eq!(quota.level(Some(100)), StorageQuotaLevel::SoftLimitExceeded);
+        assert_eq!(quota.level(Some(300)), StorageQuotaLevel::SoftLimitExceeded);
+        assert_eq!(quota.level(Some(301)), StorageQuotaLevel::HardLimitExceeded);
+        // 크기를 모르면 이미 도달한 경우에만 거부
+        assert_eq!(quota.level(None), StorageQuotaLevel::WithinQuota);
+        assert_eq!(status(StorageQuotaScope::Project, 1000, None, Some(1000)).level(None), StorageQuotaLevel::HardLimitExceeded);
+        assert_eq!(status(StorageQuotaScope::Project, i64::MAX, None, None).level(Some(i64::MAX)), StorageQuotaLevel::WithinQuota);
+    }
+
+    #[test]
+    fn test_check_reports_most_severe_scope() {
+        let check = StorageQuotaCheck {
+            project: status(StorageQuotaScope::Project, 900, Some(800), None),
+            user: status(StorageQuotaScope::User, 90, None, Some(100)),
+            upload_bytes: Some(20),
+        };
+        assert_eq!(check.level(), StorageQuotaLevel::HardLimitExceeded);
+        let message = check.message().unwrap();
+        assert!(message.starts_with("User 1 storage quota exceeded"), "{}", message);
+
+        let check = StorageQuotaCheck { upload_bytes: Some(5), ..check };
+        assert_eq!(check.level(), StorageQuotaLevel::SoftLimitExceeded);
+        assert!(check.message().unwrap().starts_with("Project 1 storage usage"));
+
+        let unlimited = StorageQuotaCheck {
+            project: status(StorageQuotaScope::Project, 900, None, None),
+            user: status(StorageQuotaScope::User, 900, None, None),
+            upload_bytes: None,
+        };
+        assert_eq!(unlimited.message(), None);
+    }
+
+    #[test]
+    fn test_validate_quota_limits() {
+        assert!(validate_quota_limits(None, None).is_ok());
+        assert!(validate_quota_limits(Some(10), Some(10)).is_ok());
+        assert!(validate_quota_limits(Some(11), Some(10)).is_err());
+        assert!(validate_quota_limits(None, Some(-1)).is_err());
+    }
+}
@@ -0,0 +1,255 @@
+//! Object Storage 정합성 점검 엔티티
+//!
+//! 업로드는 Signed URL로 스토리지에 직접 올라가므로, 행이 없는 객체(업로드 후 등록하지 않음)와
+//! 객체가 없는 행(등록 후 업로드하지 않음)이 생길 수 있습니다. 정합성 점검은 `masks/`와 `annotations/`
+//! 아래의 객체를 마스크 행과 비교해 보고서를 남기고, 설정에 따라 유예 기간이 지난 고아 객체를
+//! 격리하거나 삭제 대기열에 예약합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// 이 시간보다 최근의 객체/행은 업로드 중일 수 있어 점검에서 제외 (기본값)
+pub const DEFAULT_RECONCILIATION_GRACE_HOURS: i32 = 24;
+
+/// 점검하는 객체 경로 prefix
+pub const RECONCILED_PREFIXES: [&str; 2] = ["masks/", "annotations/"];
+
+/// 격리한 객체를 옮기는 경로 prefix (점검 대상 prefix 밖)
+pub const QUARANTINE_PREFIX: &str = "quarantine/";
+
+/// 점검 실행 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "storage_reconciliation_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StorageReconciliationStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// 고아 객체 처리 방식
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "storage_orphan_action_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StorageOrphanAction {
+    /// 보고만 함
+    #[default]
+    Report,
+    /// `quarantine/` 아래로 이동
+    Quarantine,
+    /// 삭제 대기열에 예약
+    Delete,
+}
+
+impl StorageOrphanAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageOrphanAction::Report => "REPORT",
+            StorageOrphanAction::Quarantine => "QUARANTINE",
+            StorageOrphanAction::Delete => "DELETE",
+        }
+    }
+}
+
+impl std::str::FromStr for StorageOrphanAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "REPORT" => Ok(StorageOrphanAction::Report),
+            "QUARANTINE" => Ok(StorageOrphanAction::Quarantine),
+            "DELETE" => Ok(StorageOrphanAction::Delete),
+            other => Err(format!("Unknown storage orphan action: {}", other)),
+        }
+    }
+}
+
+/// 발견한 문제 종류
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "storage_reconciliation_finding_kind_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StorageReconciliationFindingKind {
+    /// 행이 없는 객체
+    OrphanObject,
+    /// 객체가 없는 행
+    DanglingRow,
+    /// 행의 파일 크기와 객체 크기가 다름
+    SizeMismatch,
+    /// 행의 체크섬과 객체 체크섬이 다름
+    ChecksumMismatch,
+}
+
+impl StorageReconciliationFindingKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageReconciliationFindingKind::OrphanObject => "ORPHAN_OBJECT",
+            StorageReconciliationFindingKind::DanglingRow => "DANGLING_ROW",
+            StorageReconciliationFindingKind::SizeMismatch => "SIZE_MISMATCH",
+            StorageReconciliationFindingKind::ChecksumMismatch => "CHECKSUM_MISMATCH",
+        }
+    }
+}
+
+/// 점검 실행 기록
+///
+/// 이 구조체는 데이터베이스의 `storage_reconciliation_run` 테이블과 매핑됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StorageReconciliationRun {
+    pub id: i32,
+    pub status: StorageReconciliationStatus,
+    pub orphan_action: StorageOrphanAction,
+    pub grace_period_hours: i32,
+    /// 관리자 API로 실행한 사용자 (None이면 정기 실행)
+    pub triggered_by: Option<i32>,
+    pub objects_scanned: i64,
+    pub rows_scanned: i64,
+    pub recent_skipped: i64,
+    pub orphan_objects: i64,
+    pub dangling_rows: i64,
+    pub size_mismatches: i64,
+    pub checksum_mismatches: i64,
+    pub orphans_quarantined: i64,
+    pub orphans_queued_for_deletion: i64,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// 점검 실행의 집계
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageReconciliationCounts {
+    pub objects_scanned: i64,
+    pub rows_scanned: i64,
+    pub recent_skipped: i64,
+    pub orphan_objects: i64,
+    pub dangling_rows: i64,
+    pub size_mismatches: i64,
+    pub checksum_mismatches: i64,
+    pub orphans_quarantined: i64,
+    pub orphans_queued_for_deletion: i64,
+}
+
+/// 발견한 문제
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StorageReconciliationFinding {
+    pub id: i64,
+    pub run_id: i32,
+    pub kind: StorageReconciliationFindingKind,
+    pub object_key: String,
+    pub mask_id: Option<i32>,
+    /// 행에 기록된 값 (크기/체크섬 불일치)
+    pub expected: Option<String>,
+    /// 스토리지의 값 (크기/체크섬 불일치)
+    pub actual: Option<String>,
+    /// 고아 객체에 적용한 처리
+    pub action: StorageOrphanAction,
+    pub quarantine_key: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 새로 기록할 문제
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewStorageReconciliationFinding {
+    pub kind: StorageReconciliationFindingKind,
+    pub object_key: String,
+    pub mask_id: Option<i32>,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub action: StorageOrphanAction,
+    pub quarantine_key: Option<String>,
+}
+
+impl NewStorageReconciliationFinding {
+    pub fn new(kind: StorageReconciliationFindingKind, object_key: impl Into<String>) -> Self {
+        Self {
+            kind,
+            object_key: object_key.into(),
+            mask_id: None,
+            expected: None,
+            actual: None,
+            action: StorageOrphanAction::Report,
+            quarantine_key: None,
+        }
+    }
+}
+
+/// 점검 대상 마스크 행
+#[derive(Debug, Clone, FromRow)]
+pub struct ReconciliationMaskRow {
+    pub id: i32,
+    pub file_path: String,
+    pub file_size: Option<i64>,
+    pub checksum: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 고아 객체를 격리할 경로
+pub fn quarantine_key(run_id: i32, object_key: &str) -> String {
+    format!("{}run_{}/{}", QUARANTINE_PREFIX, run_id, object_key)
+}
+
+/// 비교할 수 있는 형태의 체크섬 (ETag의 따옴표 제거, 소문자)
+///
+/// 멀티파트 업로드의 ETag(`<hash>-<part 수>`)는 파일 내용의 해시가 아니므로 None
+fn normalize_checksum(checksum: &str) -> Option<String> {
+    let checksum = checksum.trim().trim_matches('"').to_ascii_lowercase();
+    if checksum.is_empty() || checksum.contains('-') {
+        return None;
+    }
+    Some(checksum)
+}
+
+/// 행의 체크섬과 스토리지의 체크섬이 다른지 여부
+///
+/// 스토리지마다 체크섬 알고리즘이 달라(S3 ETag는 MD5, 로컬 스토리지는 SHA-256) 길이가 같을 때만 비교합니다.
+pub fn checksums_differ(expected: &str, actual: &str) -> bool {
+    match (normalize_checksum(expected), normalize_checksum(actual)) {
+        (Some(expected), Some(actual)) => expected.len() == actual.len() && expected != actual,
+        _ => false,
+    }
+}
+
+/// 스토리지 메타데이터의 마지막 수정 시각 파싱 (RFC 3339)
+pub fn parse_last_modified(last_modified: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(last_modified)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums_compared_only_when_comparable() {
+        let md5 = "9e107d9d372bb6826bd81d3542a419d6";
+        assert!(!checksums_differ(md5, &format!("\"{}\"", md5.to_ascii_uppercase())));
+        assert!(checksums_differ(md5, "\"e4d909c290d0fb1ca068ffaddf22cbd0\""));
+        // 멀티파트 ETag와 다른 알고리즘의 체크섬은 비교하지 않음
+        assert!(!checksums_differ(md5, "\"e4d909c290d0fb1ca068ffaddf22cbd0-3\""));
+        assert!(!checksums_differ(md5, &"a".repeat(64)));
+    }
+
+    #[test]
+    fn test_quarantine_key_is_outside_reconciled_prefixes() {
+        let key = quarantine_key(7, "masks/annotation_1/group_2/a.png");
+        assert_eq!(key, "quarantine/run_7/masks/annotation_1/group_2/a.png");
+        assert!(RECONCILED_PREFIXES.iter().all(|prefix| !key.starts_with(prefix)));
+    }
+
+    #[test]
+    fn test_orphan_action_from_str() {
+        assert_eq!("quarantine".parse::<StorageOrphanAction>(), Ok(StorageOrphanAction::Quarantine));
+        assert_eq!("DELETE".parse::<StorageOrphanAction>(), Ok(StorageOrphanAction::Delete));
+        assert!("purge".parse::<StorageOrphanAction>().is_err());
+    }
+
+    #[test]
+    fn test_parse_last_modified() {
+        assert!(parse_last_modified("2025-11-08T03:00:00Z").is_some());
+        assert!(parse_last_modified("2025-11-08T12:00:00+09:00").is_some());
+        assert!(parse_last_modified("yesterday").is_none());
+    }
+}
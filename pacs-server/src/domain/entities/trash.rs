@@ -0,0 +1,76 @@
+//! 휴지통 엔티티
+//!
+//! 삭제된 어노테이션과 마스크 그룹은 바로 지워지지 않고 `deleted_at`/`deleted_by`가 기록된 채
+//! 프로젝트 휴지통에 보관됩니다. 보관 기간이 지나면 정리 작업이 행과 마스크 파일을 함께 삭제합니다.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// 기본 휴지통 보관 기간 (일)
+pub const DEFAULT_TRASH_RETENTION_DAYS: i64 = 30;
+
+/// 휴지통에 있는 어노테이션
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TrashedAnnotation {
+    pub id: i32,
+    pub project_id: i32,
+    /// 작성자 ID
+    pub user_id: i32,
+    pub study_uid: String,
+    pub series_uid: Option<String>,
+    pub tool_name: String,
+    /// 함께 휴지통으로 이동한 마스크 그룹 수
+    pub mask_group_count: i64,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<i32>,
+}
+
+/// 휴지통에 있는 마스크 그룹 (어노테이션은 활성 상태이고 마스크 그룹만 삭제된 경우)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TrashedMaskGroup {
+    pub id: i32,
+    pub annotation_id: i32,
+    pub group_name: Option<String>,
+    pub model_name: Option<String>,
+    /// 그룹에 포함된 마스크 수
+    pub mask_count: i64,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<i32>,
+}
+
+/// 휴지통 정리 작업 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TrashPurgeResult {
+    /// 영구 삭제된 어노테이션 수
+    pub annotations: usize,
+    /// 영구 삭제된 마스크 그룹 수 (어노테이션과 함께 삭제된 그룹 제외)
+    pub mask_groups: usize,
+    /// 삭제 대기열에 예약한 마스크 파일 수
+    pub objects_queued: u64,
+}
+
+/// 보관 기간 설정에 따른 영구 삭제 예정 시각
+pub fn purge_after(deleted_at: DateTime<Utc>, retention_days: i64) -> DateTime<Utc> {
+    deleted_at + Duration::days(retention_days)
+}
+
+/// 이 시각 이전에 삭제된 항목이 정리 대상
+pub fn purge_cutoff(now: DateTime<Utc>, retention_days: i64) -> DateTime<Utc> {
+    now - Duration::days(retention_days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_purge_after_and_cutoff_are_consistent() {
+        let deleted_at = Utc::now() - Duration::days(31);
+        let now = Utc::now();
+
+        assert!(purge_after(deleted_at, 30) < now);
+        assert!(deleted_at < purge_cutoff(now, 30));
+        assert!(deleted_at > purge_cutoff(now, 60));
+    }
+}
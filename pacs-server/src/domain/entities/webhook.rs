@@ -0,0 +1,215 @@
+//! Webhook 엔티티
+//!
+//! 프로젝트 관리자가 등록한 URL로 도메인 이벤트(어노테이션 승인, 마스크 그룹 업로드 완료, 데이터 접근 변경, 저장 용량 경고)를 전송합니다.
+//! 이벤트는 변경과 같은 트랜잭션에서 아웃박스에 기록되고, 디스패처가 구독별 전송 작업으로 나눈 뒤
+//! HMAC-SHA256으로 서명한 페이로드를 전송합니다. 실패한 전송은 지수 백오프로 재시도하며,
+//! 재시도 횟수를 모두 소진하면 dead-letter(`DEAD`)로 남습니다.
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use crate::domain::entities::retry_policy::RetryPolicy;
+
+/// 서명 헤더 (`sha256=<hex>`)
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-PACS-Signature";
+/// 서명에 사용한 Unix 타임스탬프 헤더
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-PACS-Timestamp";
+/// 이벤트 종류 헤더
+pub const WEBHOOK_EVENT_HEADER: &str = "X-PACS-Event";
+/// 전송 ID 헤더 (재시도 시 같은 값)
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-PACS-Delivery";
+
+/// Webhook 이벤트 종류
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WebhookEventType {
+    /// 어노테이션 리뷰 승인
+    AnnotationApproved,
+    /// 마스크 그룹 업로드 완료
+    MaskGroupUploaded,
+    /// 데이터 접근 요청/상태 변경
+    DataAccessChanged,
+    /// 저장 용량 경고 한도 도달
+    StorageQuotaThresholdReached,
+}
+
+impl WebhookEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::AnnotationApproved => "ANNOTATION_APPROVED",
+            WebhookEventType::MaskGroupUploaded => "MASK_GROUP_UPLOADED",
+            WebhookEventType::DataAccessChanged => "DATA_ACCESS_CHANGED",
+            WebhookEventType::StorageQuotaThresholdReached => "STORAGE_QUOTA_THRESHOLD_REACHED",
+        }
+    }
+}
+
+/// 전송 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "webhook_delivery_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WebhookDeliveryStatus {
+    /// 전송 대기 또는 재시도 대기
+    Pending,
+    Succeeded,
+    /// 재시도 횟수를 모두 소진한 dead-letter
+    Dead,
+}
+
+/// Webhook 구독
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WebhookSubscription {
+    pub id: i32,
+    pub project_id: i32,
+    pub name: String,
+    pub url: String,
+    /// 서명 키
+    pub secret: String,
+    /// 받을 이벤트 종류 (비어 있으면 모든 이벤트)
+    pub event_types: Vec<String>,
+    pub is_active: bool,
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 새 Webhook 구독
+#[derive(Debug, Clone)]
+pub struct NewWebhookSubscription {
+    pub project_id: i32,
+    pub name: String,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<WebhookEventType>,
+    pub created_by: i32,
+}
+
+/// Webhook 구독 수정 (None이면 유지)
+#[derive(Debug, Clone, Default)]
+pub struct UpdateWebhookSubscription {
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub event_types: Option<Vec<WebhookEventType>>,
+    pub is_active: Option<bool>,
+}
+
+/// 구독별 이벤트 전송
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub subscription_id: i32,
+    pub event_id: i64,
+    pub event_type: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempt_count: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// 전송 시도 로그
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WebhookDeliveryAttempt {
+    pub id: i64,
+    pub delivery_id: i64,
+    pub attempt_number: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub duration_ms: i32,
+    pub attempted_at: DateTime<Utc>,
+}
+
+/// 디스패처가 전송하기 위해 가져간 전송 작업 (구독 URL/서명 키와 이벤트 포함)
+#[derive(Debug, Clone, FromRow)]
+pub struct ClaimedWebhookDelivery {
+    pub id: i64,
+    pub attempt_count: i32,
+    pub url: String,
+    pub secret: String,
+    pub event_id: i64,
+    pub project_id: i32,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub event_created_at: DateTime<Utc>,
+}
+
+impl ClaimedWebhookDelivery {
+    /// 전송할 본문
+    pub fn body(&self) -> String {
+        serde_json::json!({
+            "id": self.event_id,
+            "event_type": self.event_type,
+            "project_id": self.project_id,
+            "created_at": self.event_created_at,
+            "data": self.payload,
+        })
+        .to_string()
+    }
+}
+
+/// 전송 시도 결과
+#[derive(Debug, Clone)]
+pub struct WebhookAttemptOutcome {
+    /// 응답 상태 코드 (연결 실패/시간 초과면 None)
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub duration_ms: i32,
+    /// 실패 시 다음 시도까지의 시간 (초, None이면 dead-letter)
+    pub retry_in_seconds: Option<i64>,
+}
+
+impl WebhookAttemptOutcome {
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// 전송 재시도 정책 기본값 (최대 8회, 30초부터 두 배씩 최대 1시간)
+pub const WEBHOOK_RETRY_POLICY: RetryPolicy = RetryPolicy::new(8, 30, 3600);
+
+/// 페이로드 서명 (`sha256=` + HMAC-SHA256(secret, "{timestamp}.{body}")의 hex)
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_type_names_match_serialized_values() {
+        for event_type in [
+            WebhookEventType::AnnotationApproved,
+            WebhookEventType::MaskGroupUploaded,
+            WebhookEventType::DataAccessChanged,
+            WebhookEventType::StorageQuotaThresholdReached,
+        ] {
+            assert_eq!(serde_json::to_value(event_type).unwrap(), event_type.as_str());
+            let parsed: WebhookEventType = serde_json::from_value(event_type.as_str().into()).unwrap();
+            assert_eq!(parsed, event_type);
+        }
+        assert!(serde_json::from_value::<WebhookEventType>("annotation_approved".into()).is_err());
+    }
+
+    #[test]
+    fn test_signature_matches_hmac_sha256() {
+        // printf '%s' '1700000000.{"id":1}' | openssl dgst -sha256 -hmac whsec_test
+        let signature = sign_webhook_payload("whsec_test", 1700000000, "{\"id\":1}");
+        assert_eq!(
+            signature,
+            "sha256=2f441ba4b3b2d50d28a9ab9d9fd8880376ecd1eb5d0435401553f5d8d0a5dcf8"
+        );
+        assert_ne!(signature, sign_webhook_payload("whsec_test", 1700000001, "{\"id\":1}"));
+        assert_ne!(signature, sign_webhook_payload("whsec_other", 1700000000, "{\"id\":1}"));
+    }
+}
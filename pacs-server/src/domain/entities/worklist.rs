@@ -0,0 +1,262 @@
+//! 어노테이션 작업 목록(Worklist) 엔티티
+//!
+//! 프로젝트 관리자는 프로젝트 데이터의 Study마다 작업을 만들어 판독자에게 배정하고,
+//! 판독자는 작업을 가져가(claim) 진행한 뒤 완료하거나 건너뜁니다.
+//! 작업 상태는 `TODO → IN_PROGRESS → DONE / SKIPPED` 순서로 진행되며,
+//! 진행 중인 작업을 내려놓으면(release) 다시 TODO가 됩니다.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// 작업 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "worklist_task_status_enum", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WorklistTaskStatus {
+    /// 대기 중
+    Todo,
+    /// 판독자가 작업 중
+    InProgress,
+    /// 완료
+    Done,
+    /// 건너뜀
+    Skipped,
+}
+
+impl WorklistTaskStatus {
+    /// 이 종료 상태로 전환할 수 있는 상태
+    ///
+    /// 완료는 작업을 가져간 뒤에만 가능하고, 건너뛰기는 가져가기 전에도 가능합니다.
+    pub fn closable_from(&self) -> &'static [WorklistTaskStatus] {
+        match self {
+            WorklistTaskStatus::Done => &[WorklistTaskStatus::InProgress],
+            WorklistTaskStatus::Skipped => &[WorklistTaskStatus::Todo, WorklistTaskStatus::InProgress],
+            _ => &[],
+        }
+    }
+}
+
+impl std::fmt::Display for WorklistTaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorklistTaskStatus::Todo => write!(f, "TODO"),
+            WorklistTaskStatus::InProgress => write!(f, "IN_PROGRESS"),
+            WorklistTaskStatus::Done => write!(f, "DONE"),
+            WorklistTaskStatus::Skipped => write!(f, "SKIPPED"),
+        }
+    }
+}
+
+impl std::str::FromStr for WorklistTaskStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().replace('-', "_").as_str() {
+            "TODO" => Ok(WorklistTaskStatus::Todo),
+            "IN_PROGRESS" => Ok(WorklistTaskStatus::InProgress),
+            "DONE" => Ok(WorklistTaskStatus::Done),
+            "SKIPPED" => Ok(WorklistTaskStatus::Skipped),
+            other => Err(format!("Unknown worklist task status: {}", other)),
+        }
+    }
+}
+
+/// 작업 (대상 Study 정보 포함)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WorklistTask {
+    pub id: i32,
+    pub project_id: i32,
+    pub study_id: i32,
+    pub study_uid: String,
+    pub study_description: Option<String>,
+    pub patient_id: Option<String>,
+    pub study_date: Option<NaiveDate>,
+    /// 배정된(또는 작업을 가져간) 판독자 ID
+    pub assignee_id: Option<i32>,
+    pub status: WorklistTaskStatus,
+    pub priority: i32,
+    pub note: Option<String>,
+    /// 종료된 작업 구간의 누적 소요 시간 (초)
+    pub time_spent_seconds: i64,
+    /// 현재 작업 구간 시작 시간 (IN_PROGRESS일 때만)
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WorklistTask {
+    /// 진행 중인 구간까지 포함한 총 소요 시간 (초)
+    pub fn elapsed_seconds(&self, now: DateTime<Utc>) -> i64 {
+        let running = self
+            .started_at
+            .map(|started_at| (now - started_at).num_seconds().max(0))
+            .unwrap_or(0);
+        self.time_spent_seconds + running
+    }
+}
+
+/// 새 작업
+#[derive(Debug, Clone)]
+pub struct NewWorklistTask {
+    pub study_id: i32,
+    pub assignee_id: Option<i32>,
+}
+
+/// 작업을 만들 Study 선택 조건
+///
+/// 조건은 모두 AND로 결합되며, 이미 작업이 있는 Study는 항상 제외됩니다.
+#[derive(Debug, Clone, Default)]
+pub struct WorklistStudyFilter {
+    /// 직접 지정한 Study ID
+    pub study_ids: Option<Vec<i32>>,
+    pub study_date_from: Option<NaiveDate>,
+    pub study_date_to: Option<NaiveDate>,
+    /// Series 중 하나라도 이 Modality를 가진 Study
+    pub modality: Option<String>,
+    /// Study 설명 부분 일치 (대소문자 무시)
+    pub study_description: Option<String>,
+    pub patient_id: Option<String>,
+}
+
+/// 작업 목록 조회 조건
+#[derive(Debug, Clone, Default)]
+pub struct WorklistTaskQuery {
+    pub status: Option<WorklistTaskStatus>,
+    pub assignee_id: Option<i32>,
+    /// 배정되지 않은 작업만
+    pub unassigned: bool,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+/// 완료된 작업에 연결된 결과물
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorklistTaskResults {
+    pub annotation_ids: Vec<i32>,
+    pub mask_group_ids: Vec<i32>,
+}
+
+impl WorklistTaskResults {
+    /// ID를 정렬하고 중복을 제거
+    pub fn normalized(mut self) -> Self {
+        self.annotation_ids.sort_unstable();
+        self.annotation_ids.dedup();
+        self.mask_group_ids.sort_unstable();
+        self.mask_group_ids.dedup();
+        self
+    }
+}
+
+/// 판독자별 작업 진행 현황 (`assignee_id`가 None이면 배정되지 않은 작업)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, FromRow)]
+pub struct WorklistProgress {
+    pub assignee_id: Option<i32>,
+    pub todo: i64,
+    pub in_progress: i64,
+    pub done: i64,
+    pub skipped: i64,
+    /// 진행 중인 구간을 포함한 총 소요 시간 (초)
+    pub time_spent_seconds: i64,
+}
+
+impl WorklistProgress {
+    pub fn total(&self) -> i64 {
+        self.todo + self.in_progress + self.done + self.skipped
+    }
+
+    /// 여러 판독자의 진행 현황 합계
+    pub fn sum<'a>(rows: impl IntoIterator<Item = &'a WorklistProgress>) -> WorklistProgress {
+        rows.into_iter().fold(WorklistProgress::default(), |mut acc, row| {
+            acc.todo += row.todo;
+            acc.in_progress += row.in_progress;
+            acc.done += row.done;
+            acc.skipped += row.skipped;
+            acc.time_spent_seconds += row.time_spent_seconds;
+            acc
+        })
+    }
+}
+
+/// 작업을 판독자에게 순서대로 돌아가며 배정 (판독자가 없으면 배정하지 않음)
+pub fn distribute_round_robin(study_ids: &[i32], assignee_ids: &[i32]) -> Vec<NewWorklistTask> {
+    study_ids
+        .iter()
+        .enumerate()
+        .map(|(index, &study_id)| NewWorklistTask {
+            study_id,
+            assignee_id: if assignee_ids.is_empty() {
+                None
+            } else {
+                Some(assignee_ids[index % assignee_ids.len()])
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_status_parsing() {
+        assert_eq!("in-progress".parse::<WorklistTaskStatus>(), Ok(WorklistTaskStatus::InProgress));
+        assert_eq!("DONE".parse::<WorklistTaskStatus>(), Ok(WorklistTaskStatus::Done));
+        assert!("finished".parse::<WorklistTaskStatus>().is_err());
+        assert_eq!(WorklistTaskStatus::Skipped.closable_from(), &[WorklistTaskStatus::Todo, WorklistTaskStatus::InProgress]);
+        assert!(WorklistTaskStatus::InProgress.closable_from().is_empty());
+    }
+
+    #[test]
+    fn test_round_robin_distribution() {
+        let tasks = distribute_round_robin(&[10, 11, 12], &[1, 2]);
+        let assignees: Vec<_> = tasks.iter().map(|t| t.assignee_id).collect();
+        assert_eq!(assignees, vec![Some(1), Some(2), Some(1)]);
+
+        let tasks = distribute_round_robin(&[10], &[]);
+        assert_eq!(tasks[0].assignee_id, None);
+    }
+
+    #[test]
+    fn test_progress_sum_and_results() {
+        let rows = vec![
+            WorklistProgress { assignee_id: Some(1), todo: 1, in_progress: 1, done: 2, skipped: 0, time_spent_seconds: 60 },
+            WorklistProgress { assignee_id: None, todo: 3, in_progress: 0, done: 0, skipped: 1, time_spent_seconds: 0 },
+        ];
+        let total = WorklistProgress::sum(&rows);
+        assert_eq!(total.total(), 8);
+        assert_eq!(total.time_spent_seconds, 60);
+
+        let results = WorklistTaskResults { annotation_ids: vec![3, 1, 3], mask_group_ids: vec![] }.normalized();
+        assert_eq!(results.annotation_ids, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_elapsed_seconds_includes_running_segment() {
+        let now = Utc::now();
+        let task = WorklistTask {
+            id: 1,
+            project_id: 1,
+            study_id: 1,
+            study_uid: "1.2.3".to_string(),
+            study_description: None,
+            patient_id: None,
+            study_date: None,
+            assignee_id: Some(1),
+            status: WorklistTaskStatus::InProgress,
+            priority: 0,
+            note: None,
+            time_spent_seconds: 100,
+            started_at: Some(now - Duration::seconds(20)),
+            completed_at: None,
+            created_by: None,
+            created_at: now,
+            updated_at: now,
+        };
+        assert_eq!(task.elapsed_seconds(now), 120);
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::{HashMap, HashSet};
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::domain::entities::annotation_bundle::{BundleAnnotation, BundleHistory, BundleImportPlan, BundleMaskGroup};
+use crate::domain::ServiceError;
+
+/// 어노테이션 번들 내보내기 / 가져오기 리포지토리
+#[async_trait]
+pub trait AnnotationBundleRepository: Send + Sync {
+    /// 프로젝트 이름 조회
+    async fn find_project_name(&self, project_id: i32) -> Result<Option<String>, ServiceError>;
+
+    /// 프로젝트 이름으로 프로젝트 ID 조회
+    async fn find_project_id_by_name(&self, name: &str) -> Result<Option<i32>, ServiceError>;
+
+    /// 프로젝트 멤버이거나 전역 관리자(SUPER_ADMIN, ADMIN)인지 확인
+    async fn has_project_access(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 프로젝트에서 `viewer_id`가 볼 수 있는 (공유되었거나 본인이 작성한) 어노테이션을
+    /// ID 순으로 `after_id` 다음부터 최대 `limit`개 조회
+    async fn export_annotations(&self, project_id: i32, viewer_id: i32, after_id: i32, limit: i64) -> Result<Vec<BundleAnnotation>, ServiceError>;
+
+    /// 어노테이션들의 히스토리 조회 (시간순)
+    async fn export_history(&self, annotation_ids: &[i32]) -> Result<Vec<BundleHistory>, ServiceError>;
+
+    /// 어노테이션들의 마스크 그룹 메타데이터 조회
+    async fn export_mask_groups(&self, annotation_ids: &[i32]) -> Result<Vec<BundleMaskGroup>, ServiceError>;
+
+    /// 사용자명 → 사용자 ID 매핑 조회 (존재하는 사용자만 포함)
+    async fn find_user_ids(&self, usernames: &[String]) -> Result<HashMap<String, i32>, ServiceError>;
+
+    /// 이미 존재하는 어노테이션 external_id → 프로젝트 ID 매핑 조회
+    async fn find_existing_annotations(&self, external_ids: &[Uuid]) -> Result<HashMap<Uuid, i32>, ServiceError>;
+
+    /// 이미 존재하는 마스크 그룹 external_id 조회
+    async fn find_existing_mask_groups(&self, external_ids: &[Uuid]) -> Result<HashSet<Uuid>, ServiceError>;
+
+    /// 임포트 계획을 하나의 트랜잭션으로 적용
+    async fn apply_import(&self, plan: &BundleImportPlan) -> Result<(), ServiceError>;
+}
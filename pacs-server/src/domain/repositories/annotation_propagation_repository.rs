@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use crate::domain::entities::annotation_propagation::{AnnotationPropagation, AnnotationPropagationPlan, LesionTimepoint};
+use crate::domain::entities::project_data::{ProjectDataSeries, ProjectDataStudy};
+use crate::domain::entities::Annotation;
+use crate::domain::ServiceError;
+
+/// 어노테이션 전파 / 병변 추적 저장소
+#[async_trait]
+pub trait AnnotationPropagationRepository: Send + Sync {
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 프로젝트에 등록된 Study
+    async fn find_study(&self, project_id: i32, study_uid: &str) -> Result<Option<ProjectDataStudy>, ServiceError>;
+
+    /// Study의 Series 목록
+    async fn list_series(&self, study_id: i32) -> Result<Vec<ProjectDataSeries>, ServiceError>;
+
+    /// 프로젝트의 활성 어노테이션 (ID 목록)
+    async fn find_annotations(&self, project_id: i32, annotation_ids: &[i32]) -> Result<Vec<Annotation>, ServiceError>;
+
+    /// 대상 Study로 이미 전파한 원본 어노테이션 ID
+    async fn find_propagated_sources(&self, source_annotation_ids: &[i32], target_study_uid: &str) -> Result<Vec<i32>, ServiceError>;
+
+    /// 인스턴스 메타데이터가 하나뿐인 Series의 (Series UID, 인스턴스 UID)
+    async fn find_single_instances(&self, project_id: i32, series_uids: &[String]) -> Result<Vec<(String, String)>, ServiceError>;
+
+    /// 계획대로 원본을 대상 Study에 DRAFT로 복사하고 원본과 연결 (하나라도 실패하면 모두 취소)
+    async fn propagate(
+        &self,
+        target_study_uid: &str,
+        plans: &[AnnotationPropagationPlan],
+        propagated_by: i32,
+    ) -> Result<Vec<(Annotation, AnnotationPropagation)>, ServiceError>;
+
+    /// 환자의 Study에 있는 활성 어노테이션 (병변별, 검사일 순)
+    async fn list_lesion_timepoints(
+        &self,
+        project_id: i32,
+        patient_id: &str,
+        lesion_id: Option<i32>,
+    ) -> Result<Vec<LesionTimepoint>, ServiceError>;
+}
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use sqlx::PgPool;
-use crate::domain::entities::{Annotation, AnnotationHistory, NewAnnotation};
+use crate::domain::entities::{Annotation, AnnotationHistory, AnnotationReviewComment, AnnotationReviewStatus, AnnotationSearchCriteria, NewAnnotation};
 
 #[async_trait]
 pub trait AnnotationRepository: Send + Sync {
@@ -13,16 +13,28 @@ pub trait AnnotationRepository: Send + Sync {
     async fn find_by_project_and_study(&self, project_id: i32, study_uid: &str) -> Result<Vec<Annotation>, sqlx::Error>;
     async fn find_shared_annotations(&self, project_id: i32) -> Result<Vec<Annotation>, sqlx::Error>;
     
-    // viewer_software 필터링 메서드들
-    async fn find_by_user_id_with_viewer(&self, user_id: i32, viewer_software: Option<&str>) -> Result<Vec<Annotation>, sqlx::Error>;
-    async fn find_by_project_id_with_viewer(&self, project_id: i32, viewer_software: Option<&str>) -> Result<Vec<Annotation>, sqlx::Error>;
-    async fn find_by_study_uid_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>) -> Result<Vec<Annotation>, sqlx::Error>;
+    // viewer_software, review_status 필터링 메서드들
+    async fn find_by_user_id_with_viewer(&self, user_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, sqlx::Error>;
+    async fn find_by_project_id_with_viewer(&self, project_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, sqlx::Error>;
+    async fn find_by_study_uid_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, sqlx::Error>;
     async fn create(&self, new_annotation: NewAnnotation) -> Result<Annotation, sqlx::Error>;
     async fn update(&self, id: i32, data: serde_json::Value, is_shared: bool) -> Result<Option<Annotation>, sqlx::Error>;
     async fn update_with_measurements(&self, id: i32, data: serde_json::Value, is_shared: bool, measurement_values: Option<serde_json::Value>) -> Result<Option<Annotation>, sqlx::Error>;
-    async fn delete(&self, id: i32) -> Result<bool, sqlx::Error>;
+    /// 어노테이션을 휴지통으로 이동 (하위 마스크 그룹 포함)
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<bool, sqlx::Error>;
     async fn create_history(&self, annotation_id: i32, user_id: i32, action: &str, data_before: Option<serde_json::Value>, data_after: Option<serde_json::Value>) -> Result<AnnotationHistory, sqlx::Error>;
     async fn get_history(&self, annotation_id: i32) -> Result<Vec<AnnotationHistory>, sqlx::Error>;
+
+    // 검토 워크플로우 메서드들
+    /// 검토 상태를 `from`에서 `to`로 전환 (현재 상태가 `from`이 아니면 None)
+    async fn update_review_status(&self, id: i32, actor_id: i32, from: AnnotationReviewStatus, to: AnnotationReviewStatus, comment: Option<&str>) -> Result<Option<Annotation>, sqlx::Error>;
+    async fn assign_reviewer(&self, id: i32, actor_id: i32, reviewer_id: i32) -> Result<Option<Annotation>, sqlx::Error>;
+    async fn find_by_reviewer(&self, reviewer_id: i32, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, sqlx::Error>;
+    async fn create_review_comment(&self, annotation_id: i32, user_id: i32, review_status: AnnotationReviewStatus, comment: &str) -> Result<AnnotationReviewComment, sqlx::Error>;
+    async fn get_review_comments(&self, annotation_id: i32) -> Result<Vec<AnnotationReviewComment>, sqlx::Error>;
+
+    /// 필터/정렬/키셋 페이지네이션을 조합한 통합 검색 (최대 `criteria.limit`개 반환)
+    async fn search(&self, criteria: &AnnotationSearchCriteria) -> Result<Vec<Annotation>, sqlx::Error>;
     fn pool(&self) -> &PgPool;
 }
 
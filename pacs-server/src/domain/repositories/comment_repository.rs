@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use crate::domain::entities::comment::{Comment, CommentHistory, CommentTargetType, NewComment};
+use crate::domain::ServiceError;
+
+#[async_trait]
+pub trait CommentRepository: Send + Sync {
+    /// 코멘트 생성
+    async fn create(&self, new_comment: &NewComment) -> Result<Comment, ServiceError>;
+
+    /// ID로 코멘트 조회
+    async fn get_by_id(&self, id: i32) -> Result<Option<Comment>, ServiceError>;
+
+    /// 대상(어노테이션 / 마스크 그룹)의 코멘트 목록 조회 (작성 순)
+    async fn list_by_target(&self, target_type: CommentTargetType, target_id: i32) -> Result<Vec<Comment>, ServiceError>;
+
+    /// 코멘트 본문 수정 (이력 기록)
+    async fn update_body(&self, id: i32, user_id: i32, body: &str, mentions: &[i32]) -> Result<Comment, ServiceError>;
+
+    /// 코멘트 소프트 삭제 (이력 기록)
+    async fn soft_delete(&self, id: i32, user_id: i32) -> Result<(), ServiceError>;
+
+    /// 스레드 해결 상태 변경 (이력 기록)
+    async fn set_resolved(&self, id: i32, user_id: i32, resolved: bool) -> Result<Comment, ServiceError>;
+
+    /// 코멘트 변경 이력 조회 (최신순)
+    async fn get_history(&self, id: i32) -> Result<Vec<CommentHistory>, ServiceError>;
+}
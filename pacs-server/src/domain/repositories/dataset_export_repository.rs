@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use crate::domain::entities::dataset_export::{
+    DatasetAnnotationRow, DatasetExportCounts, DatasetExportFilter, DatasetExportJob, DatasetMaskRow,
+    NewDatasetExportJob,
+};
+use crate::domain::ServiceError;
+
+#[async_trait]
+pub trait DatasetExportRepository: Send + Sync {
+    /// 프로젝트 이름 조회 (프로젝트가 없으면 None)
+    async fn find_project_name(&self, project_id: i32) -> Result<Option<String>, ServiceError>;
+
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 내보내기 작업 생성 (PENDING 상태)
+    async fn create(&self, new_job: &NewDatasetExportJob) -> Result<DatasetExportJob, ServiceError>;
+
+    /// ID로 내보내기 작업 조회
+    async fn get_by_id(&self, id: i32) -> Result<Option<DatasetExportJob>, ServiceError>;
+
+    /// 프로젝트의 내보내기 작업 목록 조회 (최신순)
+    async fn list_by_project(&self, project_id: i32) -> Result<Vec<DatasetExportJob>, ServiceError>;
+
+    /// 작업을 RUNNING 상태로 변경
+    async fn mark_running(&self, id: i32) -> Result<(), ServiceError>;
+
+    /// 작업을 COMPLETED 상태로 변경하고 결과 기록
+    async fn mark_completed(
+        &self,
+        id: i32,
+        output_path: &str,
+        counts: DatasetExportCounts,
+    ) -> Result<DatasetExportJob, ServiceError>;
+
+    /// 작업을 FAILED 상태로 변경하고 에러 메시지 기록
+    async fn mark_failed(&self, id: i32, error_message: &str) -> Result<(), ServiceError>;
+
+    /// 필터에 맞는 어노테이션 조회 (요청자가 볼 수 있는 공유/본인 어노테이션만, ID 순)
+    async fn find_annotations(
+        &self,
+        project_id: i32,
+        filter: &DatasetExportFilter,
+        viewer_id: Option<i32>,
+    ) -> Result<Vec<DatasetAnnotationRow>, ServiceError>;
+
+    /// 필터에 맞는 마스크 슬라이스 조회 (요청자가 볼 수 있는 어노테이션의 마스크만, 그룹/슬라이스 순)
+    async fn find_masks(
+        &self,
+        project_id: i32,
+        filter: &DatasetExportFilter,
+        viewer_id: Option<i32>,
+    ) -> Result<Vec<DatasetMaskRow>, ServiceError>;
+}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use crate::domain::entities::dataset_export::DatasetExportFilter;
+use crate::domain::entities::dataset_release::{
+    DatasetRelease, NewDatasetRelease, ReleaseAnnotationSnapshot, ReleaseMaskSource,
+};
+use crate::domain::ServiceError;
+
+#[async_trait]
+pub trait DatasetReleaseRepository: Send + Sync {
+    /// 프로젝트 이름 조회 (프로젝트가 없으면 None)
+    async fn find_project_name(&self, project_id: i32) -> Result<Option<String>, ServiceError>;
+
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 이름으로 릴리스 조회
+    async fn find_by_name(&self, project_id: i32, name: &str) -> Result<Option<DatasetRelease>, ServiceError>;
+
+    /// 선택 조건에 맞는 어노테이션의 현재 버전을 히스토리 ID로 고정합니다.
+    ///
+    /// 데이터를 변경하지 않습니다. 현재 `data`와 일치하는 히스토리가 없으면 히스토리 ID만 예약하고
+    /// `snapshot_pending`으로 표시하며, 실제 `release_snapshot` 히스토리는 `create`에서 기록됩니다.
+    async fn pin_annotations(
+        &self,
+        project_id: i32,
+        filter: &DatasetExportFilter,
+        annotation_ids: Option<&[i32]>,
+        viewer_id: i32,
+    ) -> Result<Vec<ReleaseAnnotationSnapshot>, ServiceError>;
+
+    /// 선택 조건에 맞는 마스크 조회
+    async fn find_masks(
+        &self,
+        project_id: i32,
+        filter: &DatasetExportFilter,
+        annotation_ids: Option<&[i32]>,
+        viewer_id: i32,
+    ) -> Result<Vec<ReleaseMaskSource>, ServiceError>;
+
+    /// 대기 중인 스냅샷 히스토리, 릴리스, 고정 항목을 한 트랜잭션으로 저장 (같은 이름이 있으면 AlreadyExists)
+    async fn create(&self, release: &NewDatasetRelease) -> Result<DatasetRelease, ServiceError>;
+
+    /// ID로 릴리스 조회
+    async fn get_by_id(&self, id: i32) -> Result<Option<DatasetRelease>, ServiceError>;
+
+    /// 프로젝트의 릴리스 목록 조회 (최신순)
+    async fn list_by_project(&self, project_id: i32) -> Result<Vec<DatasetRelease>, ServiceError>;
+
+    /// 릴리스에 고정된 어노테이션 ID → 히스토리 ID
+    async fn get_annotation_pins(&self, release_id: i32) -> Result<HashMap<i32, i32>, ServiceError>;
+
+    /// 릴리스에 고정된 마스크 ID → 체크섬
+    async fn get_mask_pins(&self, release_id: i32) -> Result<HashMap<i32, String>, ServiceError>;
+}
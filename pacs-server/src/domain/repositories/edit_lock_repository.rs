@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use crate::domain::entities::edit_lock::{EditLock, EditLockAttempt, NewEditLock};
+use crate::domain::ServiceError;
+
+/// 만료된 잠금은 조회/갱신/해제 대상이 아니며, 획득 시 정리됩니다.
+#[async_trait]
+pub trait EditLockRepository: Send + Sync {
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 프로젝트의 활성 어노테이션이 속한 Study UID
+    async fn find_annotation_study(&self, project_id: i32, annotation_id: i32) -> Result<Option<String>, ServiceError>;
+
+    /// 잠금 획득 (이미 가진 잠금이면 만료 시간만 갱신)
+    ///
+    /// 같은 Study의 잠금 획득은 직렬화되며, 다른 사용자의 잠금과 충돌하면 그 잠금을 반환합니다.
+    async fn acquire(&self, new_lock: &NewEditLock) -> Result<EditLockAttempt, ServiceError>;
+
+    /// 유효한 본인 잠금의 만료 시간 연장
+    async fn heartbeat(&self, project_id: i32, lock_id: i32, holder_id: i32, ttl_seconds: i64) -> Result<Option<EditLock>, ServiceError>;
+
+    /// 유효한 잠금 해제 (`holder_id`가 있으면 본인 잠금만, `released_by`는 해제한 사용자)
+    async fn release(&self, project_id: i32, lock_id: i32, holder_id: Option<i32>, released_by: i32) -> Result<Option<EditLock>, ServiceError>;
+
+    async fn find_active(&self, project_id: i32, lock_id: i32) -> Result<Option<EditLock>, ServiceError>;
+
+    /// 프로젝트의 유효한 잠금 목록 (Study 지정 가능)
+    async fn list_active(&self, project_id: i32, study_uid: Option<&str>) -> Result<Vec<EditLock>, ServiceError>;
+}
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use crate::domain::entities::label::{Label, LabelDefinition, LabelImportItem, LabelMigrationResult, LabelUsage, NewLabel};
+use crate::domain::ServiceError;
+
+#[async_trait]
+pub trait LabelRepository: Send + Sync {
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 프로젝트 라벨 목록 (이름순)
+    async fn list_by_project(&self, project_id: i32) -> Result<Vec<Label>, ServiceError>;
+
+    async fn create(&self, new_label: &NewLabel) -> Result<Label, ServiceError>;
+
+    /// 라벨 수정 (없으면 None)
+    async fn update(
+        &self,
+        project_id: i32,
+        label_id: i32,
+        definition: &LabelDefinition,
+        parent_id: Option<i32>,
+    ) -> Result<Option<Label>, ServiceError>;
+
+    /// 라벨 삭제 (삭제되지 않았으면 false)
+    async fn delete(&self, project_id: i32, label_id: i32) -> Result<bool, ServiceError>;
+
+    /// 가져오기 항목을 한 트랜잭션으로 추가/수정하고 상위 라벨을 이름으로 연결합니다.
+    async fn import(&self, project_id: i32, items: &[LabelImportItem]) -> Result<(), ServiceError>;
+
+    /// 프로젝트의 활성 마스크/어노테이션에서 사용 중인 라벨과 사용 횟수
+    async fn list_usage(&self, project_id: i32) -> Result<Vec<LabelUsage>, ServiceError>;
+
+    /// 자유 입력 라벨을 정식 라벨 이름으로 일괄 변경합니다. (`(기존 라벨, 정식 이름)` 목록)
+    ///
+    /// 마스크 `label_name`과 어노테이션 `data.label`을 바꾸고 어노테이션 히스토리를 남깁니다.
+    /// 잠긴 어노테이션과 그 마스크는 변경하지 않습니다.
+    async fn apply_mappings(
+        &self,
+        project_id: i32,
+        mappings: &[(String, String)],
+        actor_id: i32,
+    ) -> Result<LabelMigrationResult, ServiceError>;
+}
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use crate::domain::entities::mask_group::{MaskGroup, NewMaskGroup, UpdateMaskGroup, MaskGroupStats};
 use crate::domain::entities::mask::Mask;
+use crate::domain::entities::mask_group_lineage::MaskGroupLineageNode;
 use crate::domain::ServiceError;
 
 #[async_trait]
@@ -14,8 +15,8 @@ pub trait MaskGroupRepository: Send + Sync {
     /// 마스크 그룹 업데이트
     async fn update(&self, id: i32, update_mask_group: &UpdateMaskGroup) -> Result<MaskGroup, ServiceError>;
     
-    /// 마스크 그룹 삭제
-    async fn delete(&self, id: i32) -> Result<(), ServiceError>;
+    /// 마스크 그룹을 휴지통으로 이동
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<(), ServiceError>;
     
     /// 마스크 그룹 목록 조회
     async fn list(
@@ -42,4 +43,12 @@ pub trait MaskGroupRepository: Send + Sync {
         modality: Option<String>,
         mask_type: Option<String>,
     ) -> Result<i64, ServiceError>;
-}
\ No newline at end of file
+
+    /// 어노테이션의 마스크 그룹 계보 조회 (휴지통에 있는 그룹 제외, ID 순)
+    async fn find_lineage(&self, annotation_id: i32) -> Result<Vec<MaskGroupLineageNode>, ServiceError>;
+
+    /// 파생 원본 설정 또는 해제
+    ///
+    /// 원본이 같은 어노테이션에 없거나 순환이 생기면 변경하지 않고 None을 반환합니다.
+    async fn set_derived_from(&self, id: i32, derived_from_id: Option<i32>) -> Result<Option<MaskGroup>, ServiceError>;
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use crate::domain::entities::mask_import::{MaskImportJob, NewMaskImportJob};
+use crate::domain::ServiceError;
+
+#[async_trait]
+pub trait MaskImportRepository: Send + Sync {
+    /// 임포트 작업 생성 (PENDING 상태)
+    async fn create(&self, new_job: &NewMaskImportJob) -> Result<MaskImportJob, ServiceError>;
+
+    /// ID로 임포트 작업 조회
+    async fn get_by_id(&self, id: i32) -> Result<Option<MaskImportJob>, ServiceError>;
+
+    /// 어노테이션의 임포트 작업 목록 조회 (최신순)
+    async fn list_by_annotation(&self, annotation_id: i32) -> Result<Vec<MaskImportJob>, ServiceError>;
+
+    /// 작업을 RUNNING 상태로 변경
+    async fn mark_running(&self, id: i32) -> Result<(), ServiceError>;
+
+    /// 작업을 COMPLETED 상태로 변경하고 결과 기록
+    async fn mark_completed(
+        &self,
+        id: i32,
+        mask_group_id: i32,
+        total_frames: i32,
+        imported_masks: i32,
+        unmapped_frames: serde_json::Value,
+    ) -> Result<MaskImportJob, ServiceError>;
+
+    /// 작업을 FAILED 상태로 변경하고 에러 메시지 기록
+    ///
+    /// 임포트 중 만든 마스크 그룹(`created_mask_group_id`)이 있으면 같은 트랜잭션에서 삭제하고,
+    /// 이미 업로드된 슬라이스 객체는 Object Storage 삭제 대기열에 예약합니다.
+    async fn mark_failed(
+        &self,
+        id: i32,
+        created_mask_group_id: Option<i32>,
+        error_message: &str,
+    ) -> Result<(), ServiceError>;
+}
@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::mask_preview::{ContactSheetTask, MaskGroupContactSheet, MaskPreviewTask};
+use crate::domain::ServiceError;
+
+/// 마스크 미리보기 저장소
+///
+/// 검증을 통과했고 미리보기가 `PENDING`인 마스크와, contact sheet가 `PENDING`인 마스크 그룹을
+/// 미리보기 작업이 가져가 결과를 기록합니다.
+#[async_trait]
+pub trait MaskPreviewRepository: Send + Sync {
+    /// 미리보기를 만들 때가 된 마스크를 가져오고 `lease_seconds` 동안 다른 작업이 가져가지 못하게 함
+    async fn claim_pending_masks(&self, limit: i64, lease_seconds: i64) -> Result<Vec<MaskPreviewTask>, ServiceError>;
+
+    /// 썸네일/미리보기 생성 완료 (가져간 뒤 마스크가 바뀌었으면 기록하지 않고 false)
+    ///
+    /// 마스크 그룹의 contact sheet를 다시 만들도록 함께 표시합니다.
+    async fn mark_mask_ready(&self, task: &MaskPreviewTask, thumbnail_key: &str, preview_key: &str) -> Result<bool, ServiceError>;
+
+    /// 마스크 미리보기 생성 실패 기록 (`retry_in_seconds`가 None이면 `FAILED`)
+    async fn record_mask_failure(
+        &self,
+        mask_id: i32,
+        lease_until: DateTime<Utc>,
+        error: &str,
+        retry_in_seconds: Option<i64>,
+    ) -> Result<(), ServiceError>;
+
+    /// contact sheet를 만들 때가 된 마스크 그룹을 가져옴 (미리보기를 기다리는 마스크가 남은 그룹은 제외)
+    async fn claim_contact_sheets(&self, limit: i64, lease_seconds: i64) -> Result<Vec<ContactSheetTask>, ServiceError>;
+
+    /// 마스크 그룹의 썸네일 경로 (슬라이스 순서, 최대 `limit`개)
+    async fn find_thumbnail_keys(&self, mask_group_id: i32, limit: i64) -> Result<Vec<String>, ServiceError>;
+
+    /// contact sheet 생성 완료 (썸네일이 없으면 `contact_sheet_key`는 None, 그 사이 다시 표시되었으면 false)
+    async fn mark_contact_sheet_ready(
+        &self,
+        task: &ContactSheetTask,
+        contact_sheet_key: Option<&str>,
+        mask_count: i32,
+    ) -> Result<bool, ServiceError>;
+
+    /// contact sheet 생성 실패 기록 (`retry_in_seconds`가 None이면 `FAILED`)
+    async fn record_contact_sheet_failure(
+        &self,
+        mask_group_id: i32,
+        lease_until: DateTime<Utc>,
+        error: &str,
+        retry_in_seconds: Option<i64>,
+    ) -> Result<(), ServiceError>;
+
+    /// 마스크 그룹의 contact sheet 상태
+    async fn find_contact_sheet(&self, mask_group_id: i32) -> Result<Option<MaskGroupContactSheet>, ServiceError>;
+}
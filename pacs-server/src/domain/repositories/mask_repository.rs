@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use crate::domain::entities::mask::{Mask, NewMask, UpdateMask, MaskStats};
+use crate::domain::entities::mask_preview::MaskPreview;
 use crate::domain::ServiceError;
 
 #[async_trait]
@@ -13,7 +14,7 @@ pub trait MaskRepository: Send + Sync {
     /// 마스크 업데이트
     async fn update(&self, id: i32, update_mask: &UpdateMask) -> Result<Mask, ServiceError>;
     
-    /// 마스크 삭제
+    /// 마스크 삭제 (같은 트랜잭션에서 마스크 파일 삭제를 예약)
     async fn delete(&self, id: i32) -> Result<(), ServiceError>;
     
     /// 마스크 목록 조회
@@ -38,4 +39,7 @@ pub trait MaskRepository: Send + Sync {
         label_name: Option<String>,
         mime_type: Option<String>,
     ) -> Result<i64, ServiceError>;
-}
\ No newline at end of file
+    
+    /// 마스크들의 썸네일/미리보기 객체 조회
+    async fn find_previews(&self, mask_ids: &[i32]) -> Result<Vec<MaskPreview>, ServiceError>;
+}
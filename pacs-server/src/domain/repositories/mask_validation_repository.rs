@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::mask_validation::{
+    MaskGroupLabelVolume, MaskValidation, MaskValidationOutcome, MaskValidationTask,
+};
+use crate::domain::ServiceError;
+
+/// 마스크 서버 검증 저장소
+///
+/// 새 마스크와 파일이 바뀐 마스크는 `PENDING` 상태가 되며, 검증 작업이 가져가 결과를 기록합니다.
+#[async_trait]
+pub trait MaskValidationRepository: Send + Sync {
+    /// 검증할 때가 된 마스크를 가져오고 `lease_seconds` 동안 다른 작업이 가져가지 못하게 함
+    async fn claim_pending(&self, limit: i64, lease_seconds: i64) -> Result<Vec<MaskValidationTask>, ServiceError>;
+
+    /// 참조 인스턴스의 크기 `(rows, columns)` (메타데이터가 없거나 크기를 모르면 None)
+    async fn find_instance_dimensions(&self, project_id: i32, sop_instance_uid: &str) -> Result<Option<(i32, i32)>, ServiceError>;
+
+    /// 검사 결과 기록 (가져간 뒤 마스크가 바뀌었으면 기록하지 않고 false)
+    ///
+    /// 실제 파일 크기가 다르면 크기와 저장 용량 사용량을 함께 고치고, 체크섬이 없으면 계산한 SHA-256을 채웁니다.
+    async fn complete(&self, task: &MaskValidationTask, outcome: &MaskValidationOutcome) -> Result<bool, ServiceError>;
+
+    /// 객체를 읽지 못한 시도 기록 (`retry_in_seconds`가 None이면 `ERROR`)
+    async fn record_failure(
+        &self,
+        mask_id: i32,
+        lease_until: DateTime<Utc>,
+        error: &str,
+        retry_in_seconds: Option<i64>,
+    ) -> Result<(), ServiceError>;
+
+    /// 마스크 그룹의 마스크별 검증 결과 (슬라이스 순서)
+    async fn find_by_mask_group(&self, mask_group_id: i32) -> Result<Vec<MaskValidation>, ServiceError>;
+
+    /// 마스크 그룹의 라벨별 복셀 수 합계 (검증을 통과한 마스크만)
+    async fn label_volumes(&self, mask_group_id: i32) -> Result<Vec<MaskGroupLabelVolume>, ServiceError>;
+
+    /// 마스크 그룹의 마스크를 다시 검증하도록 `PENDING`으로 되돌림 (되돌린 마스크 수 반환)
+    async fn requeue_mask_group(&self, mask_group_id: i32) -> Result<u64, ServiceError>;
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use crate::domain::entities::measurement::{AnnotationMeasurement, InstanceMetadata, NewInstanceMetadata};
+use crate::domain::entities::Annotation;
+use crate::domain::ServiceError;
+
+/// 인스턴스 메타데이터 / 측정값 계산 결과 저장소
+#[async_trait]
+pub trait MeasurementRepository: Send + Sync {
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 인스턴스 메타데이터 등록 (이미 있으면 갱신)
+    async fn upsert_instance_metadata(
+        &self,
+        project_id: i32,
+        instances: &[NewInstanceMetadata],
+        updated_by: i32,
+    ) -> Result<Vec<InstanceMetadata>, ServiceError>;
+
+    /// 프로젝트의 인스턴스 메타데이터 (Study / Series 지정 가능)
+    async fn list_instance_metadata(
+        &self,
+        project_id: i32,
+        study_uid: Option<&str>,
+        series_uid: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<InstanceMetadata>, ServiceError>;
+
+    /// 프로젝트의 활성 어노테이션
+    async fn find_annotation(&self, project_id: i32, annotation_id: i32) -> Result<Option<Annotation>, ServiceError>;
+
+    /// 재계산할 활성 어노테이션 (ID 순, `after_id` 다음부터, `only_missing`이면 계산 결과가 없는 것만)
+    async fn list_annotations_for_recompute(
+        &self,
+        project_id: i32,
+        only_missing: bool,
+        after_id: i32,
+        limit: i64,
+    ) -> Result<Vec<Annotation>, ServiceError>;
+
+    /// 지정한 Series의 활성 어노테이션
+    async fn list_annotations_by_series(&self, project_id: i32, series_uids: &[String]) -> Result<Vec<Annotation>, ServiceError>;
+
+    async fn find_measurement(&self, annotation_id: i32) -> Result<Option<AnnotationMeasurement>, ServiceError>;
+
+    /// 측정값을 다시 계산해서 저장
+    async fn recompute(&self, annotation: &Annotation) -> Result<AnnotationMeasurement, ServiceError>;
+
+    /// 클라이언트 측정값과 차이가 있는 활성 어노테이션의 계산 결과 (최근 계산순)
+    async fn list_discrepancies(&self, project_id: i32, limit: i64) -> Result<Vec<AnnotationMeasurement>, ServiceError>;
+}
@@ -5,8 +5,27 @@ mod permission_repository;
 mod capability_repository;
 mod access_log_repository;
 mod annotation_repository;
+mod annotation_bundle_repository;
+mod dataset_export_repository;
+mod dataset_release_repository;
+mod trash_repository;
+mod label_repository;
+mod worklist_repository;
+mod edit_lock_repository;
+mod realtime_repository;
+mod webhook_repository;
+mod measurement_repository;
+mod annotation_propagation_repository;
+mod multipart_upload_repository;
+mod storage_deletion_repository;
+mod storage_reconciliation_repository;
+mod storage_quota_repository;
+mod mask_validation_repository;
+mod mask_preview_repository;
 mod mask_group_repository;
 mod mask_repository;
+mod mask_import_repository;
+mod comment_repository;
 mod project_data_repository;
 mod project_data_access_repository;
 
@@ -17,7 +36,26 @@ pub use permission_repository::*;
 pub use capability_repository::*;
 pub use access_log_repository::*;
 pub use annotation_repository::*;
+pub use annotation_bundle_repository::*;
+pub use dataset_export_repository::*;
+pub use dataset_release_repository::*;
+pub use trash_repository::*;
+pub use label_repository::*;
+pub use worklist_repository::*;
+pub use edit_lock_repository::*;
+pub use realtime_repository::*;
+pub use webhook_repository::*;
+pub use measurement_repository::*;
+pub use annotation_propagation_repository::*;
+pub use multipart_upload_repository::*;
+pub use storage_deletion_repository::*;
+pub use storage_reconciliation_repository::*;
+pub use storage_quota_repository::*;
+pub use mask_validation_repository::*;
+pub use mask_preview_repository::*;
 pub use mask_group_repository::*;
 pub use mask_repository::*;
+pub use mask_import_repository::*;
+pub use comment_repository::*;
 pub use project_data_repository::*;
 pub use project_data_access_repository::*;
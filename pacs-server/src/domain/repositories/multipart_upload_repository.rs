@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::multipart_upload::{MaskGroupMultipartUpload, NewMaskGroupMultipartUpload};
+use crate::domain::ServiceError;
+
+#[async_trait]
+pub trait MultipartUploadRepository: Send + Sync {
+    /// 업로드 세션 생성 (IN_PROGRESS 상태)
+    async fn create(&self, new_upload: &NewMaskGroupMultipartUpload) -> Result<MaskGroupMultipartUpload, ServiceError>;
+
+    /// 마스크 그룹의 업로드 세션 조회
+    async fn find(&self, mask_group_id: i32, id: i32) -> Result<Option<MaskGroupMultipartUpload>, ServiceError>;
+
+    /// 마스크 그룹의 업로드 세션 목록 (최신순)
+    async fn list_by_mask_group(&self, mask_group_id: i32) -> Result<Vec<MaskGroupMultipartUpload>, ServiceError>;
+
+    /// 진행 중인 세션의 만료 시각 연장 (이어받기 시)
+    async fn extend_expiry(&self, id: i32, expires_at: DateTime<Utc>) -> Result<Option<MaskGroupMultipartUpload>, ServiceError>;
+
+    /// 진행 중인 세션을 COMPLETED 상태로 변경 (이미 끝난 세션이면 None)
+    async fn mark_completed(&self, id: i32) -> Result<Option<MaskGroupMultipartUpload>, ServiceError>;
+
+    /// 진행 중인 세션을 ABORTED 상태로 변경 (이미 끝난 세션이면 None)
+    async fn mark_aborted(&self, id: i32) -> Result<Option<MaskGroupMultipartUpload>, ServiceError>;
+
+    /// 만료 시각이 지난 진행 중인 세션 (오래된 순)
+    async fn list_expired(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<MaskGroupMultipartUpload>, ServiceError>;
+}
@@ -44,6 +44,9 @@ pub trait ProjectRepository: Send + Sync {
         &self,
         query: &ProjectListQuery,
     ) -> Result<i64, sqlx::Error>;
+
+    /// 프로젝트에서 사용자의 역할 이름 (멤버가 아니거나 역할이 없으면 None)
+    async fn find_member_role(&self, project_id: i32, user_id: i32) -> Result<Option<String>, sqlx::Error>;
     
     fn pool(&self) -> &PgPool;
 }
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+use crate::domain::entities::realtime::RealtimeEvent;
+use crate::domain::ServiceError;
+
+#[async_trait]
+pub trait RealtimeRepository: Send + Sync {
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 모든 서버 워커가 발행한 이벤트를 받아 `sender`로 전달
+    ///
+    /// 정상적으로는 반환하지 않으며, 이벤트 수신을 계속할 수 없으면 오류를 반환합니다.
+    async fn listen(&self, sender: &broadcast::Sender<RealtimeEvent>) -> Result<(), ServiceError>;
+}
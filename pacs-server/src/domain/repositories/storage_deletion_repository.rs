@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use crate::domain::entities::storage_deletion::StorageDeletion;
+use crate::domain::ServiceError;
+
+/// Object Storage 삭제 대기열 저장소
+///
+/// 항목은 행을 삭제하는 트랜잭션에서 `storage_deletion_queue` 모듈로 기록합니다.
+#[async_trait]
+pub trait StorageDeletionRepository: Send + Sync {
+    /// 시도할 때가 된 항목을 가져오고 `lease_seconds` 동안 다른 작업이 가져가지 못하게 함
+    async fn claim_due(&self, limit: i64, lease_seconds: i64) -> Result<Vec<StorageDeletion>, ServiceError>;
+
+    /// 삭제 완료 처리
+    async fn mark_deleted(&self, id: i64) -> Result<(), ServiceError>;
+
+    /// 실패 기록 (`retry_in_seconds`가 None이면 dead-letter)
+    async fn record_failure(&self, id: i64, error: &str, retry_in_seconds: Option<i64>) -> Result<StorageDeletion, ServiceError>;
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use crate::domain::entities::storage_quota::{StorageQuota, StorageQuotaScope, StorageUsage, StorageUsageTotals};
+use crate::domain::ServiceError;
+
+/// 저장 용량 사용량/한도 저장소
+#[async_trait]
+pub trait StorageQuotaRepository: Send + Sync {
+    /// 프로젝트 또는 사용자 존재 여부
+    async fn scope_exists(&self, scope: StorageQuotaScope, scope_id: i32) -> Result<bool, ServiceError>;
+
+    /// 프로젝트 또는 사용자의 사용량 합계
+    async fn get_usage_totals(&self, scope: StorageQuotaScope, scope_id: i32) -> Result<StorageUsageTotals, ServiceError>;
+
+    /// 프로젝트의 사용자별 / 사용자의 프로젝트별 사용량 (사용량이 큰 순서)
+    async fn list_usage(&self, scope: StorageQuotaScope, scope_id: i32) -> Result<Vec<StorageUsage>, ServiceError>;
+
+    /// 프로젝트 또는 사용자의 한도
+    async fn find_quota(&self, scope: StorageQuotaScope, scope_id: i32) -> Result<Option<StorageQuota>, ServiceError>;
+
+    /// 한도 설정 (경고 한도가 바뀌면 다음 사용량 변경 때 다시 평가)
+    async fn upsert_quota(
+        &self,
+        scope: StorageQuotaScope,
+        scope_id: i32,
+        soft_limit_bytes: Option<i64>,
+        hard_limit_bytes: Option<i64>,
+        updated_by: i32,
+    ) -> Result<StorageQuota, ServiceError>;
+
+    /// 마스크 행으로 사용량 전체를 다시 계산 (갱신된 (프로젝트, 사용자) 수 반환)
+    async fn recalculate_usage(&self) -> Result<u64, ServiceError>;
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use crate::domain::entities::storage_reconciliation::{
+    NewStorageReconciliationFinding, ReconciliationMaskRow, StorageOrphanAction, StorageReconciliationCounts,
+    StorageReconciliationFinding, StorageReconciliationRun,
+};
+use crate::domain::ServiceError;
+
+/// Object Storage 정합성 점검 저장소
+#[async_trait]
+pub trait StorageReconciliationRepository: Send + Sync {
+    /// 점검 실행 시작 (`stale_after_seconds` 안에 시작한 실행이 진행 중이면 None)
+    async fn start_run(
+        &self,
+        triggered_by: Option<i32>,
+        orphan_action: StorageOrphanAction,
+        grace_period_hours: i32,
+        stale_after_seconds: i64,
+    ) -> Result<Option<StorageReconciliationRun>, ServiceError>;
+
+    /// 점검 실행 종료 (`error`가 있으면 실패)
+    async fn finish_run(
+        &self,
+        run_id: i32,
+        counts: &StorageReconciliationCounts,
+        error: Option<&str>,
+    ) -> Result<StorageReconciliationRun, ServiceError>;
+
+    /// 최근 점검 실행 목록
+    async fn list_runs(&self, limit: i64) -> Result<Vec<StorageReconciliationRun>, ServiceError>;
+
+    async fn find_run(&self, run_id: i32) -> Result<Option<StorageReconciliationRun>, ServiceError>;
+
+    /// 객체 경로 중 행이 참조하지 않는 경로
+    ///
+    /// 마스크 행의 `file_path`이거나, `annotations/annotation_{id}/` 아래에서 어노테이션이 남아 있으면 참조된 것으로 봅니다.
+    /// 이미 삭제 대기열에 예약된 객체는 제외합니다.
+    async fn find_unreferenced_objects(&self, object_keys: &[String]) -> Result<Vec<String>, ServiceError>;
+
+    /// `after_id`보다 큰 ID의 마스크 행 (ID 순서)
+    async fn list_masks_after(&self, after_id: i32, limit: i64) -> Result<Vec<ReconciliationMaskRow>, ServiceError>;
+
+    async fn insert_findings(&self, run_id: i32, findings: &[NewStorageReconciliationFinding]) -> Result<(), ServiceError>;
+
+    async fn list_findings(&self, run_id: i32, limit: i64) -> Result<Vec<StorageReconciliationFinding>, ServiceError>;
+
+    /// 고아 객체를 삭제 대기열에 예약
+    async fn enqueue_orphan_deletions(&self, object_keys: &[String]) -> Result<u64, ServiceError>;
+}
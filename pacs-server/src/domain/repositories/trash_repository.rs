@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::trash::{TrashedAnnotation, TrashedMaskGroup};
+use crate::domain::ServiceError;
+
+#[async_trait]
+pub trait TrashRepository: Send + Sync {
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 프로젝트 휴지통의 어노테이션 목록 (공유되었거나 본인이 작성한 것만, 최근 삭제순)
+    async fn list_annotations(&self, project_id: i32, viewer_id: i32) -> Result<Vec<TrashedAnnotation>, ServiceError>;
+
+    /// 프로젝트 휴지통의 마스크 그룹 목록 (어노테이션이 활성 상태인 것만, 최근 삭제순)
+    async fn list_mask_groups(&self, project_id: i32, viewer_id: i32) -> Result<Vec<TrashedMaskGroup>, ServiceError>;
+
+    /// 휴지통에 있는 어노테이션 조회
+    async fn find_annotation(&self, project_id: i32, annotation_id: i32, viewer_id: i32) -> Result<Option<TrashedAnnotation>, ServiceError>;
+
+    /// 휴지통에 있는 마스크 그룹 조회 (어노테이션이 휴지통에 있는 경우 포함)
+    async fn find_mask_group(&self, project_id: i32, mask_group_id: i32, viewer_id: i32) -> Result<Option<TrashedMaskGroup>, ServiceError>;
+
+    /// 어노테이션 복원 (함께 삭제된 마스크 그룹 포함, 복원되지 않았으면 false)
+    async fn restore_annotation(&self, annotation_id: i32, restored_by: i32) -> Result<bool, ServiceError>;
+
+    /// 마스크 그룹 복원 (어노테이션이 휴지통에 있거나 복원되지 않았으면 false)
+    async fn restore_mask_group(&self, mask_group_id: i32) -> Result<bool, ServiceError>;
+
+    /// `cutoff` 이전에 삭제된 어노테이션 ID 목록
+    async fn find_expired_annotations(&self, cutoff: DateTime<Utc>, limit: i64) -> Result<Vec<i32>, ServiceError>;
+
+    /// `cutoff` 이전에 삭제된 마스크 그룹 ID 목록 (어노테이션이 활성 상태인 것만)
+    async fn find_expired_mask_groups(&self, cutoff: DateTime<Utc>, limit: i64) -> Result<Vec<i32>, ServiceError>;
+
+    /// 어노테이션을 영구 삭제하고, 같은 트랜잭션에서 마스크 파일 삭제를 예약한 뒤 예약한 파일 수를 반환합니다.
+    ///
+    /// 그 사이 복원되었거나 아직 보관 기간이 남아 있으면 삭제하지 않고 None을 반환합니다.
+    async fn purge_annotation(&self, annotation_id: i32, cutoff: DateTime<Utc>) -> Result<Option<u64>, ServiceError>;
+
+    /// 마스크 그룹을 영구 삭제하고, 같은 트랜잭션에서 마스크 파일 삭제를 예약한 뒤 예약한 파일 수를 반환합니다.
+    ///
+    /// 그 사이 복원되었거나 아직 보관 기간이 남아 있으면 삭제하지 않고 None을 반환합니다.
+    async fn purge_mask_group(&self, mask_group_id: i32, cutoff: DateTime<Utc>) -> Result<Option<u64>, ServiceError>;
+}
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use crate::domain::entities::webhook::{
+    ClaimedWebhookDelivery, NewWebhookSubscription, UpdateWebhookSubscription, WebhookAttemptOutcome, WebhookDelivery,
+    WebhookDeliveryAttempt, WebhookDeliveryStatus, WebhookSubscription,
+};
+use crate::domain::ServiceError;
+
+/// Webhook 구독 / 아웃박스 / 전송 저장소
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn create_subscription(&self, new_subscription: &NewWebhookSubscription) -> Result<WebhookSubscription, ServiceError>;
+
+    async fn list_subscriptions(&self, project_id: i32) -> Result<Vec<WebhookSubscription>, ServiceError>;
+
+    async fn find_subscription(&self, project_id: i32, subscription_id: i32) -> Result<Option<WebhookSubscription>, ServiceError>;
+
+    async fn update_subscription(
+        &self,
+        project_id: i32,
+        subscription_id: i32,
+        update: &UpdateWebhookSubscription,
+    ) -> Result<Option<WebhookSubscription>, ServiceError>;
+
+    /// 구독 삭제 (전송 기록도 함께 삭제)
+    async fn delete_subscription(&self, project_id: i32, subscription_id: i32) -> Result<bool, ServiceError>;
+
+    /// 프로젝트의 전송 목록 (최신순, 구독/상태 지정 가능)
+    async fn list_deliveries(
+        &self,
+        project_id: i32,
+        subscription_id: Option<i32>,
+        status: Option<WebhookDeliveryStatus>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, ServiceError>;
+
+    async fn find_delivery(&self, project_id: i32, delivery_id: i64) -> Result<Option<WebhookDelivery>, ServiceError>;
+
+    /// 전송 시도 로그 (시도 순)
+    async fn list_attempts(&self, delivery_id: i64) -> Result<Vec<WebhookDeliveryAttempt>, ServiceError>;
+
+    /// dead-letter 전송을 다시 전송 대기로 되돌림 (시도 횟수 초기화)
+    async fn retry_dead_delivery(&self, project_id: i32, delivery_id: i64) -> Result<Option<WebhookDelivery>, ServiceError>;
+
+    /// 아직 나누지 않은 아웃박스 이벤트를 활성 구독별 전송 작업으로 나눔 (처리한 이벤트 수 반환)
+    ///
+    /// 여러 디스패처가 동시에 실행되어도 같은 이벤트를 두 번 나누지 않습니다.
+    async fn fan_out_events(&self, limit: i64) -> Result<u64, ServiceError>;
+
+    /// 전송할 차례가 된 작업을 가져감
+    ///
+    /// 가져간 작업은 `lease_seconds` 동안 다른 디스패처가 가져가지 않으며,
+    /// 결과를 기록하지 못하고 임대가 끝나면 다시 전송 대상이 됩니다.
+    async fn claim_due_deliveries(&self, limit: i64, lease_seconds: i64) -> Result<Vec<ClaimedWebhookDelivery>, ServiceError>;
+
+    /// 전송 시도 결과 기록 (시도 로그 추가, 성공/재시도 대기/dead-letter 상태 반영)
+    async fn record_attempt(&self, delivery_id: i64, outcome: &WebhookAttemptOutcome) -> Result<WebhookDelivery, ServiceError>;
+}
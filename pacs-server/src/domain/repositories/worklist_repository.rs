@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use crate::domain::entities::worklist::{
+    NewWorklistTask, WorklistProgress, WorklistStudyFilter, WorklistTask, WorklistTaskQuery,
+    WorklistTaskResults, WorklistTaskStatus,
+};
+use crate::domain::ServiceError;
+
+/// 작업 상태 전환은 모두 현재 상태와 담당자가 기대한 값일 때만 적용되며,
+/// 그렇지 않으면 None을 반환합니다. (동시 요청 방지)
+#[async_trait]
+pub trait WorklistRepository: Send + Sync {
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError>;
+
+    /// 조건에 맞고 아직 작업이 없는 프로젝트 Study ID (Study 날짜, ID순)
+    async fn find_untasked_studies(&self, project_id: i32, filter: &WorklistStudyFilter) -> Result<Vec<i32>, ServiceError>;
+
+    /// 작업 생성 (이미 작업이 있는 Study는 건너뜀)
+    async fn create_tasks(
+        &self,
+        project_id: i32,
+        tasks: &[NewWorklistTask],
+        priority: i32,
+        created_by: i32,
+    ) -> Result<Vec<WorklistTask>, ServiceError>;
+
+    /// 작업 목록 (우선순위 높은 순, ID순)
+    async fn list_tasks(&self, project_id: i32, query: &WorklistTaskQuery) -> Result<Vec<WorklistTask>, ServiceError>;
+
+    async fn find_task(&self, project_id: i32, task_id: i32) -> Result<Option<WorklistTask>, ServiceError>;
+
+    /// 작업에 연결된 결과물
+    async fn find_results(&self, task_id: i32) -> Result<WorklistTaskResults, ServiceError>;
+
+    /// 결과물 중 프로젝트의 해당 Study에 속한 활성 어노테이션/마스크 그룹만 반환
+    async fn filter_study_results(
+        &self,
+        project_id: i32,
+        study_uid: &str,
+        results: &WorklistTaskResults,
+    ) -> Result<WorklistTaskResults, ServiceError>;
+
+    /// 대기 중인 작업의 담당자 변경
+    async fn assign(&self, project_id: i32, task_id: i32, assignee_id: Option<i32>) -> Result<Option<WorklistTask>, ServiceError>;
+
+    /// 대기 중이고 배정되지 않았거나 본인에게 배정된 작업 가져가기 (IN_PROGRESS)
+    async fn claim(&self, project_id: i32, task_id: i32, user_id: i32) -> Result<Option<WorklistTask>, ServiceError>;
+
+    /// 진행 중인 본인 작업 내려놓기 (소요 시간을 누적하고 배정 해제된 TODO로 되돌림)
+    async fn release(&self, project_id: i32, task_id: i32, user_id: i32) -> Result<Option<WorklistTask>, ServiceError>;
+
+    /// 본인 작업을 DONE/SKIPPED로 종료하고 결과물을 연결
+    /// (`WorklistTaskStatus::closable_from` 상태에서만)
+    async fn close(
+        &self,
+        project_id: i32,
+        task_id: i32,
+        user_id: i32,
+        to: WorklistTaskStatus,
+        note: Option<&str>,
+        results: &WorklistTaskResults,
+    ) -> Result<Option<WorklistTask>, ServiceError>;
+
+    /// 종료된 작업을 다시 TODO로 되돌리고 결과물 연결을 해제 (담당자는 유지)
+    async fn reopen(&self, project_id: i32, task_id: i32) -> Result<Option<WorklistTask>, ServiceError>;
+
+    /// 판독자별 진행 현황
+    async fn progress(&self, project_id: i32) -> Result<Vec<WorklistProgress>, ServiceError>;
+}
@@ -1,6 +1,11 @@
 use async_trait::async_trait;
-use crate::domain::entities::{Annotation, AnnotationHistory, NewAnnotation};
+use crate::domain::entities::{Annotation, AnnotationHistory, AnnotationReviewComment, AnnotationReviewStatus, AnnotationSearchCriteria, NewAnnotation, MAX_SEARCH_LIMIT};
+use crate::domain::entities::label::annotation_label;
+use crate::domain::entities::realtime::{RealtimeEvent, RealtimeEventType};
 use crate::domain::repositories::{AnnotationRepository, UserRepository, ProjectRepository};
+use crate::domain::services::label_validation::canonical_label;
+use crate::domain::services::{edit_lock_guard, measurement_computation};
+use crate::domain::services::realtime_events::publish_best_effort;
 use crate::domain::ServiceError;
 
 /// Annotation 관리 도메인 서비스
@@ -21,15 +26,15 @@ pub trait AnnotationService: Send + Sync {
     /// Study UID로 Annotation 목록 조회
     async fn get_annotations_by_study(&self, study_uid: &str) -> Result<Vec<Annotation>, ServiceError>;
 
-    // viewer_software 필터링 메서드들
-    /// 사용자의 Annotation 목록 조회 (viewer_software 필터링)
-    async fn get_annotations_by_user_with_viewer(&self, user_id: i32, viewer_software: Option<&str>) -> Result<Vec<Annotation>, ServiceError>;
+    // viewer_software, review_status 필터링 메서드들
+    /// 사용자의 Annotation 목록 조회 (viewer_software, review_status 필터링)
+    async fn get_annotations_by_user_with_viewer(&self, user_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, ServiceError>;
 
-    /// 프로젝트의 Annotation 목록 조회 (viewer_software 필터링)
-    async fn get_annotations_by_project_with_viewer(&self, project_id: i32, viewer_software: Option<&str>) -> Result<Vec<Annotation>, ServiceError>;
+    /// 프로젝트의 Annotation 목록 조회 (viewer_software, review_status 필터링)
+    async fn get_annotations_by_project_with_viewer(&self, project_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, ServiceError>;
 
-    /// Study UID로 Annotation 목록 조회 (viewer_software 필터링)
-    async fn get_annotations_by_study_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>) -> Result<Vec<Annotation>, ServiceError>;
+    /// Study UID로 Annotation 목록 조회 (viewer_software, review_status 필터링)
+    async fn get_annotations_by_study_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, ServiceError>;
 
     /// Series UID로 Annotation 목록 조회
     async fn get_annotations_by_series(&self, series_uid: &str) -> Result<Vec<Annotation>, ServiceError>;
@@ -43,14 +48,14 @@ pub trait AnnotationService: Send + Sync {
     /// 공유 Annotation 목록 조회
     async fn get_shared_annotations(&self, project_id: i32) -> Result<Vec<Annotation>, ServiceError>;
 
-    /// Annotation 업데이트
-    async fn update_annotation(&self, id: i32, data: serde_json::Value, is_shared: bool) -> Result<Annotation, ServiceError>;
+    /// Annotation 업데이트 (다른 사용자의 편집 잠금이 있으면 거부)
+    async fn update_annotation(&self, id: i32, data: serde_json::Value, is_shared: bool, editor_id: i32) -> Result<Annotation, ServiceError>;
 
-    /// Annotation 업데이트 (measurement_values 포함)
-    async fn update_annotation_with_measurements(&self, id: i32, data: serde_json::Value, is_shared: bool, measurement_values: Option<serde_json::Value>) -> Result<Annotation, ServiceError>;
+    /// Annotation 업데이트 (measurement_values 포함, 다른 사용자의 편집 잠금이 있으면 거부)
+    async fn update_annotation_with_measurements(&self, id: i32, data: serde_json::Value, is_shared: bool, measurement_values: Option<serde_json::Value>, editor_id: i32) -> Result<Annotation, ServiceError>;
 
-    /// Annotation 삭제
-    async fn delete_annotation(&self, id: i32) -> Result<(), ServiceError>;
+    /// Annotation 삭제 (휴지통으로 이동, 다른 사용자의 편집 잠금이 있으면 거부)
+    async fn delete_annotation(&self, id: i32, deleted_by: i32) -> Result<(), ServiceError>;
 
     /// Annotation 히스토리 생성
     async fn create_history(&self, annotation_id: i32, user_id: i32, action: &str, data_before: Option<serde_json::Value>, data_after: Option<serde_json::Value>) -> Result<AnnotationHistory, ServiceError>;
@@ -60,8 +65,31 @@ pub trait AnnotationService: Send + Sync {
 
     /// 사용자가 Annotation에 접근할 수 있는지 확인
     async fn can_access_annotation(&self, user_id: i32, annotation_id: i32) -> Result<bool, ServiceError>;
+
+    // 검토 워크플로우 메서드들
+    /// 검토 상태 전환 (제출, 승인, 반려, 잠금 등)
+    async fn change_review_status(&self, annotation_id: i32, actor_id: i32, next: AnnotationReviewStatus, comment: Option<String>) -> Result<Annotation, ServiceError>;
+
+    /// 검토자 배정
+    async fn assign_reviewer(&self, annotation_id: i32, actor_id: i32, reviewer_id: i32) -> Result<Annotation, ServiceError>;
+
+    /// 검토자에게 배정된 Annotation 목록 조회
+    async fn get_annotations_by_reviewer(&self, reviewer_id: i32, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, ServiceError>;
+
+    /// 검토 코멘트 작성
+    async fn add_review_comment(&self, annotation_id: i32, user_id: i32, comment: &str) -> Result<AnnotationReviewComment, ServiceError>;
+
+    /// 검토 코멘트 목록 조회
+    /// 프로젝트 멤버이면서 작성자, 배정된 검토자이거나 공유된 Annotation이어야 합니다.
+    async fn get_review_comments(&self, annotation_id: i32, user_id: i32) -> Result<Vec<AnnotationReviewComment>, ServiceError>;
+
+    /// 필터/정렬/키셋 페이지네이션을 조합한 Annotation 검색
+    async fn search_annotations(&self, criteria: &AnnotationSearchCriteria) -> Result<Vec<Annotation>, ServiceError>;
 }
 
+/// 검토자로 배정될 수 있는 프로젝트 역할
+pub const REVIEWER_ROLES: &[&str] = &["REVIEWER", "PROJECT_ADMIN"];
+
 pub struct AnnotationServiceImpl<A, U, P>
 where
     A: AnnotationRepository,
@@ -86,6 +114,100 @@ where
             project_repository,
         }
     }
+
+    /// 사용자가 프로젝트 멤버인지 확인
+    async fn is_project_member(&self, user_id: i32, project_id: i32) -> Result<bool, ServiceError> {
+        let is_member = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM security_user_project WHERE user_id = $1 AND project_id = $2"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(self.annotation_repository.pool())
+        .await
+        .map_err(|e| ServiceError::DatabaseError(e.to_string()))?;
+
+        Ok(is_member > 0)
+    }
+
+    /// 사용자가 프로젝트에서 검토자 역할을 가지고 있는지 확인
+    async fn has_reviewer_role(&self, user_id: i32, project_id: i32) -> Result<bool, ServiceError> {
+        let role_name = sqlx::query_scalar::<_, String>(
+            "SELECT r.name
+             FROM security_user_project up
+             JOIN security_role r ON r.id = up.role_id
+             WHERE up.user_id = $1 AND up.project_id = $2"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_optional(self.annotation_repository.pool())
+        .await
+        .map_err(|e| ServiceError::DatabaseError(e.to_string()))?;
+
+        Ok(role_name.map(|name| REVIEWER_ROLES.contains(&name.as_str())).unwrap_or(false))
+    }
+
+    /// 검토 코멘트를 보고 쓸 수 있는지 확인
+    ///
+    /// 코멘트와 같은 규칙(프로젝트 멤버 + 공유되었거나 작성자)에 배정된 검토자를 더함
+    async fn ensure_can_view_review(&self, annotation: &Annotation, user_id: i32) -> Result<(), ServiceError> {
+        let visible = annotation.is_shared
+            || annotation.user_id == user_id
+            || annotation.reviewer_id == Some(user_id);
+        if visible && self.is_project_member(user_id, annotation.project_id).await? {
+            Ok(())
+        } else {
+            Err(ServiceError::Unauthorized(format!(
+                "User {} cannot access review comments of annotation {}",
+                user_id, annotation.id
+            )))
+        }
+    }
+
+    /// 프로젝트 라벨 분류 체계로 `data.label`을 검증하고 정식 이름으로 바꿈
+    async fn apply_label_taxonomy(&self, project_id: i32, tool_name: &str, data: &mut serde_json::Value) -> Result<(), ServiceError> {
+        let Some(label) = annotation_label(data) else {
+            return Ok(());
+        };
+
+        let canonical = canonical_label(self.annotation_repository.pool(), project_id, label, Some(tool_name)).await?;
+        if canonical != label {
+            data["label"] = serde_json::Value::String(canonical);
+        }
+        Ok(())
+    }
+
+    /// 잠긴 Annotation은 수정/삭제할 수 없음
+    fn ensure_not_locked(annotation: &Annotation) -> Result<(), ServiceError> {
+        if annotation.review_status.is_locked() {
+            return Err(ServiceError::ValidationError("Annotation is locked and cannot be modified".into()));
+        }
+        Ok(())
+    }
+
+    /// 다른 사용자가 Study 또는 Annotation 편집 잠금을 가지고 있으면 수정/삭제할 수 없음
+    async fn ensure_not_edit_locked(&self, annotation: &Annotation, editor_id: i32) -> Result<(), ServiceError> {
+        edit_lock_guard::ensure_editable(
+            self.annotation_repository.pool(),
+            annotation.project_id,
+            &annotation.study_uid,
+            annotation.id,
+            editor_id,
+        )
+        .await
+    }
+
+    /// 어노테이션 변경 이벤트를 프로젝트 구독자에게 발행
+    async fn publish_event(&self, event_type: RealtimeEventType, annotation: &Annotation, actor_id: i32) {
+        let event = RealtimeEvent::new(
+            event_type,
+            annotation.project_id,
+            Some(annotation.study_uid.clone()),
+            annotation.id,
+            Some(actor_id),
+        )
+        .with_annotation_visibility(Some(annotation.user_id), annotation.is_shared);
+        publish_best_effort(self.annotation_repository.pool(), event).await;
+    }
 }
 
 #[async_trait]
@@ -127,7 +249,13 @@ where
             return Err(ServiceError::Unauthorized("User is not a member of this project".into()));
         }
 
-        Ok(self.annotation_repository.create(new_annotation).await?)
+        let mut new_annotation = new_annotation;
+        self.apply_label_taxonomy(new_annotation.project_id, &new_annotation.tool_name, &mut new_annotation.data).await?;
+
+        let annotation = self.annotation_repository.create(new_annotation).await?;
+        measurement_computation::refresh_best_effort(self.annotation_repository.pool(), &annotation).await;
+        self.publish_event(RealtimeEventType::AnnotationCreated, &annotation, annotation.user_id).await;
+        Ok(annotation)
     }
 
     async fn get_annotation_by_id(&self, id: i32) -> Result<Annotation, ServiceError> {
@@ -185,9 +313,14 @@ where
         Ok(self.annotation_repository.find_shared_annotations(project_id).await?)
     }
 
-    async fn update_annotation(&self, id: i32, data: serde_json::Value, is_shared: bool) -> Result<Annotation, ServiceError> {
+    async fn update_annotation(&self, id: i32, data: serde_json::Value, is_shared: bool, editor_id: i32) -> Result<Annotation, ServiceError> {
         // Annotation 존재 확인
         let annotation = self.get_annotation_by_id(id).await?;
+        Self::ensure_not_locked(&annotation)?;
+        self.ensure_not_edit_locked(&annotation, editor_id).await?;
+
+        let mut data = data;
+        self.apply_label_taxonomy(annotation.project_id, &annotation.tool_name, &mut data).await?;
 
         // 업데이트 실행
         match self.annotation_repository.update(id, data, is_shared).await? {
@@ -200,15 +333,22 @@ where
                     Some(annotation.data),
                     Some(updated_annotation.data.clone())
                 ).await?;
+                measurement_computation::refresh_best_effort(self.annotation_repository.pool(), &updated_annotation).await;
+                self.publish_event(RealtimeEventType::AnnotationUpdated, &updated_annotation, editor_id).await;
                 Ok(updated_annotation)
             }
             None => Err(ServiceError::NotFound("Annotation not found".into()))
         }
     }
 
-    async fn update_annotation_with_measurements(&self, id: i32, data: serde_json::Value, is_shared: bool, measurement_values: Option<serde_json::Value>) -> Result<Annotation, ServiceError> {
+    async fn update_annotation_with_measurements(&self, id: i32, data: serde_json::Value, is_shared: bool, measurement_values: Option<serde_json::Value>, editor_id: i32) -> Result<Annotation, ServiceError> {
         // 현재 annotation 조회
         let annotation = self.get_annotation_by_id(id).await?;
+        Self::ensure_not_locked(&annotation)?;
+        self.ensure_not_edit_locked(&annotation, editor_id).await?;
+
+        let mut data = data;
+        self.apply_label_taxonomy(annotation.project_id, &annotation.tool_name, &mut data).await?;
 
         // 업데이트 실행 (measurement_values 포함)
         match self.annotation_repository.update_with_measurements(id, data, is_shared, measurement_values).await? {
@@ -221,18 +361,23 @@ where
                     Some(annotation.data),
                     Some(updated_annotation.data.clone())
                 ).await?;
+                measurement_computation::refresh_best_effort(self.annotation_repository.pool(), &updated_annotation).await;
+                self.publish_event(RealtimeEventType::AnnotationUpdated, &updated_annotation, editor_id).await;
                 Ok(updated_annotation)
             }
             None => Err(ServiceError::NotFound("Annotation not found".into()))
         }
     }
 
-    async fn delete_annotation(&self, id: i32) -> Result<(), ServiceError> {
+    async fn delete_annotation(&self, id: i32, deleted_by: i32) -> Result<(), ServiceError> {
         // Annotation 존재 확인
-        self.get_annotation_by_id(id).await?;
+        let annotation = self.get_annotation_by_id(id).await?;
+        Self::ensure_not_locked(&annotation)?;
+        self.ensure_not_edit_locked(&annotation, deleted_by).await?;
 
-        let deleted = self.annotation_repository.delete(id).await?;
+        let deleted = self.annotation_repository.delete(id, deleted_by).await?;
         if deleted {
+            self.publish_event(RealtimeEventType::AnnotationDeleted, &annotation, deleted_by).await;
             Ok(())
         } else {
             Err(ServiceError::NotFound("Annotation not found".into()))
@@ -267,28 +412,152 @@ where
         Ok(is_member > 0)
     }
 
-    // viewer_software 필터링 메서드들
-    async fn get_annotations_by_user_with_viewer(&self, user_id: i32, viewer_software: Option<&str>) -> Result<Vec<Annotation>, ServiceError> {
+    // viewer_software, review_status 필터링 메서드들
+    async fn get_annotations_by_user_with_viewer(&self, user_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, ServiceError> {
         // 사용자 존재 확인
         if self.user_repository.find_by_id(user_id).await?.is_none() {
             return Err(ServiceError::NotFound("User not found".into()));
         }
 
-        Ok(self.annotation_repository.find_by_user_id_with_viewer(user_id, viewer_software).await?)
+        Ok(self.annotation_repository.find_by_user_id_with_viewer(user_id, viewer_software, review_status).await?)
     }
 
-    async fn get_annotations_by_project_with_viewer(&self, project_id: i32, viewer_software: Option<&str>) -> Result<Vec<Annotation>, ServiceError> {
+    async fn get_annotations_by_project_with_viewer(&self, project_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, ServiceError> {
         // 프로젝트 존재 확인
         if self.project_repository.find_by_id(project_id).await?.is_none() {
             return Err(ServiceError::NotFound("Project not found".into()));
         }
 
-        Ok(self.annotation_repository.find_by_project_id_with_viewer(project_id, viewer_software).await?)
+        Ok(self.annotation_repository.find_by_project_id_with_viewer(project_id, viewer_software, review_status).await?)
     }
 
-    async fn get_annotations_by_study_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>) -> Result<Vec<Annotation>, ServiceError> {
-        Ok(self.annotation_repository.find_by_study_uid_with_viewer(study_uid, viewer_software).await?)
+    async fn get_annotations_by_study_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, ServiceError> {
+        Ok(self.annotation_repository.find_by_study_uid_with_viewer(study_uid, viewer_software, review_status).await?)
     }
-}
 
+    async fn change_review_status(&self, annotation_id: i32, actor_id: i32, next: AnnotationReviewStatus, comment: Option<String>) -> Result<Annotation, ServiceError> {
+        let annotation = self.get_annotation_by_id(annotation_id).await?;
+        let current = annotation.review_status;
+
+        if !current.can_transition_to(next) {
+            return Err(ServiceError::ValidationError(format!(
+                "Cannot change review status from {} to {}", current, next
+            )));
+        }
 
+        let comment = comment.map(|c| c.trim().to_string()).filter(|c| !c.is_empty());
+
+        match next {
+            // 제출 및 재작성은 작성자만 가능
+            AnnotationReviewStatus::Draft | AnnotationReviewStatus::Submitted => {
+                if annotation.user_id != actor_id {
+                    return Err(ServiceError::Unauthorized("Only the author can submit or withdraw an annotation".into()));
+                }
+            }
+            // 승인/반려/잠금은 배정된 검토자만 가능
+            AnnotationReviewStatus::Approved | AnnotationReviewStatus::Rejected | AnnotationReviewStatus::Locked => {
+                match annotation.reviewer_id {
+                    Some(reviewer_id) if reviewer_id == actor_id => {}
+                    Some(_) => return Err(ServiceError::Unauthorized("Only the assigned reviewer can review this annotation".into())),
+                    None => return Err(ServiceError::ValidationError("No reviewer has been assigned to this annotation".into())),
+                }
+                if next == AnnotationReviewStatus::Rejected && comment.is_none() {
+                    return Err(ServiceError::ValidationError("A comment is required when rejecting an annotation".into()));
+                }
+            }
+        }
+
+        self.annotation_repository
+            .update_review_status(annotation_id, actor_id, current, next, comment.as_deref())
+            .await?
+            .ok_or_else(|| ServiceError::ValidationError("Annotation review status was changed by another request".into()))
+    }
+
+    async fn assign_reviewer(&self, annotation_id: i32, actor_id: i32, reviewer_id: i32) -> Result<Annotation, ServiceError> {
+        let annotation = self.get_annotation_by_id(annotation_id).await?;
+
+        if matches!(annotation.review_status, AnnotationReviewStatus::Approved | AnnotationReviewStatus::Locked) {
+            return Err(ServiceError::ValidationError(format!(
+                "Cannot assign a reviewer to an annotation in {} state", annotation.review_status
+            )));
+        }
+
+        if !self.is_project_member(actor_id, annotation.project_id).await? {
+            return Err(ServiceError::Unauthorized("User is not a member of this project".into()));
+        }
+
+        if reviewer_id == annotation.user_id {
+            return Err(ServiceError::ValidationError("The author cannot review their own annotation".into()));
+        }
+
+        if self.user_repository.find_by_id(reviewer_id).await?.is_none() {
+            return Err(ServiceError::NotFound("Reviewer not found".into()));
+        }
+
+        if !self.has_reviewer_role(reviewer_id, annotation.project_id).await? {
+            return Err(ServiceError::ValidationError(format!(
+                "Reviewer must have one of the project roles: {}", REVIEWER_ROLES.join(", ")
+            )));
+        }
+
+        self.annotation_repository
+            .assign_reviewer(annotation_id, actor_id, reviewer_id)
+            .await?
+            .ok_or(ServiceError::NotFound("Annotation not found".into()))
+    }
+
+    async fn get_annotations_by_reviewer(&self, reviewer_id: i32, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, ServiceError> {
+        Ok(self.annotation_repository.find_by_reviewer(reviewer_id, review_status).await?)
+    }
+
+    async fn add_review_comment(&self, annotation_id: i32, user_id: i32, comment: &str) -> Result<AnnotationReviewComment, ServiceError> {
+        let comment = comment.trim();
+        if comment.is_empty() {
+            return Err(ServiceError::ValidationError("Comment cannot be empty".into()));
+        }
+
+        let annotation = self.get_annotation_by_id(annotation_id).await?;
+        self.ensure_can_view_review(&annotation, user_id).await?;
+
+        Ok(self.annotation_repository
+            .create_review_comment(annotation_id, user_id, annotation.review_status, comment)
+            .await?)
+    }
+
+    async fn get_review_comments(&self, annotation_id: i32, user_id: i32) -> Result<Vec<AnnotationReviewComment>, ServiceError> {
+        let annotation = self.get_annotation_by_id(annotation_id).await?;
+        self.ensure_can_view_review(&annotation, user_id).await?;
+
+        Ok(self.annotation_repository.get_review_comments(annotation_id).await?)
+    }
+
+    async fn search_annotations(&self, criteria: &AnnotationSearchCriteria) -> Result<Vec<Annotation>, ServiceError> {
+        if criteria.limit < 1 || criteria.limit > MAX_SEARCH_LIMIT + 1 {
+            return Err(ServiceError::ValidationError(format!("limit must be between 1 and {}", MAX_SEARCH_LIMIT)));
+        }
+        if let (Some(from), Some(to)) = (criteria.created_from, criteria.created_to) {
+            if from > to {
+                return Err(ServiceError::ValidationError("created_from must not be after created_to".into()));
+            }
+        }
+        if let (Some(from), Some(to)) = (criteria.updated_from, criteria.updated_to) {
+            if from > to {
+                return Err(ServiceError::ValidationError("updated_from must not be after updated_to".into()));
+            }
+        }
+        for predicate in criteria.data_predicates.iter().chain(criteria.measurement_predicates.iter()) {
+            predicate.validate().map_err(ServiceError::ValidationError)?;
+        }
+        if let Some(cursor) = &criteria.cursor {
+            // 커서는 발급 당시와 같은 정렬 기준/방향에서만 유효
+            if cursor.sort != criteria.sort || cursor.direction != criteria.direction {
+                return Err(ServiceError::ValidationError("Cursor does not match the requested sort order".into()));
+            }
+            if cursor.sort_timestamp().is_none() {
+                return Err(ServiceError::ValidationError("Invalid cursor".into()));
+            }
+        }
+
+        Ok(self.annotation_repository.search(criteria).await?)
+    }
+}
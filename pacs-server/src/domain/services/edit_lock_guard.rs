@@ -0,0 +1,60 @@
+//! 편집 잠금 확인
+//!
+//! `AnnotationService`(수정/삭제)와 `MaskGroupService`(수정)가 변경 전에 사용합니다.
+//! 잠금은 권고 잠금이므로 잠금 없이도 수정할 수 있지만, 다른 사용자의 유효한 잠금이 걸려 있으면 거부합니다.
+
+use sqlx::{PgExecutor, PgPool};
+use crate::domain::entities::edit_lock::EditLock;
+use crate::domain::ServiceError;
+
+/// 대상과 충돌하는 다른 사용자의 유효한 잠금 조회
+///
+/// Study 대상(`annotation_id` 없음)은 그 Study의 모든 잠금과, 어노테이션 대상은
+/// 같은 Study의 Study 잠금 및 같은 어노테이션의 잠금과 충돌합니다.
+pub async fn find_conflicting_lock<'e, E>(
+    executor: E,
+    project_id: i32,
+    study_uid: &str,
+    annotation_id: Option<i32>,
+    user_id: i32,
+) -> Result<Option<EditLock>, sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    sqlx::query_as::<_, EditLock>(
+        "SELECT l.id, l.project_id, l.target_type, l.study_uid, l.annotation_id, l.holder_id,
+                u.username AS holder_username, l.acquired_at, l.renewed_at, l.expires_at
+         FROM annotation_edit_lock l
+         LEFT JOIN security_user u ON u.id = l.holder_id
+         WHERE l.project_id = $1 AND l.study_uid = $2
+           AND l.holder_id <> $4
+           AND l.expires_at > CURRENT_TIMESTAMP
+           AND ($3::INTEGER IS NULL OR l.annotation_id IS NULL OR l.annotation_id = $3)
+         ORDER BY l.annotation_id NULLS FIRST, l.id
+         LIMIT 1"
+    )
+    .bind(project_id)
+    .bind(study_uid)
+    .bind(annotation_id)
+    .bind(user_id)
+    .fetch_optional(executor)
+    .await
+}
+
+/// 다른 사용자의 잠금이 없으면 Ok, 있으면 잠금 정보를 담은 ValidationError
+pub async fn ensure_editable(
+    pool: &PgPool,
+    project_id: i32,
+    study_uid: &str,
+    annotation_id: i32,
+    editor_id: i32,
+) -> Result<(), ServiceError> {
+    let conflict = find_conflicting_lock(pool, project_id, study_uid, Some(annotation_id), editor_id)
+        .await
+        .map_err(|e| ServiceError::DatabaseError(format!("Failed to check edit locks: {}", e)))?;
+
+    match conflict {
+        Some(lock) => Err(ServiceError::ValidationError(lock.describe())),
+        None => Ok(()),
+    }
+}
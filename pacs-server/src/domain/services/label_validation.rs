@@ -0,0 +1,43 @@
+//! 프로젝트 라벨 분류 체계에 따른 마스크/어노테이션 라벨 검증
+//!
+//! `MaskService`와 `AnnotationService`가 라벨을 저장하기 전에 사용합니다.
+//! 분류 체계가 없는 프로젝트는 기존처럼 자유 입력 라벨을 그대로 허용합니다.
+
+use sqlx::PgPool;
+use crate::domain::entities::label::{Label, LabelTaxonomy};
+use crate::domain::ServiceError;
+
+/// 프로젝트 라벨 분류 체계 조회
+pub async fn load_label_taxonomy(pool: &PgPool, project_id: i32) -> Result<LabelTaxonomy, ServiceError> {
+    let labels = sqlx::query_as::<_, Label>(
+        "SELECT id, project_id, parent_id, name, display_name, color, code_scheme, code_value, code_meaning,
+                allowed_tools, aliases, created_at, updated_at
+         FROM annotation_label WHERE project_id = $1"
+    )
+    .bind(project_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| ServiceError::DatabaseError(format!("Failed to load label taxonomy: {}", e)))?;
+
+    Ok(LabelTaxonomy::new(labels))
+}
+
+/// 라벨을 프로젝트 분류 체계로 검증하고 저장할 정식 이름을 반환합니다.
+///
+/// 별칭이나 대소문자/구분자만 다른 라벨은 정식 이름으로 바뀌고, 도구가 주어지면 허용 도구도 확인합니다.
+pub async fn canonical_label(
+    pool: &PgPool,
+    project_id: i32,
+    label: &str,
+    tool_name: Option<&str>,
+) -> Result<String, ServiceError> {
+    let taxonomy = load_label_taxonomy(pool, project_id).await?;
+    if taxonomy.is_empty() {
+        return Ok(label.to_string());
+    }
+
+    taxonomy
+        .validate_usage(label, tool_name)
+        .map(|found| found.name.clone())
+        .map_err(ServiceError::ValidationError)
+}
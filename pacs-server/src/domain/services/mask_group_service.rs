@@ -2,7 +2,13 @@ use async_trait::async_trait;
 use std::sync::Arc;
 use crate::domain::entities::mask_group::{MaskGroup, NewMaskGroup, UpdateMaskGroup, MaskGroupStats};
 use crate::domain::entities::mask::Mask;
+use crate::domain::entities::mask_group_lineage::MaskGroupLineageNode;
 use crate::domain::repositories::{MaskGroupRepository, AnnotationRepository, UserRepository};
+use crate::domain::entities::realtime::{RealtimeEvent, RealtimeEventType};
+use crate::domain::entities::webhook::WebhookEventType;
+use crate::domain::entities::storage_quota::StorageQuotaLevel;
+use crate::domain::services::{edit_lock_guard, storage_usage, webhook_outbox};
+use crate::domain::services::realtime_events::publish_best_effort;
 use crate::domain::ServiceError;
 
 /// 마스크 그룹 서비스 trait
@@ -16,10 +22,11 @@ pub trait MaskGroupService: Send + Sync {
     async fn get_mask_group_by_id(&self, id: i32) -> Result<Option<MaskGroup>, ServiceError>;
     
     /// 마스크 그룹을 업데이트합니다.
-    async fn update_mask_group(&self, id: i32, update_mask_group: &UpdateMaskGroup) -> Result<MaskGroup, ServiceError>;
+    /// 다른 사용자가 어노테이션 또는 Study의 편집 잠금을 가지고 있으면 거부합니다.
+    async fn update_mask_group(&self, id: i32, update_mask_group: &UpdateMaskGroup, editor_id: i32) -> Result<MaskGroup, ServiceError>;
     
-    /// 마스크 그룹을 삭제합니다.
-    async fn delete_mask_group(&self, id: i32) -> Result<(), ServiceError>;
+    /// 마스크 그룹을 휴지통으로 이동합니다.
+    async fn delete_mask_group(&self, id: i32, deleted_by: i32) -> Result<(), ServiceError>;
     
     /// 어노테이션의 마스크 그룹 목록을 조회합니다.
     async fn list_mask_groups(
@@ -52,6 +59,20 @@ pub trait MaskGroupService: Send + Sync {
     
     /// 어노테이션에 마스크 그룹을 생성할 수 있는지 확인합니다.
     async fn can_create_mask_group(&self, user_id: i32, annotation_id: i32) -> Result<bool, ServiceError>;
+
+    /// 마스크 그룹 업로드 완료를 프로젝트 구독자에게 알립니다.
+    async fn complete_upload(&self, mask_group_id: i32, completed_by: i32) -> Result<(), ServiceError>;
+
+    /// 마스크 그룹 업로드 전 프로젝트/사용자 저장 용량 한도를 확인합니다.
+    /// 업로드 한도를 넘으면 거부하고, 경고 한도 이상이면 경고 메시지를 반환합니다.
+    async fn check_storage_quota(&self, mask_group_id: i32, upload_bytes: Option<i64>) -> Result<Option<String>, ServiceError>;
+
+    /// 어노테이션의 마스크 그룹 계보를 조회합니다.
+    async fn get_mask_group_lineage(&self, annotation_id: i32) -> Result<Vec<MaskGroupLineageNode>, ServiceError>;
+
+    /// 마스크 그룹의 파생 원본을 설정하거나 해제합니다.
+    /// 원본이 같은 어노테이션에 없거나 순환이 생기면 거부하며, 편집 잠금도 확인합니다.
+    async fn set_mask_group_derived_from(&self, id: i32, derived_from_id: Option<i32>, editor_id: i32) -> Result<MaskGroup, ServiceError>;
 }
 
 /// 마스크 그룹 서비스 구현체
@@ -102,7 +123,7 @@ where
         // 어노테이션이 존재하는지 확인 (트랜잭션 내에서)
         println!("🔍 [MaskGroupService] 어노테이션 존재 확인: annotation_id = {}", new_mask_group.annotation_id);
         let annotation = sqlx::query_scalar::<_, bool>(
-            "SELECT EXISTS(SELECT 1 FROM annotation_annotation WHERE id = $1)"
+            "SELECT EXISTS(SELECT 1 FROM annotation_annotation WHERE id = $1 AND deleted_at IS NULL)"
         )
         .bind(new_mask_group.annotation_id)
         .fetch_one(&mut *tx)
@@ -131,11 +152,30 @@ where
             }
         }
 
+        // 파생 원본은 같은 어노테이션의 휴지통에 없는 마스크 그룹이어야 함
+        if let Some(derived_from_id) = new_mask_group.derived_from_id {
+            let source_annotation_id = sqlx::query_scalar::<_, i32>(
+                "SELECT annotation_id FROM annotation_mask_group WHERE id = $1 AND deleted_at IS NULL FOR SHARE"
+            )
+            .bind(derived_from_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to check source mask group: {}", e)))?;
+
+            if source_annotation_id != Some(new_mask_group.annotation_id) {
+                tx.rollback().await.ok();
+                return Err(ServiceError::ValidationError(format!(
+                    "Mask group {} to derive from not found in annotation {}",
+                    derived_from_id, new_mask_group.annotation_id
+                )));
+            }
+        }
+
         // 마스크 그룹 생성 (트랜잭션 내에서)
         let mask_group = sqlx::query_as::<_, MaskGroup>(
-            "INSERT INTO annotation_mask_group (annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-             RETURNING id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, created_at, updated_at"
+            "INSERT INTO annotation_mask_group (annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, derived_from_id)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             RETURNING id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, derived_from_id, created_at, updated_at"
         )
         .bind(new_mask_group.annotation_id)
         .bind(&new_mask_group.group_name)
@@ -146,6 +186,7 @@ where
         .bind(&new_mask_group.mask_type)
         .bind(&new_mask_group.description)
         .bind(new_mask_group.created_by)
+        .bind(new_mask_group.derived_from_id)
         .fetch_one(&mut *tx)
         .await
         .map_err(|e| ServiceError::DatabaseError(format!("Failed to create mask group: {}", e)))?;
@@ -162,29 +203,44 @@ where
             .await
     }
 
-    async fn update_mask_group(&self, id: i32, update_mask_group: &UpdateMaskGroup) -> Result<MaskGroup, ServiceError> {
+    async fn update_mask_group(&self, id: i32, update_mask_group: &UpdateMaskGroup, editor_id: i32) -> Result<MaskGroup, ServiceError> {
         // 마스크 그룹이 존재하는지 확인
         let existing_mask_group = self.mask_group_repository
             .get_by_id(id)
             .await?
             .ok_or_else(|| ServiceError::NotFound(format!("Mask group with id {} not found", id)))?;
 
+        // 편집 잠금 확인
+        let annotation = self.annotation_repository
+            .find_by_id(existing_mask_group.annotation_id)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to get annotation: {}", e)))?
+            .ok_or_else(|| ServiceError::NotFound(format!("Annotation with id {} not found", existing_mask_group.annotation_id)))?;
+        edit_lock_guard::ensure_editable(
+            self.annotation_repository.pool(),
+            annotation.project_id,
+            &annotation.study_uid,
+            annotation.id,
+            editor_id,
+        )
+        .await?;
+
         // 업데이트 실행
         self.mask_group_repository
             .update(id, update_mask_group)
             .await
     }
 
-    async fn delete_mask_group(&self, id: i32) -> Result<(), ServiceError> {
+    async fn delete_mask_group(&self, id: i32, deleted_by: i32) -> Result<(), ServiceError> {
         // 마스크 그룹이 존재하는지 확인
         let existing_mask_group = self.mask_group_repository
             .get_by_id(id)
             .await?
             .ok_or_else(|| ServiceError::NotFound(format!("Mask group with id {} not found", id)))?;
 
-        // 삭제 실행
+        // 휴지통으로 이동
         self.mask_group_repository
-            .delete(id)
+            .delete(id, deleted_by)
             .await
     }
 
@@ -325,4 +381,95 @@ where
         // 여기서는 간단히 user_id로 확인 (실제로는 프로젝트 권한 확인 필요)
         Ok(annotation.user_id == user_id)
     }
+
+    async fn complete_upload(&self, mask_group_id: i32, completed_by: i32) -> Result<(), ServiceError> {
+        let mask_group = self.mask_group_repository
+            .get_by_id(mask_group_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Mask group with id {} not found", mask_group_id)))?;
+        let annotation = self.annotation_repository
+            .find_by_id(mask_group.annotation_id)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to find annotation: {}", e)))?
+            .ok_or_else(|| ServiceError::NotFound(format!("Annotation with id {} not found", mask_group.annotation_id)))?;
+
+        // Webhook 이벤트는 기록에 실패하면 업로드 완료 요청도 실패로 응답 (클라이언트가 다시 완료 요청)
+        let payload = serde_json::json!({
+            "mask_group_id": mask_group.id,
+            "annotation_id": annotation.id,
+            "study_uid": annotation.study_uid,
+            "group_name": mask_group.group_name,
+            "slice_count": mask_group.slice_count,
+            "completed_by": completed_by,
+        });
+        webhook_outbox::enqueue(self.annotation_repository.pool(), annotation.project_id, WebhookEventType::MaskGroupUploaded, &payload)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to record webhook event: {}", e)))?;
+
+        let event = RealtimeEvent::new(
+            RealtimeEventType::MaskGroupUploaded,
+            annotation.project_id,
+            Some(annotation.study_uid),
+            mask_group.id,
+            Some(completed_by),
+        )
+        .with_annotation_visibility(Some(annotation.user_id), annotation.is_shared);
+        publish_best_effort(self.annotation_repository.pool(), event).await;
+        Ok(())
+    }
+
+    async fn check_storage_quota(&self, mask_group_id: i32, upload_bytes: Option<i64>) -> Result<Option<String>, ServiceError> {
+        if upload_bytes.is_some_and(|bytes| bytes < 0) {
+            return Err(ServiceError::ValidationError("File size cannot be negative".to_string()));
+        }
+        let check = storage_usage::check_mask_group_quota(self.annotation_repository.pool(), mask_group_id, upload_bytes)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to check storage quota: {}", e)))?
+            .ok_or_else(|| ServiceError::NotFound(format!("Mask group with id {} not found", mask_group_id)))?;
+
+        match check.level() {
+            StorageQuotaLevel::HardLimitExceeded => Err(ServiceError::ValidationError(check.message().unwrap_or_default())),
+            _ => Ok(check.message()),
+        }
+    }
+
+    async fn get_mask_group_lineage(&self, annotation_id: i32) -> Result<Vec<MaskGroupLineageNode>, ServiceError> {
+        self.mask_group_repository
+            .find_lineage(annotation_id)
+            .await
+    }
+
+    async fn set_mask_group_derived_from(&self, id: i32, derived_from_id: Option<i32>, editor_id: i32) -> Result<MaskGroup, ServiceError> {
+        let existing_mask_group = self.mask_group_repository
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Mask group with id {} not found", id)))?;
+
+        // 편집 잠금 확인
+        let annotation = self.annotation_repository
+            .find_by_id(existing_mask_group.annotation_id)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to get annotation: {}", e)))?
+            .ok_or_else(|| ServiceError::NotFound(format!("Annotation with id {} not found", existing_mask_group.annotation_id)))?;
+        edit_lock_guard::ensure_editable(
+            self.annotation_repository.pool(),
+            annotation.project_id,
+            &annotation.study_uid,
+            annotation.id,
+            editor_id,
+        )
+        .await?;
+
+        self.mask_group_repository
+            .set_derived_from(id, derived_from_id)
+            .await?
+            .ok_or_else(|| {
+                ServiceError::ValidationError(format!(
+                    "Mask group {} cannot derive from mask group {}: it must be another mask group in annotation {} without creating a cycle",
+                    id,
+                    derived_from_id.unwrap_or_default(),
+                    annotation.id
+                ))
+            })
+    }
 }
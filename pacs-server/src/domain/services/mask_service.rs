@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 use crate::domain::entities::mask::{Mask, NewMask, UpdateMask, MaskStats};
+use crate::domain::entities::mask_group::MaskGroup;
+use crate::domain::entities::mask_preview::MaskPreview;
 use crate::domain::repositories::{MaskRepository, MaskGroupRepository, UserRepository};
+use crate::domain::services::label_validation::canonical_label;
 use crate::domain::ServiceError;
 
 /// 마스크 서비스 trait
@@ -48,6 +51,9 @@ pub trait MaskService: Send + Sync {
     
     /// 마스크 그룹에 마스크를 생성할 수 있는지 확인합니다.
     async fn can_create_mask(&self, user_id: i32, mask_group_id: i32) -> Result<bool, ServiceError>;
+    
+    /// 마스크들의 썸네일/미리보기 객체를 조회합니다.
+    async fn get_mask_previews(&self, mask_ids: &[i32]) -> Result<Vec<MaskPreview>, ServiceError>;
 }
 
 /// 마스크 서비스 구현체
@@ -81,6 +87,23 @@ where
             user_repository,
         }
     }
+
+    /// 마스크 그룹이 속한 프로젝트의 라벨 분류 체계로 `label_name`을 검증하고 정식 이름으로 바꿈
+    async fn apply_label_taxonomy(&self, mask_group: &MaskGroup, label_name: &mut Option<String>) -> Result<(), ServiceError> {
+        let Some(label) = label_name.as_deref().filter(|label| !label.trim().is_empty()) else {
+            return Ok(());
+        };
+
+        let pool = self.user_repository.pool();
+        let project_id = sqlx::query_scalar::<_, i32>("SELECT project_id FROM annotation_annotation WHERE id = $1")
+            .bind(mask_group.annotation_id)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(e.to_string()))?;
+
+        *label_name = Some(canonical_label(pool, project_id, label, None).await?);
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -97,9 +120,13 @@ where
             .await?
             .ok_or_else(|| ServiceError::NotFound(format!("Mask group with id {} not found", new_mask.mask_group_id)))?;
 
+        // 프로젝트 라벨 분류 체계 검증
+        let mut new_mask = new_mask.clone();
+        self.apply_label_taxonomy(&mask_group, &mut new_mask.label_name).await?;
+
         // 마스크 생성
         self.mask_repository
-            .create(new_mask)
+            .create(&new_mask)
             .await
     }
 
@@ -116,9 +143,19 @@ where
             .await?
             .ok_or_else(|| ServiceError::NotFound(format!("Mask with id {} not found", id)))?;
 
+        // 프로젝트 라벨 분류 체계 검증
+        let mut update_mask = update_mask.clone();
+        if update_mask.label_name.is_some() {
+            let mask_group = self.mask_group_repository
+                .get_by_id(existing_mask.mask_group_id)
+                .await?
+                .ok_or_else(|| ServiceError::NotFound(format!("Mask group with id {} not found", existing_mask.mask_group_id)))?;
+            self.apply_label_taxonomy(&mask_group, &mut update_mask.label_name).await?;
+        }
+
         // 업데이트 실행
         self.mask_repository
-            .update(id, update_mask)
+            .update(id, &update_mask)
             .await
     }
 
@@ -129,7 +166,7 @@ where
             .await?
             .ok_or_else(|| ServiceError::NotFound(format!("Mask with id {} not found", id)))?;
 
-        // 삭제 실행
+        // 삭제 실행 (마스크 파일은 삭제 대기열에서 지움)
         self.mask_repository
             .delete(id)
             .await
@@ -228,4 +265,13 @@ where
         // 여기서는 간단히 created_by로 확인 (실제로는 프로젝트 권한 확인 필요)
         Ok(mask_group.created_by == Some(user_id))
     }
+
+    async fn get_mask_previews(&self, mask_ids: &[i32]) -> Result<Vec<MaskPreview>, ServiceError> {
+        if mask_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.mask_repository
+            .find_previews(mask_ids)
+            .await
+    }
 }
@@ -0,0 +1,107 @@
+//! 어노테이션 측정값 계산
+//!
+//! 어노테이션이 생성/수정될 때와 재계산을 요청할 때, 등록된 인스턴스 메타데이터의 픽셀 간격으로
+//! 도형의 측정값을 계산해 클라이언트 측정값과 함께 저장합니다.
+
+use sqlx::{PgExecutor, PgPool};
+use crate::domain::entities::measurement::{AnnotationMeasurement, MeasurementComputation, PixelSpacing, SpacingSource};
+use crate::domain::entities::Annotation;
+
+const MEASUREMENT_COLUMNS: &str = "annotation_id, geometry_type, measurements, pixel_spacing_row, pixel_spacing_column,
+    spacing_source, discrepancies, has_discrepancy, error, computed_at";
+
+/// 어노테이션 인스턴스의 픽셀 간격 (없으면 같은 Series의 다른 인스턴스)
+pub async fn find_pixel_spacing<'e, E>(
+    executor: E,
+    project_id: i32,
+    series_uid: Option<&str>,
+    instance_uid: Option<&str>,
+) -> Result<Option<(PixelSpacing, SpacingSource)>, sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    if series_uid.is_none() && instance_uid.is_none() {
+        return Ok(None);
+    }
+    let row = sqlx::query_as::<_, (f64, f64, bool)>(
+        "SELECT pixel_spacing_row, pixel_spacing_column, instance_uid = $3 AS same_instance
+         FROM dicom_instance_metadata
+         WHERE project_id = $1 AND (instance_uid = $3 OR series_uid = $2)
+         ORDER BY (instance_uid = $3) DESC, updated_at DESC
+         LIMIT 1"
+    )
+    .bind(project_id)
+    .bind(series_uid)
+    .bind(instance_uid)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(row.map(|(row, column, same_instance)| {
+        let source = if same_instance { SpacingSource::Instance } else { SpacingSource::Series };
+        (PixelSpacing { row, column }, source)
+    }))
+}
+
+/// 계산 결과 저장 (이전 결과를 덮어씀)
+pub async fn save<'e, E>(executor: E, annotation_id: i32, computation: &MeasurementComputation) -> Result<AnnotationMeasurement, sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    let encode = |value: serde_json::Result<serde_json::Value>| {
+        value.map_err(|e| sqlx::Error::Protocol(format!("Failed to encode measurements: {}", e)))
+    };
+    sqlx::query_as::<_, AnnotationMeasurement>(&format!(
+        "INSERT INTO annotation_measurement (annotation_id, geometry_type, measurements, pixel_spacing_row, pixel_spacing_column,
+                                             spacing_source, discrepancies, has_discrepancy, error, computed_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, CURRENT_TIMESTAMP)
+         ON CONFLICT (annotation_id) DO UPDATE
+         SET geometry_type = EXCLUDED.geometry_type,
+             measurements = EXCLUDED.measurements,
+             pixel_spacing_row = EXCLUDED.pixel_spacing_row,
+             pixel_spacing_column = EXCLUDED.pixel_spacing_column,
+             spacing_source = EXCLUDED.spacing_source,
+             discrepancies = EXCLUDED.discrepancies,
+             has_discrepancy = EXCLUDED.has_discrepancy,
+             error = EXCLUDED.error,
+             computed_at = EXCLUDED.computed_at
+         RETURNING {}",
+        MEASUREMENT_COLUMNS
+    ))
+    .bind(annotation_id)
+    .bind(computation.geometry_type.map(|t| t.as_str()))
+    .bind(encode(serde_json::to_value(&computation.measurements))?)
+    .bind(computation.pixel_spacing.map(|s| s.row))
+    .bind(computation.pixel_spacing.map(|s| s.column))
+    .bind(computation.spacing_source.as_str())
+    .bind(encode(serde_json::to_value(&computation.discrepancies))?)
+    .bind(!computation.discrepancies.is_empty())
+    .bind(&computation.error)
+    .fetch_one(executor)
+    .await
+}
+
+/// 어노테이션의 측정값을 계산해서 저장
+pub async fn refresh(pool: &PgPool, annotation: &Annotation) -> Result<AnnotationMeasurement, sqlx::Error> {
+    let spacing = find_pixel_spacing(
+        pool,
+        annotation.project_id,
+        annotation.series_uid.as_deref(),
+        annotation.instance_uid.as_deref(),
+    )
+    .await?;
+    let computation = MeasurementComputation::compute(
+        &annotation.tool_name,
+        &annotation.data,
+        annotation.measurement_values.as_ref(),
+        spacing.map(|(spacing, _)| spacing),
+        spacing.map(|(_, source)| source).unwrap_or(SpacingSource::None),
+    );
+    save(pool, annotation.id, &computation).await
+}
+
+/// 이미 반영된 어노테이션 변경의 측정값 계산 (실패해도 변경 결과에 영향을 주지 않으며, 재계산으로 복구)
+pub async fn refresh_best_effort(pool: &PgPool, annotation: &Annotation) {
+    if let Err(e) = refresh(pool, annotation).await {
+        eprintln!("Failed to compute measurements for annotation {}: {}", annotation.id, e);
+    }
+}
@@ -7,11 +7,18 @@ pub mod auth_service;
 pub mod annotation_service;
 pub mod mask_group_service;
 pub mod mask_service;
+pub mod label_validation;
+pub mod edit_lock_guard;
+pub mod realtime_events;
+pub mod webhook_outbox;
+pub mod storage_deletion_queue;
+pub mod storage_usage;
+pub mod measurement_computation;
 pub mod project_data_service;
 pub mod user_registration_service;
 
 pub use user_service::{UserService, UserServiceImpl};
-pub use project_service::{ProjectService, ProjectServiceImpl};
+pub use project_service::{ensure_project_admin, ProjectService, ProjectServiceImpl, PROJECT_ADMIN_ROLE};
 pub use permission_service::{PermissionService, PermissionServiceImpl};
 pub use capability_service::CapabilityService;
 pub use access_control_service::{AccessControlService, AccessControlServiceImpl};
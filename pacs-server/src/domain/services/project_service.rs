@@ -4,6 +4,29 @@ use crate::domain::ServiceError;
 use crate::application::dto::project_dto::ProjectListQuery;
 use async_trait::async_trait;
 
+/// 프로젝트 관리 권한을 가진 역할 (라벨, 워크리스트, 편집 잠금, Webhook, 측정값, 스토리지 한도 관리)
+pub const PROJECT_ADMIN_ROLE: &str = "PROJECT_ADMIN";
+
+/// 사용자가 프로젝트 관리자인지 확인 (아니면 Unauthorized, `action`은 오류 메시지에 쓰임)
+pub async fn ensure_project_admin(
+    project_repository: &dyn ProjectRepository,
+    project_id: i32,
+    user_id: i32,
+    action: &str,
+) -> Result<(), ServiceError> {
+    let role = project_repository
+        .find_member_role(project_id, user_id)
+        .await
+        .map_err(|e| ServiceError::DatabaseError(format!("Failed to get project role: {}", e)))?;
+    if role.as_deref() != Some(PROJECT_ADMIN_ROLE) {
+        return Err(ServiceError::Unauthorized(format!(
+            "Only {} can {} of project {}",
+            PROJECT_ADMIN_ROLE, action, project_id
+        )));
+    }
+    Ok(())
+}
+
 /// 프로젝트 관리 도메인 서비스
 #[async_trait]
 pub trait ProjectService: Send + Sync {
@@ -0,0 +1,29 @@
+//! 실시간 변경 이벤트 발행
+//!
+//! 이벤트는 Postgres `NOTIFY`로 발행되며, 트랜잭션 안에서 발행하면 커밋될 때 전달됩니다.
+//! 각 서버 워커의 리스너가 이벤트를 받아 자신의 구독자에게 전달합니다.
+
+use sqlx::{PgExecutor, PgPool};
+use crate::domain::entities::realtime::{RealtimeEvent, REALTIME_CHANNEL};
+
+/// 이벤트 발행
+pub async fn publish<'e, E>(executor: E, event: &RealtimeEvent) -> Result<(), sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    let payload = serde_json::to_string(event)
+        .map_err(|e| sqlx::Error::Protocol(format!("Failed to encode realtime event: {}", e)))?;
+    sqlx::query("SELECT pg_notify($1, $2)")
+        .bind(REALTIME_CHANNEL)
+        .bind(payload)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
+/// 이미 반영된 변경의 이벤트 발행 (발행 실패는 변경 결과에 영향을 주지 않음)
+pub async fn publish_best_effort(pool: &PgPool, event: RealtimeEvent) {
+    if let Err(e) = publish(pool, &event).await {
+        eprintln!("Failed to publish realtime event {}: {}", event.event_type.as_str(), e);
+    }
+}
@@ -0,0 +1,47 @@
+//! Object Storage 삭제 대기열
+//!
+//! 행을 삭제하는 트랜잭션에서 지워야 할 객체를 기록하므로, 삭제가 커밋되면 객체 삭제도 반드시 예약됩니다.
+//! 기록된 항목은 삭제 작업이 Object Storage에서 지우고, 실패하면 재시도합니다.
+
+use sqlx::PgExecutor;
+use crate::domain::entities::storage_deletion::StorageDeletionReason;
+
+/// 객체 삭제 예약 (빈 경로는 무시, 예약한 항목 수 반환)
+pub async fn enqueue_objects<'e, E>(
+    executor: E,
+    object_keys: &[String],
+    reason: StorageDeletionReason,
+) -> Result<u64, sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    if object_keys.is_empty() {
+        return Ok(0);
+    }
+    let result = sqlx::query(
+        "INSERT INTO storage_deletion_queue (object_key, reason)
+         SELECT key, $2 FROM UNNEST($1::TEXT[]) AS key WHERE key <> ''"
+    )
+    .bind(object_keys)
+    .bind(reason.as_str())
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// prefix로 시작하는 모든 객체의 삭제 예약 (행이 없는 업로드 잔여물까지 정리)
+pub async fn enqueue_prefix<'e, E>(
+    executor: E,
+    prefix: &str,
+    reason: StorageDeletionReason,
+) -> Result<(), sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    sqlx::query("INSERT INTO storage_deletion_queue (object_key, is_prefix, reason) VALUES ($1, TRUE, $2)")
+        .bind(prefix)
+        .bind(reason.as_str())
+        .execute(executor)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,212 @@
+//! 저장 용량 사용량 집계
+//!
+//! 마스크 행을 만들거나 지우는 트랜잭션에서 사용량을 갱신하므로, 행이 커밋되면 사용량도 반드시 함께 바뀝니다.
+//! 사용량은 마스크 그룹의 프로젝트와 생성자(생성자가 없으면 어노테이션 작성자)에 귀속되며,
+//! 갱신 후 경고 한도를 처음 넘으면 같은 트랜잭션에서 `STORAGE_QUOTA_THRESHOLD_REACHED` 이벤트를 기록합니다.
+
+use sqlx::{PgConnection, PgExecutor};
+use crate::domain::entities::storage_quota::{StorageQuotaCheck, StorageQuotaScope, StorageQuotaStatus};
+use crate::domain::entities::webhook::WebhookEventType;
+use crate::domain::services::webhook_outbox;
+
+/// 마스크 그룹의 사용량 귀속 대상 (project_id, user_id)
+const MASK_GROUP_OWNER_SQL: &str =
+    "SELECT a.project_id, COALESCE(u.id, a.user_id) AS user_id
+     FROM annotation_mask_group g
+     JOIN annotation_annotation a ON a.id = g.annotation_id
+     LEFT JOIN security_user u ON u.id = g.created_by
+     WHERE g.id = $1";
+
+/// 마스크 추가/삭제/크기 변경 반영 (마스크 그룹이 없으면 무시)
+pub async fn record_mask_change(
+    conn: &mut PgConnection,
+    mask_group_id: i32,
+    bytes_delta: i64,
+    object_delta: i64,
+) -> Result<(), sqlx::Error> {
+    if bytes_delta == 0 && object_delta == 0 {
+        return Ok(());
+    }
+    let owner = sqlx::query_as::<_, (i32, i32)>(MASK_GROUP_OWNER_SQL)
+        .bind(mask_group_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+    match owner {
+        Some((project_id, user_id)) => apply_delta(conn, project_id, user_id, bytes_delta, object_delta).await,
+        None => Ok(()),
+    }
+}
+
+/// 영구 삭제할 마스크 그룹들의 마스크 사용량 차감 (행을 삭제하기 전에 호출)
+pub async fn release_mask_groups(conn: &mut PgConnection, mask_group_ids: &[i32]) -> Result<(), sqlx::Error> {
+    if mask_group_ids.is_empty() {
+        return Ok(());
+    }
+    let totals = sqlx::query_as::<_, (i32, i32, i64, i64)>(
+        "SELECT a.project_id, COALESCE(u.id, a.user_id), COALESCE(SUM(m.file_size), 0)::BIGINT, COUNT(*)
+         FROM annotation_mask m
+         JOIN annotation_mask_group g ON g.id = m.mask_group_id
+         JOIN annotation_annotation a ON a.id = g.annotation_id
+         LEFT JOIN security_user u ON u.id = g.created_by
+         WHERE g.id = ANY($1)
+         GROUP BY 1, 2"
+    )
+    .bind(mask_group_ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for (project_id, user_id, bytes, objects) in totals {
+        apply_delta(conn, project_id, user_id, -bytes, -objects).await?;
+    }
+    Ok(())
+}
+
+/// 마스크 행으로 사용량 전체를 다시 계산 (갱신된 (프로젝트, 사용자) 수 반환)
+pub async fn recalculate(conn: &mut PgConnection) -> Result<u64, sqlx::Error> {
+    sqlx::query("DELETE FROM storage_usage").execute(&mut *conn).await?;
+    let result = sqlx::query(
+        "INSERT INTO storage_usage (project_id, user_id, bytes_used, object_count)
+         SELECT a.project_id, COALESCE(u.id, a.user_id), COALESCE(SUM(m.file_size), 0), COUNT(*)
+         FROM annotation_mask m
+         JOIN annotation_mask_group g ON g.id = m.mask_group_id
+         JOIN annotation_annotation a ON a.id = g.annotation_id
+         LEFT JOIN security_user u ON u.id = g.created_by
+         GROUP BY 1, 2"
+    )
+    .execute(&mut *conn)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+async fn apply_delta(
+    conn: &mut PgConnection,
+    project_id: i32,
+    user_id: i32,
+    bytes_delta: i64,
+    object_delta: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO storage_usage (project_id, user_id, bytes_used, object_count)
+         VALUES ($1, $2, GREATEST($3, 0), GREATEST($4, 0))
+         ON CONFLICT (project_id, user_id) DO UPDATE
+         SET bytes_used = GREATEST(storage_usage.bytes_used + $3, 0),
+             object_count = GREATEST(storage_usage.object_count + $4, 0),
+             updated_at = CURRENT_TIMESTAMP"
+    )
+    .bind(project_id)
+    .bind(user_id)
+    .bind(bytes_delta)
+    .bind(object_delta)
+    .execute(&mut *conn)
+    .await?;
+
+    refresh_alert(conn, StorageQuotaScope::Project, project_id, project_id).await?;
+    refresh_alert(conn, StorageQuotaScope::User, user_id, project_id).await
+}
+
+/// 경고 한도를 처음 넘으면 이벤트 기록, 한도 아래로 내려가면 다시 알릴 수 있도록 초기화
+///
+/// 사용자 한도 이벤트는 사용량을 바꾼 프로젝트의 구독자에게 전송됩니다.
+async fn refresh_alert(
+    conn: &mut PgConnection,
+    scope: StorageQuotaScope,
+    scope_id: i32,
+    event_project_id: i32,
+) -> Result<(), sqlx::Error> {
+    let column = match scope {
+        StorageQuotaScope::Project => "project_id",
+        StorageQuotaScope::User => "user_id",
+    };
+    let crossed = sqlx::query_as::<_, (i64, i64, Option<i64>)>(&format!(
+        "WITH usage AS (
+             SELECT COALESCE(SUM(bytes_used), 0)::BIGINT AS bytes FROM storage_usage WHERE {column} = $1
+         )
+         UPDATE storage_quota q
+         SET alerted_at = CASE WHEN usage.bytes >= q.soft_limit_bytes THEN CURRENT_TIMESTAMP END
+         FROM usage
+         WHERE q.{column} = $1
+           AND q.soft_limit_bytes IS NOT NULL
+           AND (q.alerted_at IS NULL) = (usage.bytes >= q.soft_limit_bytes)
+         RETURNING usage.bytes, q.soft_limit_bytes, q.hard_limit_bytes",
+        column = column
+    ))
+    .bind(scope_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    if let Some((bytes_used, soft_limit_bytes, hard_limit_bytes)) = crossed {
+        if bytes_used >= soft_limit_bytes {
+            let payload = serde_json::json!({
+                "scope": scope.as_str(),
+                "project_id": event_project_id,
+                "user_id": (scope == StorageQuotaScope::User).then_some(scope_id),
+                "bytes_used": bytes_used,
+                "soft_limit_bytes": soft_limit_bytes,
+                "hard_limit_bytes": hard_limit_bytes,
+            });
+            webhook_outbox::enqueue(&mut *conn, event_project_id, WebhookEventType::StorageQuotaThresholdReached, &payload)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct QuotaCheckRow {
+    project_id: i32,
+    user_id: i32,
+    project_bytes_used: i64,
+    project_soft_limit_bytes: Option<i64>,
+    project_hard_limit_bytes: Option<i64>,
+    user_bytes_used: i64,
+    user_soft_limit_bytes: Option<i64>,
+    user_hard_limit_bytes: Option<i64>,
+}
+
+/// 마스크 그룹에 업로드하기 전 프로젝트/사용자 한도 확인 (마스크 그룹이 없으면 None)
+pub async fn check_mask_group_quota<'e, E>(
+    executor: E,
+    mask_group_id: i32,
+    upload_bytes: Option<i64>,
+) -> Result<Option<StorageQuotaCheck>, sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    let row = sqlx::query_as::<_, QuotaCheckRow>(&format!(
+        "WITH owner AS ({})
+         SELECT o.project_id, o.user_id,
+                (SELECT COALESCE(SUM(s.bytes_used), 0)::BIGINT FROM storage_usage s WHERE s.project_id = o.project_id)
+                    AS project_bytes_used,
+                pq.soft_limit_bytes AS project_soft_limit_bytes,
+                pq.hard_limit_bytes AS project_hard_limit_bytes,
+                (SELECT COALESCE(SUM(s.bytes_used), 0)::BIGINT FROM storage_usage s WHERE s.user_id = o.user_id)
+                    AS user_bytes_used,
+                uq.soft_limit_bytes AS user_soft_limit_bytes,
+                uq.hard_limit_bytes AS user_hard_limit_bytes
+         FROM owner o
+         LEFT JOIN storage_quota pq ON pq.project_id = o.project_id
+         LEFT JOIN storage_quota uq ON uq.user_id = o.user_id",
+        MASK_GROUP_OWNER_SQL
+    ))
+    .bind(mask_group_id)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(row.map(|row| StorageQuotaCheck {
+        project: StorageQuotaStatus {
+            scope: StorageQuotaScope::Project,
+            scope_id: row.project_id,
+            bytes_used: row.project_bytes_used,
+            soft_limit_bytes: row.project_soft_limit_bytes,
+            hard_limit_bytes: row.project_hard_limit_bytes,
+        },
+        user: StorageQuotaStatus {
+            scope: StorageQuotaScope::User,
+            scope_id: row.user_id,
+            bytes_used: row.user_bytes_used,
+            soft_limit_bytes: row.user_soft_limit_bytes,
+            hard_limit_bytes: row.user_hard_limit_bytes,
+        },
+        upload_bytes,
+    }))
+}
@@ -0,0 +1,27 @@
+//! Webhook 트랜잭션 아웃박스
+//!
+//! 도메인 변경과 같은 트랜잭션(또는 같은 문장)에서 이벤트를 기록하므로, 변경이 커밋되면 이벤트도 반드시 남습니다.
+//! 기록된 이벤트는 디스패처가 구독별 전송 작업으로 나눈 뒤 전송합니다.
+
+use sqlx::PgExecutor;
+use crate::domain::entities::webhook::WebhookEventType;
+
+/// 아웃박스에 이벤트 기록 (이벤트 ID 반환)
+pub async fn enqueue<'e, E>(
+    executor: E,
+    project_id: i32,
+    event_type: WebhookEventType,
+    payload: &serde_json::Value,
+) -> Result<i64, sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    sqlx::query_scalar::<_, i64>(
+        "INSERT INTO webhook_outbox_event (project_id, event_type, payload) VALUES ($1, $2, $3) RETURNING id"
+    )
+    .bind(project_id)
+    .bind(event_type.as_str())
+    .bind(payload)
+    .fetch_one(executor)
+    .await
+}
@@ -0,0 +1,23 @@
+//! # 아카이브 모듈
+//!
+//! 전체 아카이브를 메모리에 올리지 않고 항목 단위로 ZIP 청크를 만들어 응답 스트림으로 내보내는 기능을 제공합니다.
+
+pub mod zip_stream;
+
+pub use zip_stream::ZipStreamWriter;
+
+/// 아카이브 에러 타입
+#[derive(Debug, thiserror::Error)]
+pub enum ArchiveError {
+    #[error("Invalid entry name: {0}")]
+    InvalidEntryName(String),
+
+    #[error("Archive too large: {0}")]
+    TooLarge(String),
+}
+
+impl From<ArchiveError> for crate::domain::ServiceError {
+    fn from(err: ArchiveError) -> Self {
+        crate::domain::ServiceError::ExternalServiceError(err.to_string())
+    }
+}
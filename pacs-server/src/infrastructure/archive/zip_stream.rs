@@ -0,0 +1,208 @@
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use flate2::Crc;
+
+use super::ArchiveError;
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+
+/// ZIP 2.0 (저장 방식 읽기에 필요한 최소 버전)
+const ZIP_VERSION: u16 = 20;
+/// 범용 플래그: 파일명이 UTF-8
+const FLAG_UTF8: u16 = 0x0800;
+/// 압축 방식: 저장 (PNG는 이미 압축되어 있음)
+const METHOD_STORED: u16 = 0;
+
+/// ZIP64 없이 담을 수 있는 최대 항목 수
+pub const MAX_ZIP_ENTRIES: usize = u16::MAX as usize;
+
+/// 중앙 디렉터리 항목
+struct CentralDirectoryEntry {
+    name: String,
+    crc32: u32,
+    size: u32,
+    offset: u32,
+}
+
+/// 항목을 하나씩 받아 ZIP 청크로 변환하는 스트리밍 인코더
+///
+/// 항목 데이터만 받아 로컬 헤더와 함께 바로 내보내고, 중앙 디렉터리에 필요한 정보만 보관합니다.
+/// 모든 항목은 압축하지 않고 저장하며 ZIP64는 지원하지 않으므로 아카이브는 4 GiB, 65,535개 항목으로 제한됩니다.
+pub struct ZipStreamWriter {
+    entries: Vec<CentralDirectoryEntry>,
+    offset: u64,
+    dos_time: u16,
+    dos_date: u16,
+}
+
+impl ZipStreamWriter {
+    /// 모든 항목의 수정 시각을 `modified_at`으로 하는 인코더 생성
+    pub fn new(modified_at: DateTime<Utc>) -> Self {
+        let (dos_time, dos_date) = dos_date_time(modified_at);
+        Self {
+            entries: Vec::new(),
+            offset: 0,
+            dos_time,
+            dos_date,
+        }
+    }
+
+    /// 항목 하나를 로컬 헤더와 데이터로 인코딩
+    pub fn add_entry(&mut self, name: &str, data: &[u8]) -> Result<Vec<u8>, ArchiveError> {
+        if name.is_empty() || name.starts_with('/') || name.split('/').any(|segment| segment == "..") {
+            return Err(ArchiveError::InvalidEntryName(name.to_string()));
+        }
+        if self.entries.len() >= MAX_ZIP_ENTRIES {
+            return Err(ArchiveError::TooLarge(format!("at most {} entries are supported", MAX_ZIP_ENTRIES)));
+        }
+        let name_len = u16::try_from(name.len()).map_err(|_| ArchiveError::InvalidEntryName(name.to_string()))?;
+        let size = u32::try_from(data.len())
+            .map_err(|_| ArchiveError::TooLarge(format!("entry '{}' exceeds 4 GiB", name)))?;
+        let offset = u32::try_from(self.offset)
+            .map_err(|_| ArchiveError::TooLarge("archive exceeds 4 GiB".to_string()))?;
+
+        let mut crc = Crc::new();
+        crc.update(data);
+        let crc32 = crc.sum();
+
+        let mut chunk = Vec::with_capacity(30 + name.len() + data.len());
+        put_u32(&mut chunk, LOCAL_FILE_HEADER_SIGNATURE);
+        put_u16(&mut chunk, ZIP_VERSION);
+        put_u16(&mut chunk, FLAG_UTF8);
+        put_u16(&mut chunk, METHOD_STORED);
+        put_u16(&mut chunk, self.dos_time);
+        put_u16(&mut chunk, self.dos_date);
+        put_u32(&mut chunk, crc32);
+        put_u32(&mut chunk, size); // 압축 크기
+        put_u32(&mut chunk, size); // 원본 크기
+        put_u16(&mut chunk, name_len);
+        put_u16(&mut chunk, 0); // extra field 길이
+        chunk.extend_from_slice(name.as_bytes());
+        chunk.extend_from_slice(data);
+
+        self.offset += chunk.len() as u64;
+        self.entries.push(CentralDirectoryEntry {
+            name: name.to_string(),
+            crc32,
+            size,
+            offset,
+        });
+        Ok(chunk)
+    }
+
+    /// 중앙 디렉터리와 종료 레코드를 인코딩
+    pub fn finish(self) -> Result<Vec<u8>, ArchiveError> {
+        let directory_offset = u32::try_from(self.offset)
+            .map_err(|_| ArchiveError::TooLarge("archive exceeds 4 GiB".to_string()))?;
+
+        let mut chunk = Vec::new();
+        for entry in &self.entries {
+            put_u32(&mut chunk, CENTRAL_DIRECTORY_SIGNATURE);
+            put_u16(&mut chunk, ZIP_VERSION); // 생성 버전
+            put_u16(&mut chunk, ZIP_VERSION); // 필요 버전
+            put_u16(&mut chunk, FLAG_UTF8);
+            put_u16(&mut chunk, METHOD_STORED);
+            put_u16(&mut chunk, self.dos_time);
+            put_u16(&mut chunk, self.dos_date);
+            put_u32(&mut chunk, entry.crc32);
+            put_u32(&mut chunk, entry.size);
+            put_u32(&mut chunk, entry.size);
+            put_u16(&mut chunk, entry.name.len() as u16);
+            put_u16(&mut chunk, 0); // extra field 길이
+            put_u16(&mut chunk, 0); // 주석 길이
+            put_u16(&mut chunk, 0); // 디스크 번호
+            put_u16(&mut chunk, 0); // 내부 속성
+            put_u32(&mut chunk, 0); // 외부 속성
+            put_u32(&mut chunk, entry.offset);
+            chunk.extend_from_slice(entry.name.as_bytes());
+        }
+        let directory_size = u32::try_from(chunk.len())
+            .map_err(|_| ArchiveError::TooLarge("central directory exceeds 4 GiB".to_string()))?;
+        let entry_count = self.entries.len() as u16;
+
+        put_u32(&mut chunk, END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        put_u16(&mut chunk, 0); // 디스크 번호
+        put_u16(&mut chunk, 0); // 중앙 디렉터리 시작 디스크
+        put_u16(&mut chunk, entry_count);
+        put_u16(&mut chunk, entry_count);
+        put_u32(&mut chunk, directory_size);
+        put_u32(&mut chunk, directory_offset);
+        put_u16(&mut chunk, 0); // 주석 길이
+        Ok(chunk)
+    }
+}
+
+fn put_u16(buffer: &mut Vec<u8>, value: u16) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(buffer: &mut Vec<u8>, value: u32) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+/// MS-DOS 형식 (시각, 날짜), 1980년 이전은 1980-01-01로 고정
+fn dos_date_time(at: DateTime<Utc>) -> (u16, u16) {
+    if at.year() < 1980 {
+        return (0, (1 << 5) | 1);
+    }
+    let time = ((at.hour() as u16) << 11) | ((at.minute() as u16) << 5) | (at.second() as u16 / 2);
+    let date = (((at.year() - 1980) as u16) << 9) | ((at.month() as u16) << 5) | at.day() as u16;
+    (time, date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn read_u16(data: &[u8], at: usize) -> u16 {
+        u16::from_le_bytes([data[at], data[at + 1]])
+    }
+
+    fn read_u32(data: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]])
+    }
+
+    #[test]
+    fn test_writes_stored_entries_and_central_directory() {
+        let modified_at = Utc.with_ymd_and_hms(2025, 11, 9, 13, 45, 30).unwrap();
+        let mut writer = ZipStreamWriter::new(modified_at);
+
+        let mut archive = writer.add_entry("masks/a.png", b"hello").unwrap();
+        let second_offset = archive.len();
+        archive.extend(writer.add_entry("manifest.json", b"{}").unwrap());
+        let directory_offset = archive.len();
+        archive.extend(writer.finish().unwrap());
+
+        // 로컬 헤더: 시그니처, CRC, 크기, 파일명, 데이터
+        assert_eq!(read_u32(&archive, 0), LOCAL_FILE_HEADER_SIGNATURE);
+        assert_eq!(read_u32(&archive, 14), 0x3610_a686); // CRC-32("hello")
+        assert_eq!(read_u32(&archive, 18), 5);
+        assert_eq!(&archive[30..41], b"masks/a.png");
+        assert_eq!(&archive[41..46], b"hello");
+        assert_eq!(read_u16(&archive, 10), (13 << 11) | (45 << 5) | 15);
+        assert_eq!(read_u16(&archive, 12), (45 << 9) | (11 << 5) | 9);
+
+        // 중앙 디렉터리는 두 번째 항목의 로컬 헤더 위치를 가리킴
+        assert_eq!(read_u32(&archive, directory_offset), CENTRAL_DIRECTORY_SIGNATURE);
+        let second_entry = directory_offset + 46 + "masks/a.png".len();
+        assert_eq!(read_u32(&archive, second_entry + 42) as usize, second_offset);
+
+        // 종료 레코드: 항목 수와 중앙 디렉터리 위치
+        let end = archive.len() - 22;
+        assert_eq!(read_u32(&archive, end), END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        assert_eq!(read_u16(&archive, end + 10), 2);
+        assert_eq!(read_u32(&archive, end + 12) as usize, end - directory_offset);
+        assert_eq!(read_u32(&archive, end + 16) as usize, directory_offset);
+    }
+
+    #[test]
+    fn test_rejects_unsafe_entry_names() {
+        let mut writer = ZipStreamWriter::new(Utc::now());
+        assert!(writer.add_entry("../etc/passwd", b"").is_err());
+        assert!(writer.add_entry("/abs.png", b"").is_err());
+        assert!(writer.add_entry("", b"").is_err());
+        assert!(writer.add_entry("masks/ok.png", b"").is_ok());
+    }
+}
@@ -66,14 +66,14 @@ pub struct CorsConfig {
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct ObjectStorageConfig {
-    pub provider: String,  // "s3" or "minio"
-    pub bucket_name: String,
+    pub provider: String,  // "s3", "minio" or "local"
+    pub bucket_name: String,  // local: root directory
     pub region: String,
-    pub endpoint: String,  // MinIO endpoint (empty for AWS S3)
+    pub endpoint: String,  // MinIO endpoint (empty for AWS S3), local: public base URL of this server
     #[serde(rename = "access_key_id")]
     pub access_key: String,
     #[serde(rename = "secret_access_key")]
-    pub secret_key: String,
+    pub secret_key: String,  // local: signed URL HMAC key
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -0,0 +1,998 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use uuid::Uuid;
+use crate::application::services::object_storage_service::{
+    ObjectStorageService, ObjectStorageError, UploadedFile, SignedUrlOptions, UploadedPart, CompletedPart,
+    MAX_MULTIPART_PARTS,
+};
+
+/// 로컬 스토리지 Signed URL이 가리키는 서버 경로
+pub const LOCAL_STORAGE_ROUTE_PREFIX: &str = "/api/storage/objects";
+
+/// 체크섬과 MIME 타입을 담는 사이드카 파일 접미사
+const METADATA_SUFFIX: &str = ".meta.json";
+
+/// 임시 파일과 멀티파트 파트를 보관하는 내부 디렉터리 (객체 경로로 사용 불가)
+const INTERNAL_DIR: &str = ".local-storage";
+
+/// 다운로드 스트림의 청크 크기
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 객체/파트 사이드카 메타데이터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LocalObjectMetadata {
+    /// SHA-256 (hex)
+    checksum: String,
+    mime_type: Option<String>,
+    size: i64,
+    last_modified: String,
+}
+
+/// 멀티파트 업로드 세션 정보 (`upload.json`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LocalMultipartSession {
+    file_path: String,
+    content_type: Option<String>,
+    created_at: String,
+}
+
+/// 로컬 스토리지 Signed URL의 쿼리 파라미터
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LocalSignedUrlQuery {
+    /// 만료 시각 (Unix timestamp, 초)
+    pub expires: i64,
+    /// HMAC-SHA256 서명 (hex)
+    pub signature: String,
+    /// 업로드 URL에 고정된 Content-Type
+    pub content_type: Option<String>,
+    /// 멀티파트 파트 업로드일 때의 업로드 ID
+    pub upload_id: Option<String>,
+    /// 멀티파트 파트 업로드일 때의 파트 번호
+    pub part_number: Option<i32>,
+}
+
+/// 로컬 디스크를 사용한 객체 스토리지 서비스 구현
+///
+/// 객체 스토리지가 없는 온프레미스 환경과 개발 환경을 위한 구현입니다.
+/// 업로드/다운로드 URL은 이 서버의 `/api/storage/objects/{path}` 경로를 가리키며
+/// HMAC-SHA256으로 서명됩니다. 객체마다 `<파일>.meta.json` 사이드카에
+/// 체크섬과 MIME 타입을 저장하고, 쓰기는 임시 파일에 기록한 뒤 rename하여 원자적으로 반영합니다.
+pub struct LocalObjectStorageService {
+    root: PathBuf,
+    public_base_url: String,
+    signing_key: Vec<u8>,
+}
+
+impl LocalObjectStorageService {
+    /// 새로운 로컬 객체 스토리지 서비스 인스턴스 생성
+    ///
+    /// - `root`: 객체를 저장할 디렉터리 (없으면 생성)
+    /// - `public_base_url`: Signed URL에 사용할 이 서버의 주소 (비어 있으면 상대 경로 URL)
+    /// - `signing_key`: URL 서명 키 (비어 있으면 프로세스마다 임의 키 생성)
+    pub async fn new(
+        root: impl Into<PathBuf>,
+        public_base_url: &str,
+        signing_key: &str,
+    ) -> Result<Self, ObjectStorageError> {
+        let root = root.into();
+        if root.as_os_str().is_empty() {
+            return Err(ObjectStorageError::ConfigError(
+                "Local object storage requires a root directory (bucket_name)".to_string(),
+            ));
+        }
+
+        for dir in [root.join(INTERNAL_DIR).join("tmp"), root.join(INTERNAL_DIR).join("uploads")] {
+            fs::create_dir_all(&dir).await.map_err(|e| {
+                ObjectStorageError::ConfigError(format!(
+                    "Failed to create local storage directory {}: {}",
+                    dir.display(),
+                    e
+                ))
+            })?;
+        }
+
+        let signing_key = if signing_key.is_empty() {
+            eprintln!("⚠️  Local object storage has no signing key; signed URLs will not survive a restart");
+            format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple()).into_bytes()
+        } else {
+            signing_key.as_bytes().to_vec()
+        };
+
+        Ok(Self {
+            root,
+            public_base_url: public_base_url.trim_end_matches('/').to_string(),
+            signing_key,
+        })
+    }
+
+    /// 객체 경로를 디스크 경로로 변환 (루트 밖을 가리키는 경로는 거부)
+    fn object_path(&self, file_path: &str) -> Result<PathBuf, ObjectStorageError> {
+        let invalid = || ObjectStorageError::InvalidRequest(format!("Invalid object path: {}", file_path));
+
+        if file_path.is_empty()
+            || file_path.contains('\\')
+            || file_path.contains('\0')
+            || file_path.ends_with(METADATA_SUFFIX)
+        {
+            return Err(invalid());
+        }
+
+        let mut path = self.root.clone();
+        for (index, segment) in file_path.split('/').enumerate() {
+            if segment.is_empty() || segment == "." || segment == ".." || (index == 0 && segment == INTERNAL_DIR) {
+                return Err(invalid());
+            }
+            path.push(segment);
+        }
+        Ok(path)
+    }
+
+    fn temp_path(&self) -> PathBuf {
+        self.root.join(INTERNAL_DIR).join("tmp").join(Uuid::new_v4().to_string())
+    }
+
+    fn upload_dir(&self, upload_id: &str) -> Result<PathBuf, ObjectStorageError> {
+        let upload_id = Uuid::parse_str(upload_id)
+            .map_err(|_| ObjectStorageError::InvalidRequest(format!("Invalid upload ID: {}", upload_id)))?;
+        Ok(self.root.join(INTERNAL_DIR).join("uploads").join(upload_id.to_string()))
+    }
+
+    fn mac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(&self.signing_key).expect("HMAC accepts keys of any length")
+    }
+
+    fn sign(&self, canonical: &str) -> String {
+        let mut mac = self.mac();
+        mac.update(canonical.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    /// 서명할 URL 생성
+    fn signed_url(
+        &self,
+        method: &str,
+        file_path: &str,
+        ttl_seconds: u64,
+        content_type: Option<&str>,
+        part: Option<(&str, i32)>,
+    ) -> Result<String, ObjectStorageError> {
+        self.object_path(file_path)?;
+
+        let expires = Utc::now().timestamp() + ttl_seconds as i64;
+        let signature = self.sign(&canonical_request(method, file_path, expires, content_type, part));
+
+        let mut url = format!(
+            "{}{}/{}?expires={}",
+            self.public_base_url,
+            LOCAL_STORAGE_ROUTE_PREFIX,
+            percent_encode(file_path, true),
+            expires
+        );
+        if let Some(content_type) = content_type {
+            url.push_str(&format!("&content_type={}", percent_encode(content_type, false)));
+        }
+        if let Some((upload_id, part_number)) = part {
+            url.push_str(&format!("&upload_id={}&part_number={}", percent_encode(upload_id, false), part_number));
+        }
+        url.push_str(&format!("&signature={}", signature));
+        Ok(url)
+    }
+
+    /// 요청 메서드와 경로에 대한 Signed URL 검증
+    pub fn verify_signature(
+        &self,
+        method: &str,
+        file_path: &str,
+        query: &LocalSignedUrlQuery,
+    ) -> Result<(), ObjectStorageError> {
+        if query.expires < Utc::now().timestamp() {
+            return Err(ObjectStorageError::PermissionDenied("Signed URL has expired".to_string()));
+        }
+
+        let signature = hex::decode(&query.signature)
+            .map_err(|_| ObjectStorageError::PermissionDenied("Invalid signature".to_string()))?;
+        let part = match (&query.upload_id, query.part_number) {
+            (Some(upload_id), Some(part_number)) => Some((upload_id.as_str(), part_number)),
+            (None, None) => None,
+            _ => return Err(ObjectStorageError::PermissionDenied("Invalid signature".to_string())),
+        };
+
+        let mut mac = self.mac();
+        mac.update(canonical_request(method, file_path, query.expires, query.content_type.as_deref(), part).as_bytes());
+        mac.verify_slice(&signature)
+            .map_err(|_| ObjectStorageError::PermissionDenied("Invalid signature".to_string()))
+    }
+
+    /// 요청 본문 스트림을 객체로 저장하고 체크섬(SHA-256 hex)을 반환
+    pub async fn write_object_stream<S, B, E>(
+        &self,
+        file_path: &str,
+        stream: S,
+        content_type: Option<&str>,
+    ) -> Result<String, ObjectStorageError>
+    where
+        S: Stream<Item = Result<B, E>> + Unpin,
+        B: AsRef<[u8]>,
+        E: std::fmt::Display,
+    {
+        let object_path = self.object_path(file_path)?;
+        let (temp_path, checksum, size) = self.write_temp(stream).await?;
+        let metadata = LocalObjectMetadata {
+            checksum: checksum.clone(),
+            mime_type: content_type.map(|s| s.to_string()),
+            size,
+            last_modified: Utc::now().to_rfc3339(),
+        };
+        self.commit(&temp_path, &object_path, &metadata, file_path).await?;
+        Ok(checksum)
+    }
+
+    /// 멀티파트 업로드의 파트를 저장하고 ETag(SHA-256 hex)를 반환
+    pub async fn write_part_stream<S, B, E>(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        part_number: i32,
+        stream: S,
+    ) -> Result<String, ObjectStorageError>
+    where
+        S: Stream<Item = Result<B, E>> + Unpin,
+        B: AsRef<[u8]>,
+        E: std::fmt::Display,
+    {
+        if !(1..=MAX_MULTIPART_PARTS).contains(&part_number) {
+            return Err(ObjectStorageError::InvalidRequest(format!("Invalid part number: {}", part_number)));
+        }
+        let upload_dir = self.read_session(file_path, upload_id).await?.0;
+
+        let (temp_path, checksum, size) = self.write_temp(stream).await?;
+        let metadata = LocalObjectMetadata {
+            checksum: checksum.clone(),
+            mime_type: None,
+            size,
+            last_modified: Utc::now().to_rfc3339(),
+        };
+        self.commit(&temp_path, &upload_dir.join(part_file_name(part_number)), &metadata, file_path)
+            .await?;
+        Ok(checksum)
+    }
+
+    /// 객체 내용을 청크 스트림으로 읽기
+    pub async fn read_object_stream(
+        &self,
+        file_path: &str,
+    ) -> Result<(impl Stream<Item = std::io::Result<Vec<u8>>>, UploadedFile), ObjectStorageError> {
+        let object_path = self.object_path(file_path)?;
+        let metadata = self.read_metadata(&object_path, file_path).await?;
+        let file = fs::File::open(&object_path)
+            .await
+            .map_err(|e| io_error("open", file_path, e))?;
+
+        let stream = futures::stream::unfold(Some(file), |file| async move {
+            let mut file = file?;
+            let mut buffer = vec![0u8; READ_CHUNK_SIZE];
+            match file.read(&mut buffer).await {
+                Ok(0) => None,
+                Ok(read) => {
+                    buffer.truncate(read);
+                    Some((Ok(buffer), Some(file)))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        });
+        Ok((stream, metadata))
+    }
+
+    /// 스트림을 임시 파일에 기록하고 (임시 경로, SHA-256 hex, 크기)를 반환
+    async fn write_temp<S, B, E>(&self, mut stream: S) -> Result<(PathBuf, String, i64), ObjectStorageError>
+    where
+        S: Stream<Item = Result<B, E>> + Unpin,
+        B: AsRef<[u8]>,
+        E: std::fmt::Display,
+    {
+        let temp_path = self.temp_path();
+        let mut file = fs::File::create(&temp_path)
+            .await
+            .map_err(|e| io_error("create temporary file for", "upload", e))?;
+        let mut hasher = Sha256::new();
+        let mut size = 0i64;
+
+        let result: Result<(), ObjectStorageError> = async {
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk
+                    .map_err(|e| ObjectStorageError::InvalidRequest(format!("Failed to read upload body: {}", e)))?;
+                let bytes = chunk.as_ref();
+                hasher.update(bytes);
+                size += bytes.len() as i64;
+                file.write_all(bytes).await.map_err(|e| io_error("write", "upload", e))?;
+            }
+            file.sync_all().await.map_err(|e| io_error("write", "upload", e))
+        }
+        .await;
+
+        if let Err(e) = result {
+            let _ = fs::remove_file(&temp_path).await;
+            return Err(e);
+        }
+        Ok((temp_path, hex::encode(hasher.finalize()), size))
+    }
+
+    /// 임시 파일과 사이드카를 최종 위치로 rename
+    async fn commit(
+        &self,
+        temp_path: &Path,
+        object_path: &Path,
+        metadata: &LocalObjectMetadata,
+        file_path: &str,
+    ) -> Result<(), ObjectStorageError> {
+        let result = async {
+            if let Some(parent) = object_path.parent() {
+                fs::create_dir_all(parent).await.map_err(|e| io_error("create directory for", file_path, e))?;
+            }
+            let metadata_temp = self.write_metadata_temp(metadata).await?;
+            fs::rename(temp_path, object_path)
+                .await
+                .map_err(|e| io_error("write", file_path, e))?;
+            fs::rename(&metadata_temp, metadata_path(object_path))
+                .await
+                .map_err(|e| io_error("write metadata of", file_path, e))
+        }
+        .await;
+
+        if result.is_err() {
+            let _ = fs::remove_file(temp_path).await;
+        }
+        result
+    }
+
+    async fn write_metadata_temp(&self, metadata: &LocalObjectMetadata) -> Result<PathBuf, ObjectStorageError> {
+        let metadata_temp = self.temp_path();
+        let json = serde_json::to_vec(metadata)
+            .map_err(|e| ObjectStorageError::LocalStorageError(format!("Failed to serialize metadata: {}", e)))?;
+        fs::write(&metadata_temp, json)
+            .await
+            .map_err(|e| io_error("write", "metadata", e))?;
+        Ok(metadata_temp)
+    }
+
+    /// 사이드카를 읽고, 없거나 내용과 맞지 않으면 파일에서 다시 계산
+    async fn read_metadata(&self, object_path: &Path, file_path: &str) -> Result<UploadedFile, ObjectStorageError> {
+        let file_metadata = fs::metadata(object_path)
+            .await
+            .map_err(|e| io_error("read", file_path, e))?;
+        if !file_metadata.is_file() {
+            return Err(ObjectStorageError::FileNotFound(file_path.to_string()));
+        }
+        let size = file_metadata.len() as i64;
+
+        let sidecar = match fs::read(metadata_path(object_path)).await {
+            Ok(json) => serde_json::from_slice::<LocalObjectMetadata>(&json).ok(),
+            Err(_) => None,
+        };
+
+        let metadata = match sidecar {
+            Some(sidecar) if sidecar.size == size => sidecar,
+            stale => LocalObjectMetadata {
+                checksum: self.compute_checksum(object_path, file_path).await?,
+                mime_type: stale.and_then(|sidecar| sidecar.mime_type),
+                size,
+                last_modified: file_metadata
+                    .modified()
+                    .map(|at| DateTime::<Utc>::from(at).to_rfc3339())
+                    .unwrap_or_else(|_| Utc::now().to_rfc3339()),
+            },
+        };
+
+        Ok(UploadedFile {
+            file_path: file_path.to_string(),
+            file_size: metadata.size,
+            checksum: Some(metadata.checksum),
+            mime_type: metadata.mime_type,
+            last_modified: Some(metadata.last_modified),
+        })
+    }
+
+    async fn compute_checksum(&self, path: &Path, file_path: &str) -> Result<String, ObjectStorageError> {
+        let mut file = fs::File::open(path).await.map_err(|e| io_error("read", file_path, e))?;
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0u8; READ_CHUNK_SIZE];
+        loop {
+            let read = file.read(&mut buffer).await.map_err(|e| io_error("read", file_path, e))?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+        Ok(hex::encode(hasher.finalize()))
+    }
+
+    /// 멀티파트 세션 조회 (경로가 세션과 일치해야 함)
+    async fn read_session(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<(PathBuf, LocalMultipartSession), ObjectStorageError> {
+        let upload_dir = self.upload_dir(upload_id)?;
+        let json = fs::read(upload_dir.join("upload.json")).await.map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => {
+                ObjectStorageError::FileNotFound(format!("Multipart upload {} not found", upload_id))
+            }
+            _ => io_error("read multipart upload", upload_id, e),
+        })?;
+        let session: LocalMultipartSession = serde_json::from_slice(&json).map_err(|e| {
+            ObjectStorageError::LocalStorageError(format!("Corrupt multipart upload {}: {}", upload_id, e))
+        })?;
+        if session.file_path != file_path {
+            return Err(ObjectStorageError::InvalidRequest(format!(
+                "Multipart upload {} does not belong to {}",
+                upload_id, file_path
+            )));
+        }
+        Ok((upload_dir, session))
+    }
+}
+
+/// 서명 대상 문자열
+fn canonical_request(
+    method: &str,
+    file_path: &str,
+    expires: i64,
+    content_type: Option<&str>,
+    part: Option<(&str, i32)>,
+) -> String {
+    let (upload_id, part_number) = match part {
+        Some((upload_id, part_number)) => (upload_id.to_string(), part_number.to_string()),
+        None => (String::new(), String::new()),
+    };
+    format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        method.to_uppercase(),
+        file_path,
+        expires,
+        content_type.unwrap_or(""),
+        upload_id,
+        part_number
+    )
+}
+
+/// URL 인코딩 (RFC 3986 unreserved 문자만 그대로 유지)
+fn percent_encode(value: &str, keep_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            b'/' if keep_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn metadata_path(object_path: &Path) -> PathBuf {
+    let mut path = object_path.as_os_str().to_os_string();
+    path.push(METADATA_SUFFIX);
+    PathBuf::from(path)
+}
+
+fn part_file_name(part_number: i32) -> String {
+    format!("part-{:05}", part_number)
+}
+
+fn io_error(context: &str, file_path: &str, e: std::io::Error) -> ObjectStorageError {
+    match e.kind() {
+        std::io::ErrorKind::NotFound => ObjectStorageError::FileNotFound(file_path.to_string()),
+        std::io::ErrorKind::PermissionDenied => {
+            ObjectStorageError::PermissionDenied(format!("Failed to {} {}: {}", context, file_path, e))
+        }
+        _ => ObjectStorageError::LocalStorageError(format!("Failed to {} {}: {}", context, file_path, e)),
+    }
+}
+
+#[async_trait]
+impl ObjectStorageService for LocalObjectStorageService {
+    async fn generate_upload_url(
+        &self,
+        file_path: &str,
+        options: SignedUrlOptions,
+    ) -> Result<String, ObjectStorageError> {
+        self.signed_url("PUT", file_path, options.ttl_seconds, options.content_type.as_deref(), None)
+    }
+
+    async fn generate_download_url(
+        &self,
+        file_path: &str,
+        ttl_seconds: u64,
+    ) -> Result<String, ObjectStorageError> {
+        self.signed_url("GET", file_path, ttl_seconds, None, None)
+    }
+
+    async fn delete_file(&self, file_path: &str) -> Result<(), ObjectStorageError> {
+        let object_path = self.object_path(file_path)?;
+
+        // S3와 마찬가지로 없는 객체의 삭제는 성공으로 처리
+        for path in [object_path.clone(), metadata_path(&object_path)] {
+            match fs::remove_file(&path).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(io_error("delete", file_path, e)),
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_file_metadata(&self, file_path: &str) -> Result<UploadedFile, ObjectStorageError> {
+        let object_path = self.object_path(file_path)?;
+        self.read_metadata(&object_path, file_path).await
+    }
+
+    async fn file_exists(&self, file_path: &str) -> Result<bool, ObjectStorageError> {
+        let object_path = self.object_path(file_path)?;
+        match fs::metadata(&object_path).await {
+            Ok(metadata) => Ok(metadata.is_file()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(io_error("check", file_path, e)),
+        }
+    }
+
+    async fn list_files(
+        &self,
+        prefix: &str,
+        max_keys: Option<i32>,
+    ) -> Result<Vec<String>, ObjectStorageError> {
+        self.list_files_after(prefix, None, max_keys).await
+    }
+
+    async fn list_files_after(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        max_keys: Option<i32>,
+    ) -> Result<Vec<String>, ObjectStorageError> {
+        // max_keys를 생략하면 S3와 같이 최대 1000개
+        let limit = max_keys.unwrap_or(1000).max(0) as usize;
+
+        // prefix의 디렉터리 부분부터 탐색
+        let start_key = prefix.rfind('/').map(|index| &prefix[..index]).unwrap_or("");
+        let start_dir = if start_key.is_empty() {
+            self.root.clone()
+        } else {
+            match self.object_path(start_key) {
+                Ok(path) => path,
+                Err(_) => return Ok(Vec::new()),
+            }
+        };
+
+        let mut files = Vec::new();
+        let mut pending = vec![(start_dir, start_key.to_string())];
+        while let Some((dir, dir_key)) = pending.pop() {
+            let mut entries = match fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(io_error("list", &dir_key, e)),
+            };
+
+            while let Some(entry) = entries.next_entry().await.map_err(|e| io_error("list", &dir_key, e))? {
+                let Ok(name) = entry.file_name().into_string() else {
+                    continue;
+                };
+                let key = if dir_key.is_empty() { name.clone() } else { format!("{}/{}", dir_key, name) };
+                let file_type = entry.file_type().await.map_err(|e| io_error("list", &key, e))?;
+
+                if file_type.is_dir() {
+                    let internal = dir_key.is_empty() && name == INTERNAL_DIR;
+                    if !internal && (key.starts_with(prefix) || prefix.starts_with(&format!("{}/", key))) {
+                        pending.push((entry.path(), key));
+                    }
+                } else if file_type.is_file()
+                    && !name.ends_with(METADATA_SUFFIX)
+                    && key.starts_with(prefix)
+                    && start_after.is_none_or(|start_after| key.as_str() > start_after)
+                {
+                    files.push(key);
+                }
+            }
+        }
+
+        files.sort();
+        files.truncate(limit);
+        Ok(files)
+    }
+
+    async fn copy_file(
+        &self,
+        source_path: &str,
+        destination_path: &str,
+    ) -> Result<(), ObjectStorageError> {
+        let source = self.object_path(source_path)?;
+        let destination = self.object_path(destination_path)?;
+        let metadata = self.read_metadata(&source, source_path).await?;
+
+        // 임시 파일로 복사한 뒤 rename하여 대상이 중간 상태로 보이지 않도록 함
+        let temp_path = self.temp_path();
+        if let Err(e) = fs::copy(&source, &temp_path).await {
+            let _ = fs::remove_file(&temp_path).await;
+            return Err(io_error("copy", source_path, e));
+        }
+
+        let metadata = LocalObjectMetadata {
+            checksum: metadata.checksum.unwrap_or_default(),
+            mime_type: metadata.mime_type,
+            size: metadata.file_size,
+            last_modified: Utc::now().to_rfc3339(),
+        };
+        self.commit(&temp_path, &destination, &metadata, destination_path).await
+    }
+
+    async fn move_file(
+        &self,
+        source_path: &str,
+        destination_path: &str,
+    ) -> Result<(), ObjectStorageError> {
+        let source = self.object_path(source_path)?;
+        let destination = self.object_path(destination_path)?;
+        if !self.file_exists(source_path).await? {
+            return Err(ObjectStorageError::FileNotFound(source_path.to_string()));
+        }
+
+        // 같은 파일시스템 안의 rename은 원자적
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|e| io_error("create directory for", destination_path, e))?;
+        }
+        fs::rename(&source, &destination)
+            .await
+            .map_err(|e| io_error("move", source_path, e))?;
+        match fs::rename(metadata_path(&source), metadata_path(&destination)).await {
+            Ok(()) => Ok(()),
+            // 사이드카가 없으면 조회 시 다시 계산됨
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let _ = fs::remove_file(metadata_path(&destination)).await;
+                Ok(())
+            }
+            Err(e) => Err(io_error("move metadata of", source_path, e)),
+        }
+    }
+
+    async fn upload_file(
+        &self,
+        file_path: &str,
+        data: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<(), ObjectStorageError> {
+        let stream = futures::stream::iter([Ok::<_, std::io::Error>(data)]);
+        self.write_object_stream(file_path, stream, content_type).await?;
+        Ok(())
+    }
+
+    async fn download_file(&self, file_path: &str) -> Result<Vec<u8>, ObjectStorageError> {
+        let object_path = self.object_path(file_path)?;
+        fs::read(&object_path)
+            .await
+            .map_err(|e| io_error("read", file_path, e))
+    }
+
+    async fn initiate_multipart_upload(
+        &self,
+        file_path: &str,
+        content_type: Option<&str>,
+    ) -> Result<String, ObjectStorageError> {
+        self.object_path(file_path)?;
+
+        let upload_id = Uuid::new_v4().to_string();
+        let upload_dir = self.upload_dir(&upload_id)?;
+        fs::create_dir_all(&upload_dir)
+            .await
+            .map_err(|e| io_error("create multipart upload for", file_path, e))?;
+
+        let session = LocalMultipartSession {
+            file_path: file_path.to_string(),
+            content_type: content_type.map(|s| s.to_string()),
+            created_at: Utc::now().to_rfc3339(),
+        };
+        let json = serde_json::to_vec(&session)
+            .map_err(|e| ObjectStorageError::LocalStorageError(format!("Failed to serialize upload: {}", e)))?;
+        fs::write(upload_dir.join("upload.json"), json)
+            .await
+            .map_err(|e| io_error("create multipart upload for", file_path, e))?;
+
+        Ok(upload_id)
+    }
+
+    async fn generate_upload_part_url(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        part_number: i32,
+        ttl_seconds: u64,
+    ) -> Result<String, ObjectStorageError> {
+        self.read_session(file_path, upload_id).await?;
+        self.signed_url("PUT", file_path, ttl_seconds, None, Some((upload_id, part_number)))
+    }
+
+    async fn list_uploaded_parts(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<Vec<UploadedPart>, ObjectStorageError> {
+        let (upload_dir, _) = self.read_session(file_path, upload_id).await?;
+
+        let mut parts = Vec::new();
+        let mut entries = fs::read_dir(&upload_dir)
+            .await
+            .map_err(|e| io_error("list parts of", file_path, e))?;
+        while let Some(entry) = entries.next_entry().await.map_err(|e| io_error("list parts of", file_path, e))? {
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            let Some(part_number) = name.strip_prefix("part-").and_then(|n| n.parse::<i32>().ok()) else {
+                continue;
+            };
+            let Ok(json) = fs::read(metadata_path(&entry.path())).await else {
+                continue;
+            };
+            let Ok(metadata) = serde_json::from_slice::<LocalObjectMetadata>(&json) else {
+                continue;
+            };
+            parts.push(UploadedPart {
+                part_number,
+                etag: metadata.checksum,
+                size: metadata.size,
+            });
+        }
+
+        parts.sort_by_key(|part| part.part_number);
+        Ok(parts)
+    }
+
+    async fn complete_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        parts: &[CompletedPart],
+    ) -> Result<(), ObjectStorageError> {
+        let object_path = self.object_path(file_path)?;
+        let (upload_dir, session) = self.read_session(file_path, upload_id).await?;
+        if parts.is_empty() {
+            return Err(ObjectStorageError::InvalidRequest("No parts to complete".to_string()));
+        }
+
+        let uploaded = self.list_uploaded_parts(file_path, upload_id).await?;
+        let mut previous = 0;
+        for part in parts {
+            if part.part_number <= previous {
+                return Err(ObjectStorageError::InvalidRequest("Parts must be in ascending order".to_string()));
+            }
+            previous = part.part_number;
+
+            let matches = uploaded.iter().any(|uploaded| {
+                uploaded.part_number == part.part_number && uploaded.etag == part.etag.trim().trim_matches('"')
+            });
+            if !matches {
+                return Err(ObjectStorageError::InvalidRequest(format!(
+                    "Part {} was not uploaded or its ETag does not match",
+                    part.part_number
+                )));
+            }
+        }
+
+        // 파트를 순서대로 이어 붙여 임시 파일에 기록
+        let temp_path = self.temp_path();
+        let result: Result<(String, i64), ObjectStorageError> = async {
+            let mut output = fs::File::create(&temp_path)
+                .await
+                .map_err(|e| io_error("create temporary file for", file_path, e))?;
+            let mut hasher = Sha256::new();
+            let mut size = 0i64;
+            let mut buffer = vec![0u8; READ_CHUNK_SIZE];
+            for part in parts {
+                let mut input = fs::File::open(upload_dir.join(part_file_name(part.part_number)))
+                    .await
+                    .map_err(|e| io_error("read part of", file_path, e))?;
+                loop {
+                    let read = input.read(&mut buffer).await.map_err(|e| io_error("read part of", file_path, e))?;
+                    if read == 0 {
+                        break;
+                    }
+                    hasher.update(&buffer[..read]);
+                    size += read as i64;
+                    output.write_all(&buffer[..read]).await.map_err(|e| io_error("write", file_path, e))?;
+                }
+            }
+            output.sync_all().await.map_err(|e| io_error("write", file_path, e))?;
+            Ok((hex::encode(hasher.finalize()), size))
+        }
+        .await;
+
+        let (checksum, size) = match result {
+            Ok(assembled) => assembled,
+            Err(e) => {
+                let _ = fs::remove_file(&temp_path).await;
+                return Err(e);
+            }
+        };
+
+        let metadata = LocalObjectMetadata {
+            checksum,
+            mime_type: session.content_type,
+            size,
+            last_modified: Utc::now().to_rfc3339(),
+        };
+        self.commit(&temp_path, &object_path, &metadata, file_path).await?;
+
+        let _ = fs::remove_dir_all(&upload_dir).await;
+        Ok(())
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<(), ObjectStorageError> {
+        let upload_dir = match self.read_session(file_path, upload_id).await {
+            Ok((upload_dir, _)) => upload_dir,
+            // 이미 정리된 업로드는 성공으로 처리
+            Err(ObjectStorageError::FileNotFound(_)) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+
+        match fs::remove_dir_all(&upload_dir).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(io_error("abort multipart upload of", file_path, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn storage() -> (LocalObjectStorageService, PathBuf) {
+        let root = std::env::temp_dir().join(format!("pacs-local-storage-{}", Uuid::new_v4()));
+        let service = LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+            .await
+            .unwrap();
+        (service, root)
+    }
+
+    fn query_of(url: &str) -> LocalSignedUrlQuery {
+        let query = url.split_once('?').unwrap().1;
+        let mut parsed = LocalSignedUrlQuery::default();
+        for pair in query.split('&') {
+            let (key, value) = pair.split_once('=').unwrap();
+            match key {
+                "expires" => parsed.expires = value.parse().unwrap(),
+                "signature" => parsed.signature = value.to_string(),
+                "content_type" => parsed.content_type = Some(value.replace("%2F", "/")),
+                "upload_id" => parsed.upload_id = Some(value.to_string()),
+                "part_number" => parsed.part_number = value.parse().ok(),
+                _ => {}
+            }
+        }
+        parsed
+    }
+
+    #[tokio::test]
+    async fn test_signed_url_is_bound_to_method_and_path() {
+        let (service, root) = storage().await;
+        let url = service
+            .generate_upload_url(
+                "masks/1/liver mask.png",
+                SignedUrlOptions { ttl_seconds: 60, content_type: Some("image/png".to_string()), ..Default::default() },
+            )
+            .await
+            .unwrap();
+        assert!(url.starts_with("http://localhost:8080/api/storage/objects/masks/1/liver%20mask.png?expires="));
+
+        let query = query_of(&url);
+        assert!(service.verify_signature("PUT", "masks/1/liver mask.png", &query).is_ok());
+        assert!(service.verify_signature("GET", "masks/1/liver mask.png", &query).is_err());
+        assert!(service.verify_signature("PUT", "masks/1/other.png", &query).is_err());
+
+        let expired = LocalSignedUrlQuery { expires: query.expires - 3600, ..query };
+        assert!(matches!(
+            service.verify_signature("PUT", "masks/1/liver mask.png", &expired),
+            Err(ObjectStorageError::PermissionDenied(_))
+        ));
+
+        let _ = fs::remove_dir_all(root).await;
+    }
+
+    #[tokio::test]
+    async fn test_rejects_paths_outside_root() {
+        let (service, root) = storage().await;
+        for path in ["../etc/passwd", "masks/../../x", "/abs", "masks//x", ".local-storage/tmp/x", "a.png.meta.json"] {
+            assert!(matches!(
+                service.upload_file(path, b"x".to_vec(), None).await,
+                Err(ObjectStorageError::InvalidRequest(_))
+            ), "{}", path);
+        }
+        let _ = fs::remove_dir_all(root).await;
+    }
+
+    #[tokio::test]
+    async fn test_list_copy_move_and_metadata() {
+        let (service, root) = storage().await;
+        service.upload_file("masks/1/a.png", b"aaa".to_vec(), Some("image/png")).await.unwrap();
+        service.upload_file("masks/1/b.png", b"bb".to_vec(), None).await.unwrap();
+        service.upload_file("masks/10/c.png", b"c".to_vec(), None).await.unwrap();
+
+        assert_eq!(service.list_files("masks/1/", None).await.unwrap(), vec!["masks/1/a.png", "masks/1/b.png"]);
+        assert_eq!(service.list_files("masks/1", None).await.unwrap().len(), 3);
+        assert_eq!(service.list_files("", Some(1)).await.unwrap(), vec!["masks/1/a.png"]);
+        assert_eq!(
+            service.list_files_after("masks/", Some("masks/1/a.png"), Some(1)).await.unwrap(),
+            vec!["masks/1/b.png"]
+        );
+
+        service.copy_file("masks/1/a.png", "copies/a.png").await.unwrap();
+        let copied = service.get_file_metadata("copies/a.png").await.unwrap();
+        assert_eq!(copied.file_size, 3);
+        assert_eq!(copied.mime_type.as_deref(), Some("image/png"));
+        assert_eq!(copied.checksum.as_deref(), Some(hex::encode(Sha256::digest(b"aaa")).as_str()));
+
+        service.move_file("masks/1/b.png", "moved/b.png").await.unwrap();
+        assert!(!service.file_exists("masks/1/b.png").await.unwrap());
+        assert_eq!(service.download_file("moved/b.png").await.unwrap(), b"bb");
+
+        service.delete_file("moved/b.png").await.unwrap();
+        service.delete_file("moved/b.png").await.unwrap();
+        assert!(matches!(
+            service.get_file_metadata("moved/b.png").await,
+            Err(ObjectStorageError::FileNotFound(_))
+        ));
+
+        let _ = fs::remove_dir_all(root).await;
+    }
+
+    #[tokio::test]
+    async fn test_multipart_upload_assembles_parts_in_order() {
+        let (service, root) = storage().await;
+        let upload_id = service.initiate_multipart_upload("big/mask.nii", Some("application/octet-stream")).await.unwrap();
+
+        let second = service
+            .write_part_stream("big/mask.nii", &upload_id, 2, futures::stream::iter([Ok::<_, std::io::Error>(b"world".to_vec())]))
+            .await
+            .unwrap();
+        let first = service
+            .write_part_stream("big/mask.nii", &upload_id, 1, futures::stream::iter([Ok::<_, std::io::Error>(b"hello ".to_vec())]))
+            .await
+            .unwrap();
+
+        let parts = service.list_uploaded_parts("big/mask.nii", &upload_id).await.unwrap();
+        assert_eq!(parts.iter().map(|p| p.part_number).collect::<Vec<_>>(), vec![1, 2]);
+
+        let wrong = [CompletedPart { part_number: 1, etag: second.clone() }];
+        assert!(matches!(
+            service.complete_multipart_upload("big/mask.nii", &upload_id, &wrong).await,
+            Err(ObjectStorageError::InvalidRequest(_))
+        ));
+
+        let completed = [
+            CompletedPart { part_number: 1, etag: format!("\"{}\"", first) },
+            CompletedPart { part_number: 2, etag: second },
+        ];
+        service.complete_multipart_upload("big/mask.nii", &upload_id, &completed).await.unwrap();
+        assert_eq!(service.download_file("big/mask.nii").await.unwrap(), b"hello world");
+        assert_eq!(
+            service.get_file_metadata("big/mask.nii").await.unwrap().mime_type.as_deref(),
+            Some("application/octet-stream")
+        );
+
+        // 완료된 업로드는 정리되어 있으며, 중단은 멱등
+        service.abort_multipart_upload("big/mask.nii", &upload_id).await.unwrap();
+        assert!(service.list_files("", None).await.unwrap() == vec!["big/mask.nii"]);
+
+        let _ = fs::remove_dir_all(root).await;
+    }
+}
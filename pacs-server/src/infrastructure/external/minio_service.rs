@@ -1,17 +1,19 @@
 use async_trait::async_trait;
-use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_s3::{
+    config::{BehaviorVersion, Credentials, Region},
     presigning::PresigningConfig,
-    types::{ObjectCannedAcl, StorageClass},
+    primitives::ByteStream,
+    types::{CompletedMultipartUpload, CompletedPart as S3CompletedPart, ObjectCannedAcl, StorageClass},
     Client as S3Client,
 };
 use std::time::Duration;
 use crate::application::services::object_storage_service::{
-    ObjectStorageService, ObjectStorageError, UploadedFile, SignedUrlOptions,
+    ObjectStorageService, ObjectStorageError, UploadedFile, SignedUrlOptions, UploadedPart, CompletedPart,
 };
 
 /// MinIO Object Storage 서비스 구현
 /// MinIO는 S3 호환 API를 제공하므로 S3 클라이언트를 사용
+/// (사용자 지정 엔드포인트, path-style 주소 사용)
 pub struct MinIOObjectStorageService {
     client: S3Client,
     bucket_name: String,
@@ -19,22 +21,39 @@ pub struct MinIOObjectStorageService {
 
 impl MinIOObjectStorageService {
     /// 새로운 MinIO 서비스 인스턴스 생성
+    ///
+    /// 서버에 연결하지 않으며, 버킷 생성이 필요하면 `ensure_bucket_exists`를 호출합니다.
     pub async fn new(
         bucket_name: &str,
         region: &str,
         endpoint: &str,
-        _access_key: &str,
-        _secret_key: &str,
+        access_key: &str,
+        secret_key: &str,
     ) -> Result<Self, ObjectStorageError> {
-        // TODO: AWS SDK lifetime 문제 해결 필요
-        // 임시로 에러 반환하여 컴파일 문제 회피
-        Err(ObjectStorageError::ConfigError(
-            "MinIO service temporarily disabled due to AWS SDK lifetime issues".to_string()
-        ))
+        if endpoint.is_empty() {
+            return Err(ObjectStorageError::ConfigError(
+                "MinIO endpoint is required (APP_OBJECT_STORAGE__ENDPOINT)".to_string()
+            ));
+        }
+
+        let config = aws_sdk_s3::config::Builder::new()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new(region.to_string()))
+            .endpoint_url(endpoint)
+            .credentials_provider(Credentials::new(access_key, secret_key, None, None, "pacs-server"))
+            .force_path_style(true)
+            .build();
+
+        Ok(MinIOObjectStorageService {
+            client: S3Client::from_conf(config),
+            bucket_name: bucket_name.to_string(),
+        })
     }
     
     /// 버킷 존재 여부 확인 및 생성
-    async fn ensure_bucket_exists(client: &S3Client, bucket_name: &str) -> Result<(), ObjectStorageError> {
+    pub async fn ensure_bucket_exists(&self) -> Result<(), ObjectStorageError> {
+        let client = &self.client;
+        let bucket_name = &self.bucket_name;
         // 버킷 존재 여부 확인
         match client.head_bucket().bucket(bucket_name).send().await {
             Ok(_) => Ok(()),
@@ -77,6 +96,9 @@ impl MinIOObjectStorageService {
             aws_sdk_s3::Error::NoSuchKey(_) => ObjectStorageError::FileNotFound(
                 "File not found in MinIO bucket".to_string()
             ),
+            aws_sdk_s3::Error::NoSuchUpload(_) => ObjectStorageError::FileNotFound(
+                "Multipart upload not found in MinIO bucket".to_string()
+            ),
             _ => ObjectStorageError::MinIOError(error.to_string()),
         }
     }
@@ -233,6 +255,39 @@ impl ObjectStorageService for MinIOObjectStorageService {
         Ok(files)
     }
     
+    async fn list_files_after(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        max_keys: Option<i32>,
+    ) -> Result<Vec<String>, ObjectStorageError> {
+        let mut list_objects = self.client
+            .list_objects_v2()
+            .bucket(&self.bucket_name)
+            .prefix(prefix);
+        
+        if let Some(start_after) = start_after {
+            list_objects = list_objects.start_after(self.file_path_to_key(start_after));
+        }
+        
+        if let Some(max_keys) = max_keys {
+            list_objects = list_objects.max_keys(max_keys);
+        }
+        
+        let response = list_objects
+            .send()
+            .await
+            .map_err(|e| self.map_minio_error(e.into()))?;
+        
+        let files = response
+            .contents()
+            .iter()
+            .filter_map(|obj| obj.key().map(|s| s.to_string()))
+            .collect();
+        
+        Ok(files)
+    }
+    
     async fn copy_file(
         &self,
         source_path: &str,
@@ -268,25 +323,251 @@ impl ObjectStorageService for MinIOObjectStorageService {
         
         Ok(())
     }
+    
+    async fn upload_file(
+        &self,
+        file_path: &str,
+        data: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<(), ObjectStorageError> {
+        let key = self.file_path_to_key(file_path);
+        
+        let mut put_object = self.client
+            .put_object()
+            .bucket(&self.bucket_name)
+            .key(&key)
+            .body(ByteStream::from(data));
+        
+        if let Some(content_type) = content_type {
+            put_object = put_object.content_type(content_type);
+        }
+        
+        put_object
+            .send()
+            .await
+            .map_err(|e| self.map_minio_error(e.into()))?;
+        
+        Ok(())
+    }
+    
+    async fn download_file(&self, file_path: &str) -> Result<Vec<u8>, ObjectStorageError> {
+        let key = self.file_path_to_key(file_path);
+        
+        let response = self.client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(&key)
+            .send()
+            .await
+            .map_err(|e| match self.map_minio_error(e.into()) {
+                ObjectStorageError::FileNotFound(_) => ObjectStorageError::FileNotFound(file_path.to_string()),
+                error => error,
+            })?;
+        
+        let bytes = response.body
+            .collect()
+            .await
+            .map_err(|e| ObjectStorageError::MinIOError(format!("Failed to read object body: {}", e)))?;
+        
+        Ok(bytes.into_bytes().to_vec())
+    }
+    
+    async fn initiate_multipart_upload(
+        &self,
+        file_path: &str,
+        content_type: Option<&str>,
+    ) -> Result<String, ObjectStorageError> {
+        let key = self.file_path_to_key(file_path);
+        
+        let mut create_upload = self.client
+            .create_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(&key)
+            .set_acl(Some(ObjectCannedAcl::Private))
+            .set_storage_class(Some(StorageClass::Standard));
+        
+        if let Some(content_type) = content_type {
+            create_upload = create_upload.content_type(content_type);
+        }
+        
+        let result = create_upload
+            .send()
+            .await
+            .map_err(|e| self.map_minio_error(e.into()))?;
+        
+        result
+            .upload_id()
+            .map(|id| id.to_string())
+            .ok_or_else(|| ObjectStorageError::MinIOError("MinIO returned no upload ID".to_string()))
+    }
+    
+    async fn generate_upload_part_url(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        part_number: i32,
+        ttl_seconds: u64,
+    ) -> Result<String, ObjectStorageError> {
+        let key = self.file_path_to_key(file_path);
+        
+        let presigning_config = PresigningConfig::expires_in(Duration::from_secs(ttl_seconds))
+            .map_err(|e| ObjectStorageError::MinIOError(format!("Failed to create presigning config: {}", e)))?;
+        
+        let request = self.client
+            .upload_part()
+            .bucket(&self.bucket_name)
+            .key(&key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .presigned(presigning_config)
+            .await
+            .map_err(|e| self.map_minio_error(e.into()))?;
+        
+        Ok(request.uri().to_string())
+    }
+    
+    async fn list_uploaded_parts(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<Vec<UploadedPart>, ObjectStorageError> {
+        let key = self.file_path_to_key(file_path);
+        
+        let mut parts = Vec::new();
+        let mut marker: Option<String> = None;
+        loop {
+            let result = self.client
+                .list_parts()
+                .bucket(&self.bucket_name)
+                .key(&key)
+                .upload_id(upload_id)
+                .set_part_number_marker(marker.take())
+                .send()
+                .await
+                .map_err(|e| self.map_minio_error(e.into()))?;
+            
+            for part in result.parts() {
+                if let (Some(part_number), Some(etag)) = (part.part_number(), part.e_tag()) {
+                    parts.push(UploadedPart {
+                        part_number,
+                        etag: etag.to_string(),
+                        size: part.size().unwrap_or(0),
+                    });
+                }
+            }
+            
+            match (result.is_truncated(), result.next_part_number_marker()) {
+                (Some(true), Some(next)) => marker = Some(next.to_string()),
+                _ => break,
+            }
+        }
+        
+        parts.sort_by_key(|part| part.part_number);
+        Ok(parts)
+    }
+    
+    async fn complete_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        parts: &[CompletedPart],
+    ) -> Result<(), ObjectStorageError> {
+        let key = self.file_path_to_key(file_path);
+        
+        let completed = CompletedMultipartUpload::builder()
+            .set_parts(Some(
+                parts
+                    .iter()
+                    .map(|part| {
+                        S3CompletedPart::builder()
+                            .part_number(part.part_number)
+                            .e_tag(&part.etag)
+                            .build()
+                    })
+                    .collect(),
+            ))
+            .build();
+        
+        self.client
+            .complete_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(&key)
+            .upload_id(upload_id)
+            .multipart_upload(completed)
+            .send()
+            .await
+            .map_err(|e| self.map_minio_error(e.into()))?;
+        
+        Ok(())
+    }
+    
+    async fn abort_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<(), ObjectStorageError> {
+        let key = self.file_path_to_key(file_path);
+        
+        match self.client
+            .abort_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(&key)
+            .upload_id(upload_id)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => match self.map_minio_error(e.into()) {
+                // 이미 완료되었거나 중단된 업로드
+                ObjectStorageError::FileNotFound(_) => Ok(()),
+                error => Err(error),
+            },
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::services::object_storage_service::SignedUrlOptions;
+    use crate::application::services::object_storage_service::{ObjectStorageServiceFactory, SignedUrlOptions};
+
+    async fn service() -> MinIOObjectStorageService {
+        MinIOObjectStorageService::new("test-bucket", "us-east-1", "http://localhost:9000", "minioadmin", "minioadmin")
+            .await
+            .unwrap()
+    }
 
     #[tokio::test]
     async fn test_minio_service_creation() {
-        // 이 테스트는 실제 MinIO 서버가 필요하므로 스킵
-        // 실제 테스트에서는 mock을 사용하거나 테스트용 MinIO 서버 사용
+        assert!(matches!(
+            MinIOObjectStorageService::new("test-bucket", "us-east-1", "", "minioadmin", "minioadmin").await,
+            Err(ObjectStorageError::ConfigError(_))
+        ));
+        // 팩토리가 minio 제공자를 인식 (엔드포인트가 없으면 설정 오류)
+        match ObjectStorageServiceFactory::create("minio", "test-bucket", "us-east-1", "", "minioadmin", "minioadmin").await {
+            Err(ObjectStorageError::ConfigError(message)) => assert!(message.contains("endpoint"), "{}", message),
+            _ => panic!("expected a MinIO configuration error"),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_presigned_urls_use_endpoint_and_path_style() {
+        let service = service().await;
+        
+        let upload_url = service
+            .generate_upload_url("mask/123/456/file.png", SignedUrlOptions::default())
+            .await
+            .unwrap();
+        assert!(upload_url.starts_with("http://localhost:9000/test-bucket/mask/123/456/file.png?"), "{}", upload_url);
+        
+        let download_url = service.generate_download_url("mask/123/456/file.png", 60).await.unwrap();
+        assert!(download_url.starts_with("http://localhost:9000/test-bucket/mask/123/456/file.png?"), "{}", download_url);
+        assert!(download_url.contains("X-Amz-Expires=60"));
     }
     
     #[tokio::test]
     async fn test_file_path_to_key() {
-        let service = MinIOObjectStorageService {
-            client: S3Client::new(&aws_config::load_from_env().await),
-            bucket_name: "test-bucket".to_string(),
-        };
+        let service = service().await;
         
         // 일반 파일 경로
         assert_eq!(service.file_path_to_key("mask/123/456/file.png"), "mask/123/456/file.png");
@@ -1,4 +1,8 @@
 pub mod keycloak_client;
 pub mod s3_object_storage_service;
+pub mod minio_service;
+pub mod local_object_storage_service;
 pub use keycloak_client::*;
-pub use s3_object_storage_service::*;
\ No newline at end of file
+pub use s3_object_storage_service::*;
+pub use minio_service::*;
+pub use local_object_storage_service::*;
\ No newline at end of file
@@ -3,8 +3,11 @@ use aws_sdk_s3::Client as S3Client;
 use aws_sdk_s3::primitives::ByteStream;
 use aws_sdk_s3::config::Credentials;
 use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart as S3CompletedPart};
 use std::time::Duration;
-use crate::application::services::object_storage_service::{ObjectStorageService, ObjectStorageError, UploadedFile, SignedUrlOptions};
+use crate::application::services::object_storage_service::{
+    ObjectStorageService, ObjectStorageError, UploadedFile, SignedUrlOptions, UploadedPart, CompletedPart,
+};
 use crate::domain::ServiceError;
 
 /// AWS S3를 사용한 객체 스토리지 서비스 구현
@@ -139,7 +142,14 @@ impl ObjectStorageService for S3ObjectStorageService {
             .key(&object_key)
             .send()
             .await
-            .map_err(|e| ObjectStorageError::S3Error(e.to_string()))?;
+            .map_err(|e| {
+                // 없는 객체는 호출자가 구분할 수 있도록 FileNotFound로 반환
+                if e.as_service_error().is_some_and(|se| se.is_not_found()) {
+                    ObjectStorageError::FileNotFound(file_path.to_string())
+                } else {
+                    ObjectStorageError::S3Error(e.to_string())
+                }
+            })?;
 
         Ok(UploadedFile {
             file_path: file_path.to_string(),
@@ -156,10 +166,24 @@ impl ObjectStorageService for S3ObjectStorageService {
         prefix: &str,
         max_keys: Option<i32>,
     ) -> Result<Vec<String>, ObjectStorageError> {
+        self.list_files_after(prefix, None, max_keys).await
+    }
+
+    async fn list_files_after(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        max_keys: Option<i32>,
+    ) -> Result<Vec<String>, ObjectStorageError> {
+        // 다른 메서드와 같이 객체 키 프리픽스 아래에서 조회
         let mut list_request = self.client
             .list_objects_v2()
             .bucket(&self.bucket_name)
-            .prefix(prefix);
+            .prefix(self.generate_object_key(prefix));
+
+        if let Some(start_after) = start_after {
+            list_request = list_request.start_after(self.generate_object_key(start_after));
+        }
 
         if let Some(max) = max_keys {
             list_request = list_request.max_keys(max);
@@ -216,4 +240,221 @@ impl ObjectStorageService for S3ObjectStorageService {
         self.delete_file(source_path).await?;
         Ok(())
     }
+
+    async fn upload_file(
+        &self,
+        file_path: &str,
+        data: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<(), ObjectStorageError> {
+        let object_key = self.generate_object_key(file_path);
+
+        let mut put_request = self.client
+            .put_object()
+            .bucket(&self.bucket_name)
+            .key(&object_key)
+            .body(ByteStream::from(data));
+
+        if let Some(content_type) = content_type {
+            put_request = put_request.content_type(content_type);
+        }
+
+        put_request
+            .send()
+            .await
+            .map_err(|e| ObjectStorageError::S3Error(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn download_file(&self, file_path: &str) -> Result<Vec<u8>, ObjectStorageError> {
+        let object_key = self.generate_object_key(file_path);
+
+        let result = self.client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(&object_key)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.to_string().contains("NoSuchKey") {
+                    ObjectStorageError::FileNotFound(file_path.to_string())
+                } else {
+                    ObjectStorageError::S3Error(e.to_string())
+                }
+            })?;
+
+        let bytes = result.body
+            .collect()
+            .await
+            .map_err(|e| ObjectStorageError::S3Error(e.to_string()))?;
+
+        Ok(bytes.into_bytes().to_vec())
+    }
+
+    async fn initiate_multipart_upload(
+        &self,
+        file_path: &str,
+        content_type: Option<&str>,
+    ) -> Result<String, ObjectStorageError> {
+        let object_key = self.generate_object_key(file_path);
+
+        let mut create_request = self.client
+            .create_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(&object_key);
+
+        if let Some(content_type) = content_type {
+            create_request = create_request.content_type(content_type);
+        }
+
+        let result = create_request
+            .send()
+            .await
+            .map_err(|e| ObjectStorageError::S3Error(e.to_string()))?;
+
+        result
+            .upload_id()
+            .map(|id| id.to_string())
+            .ok_or_else(|| ObjectStorageError::S3Error("S3 returned no upload ID".to_string()))
+    }
+
+    async fn generate_upload_part_url(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        part_number: i32,
+        ttl_seconds: u64,
+    ) -> Result<String, ObjectStorageError> {
+        let object_key = self.generate_object_key(file_path);
+
+        let presigned = self.client
+            .upload_part()
+            .bucket(&self.bucket_name)
+            .key(&object_key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .presigned(PresigningConfig::expires_in(Duration::from_secs(ttl_seconds))
+                .map_err(|e| ObjectStorageError::S3Error(e.to_string()))?)
+            .await
+            .map_err(|e| ObjectStorageError::S3Error(e.to_string()))?;
+
+        Ok(presigned.uri().to_string())
+    }
+
+    async fn list_uploaded_parts(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<Vec<UploadedPart>, ObjectStorageError> {
+        let object_key = self.generate_object_key(file_path);
+
+        // 한 번에 최대 1000개씩 반환되므로 마커를 따라가며 모두 조회
+        let mut parts = Vec::new();
+        let mut marker: Option<String> = None;
+        loop {
+            let mut list_request = self.client
+                .list_parts()
+                .bucket(&self.bucket_name)
+                .key(&object_key)
+                .upload_id(upload_id);
+
+            if let Some(marker) = &marker {
+                list_request = list_request.part_number_marker(marker);
+            }
+
+            let result = list_request
+                .send()
+                .await
+                .map_err(|e| {
+                    if e.to_string().contains("NoSuchUpload") {
+                        ObjectStorageError::FileNotFound(format!("multipart upload {}", upload_id))
+                    } else {
+                        ObjectStorageError::S3Error(e.to_string())
+                    }
+                })?;
+
+            for part in result.parts() {
+                if let (Some(part_number), Some(etag)) = (part.part_number(), part.e_tag()) {
+                    parts.push(UploadedPart {
+                        part_number,
+                        etag: etag.to_string(),
+                        size: part.size().unwrap_or(0),
+                    });
+                }
+            }
+
+            match (result.is_truncated(), result.next_part_number_marker()) {
+                (Some(true), Some(next)) => marker = Some(next.to_string()),
+                _ => break,
+            }
+        }
+
+        parts.sort_by_key(|part| part.part_number);
+        Ok(parts)
+    }
+
+    async fn complete_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        parts: &[CompletedPart],
+    ) -> Result<(), ObjectStorageError> {
+        let object_key = self.generate_object_key(file_path);
+
+        let completed = CompletedMultipartUpload::builder()
+            .set_parts(Some(
+                parts
+                    .iter()
+                    .map(|part| {
+                        S3CompletedPart::builder()
+                            .part_number(part.part_number)
+                            .e_tag(&part.etag)
+                            .build()
+                    })
+                    .collect(),
+            ))
+            .build();
+
+        self.client
+            .complete_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(&object_key)
+            .upload_id(upload_id)
+            .multipart_upload(completed)
+            .send()
+            .await
+            .map_err(|e| {
+                let message = e.to_string();
+                if message.contains("InvalidPart") || message.contains("EntityTooSmall") {
+                    ObjectStorageError::InvalidRequest(message)
+                } else {
+                    ObjectStorageError::S3Error(message)
+                }
+            })?;
+
+        Ok(())
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+    ) -> Result<(), ObjectStorageError> {
+        let object_key = self.generate_object_key(file_path);
+
+        match self.client
+            .abort_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(&object_key)
+            .upload_id(upload_id)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            // 이미 완료되었거나 중단된 업로드
+            Err(e) if e.to_string().contains("NoSuchUpload") => Ok(()),
+            Err(e) => Err(ObjectStorageError::S3Error(e.to_string())),
+        }
+    }
 }
@@ -0,0 +1,533 @@
+//! # DICOM SEG 라벨 볼륨 리더
+//!
+//! DICOM Segmentation Storage 객체를 읽어 프레임(세그먼트 × 슬라이스)별 이진 마스크로 분해합니다.
+//! 마스크 임포트에 필요한 최소한의 태그만 해석하는 경량 파서이며,
+//! 비압축 전송 구문(Implicit/Explicit VR Little Endian, Deflated Explicit VR Little Endian)만 지원합니다.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use super::{ImagingError, LabelFrame, LabelVolume};
+
+const UNDEFINED_LENGTH: u32 = 0xFFFF_FFFF;
+
+const TAG_ITEM: u32 = 0xFFFE_E000;
+const TAG_ITEM_DELIMITATION: u32 = 0xFFFE_E00D;
+const TAG_SEQUENCE_DELIMITATION: u32 = 0xFFFE_E0DD;
+
+const TAG_TRANSFER_SYNTAX_UID: u32 = 0x0002_0010;
+const TAG_MODALITY: u32 = 0x0008_0060;
+const TAG_REFERENCED_SOP_INSTANCE_UID: u32 = 0x0008_1155;
+const TAG_SOURCE_IMAGE_SEQUENCE: u32 = 0x0008_2112;
+const TAG_DERIVATION_IMAGE_SEQUENCE: u32 = 0x0008_9124;
+const TAG_IN_STACK_POSITION_NUMBER: u32 = 0x0020_9057;
+const TAG_FRAME_CONTENT_SEQUENCE: u32 = 0x0020_9111;
+const TAG_NUMBER_OF_FRAMES: u32 = 0x0028_0008;
+const TAG_ROWS: u32 = 0x0028_0010;
+const TAG_COLUMNS: u32 = 0x0028_0011;
+const TAG_BITS_ALLOCATED: u32 = 0x0028_0100;
+const TAG_SEGMENTATION_TYPE: u32 = 0x0062_0001;
+const TAG_SEGMENT_SEQUENCE: u32 = 0x0062_0002;
+const TAG_SEGMENT_NUMBER: u32 = 0x0062_0004;
+const TAG_SEGMENT_LABEL: u32 = 0x0062_0005;
+const TAG_SEGMENT_IDENTIFICATION_SEQUENCE: u32 = 0x0062_000A;
+const TAG_REFERENCED_SEGMENT_NUMBER: u32 = 0x0062_000B;
+const TAG_MAXIMUM_FRACTIONAL_VALUE: u32 = 0x0062_000E;
+const TAG_SHARED_FUNCTIONAL_GROUPS_SEQUENCE: u32 = 0x5200_9229;
+const TAG_PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE: u32 = 0x5200_9230;
+const TAG_PIXEL_DATA: u32 = 0x7FE0_0010;
+
+const IMPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2";
+const EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1";
+const DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1.99";
+
+/// Implicit VR에서 시퀀스로 해석해야 하는 태그 목록
+const SEQUENCE_TAGS: &[u32] = &[
+    TAG_SOURCE_IMAGE_SEQUENCE,
+    TAG_DERIVATION_IMAGE_SEQUENCE,
+    TAG_FRAME_CONTENT_SEQUENCE,
+    TAG_SEGMENT_SEQUENCE,
+    TAG_SEGMENT_IDENTIFICATION_SEQUENCE,
+    TAG_SHARED_FUNCTIONAL_GROUPS_SEQUENCE,
+    TAG_PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE,
+];
+
+/// 데이터 요소 값
+#[derive(Debug)]
+enum Element {
+    Value(Vec<u8>),
+    Sequence(Vec<Dataset>),
+}
+
+type Dataset = HashMap<u32, Element>;
+
+/// 리틀 엔디언 DICOM 데이터셋 파서
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
+    explicit_vr: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn new(data: &'a [u8], pos: usize, explicit_vr: bool) -> Self {
+        Self { data, pos, explicit_vr }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ImagingError> {
+        let end = self.pos.checked_add(len).filter(|end| *end <= self.data.len()).ok_or_else(|| {
+            ImagingError::InvalidData(format!("Unexpected end of DICOM data at offset {}", self.pos))
+        })?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn read_u16(&mut self) -> Result<u16, ImagingError> {
+        let b = self.take(2)?;
+        Ok(u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, ImagingError> {
+        let b = self.take(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn read_tag(&mut self) -> Result<u32, ImagingError> {
+        let group = self.read_u16()? as u32;
+        let element = self.read_u16()? as u32;
+        Ok((group << 16) | element)
+    }
+
+    fn peek_group(&self) -> Option<u16> {
+        self.data
+            .get(self.pos..self.pos + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    /// 데이터셋을 읽습니다. `end`가 없으면 Item Delimitation 또는 데이터 끝까지 읽습니다.
+    fn parse_dataset(&mut self, end: Option<usize>) -> Result<Dataset, ImagingError> {
+        let mut dataset = Dataset::new();
+        loop {
+            match end {
+                Some(end) if self.pos >= end => break,
+                None if self.pos >= self.data.len() => break,
+                _ => {}
+            }
+
+            let tag = self.read_tag()?;
+            if tag == TAG_ITEM_DELIMITATION {
+                self.read_u32()?;
+                break;
+            }
+
+            let (vr, length) = if self.explicit_vr {
+                let vr = self.take(2)?;
+                let vr = [vr[0], vr[1]];
+                let length = match &vr {
+                    b"OB" | b"OD" | b"OF" | b"OL" | b"OV" | b"OW" | b"SQ" | b"SV" | b"UC"
+                    | b"UN" | b"UR" | b"UT" | b"UV" => {
+                        self.take(2)?;
+                        self.read_u32()?
+                    }
+                    _ => self.read_u16()? as u32,
+                };
+                (Some(vr), length)
+            } else {
+                (None, self.read_u32()?)
+            };
+
+            if tag == TAG_PIXEL_DATA && length == UNDEFINED_LENGTH {
+                return Err(ImagingError::UnsupportedFormat(
+                    "Encapsulated (compressed) pixel data is not supported".to_string(),
+                ));
+            }
+
+            let is_sequence = match vr {
+                Some(vr) => &vr == b"SQ" || (&vr == b"UN" && length == UNDEFINED_LENGTH),
+                None => SEQUENCE_TAGS.contains(&tag) || length == UNDEFINED_LENGTH,
+            };
+
+            if is_sequence {
+                // UN + 정의되지 않은 길이는 Implicit VR로 인코딩된 시퀀스
+                let restore = self.explicit_vr;
+                if vr.as_ref() == Some(b"UN") {
+                    self.explicit_vr = false;
+                }
+                let items = self.parse_sequence(length)?;
+                self.explicit_vr = restore;
+                dataset.insert(tag, Element::Sequence(items));
+            } else {
+                let value = self.take(length as usize)?;
+                dataset.insert(tag, Element::Value(value.to_vec()));
+            }
+        }
+        Ok(dataset)
+    }
+
+    fn parse_sequence(&mut self, length: u32) -> Result<Vec<Dataset>, ImagingError> {
+        let end = if length == UNDEFINED_LENGTH { None } else { Some(self.pos + length as usize) };
+        let mut items = Vec::new();
+        loop {
+            if let Some(end) = end {
+                if self.pos >= end {
+                    break;
+                }
+            }
+            let tag = self.read_tag()?;
+            let item_length = self.read_u32()?;
+            if tag == TAG_SEQUENCE_DELIMITATION {
+                break;
+            }
+            if tag != TAG_ITEM {
+                return Err(ImagingError::InvalidData(format!(
+                    "Expected sequence item, found tag {:08X}",
+                    tag
+                )));
+            }
+            let item_end = if item_length == UNDEFINED_LENGTH {
+                None
+            } else {
+                Some(self.pos + item_length as usize)
+            };
+            items.push(self.parse_dataset(item_end)?);
+        }
+        Ok(items)
+    }
+}
+
+fn value(dataset: &Dataset, tag: u32) -> Option<&[u8]> {
+    match dataset.get(&tag) {
+        Some(Element::Value(v)) => Some(v.as_slice()),
+        _ => None,
+    }
+}
+
+fn sequence(dataset: &Dataset, tag: u32) -> Option<&[Dataset]> {
+    match dataset.get(&tag) {
+        Some(Element::Sequence(items)) => Some(items.as_slice()),
+        _ => None,
+    }
+}
+
+fn string_value(dataset: &Dataset, tag: u32) -> Option<String> {
+    value(dataset, tag)
+        .map(|v| String::from_utf8_lossy(v).trim_matches(|c: char| c == '\0' || c.is_whitespace()).to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn u16_value(dataset: &Dataset, tag: u32) -> Option<u16> {
+    value(dataset, tag).filter(|v| v.len() >= 2).map(|v| u16::from_le_bytes([v[0], v[1]]))
+}
+
+fn u32_value(dataset: &Dataset, tag: u32) -> Option<u32> {
+    value(dataset, tag)
+        .filter(|v| v.len() >= 4)
+        .map(|v| u32::from_le_bytes([v[0], v[1], v[2], v[3]]))
+}
+
+/// 중첩 시퀀스 경로의 첫 번째 아이템들을 따라 내려갑니다.
+fn first_item<'d>(dataset: &'d Dataset, path: &[u32]) -> Option<&'d Dataset> {
+    path.iter().try_fold(dataset, |ds, tag| sequence(ds, *tag).and_then(|items| items.first()))
+}
+
+/// 파일 메타 정보를 읽고 데이터셋 본문을 파싱합니다.
+fn parse_file(data: &[u8]) -> Result<Dataset, ImagingError> {
+    let has_preamble = data.len() >= 132 && &data[128..132] == b"DICM";
+    if !has_preamble {
+        // 메타 정보가 없는 raw 데이터셋은 Implicit VR Little Endian으로 간주
+        return Parser::new(data, 0, false).parse_dataset(None);
+    }
+
+    let mut meta_parser = Parser::new(data, 132, true);
+    let mut transfer_syntax = None;
+    while meta_parser.peek_group() == Some(0x0002) {
+        let tag = meta_parser.read_tag()?;
+        let vr = meta_parser.take(2)?;
+        let length = match vr {
+            b"OB" | b"OW" | b"UN" | b"SQ" | b"UT" => {
+                meta_parser.take(2)?;
+                meta_parser.read_u32()?
+            }
+            _ => meta_parser.read_u16()? as u32,
+        };
+        let value = meta_parser.take(length as usize)?;
+        if tag == TAG_TRANSFER_SYNTAX_UID {
+            transfer_syntax = Some(
+                String::from_utf8_lossy(value)
+                    .trim_matches(|c: char| c == '\0' || c.is_whitespace())
+                    .to_string(),
+            );
+        }
+    }
+
+    let body_start = meta_parser.pos;
+    match transfer_syntax.as_deref().unwrap_or(EXPLICIT_VR_LITTLE_ENDIAN) {
+        IMPLICIT_VR_LITTLE_ENDIAN => Parser::new(data, body_start, false).parse_dataset(None),
+        EXPLICIT_VR_LITTLE_ENDIAN => Parser::new(data, body_start, true).parse_dataset(None),
+        DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN => {
+            let mut inflated = Vec::new();
+            flate2::read::DeflateDecoder::new(&data[body_start..])
+                .read_to_end(&mut inflated)
+                .map_err(|e| ImagingError::InvalidData(format!("Failed to inflate DICOM dataset: {}", e)))?;
+            Parser::new(&inflated, 0, true).parse_dataset(None)
+        }
+        other => Err(ImagingError::UnsupportedFormat(format!(
+            "DICOM transfer syntax {} is not supported",
+            other
+        ))),
+    }
+}
+
+/// DICOM SEG 객체를 읽어 프레임별 이진 마스크로 분해합니다.
+///
+/// 각 프레임의 세그먼트 번호와 참조 SOP Instance UID는 Per-frame Functional Groups에서 읽습니다.
+/// `slice_index`는 In-Stack Position Number(1부터 시작)가 있으면 그 값에서 1을 뺀 값을,
+/// 없으면 같은 세그먼트 안에서의 프레임 순서를 사용합니다. 전경 픽셀이 없는 프레임은 제외합니다.
+pub fn read_dicom_seg(data: &[u8]) -> Result<LabelVolume, ImagingError> {
+    let dataset = parse_file(data)?;
+
+    if let Some(modality) = string_value(&dataset, TAG_MODALITY) {
+        if modality != "SEG" {
+            return Err(ImagingError::InvalidData(format!(
+                "Expected DICOM SEG, found modality {}",
+                modality
+            )));
+        }
+    }
+
+    let rows = u16_value(&dataset, TAG_ROWS)
+        .ok_or_else(|| ImagingError::InvalidData("Missing Rows".to_string()))? as usize;
+    let columns = u16_value(&dataset, TAG_COLUMNS)
+        .ok_or_else(|| ImagingError::InvalidData("Missing Columns".to_string()))? as usize;
+    let number_of_frames = string_value(&dataset, TAG_NUMBER_OF_FRAMES)
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(1);
+    let bits_allocated = u16_value(&dataset, TAG_BITS_ALLOCATED).unwrap_or(1);
+    let fractional = string_value(&dataset, TAG_SEGMENTATION_TYPE).as_deref() == Some("FRACTIONAL");
+    let max_fractional = u16_value(&dataset, TAG_MAXIMUM_FRACTIONAL_VALUE).unwrap_or(255).max(1) as u32;
+
+    let segment_labels: HashMap<i32, Option<String>> = sequence(&dataset, TAG_SEGMENT_SEQUENCE)
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(|item| {
+            u16_value(item, TAG_SEGMENT_NUMBER)
+                .map(|number| (number as i32, string_value(item, TAG_SEGMENT_LABEL)))
+        })
+        .collect();
+
+    let pixel_data = value(&dataset, TAG_PIXEL_DATA)
+        .ok_or_else(|| ImagingError::InvalidData("Missing Pixel Data".to_string()))?;
+    let frame_len = rows * columns;
+    let frame_pixel = |frame: usize, pixel: usize| -> Result<u8, ImagingError> {
+        match bits_allocated {
+            1 => {
+                let bit = frame * frame_len + pixel;
+                pixel_data
+                    .get(bit / 8)
+                    .map(|byte| (byte >> (bit % 8)) & 1)
+                    .ok_or_else(|| ImagingError::InvalidData("Pixel Data is truncated".to_string()))
+            }
+            8 => pixel_data
+                .get(frame * frame_len + pixel)
+                .map(|v| {
+                    if fractional {
+                        (*v as u32 * 2 >= max_fractional) as u8
+                    } else {
+                        (*v != 0) as u8
+                    }
+                })
+                .ok_or_else(|| ImagingError::InvalidData("Pixel Data is truncated".to_string())),
+            other => Err(ImagingError::UnsupportedFormat(format!(
+                "Bits Allocated {} is not supported for DICOM SEG",
+                other
+            ))),
+        }
+    };
+
+    let per_frame = sequence(&dataset, TAG_PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE).unwrap_or(&[]);
+    let shared = sequence(&dataset, TAG_SHARED_FUNCTIONAL_GROUPS_SEQUENCE).and_then(|s| s.first());
+    let segment_path = [TAG_SEGMENT_IDENTIFICATION_SEQUENCE];
+    let source_path = [TAG_DERIVATION_IMAGE_SEQUENCE, TAG_SOURCE_IMAGE_SEQUENCE];
+    let position_path = [TAG_FRAME_CONTENT_SEQUENCE];
+
+    let mut frames = Vec::new();
+    let mut ordinal_per_segment: HashMap<i32, i32> = HashMap::new();
+    for frame in 0..number_of_frames {
+        let groups = per_frame.get(frame);
+        let lookup = |path: &[u32]| {
+            groups
+                .and_then(|g| first_item(g, path))
+                .or_else(|| shared.and_then(|s| first_item(s, path)))
+        };
+
+        let label_value = lookup(&segment_path)
+            .and_then(|item| u16_value(item, TAG_REFERENCED_SEGMENT_NUMBER))
+            .map(|n| n as i32)
+            .unwrap_or(1);
+        let sop_instance_uid = lookup(&source_path)
+            .and_then(|item| string_value(item, TAG_REFERENCED_SOP_INSTANCE_UID));
+        let ordinal = ordinal_per_segment.entry(label_value).or_insert(0);
+        let slice_index = lookup(&position_path)
+            .and_then(|item| u32_value(item, TAG_IN_STACK_POSITION_NUMBER))
+            .map(|p| p as i32 - 1)
+            .unwrap_or(*ordinal);
+        *ordinal += 1;
+
+        let pixels = (0..frame_len)
+            .map(|p| frame_pixel(frame, p))
+            .collect::<Result<Vec<u8>, _>>()?;
+        if pixels.iter().all(|p| *p == 0) {
+            continue;
+        }
+
+        frames.push(LabelFrame {
+            frame_index: frame,
+            slice_index,
+            label_value,
+            label_name: segment_labels.get(&label_value).cloned().flatten(),
+            sop_instance_uid,
+            pixels,
+        });
+    }
+
+    Ok(LabelVolume {
+        rows: rows as u32,
+        columns: columns as u32,
+        frames,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Explicit VR Little Endian 요소 하나를 인코딩합니다.
+    fn element(tag: u32, vr: &[u8; 2], value: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&((tag >> 16) as u16).to_le_bytes());
+        out.extend_from_slice(&((tag & 0xFFFF) as u16).to_le_bytes());
+        out.extend_from_slice(vr);
+        match vr {
+            b"OB" | b"OW" | b"SQ" | b"UN" | b"UT" => {
+                out.extend_from_slice(&[0, 0]);
+                out.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            }
+            _ => out.extend_from_slice(&(value.len() as u16).to_le_bytes()),
+        }
+        out.extend_from_slice(value);
+        out
+    }
+
+    /// 정의되지 않은 길이의 시퀀스를 인코딩합니다.
+    fn sequence_of(tag: u32, items: &[Vec<u8>]) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&((tag >> 16) as u16).to_le_bytes());
+        out.extend_from_slice(&((tag & 0xFFFF) as u16).to_le_bytes());
+        out.extend_from_slice(b"SQ\0\0");
+        out.extend_from_slice(&UNDEFINED_LENGTH.to_le_bytes());
+        for item in items {
+            out.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0]);
+            out.extend_from_slice(&(item.len() as u32).to_le_bytes());
+            out.extend_from_slice(item);
+        }
+        out.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+        out
+    }
+
+    fn padded(s: &str) -> Vec<u8> {
+        let mut v = s.as_bytes().to_vec();
+        if v.len() % 2 == 1 {
+            v.push(0);
+        }
+        v
+    }
+
+    fn frame_group(segment: u16, sop_uid: Option<&str>) -> Vec<u8> {
+        let mut item = sequence_of(
+            TAG_SEGMENT_IDENTIFICATION_SEQUENCE,
+            &[element(TAG_REFERENCED_SEGMENT_NUMBER, b"US", &segment.to_le_bytes())],
+        );
+        if let Some(uid) = sop_uid {
+            let source = element(TAG_REFERENCED_SOP_INSTANCE_UID, b"UI", &padded(uid));
+            let derivation = sequence_of(TAG_SOURCE_IMAGE_SEQUENCE, &[source]);
+            let mut with_derivation = sequence_of(TAG_DERIVATION_IMAGE_SEQUENCE, &[derivation]);
+            with_derivation.extend(item);
+            item = with_derivation;
+        }
+        item
+    }
+
+    /// 2x2 BINARY SEG (세그먼트 2개, 프레임 3개)를 생성합니다.
+    fn build_binary_seg() -> Vec<u8> {
+        let mut file = vec![0u8; 128];
+        file.extend_from_slice(b"DICM");
+        file.extend(element(TAG_TRANSFER_SYNTAX_UID, b"UI", &padded(EXPLICIT_VR_LITTLE_ENDIAN)));
+
+        // 태그 오름차순으로 데이터셋 구성
+        file.extend(element(TAG_MODALITY, b"CS", b"SEG "));
+        file.extend(element(TAG_NUMBER_OF_FRAMES, b"IS", b"3 "));
+        file.extend(element(TAG_ROWS, b"US", &2u16.to_le_bytes()));
+        file.extend(element(TAG_COLUMNS, b"US", &2u16.to_le_bytes()));
+        file.extend(element(TAG_BITS_ALLOCATED, b"US", &1u16.to_le_bytes()));
+        file.extend(element(TAG_SEGMENTATION_TYPE, b"CS", b"BINARY"));
+        let segment = |number: u16, label: &str| {
+            let mut item = element(TAG_SEGMENT_NUMBER, b"US", &number.to_le_bytes());
+            item.extend(element(TAG_SEGMENT_LABEL, b"LO", &padded(label)));
+            item
+        };
+        file.extend(sequence_of(TAG_SEGMENT_SEQUENCE, &[segment(1, "liver"), segment(2, "tumor")]));
+        file.extend(sequence_of(
+            TAG_PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE,
+            &[
+                frame_group(1, Some("1.2.3.1")),
+                frame_group(1, None),
+                frame_group(2, Some("1.2.3.1")),
+            ],
+        ));
+        // 프레임 0: 1000, 프레임 1: 0110, 프레임 2: 0001 → 비트 순서(LSB 우선) 0b0110_0001, 0b0000_1000
+        file.extend(element(TAG_PIXEL_DATA, b"OB", &[0b0110_0001, 0b0000_1000]));
+        file
+    }
+
+    #[test]
+    fn test_read_binary_dicom_seg() {
+        let volume = read_dicom_seg(&build_binary_seg()).unwrap();
+
+        assert_eq!((volume.rows, volume.columns), (2, 2));
+        assert_eq!(volume.frames.len(), 3);
+
+        let first = &volume.frames[0];
+        assert_eq!(first.label_value, 1);
+        assert_eq!(first.label_name.as_deref(), Some("liver"));
+        assert_eq!(first.sop_instance_uid.as_deref(), Some("1.2.3.1"));
+        assert_eq!(first.slice_index, 0);
+        assert_eq!(first.pixels, vec![1, 0, 0, 0]);
+
+        let second = &volume.frames[1];
+        assert_eq!(second.sop_instance_uid, None);
+        assert_eq!(second.slice_index, 1);
+        assert_eq!(second.pixels, vec![0, 1, 1, 0]);
+
+        let third = &volume.frames[2];
+        assert_eq!(third.label_name.as_deref(), Some("tumor"));
+        assert_eq!(third.slice_index, 0);
+        assert_eq!(third.pixels, vec![0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_rejects_non_seg_modality() {
+        let mut file = vec![0u8; 128];
+        file.extend_from_slice(b"DICM");
+        file.extend(element(TAG_TRANSFER_SYNTAX_UID, b"UI", &padded(EXPLICIT_VR_LITTLE_ENDIAN)));
+        file.extend(element(TAG_MODALITY, b"CS", b"CT"));
+        assert!(matches!(read_dicom_seg(&file), Err(ImagingError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_rejects_compressed_transfer_syntax() {
+        let mut file = vec![0u8; 128];
+        file.extend_from_slice(b"DICM");
+        file.extend(element(TAG_TRANSFER_SYNTAX_UID, b"UI", &padded("1.2.840.10008.1.2.5")));
+        assert!(matches!(read_dicom_seg(&file), Err(ImagingError::UnsupportedFormat(_))));
+    }
+}
@@ -0,0 +1,149 @@
+//! # 영상 처리 모듈
+//!
+//! 라벨 볼륨(NIfTI, DICOM SEG)을 읽어 슬라이스/라벨 단위의 이진 마스크로 분해하고,
+//! 마스크를 PNG로 인코딩/디코딩하고 COCO RLE로 변환하며, 썸네일과 컬러 미리보기를 만들고
+//! 두 마스크의 겹침과 Hausdorff 거리를 계산하는 기능을 제공합니다.
+
+pub mod dicom_seg;
+pub mod nifti;
+pub mod overlap;
+pub mod png_codec;
+pub mod rle;
+pub mod thumbnail;
+
+pub use png_codec::{decode_binary_mask_png, decode_label_mask_png, encode_binary_mask_png};
+pub use overlap::{hausdorff_distance, overlap_counts};
+pub use rle::{encode_column_major_rle, label_regions, mask_bounding_box};
+pub use thumbnail::{encode_contact_sheet_png, encode_preview_png, encode_thumbnail_png};
+
+/// 영상 처리 에러 타입
+#[derive(Debug, thiserror::Error)]
+pub enum ImagingError {
+    #[error("Unsupported format: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("Invalid data: {0}")]
+    InvalidData(String),
+
+    #[error("Encoding failed: {0}")]
+    EncodingError(String),
+}
+
+impl From<ImagingError> for crate::domain::ServiceError {
+    fn from(err: ImagingError) -> Self {
+        match err {
+            ImagingError::EncodingError(msg) => crate::domain::ServiceError::ExternalServiceError(msg),
+            other => crate::domain::ServiceError::ValidationError(other.to_string()),
+        }
+    }
+}
+
+/// 라벨 볼륨 파일 포맷
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelVolumeFormat {
+    /// NIfTI-1 (`.nii`, `.nii.gz`)
+    Nifti,
+    /// DICOM Segmentation Storage (`.dcm`)
+    DicomSeg,
+}
+
+impl LabelVolumeFormat {
+    /// 포맷 이름 (`nifti`, `dicom_seg`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LabelVolumeFormat::Nifti => "nifti",
+            LabelVolumeFormat::DicomSeg => "dicom_seg",
+        }
+    }
+
+    /// 명시된 포맷 이름 또는 파일 경로의 확장자로 포맷을 결정합니다.
+    pub fn resolve(format: Option<&str>, file_path: &str) -> Result<Self, ImagingError> {
+        if let Some(format) = format {
+            return match format.to_lowercase().as_str() {
+                "nifti" | "nii" => Ok(LabelVolumeFormat::Nifti),
+                "dicom_seg" | "dicom-seg" | "seg" | "dicom" => Ok(LabelVolumeFormat::DicomSeg),
+                other => Err(ImagingError::UnsupportedFormat(other.to_string())),
+            };
+        }
+
+        let lower = file_path.to_lowercase();
+        if lower.ends_with(".nii") || lower.ends_with(".nii.gz") {
+            Ok(LabelVolumeFormat::Nifti)
+        } else if lower.ends_with(".dcm") || lower.ends_with(".seg") {
+            Ok(LabelVolumeFormat::DicomSeg)
+        } else {
+            Err(ImagingError::UnsupportedFormat(format!(
+                "Cannot infer label volume format from '{}'",
+                file_path
+            )))
+        }
+    }
+}
+
+/// 라벨 볼륨의 단일 프레임 (하나의 슬라이스, 하나의 라벨)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelFrame {
+    /// 원본 파일 내 프레임 순번 (0부터 시작)
+    pub frame_index: usize,
+    /// 볼륨 내 슬라이스 인덱스 (0부터 시작)
+    pub slice_index: i32,
+    /// 라벨 값 (NIfTI 복셀 값 또는 DICOM SEG Segment Number)
+    pub label_value: i32,
+    /// 라벨 이름 (DICOM SEG Segment Label 등)
+    pub label_name: Option<String>,
+    /// 원본 파일이 참조하는 SOP Instance UID
+    pub sop_instance_uid: Option<String>,
+    /// 행 우선(row-major) 이진 마스크 (0 또는 1)
+    pub pixels: Vec<u8>,
+}
+
+/// 슬라이스/라벨 단위로 분해된 라벨 볼륨
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelVolume {
+    /// 이미지 높이 (픽셀)
+    pub rows: u32,
+    /// 이미지 너비 (픽셀)
+    pub columns: u32,
+    /// 전경 픽셀이 하나 이상 있는 프레임 목록
+    pub frames: Vec<LabelFrame>,
+}
+
+impl LabelVolume {
+    /// 프레임이 존재하는 서로 다른 슬라이스 개수
+    pub fn slice_count(&self) -> usize {
+        let mut slices: Vec<i32> = self.frames.iter().map(|f| f.slice_index).collect();
+        slices.sort_unstable();
+        slices.dedup();
+        slices.len()
+    }
+}
+
+/// 주어진 포맷으로 라벨 볼륨을 읽습니다.
+pub fn read_label_volume(format: LabelVolumeFormat, data: &[u8]) -> Result<LabelVolume, ImagingError> {
+    match format {
+        LabelVolumeFormat::Nifti => nifti::read_nifti(data),
+        LabelVolumeFormat::DicomSeg => dicom_seg::read_dicom_seg(data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_format_from_extension() {
+        assert_eq!(LabelVolumeFormat::resolve(None, "a/b/seg.nii.gz").unwrap(), LabelVolumeFormat::Nifti);
+        assert_eq!(LabelVolumeFormat::resolve(None, "a/b/seg.NII").unwrap(), LabelVolumeFormat::Nifti);
+        assert_eq!(LabelVolumeFormat::resolve(None, "a/b/seg.dcm").unwrap(), LabelVolumeFormat::DicomSeg);
+        assert!(LabelVolumeFormat::resolve(None, "a/b/seg.zip").is_err());
+    }
+
+    #[test]
+    fn test_resolve_explicit_format_wins() {
+        assert_eq!(
+            LabelVolumeFormat::resolve(Some("dicom_seg"), "upload.bin").unwrap(),
+            LabelVolumeFormat::DicomSeg
+        );
+        assert!(LabelVolumeFormat::resolve(Some("nrrd"), "upload.nii").is_err());
+    }
+}
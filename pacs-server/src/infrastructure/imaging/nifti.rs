@@ -0,0 +1,277 @@
+//! # NIfTI-1 라벨 볼륨 리더
+//!
+//! 단일 파일 NIfTI-1(`n+1`) 라벨 맵을 읽어 슬라이스(z)별, 라벨 값별 이진 마스크로 분해합니다.
+//! gzip으로 압축된 `.nii.gz`도 지원합니다. 복셀 순서는 파일에 저장된 그대로
+//! (x = 열, y = 행) 유지하며 방향(orientation) 변환은 하지 않습니다.
+
+use std::collections::BTreeSet;
+use std::io::Read;
+
+use super::{ImagingError, LabelFrame, LabelVolume};
+
+/// NIfTI-1 헤더 크기
+const NIFTI1_HEADER_SIZE: usize = 348;
+/// NIfTI-2 헤더 크기
+const NIFTI2_HEADER_SIZE: i32 = 540;
+/// 압축 해제된 NIfTI 파일의 최대 크기 (1 GiB)
+pub const MAX_NIFTI_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// 헤더 필드를 엔디언에 맞게 읽기 위한 헬퍼
+struct HeaderReader<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+}
+
+impl HeaderReader<'_> {
+    fn i16_at(&self, offset: usize) -> i16 {
+        let raw = [self.bytes[offset], self.bytes[offset + 1]];
+        if self.little_endian { i16::from_le_bytes(raw) } else { i16::from_be_bytes(raw) }
+    }
+
+    fn f32_at(&self, offset: usize) -> f32 {
+        let raw = [
+            self.bytes[offset],
+            self.bytes[offset + 1],
+            self.bytes[offset + 2],
+            self.bytes[offset + 3],
+        ];
+        if self.little_endian { f32::from_le_bytes(raw) } else { f32::from_be_bytes(raw) }
+    }
+}
+
+/// gzip 매직 넘버가 있으면 압축을 해제합니다. 압축 해제 결과가 `max_size`를 넘으면 거부합니다.
+fn decompress_if_gzipped(data: &[u8], max_size: u64) -> Result<Vec<u8>, ImagingError> {
+    if data.len() >= 2 && data[0] == 0x1f && data[1] == 0x8b {
+        let mut out = Vec::new();
+        flate2::read::GzDecoder::new(data)
+            .take(max_size + 1)
+            .read_to_end(&mut out)
+            .map_err(|e| ImagingError::InvalidData(format!("Failed to decompress NIfTI: {}", e)))?;
+        if out.len() as u64 > max_size {
+            return Err(ImagingError::InvalidData(format!(
+                "Decompressed NIfTI exceeds {} bytes",
+                max_size
+            )));
+        }
+        Ok(out)
+    } else {
+        Ok(data.to_vec())
+    }
+}
+
+/// NIfTI datatype 코드별 복셀 크기 (바이트)
+fn voxel_size(datatype: i16) -> Result<usize, ImagingError> {
+    match datatype {
+        2 | 256 => Ok(1),  // UINT8, INT8
+        4 | 512 => Ok(2),  // INT16, UINT16
+        8 | 768 | 16 => Ok(4), // INT32, UINT32, FLOAT32
+        64 => Ok(8),       // FLOAT64
+        other => Err(ImagingError::UnsupportedFormat(format!(
+            "NIfTI datatype {} is not supported for label maps",
+            other
+        ))),
+    }
+}
+
+/// 복셀 하나를 실수 값으로 읽습니다.
+fn read_voxel(raw: &[u8], datatype: i16, little_endian: bool) -> f64 {
+    macro_rules! num {
+        ($t:ty, $n:expr) => {{
+            let mut buf = [0u8; $n];
+            buf.copy_from_slice(&raw[..$n]);
+            if little_endian { <$t>::from_le_bytes(buf) as f64 } else { <$t>::from_be_bytes(buf) as f64 }
+        }};
+    }
+    match datatype {
+        2 => raw[0] as f64,
+        256 => raw[0] as i8 as f64,
+        4 => num!(i16, 2),
+        512 => num!(u16, 2),
+        8 => num!(i32, 4),
+        768 => num!(u32, 4),
+        16 => num!(f32, 4),
+        64 => num!(f64, 8),
+        _ => 0.0,
+    }
+}
+
+/// NIfTI-1 라벨 볼륨을 읽어 슬라이스/라벨 단위 프레임으로 분해합니다.
+///
+/// 0이 아닌 각 정수 라벨 값마다, 해당 라벨이 존재하는 슬라이스에 대해 하나의 프레임을 생성합니다.
+/// 라벨 이름은 알 수 없으므로 `None`으로 남겨 두고 호출자가 매핑합니다.
+pub fn read_nifti(data: &[u8]) -> Result<LabelVolume, ImagingError> {
+    let bytes = decompress_if_gzipped(data, MAX_NIFTI_SIZE)?;
+    if bytes.len() < NIFTI1_HEADER_SIZE {
+        return Err(ImagingError::InvalidData("NIfTI header is truncated".to_string()));
+    }
+
+    let size_raw = [bytes[0], bytes[1], bytes[2], bytes[3]];
+    let little_endian = if i32::from_le_bytes(size_raw) == NIFTI1_HEADER_SIZE as i32 {
+        true
+    } else if i32::from_be_bytes(size_raw) == NIFTI1_HEADER_SIZE as i32 {
+        false
+    } else if i32::from_le_bytes(size_raw) == NIFTI2_HEADER_SIZE
+        || i32::from_be_bytes(size_raw) == NIFTI2_HEADER_SIZE
+    {
+        return Err(ImagingError::UnsupportedFormat("NIfTI-2 is not supported".to_string()));
+    } else {
+        return Err(ImagingError::InvalidData("Not a NIfTI-1 file".to_string()));
+    };
+
+    match &bytes[344..348] {
+        b"n+1\0" => {}
+        b"ni1\0" => {
+            return Err(ImagingError::UnsupportedFormat(
+                "Separate .hdr/.img NIfTI pairs are not supported".to_string(),
+            ))
+        }
+        _ => return Err(ImagingError::InvalidData("Invalid NIfTI magic".to_string())),
+    }
+
+    let header = HeaderReader { bytes: &bytes, little_endian };
+    let ndim = header.i16_at(40);
+    if !(2..=7).contains(&ndim) {
+        return Err(ImagingError::InvalidData(format!("Invalid NIfTI dimension count {}", ndim)));
+    }
+    let dim = |i: usize| -> i16 { if i as i16 <= ndim { header.i16_at(40 + i * 2) } else { 1 } };
+    let (nx, ny, nz) = (dim(1), dim(2), dim(3));
+    if nx <= 0 || ny <= 0 || nz <= 0 {
+        return Err(ImagingError::InvalidData(format!("Invalid NIfTI dimensions {}x{}x{}", nx, ny, nz)));
+    }
+    if (4..=ndim as usize).any(|i| dim(i) > 1) {
+        return Err(ImagingError::UnsupportedFormat(
+            "Only 3D label volumes are supported".to_string(),
+        ));
+    }
+
+    let datatype = header.i16_at(70);
+    let voxel_bytes = voxel_size(datatype)?;
+    let vox_offset = (header.f32_at(108) as usize).max(NIFTI1_HEADER_SIZE + 4);
+    let slope = header.f32_at(112);
+    let intercept = header.f32_at(116);
+    let scale = |v: f64| -> f64 {
+        if slope != 0.0 && slope.is_finite() { v * slope as f64 + intercept as f64 } else { v }
+    };
+
+    let (nx, ny, nz) = (nx as usize, ny as usize, nz as usize);
+    let slice_len = nx * ny;
+    let needed = vox_offset + slice_len * nz * voxel_bytes;
+    if bytes.len() < needed {
+        return Err(ImagingError::InvalidData(format!(
+            "NIfTI voxel data is truncated: expected {} bytes, got {}",
+            needed,
+            bytes.len()
+        )));
+    }
+
+    let mut frames = Vec::new();
+    for z in 0..nz {
+        let start = vox_offset + z * slice_len * voxel_bytes;
+        let labels: Vec<i32> = bytes[start..start + slice_len * voxel_bytes]
+            .chunks_exact(voxel_bytes)
+            .map(|raw| scale(read_voxel(raw, datatype, little_endian)).round() as i32)
+            .collect();
+
+        let present: BTreeSet<i32> = labels.iter().copied().filter(|v| *v != 0).collect();
+        for label_value in present {
+            let frame_index = frames.len();
+            frames.push(LabelFrame {
+                frame_index,
+                slice_index: z as i32,
+                label_value,
+                label_name: None,
+                sop_instance_uid: None,
+                pixels: labels.iter().map(|v| (*v == label_value) as u8).collect(),
+            });
+        }
+    }
+
+    Ok(LabelVolume {
+        rows: ny as u32,
+        columns: nx as u32,
+        frames,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// 테스트용 UINT8 NIfTI-1 볼륨을 생성합니다.
+    fn build_nifti_u8(nx: i16, ny: i16, nz: i16, voxels: &[u8]) -> Vec<u8> {
+        let mut header = vec![0u8; NIFTI1_HEADER_SIZE];
+        header[0..4].copy_from_slice(&(NIFTI1_HEADER_SIZE as i32).to_le_bytes());
+        let dims: [i16; 8] = [3, nx, ny, nz, 1, 1, 1, 1];
+        for (i, d) in dims.iter().enumerate() {
+            header[40 + i * 2..42 + i * 2].copy_from_slice(&d.to_le_bytes());
+        }
+        header[70..72].copy_from_slice(&2i16.to_le_bytes());
+        header[72..74].copy_from_slice(&8i16.to_le_bytes());
+        header[108..112].copy_from_slice(&352f32.to_le_bytes());
+        header[344..348].copy_from_slice(b"n+1\0");
+
+        let mut out = header;
+        out.extend_from_slice(&[0u8; 4]);
+        out.extend_from_slice(voxels);
+        out
+    }
+
+    #[test]
+    fn test_read_nifti_splits_slices_and_labels() {
+        // 2x2x2 볼륨: z=0에는 라벨 1, 2 / z=1에는 라벨 1만 존재
+        let voxels = [1, 0, 2, 2, 0, 0, 0, 1];
+        let volume = read_nifti(&build_nifti_u8(2, 2, 2, &voxels)).unwrap();
+
+        assert_eq!(volume.rows, 2);
+        assert_eq!(volume.columns, 2);
+        assert_eq!(volume.frames.len(), 3);
+        assert_eq!(volume.slice_count(), 2);
+
+        let first = &volume.frames[0];
+        assert_eq!((first.slice_index, first.label_value), (0, 1));
+        assert_eq!(first.pixels, vec![1, 0, 0, 0]);
+
+        let second = &volume.frames[1];
+        assert_eq!((second.slice_index, second.label_value), (0, 2));
+        assert_eq!(second.pixels, vec![0, 0, 1, 1]);
+
+        let third = &volume.frames[2];
+        assert_eq!((third.slice_index, third.label_value), (1, 1));
+        assert_eq!(third.pixels, vec![0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_read_gzipped_nifti() {
+        let raw = build_nifti_u8(1, 1, 1, &[3]);
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&raw).unwrap();
+        let gz = encoder.finish().unwrap();
+
+        let volume = read_nifti(&gz).unwrap();
+        assert_eq!(volume.frames.len(), 1);
+        assert_eq!(volume.frames[0].label_value, 3);
+    }
+
+    #[test]
+    fn test_decompress_rejects_oversized_gzip() {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&[0u8; 4096]).unwrap();
+        let gz = encoder.finish().unwrap();
+
+        assert_eq!(decompress_if_gzipped(&gz, 4096).unwrap().len(), 4096);
+        assert!(matches!(decompress_if_gzipped(&gz, 4095), Err(ImagingError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_read_nifti_rejects_truncated_data() {
+        let mut raw = build_nifti_u8(4, 4, 4, &[0u8; 64]);
+        raw.truncate(raw.len() - 10);
+        assert!(matches!(read_nifti(&raw), Err(ImagingError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_read_nifti_rejects_non_nifti() {
+        assert!(read_nifti(&[0u8; 400]).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+//! # 마스크 겹침 측정
+//!
+//! 같은 크기의 두 이진 마스크(0이 아닌 픽셀이 전경)의 복셀 수와 교집합, 대칭 Hausdorff 거리를 계산합니다.
+//! Hausdorff 거리는 Felzenszwalb-Huttenlocher 방식의 정확한 유클리드 거리 변환으로 구하며 단위는 픽셀입니다.
+
+/// 거리 변환에서 전경이 없는 위치의 값
+const FAR: f64 = 1e20;
+
+/// 두 마스크의 복셀 수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OverlapCounts {
+    pub first: u64,
+    pub second: u64,
+    pub intersection: u64,
+}
+
+/// 두 마스크의 전경 복셀 수와 교집합 복셀 수
+pub fn overlap_counts(first: &[u8], second: &[u8]) -> OverlapCounts {
+    let mut counts = OverlapCounts::default();
+    for (a, b) in first.iter().zip(second) {
+        let (a, b) = (*a != 0, *b != 0);
+        counts.first += a as u64;
+        counts.second += b as u64;
+        counts.intersection += (a && b) as u64;
+    }
+    counts
+}
+
+/// 1차원 제곱 거리 변환 (하한 포물선의 아래 포락선)
+fn distance_transform_1d(f: &[f64], out: &mut [f64], vertices: &mut [usize], bounds: &mut [f64]) {
+    let n = f.len();
+    let mut k = 0;
+    vertices[0] = 0;
+    bounds[0] = f64::NEG_INFINITY;
+    bounds[1] = f64::INFINITY;
+    for q in 1..n {
+        // bounds[0]이 음의 무한대이므로 k가 0 아래로 내려가지 않음
+        let mut s;
+        loop {
+            let p = vertices[k];
+            s = ((f[q] + (q * q) as f64) - (f[p] + (p * p) as f64)) / (2.0 * (q as f64 - p as f64));
+            if s > bounds[k] {
+                break;
+            }
+            k -= 1;
+        }
+        k += 1;
+        vertices[k] = q;
+        bounds[k] = s;
+        bounds[k + 1] = f64::INFINITY;
+    }
+    k = 0;
+    for (q, value) in out.iter_mut().enumerate().take(n) {
+        while bounds[k + 1] < q as f64 {
+            k += 1;
+        }
+        let p = vertices[k];
+        let d = q as f64 - p as f64;
+        *value = d * d + f[p];
+    }
+}
+
+/// 각 픽셀에서 가장 가까운 전경 픽셀까지의 제곱 유클리드 거리
+pub fn squared_distance_transform(width: u32, height: u32, mask: &[u8]) -> Vec<f64> {
+    let (width, height) = (width as usize, height as usize);
+    let mut grid: Vec<f64> = mask.iter().map(|v| if *v != 0 { 0.0 } else { FAR }).collect();
+
+    let longest = width.max(height);
+    let mut f = vec![0.0; longest];
+    let mut out = vec![0.0; longest];
+    let mut vertices = vec![0usize; longest];
+    let mut bounds = vec![0.0; longest + 1];
+
+    for x in 0..width {
+        for y in 0..height {
+            f[y] = grid[y * width + x];
+        }
+        distance_transform_1d(&f[..height], &mut out[..height], &mut vertices, &mut bounds);
+        for y in 0..height {
+            grid[y * width + x] = out[y];
+        }
+    }
+    for y in 0..height {
+        let row = &mut grid[y * width..(y + 1) * width];
+        f[..width].copy_from_slice(row);
+        distance_transform_1d(&f[..width], &mut out[..width], &mut vertices, &mut bounds);
+        row.copy_from_slice(&out[..width]);
+    }
+    grid
+}
+
+/// 두 마스크의 대칭 Hausdorff 거리 (픽셀, 어느 한쪽에 전경이 없으면 None)
+pub fn hausdorff_distance(width: u32, height: u32, first: &[u8], second: &[u8]) -> Option<f64> {
+    if !first.iter().any(|v| *v != 0) || !second.iter().any(|v| *v != 0) {
+        return None;
+    }
+    let to_second = squared_distance_transform(width, height, second);
+    let to_first = squared_distance_transform(width, height, first);
+    let directed = |mask: &[u8], distances: &[f64]| {
+        mask.iter()
+            .zip(distances)
+            .filter(|(v, _)| **v != 0)
+            .map(|(_, d)| *d)
+            .fold(0.0, f64::max)
+    };
+    let squared = directed(first, &to_second).max(directed(second, &to_first));
+    Some(squared.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlap_counts() {
+        let counts = overlap_counts(&[1, 1, 0, 0], &[0, 255, 1, 0]);
+        assert_eq!(counts, OverlapCounts { first: 2, second: 2, intersection: 1 });
+    }
+
+    #[test]
+    fn test_distance_transform_matches_brute_force() {
+        let (width, height) = (7u32, 5u32);
+        let mut mask = vec![0u8; 35];
+        mask[8] = 1;
+        mask[26] = 1;
+        let distances = squared_distance_transform(width, height, &mask);
+        for y in 0..height as i64 {
+            for x in 0..width as i64 {
+                let expected = [(1i64, 1i64), (5, 3)]
+                    .iter()
+                    .map(|(fx, fy)| ((x - fx).pow(2) + (y - fy).pow(2)) as f64)
+                    .fold(f64::MAX, f64::min);
+                assert_eq!(distances[(y * width as i64 + x) as usize], expected, "({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hausdorff_distance() {
+        // 4x4: 첫 마스크는 (0,0), 두 번째 마스크는 (0,0)과 (3,3)
+        let mut first = vec![0u8; 16];
+        first[0] = 1;
+        let mut second = first.clone();
+        second[15] = 1;
+        assert_eq!(hausdorff_distance(4, 4, &first, &first), Some(0.0));
+        assert_eq!(hausdorff_distance(4, 4, &first, &second), Some(18f64.sqrt()));
+        assert_eq!(hausdorff_distance(4, 4, &first, &[0; 16]), None);
+    }
+}
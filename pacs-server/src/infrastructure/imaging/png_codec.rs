@@ -0,0 +1,124 @@
+//! # PNG 인코딩
+//!
+//! 이진 마스크를 8비트 그레이스케일 PNG(전경 255, 배경 0)로 인코딩하고,
+//! 저장된 마스크 PNG를 다시 이진 마스크 또는 라벨 값으로 디코딩합니다.
+
+use super::ImagingError;
+
+/// 이진 마스크(0/1, 행 우선)를 8비트 그레이스케일 PNG로 인코딩합니다.
+pub fn encode_binary_mask_png(width: u32, height: u32, pixels: &[u8]) -> Result<Vec<u8>, ImagingError> {
+    if pixels.len() != (width as usize) * (height as usize) {
+        return Err(ImagingError::EncodingError(format!(
+            "Mask has {} pixels, expected {}x{}",
+            pixels.len(),
+            width,
+            height
+        )));
+    }
+
+    let gray: Vec<u8> = pixels.iter().map(|p| if *p != 0 { 255 } else { 0 }).collect();
+    let mut out = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut out, width, height);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| ImagingError::EncodingError(e.to_string()))?;
+        writer
+            .write_image_data(&gray)
+            .map_err(|e| ImagingError::EncodingError(e.to_string()))?;
+    }
+    Ok(out)
+}
+
+/// 마스크 PNG를 이진 마스크(0/1, 행 우선)로 디코딩합니다.
+///
+/// 알파 채널을 제외한 값 중 하나라도 0이 아니면 전경으로 봅니다. 반환값은 `(너비, 높이, 픽셀)`입니다.
+pub fn decode_binary_mask_png(data: &[u8]) -> Result<(u32, u32, Vec<u8>), ImagingError> {
+    let (width, height, labels) = decode_label_mask_png(data)?;
+    Ok((width, height, labels.into_iter().map(|v| u8::from(v != 0)).collect()))
+}
+
+/// 마스크 PNG를 라벨 값(행 우선)으로 디코딩합니다.
+///
+/// 그레이스케일은 픽셀 값(16비트는 상위 8비트)을 라벨 값으로 보고, 컬러 PNG는 색상 값 중 하나라도
+/// 0이 아니면 1로 봅니다. 알파 채널은 무시하며 반환값은 `(너비, 높이, 라벨 값)`입니다.
+pub fn decode_label_mask_png(data: &[u8]) -> Result<(u32, u32, Vec<u8>), ImagingError> {
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder
+        .read_info()
+        .map_err(|e| ImagingError::InvalidData(format!("Invalid mask PNG: {}", e)))?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buf)
+        .map_err(|e| ImagingError::InvalidData(format!("Invalid mask PNG: {}", e)))?;
+
+    let (color_channels, samples) = match info.color_type {
+        png::ColorType::Grayscale => (1, 1),
+        png::ColorType::GrayscaleAlpha => (1, 2),
+        png::ColorType::Rgb => (3, 3),
+        png::ColorType::Rgba => (3, 4),
+        png::ColorType::Indexed => {
+            return Err(ImagingError::UnsupportedFormat("Indexed mask PNG was not expanded".to_string()));
+        }
+    };
+
+    let labels = buf[..info.buffer_size()]
+        .chunks_exact(info.line_size)
+        .flat_map(|line| line[..info.width as usize * samples].chunks_exact(samples))
+        .map(|pixel| match color_channels {
+            1 => pixel[0],
+            _ => u8::from(pixel[..color_channels].iter().any(|v| *v != 0)),
+        })
+        .collect();
+
+    Ok((info.width, info.height, labels))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_binary_mask_png_roundtrip() {
+        let encoded = encode_binary_mask_png(2, 2, &[1, 0, 0, 1]).unwrap();
+        assert_eq!(&encoded[..8], b"\x89PNG\r\n\x1a\n");
+
+        let decoder = png::Decoder::new(encoded.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf).unwrap();
+        assert_eq!((info.width, info.height), (2, 2));
+        assert_eq!(&buf[..info.buffer_size()], &[255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_decode_binary_mask_png_roundtrip() {
+        let encoded = encode_binary_mask_png(3, 2, &[1, 0, 0, 0, 1, 1]).unwrap();
+        let (width, height, pixels) = decode_binary_mask_png(&encoded).unwrap();
+        assert_eq!((width, height), (3, 2));
+        assert_eq!(pixels, vec![1, 0, 0, 0, 1, 1]);
+
+        assert!(decode_binary_mask_png(b"not a png").is_err());
+    }
+
+    #[test]
+    fn test_decode_label_mask_png_keeps_gray_values() {
+        let mut encoded = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut encoded, 2, 2);
+            encoder.set_color(png::ColorType::Grayscale);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.write_header().unwrap().write_image_data(&[0, 1, 2, 1]).unwrap();
+        }
+        assert_eq!(decode_label_mask_png(&encoded).unwrap(), (2, 2, vec![0, 1, 2, 1]));
+        assert_eq!(decode_binary_mask_png(&encoded).unwrap().2, vec![0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_encode_rejects_size_mismatch() {
+        assert!(encode_binary_mask_png(3, 3, &[0; 4]).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+//! # COCO RLE 변환
+//!
+//! 이진 마스크를 COCO의 비압축 RLE(열 우선, 배경 길이부터 시작)로 변환하고,
+//! 마스크의 경계 상자와 라벨 값별 영역 통계를 계산합니다.
+
+/// 이진 마스크(0/1, 행 우선)를 열 우선 순서의 run-length 목록으로 변환합니다.
+///
+/// COCO 규약에 따라 첫 값은 항상 배경 길이이며, 전경으로 시작하면 0이 먼저 옵니다.
+pub fn encode_column_major_rle(width: u32, height: u32, pixels: &[u8]) -> Vec<u32> {
+    let (width, height) = (width as usize, height as usize);
+    let mut counts = Vec::new();
+    let mut current = 0u8;
+    let mut run = 0u32;
+
+    for x in 0..width {
+        for y in 0..height {
+            let value = u8::from(pixels.get(y * width + x).is_some_and(|p| *p != 0));
+            if value != current {
+                counts.push(run);
+                current = value;
+                run = 0;
+            }
+            run += 1;
+        }
+    }
+    counts.push(run);
+    counts
+}
+
+/// 전경 픽셀의 경계 상자 `[x, y, width, height]`와 전경 픽셀 수를 반환합니다.
+///
+/// 전경 픽셀이 없으면 `None`을 반환합니다.
+pub fn mask_bounding_box(width: u32, height: u32, pixels: &[u8]) -> Option<([f64; 4], u64)> {
+    let width = width as usize;
+    let mut bounds: Option<(usize, usize, usize, usize)> = None;
+    let mut area = 0u64;
+
+    for (index, _) in pixels.iter().enumerate().take(width * height as usize).filter(|(_, p)| **p != 0) {
+        let (x, y) = (index % width, index / width);
+        area += 1;
+        bounds = Some(match bounds {
+            None => (x, y, x, y),
+            Some((min_x, min_y, max_x, max_y)) => (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y)),
+        });
+    }
+
+    bounds.map(|(min_x, min_y, max_x, max_y)| {
+        (
+            [
+                min_x as f64,
+                min_y as f64,
+                (max_x - min_x + 1) as f64,
+                (max_y - min_y + 1) as f64,
+            ],
+            area,
+        )
+    })
+}
+
+/// 픽셀 좌표 경계 (min_x, min_y, max_x, max_y)
+type Bounds = (usize, usize, usize, usize);
+
+/// 라벨 값별 영역 (전경 픽셀 수와 경계 상자)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelRegion {
+    pub label_value: u8,
+    pub voxel_count: u64,
+    /// `[x, y, width, height]` (픽셀)
+    pub bounding_box: [u32; 4],
+}
+
+/// 라벨 마스크(행 우선)에서 0이 아닌 라벨 값별 픽셀 수와 경계 상자를 라벨 값 순서로 반환합니다.
+pub fn label_regions(width: u32, height: u32, labels: &[u8]) -> Vec<LabelRegion> {
+    let width = width as usize;
+    // 라벨 값별 ((min_x, min_y, max_x, max_y), count)
+    let mut regions: [Option<(Bounds, u64)>; 256] = [None; 256];
+
+    for (index, label) in labels.iter().enumerate().take(width * height as usize).filter(|(_, l)| **l != 0) {
+        let (x, y) = (index % width, index / width);
+        let region = &mut regions[*label as usize];
+        *region = Some(match *region {
+            None => ((x, y, x, y), 1),
+            Some(((min_x, min_y, max_x, max_y), count)) => {
+                ((min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y)), count + 1)
+            }
+        });
+    }
+
+    regions
+        .iter()
+        .enumerate()
+        .filter_map(|(label_value, region)| {
+            region.map(|((min_x, min_y, max_x, max_y), voxel_count)| LabelRegion {
+                label_value: label_value as u8,
+                voxel_count,
+                bounding_box: [
+                    min_x as u32,
+                    min_y as u32,
+                    (max_x - min_x + 1) as u32,
+                    (max_y - min_y + 1) as u32,
+                ],
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_column_major_rle() {
+        // 3x2 마스크 (행 우선)
+        // 1 0 0
+        // 1 1 0
+        let pixels = [1, 0, 0, 1, 1, 0];
+        // 열 우선: 1 1 | 0 1 | 0 0
+        assert_eq!(encode_column_major_rle(3, 2, &pixels), vec![0, 2, 1, 1, 2]);
+        assert_eq!(encode_column_major_rle(2, 2, &[0, 0, 0, 0]), vec![4]);
+    }
+
+    #[test]
+    fn test_mask_bounding_box() {
+        let pixels = [0, 0, 0, 0, 1, 1, 0, 1, 0];
+        assert_eq!(mask_bounding_box(3, 3, &pixels), Some(([1.0, 1.0, 2.0, 2.0], 3)));
+        assert_eq!(mask_bounding_box(3, 3, &[0; 9]), None);
+    }
+
+    #[test]
+    fn test_label_regions() {
+        // 4x3 라벨 마스크
+        // 1 1 0 0
+        // 0 0 2 0
+        // 0 0 2 2
+        let labels = [1, 1, 0, 0, 0, 0, 2, 0, 0, 0, 2, 2];
+        assert_eq!(
+            label_regions(4, 3, &labels),
+            vec![
+                LabelRegion { label_value: 1, voxel_count: 2, bounding_box: [0, 0, 2, 1] },
+                LabelRegion { label_value: 2, voxel_count: 3, bounding_box: [2, 1, 2, 2] },
+            ]
+        );
+        assert!(label_regions(2, 2, &[0; 4]).is_empty());
+    }
+}
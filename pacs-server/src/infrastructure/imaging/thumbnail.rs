@@ -0,0 +1,171 @@
+//! # 마스크 미리보기
+//!
+//! 라벨 마스크를 목록용 썸네일(8비트 그레이스케일), 영상 위에 겹쳐 그리는 컬러 미리보기(RGBA, 배경 투명),
+//! 마스크 그룹의 썸네일을 격자로 모은 contact sheet로 렌더링합니다.
+//! 축소할 때는 블록 안의 가장 큰 라벨 값을 취하므로 얇은 구조도 사라지지 않습니다.
+
+use super::ImagingError;
+
+/// 컬러 미리보기 팔레트 (라벨 값 1부터 순환)
+const PREVIEW_PALETTE: [[u8; 3]; 8] = [
+    [230, 25, 75],
+    [60, 180, 75],
+    [255, 225, 25],
+    [0, 130, 200],
+    [245, 130, 48],
+    [145, 30, 180],
+    [70, 240, 240],
+    [240, 50, 230],
+];
+
+/// 컬러 미리보기의 전경 불투명도
+const PREVIEW_ALPHA: u8 = 160;
+
+/// contact sheet의 타일 사이 간격과 배경 값
+const CONTACT_SHEET_GAP: u32 = 2;
+const CONTACT_SHEET_BACKGROUND: u8 = 48;
+
+/// 라벨 값의 미리보기 색상 (같은 라벨 값은 어느 마스크에서나 같은 색)
+pub fn preview_color(label_value: u8) -> [u8; 3] {
+    PREVIEW_PALETTE[(label_value as usize + PREVIEW_PALETTE.len() - 1) % PREVIEW_PALETTE.len()]
+}
+
+/// 긴 변이 `max_size`를 넘지 않도록 축소한 라벨 마스크 `(너비, 높이, 라벨 값)` (이미 작으면 그대로)
+pub fn downscale_labels(width: u32, height: u32, labels: &[u8], max_size: u32) -> (u32, u32, Vec<u8>) {
+    let longest = width.max(height);
+    if longest <= max_size || max_size == 0 {
+        return (width, height, labels.to_vec());
+    }
+    let out_width = ((width as u64 * max_size as u64) / longest as u64).max(1) as u32;
+    let out_height = ((height as u64 * max_size as u64) / longest as u64).max(1) as u32;
+
+    let mut out = vec![0u8; (out_width * out_height) as usize];
+    for oy in 0..out_height {
+        let y_start = (oy as u64 * height as u64 / out_height as u64) as usize;
+        let y_end = (((oy + 1) as u64 * height as u64).div_ceil(out_height as u64) as usize).max(y_start + 1);
+        for ox in 0..out_width {
+            let x_start = (ox as u64 * width as u64 / out_width as u64) as usize;
+            let x_end = (((ox + 1) as u64 * width as u64).div_ceil(out_width as u64) as usize).max(x_start + 1);
+            let mut value = 0u8;
+            for y in y_start..y_end.min(height as usize) {
+                let row = y * width as usize;
+                for x in x_start..x_end.min(width as usize) {
+                    value = value.max(labels.get(row + x).copied().unwrap_or(0));
+                }
+            }
+            out[(oy * out_width + ox) as usize] = value;
+        }
+    }
+    (out_width, out_height, out)
+}
+
+/// 썸네일 PNG (전경 255, 배경 0의 8비트 그레이스케일)
+pub fn encode_thumbnail_png(width: u32, height: u32, labels: &[u8], max_size: u32) -> Result<Vec<u8>, ImagingError> {
+    let (width, height, labels) = downscale_labels(width, height, labels, max_size);
+    let gray: Vec<u8> = labels.iter().map(|v| if *v != 0 { 255 } else { 0 }).collect();
+    encode_png(width, height, png::ColorType::Grayscale, &gray)
+}
+
+/// 컬러 미리보기 PNG (라벨 값별 색상, 배경 투명)
+pub fn encode_preview_png(width: u32, height: u32, labels: &[u8], max_size: u32) -> Result<Vec<u8>, ImagingError> {
+    let (width, height, labels) = downscale_labels(width, height, labels, max_size);
+    let mut rgba = Vec::with_capacity(labels.len() * 4);
+    for label in labels {
+        match label {
+            0 => rgba.extend_from_slice(&[0, 0, 0, 0]),
+            value => {
+                rgba.extend_from_slice(&preview_color(value));
+                rgba.push(PREVIEW_ALPHA);
+            }
+        }
+    }
+    encode_png(width, height, png::ColorType::Rgba, &rgba)
+}
+
+/// 썸네일 `(너비, 높이, 그레이스케일)` 목록을 `tile_size` 칸의 격자로 모은 PNG
+///
+/// 열 수는 타일 수의 제곱근(올림)이며, 각 타일은 칸의 가운데에 놓입니다.
+pub fn encode_contact_sheet_png(tiles: &[(u32, u32, Vec<u8>)], tile_size: u32) -> Result<Vec<u8>, ImagingError> {
+    if tiles.is_empty() || tile_size == 0 {
+        return Err(ImagingError::EncodingError("Contact sheet needs at least one tile".to_string()));
+    }
+    let columns = (tiles.len() as f64).sqrt().ceil() as u32;
+    let rows = (tiles.len() as u32).div_ceil(columns);
+    let cell = tile_size + CONTACT_SHEET_GAP;
+    let (width, height) = (columns * cell + CONTACT_SHEET_GAP, rows * cell + CONTACT_SHEET_GAP);
+
+    let mut sheet = vec![CONTACT_SHEET_BACKGROUND; (width * height) as usize];
+    for (index, (tile_width, tile_height, gray)) in tiles.iter().enumerate() {
+        let (tile_width, tile_height, gray) = downscale_labels(*tile_width, *tile_height, gray, tile_size);
+        let column = index as u32 % columns;
+        let row = index as u32 / columns;
+        let left = CONTACT_SHEET_GAP + column * cell + (tile_size - tile_width) / 2;
+        let top = CONTACT_SHEET_GAP + row * cell + (tile_size - tile_height) / 2;
+        for y in 0..tile_height {
+            let source = (y * tile_width) as usize;
+            let target = ((top + y) * width + left) as usize;
+            sheet[target..target + tile_width as usize]
+                .copy_from_slice(&gray[source..source + tile_width as usize]);
+        }
+    }
+    encode_png(width, height, png::ColorType::Grayscale, &sheet)
+}
+
+fn encode_png(width: u32, height: u32, color: png::ColorType, data: &[u8]) -> Result<Vec<u8>, ImagingError> {
+    let mut out = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut out, width, height);
+        encoder.set_color(color);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| ImagingError::EncodingError(e.to_string()))?;
+        writer
+            .write_image_data(data)
+            .map_err(|e| ImagingError::EncodingError(e.to_string()))?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::imaging::decode_label_mask_png;
+
+    #[test]
+    fn test_downscale_keeps_thin_structures() {
+        // 4x2 마스크의 한 픽셀짜리 라벨 2는 2x1로 줄여도 남음
+        let labels = [0, 0, 0, 0, 0, 2, 0, 1];
+        assert_eq!(downscale_labels(4, 2, &labels, 2), (2, 1, vec![2, 1]));
+        assert_eq!(downscale_labels(4, 2, &labels, 8), (4, 2, labels.to_vec()));
+    }
+
+    #[test]
+    fn test_thumbnail_and_preview_pngs() {
+        let labels = vec![0, 1, 2, 0];
+        let thumbnail = encode_thumbnail_png(2, 2, &labels, 64).unwrap();
+        assert_eq!(decode_label_mask_png(&thumbnail).unwrap(), (2, 2, vec![0, 255, 255, 0]));
+
+        let preview = encode_preview_png(2, 2, &labels, 64).unwrap();
+        let decoder = png::Decoder::new(preview.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf).unwrap();
+        assert_eq!(info.color_type, png::ColorType::Rgba);
+        assert_eq!(&buf[0..4], &[0, 0, 0, 0]);
+        assert_eq!(&buf[4..8], &[230, 25, 75, PREVIEW_ALPHA]);
+        assert_eq!(&buf[8..11], &preview_color(2));
+    }
+
+    #[test]
+    fn test_contact_sheet_grid() {
+        let tile = (2, 2, vec![255; 4]);
+        let sheet = encode_contact_sheet_png(&[tile.clone(), tile.clone(), tile], 2).unwrap();
+        // 3개 타일은 2x2 격자: 2 * (2 + 2) + 2
+        let (width, height, pixels) = decode_label_mask_png(&sheet).unwrap();
+        assert_eq!((width, height), (10, 10));
+        assert_eq!(pixels[(2 * width + 2) as usize], 255);
+        assert_eq!(pixels[(6 * width + 6) as usize], CONTACT_SHEET_BACKGROUND);
+        assert!(encode_contact_sheet_png(&[], 2).is_err());
+    }
+}
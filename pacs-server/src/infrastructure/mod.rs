@@ -5,3 +5,5 @@ pub mod external;
 pub mod config;
 pub mod auth;
 pub mod middleware;
+pub mod imaging;
+pub mod archive;
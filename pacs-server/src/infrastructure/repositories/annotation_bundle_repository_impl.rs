@@ -0,0 +1,250 @@
+use std::collections::{HashMap, HashSet};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::domain::entities::{BundleAnnotation, BundleHistory, BundleImportPlan, BundleMaskGroup};
+use crate::domain::repositories::AnnotationBundleRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+#[derive(Clone)]
+pub struct AnnotationBundleRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AnnotationBundleRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AnnotationBundleRepository for AnnotationBundleRepositoryImpl {
+    async fn find_project_name(&self, project_id: i32) -> Result<Option<String>, ServiceError> {
+        sqlx::query_scalar::<_, String>("SELECT name FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("get project name", e))
+    }
+
+    async fn find_project_id_by_name(&self, name: &str) -> Result<Option<i32>, ServiceError> {
+        sqlx::query_scalar::<_, i32>("SELECT id FROM security_project WHERE name = $1")
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("find project by name", e))
+    }
+
+    async fn has_project_access(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)
+                 OR EXISTS(
+                     SELECT 1
+                     FROM security_user_project up
+                     JOIN security_role r ON r.id = up.role_id
+                     WHERE up.user_id = $1 AND r.name IN ('SUPER_ADMIN', 'ADMIN')
+                 )"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project access", e))
+    }
+
+    async fn export_annotations(&self, project_id: i32, viewer_id: i32, after_id: i32, limit: i64) -> Result<Vec<BundleAnnotation>, ServiceError> {
+        sqlx::query_as::<_, BundleAnnotation>(
+            "SELECT a.id AS source_id, a.external_id, u.username, a.study_uid, a.series_uid, a.instance_uid,
+                    a.tool_name, a.tool_version, a.viewer_software, a.description, a.data, a.measurement_values,
+                    a.is_shared, a.review_status, a.created_at, a.updated_at
+             FROM annotation_annotation a
+             JOIN security_user u ON u.id = a.user_id
+             WHERE a.project_id = $1 AND a.id > $2 AND a.deleted_at IS NULL
+               AND (a.is_shared OR a.user_id = $4)
+             ORDER BY a.id
+             LIMIT $3"
+        )
+        .bind(project_id)
+        .bind(after_id)
+        .bind(limit)
+        .bind(viewer_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("export annotations", e))
+    }
+
+    async fn export_history(&self, annotation_ids: &[i32]) -> Result<Vec<BundleHistory>, ServiceError> {
+        sqlx::query_as::<_, BundleHistory>(
+            "SELECT a.external_id AS annotation_external_id, u.username, h.action,
+                    h.data_before, h.data_after, h.action_at
+             FROM annotation_annotation_history h
+             JOIN annotation_annotation a ON a.id = h.annotation_id
+             JOIN security_user u ON u.id = h.user_id
+             WHERE h.annotation_id = ANY($1)
+             ORDER BY h.annotation_id, h.action_at, h.id"
+        )
+        .bind(annotation_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("export annotation history", e))
+    }
+
+    async fn export_mask_groups(&self, annotation_ids: &[i32]) -> Result<Vec<BundleMaskGroup>, ServiceError> {
+        sqlx::query_as::<_, BundleMaskGroup>(
+            "SELECT g.external_id, a.external_id AS annotation_external_id, g.group_name, g.model_name,
+                    g.version, g.modality, g.slice_count, g.mask_type, g.description,
+                    u.username AS created_by, g.created_at, g.updated_at
+             FROM annotation_mask_group g
+             JOIN annotation_annotation a ON a.id = g.annotation_id
+             LEFT JOIN security_user u ON u.id = g.created_by
+             WHERE g.annotation_id = ANY($1) AND g.deleted_at IS NULL
+             ORDER BY g.annotation_id, g.id"
+        )
+        .bind(annotation_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("export mask groups", e))
+    }
+
+    async fn find_user_ids(&self, usernames: &[String]) -> Result<HashMap<String, i32>, ServiceError> {
+        let rows = sqlx::query_as::<_, (String, i32)>(
+            "SELECT username, id FROM security_user WHERE username = ANY($1)"
+        )
+        .bind(usernames)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("find users by username", e))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn find_existing_annotations(&self, external_ids: &[Uuid]) -> Result<HashMap<Uuid, i32>, ServiceError> {
+        let rows = sqlx::query_as::<_, (Uuid, i32)>(
+            "SELECT external_id, project_id FROM annotation_annotation WHERE external_id = ANY($1)"
+        )
+        .bind(external_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("find existing annotations", e))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn find_existing_mask_groups(&self, external_ids: &[Uuid]) -> Result<HashSet<Uuid>, ServiceError> {
+        let rows = sqlx::query_scalar::<_, Uuid>(
+            "SELECT external_id FROM annotation_mask_group WHERE external_id = ANY($1)"
+        )
+        .bind(external_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("find existing mask groups", e))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn apply_import(&self, plan: &BundleImportPlan) -> Result<(), ServiceError> {
+        let map_err = |e: sqlx::Error| database_error("import annotation bundle", e);
+
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+        let mut annotation_ids: HashMap<Uuid, i32> = HashMap::new();
+
+        for annotation in &plan.annotations {
+            let record = &annotation.record;
+            // 동시에 같은 번들을 임포트하는 경우에도 중복 생성되지 않도록 external_id 충돌은 무시
+            let id = sqlx::query_scalar::<_, i32>(
+                "INSERT INTO annotation_annotation
+                    (external_id, project_id, user_id, study_uid, series_uid, instance_uid, tool_name, tool_version,
+                     viewer_software, description, data, measurement_values, is_shared, review_status,
+                     created_at, updated_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+                 ON CONFLICT (external_id) DO NOTHING
+                 RETURNING id"
+            )
+            .bind(record.external_id)
+            .bind(plan.project_id)
+            .bind(annotation.user_id)
+            .bind(&record.study_uid)
+            .bind(&record.series_uid)
+            .bind(&record.instance_uid)
+            .bind(&record.tool_name)
+            .bind(&record.tool_version)
+            .bind(&record.viewer_software)
+            .bind(&record.description)
+            .bind(&record.data)
+            .bind(&record.measurement_values)
+            .bind(record.is_shared)
+            .bind(record.review_status)
+            .bind(record.created_at)
+            .bind(record.updated_at)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(map_err)?;
+
+            if let Some(id) = id {
+                annotation_ids.insert(record.external_id, id);
+            }
+        }
+
+        for history in &plan.history {
+            let record = &history.record;
+            let Some(&annotation_id) = annotation_ids.get(&record.annotation_external_id) else {
+                continue;
+            };
+            sqlx::query(
+                "INSERT INTO annotation_annotation_history (annotation_id, user_id, action, data_before, data_after, action_at)
+                 VALUES ($1, $2, $3, $4, $5, $6)"
+            )
+            .bind(annotation_id)
+            .bind(history.user_id)
+            .bind(&record.action)
+            .bind(&record.data_before)
+            .bind(&record.data_after)
+            .bind(record.action_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_err)?;
+        }
+
+        for mask_group in &plan.mask_groups {
+            let record = &mask_group.record;
+            let annotation_id = match annotation_ids.get(&record.annotation_external_id) {
+                Some(&id) => Some(id),
+                None => sqlx::query_scalar::<_, i32>("SELECT id FROM annotation_annotation WHERE external_id = $1")
+                    .bind(record.annotation_external_id)
+                    .fetch_optional(&mut *tx)
+                    .await
+                    .map_err(map_err)?,
+            };
+            let Some(annotation_id) = annotation_id else {
+                continue;
+            };
+
+            sqlx::query(
+                "INSERT INTO annotation_mask_group
+                    (external_id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type,
+                     description, created_by, created_at, updated_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                 ON CONFLICT (external_id) DO NOTHING"
+            )
+            .bind(record.external_id)
+            .bind(annotation_id)
+            .bind(&record.group_name)
+            .bind(&record.model_name)
+            .bind(&record.version)
+            .bind(&record.modality)
+            .bind(record.slice_count)
+            .bind(&record.mask_type)
+            .bind(&record.description)
+            .bind(mask_group.created_by)
+            .bind(record.created_at)
+            .bind(record.updated_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_err)?;
+        }
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::annotation_propagation::{AnnotationPropagation, AnnotationPropagationPlan, LesionTimepoint};
+use crate::domain::entities::project_data::{ProjectDataSeries, ProjectDataStudy};
+use crate::domain::entities::realtime::{RealtimeEvent, RealtimeEventType};
+use crate::domain::entities::Annotation;
+use crate::domain::repositories::AnnotationPropagationRepository;
+use crate::domain::services::{measurement_computation, realtime_events};
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+const ANNOTATION_COLUMNS: &str = "id, project_id, user_id, study_uid, series_uid, instance_uid,
+    tool_name, tool_version, data, is_shared, created_at, updated_at,
+    viewer_software, description, measurement_values,
+    review_status, reviewer_id, reviewed_at";
+
+const PROPAGATION_COLUMNS: &str = "annotation_id, source_annotation_id, root_annotation_id, source_study_uid, target_study_uid,
+    series_match, propagated_by, created_at";
+
+#[derive(Clone)]
+pub struct AnnotationPropagationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AnnotationPropagationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AnnotationPropagationRepository for AnnotationPropagationRepositoryImpl {
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project membership", e))
+    }
+
+    async fn find_study(&self, project_id: i32, study_uid: &str) -> Result<Option<ProjectDataStudy>, ServiceError> {
+        sqlx::query_as::<_, ProjectDataStudy>(
+            "SELECT id, project_id, study_uid, study_description, patient_id, patient_name, patient_birth_date, study_date, created_at, updated_at
+             FROM project_data_study
+             WHERE project_id = $1 AND study_uid = $2"
+        )
+        .bind(project_id)
+        .bind(study_uid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get project study", e))
+    }
+
+    async fn list_series(&self, study_id: i32) -> Result<Vec<ProjectDataSeries>, ServiceError> {
+        sqlx::query_as::<_, ProjectDataSeries>(
+            "SELECT id, study_id, series_uid, series_description, modality, series_number, created_at
+             FROM project_data_series
+             WHERE study_id = $1
+             ORDER BY series_number NULLS LAST, series_uid"
+        )
+        .bind(study_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list study series", e))
+    }
+
+    async fn find_annotations(&self, project_id: i32, annotation_ids: &[i32]) -> Result<Vec<Annotation>, ServiceError> {
+        sqlx::query_as::<_, Annotation>(&format!(
+            "SELECT {} FROM annotation_annotation
+             WHERE project_id = $1 AND id = ANY($2) AND deleted_at IS NULL
+             ORDER BY id",
+            ANNOTATION_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(annotation_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get annotations", e))
+    }
+
+    async fn find_propagated_sources(&self, source_annotation_ids: &[i32], target_study_uid: &str) -> Result<Vec<i32>, ServiceError> {
+        sqlx::query_scalar::<_, i32>(
+            "SELECT source_annotation_id FROM annotation_propagation
+             WHERE source_annotation_id = ANY($1) AND target_study_uid = $2"
+        )
+        .bind(source_annotation_ids)
+        .bind(target_study_uid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get existing propagations", e))
+    }
+
+    async fn find_single_instances(&self, project_id: i32, series_uids: &[String]) -> Result<Vec<(String, String)>, ServiceError> {
+        sqlx::query_as::<_, (String, String)>(
+            "SELECT series_uid, MIN(instance_uid)
+             FROM dicom_instance_metadata
+             WHERE project_id = $1 AND series_uid = ANY($2)
+             GROUP BY series_uid
+             HAVING COUNT(*) = 1"
+        )
+        .bind(project_id)
+        .bind(series_uids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get single-instance series", e))
+    }
+
+    async fn propagate(
+        &self,
+        target_study_uid: &str,
+        plans: &[AnnotationPropagationPlan],
+        propagated_by: i32,
+    ) -> Result<Vec<(Annotation, AnnotationPropagation)>, ServiceError> {
+        let mut tx = self.pool.begin().await.map_err(|e| database_error("begin transaction", e))?;
+
+        let mut propagated = Vec::with_capacity(plans.len());
+        for plan in plans {
+            let source = &plan.source;
+            // 후속 시점의 병변 크기는 다르므로 클라이언트 측정값은 복사하지 않음
+            let annotation = sqlx::query_as::<_, Annotation>(&format!(
+                "INSERT INTO annotation_annotation (project_id, user_id, study_uid, series_uid, instance_uid,
+                                                   tool_name, tool_version, data, is_shared, viewer_software, description)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                 RETURNING {}",
+                ANNOTATION_COLUMNS
+            ))
+            .bind(source.project_id)
+            .bind(propagated_by)
+            .bind(target_study_uid)
+            .bind(&plan.series_uid)
+            .bind(&plan.instance_uid)
+            .bind(&source.tool_name)
+            .bind(&source.tool_version)
+            .bind(&source.data)
+            .bind(source.is_shared)
+            .bind(&source.viewer_software)
+            .bind(&source.description)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| database_error("create propagated annotation", e))?;
+
+            sqlx::query(
+                "INSERT INTO annotation_annotation_history (annotation_id, user_id, action, data_before, data_after)
+                 VALUES ($1, $2, 'propagate', NULL, $3)"
+            )
+            .bind(annotation.id)
+            .bind(propagated_by)
+            .bind(&annotation.data)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| database_error("create annotation history", e))?;
+
+            let propagation = sqlx::query_as::<_, AnnotationPropagation>(&format!(
+                "INSERT INTO annotation_propagation (annotation_id, source_annotation_id, root_annotation_id,
+                                                    source_study_uid, target_study_uid, series_match, propagated_by)
+                 SELECT $1, $2, COALESCE((SELECT root_annotation_id FROM annotation_propagation WHERE annotation_id = $2), $2),
+                        $3, $4, $5, $6
+                 ON CONFLICT (source_annotation_id, target_study_uid) DO NOTHING
+                 RETURNING {}",
+                PROPAGATION_COLUMNS
+            ))
+            .bind(annotation.id)
+            .bind(source.id)
+            .bind(&source.study_uid)
+            .bind(target_study_uid)
+            .bind(plan.series_match.as_str())
+            .bind(propagated_by)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| database_error("link propagated annotation", e))?
+            .ok_or_else(|| ServiceError::AlreadyExists(format!(
+                "Annotation {} has already been propagated to study {}",
+                source.id, target_study_uid
+            )))?;
+
+            let event = RealtimeEvent::new(
+                RealtimeEventType::AnnotationCreated,
+                annotation.project_id,
+                Some(annotation.study_uid.clone()),
+                annotation.id,
+                Some(propagated_by),
+            )
+            .with_annotation_visibility(Some(annotation.user_id), annotation.is_shared);
+            realtime_events::publish(&mut *tx, &event)
+                .await
+                .map_err(|e| database_error("publish realtime event", e))?;
+
+            propagated.push((annotation, propagation));
+        }
+
+        tx.commit().await.map_err(|e| database_error("commit transaction", e))?;
+
+        for (annotation, _) in &propagated {
+            measurement_computation::refresh_best_effort(&self.pool, annotation).await;
+        }
+        Ok(propagated)
+    }
+
+    async fn list_lesion_timepoints(
+        &self,
+        project_id: i32,
+        patient_id: &str,
+        lesion_id: Option<i32>,
+    ) -> Result<Vec<LesionTimepoint>, ServiceError> {
+        sqlx::query_as::<_, LesionTimepoint>(
+            "SELECT COALESCE(p.root_annotation_id, a.id) AS lesion_id, a.id AS annotation_id,
+                    a.study_uid, s.study_date, a.series_uid, a.instance_uid, a.tool_name, a.description,
+                    a.review_status, p.source_annotation_id, a.measurement_values,
+                    m.measurements AS computed_measurements, a.created_at
+             FROM annotation_annotation a
+             JOIN project_data_study s ON s.project_id = a.project_id AND s.study_uid = a.study_uid
+             LEFT JOIN annotation_propagation p ON p.annotation_id = a.id
+             LEFT JOIN annotation_measurement m ON m.annotation_id = a.id
+             WHERE a.project_id = $1 AND s.patient_id = $2 AND a.deleted_at IS NULL
+               AND ($3::INTEGER IS NULL OR COALESCE(p.root_annotation_id, a.id) = $3)
+             ORDER BY lesion_id, s.study_date NULLS LAST, a.created_at, a.id"
+        )
+        .bind(project_id)
+        .bind(patient_id)
+        .bind(lesion_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list lesion timepoints", e))
+    }
+}
@@ -1,7 +1,11 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
-use crate::domain::entities::{Annotation, AnnotationHistory, NewAnnotation};
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use crate::domain::entities::{
+    Annotation, AnnotationHistory, AnnotationReviewComment, AnnotationReviewStatus, AnnotationSearchCriteria,
+    AnnotationSortField, JsonPathPredicate, JsonPredicateOp, NewAnnotation, SortDirection, WebhookEventType,
+};
 use crate::domain::repositories::AnnotationRepository;
+use crate::domain::services::webhook_outbox;
 
 #[derive(Clone)]
 pub struct AnnotationRepositoryImpl {
@@ -12,6 +16,59 @@ impl AnnotationRepositoryImpl {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// JSONB 경로 조건을 WHERE 절에 추가
+    ///
+    /// 범위 비교는 같은 JSON 타입끼리만 수행하여 숫자/문자열이 섞인 경우에도 오류 없이 평가됩니다.
+    /// `eq` / `contains`는 컬럼 전체에 대한 `@>` 조건을 함께 걸어 GIN 인덱스로 후보를 좁힌 뒤 경로 값을 확인합니다.
+    fn push_json_predicate(qb: &mut QueryBuilder<'_, Postgres>, column: &str, predicate: &JsonPathPredicate) {
+        let path = predicate.path.clone();
+        let value = predicate.value.clone().unwrap_or(serde_json::Value::Null);
+
+        qb.push(" AND ");
+        let documents = predicate.containment_documents();
+        if !documents.is_empty() {
+            qb.push("(");
+            for (i, document) in documents.into_iter().enumerate() {
+                if i > 0 {
+                    qb.push(" OR ");
+                }
+                qb.push(format!("{} @> ", column)).push_bind(document);
+            }
+            qb.push(") AND ");
+        }
+        match predicate.op {
+            JsonPredicateOp::Exists => {
+                qb.push(format!("({} #> ", column)).push_bind(path).push(") IS NOT NULL");
+            }
+            JsonPredicateOp::Eq => {
+                qb.push(format!("({} #> ", column)).push_bind(path).push(") = ").push_bind(value);
+            }
+            JsonPredicateOp::Ne => {
+                qb.push(format!("({} #> ", column)).push_bind(path).push(") IS DISTINCT FROM ").push_bind(value);
+            }
+            JsonPredicateOp::Contains => {
+                qb.push(format!("({} #> ", column)).push_bind(path).push(") @> ").push_bind(value);
+            }
+            JsonPredicateOp::Gt | JsonPredicateOp::Gte | JsonPredicateOp::Lt | JsonPredicateOp::Lte => {
+                let operator = match predicate.op {
+                    JsonPredicateOp::Gt => ">",
+                    JsonPredicateOp::Gte => ">=",
+                    JsonPredicateOp::Lt => "<",
+                    _ => "<=",
+                };
+                qb.push(format!("(jsonb_typeof({} #> ", column))
+                    .push_bind(path.clone())
+                    .push(") = jsonb_typeof(")
+                    .push_bind(value.clone())
+                    .push(format!(") AND ({} #> ", column))
+                    .push_bind(path)
+                    .push(format!(") {} ", operator))
+                    .push_bind(value)
+                    .push(")");
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -20,9 +77,10 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
              FROM annotation_annotation
-             WHERE id = $1"
+             WHERE id = $1 AND deleted_at IS NULL"
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -33,9 +91,10 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
              FROM annotation_annotation
-             WHERE project_id = $1
+             WHERE project_id = $1 AND deleted_at IS NULL
              ORDER BY created_at DESC"
         )
         .bind(project_id)
@@ -47,9 +106,10 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
              FROM annotation_annotation
-             WHERE user_id = $1
+             WHERE user_id = $1 AND deleted_at IS NULL
              ORDER BY created_at DESC"
         )
         .bind(user_id)
@@ -61,9 +121,10 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
              FROM annotation_annotation
-             WHERE study_uid = $1
+             WHERE study_uid = $1 AND deleted_at IS NULL
              ORDER BY created_at DESC"
         )
         .bind(study_uid)
@@ -75,9 +136,10 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
              FROM annotation_annotation
-             WHERE series_uid = $1
+             WHERE series_uid = $1 AND deleted_at IS NULL
              ORDER BY created_at DESC"
         )
         .bind(series_uid)
@@ -89,9 +151,10 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
              FROM annotation_annotation
-             WHERE instance_uid = $1
+             WHERE instance_uid = $1 AND deleted_at IS NULL
              ORDER BY created_at DESC"
         )
         .bind(instance_uid)
@@ -103,9 +166,10 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
              FROM annotation_annotation
-             WHERE project_id = $1 AND study_uid = $2
+             WHERE project_id = $1 AND deleted_at IS NULL AND study_uid = $2
              ORDER BY created_at DESC"
         )
         .bind(project_id)
@@ -118,9 +182,10 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
              FROM annotation_annotation
-             WHERE project_id = $1 AND is_shared = true
+             WHERE project_id = $1 AND deleted_at IS NULL AND is_shared = true
              ORDER BY created_at DESC"
         )
         .bind(project_id)
@@ -138,7 +203,8 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
              VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
              RETURNING id, project_id, user_id, study_uid, series_uid, instance_uid, 
                        tool_name, tool_version, data, is_shared, created_at, updated_at,
-                       viewer_software, description, measurement_values"
+                       viewer_software, description, measurement_values,
+                       review_status, reviewer_id, reviewed_at"
         )
         .bind(new_annotation.project_id)
         .bind(new_annotation.user_id)
@@ -180,8 +246,9 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         let old_annotation = sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
-             FROM annotation_annotation WHERE id = $1"
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
+             FROM annotation_annotation WHERE id = $1 AND deleted_at IS NULL"
         )
         .bind(id)
         .fetch_optional(&mut *tx)
@@ -194,10 +261,11 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         let updated_annotation = sqlx::query_as::<_, Annotation>(
             "UPDATE annotation_annotation 
              SET data = $2, is_shared = $3, updated_at = CURRENT_TIMESTAMP
-             WHERE id = $1
+             WHERE id = $1 AND deleted_at IS NULL
              RETURNING id, project_id, user_id, study_uid, series_uid, instance_uid, 
                        tool_name, tool_version, data, is_shared, created_at, updated_at,
-                       viewer_software, description, measurement_values"
+                       viewer_software, description, measurement_values,
+                       review_status, reviewer_id, reviewed_at"
         )
         .bind(id)
         .bind(data)
@@ -232,8 +300,9 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         let old_annotation = sqlx::query_as::<_, Annotation>(
             "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
                     tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
-             FROM annotation_annotation WHERE id = $1"
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
+             FROM annotation_annotation WHERE id = $1 AND deleted_at IS NULL"
         )
         .bind(id)
         .fetch_optional(&mut *tx)
@@ -246,10 +315,11 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         let updated_annotation = sqlx::query_as::<_, Annotation>(
             "UPDATE annotation_annotation 
              SET data = $2, is_shared = $3, measurement_values = $4, updated_at = CURRENT_TIMESTAMP
-             WHERE id = $1
+             WHERE id = $1 AND deleted_at IS NULL
              RETURNING id, project_id, user_id, study_uid, series_uid, instance_uid, 
                        tool_name, tool_version, data, is_shared, created_at, updated_at,
-                       viewer_software, description, measurement_values"
+                       viewer_software, description, measurement_values,
+                       review_status, reviewer_id, reviewed_at"
         )
         .bind(id)
         .bind(data)
@@ -278,47 +348,56 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         Ok(updated_annotation)
     }
 
-    async fn delete(&self, id: i32) -> Result<bool, sqlx::Error> {
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<bool, sqlx::Error> {
         let mut tx = self.pool.begin().await?;
 
         // 기존 annotation 데이터를 가져와서 history에 저장
-        let old_annotation = sqlx::query_as::<_, Annotation>(
-            "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
-                    tool_name, tool_version, data, is_shared, created_at, updated_at,
-                    viewer_software, description, measurement_values
-             FROM annotation_annotation WHERE id = $1"
+        let old_data = sqlx::query_scalar::<_, serde_json::Value>(
+            "SELECT data FROM annotation_annotation WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"
         )
         .bind(id)
         .fetch_optional(&mut *tx)
         .await?;
 
-        if let Some(annotation) = old_annotation {
-            // history 생성 (같은 트랜잭션 내에서)
-            let _ = sqlx::query_as::<_, AnnotationHistory>(
-                "INSERT INTO annotation_annotation_history (annotation_id, user_id, action, data_before, data_after)
-                 VALUES ($1, $2, $3, $4, $5)
-                 RETURNING id, annotation_id, user_id, action, data_before, data_after, action_at"
-            )
-            .bind(annotation.id)
-            .bind(annotation.user_id)
-            .bind("delete")
-            .bind(Some(annotation.data.clone()))
-            .bind(None::<serde_json::Value>)
-            .fetch_one(&mut *tx)
-            .await?;
+        let Some(old_data) = old_data else {
+            tx.commit().await?;
+            return Ok(false);
+        };
 
-            // annotation 삭제
-            let result = sqlx::query("DELETE FROM annotation_annotation WHERE id = $1")
-                .bind(id)
-                .execute(&mut *tx)
-                .await?;
+        // history 생성 (같은 트랜잭션 내에서)
+        let _ = sqlx::query_as::<_, AnnotationHistory>(
+            "INSERT INTO annotation_annotation_history (annotation_id, user_id, action, data_before, data_after)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING id, annotation_id, user_id, action, data_before, data_after, action_at"
+        )
+        .bind(id)
+        .bind(deleted_by)
+        .bind("delete")
+        .bind(Some(old_data))
+        .bind(None::<serde_json::Value>)
+        .fetch_one(&mut *tx)
+        .await?;
 
-            tx.commit().await?;
-            Ok(result.rows_affected() > 0)
-        } else {
-            tx.commit().await?;
-            Ok(false)
-        }
+        // annotation을 휴지통으로 이동 (하위 마스크 그룹도 같은 시간으로 함께 이동하여 복원 시 함께 되살림)
+        sqlx::query(
+            "UPDATE annotation_annotation SET deleted_at = CURRENT_TIMESTAMP, deleted_by = $2 WHERE id = $1"
+        )
+        .bind(id)
+        .bind(deleted_by)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "UPDATE annotation_mask_group SET deleted_at = CURRENT_TIMESTAMP, deleted_by = $2
+             WHERE annotation_id = $1 AND deleted_at IS NULL"
+        )
+        .bind(id)
+        .bind(deleted_by)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
     }
 
     async fn create_history(&self, annotation_id: i32, user_id: i32, action: &str, data_before: Option<serde_json::Value>, data_after: Option<serde_json::Value>) -> Result<AnnotationHistory, sqlx::Error> {
@@ -348,105 +427,325 @@ impl AnnotationRepository for AnnotationRepositoryImpl {
         .await
     }
 
-    // viewer_software 필터링 메서드들
-    async fn find_by_user_id_with_viewer(&self, user_id: i32, viewer_software: Option<&str>) -> Result<Vec<Annotation>, sqlx::Error> {
-        match viewer_software {
-            Some(viewer) => {
-                sqlx::query_as::<_, Annotation>(
-                    "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
-                            tool_name, tool_version, data, is_shared, created_at, updated_at,
-                            viewer_software, description, measurement_values
-                     FROM annotation_annotation
-                     WHERE user_id = $1 AND viewer_software = $2
-                     ORDER BY created_at DESC"
-                )
-                .bind(user_id)
-                .bind(viewer)
-                .fetch_all(&self.pool)
-                .await
-            },
-            None => {
-                sqlx::query_as::<_, Annotation>(
-                    "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
-                            tool_name, tool_version, data, is_shared, created_at, updated_at,
-                            viewer_software, description, measurement_values
-                     FROM annotation_annotation
-                     WHERE user_id = $1
-                     ORDER BY created_at DESC"
+    async fn update_review_status(&self, id: i32, actor_id: i32, from: AnnotationReviewStatus, to: AnnotationReviewStatus, comment: Option<&str>) -> Result<Option<Annotation>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // 현재 상태가 from인 경우에만 전환 (동시 전환 방지)
+        let updated_annotation = sqlx::query_as::<_, Annotation>(
+            "UPDATE annotation_annotation 
+             SET review_status = $3,
+                 reviewed_at = CASE WHEN $3 IN ('APPROVED'::annotation_review_status_enum, 'REJECTED'::annotation_review_status_enum)
+                                    THEN CURRENT_TIMESTAMP ELSE reviewed_at END,
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND deleted_at IS NULL AND review_status = $2
+             RETURNING id, project_id, user_id, study_uid, series_uid, instance_uid, 
+                       tool_name, tool_version, data, is_shared, created_at, updated_at,
+                       viewer_software, description, measurement_values,
+                       review_status, reviewer_id, reviewed_at"
+        )
+        .bind(id)
+        .bind(from)
+        .bind(to)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(annotation) = &updated_annotation {
+            // history 생성 (같은 트랜잭션 내에서)
+            let _ = sqlx::query_as::<_, AnnotationHistory>(
+                "INSERT INTO annotation_annotation_history (annotation_id, user_id, action, data_before, data_after)
+                 VALUES ($1, $2, $3, $4, $5)
+                 RETURNING id, annotation_id, user_id, action, data_before, data_after, action_at"
+            )
+            .bind(annotation.id)
+            .bind(actor_id)
+            .bind(format!("REVIEW_{}", to))
+            .bind(Some(serde_json::json!({ "review_status": from })))
+            .bind(Some(serde_json::json!({ "review_status": to, "comment": comment })))
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if let Some(comment) = comment {
+                sqlx::query(
+                    "INSERT INTO annotation_review_comment (annotation_id, user_id, review_status, comment)
+                     VALUES ($1, $2, $3, $4)"
                 )
-                .bind(user_id)
-                .fetch_all(&self.pool)
-                .await
+                .bind(annotation.id)
+                .bind(actor_id)
+                .bind(to)
+                .bind(comment)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            if to == AnnotationReviewStatus::Approved {
+                let payload = serde_json::json!({
+                    "annotation_id": annotation.id,
+                    "study_uid": annotation.study_uid,
+                    "series_uid": annotation.series_uid,
+                    "author_id": annotation.user_id,
+                    "approved_by": actor_id,
+                    "comment": comment,
+                });
+                webhook_outbox::enqueue(&mut *tx, annotation.project_id, WebhookEventType::AnnotationApproved, &payload).await?;
             }
         }
+
+        tx.commit().await?;
+        Ok(updated_annotation)
     }
 
-    async fn find_by_project_id_with_viewer(&self, project_id: i32, viewer_software: Option<&str>) -> Result<Vec<Annotation>, sqlx::Error> {
-        match viewer_software {
-            Some(viewer) => {
-                sqlx::query_as::<_, Annotation>(
-                    "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
-                            tool_name, tool_version, data, is_shared, created_at, updated_at,
-                            viewer_software, description, measurement_values
-                     FROM annotation_annotation
-                     WHERE project_id = $1 AND viewer_software = $2
-                     ORDER BY created_at DESC"
-                )
-                .bind(project_id)
-                .bind(viewer)
-                .fetch_all(&self.pool)
-                .await
-            },
+    async fn assign_reviewer(&self, id: i32, actor_id: i32, reviewer_id: i32) -> Result<Option<Annotation>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let previous_reviewer = sqlx::query_scalar::<_, Option<i32>>(
+            "SELECT reviewer_id FROM annotation_annotation WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let previous_reviewer = match previous_reviewer {
+            Some(previous) => previous,
             None => {
-                sqlx::query_as::<_, Annotation>(
-                    "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
-                            tool_name, tool_version, data, is_shared, created_at, updated_at,
-                            viewer_software, description, measurement_values
-                     FROM annotation_annotation
-                     WHERE project_id = $1
-                     ORDER BY created_at DESC"
-                )
-                .bind(project_id)
-                .fetch_all(&self.pool)
-                .await
+                tx.commit().await?;
+                return Ok(None);
             }
+        };
+
+        let updated_annotation = sqlx::query_as::<_, Annotation>(
+            "UPDATE annotation_annotation 
+             SET reviewer_id = $2, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND deleted_at IS NULL
+             RETURNING id, project_id, user_id, study_uid, series_uid, instance_uid, 
+                       tool_name, tool_version, data, is_shared, created_at, updated_at,
+                       viewer_software, description, measurement_values,
+                       review_status, reviewer_id, reviewed_at"
+        )
+        .bind(id)
+        .bind(reviewer_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(annotation) = &updated_annotation {
+            let _ = sqlx::query_as::<_, AnnotationHistory>(
+                "INSERT INTO annotation_annotation_history (annotation_id, user_id, action, data_before, data_after)
+                 VALUES ($1, $2, $3, $4, $5)
+                 RETURNING id, annotation_id, user_id, action, data_before, data_after, action_at"
+            )
+            .bind(annotation.id)
+            .bind(actor_id)
+            .bind("ASSIGN_REVIEWER")
+            .bind(Some(serde_json::json!({ "reviewer_id": previous_reviewer })))
+            .bind(Some(serde_json::json!({ "reviewer_id": reviewer_id })))
+            .fetch_one(&mut *tx)
+            .await?;
         }
+
+        tx.commit().await?;
+        Ok(updated_annotation)
     }
 
-    async fn find_by_study_uid_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>) -> Result<Vec<Annotation>, sqlx::Error> {
-        match viewer_software {
-            Some(viewer) => {
-                sqlx::query_as::<_, Annotation>(
-                    "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
-                            tool_name, tool_version, data, is_shared, created_at, updated_at,
-                            viewer_software, description, measurement_values
-                     FROM annotation_annotation
-                     WHERE study_uid = $1 AND viewer_software = $2
-                     ORDER BY created_at DESC"
-                )
-                .bind(study_uid)
-                .bind(viewer)
-                .fetch_all(&self.pool)
-                .await
-            },
-            None => {
-                sqlx::query_as::<_, Annotation>(
-                    "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
-                            tool_name, tool_version, data, is_shared, created_at, updated_at,
-                            viewer_software, description, measurement_values
-                     FROM annotation_annotation
-                     WHERE study_uid = $1
-                     ORDER BY created_at DESC"
-                )
-                .bind(study_uid)
-                .fetch_all(&self.pool)
-                .await
+    async fn find_by_reviewer(&self, reviewer_id: i32, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, sqlx::Error> {
+        sqlx::query_as::<_, Annotation>(
+            "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
+                    tool_name, tool_version, data, is_shared, created_at, updated_at,
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
+             FROM annotation_annotation
+             WHERE reviewer_id = $1 AND deleted_at IS NULL AND ($2::annotation_review_status_enum IS NULL OR review_status = $2)
+             ORDER BY updated_at DESC"
+        )
+        .bind(reviewer_id)
+        .bind(review_status)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn create_review_comment(&self, annotation_id: i32, user_id: i32, review_status: AnnotationReviewStatus, comment: &str) -> Result<AnnotationReviewComment, sqlx::Error> {
+        sqlx::query_as::<_, AnnotationReviewComment>(
+            "INSERT INTO annotation_review_comment (annotation_id, user_id, review_status, comment)
+             VALUES ($1, $2, $3, $4)
+             RETURNING id, annotation_id, user_id, review_status, comment, created_at"
+        )
+        .bind(annotation_id)
+        .bind(user_id)
+        .bind(review_status)
+        .bind(comment)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn get_review_comments(&self, annotation_id: i32) -> Result<Vec<AnnotationReviewComment>, sqlx::Error> {
+        sqlx::query_as::<_, AnnotationReviewComment>(
+            "SELECT id, annotation_id, user_id, review_status, comment, created_at
+             FROM annotation_review_comment
+             WHERE annotation_id = $1
+             ORDER BY created_at ASC, id ASC"
+        )
+        .bind(annotation_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    // viewer_software, review_status 필터링 메서드들
+    async fn find_by_user_id_with_viewer(&self, user_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, sqlx::Error> {
+        sqlx::query_as::<_, Annotation>(
+            "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
+                    tool_name, tool_version, data, is_shared, created_at, updated_at,
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
+             FROM annotation_annotation
+             WHERE user_id = $1 AND deleted_at IS NULL
+               AND ($2::text IS NULL OR viewer_software = $2)
+               AND ($3::annotation_review_status_enum IS NULL OR review_status = $3)
+             ORDER BY created_at DESC"
+        )
+        .bind(user_id)
+        .bind(viewer_software)
+        .bind(review_status)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn find_by_project_id_with_viewer(&self, project_id: i32, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, sqlx::Error> {
+        sqlx::query_as::<_, Annotation>(
+            "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
+                    tool_name, tool_version, data, is_shared, created_at, updated_at,
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
+             FROM annotation_annotation
+             WHERE project_id = $1 AND deleted_at IS NULL
+               AND ($2::text IS NULL OR viewer_software = $2)
+               AND ($3::annotation_review_status_enum IS NULL OR review_status = $3)
+             ORDER BY created_at DESC"
+        )
+        .bind(project_id)
+        .bind(viewer_software)
+        .bind(review_status)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn find_by_study_uid_with_viewer(&self, study_uid: &str, viewer_software: Option<&str>, review_status: Option<AnnotationReviewStatus>) -> Result<Vec<Annotation>, sqlx::Error> {
+        sqlx::query_as::<_, Annotation>(
+            "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
+                    tool_name, tool_version, data, is_shared, created_at, updated_at,
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
+             FROM annotation_annotation
+             WHERE study_uid = $1 AND deleted_at IS NULL
+               AND ($2::text IS NULL OR viewer_software = $2)
+               AND ($3::annotation_review_status_enum IS NULL OR review_status = $3)
+             ORDER BY created_at DESC"
+        )
+        .bind(study_uid)
+        .bind(viewer_software)
+        .bind(review_status)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn search(&self, criteria: &AnnotationSearchCriteria) -> Result<Vec<Annotation>, sqlx::Error> {
+        let mut qb: QueryBuilder<'_, Postgres> = QueryBuilder::new(
+            "SELECT id, project_id, user_id, study_uid, series_uid, instance_uid, 
+                    tool_name, tool_version, data, is_shared, created_at, updated_at,
+                    viewer_software, description, measurement_values,
+                    review_status, reviewer_id, reviewed_at
+             FROM annotation_annotation a
+             WHERE deleted_at IS NULL"
+        );
+
+        if let Some(project_id) = criteria.project_id {
+            qb.push(" AND project_id = ").push_bind(project_id);
+        }
+        if let Some(user_id) = criteria.user_id {
+            qb.push(" AND user_id = ").push_bind(user_id);
+        }
+        if let Some(study_uid) = &criteria.study_uid {
+            qb.push(" AND study_uid = ").push_bind(study_uid.clone());
+        }
+        if let Some(series_uid) = &criteria.series_uid {
+            qb.push(" AND series_uid = ").push_bind(series_uid.clone());
+        }
+        if let Some(instance_uid) = &criteria.instance_uid {
+            qb.push(" AND instance_uid = ").push_bind(instance_uid.clone());
+        }
+        if let Some(tool_name) = &criteria.tool_name {
+            qb.push(" AND tool_name = ").push_bind(tool_name.clone());
+        }
+        if let Some(viewer_software) = &criteria.viewer_software {
+            qb.push(" AND viewer_software = ").push_bind(viewer_software.clone());
+        }
+        if let Some(is_shared) = criteria.is_shared {
+            qb.push(" AND is_shared = ").push_bind(is_shared);
+        }
+        if let Some(review_status) = criteria.review_status {
+            qb.push(" AND review_status = ").push_bind(review_status);
+        }
+        if let Some(created_from) = criteria.created_from {
+            qb.push(" AND created_at >= ").push_bind(created_from);
+        }
+        if let Some(created_to) = criteria.created_to {
+            qb.push(" AND created_at < ").push_bind(created_to);
+        }
+        if let Some(updated_from) = criteria.updated_from {
+            qb.push(" AND updated_at >= ").push_bind(updated_from);
+        }
+        if let Some(updated_to) = criteria.updated_to {
+            qb.push(" AND updated_at < ").push_bind(updated_to);
+        }
+        for predicate in &criteria.data_predicates {
+            Self::push_json_predicate(&mut qb, "data", predicate);
+        }
+        for predicate in &criteria.measurement_predicates {
+            Self::push_json_predicate(&mut qb, "measurement_values", predicate);
+        }
+        if let Some(viewer_id) = criteria.visible_to_user_id {
+            qb.push(" AND (is_shared OR user_id = ")
+                .push_bind(viewer_id)
+                .push(") AND EXISTS (SELECT 1 FROM security_user_project up WHERE up.project_id = a.project_id AND up.user_id = ")
+                .push_bind(viewer_id)
+                .push(")");
+        }
+
+        let sort_column = match criteria.sort {
+            AnnotationSortField::CreatedAt => "created_at",
+            AnnotationSortField::UpdatedAt => "updated_at",
+            AnnotationSortField::Id => "id",
+        };
+        let (comparison, order) = match criteria.direction {
+            SortDirection::Asc => (">", "ASC"),
+            SortDirection::Desc => ("<", "DESC"),
+        };
+
+        // 키셋 페이지네이션: (정렬 키, id) 튜플 비교로 이전 페이지 마지막 행 다음부터 조회
+        if let Some(cursor) = &criteria.cursor {
+            match criteria.sort {
+                AnnotationSortField::Id => {
+                    qb.push(format!(" AND id {} ", comparison)).push_bind(cursor.id);
+                }
+                _ => {
+                    qb.push(format!(" AND ({}, id) {} (", sort_column, comparison))
+                        .push_bind(cursor.sort_timestamp())
+                        .push(", ")
+                        .push_bind(cursor.id)
+                        .push(")");
+                }
             }
         }
+
+        match criteria.sort {
+            AnnotationSortField::Id => qb.push(format!(" ORDER BY id {}", order)),
+            _ => qb.push(format!(" ORDER BY {} {}, id {}", sort_column, order, order)),
+        };
+        qb.push(" LIMIT ").push_bind(criteria.limit);
+
+        qb.build_query_as::<Annotation>()
+            .fetch_all(&self.pool)
+            .await
     }
 
     fn pool(&self) -> &PgPool {
         &self.pool
     }
 }
-
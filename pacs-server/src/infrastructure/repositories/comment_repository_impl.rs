@@ -0,0 +1,226 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::{Comment, CommentHistory, CommentTargetType, NewComment};
+use crate::domain::repositories::CommentRepository;
+use crate::domain::ServiceError;
+
+const COMMENT_COLUMNS: &str = "id, target_type, target_id, annotation_id, parent_id, user_id, body, mentions, is_resolved, resolved_by, resolved_at, is_deleted, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct CommentRepositoryImpl {
+    pool: PgPool,
+}
+
+impl CommentRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn insert_history(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        comment_id: i32,
+        user_id: i32,
+        action: &str,
+        body_before: Option<&str>,
+        body_after: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO annotation_comment_history (comment_id, user_id, action, body_before, body_after)
+             VALUES ($1, $2, $3, $4, $5)"
+        )
+        .bind(comment_id)
+        .bind(user_id)
+        .bind(action)
+        .bind(body_before)
+        .bind(body_after)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CommentRepository for CommentRepositoryImpl {
+    async fn create(&self, new_comment: &NewComment) -> Result<Comment, ServiceError> {
+        let query = format!(
+            "INSERT INTO annotation_comment (target_type, target_id, annotation_id, parent_id, user_id, body, mentions)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING {}",
+            COMMENT_COLUMNS
+        );
+
+        sqlx::query_as::<_, Comment>(&query)
+            .bind(new_comment.target_type)
+            .bind(new_comment.target_id)
+            .bind(new_comment.annotation_id)
+            .bind(new_comment.parent_id)
+            .bind(new_comment.user_id)
+            .bind(&new_comment.body)
+            .bind(&new_comment.mentions)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to create comment: {}", e);
+                ServiceError::DatabaseError(format!("Failed to create comment: {}", e))
+            })
+    }
+
+    async fn get_by_id(&self, id: i32) -> Result<Option<Comment>, ServiceError> {
+        let query = format!("SELECT {} FROM annotation_comment WHERE id = $1", COMMENT_COLUMNS);
+
+        sqlx::query_as::<_, Comment>(&query)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to get comment {}: {}", id, e);
+                ServiceError::DatabaseError(format!("Failed to get comment: {}", e))
+            })
+    }
+
+    async fn list_by_target(&self, target_type: CommentTargetType, target_id: i32) -> Result<Vec<Comment>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_comment
+             WHERE target_type = $1 AND target_id = $2
+             ORDER BY created_at ASC, id ASC",
+            COMMENT_COLUMNS
+        );
+
+        sqlx::query_as::<_, Comment>(&query)
+            .bind(target_type)
+            .bind(target_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to list comments for {} {}: {}", target_type, target_id, e);
+                ServiceError::DatabaseError(format!("Failed to list comments: {}", e))
+            })
+    }
+
+    async fn update_body(&self, id: i32, user_id: i32, body: &str, mentions: &[i32]) -> Result<Comment, ServiceError> {
+        let map_err = |e: sqlx::Error| {
+            eprintln!("Failed to update comment {}: {}", id, e);
+            ServiceError::DatabaseError(format!("Failed to update comment: {}", e))
+        };
+
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        let body_before = sqlx::query_scalar::<_, String>(
+            "SELECT body FROM annotation_comment WHERE id = $1 FOR UPDATE"
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| ServiceError::NotFound(format!("Comment with id {} not found", id)))?;
+
+        let query = format!(
+            "UPDATE annotation_comment
+             SET body = $2, mentions = $3, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1
+             RETURNING {}",
+            COMMENT_COLUMNS
+        );
+        let comment = sqlx::query_as::<_, Comment>(&query)
+            .bind(id)
+            .bind(body)
+            .bind(mentions)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(map_err)?;
+
+        Self::insert_history(&mut tx, id, user_id, "UPDATE", Some(&body_before), Some(body))
+            .await
+            .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(comment)
+    }
+
+    async fn soft_delete(&self, id: i32, user_id: i32) -> Result<(), ServiceError> {
+        let map_err = |e: sqlx::Error| {
+            eprintln!("Failed to delete comment {}: {}", id, e);
+            ServiceError::DatabaseError(format!("Failed to delete comment: {}", e))
+        };
+
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        let body_before = sqlx::query_scalar::<_, String>(
+            "SELECT body FROM annotation_comment WHERE id = $1 AND NOT is_deleted FOR UPDATE"
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| ServiceError::NotFound(format!("Comment with id {} not found", id)))?;
+
+        // 답글 스레드를 유지하기 위해 행은 남기고 본문만 비움
+        sqlx::query(
+            "UPDATE annotation_comment
+             SET body = '', mentions = '{}', is_deleted = true, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1"
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        Self::insert_history(&mut tx, id, user_id, "DELETE", Some(&body_before), None)
+            .await
+            .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(())
+    }
+
+    async fn set_resolved(&self, id: i32, user_id: i32, resolved: bool) -> Result<Comment, ServiceError> {
+        let map_err = |e: sqlx::Error| {
+            eprintln!("Failed to change resolved state of comment {}: {}", id, e);
+            ServiceError::DatabaseError(format!("Failed to change resolved state: {}", e))
+        };
+
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        let query = format!(
+            "UPDATE annotation_comment
+             SET is_resolved = $2,
+                 resolved_by = CASE WHEN $2 THEN $3 ELSE NULL END,
+                 resolved_at = CASE WHEN $2 THEN CURRENT_TIMESTAMP ELSE NULL END
+             WHERE id = $1
+             RETURNING {}",
+            COMMENT_COLUMNS
+        );
+        let comment = sqlx::query_as::<_, Comment>(&query)
+            .bind(id)
+            .bind(resolved)
+            .bind(user_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(map_err)?
+            .ok_or_else(|| ServiceError::NotFound(format!("Comment with id {} not found", id)))?;
+
+        let action = if resolved { "RESOLVE" } else { "REOPEN" };
+        Self::insert_history(&mut tx, id, user_id, action, None, None)
+            .await
+            .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(comment)
+    }
+
+    async fn get_history(&self, id: i32) -> Result<Vec<CommentHistory>, ServiceError> {
+        sqlx::query_as::<_, CommentHistory>(
+            "SELECT id, comment_id, user_id, action, body_before, body_after, action_at
+             FROM annotation_comment_history
+             WHERE comment_id = $1
+             ORDER BY action_at DESC, id DESC"
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to get history of comment {}: {}", id, e);
+            ServiceError::DatabaseError(format!("Failed to get comment history: {}", e))
+        })
+    }
+}
@@ -0,0 +1,7 @@
+use crate::domain::ServiceError;
+
+/// 데이터베이스 오류를 기록하고 `ServiceError::DatabaseError`로 변환 (`context`는 "get edit lock"처럼 실패한 동작)
+pub(crate) fn database_error(context: &str, e: sqlx::Error) -> ServiceError {
+    eprintln!("Failed to {}: {}", context, e);
+    ServiceError::DatabaseError(format!("Failed to {}: {}", context, e))
+}
@@ -0,0 +1,236 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use crate::domain::entities::{
+    DatasetAnnotationRow, DatasetExportCounts, DatasetExportFilter, DatasetExportJob, DatasetMaskRow,
+    NewDatasetExportJob,
+};
+use crate::domain::repositories::DatasetExportRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+const DATASET_EXPORT_JOB_COLUMNS: &str = "id, project_id, format, filters, include_signed_urls, url_ttl_seconds, status, annotation_count, mask_count, skipped_count, output_path, error_message, requested_by, created_at, updated_at, completed_at";
+
+#[derive(Clone)]
+pub struct DatasetExportRepositoryImpl {
+    pool: PgPool,
+}
+
+impl DatasetExportRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 어노테이션(`a`)에 공통으로 적용되는 프로젝트/가시성/검토 상태 조건 (휴지통 항목 제외)
+fn push_annotation_conditions(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    project_id: i32,
+    filter: &DatasetExportFilter,
+    viewer_id: Option<i32>,
+) {
+    builder
+        .push(" WHERE a.deleted_at IS NULL AND a.project_id = ")
+        .push_bind(project_id);
+
+    if let Some(viewer_id) = viewer_id {
+        builder
+            .push(" AND (a.is_shared OR a.user_id = ")
+            .push_bind(viewer_id)
+            .push(")");
+    }
+    if let Some(review_status) = filter.review_status {
+        builder.push(" AND a.review_status = ").push_bind(review_status);
+    }
+}
+
+#[async_trait]
+impl DatasetExportRepository for DatasetExportRepositoryImpl {
+    async fn find_project_name(&self, project_id: i32) -> Result<Option<String>, ServiceError> {
+        sqlx::query_scalar::<_, String>("SELECT name FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("get project name", e))
+    }
+
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project membership", e))
+    }
+
+    async fn create(&self, new_job: &NewDatasetExportJob) -> Result<DatasetExportJob, ServiceError> {
+        let query = format!(
+            "INSERT INTO annotation_dataset_export_job
+                (project_id, format, filters, include_signed_urls, url_ttl_seconds, requested_by)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             RETURNING {}",
+            DATASET_EXPORT_JOB_COLUMNS
+        );
+
+        sqlx::query_as::<_, DatasetExportJob>(&query)
+            .bind(new_job.project_id)
+            .bind(new_job.format.as_str())
+            .bind(serde_json::to_value(&new_job.filter).unwrap_or_default())
+            .bind(new_job.include_signed_urls)
+            .bind(new_job.url_ttl_seconds)
+            .bind(new_job.requested_by)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| database_error("create dataset export job", e))
+    }
+
+    async fn get_by_id(&self, id: i32) -> Result<Option<DatasetExportJob>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_dataset_export_job WHERE id = $1",
+            DATASET_EXPORT_JOB_COLUMNS
+        );
+
+        sqlx::query_as::<_, DatasetExportJob>(&query)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("get dataset export job", e))
+    }
+
+    async fn list_by_project(&self, project_id: i32) -> Result<Vec<DatasetExportJob>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_dataset_export_job WHERE project_id = $1 ORDER BY created_at DESC, id DESC",
+            DATASET_EXPORT_JOB_COLUMNS
+        );
+
+        sqlx::query_as::<_, DatasetExportJob>(&query)
+            .bind(project_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("list dataset export jobs", e))
+    }
+
+    async fn mark_running(&self, id: i32) -> Result<(), ServiceError> {
+        sqlx::query(
+            "UPDATE annotation_dataset_export_job
+             SET status = 'RUNNING', updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1"
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("update dataset export job", e))?;
+
+        Ok(())
+    }
+
+    async fn mark_completed(
+        &self,
+        id: i32,
+        output_path: &str,
+        counts: DatasetExportCounts,
+    ) -> Result<DatasetExportJob, ServiceError> {
+        let query = format!(
+            "UPDATE annotation_dataset_export_job
+             SET status = 'COMPLETED', output_path = $2, annotation_count = $3, mask_count = $4,
+                 skipped_count = $5, error_message = NULL,
+                 updated_at = CURRENT_TIMESTAMP, completed_at = CURRENT_TIMESTAMP
+             WHERE id = $1
+             RETURNING {}",
+            DATASET_EXPORT_JOB_COLUMNS
+        );
+
+        sqlx::query_as::<_, DatasetExportJob>(&query)
+            .bind(id)
+            .bind(output_path)
+            .bind(counts.annotations)
+            .bind(counts.masks)
+            .bind(counts.skipped)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| database_error("complete dataset export job", e))
+    }
+
+    async fn mark_failed(&self, id: i32, error_message: &str) -> Result<(), ServiceError> {
+        sqlx::query(
+            "UPDATE annotation_dataset_export_job
+             SET status = 'FAILED', error_message = $2,
+                 updated_at = CURRENT_TIMESTAMP, completed_at = CURRENT_TIMESTAMP
+             WHERE id = $1"
+        )
+        .bind(id)
+        .bind(error_message)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("fail dataset export job", e))?;
+
+        Ok(())
+    }
+
+    async fn find_annotations(
+        &self,
+        project_id: i32,
+        filter: &DatasetExportFilter,
+        viewer_id: Option<i32>,
+    ) -> Result<Vec<DatasetAnnotationRow>, ServiceError> {
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "SELECT a.id AS annotation_id, a.study_uid, a.series_uid, a.instance_uid, a.tool_name,
+                    a.data, a.review_status
+             FROM annotation_annotation a"
+        );
+        push_annotation_conditions(&mut builder, project_id, filter, viewer_id);
+
+        if let Some(label) = &filter.label {
+            builder
+                .push(" AND COALESCE(NULLIF(a.data->>'label', ''), a.tool_name) = ")
+                .push_bind(label.clone());
+        }
+        if let Some(model_name) = &filter.model_name {
+            builder
+                .push(" AND EXISTS (SELECT 1 FROM annotation_mask_group g WHERE g.annotation_id = a.id AND g.deleted_at IS NULL AND g.model_name = ")
+                .push_bind(model_name.clone())
+                .push(")");
+        }
+        builder.push(" ORDER BY a.id");
+
+        builder
+            .build_query_as::<DatasetAnnotationRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("find annotations for dataset export", e))
+    }
+
+    async fn find_masks(
+        &self,
+        project_id: i32,
+        filter: &DatasetExportFilter,
+        viewer_id: Option<i32>,
+    ) -> Result<Vec<DatasetMaskRow>, ServiceError> {
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "SELECT m.id AS mask_id, m.mask_group_id, a.id AS annotation_id, a.study_uid, a.series_uid,
+                    m.sop_instance_uid, m.slice_index, m.label_name, g.group_name, g.model_name,
+                    a.review_status, m.file_path, m.width, m.height
+             FROM annotation_mask m
+             JOIN annotation_mask_group g ON g.id = m.mask_group_id AND g.deleted_at IS NULL
+             JOIN annotation_annotation a ON a.id = g.annotation_id"
+        );
+        push_annotation_conditions(&mut builder, project_id, filter, viewer_id);
+
+        if let Some(label) = &filter.label {
+            builder
+                .push(" AND COALESCE(m.label_name, g.group_name) = ")
+                .push_bind(label.clone());
+        }
+        if let Some(model_name) = &filter.model_name {
+            builder.push(" AND g.model_name = ").push_bind(model_name.clone());
+        }
+        builder.push(" ORDER BY m.mask_group_id, m.slice_index NULLS LAST, m.id");
+
+        builder
+            .build_query_as::<DatasetMaskRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("find masks for dataset export", e))
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use crate::domain::entities::{
+    DatasetExportFilter, DatasetRelease, NewDatasetRelease, ReleaseAnnotationSnapshot, ReleaseMaskSource,
+};
+use crate::domain::repositories::DatasetReleaseRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+const DATASET_RELEASE_COLUMNS: &str = "id, project_id, name, description, release_key, manifest_path, filters, annotation_count, mask_count, created_by, created_at";
+
+#[derive(Clone)]
+pub struct DatasetReleaseRepositoryImpl {
+    pool: PgPool,
+}
+
+impl DatasetReleaseRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 어노테이션(`a`)에 공통으로 적용되는 프로젝트/가시성/검토 상태/ID 조건 (휴지통 항목 제외)
+fn push_selection_conditions(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    project_id: i32,
+    filter: &DatasetExportFilter,
+    annotation_ids: Option<&[i32]>,
+    viewer_id: i32,
+) {
+    builder
+        .push(" WHERE a.deleted_at IS NULL AND a.project_id = ")
+        .push_bind(project_id)
+        .push(" AND (a.is_shared OR a.user_id = ")
+        .push_bind(viewer_id)
+        .push(")");
+
+    if let Some(review_status) = filter.review_status {
+        builder.push(" AND a.review_status = ").push_bind(review_status);
+    }
+    if let Some(annotation_ids) = annotation_ids {
+        builder.push(" AND a.id = ANY(").push_bind(annotation_ids.to_vec()).push(")");
+    }
+}
+
+#[async_trait]
+impl DatasetReleaseRepository for DatasetReleaseRepositoryImpl {
+    async fn find_project_name(&self, project_id: i32) -> Result<Option<String>, ServiceError> {
+        sqlx::query_scalar::<_, String>("SELECT name FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("get project name", e))
+    }
+
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project membership", e))
+    }
+
+    async fn find_by_name(&self, project_id: i32, name: &str) -> Result<Option<DatasetRelease>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_dataset_release WHERE project_id = $1 AND name = $2",
+            DATASET_RELEASE_COLUMNS
+        );
+
+        sqlx::query_as::<_, DatasetRelease>(&query)
+            .bind(project_id)
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("find dataset release by name", e))
+    }
+
+    async fn pin_annotations(
+        &self,
+        project_id: i32,
+        filter: &DatasetExportFilter,
+        annotation_ids: Option<&[i32]>,
+        viewer_id: i32,
+    ) -> Result<Vec<ReleaseAnnotationSnapshot>, ServiceError> {
+        // 현재 data와 같은 최신 히스토리를 고정하고, 없으면 create에서 기록할 히스토리 ID를 예약
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "WITH selected AS (
+                SELECT a.id, a.study_uid, a.series_uid, a.instance_uid, a.tool_name, a.data, a.review_status,
+                       (SELECT h.id FROM annotation_annotation_history h
+                         WHERE h.annotation_id = a.id AND h.data_after = a.data
+                         ORDER BY h.id DESC LIMIT 1) AS history_id
+                FROM annotation_annotation a"
+        );
+        push_selection_conditions(&mut builder, project_id, filter, annotation_ids, viewer_id);
+        if let Some(label) = &filter.label {
+            builder
+                .push(" AND COALESCE(NULLIF(a.data->>'label', ''), a.tool_name) = ")
+                .push_bind(label.clone());
+        }
+        if let Some(model_name) = &filter.model_name {
+            builder
+                .push(" AND EXISTS (SELECT 1 FROM annotation_mask_group g WHERE g.annotation_id = a.id AND g.deleted_at IS NULL AND g.model_name = ")
+                .push_bind(model_name.clone())
+                .push(")");
+        }
+        // COALESCE는 앞 값이 NULL일 때만 nextval을 평가하므로 히스토리가 없는 항목에만 ID를 예약
+        builder.push(
+            ")
+            SELECT id AS annotation_id,
+                   COALESCE(history_id, nextval(pg_get_serial_sequence('annotation_annotation_history', 'id'))::int4) AS history_id,
+                   study_uid, series_uid, instance_uid, tool_name, data, review_status,
+                   history_id IS NULL AS snapshot_pending
+            FROM selected
+            ORDER BY id",
+        );
+
+        builder
+            .build_query_as::<ReleaseAnnotationSnapshot>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("pin annotations for dataset release", e))
+    }
+
+    async fn find_masks(
+        &self,
+        project_id: i32,
+        filter: &DatasetExportFilter,
+        annotation_ids: Option<&[i32]>,
+        viewer_id: i32,
+    ) -> Result<Vec<ReleaseMaskSource>, ServiceError> {
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "SELECT m.id AS mask_id, m.mask_group_id, a.id AS annotation_id, m.sop_instance_uid, m.slice_index,
+                    m.label_name, m.file_path, m.checksum
+             FROM annotation_mask m
+             JOIN annotation_mask_group g ON g.id = m.mask_group_id AND g.deleted_at IS NULL
+             JOIN annotation_annotation a ON a.id = g.annotation_id"
+        );
+        push_selection_conditions(&mut builder, project_id, filter, annotation_ids, viewer_id);
+        if let Some(label) = &filter.label {
+            builder
+                .push(" AND COALESCE(m.label_name, g.group_name) = ")
+                .push_bind(label.clone());
+        }
+        if let Some(model_name) = &filter.model_name {
+            builder.push(" AND g.model_name = ").push_bind(model_name.clone());
+        }
+        builder.push(" ORDER BY m.id");
+
+        builder
+            .build_query_as::<ReleaseMaskSource>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("find masks for dataset release", e))
+    }
+
+    async fn create(&self, release: &NewDatasetRelease) -> Result<DatasetRelease, ServiceError> {
+        let map_err = |e: sqlx::Error| database_error("create dataset release", e);
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        // pin_annotations에서 예약한 ID로 스냅샷 히스토리 기록
+        let pending: Vec<&ReleaseAnnotationSnapshot> = release.annotations.iter().filter(|a| a.snapshot_pending).collect();
+        if !pending.is_empty() {
+            sqlx::query(
+                "INSERT INTO annotation_annotation_history (id, annotation_id, user_id, action, data_before, data_after)
+                 OVERRIDING SYSTEM VALUE
+                 SELECT history_id, annotation_id, $1, 'release_snapshot', data, data
+                 FROM UNNEST($2::int4[], $3::int4[], $4::jsonb[]) AS t(history_id, annotation_id, data)"
+            )
+            .bind(release.created_by)
+            .bind(pending.iter().map(|a| a.history_id).collect::<Vec<_>>())
+            .bind(pending.iter().map(|a| a.annotation_id).collect::<Vec<_>>())
+            .bind(pending.iter().map(|a| a.data.clone()).collect::<Vec<_>>())
+            .execute(&mut *tx)
+            .await
+            .map_err(map_err)?;
+        }
+
+        let query = format!(
+            "INSERT INTO annotation_dataset_release
+                (project_id, name, description, release_key, manifest_path, filters, annotation_count, mask_count, created_by)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             ON CONFLICT (project_id, name) DO NOTHING
+             RETURNING {}",
+            DATASET_RELEASE_COLUMNS
+        );
+        let created = sqlx::query_as::<_, DatasetRelease>(&query)
+            .bind(release.project_id)
+            .bind(&release.name)
+            .bind(&release.description)
+            .bind(release.release_key)
+            .bind(&release.manifest_path)
+            .bind(serde_json::to_value(&release.filter).unwrap_or_default())
+            .bind(release.annotations.len() as i32)
+            .bind(release.masks.len() as i32)
+            .bind(release.created_by)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(map_err)?
+            .ok_or_else(|| ServiceError::AlreadyExists(format!(
+                "Dataset release '{}' already exists in project {}",
+                release.name, release.project_id
+            )))?;
+
+        let (annotation_ids, history_ids): (Vec<i32>, Vec<i32>) =
+            release.annotations.iter().map(|a| (a.annotation_id, a.history_id)).unzip();
+        sqlx::query(
+            "INSERT INTO annotation_dataset_release_annotation (release_id, annotation_id, history_id)
+             SELECT $1, * FROM UNNEST($2::int4[], $3::int4[])"
+        )
+        .bind(created.id)
+        .bind(&annotation_ids)
+        .bind(&history_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        let masks = &release.masks;
+        sqlx::query(
+            "INSERT INTO annotation_dataset_release_mask
+                (release_id, mask_id, mask_group_id, annotation_id, checksum, source_path, object_key)
+             SELECT $1, * FROM UNNEST($2::int4[], $3::int4[], $4::int4[], $5::text[], $6::text[], $7::text[])"
+        )
+        .bind(created.id)
+        .bind(masks.iter().map(|m| m.mask_id).collect::<Vec<_>>())
+        .bind(masks.iter().map(|m| m.mask_group_id).collect::<Vec<_>>())
+        .bind(masks.iter().map(|m| m.annotation_id).collect::<Vec<_>>())
+        .bind(masks.iter().map(|m| m.checksum.clone()).collect::<Vec<_>>())
+        .bind(masks.iter().map(|m| m.source_path.clone()).collect::<Vec<_>>())
+        .bind(masks.iter().map(|m| m.object_key.clone()).collect::<Vec<_>>())
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(created)
+    }
+
+    async fn get_by_id(&self, id: i32) -> Result<Option<DatasetRelease>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_dataset_release WHERE id = $1",
+            DATASET_RELEASE_COLUMNS
+        );
+
+        sqlx::query_as::<_, DatasetRelease>(&query)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("get dataset release", e))
+    }
+
+    async fn list_by_project(&self, project_id: i32) -> Result<Vec<DatasetRelease>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_dataset_release WHERE project_id = $1 ORDER BY created_at DESC, id DESC",
+            DATASET_RELEASE_COLUMNS
+        );
+
+        sqlx::query_as::<_, DatasetRelease>(&query)
+            .bind(project_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("list dataset releases", e))
+    }
+
+    async fn get_annotation_pins(&self, release_id: i32) -> Result<HashMap<i32, i32>, ServiceError> {
+        let rows = sqlx::query_as::<_, (i32, i32)>(
+            "SELECT annotation_id, history_id FROM annotation_dataset_release_annotation WHERE release_id = $1"
+        )
+        .bind(release_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get release annotation pins", e))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn get_mask_pins(&self, release_id: i32) -> Result<HashMap<i32, String>, ServiceError> {
+        let rows = sqlx::query_as::<_, (i32, String)>(
+            "SELECT mask_id, checksum FROM annotation_dataset_release_mask WHERE release_id = $1"
+        )
+        .bind(release_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get release mask pins", e))?;
+
+        Ok(rows.into_iter().collect())
+    }
+}
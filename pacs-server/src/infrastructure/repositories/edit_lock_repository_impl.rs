@@ -0,0 +1,247 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::edit_lock::{EditLock, EditLockAttempt, NewEditLock};
+use crate::domain::repositories::EditLockRepository;
+use crate::domain::entities::realtime::{RealtimeEvent, RealtimeEventType};
+use crate::domain::services::edit_lock_guard::find_conflicting_lock;
+use crate::domain::services::realtime_events;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+/// 잠금(`l`)과 잠금을 가진 사용자 이름
+const LOCK_COLUMNS: &str = "l.id, l.project_id, l.target_type, l.study_uid, l.annotation_id, l.holder_id,
+    u.username AS holder_username, l.acquired_at, l.renewed_at, l.expires_at";
+
+#[derive(Clone)]
+pub struct EditLockRepositoryImpl {
+    pool: PgPool,
+}
+
+impl EditLockRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 잠금 행을 바꾸는 문장(`RETURNING *`)의 결과를 사용자 이름과 함께 조회하는 쿼리
+fn with_holder(statement: &str) -> String {
+    format!(
+        "WITH l AS ({}) SELECT {} FROM l LEFT JOIN security_user u ON u.id = l.holder_id",
+        statement, LOCK_COLUMNS
+    )
+}
+
+/// 잠금 변경 이벤트 (변경한 사용자는 잠금을 가진 사용자)
+///
+/// 어노테이션 잠금 이벤트는 어노테이션의 공유 여부를 따라 전달됩니다.
+async fn lock_event<'e, E>(executor: E, event_type: RealtimeEventType, lock: &EditLock) -> Result<RealtimeEvent, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+{
+    let event = RealtimeEvent::new(event_type, lock.project_id, Some(lock.study_uid.clone()), lock.id, Some(lock.holder_id));
+    let Some(annotation_id) = lock.annotation_id else {
+        return Ok(event);
+    };
+    let visibility = sqlx::query_as::<_, (i32, bool)>(
+        "SELECT user_id, is_shared FROM annotation_annotation WHERE id = $1"
+    )
+    .bind(annotation_id)
+    .fetch_optional(executor)
+    .await?;
+    Ok(match visibility {
+        Some((owner_id, is_shared)) => event.with_annotation_visibility(Some(owner_id), is_shared),
+        None => event.with_annotation_visibility(None, false),
+    })
+}
+
+#[async_trait]
+impl EditLockRepository for EditLockRepositoryImpl {
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project membership", e))
+    }
+
+    async fn find_annotation_study(&self, project_id: i32, annotation_id: i32) -> Result<Option<String>, ServiceError> {
+        sqlx::query_scalar::<_, String>(
+            "SELECT study_uid FROM annotation_annotation
+             WHERE id = $1 AND project_id = $2 AND deleted_at IS NULL"
+        )
+        .bind(annotation_id)
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get annotation study", e))
+    }
+
+    async fn acquire(&self, new_lock: &NewEditLock) -> Result<EditLockAttempt, ServiceError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| database_error("begin transaction", e))?;
+
+        // 같은 Study의 잠금 획득을 직렬화 (Study 잠금과 어노테이션 잠금 사이의 경쟁 방지)
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext('annotation_edit_lock:' || $1::TEXT || ':' || $2))")
+            .bind(new_lock.project_id)
+            .bind(&new_lock.study_uid)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| database_error("serialize edit lock acquisition", e))?;
+
+        sqlx::query(
+            "DELETE FROM annotation_edit_lock
+             WHERE project_id = $1 AND study_uid = $2 AND expires_at <= CURRENT_TIMESTAMP"
+        )
+        .bind(new_lock.project_id)
+        .bind(&new_lock.study_uid)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| database_error("remove expired edit locks", e))?;
+
+        let conflict = find_conflicting_lock(
+            &mut *tx,
+            new_lock.project_id,
+            &new_lock.study_uid,
+            new_lock.annotation_id,
+            new_lock.holder_id,
+        )
+        .await
+        .map_err(|e| database_error("check edit locks", e))?;
+        if let Some(lock) = conflict {
+            tx.rollback().await.ok();
+            return Ok(EditLockAttempt::Conflict(lock));
+        }
+
+        let renew = with_holder(
+            "UPDATE annotation_edit_lock
+             SET expires_at = CURRENT_TIMESTAMP + make_interval(secs => $5), renewed_at = CURRENT_TIMESTAMP
+             WHERE project_id = $1 AND study_uid = $2 AND target_type = $3
+               AND annotation_id IS NOT DISTINCT FROM $4 AND holder_id = $6
+             RETURNING *"
+        );
+        let renewed = sqlx::query_as::<_, EditLock>(&renew)
+            .bind(new_lock.project_id)
+            .bind(&new_lock.study_uid)
+            .bind(new_lock.target_type())
+            .bind(new_lock.annotation_id)
+            .bind(new_lock.ttl_seconds as f64)
+            .bind(new_lock.holder_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| database_error("renew edit lock", e))?;
+
+        let attempt = match renewed {
+            Some(lock) => EditLockAttempt::Renewed(lock),
+            None => {
+                let insert = with_holder(
+                    "INSERT INTO annotation_edit_lock (project_id, target_type, study_uid, annotation_id, holder_id, expires_at)
+                     VALUES ($1, $3, $2, $4, $6, CURRENT_TIMESTAMP + make_interval(secs => $5))
+                     RETURNING *"
+                );
+                let lock = sqlx::query_as::<_, EditLock>(&insert)
+                    .bind(new_lock.project_id)
+                    .bind(&new_lock.study_uid)
+                    .bind(new_lock.target_type())
+                    .bind(new_lock.annotation_id)
+                    .bind(new_lock.ttl_seconds as f64)
+                    .bind(new_lock.holder_id)
+                    .fetch_one(&mut *tx)
+                    .await
+                    .map_err(|e| database_error("create edit lock", e))?;
+                // 커밋될 때 구독자에게 전달됨
+                let event = lock_event(&mut *tx, RealtimeEventType::EditLockAcquired, &lock)
+                    .await
+                    .map_err(|e| database_error("build edit lock event", e))?;
+                realtime_events::publish(&mut *tx, &event)
+                    .await
+                    .map_err(|e| database_error("publish edit lock event", e))?;
+                EditLockAttempt::Acquired(lock)
+            }
+        };
+
+        tx.commit().await
+            .map_err(|e| database_error("commit transaction", e))?;
+        Ok(attempt)
+    }
+
+    async fn heartbeat(&self, project_id: i32, lock_id: i32, holder_id: i32, ttl_seconds: i64) -> Result<Option<EditLock>, ServiceError> {
+        let query = with_holder(
+            "UPDATE annotation_edit_lock
+             SET expires_at = CURRENT_TIMESTAMP + make_interval(secs => $4), renewed_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND project_id = $2 AND holder_id = $3 AND expires_at > CURRENT_TIMESTAMP
+             RETURNING *"
+        );
+        sqlx::query_as::<_, EditLock>(&query)
+            .bind(lock_id)
+            .bind(project_id)
+            .bind(holder_id)
+            .bind(ttl_seconds as f64)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("renew edit lock", e))
+    }
+
+    async fn release(&self, project_id: i32, lock_id: i32, holder_id: Option<i32>, released_by: i32) -> Result<Option<EditLock>, ServiceError> {
+        let query = with_holder(
+            "DELETE FROM annotation_edit_lock
+             WHERE id = $1 AND project_id = $2 AND ($3::INTEGER IS NULL OR holder_id = $3)
+               AND expires_at > CURRENT_TIMESTAMP
+             RETURNING *"
+        );
+        let released = sqlx::query_as::<_, EditLock>(&query)
+            .bind(lock_id)
+            .bind(project_id)
+            .bind(holder_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("release edit lock", e))?;
+
+        if let Some(lock) = &released {
+            match lock_event(&self.pool, RealtimeEventType::EditLockReleased, lock).await {
+                Ok(mut event) => {
+                    event.actor_id = Some(released_by);
+                    realtime_events::publish_best_effort(&self.pool, event).await;
+                }
+                Err(e) => eprintln!("Failed to build edit lock event: {}", e),
+            }
+        }
+        Ok(released)
+    }
+
+    async fn find_active(&self, project_id: i32, lock_id: i32) -> Result<Option<EditLock>, ServiceError> {
+        let query = format!(
+            "SELECT {}
+             FROM annotation_edit_lock l
+             LEFT JOIN security_user u ON u.id = l.holder_id
+             WHERE l.id = $1 AND l.project_id = $2 AND l.expires_at > CURRENT_TIMESTAMP",
+            LOCK_COLUMNS
+        );
+        sqlx::query_as::<_, EditLock>(&query)
+            .bind(lock_id)
+            .bind(project_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("get edit lock", e))
+    }
+
+    async fn list_active(&self, project_id: i32, study_uid: Option<&str>) -> Result<Vec<EditLock>, ServiceError> {
+        let query = format!(
+            "SELECT {}
+             FROM annotation_edit_lock l
+             LEFT JOIN security_user u ON u.id = l.holder_id
+             WHERE l.project_id = $1 AND ($2::TEXT IS NULL OR l.study_uid = $2)
+               AND l.expires_at > CURRENT_TIMESTAMP
+             ORDER BY l.study_uid, l.annotation_id NULLS FIRST, l.id",
+            LOCK_COLUMNS
+        );
+        sqlx::query_as::<_, EditLock>(&query)
+            .bind(project_id)
+            .bind(study_uid)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("list edit locks", e))
+    }
+}
@@ -0,0 +1,284 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgArguments;
+use sqlx::query::QueryScalar;
+use sqlx::{PgPool, Postgres};
+use crate::domain::entities::label::{
+    Label, LabelDefinition, LabelImportItem, LabelMigrationResult, LabelUsage, NewLabel,
+};
+use crate::domain::repositories::LabelRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+const LABEL_COLUMNS: &str = "id, project_id, parent_id, name, display_name, color, code_scheme, code_value, code_meaning, allowed_tools, aliases, created_at, updated_at";
+
+/// 프로젝트의 활성 마스크 (휴지통 및 잠긴 어노테이션 판단을 위해 어노테이션까지 조인)
+const PROJECT_MASKS: &str = "annotation_mask m
+    JOIN annotation_mask_group g ON g.id = m.mask_group_id AND g.deleted_at IS NULL
+    JOIN annotation_annotation a ON a.id = g.annotation_id AND a.deleted_at IS NULL";
+
+#[derive(Clone)]
+pub struct LabelRepositoryImpl {
+    pool: PgPool,
+}
+
+impl LabelRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 라벨 정의 필드를 $1..$8 순서로 바인딩
+fn bind_definition<'q>(
+    query: QueryScalar<'q, Postgres, i32, PgArguments>,
+    definition: &'q LabelDefinition,
+) -> QueryScalar<'q, Postgres, i32, PgArguments> {
+    query
+        .bind(&definition.name)
+        .bind(&definition.display_name)
+        .bind(&definition.color)
+        .bind(&definition.code_scheme)
+        .bind(&definition.code_value)
+        .bind(&definition.code_meaning)
+        .bind(&definition.allowed_tools)
+        .bind(&definition.aliases)
+}
+
+#[async_trait]
+impl LabelRepository for LabelRepositoryImpl {
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project membership", e))
+    }
+
+    async fn list_by_project(&self, project_id: i32) -> Result<Vec<Label>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_label WHERE project_id = $1 ORDER BY lower(name), id",
+            LABEL_COLUMNS
+        );
+
+        sqlx::query_as::<_, Label>(&query)
+            .bind(project_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("list labels", e))
+    }
+
+    async fn create(&self, new_label: &NewLabel) -> Result<Label, ServiceError> {
+        let definition = &new_label.definition;
+        let query = format!(
+            "INSERT INTO annotation_label
+                (project_id, parent_id, name, display_name, color, code_scheme, code_value, code_meaning, allowed_tools, aliases)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             RETURNING {}",
+            LABEL_COLUMNS
+        );
+
+        sqlx::query_as::<_, Label>(&query)
+            .bind(new_label.project_id)
+            .bind(new_label.parent_id)
+            .bind(&definition.name)
+            .bind(&definition.display_name)
+            .bind(&definition.color)
+            .bind(&definition.code_scheme)
+            .bind(&definition.code_value)
+            .bind(&definition.code_meaning)
+            .bind(&definition.allowed_tools)
+            .bind(&definition.aliases)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| database_error("create label", e))
+    }
+
+    async fn update(
+        &self,
+        project_id: i32,
+        label_id: i32,
+        definition: &LabelDefinition,
+        parent_id: Option<i32>,
+    ) -> Result<Option<Label>, ServiceError> {
+        let query = format!(
+            "UPDATE annotation_label
+             SET parent_id = $3, name = $4, display_name = $5, color = $6, code_scheme = $7, code_value = $8,
+                 code_meaning = $9, allowed_tools = $10, aliases = $11, updated_at = CURRENT_TIMESTAMP
+             WHERE project_id = $1 AND id = $2
+             RETURNING {}",
+            LABEL_COLUMNS
+        );
+
+        sqlx::query_as::<_, Label>(&query)
+            .bind(project_id)
+            .bind(label_id)
+            .bind(parent_id)
+            .bind(&definition.name)
+            .bind(&definition.display_name)
+            .bind(&definition.color)
+            .bind(&definition.code_scheme)
+            .bind(&definition.code_value)
+            .bind(&definition.code_meaning)
+            .bind(&definition.allowed_tools)
+            .bind(&definition.aliases)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("update label", e))
+    }
+
+    async fn delete(&self, project_id: i32, label_id: i32) -> Result<bool, ServiceError> {
+        let result = sqlx::query("DELETE FROM annotation_label WHERE project_id = $1 AND id = $2")
+            .bind(project_id)
+            .bind(label_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| database_error("delete label", e))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn import(&self, project_id: i32, items: &[LabelImportItem]) -> Result<(), ServiceError> {
+        let mut tx = self.pool.begin().await.map_err(|e| database_error("begin transaction", e))?;
+
+        // 상위 라벨이 같은 가져오기에서 새로 추가될 수 있으므로 모든 라벨을 저장한 뒤 상위 라벨을 연결
+        let mut label_ids = Vec::with_capacity(items.len());
+        for item in items {
+            let query = match item.label_id {
+                Some(label_id) => bind_definition(
+                    sqlx::query_scalar::<_, i32>(
+                        "UPDATE annotation_label
+                         SET name = $1, display_name = $2, color = $3, code_scheme = $4, code_value = $5,
+                             code_meaning = $6, allowed_tools = $7, aliases = $8, updated_at = CURRENT_TIMESTAMP
+                         WHERE project_id = $9 AND id = $10
+                         RETURNING id"
+                    ),
+                    &item.definition,
+                )
+                .bind(project_id)
+                .bind(label_id),
+                None => bind_definition(
+                    sqlx::query_scalar::<_, i32>(
+                        "INSERT INTO annotation_label
+                            (name, display_name, color, code_scheme, code_value, code_meaning, allowed_tools, aliases, project_id)
+                         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                         RETURNING id"
+                    ),
+                    &item.definition,
+                )
+                .bind(project_id),
+            };
+
+            let label_id = query
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| database_error("import label", e))?
+                .ok_or_else(|| ServiceError::NotFound(format!("Label '{}' was removed during import", item.definition.name)))?;
+            label_ids.push(label_id);
+        }
+
+        for (item, label_id) in items.iter().zip(label_ids) {
+            sqlx::query(
+                "UPDATE annotation_label
+                 SET parent_id = (SELECT p.id FROM annotation_label p WHERE p.project_id = $1 AND p.name = $3)
+                 WHERE project_id = $1 AND id = $2"
+            )
+            .bind(project_id)
+            .bind(label_id)
+            .bind(&item.parent)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| database_error("link imported label parent", e))?;
+        }
+
+        tx.commit().await.map_err(|e| database_error("commit label import", e))?;
+        Ok(())
+    }
+
+    async fn list_usage(&self, project_id: i32) -> Result<Vec<LabelUsage>, ServiceError> {
+        let query = format!(
+            "WITH usage AS (
+                SELECT m.label_name AS label, COUNT(*) AS mask_count, 0::BIGINT AS annotation_count
+                FROM {}
+                WHERE a.project_id = $1 AND btrim(COALESCE(m.label_name, '')) <> ''
+                GROUP BY m.label_name
+                UNION ALL
+                SELECT a.data->>'label', 0::BIGINT, COUNT(*)
+                FROM annotation_annotation a
+                WHERE a.project_id = $1 AND a.deleted_at IS NULL
+                  AND jsonb_typeof(a.data->'label') = 'string' AND btrim(a.data->>'label') <> ''
+                GROUP BY a.data->>'label'
+            )
+            SELECT label, SUM(mask_count)::BIGINT AS mask_count, SUM(annotation_count)::BIGINT AS annotation_count
+            FROM usage
+            GROUP BY label
+            ORDER BY label",
+            PROJECT_MASKS
+        );
+
+        sqlx::query_as::<_, LabelUsage>(&query)
+            .bind(project_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("list label usage", e))
+    }
+
+    async fn apply_mappings(
+        &self,
+        project_id: i32,
+        mappings: &[(String, String)],
+        actor_id: i32,
+    ) -> Result<LabelMigrationResult, ServiceError> {
+        let mut tx = self.pool.begin().await.map_err(|e| database_error("begin transaction", e))?;
+        let mut result = LabelMigrationResult::default();
+
+        let mask_query = format!(
+            "UPDATE annotation_mask SET label_name = $3, updated_at = CURRENT_TIMESTAMP
+             WHERE id IN (
+                SELECT m.id FROM {}
+                WHERE a.project_id = $1 AND a.review_status <> 'LOCKED' AND m.label_name = $2
+             )",
+            PROJECT_MASKS
+        );
+
+        for (from, to) in mappings {
+            result.masks_updated += sqlx::query(&mask_query)
+                .bind(project_id)
+                .bind(from)
+                .bind(to)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| database_error("relabel masks", e))?
+                .rows_affected();
+
+            // 자기 자신과 조인하면 변경 전 data를 함께 반환할 수 있음
+            let annotations_updated = sqlx::query_scalar::<_, i64>(
+                "WITH changed AS (
+                    UPDATE annotation_annotation a
+                    SET data = jsonb_set(a.data, '{label}', to_jsonb($3::TEXT)), updated_at = CURRENT_TIMESTAMP
+                    FROM annotation_annotation old
+                    WHERE old.id = a.id AND a.project_id = $1 AND a.deleted_at IS NULL
+                      AND a.review_status <> 'LOCKED' AND a.data->>'label' = $2
+                    RETURNING a.id, old.data AS data_before, a.data AS data_after
+                ), history AS (
+                    INSERT INTO annotation_annotation_history (annotation_id, user_id, action, data_before, data_after)
+                    SELECT id, $4, 'update', data_before, data_after FROM changed
+                    RETURNING 1
+                )
+                SELECT COUNT(*) FROM history"
+            )
+            .bind(project_id)
+            .bind(from)
+            .bind(to)
+            .bind(actor_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| database_error("relabel annotations", e))?;
+            result.annotations_updated += annotations_updated as u64;
+        }
+
+        tx.commit().await.map_err(|e| database_error("commit label migration", e))?;
+        Ok(result)
+    }
+}
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use sqlx::{PgPool, Row};
-use crate::domain::entities::{MaskGroup, NewMaskGroup, UpdateMaskGroup, MaskGroupStats, Mask};
+use crate::domain::entities::{MaskGroup, NewMaskGroup, UpdateMaskGroup, MaskGroupStats, Mask, MaskGroupLineageNode};
 use crate::domain::repositories::MaskGroupRepository;
 use crate::domain::ServiceError;
 use std::collections::HashMap;
@@ -21,9 +21,9 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
     async fn create(&self, mask_group: &NewMaskGroup) -> Result<MaskGroup, ServiceError> {
         let result = sqlx::query_as::<_, MaskGroup>(
             "INSERT INTO annotation_mask_group 
-             (annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-             RETURNING id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, created_at, updated_at"
+             (annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, derived_from_id)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             RETURNING id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, derived_from_id, created_at, updated_at"
         )
         .bind(mask_group.annotation_id)
         .bind(&mask_group.group_name)
@@ -34,6 +34,7 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
         .bind(&mask_group.mask_type)
         .bind(&mask_group.description)
         .bind(mask_group.created_by)
+        .bind(mask_group.derived_from_id)
         .fetch_one(&self.pool)
         .await;
 
@@ -48,9 +49,9 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
 
     async fn get_by_id(&self, id: i32) -> Result<Option<MaskGroup>, ServiceError> {
         let result = sqlx::query_as::<_, MaskGroup>(
-            "SELECT id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, created_at, updated_at
+            "SELECT id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, derived_from_id, created_at, updated_at
              FROM annotation_mask_group
-             WHERE id = $1"
+             WHERE id = $1 AND deleted_at IS NULL"
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -79,8 +80,8 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
                  mask_type = COALESCE($7, mask_type),
                  description = COALESCE($8, description),
                  updated_at = CURRENT_TIMESTAMP
-             WHERE id = $1
-             RETURNING id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, created_at, updated_at"
+             WHERE id = $1 AND deleted_at IS NULL
+             RETURNING id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, derived_from_id, created_at, updated_at"
         )
         .bind(id)
         .bind(&update_mask_group.group_name)
@@ -102,11 +103,16 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
         }
     }
 
-    async fn delete(&self, id: i32) -> Result<(), ServiceError> {
-        let result = sqlx::query("DELETE FROM annotation_mask_group WHERE id = $1")
-            .bind(id)
-            .execute(&self.pool)
-            .await;
+    async fn delete(&self, id: i32, deleted_by: i32) -> Result<(), ServiceError> {
+        // 행을 지우지 않고 휴지통으로 이동 (마스크 파일은 보관 기간 만료 후 정리 작업에서 삭제)
+        let result = sqlx::query(
+            "UPDATE annotation_mask_group SET deleted_at = CURRENT_TIMESTAMP, deleted_by = $2
+             WHERE id = $1 AND deleted_at IS NULL"
+        )
+        .bind(id)
+        .bind(deleted_by)
+        .execute(&self.pool)
+        .await;
 
         match result {
             Ok(result) => {
@@ -133,9 +139,10 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
         limit: Option<i64>,
     ) -> Result<Vec<MaskGroup>, ServiceError> {
         let query = sqlx::query_as::<_, MaskGroup>(
-            "SELECT id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, created_at, updated_at 
+            "SELECT id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, derived_from_id, created_at, updated_at 
              FROM annotation_mask_group 
-             WHERE ($1::int IS NULL OR annotation_id = $1)
+             WHERE deleted_at IS NULL
+               AND ($1::int IS NULL OR annotation_id = $1)
                AND ($2::int IS NULL OR created_by = $2)
                AND ($3::text IS NULL OR modality = $3)
                AND ($4::text IS NULL OR mask_type = $4)
@@ -160,10 +167,37 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
         }
     }
 
-    async fn get_masks_in_group(&self, _mask_group_id: i32) -> Result<Vec<Mask>, ServiceError> {
-        // For now, return an empty vector since we don't have the Mask entity properly set up
-        // This should be implemented when the Mask entity is properly configured
-        Ok(Vec::new())
+    async fn get_masks_in_group(&self, mask_group_id: i32) -> Result<Vec<Mask>, ServiceError> {
+        let rows = sqlx::query(
+            "SELECT id, mask_group_id, slice_index, sop_instance_uid, label_name,
+                    file_path, mime_type, file_size, checksum, width, height, created_at, updated_at
+             FROM annotation_mask
+             WHERE mask_group_id = $1
+             ORDER BY slice_index ASC NULLS LAST, id ASC"
+        )
+        .bind(mask_group_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ServiceError::DatabaseError(format!("Failed to get masks in group: {}", e)))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Mask {
+                id: row.get("id"),
+                mask_group_id: row.get("mask_group_id"),
+                slice_index: row.get("slice_index"),
+                sop_instance_uid: row.get("sop_instance_uid"),
+                label_name: row.get("label_name"),
+                file_path: row.get("file_path"),
+                mime_type: row.get("mime_type"),
+                file_size: row.get("file_size"),
+                checksum: row.get("checksum"),
+                width: row.get("width"),
+                height: row.get("height"),
+                created_at: row.get("created_at"),
+                updated_at: Some(row.get("updated_at")),
+            })
+            .collect())
     }
 
     async fn get_stats(&self, annotation_id: Option<i32>) -> Result<MaskGroupStats, ServiceError> {
@@ -173,7 +207,7 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
                 modality,
                 mask_type
              FROM annotation_mask_group 
-             WHERE annotation_id = $1
+             WHERE annotation_id = $1 AND deleted_at IS NULL
              GROUP BY modality, mask_type"
         } else {
             "SELECT 
@@ -181,6 +215,7 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
                 modality,
                 mask_type
              FROM annotation_mask_group 
+             WHERE deleted_at IS NULL
              GROUP BY modality, mask_type"
         };
 
@@ -238,7 +273,8 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
         let result = sqlx::query_scalar::<_, i64>(
             "SELECT COUNT(*) 
              FROM annotation_mask_group 
-             WHERE ($1::int IS NULL OR annotation_id = $1)
+             WHERE deleted_at IS NULL
+               AND ($1::int IS NULL OR annotation_id = $1)
                AND ($2::int IS NULL OR created_by = $2)
                AND ($3::text IS NULL OR modality = $3)
                AND ($4::text IS NULL OR mask_type = $4)"
@@ -258,4 +294,50 @@ impl MaskGroupRepository for MaskGroupRepositoryImpl {
             }
         }
     }
-}
\ No newline at end of file
+
+    async fn find_lineage(&self, annotation_id: i32) -> Result<Vec<MaskGroupLineageNode>, ServiceError> {
+        sqlx::query_as::<_, MaskGroupLineageNode>(
+            "SELECT g.id, g.annotation_id, g.group_name, g.model_name, g.version, g.mask_type, g.created_by,
+                    g.derived_from_id, g.created_at,
+                    (SELECT COUNT(*) FROM annotation_mask m WHERE m.mask_group_id = g.id) AS mask_count
+             FROM annotation_mask_group g
+             WHERE g.annotation_id = $1 AND g.deleted_at IS NULL
+             ORDER BY g.id ASC"
+        )
+        .bind(annotation_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ServiceError::DatabaseError(format!("Failed to get mask group lineage: {}", e)))
+    }
+
+    async fn set_derived_from(&self, id: i32, derived_from_id: Option<i32>) -> Result<Option<MaskGroup>, ServiceError> {
+        // 원본은 같은 어노테이션의 살아 있는 그룹이어야 하며, 원본의 조상 중에 자신이 있으면 순환
+        sqlx::query_as::<_, MaskGroup>(
+            "WITH RECURSIVE ancestors AS (
+                 SELECT id, derived_from_id FROM annotation_mask_group WHERE id = $2
+                 UNION
+                 SELECT g.id, g.derived_from_id
+                 FROM annotation_mask_group g
+                 JOIN ancestors a ON g.id = a.derived_from_id
+             )
+             UPDATE annotation_mask_group target
+             SET derived_from_id = $2,
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE target.id = $1 AND target.deleted_at IS NULL
+               AND ($2::INTEGER IS NULL OR (
+                   EXISTS (
+                       SELECT 1 FROM annotation_mask_group source
+                       WHERE source.id = $2 AND source.annotation_id = target.annotation_id
+                         AND source.deleted_at IS NULL
+                   )
+                   AND NOT EXISTS (SELECT 1 FROM ancestors WHERE ancestors.id = $1)
+               ))
+             RETURNING id, annotation_id, group_name, model_name, version, modality, slice_count, mask_type, description, created_by, derived_from_id, created_at, updated_at"
+        )
+        .bind(id)
+        .bind(derived_from_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ServiceError::DatabaseError(format!("Failed to set mask group derived_from: {}", e)))
+    }
+}
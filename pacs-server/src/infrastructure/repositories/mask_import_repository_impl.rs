@@ -0,0 +1,176 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::storage_deletion::{mask_group_mask_prefix, StorageDeletionReason};
+use crate::domain::entities::{MaskImportJob, NewMaskImportJob};
+use crate::domain::repositories::MaskImportRepository;
+use crate::domain::services::{storage_deletion_queue, storage_usage};
+use crate::domain::ServiceError;
+
+const MASK_IMPORT_JOB_COLUMNS: &str = "id, annotation_id, mask_group_id, source_path, source_format, status, total_frames, imported_masks, unmapped_frames, error_message, requested_by, created_at, updated_at, completed_at";
+
+#[derive(Clone)]
+pub struct MaskImportRepositoryImpl {
+    pool: PgPool,
+}
+
+impl MaskImportRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MaskImportRepository for MaskImportRepositoryImpl {
+    async fn create(&self, new_job: &NewMaskImportJob) -> Result<MaskImportJob, ServiceError> {
+        let query = format!(
+            "INSERT INTO annotation_mask_import_job (annotation_id, source_path, source_format, requested_by)
+             VALUES ($1, $2, $3, $4)
+             RETURNING {}",
+            MASK_IMPORT_JOB_COLUMNS
+        );
+
+        sqlx::query_as::<_, MaskImportJob>(&query)
+            .bind(new_job.annotation_id)
+            .bind(&new_job.source_path)
+            .bind(&new_job.source_format)
+            .bind(new_job.requested_by)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to create mask import job: {}", e);
+                ServiceError::DatabaseError(format!("Failed to create mask import job: {}", e))
+            })
+    }
+
+    async fn get_by_id(&self, id: i32) -> Result<Option<MaskImportJob>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_mask_import_job WHERE id = $1",
+            MASK_IMPORT_JOB_COLUMNS
+        );
+
+        sqlx::query_as::<_, MaskImportJob>(&query)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to get mask import job {}: {}", id, e);
+                ServiceError::DatabaseError(format!("Failed to get mask import job: {}", e))
+            })
+    }
+
+    async fn list_by_annotation(&self, annotation_id: i32) -> Result<Vec<MaskImportJob>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_mask_import_job WHERE annotation_id = $1 ORDER BY created_at DESC, id DESC",
+            MASK_IMPORT_JOB_COLUMNS
+        );
+
+        sqlx::query_as::<_, MaskImportJob>(&query)
+            .bind(annotation_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to list mask import jobs for annotation {}: {}", annotation_id, e);
+                ServiceError::DatabaseError(format!("Failed to list mask import jobs: {}", e))
+            })
+    }
+
+    async fn mark_running(&self, id: i32) -> Result<(), ServiceError> {
+        sqlx::query(
+            "UPDATE annotation_mask_import_job
+             SET status = 'RUNNING', updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1"
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ServiceError::DatabaseError(format!("Failed to update mask import job: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn mark_completed(
+        &self,
+        id: i32,
+        mask_group_id: i32,
+        total_frames: i32,
+        imported_masks: i32,
+        unmapped_frames: serde_json::Value,
+    ) -> Result<MaskImportJob, ServiceError> {
+        let query = format!(
+            "UPDATE annotation_mask_import_job
+             SET status = 'COMPLETED', mask_group_id = $2, total_frames = $3, imported_masks = $4,
+                 unmapped_frames = $5, error_message = NULL,
+                 updated_at = CURRENT_TIMESTAMP, completed_at = CURRENT_TIMESTAMP
+             WHERE id = $1
+             RETURNING {}",
+            MASK_IMPORT_JOB_COLUMNS
+        );
+
+        sqlx::query_as::<_, MaskImportJob>(&query)
+            .bind(id)
+            .bind(mask_group_id)
+            .bind(total_frames)
+            .bind(imported_masks)
+            .bind(unmapped_frames)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| ServiceError::DatabaseError(format!("Failed to complete mask import job: {}", e)))
+    }
+
+    async fn mark_failed(
+        &self,
+        id: i32,
+        created_mask_group_id: Option<i32>,
+        error_message: &str,
+    ) -> Result<(), ServiceError> {
+        let map_err = |e: sqlx::Error| ServiceError::DatabaseError(format!("Failed to fail mask import job: {}", e));
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        if let Some(mask_group_id) = created_mask_group_id {
+            let annotation_id = sqlx::query_scalar::<_, i32>(
+                "SELECT annotation_id FROM annotation_mask_group WHERE id = $1 FOR UPDATE"
+            )
+            .bind(mask_group_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(map_err)?;
+
+            if let Some(annotation_id) = annotation_id {
+                storage_usage::release_mask_groups(&mut tx, &[mask_group_id])
+                    .await
+                    .map_err(map_err)?;
+
+                // 마스크 행은 CASCADE로 함께 삭제되고, 작업의 mask_group_id는 NULL이 됨
+                sqlx::query("DELETE FROM annotation_mask_group WHERE id = $1")
+                    .bind(mask_group_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(map_err)?;
+
+                // 행을 만들기 전에 업로드된 객체까지 지우도록 그룹 경로 전체를 예약
+                storage_deletion_queue::enqueue_prefix(
+                    &mut *tx,
+                    &mask_group_mask_prefix(annotation_id, mask_group_id),
+                    StorageDeletionReason::MaskImportFailed,
+                )
+                .await
+                .map_err(map_err)?;
+            }
+        }
+
+        sqlx::query(
+            "UPDATE annotation_mask_import_job
+             SET status = 'FAILED', error_message = $2,
+                 updated_at = CURRENT_TIMESTAMP, completed_at = CURRENT_TIMESTAMP
+             WHERE id = $1"
+        )
+        .bind(id)
+        .bind(error_message)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use crate::domain::entities::{ContactSheetTask, MaskGroupContactSheet, MaskPreviewStatus, MaskPreviewTask};
+use crate::domain::repositories::MaskPreviewRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+#[derive(Clone)]
+pub struct MaskPreviewRepositoryImpl {
+    pool: PgPool,
+}
+
+impl MaskPreviewRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn failure_status(retry_in_seconds: Option<i64>) -> MaskPreviewStatus {
+    if retry_in_seconds.is_some() {
+        MaskPreviewStatus::Pending
+    } else {
+        MaskPreviewStatus::Failed
+    }
+}
+
+#[async_trait]
+impl MaskPreviewRepository for MaskPreviewRepositoryImpl {
+    async fn claim_pending_masks(&self, limit: i64, lease_seconds: i64) -> Result<Vec<MaskPreviewTask>, ServiceError> {
+        sqlx::query_as::<_, MaskPreviewTask>(
+            "WITH due AS (
+                 SELECT id FROM annotation_mask
+                 WHERE preview_status = 'PENDING' AND validation_status = 'VALID'
+                   AND next_preview_at <= CURRENT_TIMESTAMP
+                 ORDER BY next_preview_at, id
+                 LIMIT $1
+                 FOR UPDATE SKIP LOCKED
+             )
+             UPDATE annotation_mask m
+             SET next_preview_at = CURRENT_TIMESTAMP + $2::BIGINT * INTERVAL '1 second'
+             FROM due
+             WHERE m.id = due.id
+             RETURNING m.id AS mask_id, m.mask_group_id, m.file_path, m.preview_attempts,
+                       m.next_preview_at AS lease_until"
+        )
+        .bind(limit)
+        .bind(lease_seconds)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("claim pending mask previews", e))
+    }
+
+    async fn mark_mask_ready(&self, task: &MaskPreviewTask, thumbnail_key: &str, preview_key: &str) -> Result<bool, ServiceError> {
+        let map_err = |e: sqlx::Error| database_error("record mask preview", e);
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        // 가져간 뒤 마스크가 바뀌었으면 임대 시간이 달라지므로 결과를 버림
+        let updated = sqlx::query(
+            "UPDATE annotation_mask
+             SET preview_status = 'READY', thumbnail_key = $3, preview_key = $4, preview_error = NULL,
+                 preview_generated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND preview_status = 'PENDING' AND next_preview_at = $2"
+        )
+        .bind(task.mask_id)
+        .bind(task.lease_until)
+        .bind(thumbnail_key)
+        .bind(preview_key)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+        if updated.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            "UPDATE annotation_mask_group
+             SET contact_sheet_status = 'PENDING', contact_sheet_attempts = 0, next_contact_sheet_at = CURRENT_TIMESTAMP
+             WHERE id = $1"
+        )
+        .bind(task.mask_group_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(true)
+    }
+
+    async fn record_mask_failure(
+        &self,
+        mask_id: i32,
+        lease_until: DateTime<Utc>,
+        error: &str,
+        retry_in_seconds: Option<i64>,
+    ) -> Result<(), ServiceError> {
+        sqlx::query(
+            "UPDATE annotation_mask
+             SET preview_status = $3, preview_attempts = preview_attempts + 1, preview_error = $4,
+                 next_preview_at = CASE WHEN $5::BIGINT IS NULL THEN next_preview_at
+                                        ELSE CURRENT_TIMESTAMP + $5::BIGINT * INTERVAL '1 second' END
+             WHERE id = $1 AND preview_status = 'PENDING' AND next_preview_at = $2"
+        )
+        .bind(mask_id)
+        .bind(lease_until)
+        .bind(failure_status(retry_in_seconds))
+        .bind(error)
+        .bind(retry_in_seconds)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("record mask preview failure", e))?;
+        Ok(())
+    }
+
+    async fn claim_contact_sheets(&self, limit: i64, lease_seconds: i64) -> Result<Vec<ContactSheetTask>, ServiceError> {
+        sqlx::query_as::<_, ContactSheetTask>(
+            "WITH due AS (
+                 SELECT g.id FROM annotation_mask_group g
+                 WHERE g.contact_sheet_status = 'PENDING' AND g.next_contact_sheet_at <= CURRENT_TIMESTAMP
+                   AND NOT EXISTS (
+                       SELECT 1 FROM annotation_mask m
+                       WHERE m.mask_group_id = g.id AND m.preview_status = 'PENDING'
+                         AND m.validation_status IN ('PENDING', 'VALID')
+                   )
+                 ORDER BY g.next_contact_sheet_at, g.id
+                 LIMIT $1
+                 FOR UPDATE SKIP LOCKED
+             )
+             UPDATE annotation_mask_group g
+             SET next_contact_sheet_at = CURRENT_TIMESTAMP + $2::BIGINT * INTERVAL '1 second'
+             FROM due
+             WHERE g.id = due.id
+             RETURNING g.id AS mask_group_id, g.annotation_id, g.contact_sheet_attempts,
+                       g.next_contact_sheet_at AS lease_until"
+        )
+        .bind(limit)
+        .bind(lease_seconds)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("claim pending contact sheets", e))
+    }
+
+    async fn find_thumbnail_keys(&self, mask_group_id: i32, limit: i64) -> Result<Vec<String>, ServiceError> {
+        sqlx::query_scalar::<_, String>(
+            "SELECT thumbnail_key FROM annotation_mask
+             WHERE mask_group_id = $1 AND preview_status = 'READY' AND validation_status = 'VALID'
+               AND thumbnail_key IS NOT NULL
+             ORDER BY slice_index NULLS LAST, id
+             LIMIT $2"
+        )
+        .bind(mask_group_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get mask thumbnails", e))
+    }
+
+    async fn mark_contact_sheet_ready(
+        &self,
+        task: &ContactSheetTask,
+        contact_sheet_key: Option<&str>,
+        mask_count: i32,
+    ) -> Result<bool, ServiceError> {
+        let updated = sqlx::query(
+            "UPDATE annotation_mask_group
+             SET contact_sheet_status = 'READY', contact_sheet_key = $3, contact_sheet_mask_count = $4,
+                 contact_sheet_error = NULL, contact_sheet_generated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND contact_sheet_status = 'PENDING' AND next_contact_sheet_at = $2"
+        )
+        .bind(task.mask_group_id)
+        .bind(task.lease_until)
+        .bind(contact_sheet_key)
+        .bind(mask_count)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("record contact sheet", e))?;
+        Ok(updated.rows_affected() > 0)
+    }
+
+    async fn record_contact_sheet_failure(
+        &self,
+        mask_group_id: i32,
+        lease_until: DateTime<Utc>,
+        error: &str,
+        retry_in_seconds: Option<i64>,
+    ) -> Result<(), ServiceError> {
+        sqlx::query(
+            "UPDATE annotation_mask_group
+             SET contact_sheet_status = $3, contact_sheet_attempts = contact_sheet_attempts + 1,
+                 contact_sheet_error = $4,
+                 next_contact_sheet_at = CASE WHEN $5::BIGINT IS NULL THEN next_contact_sheet_at
+                                              ELSE CURRENT_TIMESTAMP + $5::BIGINT * INTERVAL '1 second' END
+             WHERE id = $1 AND contact_sheet_status = 'PENDING' AND next_contact_sheet_at = $2"
+        )
+        .bind(mask_group_id)
+        .bind(lease_until)
+        .bind(failure_status(retry_in_seconds))
+        .bind(error)
+        .bind(retry_in_seconds)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("record contact sheet failure", e))?;
+        Ok(())
+    }
+
+    async fn find_contact_sheet(&self, mask_group_id: i32) -> Result<Option<MaskGroupContactSheet>, ServiceError> {
+        sqlx::query_as::<_, MaskGroupContactSheet>(
+            "SELECT id AS mask_group_id, contact_sheet_status, contact_sheet_key, contact_sheet_mask_count,
+                    contact_sheet_error, contact_sheet_generated_at
+             FROM annotation_mask_group
+             WHERE id = $1"
+        )
+        .bind(mask_group_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get contact sheet", e))
+    }
+}
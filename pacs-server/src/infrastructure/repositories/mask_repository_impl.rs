@@ -2,7 +2,10 @@ use async_trait::async_trait;
 use sqlx::PgPool;
 use chrono::{DateTime, Utc};
 use crate::domain::entities::mask::{Mask, NewMask, UpdateMask, MaskStats};
+use crate::domain::entities::mask_preview::MaskPreview;
+use crate::domain::entities::StorageDeletionReason;
 use crate::domain::repositories::MaskRepository;
+use crate::domain::services::{storage_deletion_queue, storage_usage};
 use crate::domain::ServiceError;
 
 /// MaskRepository의 PostgreSQL 구현체
@@ -18,10 +21,26 @@ impl MaskRepositoryImpl {
     }
 }
 
+/// 마스크 그룹의 contact sheet를 다시 만들도록 표시
+async fn mark_contact_sheet_stale(conn: &mut sqlx::PgConnection, mask_group_id: i32) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE annotation_mask_group
+         SET contact_sheet_status = 'PENDING', contact_sheet_attempts = 0, next_contact_sheet_at = CURRENT_TIMESTAMP
+         WHERE id = $1"
+    )
+    .bind(mask_group_id)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
 #[async_trait]
 impl MaskRepository for MaskRepositoryImpl {
     /// 마스크 생성
     async fn create(&self, new_mask: &NewMask) -> Result<Mask, ServiceError> {
+        let map_err = |e: sqlx::Error| ServiceError::DatabaseError(format!("Failed to create mask: {}", e));
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
         let result = sqlx::query!(
             r#"
             INSERT INTO annotation_mask (
@@ -43,9 +62,16 @@ impl MaskRepository for MaskRepositoryImpl {
             new_mask.width,
             new_mask.height
         )
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
-        .map_err(|e| ServiceError::DatabaseError(format!("Failed to create mask: {}", e)))?;
+        .map_err(map_err)?;
+
+        // 행이 커밋되면 사용량도 반드시 함께 반영됨
+        storage_usage::record_mask_change(&mut tx, result.mask_group_id, result.file_size.unwrap_or(0), 1)
+            .await
+            .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
 
         Ok(Mask {
             id: result.id,
@@ -98,6 +124,19 @@ impl MaskRepository for MaskRepositoryImpl {
 
     /// 마스크 업데이트
     async fn update(&self, id: i32, update_mask: &UpdateMask) -> Result<Mask, ServiceError> {
+        let map_err = |e: sqlx::Error| ServiceError::DatabaseError(format!("Failed to update mask: {}", e));
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        // 파일 크기가 바뀌면 차이만큼 사용량 반영
+        let previous = sqlx::query_as::<_, (String, Option<i64>, Option<String>, Option<String>, Option<String>)>(
+            "SELECT file_path, file_size, checksum, thumbnail_key, preview_key FROM annotation_mask WHERE id = $1 FOR UPDATE"
+        )
+        .bind(update_mask.id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?;
+        let previous_size = previous.as_ref().and_then(|(_, file_size, ..)| *file_size);
+
         let result = sqlx::query!(
             r#"
             UPDATE annotation_mask
@@ -126,9 +165,42 @@ impl MaskRepository for MaskRepositoryImpl {
             update_mask.width,
             update_mask.height
         )
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
-        .map_err(|e| ServiceError::DatabaseError(format!("Failed to update mask: {}", e)))?;
+        .map_err(map_err)?;
+
+        let size_delta = result.file_size.unwrap_or(0) - previous_size.unwrap_or(0);
+        storage_usage::record_mask_change(&mut tx, result.mask_group_id, size_delta, 0)
+            .await
+            .map_err(map_err)?;
+
+        // 파일이 바뀌면 서버 검증과 미리보기 생성을 다시 수행
+        if let Some((file_path, file_size, checksum, thumbnail_key, preview_key)) = previous {
+            if file_path != result.file_path || file_size != result.file_size || checksum != result.checksum {
+                sqlx::query(
+                    "UPDATE annotation_mask
+                     SET validation_status = 'PENDING', validation_attempts = 0, next_validation_at = CURRENT_TIMESTAMP,
+                         preview_status = 'PENDING', preview_attempts = 0, preview_error = NULL,
+                         next_preview_at = CURRENT_TIMESTAMP
+                     WHERE id = $1"
+                )
+                .bind(result.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_err)?;
+                mark_contact_sheet_stale(&mut tx, result.mask_group_id).await.map_err(map_err)?;
+
+                // 경로가 바뀌면 이전 경로의 파생 객체는 다시 덮어쓰이지 않으므로 삭제 예약
+                if file_path != result.file_path {
+                    let stale_keys: Vec<String> = thumbnail_key.into_iter().chain(preview_key).collect();
+                    storage_deletion_queue::enqueue_objects(&mut *tx, &stale_keys, StorageDeletionReason::MaskDeleted)
+                        .await
+                        .map_err(map_err)?;
+                }
+            }
+        }
+
+        tx.commit().await.map_err(map_err)?;
 
         Ok(Mask {
             id: result.id,
@@ -149,14 +221,35 @@ impl MaskRepository for MaskRepositoryImpl {
 
     /// 마스크 삭제
     async fn delete(&self, id: i32) -> Result<(), ServiceError> {
-        sqlx::query!(
-            "DELETE FROM annotation_mask WHERE id = $1",
-            id
+        let map_err = |e: sqlx::Error| ServiceError::DatabaseError(format!("Failed to delete mask: {}", e));
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        let deleted = sqlx::query_as::<_, (String, i32, Option<i64>, Option<String>, Option<String>)>(
+            "DELETE FROM annotation_mask WHERE id = $1
+             RETURNING file_path, mask_group_id, file_size, thumbnail_key, preview_key"
         )
-        .execute(&self.pool)
+        .bind(id)
+        .fetch_all(&mut *tx)
         .await
-        .map_err(|e| ServiceError::DatabaseError(format!("Failed to delete mask: {}", e)))?;
+        .map_err(map_err)?;
+
+        let mut file_paths = Vec::with_capacity(deleted.len());
+        for (file_path, mask_group_id, file_size, thumbnail_key, preview_key) in deleted {
+            storage_usage::record_mask_change(&mut tx, mask_group_id, -file_size.unwrap_or(0), -1)
+                .await
+                .map_err(map_err)?;
+            mark_contact_sheet_stale(&mut tx, mask_group_id).await.map_err(map_err)?;
+            file_paths.push(file_path);
+            file_paths.extend(thumbnail_key);
+            file_paths.extend(preview_key);
+        }
 
+        // 행 삭제가 커밋되면 마스크 파일과 파생 객체 삭제도 반드시 예약됨
+        storage_deletion_queue::enqueue_objects(&mut *tx, &file_paths, StorageDeletionReason::MaskDeleted)
+            .await
+            .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
         Ok(())
     }
 
@@ -315,4 +408,17 @@ impl MaskRepository for MaskRepositoryImpl {
 
         Ok(result.unwrap_or(0))
     }
-}
\ No newline at end of file
+
+    /// 마스크들의 썸네일/미리보기 객체 조회
+    async fn find_previews(&self, mask_ids: &[i32]) -> Result<Vec<MaskPreview>, ServiceError> {
+        sqlx::query_as::<_, MaskPreview>(
+            "SELECT id AS mask_id, preview_status, thumbnail_key, preview_key
+             FROM annotation_mask
+             WHERE id = ANY($1)"
+        )
+        .bind(mask_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ServiceError::DatabaseError(format!("Failed to get mask previews: {}", e)))
+    }
+}
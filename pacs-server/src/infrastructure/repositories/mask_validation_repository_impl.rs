@@ -0,0 +1,196 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use crate::domain::entities::{
+    MaskGroupLabelVolume, MaskValidation, MaskValidationOutcome, MaskValidationStatus, MaskValidationTask,
+};
+use crate::domain::repositories::MaskValidationRepository;
+use crate::domain::services::storage_usage;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+#[derive(Clone)]
+pub struct MaskValidationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl MaskValidationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MaskValidationRepository for MaskValidationRepositoryImpl {
+    async fn claim_pending(&self, limit: i64, lease_seconds: i64) -> Result<Vec<MaskValidationTask>, ServiceError> {
+        sqlx::query_as::<_, MaskValidationTask>(
+            "WITH due AS (
+                 SELECT id FROM annotation_mask
+                 WHERE validation_status = 'PENDING' AND next_validation_at <= CURRENT_TIMESTAMP
+                 ORDER BY next_validation_at, id
+                 LIMIT $1
+                 FOR UPDATE SKIP LOCKED
+             )
+             UPDATE annotation_mask m
+             SET next_validation_at = CURRENT_TIMESTAMP + $2::BIGINT * INTERVAL '1 second'
+             FROM due, annotation_mask_group g, annotation_annotation a
+             WHERE m.id = due.id AND g.id = m.mask_group_id AND a.id = g.annotation_id
+             RETURNING m.id AS mask_id, m.mask_group_id, a.project_id, m.sop_instance_uid, m.label_name,
+                       m.file_path, m.checksum, m.width, m.height, m.validation_attempts,
+                       m.next_validation_at AS lease_until"
+        )
+        .bind(limit)
+        .bind(lease_seconds)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("claim pending mask validations", e))
+    }
+
+    async fn find_instance_dimensions(&self, project_id: i32, sop_instance_uid: &str) -> Result<Option<(i32, i32)>, ServiceError> {
+        let dimensions = sqlx::query_as::<_, (Option<i32>, Option<i32>)>(
+            "SELECT rows, columns FROM dicom_instance_metadata WHERE project_id = $1 AND instance_uid = $2"
+        )
+        .bind(project_id)
+        .bind(sop_instance_uid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get instance dimensions", e))?;
+
+        Ok(match dimensions {
+            Some((Some(rows), Some(columns))) => Some((rows, columns)),
+            _ => None,
+        })
+    }
+
+    async fn complete(&self, task: &MaskValidationTask, outcome: &MaskValidationOutcome) -> Result<bool, ServiceError> {
+        let map_err = |e: sqlx::Error| database_error("record mask validation", e);
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        // 가져간 뒤 마스크가 바뀌었으면 임대 시간이 달라지므로 결과를 버림
+        let previous_size = sqlx::query_scalar::<_, Option<i64>>(
+            "SELECT file_size FROM annotation_mask
+             WHERE id = $1 AND validation_status = 'PENDING' AND next_validation_at = $2
+             FOR UPDATE"
+        )
+        .bind(task.mask_id)
+        .bind(task.lease_until)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?;
+        let Some(previous_size) = previous_size else {
+            return Ok(false);
+        };
+
+        let label_stats = match outcome.status {
+            MaskValidationStatus::Valid => Some(serde_json::to_value(&outcome.label_stats).unwrap_or_default()),
+            _ => None,
+        };
+        sqlx::query(
+            "UPDATE annotation_mask
+             SET validation_status = $2, validation_errors = $3, label_stats = $4, content_sha256 = $5,
+                 file_size = COALESCE($6, file_size), width = COALESCE($7, width), height = COALESCE($8, height),
+                 checksum = COALESCE(checksum, $5), validated_at = CURRENT_TIMESTAMP
+             WHERE id = $1"
+        )
+        .bind(task.mask_id)
+        .bind(outcome.status)
+        .bind(serde_json::json!(outcome.errors))
+        .bind(label_stats)
+        .bind(&outcome.content_sha256)
+        .bind(outcome.file_size)
+        .bind(outcome.width)
+        .bind(outcome.height)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        if let Some(file_size) = outcome.file_size {
+            let size_delta = file_size - previous_size.unwrap_or(0);
+            storage_usage::record_mask_change(&mut tx, task.mask_group_id, size_delta, 0)
+                .await
+                .map_err(map_err)?;
+        }
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(true)
+    }
+
+    async fn record_failure(
+        &self,
+        mask_id: i32,
+        lease_until: DateTime<Utc>,
+        error: &str,
+        retry_in_seconds: Option<i64>,
+    ) -> Result<(), ServiceError> {
+        let status = if retry_in_seconds.is_some() {
+            MaskValidationStatus::Pending
+        } else {
+            MaskValidationStatus::Error
+        };
+        sqlx::query(
+            "UPDATE annotation_mask
+             SET validation_status = $3, validation_attempts = validation_attempts + 1,
+                 validation_errors = jsonb_build_array($4::TEXT),
+                 next_validation_at = CASE WHEN $5::BIGINT IS NULL THEN next_validation_at
+                                           ELSE CURRENT_TIMESTAMP + $5::BIGINT * INTERVAL '1 second' END,
+                 validated_at = CASE WHEN $5::BIGINT IS NULL THEN CURRENT_TIMESTAMP ELSE validated_at END
+             WHERE id = $1 AND validation_status = 'PENDING' AND next_validation_at = $2"
+        )
+        .bind(mask_id)
+        .bind(lease_until)
+        .bind(status)
+        .bind(error)
+        .bind(retry_in_seconds)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("record mask validation failure", e))?;
+        Ok(())
+    }
+
+    async fn find_by_mask_group(&self, mask_group_id: i32) -> Result<Vec<MaskValidation>, ServiceError> {
+        sqlx::query_as::<_, MaskValidation>(
+            "SELECT id AS mask_id, mask_group_id, slice_index, label_name, file_path, validation_status,
+                    validation_errors, label_stats, content_sha256, validation_attempts, validated_at
+             FROM annotation_mask
+             WHERE mask_group_id = $1
+             ORDER BY slice_index NULLS LAST, id"
+        )
+        .bind(mask_group_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get mask validations", e))
+    }
+
+    async fn label_volumes(&self, mask_group_id: i32) -> Result<Vec<MaskGroupLabelVolume>, ServiceError> {
+        sqlx::query_as::<_, MaskGroupLabelVolume>(
+            "SELECT (s->>'label_value')::INTEGER AS label_value,
+                    s->>'label_name' AS label_name,
+                    SUM((s->>'voxel_count')::BIGINT)::BIGINT AS voxel_count,
+                    COUNT(*) AS mask_count,
+                    MIN(m.slice_index) AS slice_start,
+                    MAX(m.slice_index) AS slice_end
+             FROM annotation_mask m
+             CROSS JOIN LATERAL jsonb_array_elements(m.label_stats) s
+             WHERE m.mask_group_id = $1 AND m.validation_status = 'VALID'
+             GROUP BY 1, 2
+             ORDER BY 2 NULLS LAST, 1"
+        )
+        .bind(mask_group_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("aggregate mask label volumes", e))
+    }
+
+    async fn requeue_mask_group(&self, mask_group_id: i32) -> Result<u64, ServiceError> {
+        let result = sqlx::query(
+            "UPDATE annotation_mask
+             SET validation_status = 'PENDING', validation_attempts = 0, next_validation_at = CURRENT_TIMESTAMP
+             WHERE mask_group_id = $1"
+        )
+        .bind(mask_group_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("requeue mask validations", e))?;
+        Ok(result.rows_affected())
+    }
+}
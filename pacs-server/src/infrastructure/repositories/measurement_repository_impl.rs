@@ -0,0 +1,200 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::measurement::{AnnotationMeasurement, InstanceMetadata, NewInstanceMetadata};
+use crate::domain::entities::Annotation;
+use crate::domain::repositories::MeasurementRepository;
+use crate::domain::services::measurement_computation;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+const METADATA_COLUMNS: &str = "id, project_id, study_uid, series_uid, instance_uid, pixel_spacing_row, pixel_spacing_column,
+    rows, columns, updated_by, created_at, updated_at";
+
+/// 어노테이션(`a`)
+const ANNOTATION_COLUMNS: &str = "a.id, a.project_id, a.user_id, a.study_uid, a.series_uid, a.instance_uid,
+    a.tool_name, a.tool_version, a.data, a.is_shared, a.created_at, a.updated_at,
+    a.viewer_software, a.description, a.measurement_values,
+    a.review_status, a.reviewer_id, a.reviewed_at";
+
+/// 계산 결과(`m`)
+const MEASUREMENT_COLUMNS: &str = "m.annotation_id, m.geometry_type, m.measurements, m.pixel_spacing_row, m.pixel_spacing_column,
+    m.spacing_source, m.discrepancies, m.has_discrepancy, m.error, m.computed_at";
+
+#[derive(Clone)]
+pub struct MeasurementRepositoryImpl {
+    pool: PgPool,
+}
+
+impl MeasurementRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MeasurementRepository for MeasurementRepositoryImpl {
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project membership", e))
+    }
+
+    async fn upsert_instance_metadata(
+        &self,
+        project_id: i32,
+        instances: &[NewInstanceMetadata],
+        updated_by: i32,
+    ) -> Result<Vec<InstanceMetadata>, ServiceError> {
+        let mut tx = self.pool.begin().await.map_err(|e| database_error("begin transaction", e))?;
+
+        let mut saved = Vec::with_capacity(instances.len());
+        for instance in instances {
+            let metadata = sqlx::query_as::<_, InstanceMetadata>(&format!(
+                "INSERT INTO dicom_instance_metadata (project_id, study_uid, series_uid, instance_uid,
+                                                      pixel_spacing_row, pixel_spacing_column, rows, columns, updated_by)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                 ON CONFLICT (project_id, instance_uid) DO UPDATE
+                 SET study_uid = EXCLUDED.study_uid,
+                     series_uid = EXCLUDED.series_uid,
+                     pixel_spacing_row = EXCLUDED.pixel_spacing_row,
+                     pixel_spacing_column = EXCLUDED.pixel_spacing_column,
+                     rows = EXCLUDED.rows,
+                     columns = EXCLUDED.columns,
+                     updated_by = EXCLUDED.updated_by,
+                     updated_at = CURRENT_TIMESTAMP
+                 RETURNING {}",
+                METADATA_COLUMNS
+            ))
+            .bind(project_id)
+            .bind(&instance.study_uid)
+            .bind(&instance.series_uid)
+            .bind(&instance.instance_uid)
+            .bind(instance.pixel_spacing.row)
+            .bind(instance.pixel_spacing.column)
+            .bind(instance.rows)
+            .bind(instance.columns)
+            .bind(updated_by)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| database_error("save instance metadata", e))?;
+            saved.push(metadata);
+        }
+
+        tx.commit().await.map_err(|e| database_error("commit transaction", e))?;
+        Ok(saved)
+    }
+
+    async fn list_instance_metadata(
+        &self,
+        project_id: i32,
+        study_uid: Option<&str>,
+        series_uid: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<InstanceMetadata>, ServiceError> {
+        sqlx::query_as::<_, InstanceMetadata>(&format!(
+            "SELECT {} FROM dicom_instance_metadata
+             WHERE project_id = $1
+               AND ($2::TEXT IS NULL OR study_uid = $2)
+               AND ($3::TEXT IS NULL OR series_uid = $3)
+             ORDER BY study_uid, series_uid, instance_uid
+             LIMIT $4",
+            METADATA_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(study_uid)
+        .bind(series_uid)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list instance metadata", e))
+    }
+
+    async fn find_annotation(&self, project_id: i32, annotation_id: i32) -> Result<Option<Annotation>, ServiceError> {
+        sqlx::query_as::<_, Annotation>(&format!(
+            "SELECT {} FROM annotation_annotation a
+             WHERE a.id = $1 AND a.project_id = $2 AND a.deleted_at IS NULL",
+            ANNOTATION_COLUMNS
+        ))
+        .bind(annotation_id)
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get annotation", e))
+    }
+
+    async fn list_annotations_for_recompute(
+        &self,
+        project_id: i32,
+        only_missing: bool,
+        after_id: i32,
+        limit: i64,
+    ) -> Result<Vec<Annotation>, ServiceError> {
+        sqlx::query_as::<_, Annotation>(&format!(
+            "SELECT {} FROM annotation_annotation a
+             WHERE a.project_id = $1 AND a.deleted_at IS NULL AND a.id > $2
+               AND (NOT $3 OR NOT EXISTS (SELECT 1 FROM annotation_measurement m WHERE m.annotation_id = a.id))
+             ORDER BY a.id
+             LIMIT $4",
+            ANNOTATION_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(after_id)
+        .bind(only_missing)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list annotations for measurement recompute", e))
+    }
+
+    async fn list_annotations_by_series(&self, project_id: i32, series_uids: &[String]) -> Result<Vec<Annotation>, ServiceError> {
+        sqlx::query_as::<_, Annotation>(&format!(
+            "SELECT {} FROM annotation_annotation a
+             WHERE a.project_id = $1 AND a.deleted_at IS NULL AND a.series_uid = ANY($2)
+             ORDER BY a.id",
+            ANNOTATION_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(series_uids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list annotations by series", e))
+    }
+
+    async fn find_measurement(&self, annotation_id: i32) -> Result<Option<AnnotationMeasurement>, ServiceError> {
+        sqlx::query_as::<_, AnnotationMeasurement>(&format!(
+            "SELECT {} FROM annotation_measurement m WHERE m.annotation_id = $1",
+            MEASUREMENT_COLUMNS
+        ))
+        .bind(annotation_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get annotation measurement", e))
+    }
+
+    async fn recompute(&self, annotation: &Annotation) -> Result<AnnotationMeasurement, ServiceError> {
+        measurement_computation::refresh(&self.pool, annotation)
+            .await
+            .map_err(|e| database_error("compute annotation measurement", e))
+    }
+
+    async fn list_discrepancies(&self, project_id: i32, limit: i64) -> Result<Vec<AnnotationMeasurement>, ServiceError> {
+        sqlx::query_as::<_, AnnotationMeasurement>(&format!(
+            "SELECT {} FROM annotation_measurement m
+             JOIN annotation_annotation a ON a.id = m.annotation_id
+             WHERE a.project_id = $1 AND a.deleted_at IS NULL AND m.has_discrepancy
+             ORDER BY m.computed_at DESC, m.annotation_id DESC
+             LIMIT $2",
+            MEASUREMENT_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list measurement discrepancies", e))
+    }
+}
@@ -1,3 +1,4 @@
+mod database_error;
 mod user_repository_impl;
 mod project_repository_impl;
 mod role_repository_impl;
@@ -5,11 +6,31 @@ mod permission_repository_impl;
 mod capability_repository_impl;
 mod access_log_repository_impl;
 mod annotation_repository_impl;
+mod annotation_bundle_repository_impl;
+mod dataset_export_repository_impl;
+mod dataset_release_repository_impl;
+mod trash_repository_impl;
+mod label_repository_impl;
+mod worklist_repository_impl;
+mod edit_lock_repository_impl;
+mod realtime_repository_impl;
+mod webhook_repository_impl;
+mod measurement_repository_impl;
+mod annotation_propagation_repository_impl;
+mod multipart_upload_repository_impl;
+mod storage_deletion_repository_impl;
+mod storage_reconciliation_repository_impl;
+mod storage_quota_repository_impl;
+mod mask_validation_repository_impl;
+mod mask_preview_repository_impl;
 mod mask_group_repository_impl;
 mod mask_repository_impl;
+mod mask_import_repository_impl;
+mod comment_repository_impl;
 mod project_data_repository_impl;
 mod project_data_access_repository_impl;
 
+pub(crate) use database_error::database_error;
 pub use user_repository_impl::*;
 pub use project_repository_impl::*;
 pub use role_repository_impl::*;
@@ -17,7 +38,26 @@ pub use permission_repository_impl::*;
 pub use capability_repository_impl::*;
 pub use access_log_repository_impl::*;
 pub use annotation_repository_impl::*;
+pub use annotation_bundle_repository_impl::*;
+pub use dataset_export_repository_impl::*;
+pub use dataset_release_repository_impl::*;
+pub use trash_repository_impl::*;
+pub use label_repository_impl::*;
+pub use worklist_repository_impl::*;
+pub use edit_lock_repository_impl::*;
+pub use realtime_repository_impl::*;
+pub use webhook_repository_impl::*;
+pub use measurement_repository_impl::*;
+pub use annotation_propagation_repository_impl::*;
+pub use multipart_upload_repository_impl::*;
+pub use storage_deletion_repository_impl::*;
+pub use storage_reconciliation_repository_impl::*;
+pub use storage_quota_repository_impl::*;
+pub use mask_validation_repository_impl::*;
+pub use mask_preview_repository_impl::*;
 pub use mask_group_repository_impl::*;
 pub use mask_repository_impl::*;
+pub use mask_import_repository_impl::*;
+pub use comment_repository_impl::*;
 pub use project_data_repository_impl::*;
 pub use project_data_access_repository_impl::*;
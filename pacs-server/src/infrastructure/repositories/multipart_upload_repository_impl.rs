@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use crate::domain::entities::{MaskGroupMultipartUpload, NewMaskGroupMultipartUpload};
+use crate::domain::repositories::MultipartUploadRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+const MULTIPART_UPLOAD_COLUMNS: &str = "id, mask_group_id, file_path, upload_id, content_type, part_size, total_size, part_count,
+    status, created_by, created_at, updated_at, expires_at, completed_at";
+
+#[derive(Clone)]
+pub struct MultipartUploadRepositoryImpl {
+    pool: PgPool,
+}
+
+impl MultipartUploadRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 진행 중인 세션의 상태 변경
+    async fn finish(&self, id: i32, status: &str, context: &str) -> Result<Option<MaskGroupMultipartUpload>, ServiceError> {
+        sqlx::query_as::<_, MaskGroupMultipartUpload>(&format!(
+            "UPDATE annotation_mask_group_multipart_upload
+             SET status = $2::multipart_upload_status_enum, updated_at = CURRENT_TIMESTAMP,
+                 completed_at = CASE WHEN $2 = 'COMPLETED' THEN CURRENT_TIMESTAMP END
+             WHERE id = $1 AND status = 'IN_PROGRESS'
+             RETURNING {}",
+            MULTIPART_UPLOAD_COLUMNS
+        ))
+        .bind(id)
+        .bind(status)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error(context, e))
+    }
+}
+
+#[async_trait]
+impl MultipartUploadRepository for MultipartUploadRepositoryImpl {
+    async fn create(&self, new_upload: &NewMaskGroupMultipartUpload) -> Result<MaskGroupMultipartUpload, ServiceError> {
+        sqlx::query_as::<_, MaskGroupMultipartUpload>(&format!(
+            "INSERT INTO annotation_mask_group_multipart_upload
+                 (mask_group_id, file_path, upload_id, content_type, part_size, total_size, part_count, created_by, expires_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             RETURNING {}",
+            MULTIPART_UPLOAD_COLUMNS
+        ))
+        .bind(new_upload.mask_group_id)
+        .bind(&new_upload.file_path)
+        .bind(&new_upload.upload_id)
+        .bind(&new_upload.content_type)
+        .bind(new_upload.part_size)
+        .bind(new_upload.total_size)
+        .bind(new_upload.part_count)
+        .bind(new_upload.created_by)
+        .bind(new_upload.expires_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("create multipart upload", e))
+    }
+
+    async fn find(&self, mask_group_id: i32, id: i32) -> Result<Option<MaskGroupMultipartUpload>, ServiceError> {
+        sqlx::query_as::<_, MaskGroupMultipartUpload>(&format!(
+            "SELECT {} FROM annotation_mask_group_multipart_upload WHERE mask_group_id = $1 AND id = $2",
+            MULTIPART_UPLOAD_COLUMNS
+        ))
+        .bind(mask_group_id)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get multipart upload", e))
+    }
+
+    async fn list_by_mask_group(&self, mask_group_id: i32) -> Result<Vec<MaskGroupMultipartUpload>, ServiceError> {
+        sqlx::query_as::<_, MaskGroupMultipartUpload>(&format!(
+            "SELECT {} FROM annotation_mask_group_multipart_upload
+             WHERE mask_group_id = $1
+             ORDER BY created_at DESC, id DESC",
+            MULTIPART_UPLOAD_COLUMNS
+        ))
+        .bind(mask_group_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list multipart uploads", e))
+    }
+
+    async fn extend_expiry(&self, id: i32, expires_at: DateTime<Utc>) -> Result<Option<MaskGroupMultipartUpload>, ServiceError> {
+        sqlx::query_as::<_, MaskGroupMultipartUpload>(&format!(
+            "UPDATE annotation_mask_group_multipart_upload
+             SET expires_at = GREATEST(expires_at, $2), updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND status = 'IN_PROGRESS'
+             RETURNING {}",
+            MULTIPART_UPLOAD_COLUMNS
+        ))
+        .bind(id)
+        .bind(expires_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("extend multipart upload expiry", e))
+    }
+
+    async fn mark_completed(&self, id: i32) -> Result<Option<MaskGroupMultipartUpload>, ServiceError> {
+        self.finish(id, "COMPLETED", "complete multipart upload").await
+    }
+
+    async fn mark_aborted(&self, id: i32) -> Result<Option<MaskGroupMultipartUpload>, ServiceError> {
+        self.finish(id, "ABORTED", "abort multipart upload").await
+    }
+
+    async fn list_expired(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<MaskGroupMultipartUpload>, ServiceError> {
+        sqlx::query_as::<_, MaskGroupMultipartUpload>(&format!(
+            "SELECT {} FROM annotation_mask_group_multipart_upload
+             WHERE status = 'IN_PROGRESS' AND expires_at <= $1
+             ORDER BY expires_at, id
+             LIMIT $2",
+            MULTIPART_UPLOAD_COLUMNS
+        ))
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list expired multipart uploads", e))
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::entities::project_data::{ProjectDataAccess, NewProjectDataAccess, UpdateProjectDataAccess, DataAccessStatus};
+use crate::domain::entities::webhook::WebhookEventType;
 use crate::domain::repositories::ProjectDataAccessRepository;
 use sqlx::PgPool;
 
@@ -12,14 +13,43 @@ impl ProjectDataAccessRepositoryImpl {
     }
 }
 
+/// 접근 권한 행을 바꾸는 문장(`RETURNING *`)과 함께 Webhook 아웃박스에 `DATA_ACCESS_CHANGED` 이벤트를 기록하는 쿼리
+///
+/// 한 문장으로 실행되므로 변경과 이벤트가 함께 커밋됩니다. 이벤트 종류는 `$1`로 바인딩해야 합니다.
+fn with_webhook_event(statement: &str, columns: &str) -> String {
+    format!(
+        "WITH a AS ({}),
+         e AS (
+             INSERT INTO webhook_outbox_event (project_id, event_type, payload)
+             SELECT pd.project_id, $1,
+                    jsonb_build_object(
+                        'access_id', a.id,
+                        'project_data_id', a.project_data_id,
+                        'study_uid', pd.study_uid,
+                        'user_id', a.user_id,
+                        'status', a.status,
+                        'requested_by', a.requested_by,
+                        'reviewed_by', a.reviewed_by,
+                        'review_note', a.review_note
+                    )
+             FROM a JOIN project_data pd ON pd.id = a.project_data_id
+         )
+         SELECT {} FROM a",
+        statement, columns
+    )
+}
+
 #[async_trait::async_trait]
 impl ProjectDataAccessRepository for ProjectDataAccessRepositoryImpl {
     async fn create(&self, new_access: &NewProjectDataAccess) -> Result<ProjectDataAccess, sqlx::Error> {
-        let result = sqlx::query_as::<_, ProjectDataAccess>(
+        let query = with_webhook_event(
             "INSERT INTO project_data_access (project_data_id, user_id, status, requested_at, requested_by, reviewed_at, reviewed_by, review_note)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-             RETURNING id, project_data_id, user_id, status, requested_at, requested_by, reviewed_at, reviewed_by, review_note, created_at, updated_at"
-        )
+             VALUES ($2, $3, $4, $5, $6, $7, $8, $9)
+             RETURNING *",
+            "id, project_data_id, user_id, status, requested_at, requested_by, reviewed_at, reviewed_by, review_note, created_at, updated_at",
+        );
+        let result = sqlx::query_as::<_, ProjectDataAccess>(&query)
+        .bind(WebhookEventType::DataAccessChanged.as_str())
         .bind(new_access.project_data_id)
         .bind(new_access.user_id)
         .bind(&new_access.status)
@@ -284,16 +314,19 @@ impl ProjectDataAccessRepository for ProjectDataAccessRepositoryImpl {
     ) -> Result<Option<ProjectDataAccess>, sqlx::Error> {
         // Simple approach: update only status field
         if let Some(status) = &update_access.status {
-            let result = sqlx::query_as::<_, ProjectDataAccess>(
+            let query = with_webhook_event(
                 "UPDATE project_data_access 
-                 SET status = $1::data_access_status_enum,
-                     reviewed_at = COALESCE($2, reviewed_at),
-                     reviewed_by = COALESCE($3, reviewed_by),
-                     review_note = COALESCE($4, review_note),
+                 SET status = $2::data_access_status_enum,
+                     reviewed_at = COALESCE($3, reviewed_at),
+                     reviewed_by = COALESCE($4, reviewed_by),
+                     review_note = COALESCE($5, review_note),
                      updated_at = CURRENT_TIMESTAMP
-                 WHERE project_data_id = $5 AND user_id = $6
-                 RETURNING id, 0 as project_id, user_id, resource_level, study_id, series_id, status, requested_at, requested_by, reviewed_at, reviewed_by, review_note, created_at, updated_at, project_data_id"
-            )
+                 WHERE project_data_id = $6 AND user_id = $7
+                 RETURNING *",
+                "id, 0 as project_id, user_id, resource_level, study_id, series_id, status, requested_at, requested_by, reviewed_at, reviewed_by, review_note, created_at, updated_at, project_data_id",
+            );
+            let result = sqlx::query_as::<_, ProjectDataAccess>(&query)
+            .bind(WebhookEventType::DataAccessChanged.as_str())
             .bind(status)
             .bind(&update_access.reviewed_at)
             .bind(&update_access.reviewed_by)
@@ -443,6 +443,19 @@ impl ProjectRepository for ProjectRepositoryImpl {
         let result = query_builder.fetch_one(&self.pool).await?;
         Ok(result.0)
     }
+
+    async fn find_member_role(&self, project_id: i32, user_id: i32) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar::<_, String>(
+            "SELECT r.name
+             FROM security_user_project up
+             JOIN security_role r ON r.id = up.role_id
+             WHERE up.user_id = $1 AND up.project_id = $2"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await
+    }
     
     fn pool(&self) -> &PgPool {
         &self.pool
@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use tokio::sync::broadcast;
+use crate::domain::entities::realtime::{RealtimeEvent, REALTIME_CHANNEL};
+use crate::domain::repositories::RealtimeRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+#[derive(Clone)]
+pub struct RealtimeRepositoryImpl {
+    pool: PgPool,
+}
+
+impl RealtimeRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RealtimeRepository for RealtimeRepositoryImpl {
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project membership", e))
+    }
+
+    async fn listen(&self, sender: &broadcast::Sender<RealtimeEvent>) -> Result<(), ServiceError> {
+        let mut listener = PgListener::connect_with(&self.pool)
+            .await
+            .map_err(|e| database_error("connect realtime listener", e))?;
+        listener
+            .listen(REALTIME_CHANNEL)
+            .await
+            .map_err(|e| database_error("listen for realtime events", e))?;
+
+        // 연결이 끊기면 recv가 다시 연결함 (끊긴 동안의 이벤트는 유실됨)
+        loop {
+            let notification = listener
+                .recv()
+                .await
+                .map_err(|e| database_error("receive realtime event", e))?;
+            match serde_json::from_str::<RealtimeEvent>(notification.payload()) {
+                // 구독자가 없으면 전달하지 않음
+                Ok(event) => {
+                    sender.send(event).ok();
+                }
+                Err(e) => eprintln!("Ignoring malformed realtime event: {}", e),
+            }
+        }
+    }
+}
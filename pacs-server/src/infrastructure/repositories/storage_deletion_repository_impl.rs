@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::{StorageDeletion, StorageDeletionStatus};
+use crate::domain::repositories::StorageDeletionRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+const STORAGE_DELETION_COLUMNS: &str = "id, object_key, is_prefix, reason, status, attempt_count, next_attempt_at,
+    last_error, created_at, updated_at, deleted_at";
+
+#[derive(Clone)]
+pub struct StorageDeletionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl StorageDeletionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StorageDeletionRepository for StorageDeletionRepositoryImpl {
+    async fn claim_due(&self, limit: i64, lease_seconds: i64) -> Result<Vec<StorageDeletion>, ServiceError> {
+        sqlx::query_as::<_, StorageDeletion>(
+            "WITH due AS (
+                 SELECT id FROM storage_deletion_queue
+                 WHERE status = 'PENDING' AND next_attempt_at <= CURRENT_TIMESTAMP
+                 ORDER BY next_attempt_at, id
+                 LIMIT $1
+                 FOR UPDATE SKIP LOCKED
+             )
+             UPDATE storage_deletion_queue q
+             SET next_attempt_at = CURRENT_TIMESTAMP + $2::BIGINT * INTERVAL '1 second'
+             FROM due
+             WHERE q.id = due.id
+             RETURNING q.*"
+        )
+        .bind(limit)
+        .bind(lease_seconds)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("claim storage deletions", e))
+    }
+
+    async fn mark_deleted(&self, id: i64) -> Result<(), ServiceError> {
+        sqlx::query(
+            "UPDATE storage_deletion_queue
+             SET status = 'DELETED', attempt_count = attempt_count + 1, last_error = NULL,
+                 deleted_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1"
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("mark storage deletion done", e))?;
+        Ok(())
+    }
+
+    async fn record_failure(&self, id: i64, error: &str, retry_in_seconds: Option<i64>) -> Result<StorageDeletion, ServiceError> {
+        let status = if retry_in_seconds.is_some() {
+            StorageDeletionStatus::Pending
+        } else {
+            StorageDeletionStatus::Dead
+        };
+        sqlx::query_as::<_, StorageDeletion>(&format!(
+            "UPDATE storage_deletion_queue
+             SET status = $2, attempt_count = attempt_count + 1, last_error = $3,
+                 next_attempt_at = CASE WHEN $4::BIGINT IS NULL THEN next_attempt_at
+                                        ELSE CURRENT_TIMESTAMP + $4::BIGINT * INTERVAL '1 second' END,
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1
+             RETURNING {}",
+            STORAGE_DELETION_COLUMNS
+        ))
+        .bind(id)
+        .bind(status)
+        .bind(error)
+        .bind(retry_in_seconds)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("record storage deletion failure", e))?
+        .ok_or_else(|| ServiceError::NotFound(format!("Storage deletion with ID {} not found", id)))
+    }
+}
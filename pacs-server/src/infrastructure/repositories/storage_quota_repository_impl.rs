@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::{StorageQuota, StorageQuotaScope, StorageUsage, StorageUsageTotals};
+use crate::domain::repositories::StorageQuotaRepository;
+use crate::domain::services::storage_usage;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+#[derive(Clone)]
+pub struct StorageQuotaRepositoryImpl {
+    pool: PgPool,
+}
+
+impl StorageQuotaRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 범위에 해당하는 `storage_usage`/`storage_quota` 컬럼
+fn scope_column(scope: StorageQuotaScope) -> &'static str {
+    match scope {
+        StorageQuotaScope::Project => "project_id",
+        StorageQuotaScope::User => "user_id",
+    }
+}
+
+#[async_trait]
+impl StorageQuotaRepository for StorageQuotaRepositoryImpl {
+    async fn scope_exists(&self, scope: StorageQuotaScope, scope_id: i32) -> Result<bool, ServiceError> {
+        let table = match scope {
+            StorageQuotaScope::Project => "security_project",
+            StorageQuotaScope::User => "security_user",
+        };
+        sqlx::query_scalar::<_, bool>(&format!("SELECT EXISTS (SELECT 1 FROM {} WHERE id = $1)", table))
+            .bind(scope_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| database_error("check storage quota scope", e))
+    }
+
+    async fn get_usage_totals(&self, scope: StorageQuotaScope, scope_id: i32) -> Result<StorageUsageTotals, ServiceError> {
+        sqlx::query_as::<_, StorageUsageTotals>(&format!(
+            "SELECT COALESCE(SUM(bytes_used), 0)::BIGINT AS bytes_used, COALESCE(SUM(object_count), 0)::BIGINT AS object_count
+             FROM storage_usage WHERE {} = $1",
+            scope_column(scope)
+        ))
+        .bind(scope_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("get storage usage", e))
+    }
+
+    async fn list_usage(&self, scope: StorageQuotaScope, scope_id: i32) -> Result<Vec<StorageUsage>, ServiceError> {
+        sqlx::query_as::<_, StorageUsage>(&format!(
+            "SELECT project_id, user_id, bytes_used, object_count, updated_at
+             FROM storage_usage
+             WHERE {} = $1
+             ORDER BY bytes_used DESC, project_id, user_id",
+            scope_column(scope)
+        ))
+        .bind(scope_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list storage usage", e))
+    }
+
+    async fn find_quota(&self, scope: StorageQuotaScope, scope_id: i32) -> Result<Option<StorageQuota>, ServiceError> {
+        sqlx::query_as::<_, StorageQuota>(&format!(
+            "SELECT * FROM storage_quota WHERE {} = $1",
+            scope_column(scope)
+        ))
+        .bind(scope_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get storage quota", e))
+    }
+
+    async fn upsert_quota(
+        &self,
+        scope: StorageQuotaScope,
+        scope_id: i32,
+        soft_limit_bytes: Option<i64>,
+        hard_limit_bytes: Option<i64>,
+        updated_by: i32,
+    ) -> Result<StorageQuota, ServiceError> {
+        let column = scope_column(scope);
+        sqlx::query_as::<_, StorageQuota>(&format!(
+            "INSERT INTO storage_quota ({column}, soft_limit_bytes, hard_limit_bytes, updated_by)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT ({column}) WHERE {column} IS NOT NULL DO UPDATE
+             SET soft_limit_bytes = EXCLUDED.soft_limit_bytes,
+                 hard_limit_bytes = EXCLUDED.hard_limit_bytes,
+                 alerted_at = CASE
+                     WHEN storage_quota.soft_limit_bytes IS NOT DISTINCT FROM EXCLUDED.soft_limit_bytes
+                     THEN storage_quota.alerted_at
+                 END,
+                 updated_by = EXCLUDED.updated_by,
+                 updated_at = CURRENT_TIMESTAMP
+             RETURNING *",
+            column = column
+        ))
+        .bind(scope_id)
+        .bind(soft_limit_bytes)
+        .bind(hard_limit_bytes)
+        .bind(updated_by)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("set storage quota", e))
+    }
+
+    async fn recalculate_usage(&self) -> Result<u64, ServiceError> {
+        let map_err = |e: sqlx::Error| database_error("recalculate storage usage", e);
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        // 계산하는 동안 마스크 변경이 사용량을 갱신하지 못하도록 잠금
+        sqlx::query("LOCK TABLE storage_usage IN EXCLUSIVE MODE")
+            .execute(&mut *tx)
+            .await
+            .map_err(map_err)?;
+        let rows = storage_usage::recalculate(&mut tx).await.map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(rows)
+    }
+}
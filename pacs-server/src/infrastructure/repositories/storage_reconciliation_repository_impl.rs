@@ -0,0 +1,220 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::{
+    NewStorageReconciliationFinding, ReconciliationMaskRow, StorageDeletionReason, StorageOrphanAction,
+    StorageReconciliationCounts, StorageReconciliationFinding, StorageReconciliationRun,
+};
+use crate::domain::repositories::StorageReconciliationRepository;
+use crate::domain::services::storage_deletion_queue;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+#[derive(Clone)]
+pub struct StorageReconciliationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl StorageReconciliationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StorageReconciliationRepository for StorageReconciliationRepositoryImpl {
+    async fn start_run(
+        &self,
+        triggered_by: Option<i32>,
+        orphan_action: StorageOrphanAction,
+        grace_period_hours: i32,
+        stale_after_seconds: i64,
+    ) -> Result<Option<StorageReconciliationRun>, ServiceError> {
+        let mut tx = self.pool.begin().await.map_err(|e| database_error("start reconciliation", e))?;
+
+        // 여러 워커가 동시에 시작하지 않도록 직렬화
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext('storage_reconciliation'))")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| database_error("lock reconciliation", e))?;
+
+        let running: bool = sqlx::query_scalar(
+            "SELECT EXISTS (
+                 SELECT 1 FROM storage_reconciliation_run
+                 WHERE status = 'RUNNING' AND started_at > CURRENT_TIMESTAMP - $1::BIGINT * INTERVAL '1 second'
+             )"
+        )
+        .bind(stale_after_seconds)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| database_error("check running reconciliation", e))?;
+        if running {
+            return Ok(None);
+        }
+
+        // 서버 재시작 등으로 끝나지 못한 실행은 실패로 정리
+        sqlx::query(
+            "UPDATE storage_reconciliation_run
+             SET status = 'FAILED', error = 'Abandoned before completion', finished_at = CURRENT_TIMESTAMP
+             WHERE status = 'RUNNING'"
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| database_error("close abandoned reconciliations", e))?;
+
+        let run = sqlx::query_as::<_, StorageReconciliationRun>(
+            "INSERT INTO storage_reconciliation_run (orphan_action, grace_period_hours, triggered_by)
+             VALUES ($1, $2, $3)
+             RETURNING *"
+        )
+        .bind(orphan_action)
+        .bind(grace_period_hours)
+        .bind(triggered_by)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| database_error("create reconciliation run", e))?;
+
+        tx.commit().await.map_err(|e| database_error("start reconciliation", e))?;
+        Ok(Some(run))
+    }
+
+    async fn finish_run(
+        &self,
+        run_id: i32,
+        counts: &StorageReconciliationCounts,
+        error: Option<&str>,
+    ) -> Result<StorageReconciliationRun, ServiceError> {
+        sqlx::query_as::<_, StorageReconciliationRun>(
+            "UPDATE storage_reconciliation_run
+             SET status = CASE WHEN $11::TEXT IS NULL THEN 'COMPLETED' ELSE 'FAILED' END::storage_reconciliation_status_enum,
+                 objects_scanned = $2, rows_scanned = $3, recent_skipped = $4, orphan_objects = $5,
+                 dangling_rows = $6, size_mismatches = $7, checksum_mismatches = $8,
+                 orphans_quarantined = $9, orphans_queued_for_deletion = $10,
+                 error = $11, finished_at = CURRENT_TIMESTAMP
+             WHERE id = $1
+             RETURNING *"
+        )
+        .bind(run_id)
+        .bind(counts.objects_scanned)
+        .bind(counts.rows_scanned)
+        .bind(counts.recent_skipped)
+        .bind(counts.orphan_objects)
+        .bind(counts.dangling_rows)
+        .bind(counts.size_mismatches)
+        .bind(counts.checksum_mismatches)
+        .bind(counts.orphans_quarantined)
+        .bind(counts.orphans_queued_for_deletion)
+        .bind(error)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("finish reconciliation run", e))?
+        .ok_or_else(|| ServiceError::NotFound(format!("Reconciliation run with ID {} not found", run_id)))
+    }
+
+    async fn list_runs(&self, limit: i64) -> Result<Vec<StorageReconciliationRun>, ServiceError> {
+        sqlx::query_as::<_, StorageReconciliationRun>(
+            "SELECT * FROM storage_reconciliation_run ORDER BY started_at DESC, id DESC LIMIT $1"
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list reconciliation runs", e))
+    }
+
+    async fn find_run(&self, run_id: i32) -> Result<Option<StorageReconciliationRun>, ServiceError> {
+        sqlx::query_as::<_, StorageReconciliationRun>("SELECT * FROM storage_reconciliation_run WHERE id = $1")
+            .bind(run_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("get reconciliation run", e))
+    }
+
+    async fn find_unreferenced_objects(&self, object_keys: &[String]) -> Result<Vec<String>, ServiceError> {
+        if object_keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        sqlx::query_scalar::<_, String>(
+            "SELECT key FROM UNNEST($1::TEXT[]) AS key
+             WHERE NOT EXISTS (SELECT 1 FROM annotation_mask m WHERE m.file_path = key)
+               AND NOT EXISTS (
+                   SELECT 1 FROM annotation_annotation a
+                   WHERE a.id = SUBSTRING(key FROM '^annotations/annotation_([0-9]{1,9})/')::INTEGER
+               )
+               AND NOT EXISTS (
+                   SELECT 1 FROM storage_deletion_queue q
+                   WHERE q.status = 'PENDING'
+                     AND (q.object_key = key OR (q.is_prefix AND STARTS_WITH(key, q.object_key)))
+               )
+             ORDER BY key"
+        )
+        .bind(object_keys)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("find unreferenced objects", e))
+    }
+
+    async fn list_masks_after(&self, after_id: i32, limit: i64) -> Result<Vec<ReconciliationMaskRow>, ServiceError> {
+        sqlx::query_as::<_, ReconciliationMaskRow>(
+            "SELECT id, file_path, file_size, checksum, created_at
+             FROM annotation_mask
+             WHERE id > $1
+             ORDER BY id
+             LIMIT $2"
+        )
+        .bind(after_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list masks for reconciliation", e))
+    }
+
+    async fn insert_findings(&self, run_id: i32, findings: &[NewStorageReconciliationFinding]) -> Result<(), ServiceError> {
+        if findings.is_empty() {
+            return Ok(());
+        }
+        let kinds: Vec<&str> = findings.iter().map(|f| f.kind.as_str()).collect();
+        let object_keys: Vec<&str> = findings.iter().map(|f| f.object_key.as_str()).collect();
+        let mask_ids: Vec<Option<i32>> = findings.iter().map(|f| f.mask_id).collect();
+        let expected: Vec<Option<&str>> = findings.iter().map(|f| f.expected.as_deref()).collect();
+        let actual: Vec<Option<&str>> = findings.iter().map(|f| f.actual.as_deref()).collect();
+        let actions: Vec<&str> = findings.iter().map(|f| f.action.as_str()).collect();
+        let quarantine_keys: Vec<Option<&str>> = findings.iter().map(|f| f.quarantine_key.as_deref()).collect();
+
+        sqlx::query(
+            "INSERT INTO storage_reconciliation_finding
+                 (run_id, kind, object_key, mask_id, expected, actual, action, quarantine_key)
+             SELECT $1, * FROM UNNEST(
+                 $2::TEXT[]::storage_reconciliation_finding_kind_enum[], $3::TEXT[], $4::INTEGER[], $5::TEXT[], $6::TEXT[],
+                 $7::TEXT[]::storage_orphan_action_enum[], $8::TEXT[]
+             )"
+        )
+        .bind(run_id)
+        .bind(kinds)
+        .bind(object_keys)
+        .bind(mask_ids)
+        .bind(expected)
+        .bind(actual)
+        .bind(actions)
+        .bind(quarantine_keys)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("record reconciliation findings", e))?;
+        Ok(())
+    }
+
+    async fn list_findings(&self, run_id: i32, limit: i64) -> Result<Vec<StorageReconciliationFinding>, ServiceError> {
+        sqlx::query_as::<_, StorageReconciliationFinding>(
+            "SELECT * FROM storage_reconciliation_finding WHERE run_id = $1 ORDER BY id LIMIT $2"
+        )
+        .bind(run_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list reconciliation findings", e))
+    }
+
+    async fn enqueue_orphan_deletions(&self, object_keys: &[String]) -> Result<u64, ServiceError> {
+        storage_deletion_queue::enqueue_objects(&self.pool, object_keys, StorageDeletionReason::ReconciliationOrphan)
+            .await
+            .map_err(|e| database_error("queue orphan deletions", e))
+    }
+}
@@ -0,0 +1,323 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use crate::domain::entities::{
+    annotation_derived_prefix, annotation_mask_prefix, mask_group_derived_prefix, mask_group_mask_prefix,
+    StorageDeletionReason, TrashedAnnotation, TrashedMaskGroup,
+};
+use crate::domain::repositories::TrashRepository;
+use crate::domain::services::{storage_deletion_queue, storage_usage};
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+const TRASHED_ANNOTATION_COLUMNS: &str = "a.id, a.project_id, a.user_id, a.study_uid, a.series_uid, a.tool_name,
+    (SELECT COUNT(*) FROM annotation_mask_group g WHERE g.annotation_id = a.id) AS mask_group_count,
+    a.deleted_at, a.deleted_by";
+
+const TRASHED_MASK_GROUP_COLUMNS: &str = "g.id, g.annotation_id, g.group_name, g.model_name,
+    (SELECT COUNT(*) FROM annotation_mask m WHERE m.mask_group_id = g.id) AS mask_count,
+    g.deleted_at, g.deleted_by";
+
+#[derive(Clone)]
+pub struct TrashRepositoryImpl {
+    pool: PgPool,
+}
+
+impl TrashRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TrashRepository for TrashRepositoryImpl {
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project membership", e))
+    }
+
+    async fn list_annotations(&self, project_id: i32, viewer_id: i32) -> Result<Vec<TrashedAnnotation>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_annotation a
+             WHERE a.project_id = $1 AND a.deleted_at IS NOT NULL AND (a.is_shared OR a.user_id = $2)
+             ORDER BY a.deleted_at DESC, a.id DESC",
+            TRASHED_ANNOTATION_COLUMNS
+        );
+
+        sqlx::query_as::<_, TrashedAnnotation>(&query)
+            .bind(project_id)
+            .bind(viewer_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("list trashed annotations", e))
+    }
+
+    async fn list_mask_groups(&self, project_id: i32, viewer_id: i32) -> Result<Vec<TrashedMaskGroup>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_mask_group g
+             JOIN annotation_annotation a ON a.id = g.annotation_id
+             WHERE a.project_id = $1 AND a.deleted_at IS NULL AND g.deleted_at IS NOT NULL
+               AND (a.is_shared OR a.user_id = $2)
+             ORDER BY g.deleted_at DESC, g.id DESC",
+            TRASHED_MASK_GROUP_COLUMNS
+        );
+
+        sqlx::query_as::<_, TrashedMaskGroup>(&query)
+            .bind(project_id)
+            .bind(viewer_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("list trashed mask groups", e))
+    }
+
+    async fn find_annotation(&self, project_id: i32, annotation_id: i32, viewer_id: i32) -> Result<Option<TrashedAnnotation>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_annotation a
+             WHERE a.id = $1 AND a.project_id = $2 AND a.deleted_at IS NOT NULL AND (a.is_shared OR a.user_id = $3)",
+            TRASHED_ANNOTATION_COLUMNS
+        );
+
+        sqlx::query_as::<_, TrashedAnnotation>(&query)
+            .bind(annotation_id)
+            .bind(project_id)
+            .bind(viewer_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("find trashed annotation", e))
+    }
+
+    async fn find_mask_group(&self, project_id: i32, mask_group_id: i32, viewer_id: i32) -> Result<Option<TrashedMaskGroup>, ServiceError> {
+        let query = format!(
+            "SELECT {} FROM annotation_mask_group g
+             JOIN annotation_annotation a ON a.id = g.annotation_id
+             WHERE g.id = $1 AND a.project_id = $2 AND g.deleted_at IS NOT NULL AND (a.is_shared OR a.user_id = $3)",
+            TRASHED_MASK_GROUP_COLUMNS
+        );
+
+        sqlx::query_as::<_, TrashedMaskGroup>(&query)
+            .bind(mask_group_id)
+            .bind(project_id)
+            .bind(viewer_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("find trashed mask group", e))
+    }
+
+    async fn restore_annotation(&self, annotation_id: i32, restored_by: i32) -> Result<bool, ServiceError> {
+        let map_err = |e: sqlx::Error| database_error("restore annotation", e);
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        let trashed = sqlx::query_as::<_, (DateTime<Utc>, serde_json::Value)>(
+            "SELECT deleted_at, data FROM annotation_annotation
+             WHERE id = $1 AND deleted_at IS NOT NULL
+             FOR UPDATE"
+        )
+        .bind(annotation_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        let Some((deleted_at, data)) = trashed else {
+            tx.commit().await.map_err(map_err)?;
+            return Ok(false);
+        };
+
+        sqlx::query("UPDATE annotation_annotation SET deleted_at = NULL, deleted_by = NULL WHERE id = $1")
+            .bind(annotation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_err)?;
+
+        // 어노테이션과 같은 시간에 삭제된(함께 휴지통으로 이동한) 마스크 그룹만 복원
+        sqlx::query(
+            "UPDATE annotation_mask_group SET deleted_at = NULL, deleted_by = NULL
+             WHERE annotation_id = $1 AND deleted_at = $2"
+        )
+        .bind(annotation_id)
+        .bind(deleted_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        sqlx::query(
+            "INSERT INTO annotation_annotation_history (annotation_id, user_id, action, data_before, data_after)
+             VALUES ($1, $2, 'restore', NULL, $3)"
+        )
+        .bind(annotation_id)
+        .bind(restored_by)
+        .bind(data)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(true)
+    }
+
+    async fn restore_mask_group(&self, mask_group_id: i32) -> Result<bool, ServiceError> {
+        let result = sqlx::query(
+            "UPDATE annotation_mask_group g SET deleted_at = NULL, deleted_by = NULL
+             FROM annotation_annotation a
+             WHERE g.id = $1 AND g.deleted_at IS NOT NULL AND a.id = g.annotation_id AND a.deleted_at IS NULL"
+        )
+        .bind(mask_group_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("restore mask group", e))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_expired_annotations(&self, cutoff: DateTime<Utc>, limit: i64) -> Result<Vec<i32>, ServiceError> {
+        sqlx::query_scalar::<_, i32>(
+            "SELECT id FROM annotation_annotation
+             WHERE deleted_at IS NOT NULL AND deleted_at < $1
+             ORDER BY deleted_at
+             LIMIT $2"
+        )
+        .bind(cutoff)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("find expired trashed annotations", e))
+    }
+
+    async fn find_expired_mask_groups(&self, cutoff: DateTime<Utc>, limit: i64) -> Result<Vec<i32>, ServiceError> {
+        sqlx::query_scalar::<_, i32>(
+            "SELECT g.id FROM annotation_mask_group g
+             JOIN annotation_annotation a ON a.id = g.annotation_id
+             WHERE g.deleted_at IS NOT NULL AND g.deleted_at < $1 AND a.deleted_at IS NULL
+             ORDER BY g.deleted_at
+             LIMIT $2"
+        )
+        .bind(cutoff)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("find expired trashed mask groups", e))
+    }
+
+    async fn purge_annotation(&self, annotation_id: i32, cutoff: DateTime<Utc>) -> Result<Option<u64>, ServiceError> {
+        let map_err = |e: sqlx::Error| database_error("purge annotation", e);
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        // 행을 잠근 상태에서 대상 여부를 다시 확인하여 복원과 경합하지 않도록 함
+        let eligible = sqlx::query_scalar::<_, i32>(
+            "SELECT id FROM annotation_annotation
+             WHERE id = $1 AND deleted_at IS NOT NULL AND deleted_at < $2
+             FOR UPDATE"
+        )
+        .bind(annotation_id)
+        .bind(cutoff)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        if eligible.is_none() {
+            tx.commit().await.map_err(map_err)?;
+            return Ok(None);
+        }
+
+        let file_paths = sqlx::query_scalar::<_, String>(
+            "SELECT m.file_path FROM annotation_mask m
+             JOIN annotation_mask_group g ON g.id = m.mask_group_id
+             WHERE g.annotation_id = $1"
+        )
+        .bind(annotation_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        let mask_group_ids = sqlx::query_scalar::<_, i32>(
+            "SELECT id FROM annotation_mask_group WHERE annotation_id = $1"
+        )
+        .bind(annotation_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(map_err)?;
+        storage_usage::release_mask_groups(&mut tx, &mask_group_ids)
+            .await
+            .map_err(map_err)?;
+
+        // 마스크 그룹/마스크는 ON DELETE CASCADE로 함께 삭제됨
+        sqlx::query("DELETE FROM annotation_annotation WHERE id = $1")
+            .bind(annotation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_err)?;
+
+        // 행이 없는 업로드 잔여물과 파생 객체까지 지우도록 어노테이션 경로 전체도 예약
+        let reason = StorageDeletionReason::AnnotationPurged;
+        let queued = storage_deletion_queue::enqueue_objects(&mut *tx, &file_paths, reason)
+            .await
+            .map_err(map_err)?;
+        storage_deletion_queue::enqueue_prefix(&mut *tx, &annotation_mask_prefix(annotation_id), reason)
+            .await
+            .map_err(map_err)?;
+        storage_deletion_queue::enqueue_prefix(&mut *tx, &annotation_derived_prefix(annotation_id), reason)
+            .await
+            .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(Some(queued))
+    }
+
+    async fn purge_mask_group(&self, mask_group_id: i32, cutoff: DateTime<Utc>) -> Result<Option<u64>, ServiceError> {
+        let map_err = |e: sqlx::Error| database_error("purge mask group", e);
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        let annotation_id = sqlx::query_scalar::<_, i32>(
+            "SELECT annotation_id FROM annotation_mask_group
+             WHERE id = $1 AND deleted_at IS NOT NULL AND deleted_at < $2
+             FOR UPDATE"
+        )
+        .bind(mask_group_id)
+        .bind(cutoff)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        let Some(annotation_id) = annotation_id else {
+            tx.commit().await.map_err(map_err)?;
+            return Ok(None);
+        };
+
+        let file_paths = sqlx::query_scalar::<_, String>(
+            "SELECT file_path FROM annotation_mask WHERE mask_group_id = $1"
+        )
+        .bind(mask_group_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        storage_usage::release_mask_groups(&mut tx, &[mask_group_id])
+            .await
+            .map_err(map_err)?;
+
+        sqlx::query("DELETE FROM annotation_mask_group WHERE id = $1")
+            .bind(mask_group_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_err)?;
+
+        let reason = StorageDeletionReason::MaskGroupPurged;
+        let queued = storage_deletion_queue::enqueue_objects(&mut *tx, &file_paths, reason)
+            .await
+            .map_err(map_err)?;
+        storage_deletion_queue::enqueue_prefix(&mut *tx, &mask_group_mask_prefix(annotation_id, mask_group_id), reason)
+            .await
+            .map_err(map_err)?;
+        storage_deletion_queue::enqueue_prefix(&mut *tx, &mask_group_derived_prefix(annotation_id, mask_group_id), reason)
+            .await
+            .map_err(map_err)?;
+
+        tx.commit().await.map_err(map_err)?;
+        Ok(Some(queued))
+    }
+}
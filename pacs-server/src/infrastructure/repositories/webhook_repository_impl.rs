@@ -0,0 +1,293 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::webhook::{
+    ClaimedWebhookDelivery, NewWebhookSubscription, UpdateWebhookSubscription, WebhookAttemptOutcome, WebhookDelivery,
+    WebhookDeliveryAttempt, WebhookDeliveryStatus, WebhookEventType, WebhookSubscription,
+};
+use crate::domain::repositories::WebhookRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+const SUBSCRIPTION_COLUMNS: &str = "id, project_id, name, url, secret, event_types, is_active, created_by, created_at, updated_at";
+
+/// 전송(`d`)과 이벤트(`e`) 종류
+const DELIVERY_COLUMNS: &str = "d.id, d.subscription_id, d.event_id, e.event_type, d.status, d.attempt_count, d.next_attempt_at,
+    d.last_status_code, d.last_error, d.created_at, d.updated_at, d.delivered_at";
+
+#[derive(Clone)]
+pub struct WebhookRepositoryImpl {
+    pool: PgPool,
+}
+
+impl WebhookRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn event_type_names(event_types: &[WebhookEventType]) -> Vec<String> {
+    event_types.iter().map(|event_type| event_type.as_str().to_string()).collect()
+}
+
+/// 전송 행을 바꾸는 문장(`RETURNING *`)의 결과를 이벤트 종류와 함께 조회하는 쿼리
+fn with_event(statement: &str) -> String {
+    format!(
+        "WITH d AS ({}) SELECT {} FROM d JOIN webhook_outbox_event e ON e.id = d.event_id",
+        statement, DELIVERY_COLUMNS
+    )
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookRepositoryImpl {
+    async fn create_subscription(&self, new_subscription: &NewWebhookSubscription) -> Result<WebhookSubscription, ServiceError> {
+        sqlx::query_as::<_, WebhookSubscription>(&format!(
+            "INSERT INTO webhook_subscription (project_id, name, url, secret, event_types, created_by)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             RETURNING {}",
+            SUBSCRIPTION_COLUMNS
+        ))
+        .bind(new_subscription.project_id)
+        .bind(&new_subscription.name)
+        .bind(&new_subscription.url)
+        .bind(&new_subscription.secret)
+        .bind(event_type_names(&new_subscription.event_types))
+        .bind(new_subscription.created_by)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("create webhook subscription", e))
+    }
+
+    async fn list_subscriptions(&self, project_id: i32) -> Result<Vec<WebhookSubscription>, ServiceError> {
+        sqlx::query_as::<_, WebhookSubscription>(&format!(
+            "SELECT {} FROM webhook_subscription WHERE project_id = $1 ORDER BY id",
+            SUBSCRIPTION_COLUMNS
+        ))
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list webhook subscriptions", e))
+    }
+
+    async fn find_subscription(&self, project_id: i32, subscription_id: i32) -> Result<Option<WebhookSubscription>, ServiceError> {
+        sqlx::query_as::<_, WebhookSubscription>(&format!(
+            "SELECT {} FROM webhook_subscription WHERE id = $1 AND project_id = $2",
+            SUBSCRIPTION_COLUMNS
+        ))
+        .bind(subscription_id)
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get webhook subscription", e))
+    }
+
+    async fn update_subscription(
+        &self,
+        project_id: i32,
+        subscription_id: i32,
+        update: &UpdateWebhookSubscription,
+    ) -> Result<Option<WebhookSubscription>, ServiceError> {
+        sqlx::query_as::<_, WebhookSubscription>(&format!(
+            "UPDATE webhook_subscription
+             SET name = COALESCE($3, name),
+                 url = COALESCE($4, url),
+                 event_types = COALESCE($5, event_types),
+                 is_active = COALESCE($6, is_active),
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND project_id = $2
+             RETURNING {}",
+            SUBSCRIPTION_COLUMNS
+        ))
+        .bind(subscription_id)
+        .bind(project_id)
+        .bind(&update.name)
+        .bind(&update.url)
+        .bind(update.event_types.as_deref().map(event_type_names))
+        .bind(update.is_active)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("update webhook subscription", e))
+    }
+
+    async fn delete_subscription(&self, project_id: i32, subscription_id: i32) -> Result<bool, ServiceError> {
+        let result = sqlx::query("DELETE FROM webhook_subscription WHERE id = $1 AND project_id = $2")
+            .bind(subscription_id)
+            .bind(project_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| database_error("delete webhook subscription", e))?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_deliveries(
+        &self,
+        project_id: i32,
+        subscription_id: Option<i32>,
+        status: Option<WebhookDeliveryStatus>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, ServiceError> {
+        sqlx::query_as::<_, WebhookDelivery>(&format!(
+            "SELECT {}
+             FROM webhook_delivery d
+             JOIN webhook_outbox_event e ON e.id = d.event_id
+             WHERE e.project_id = $1
+               AND ($2::INTEGER IS NULL OR d.subscription_id = $2)
+               AND ($3::webhook_delivery_status_enum IS NULL OR d.status = $3)
+             ORDER BY d.id DESC
+             LIMIT $4",
+            DELIVERY_COLUMNS
+        ))
+        .bind(project_id)
+        .bind(subscription_id)
+        .bind(status)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list webhook deliveries", e))
+    }
+
+    async fn find_delivery(&self, project_id: i32, delivery_id: i64) -> Result<Option<WebhookDelivery>, ServiceError> {
+        sqlx::query_as::<_, WebhookDelivery>(&format!(
+            "SELECT {}
+             FROM webhook_delivery d
+             JOIN webhook_outbox_event e ON e.id = d.event_id
+             WHERE d.id = $1 AND e.project_id = $2",
+            DELIVERY_COLUMNS
+        ))
+        .bind(delivery_id)
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("get webhook delivery", e))
+    }
+
+    async fn list_attempts(&self, delivery_id: i64) -> Result<Vec<WebhookDeliveryAttempt>, ServiceError> {
+        sqlx::query_as::<_, WebhookDeliveryAttempt>(
+            "SELECT id, delivery_id, attempt_number, status_code, error, duration_ms, attempted_at
+             FROM webhook_delivery_attempt
+             WHERE delivery_id = $1
+             ORDER BY attempt_number"
+        )
+        .bind(delivery_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("list webhook delivery attempts", e))
+    }
+
+    async fn retry_dead_delivery(&self, project_id: i32, delivery_id: i64) -> Result<Option<WebhookDelivery>, ServiceError> {
+        sqlx::query_as::<_, WebhookDelivery>(&with_event(
+            "UPDATE webhook_delivery
+             SET status = 'PENDING', attempt_count = 0, next_attempt_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND status = 'DEAD'
+               AND event_id IN (SELECT id FROM webhook_outbox_event WHERE project_id = $2)
+             RETURNING *",
+        ))
+        .bind(delivery_id)
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("retry webhook delivery", e))
+    }
+
+    async fn fan_out_events(&self, limit: i64) -> Result<u64, ServiceError> {
+        let result = sqlx::query(
+            "WITH events AS (
+                 SELECT id, project_id, event_type FROM webhook_outbox_event
+                 WHERE dispatched_at IS NULL
+                 ORDER BY id
+                 LIMIT $1
+                 FOR UPDATE SKIP LOCKED
+             ),
+             deliveries AS (
+                 INSERT INTO webhook_delivery (subscription_id, event_id)
+                 SELECT s.id, ev.id
+                 FROM events ev
+                 JOIN webhook_subscription s ON s.project_id = ev.project_id
+                 WHERE s.is_active AND (cardinality(s.event_types) = 0 OR ev.event_type = ANY(s.event_types))
+                 ON CONFLICT (subscription_id, event_id) DO NOTHING
+             )
+             UPDATE webhook_outbox_event o
+             SET dispatched_at = CURRENT_TIMESTAMP
+             FROM events ev
+             WHERE o.id = ev.id"
+        )
+        .bind(limit)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| database_error("fan out webhook events", e))?;
+        Ok(result.rows_affected())
+    }
+
+    async fn claim_due_deliveries(&self, limit: i64, lease_seconds: i64) -> Result<Vec<ClaimedWebhookDelivery>, ServiceError> {
+        sqlx::query_as::<_, ClaimedWebhookDelivery>(
+            "WITH due AS (
+                 SELECT d.id FROM webhook_delivery d
+                 JOIN webhook_subscription s ON s.id = d.subscription_id
+                 WHERE d.status = 'PENDING' AND d.next_attempt_at <= CURRENT_TIMESTAMP AND s.is_active
+                 ORDER BY d.next_attempt_at
+                 LIMIT $1
+                 FOR UPDATE OF d SKIP LOCKED
+             ),
+             claimed AS (
+                 UPDATE webhook_delivery d
+                 SET next_attempt_at = CURRENT_TIMESTAMP + $2::BIGINT * INTERVAL '1 second'
+                 FROM due
+                 WHERE d.id = due.id
+                 RETURNING d.id, d.subscription_id, d.event_id, d.attempt_count
+             )
+             SELECT c.id, c.attempt_count, s.url, s.secret, e.id AS event_id, e.project_id, e.event_type, e.payload,
+                    e.created_at AS event_created_at
+             FROM claimed c
+             JOIN webhook_subscription s ON s.id = c.subscription_id
+             JOIN webhook_outbox_event e ON e.id = c.event_id
+             ORDER BY c.id"
+        )
+        .bind(limit)
+        .bind(lease_seconds)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("claim webhook deliveries", e))
+    }
+
+    async fn record_attempt(&self, delivery_id: i64, outcome: &WebhookAttemptOutcome) -> Result<WebhookDelivery, ServiceError> {
+        let status = match (outcome.succeeded(), outcome.retry_in_seconds) {
+            (true, _) => WebhookDeliveryStatus::Succeeded,
+            (false, Some(_)) => WebhookDeliveryStatus::Pending,
+            (false, None) => WebhookDeliveryStatus::Dead,
+        };
+        // 시도 로그 번호는 dead-letter 재시도 후에도 이어짐
+        sqlx::query_as::<_, WebhookDelivery>(&format!(
+            "WITH d AS (
+                 UPDATE webhook_delivery
+                 SET attempt_count = attempt_count + 1,
+                     status = $2,
+                     last_status_code = $3,
+                     last_error = $4,
+                     next_attempt_at = CASE WHEN $5::BIGINT IS NULL THEN next_attempt_at
+                                            ELSE CURRENT_TIMESTAMP + $5::BIGINT * INTERVAL '1 second' END,
+                     delivered_at = CASE WHEN $2 = 'SUCCEEDED'::webhook_delivery_status_enum THEN CURRENT_TIMESTAMP END,
+                     updated_at = CURRENT_TIMESTAMP
+                 WHERE id = $1
+                 RETURNING *
+             ),
+             attempt AS (
+                 INSERT INTO webhook_delivery_attempt (delivery_id, attempt_number, status_code, error, duration_ms)
+                 SELECT d.id,
+                        COALESCE((SELECT MAX(attempt_number) FROM webhook_delivery_attempt WHERE delivery_id = d.id), 0) + 1,
+                        $3, $4, $6
+                 FROM d
+             )
+             SELECT {} FROM d JOIN webhook_outbox_event e ON e.id = d.event_id",
+            DELIVERY_COLUMNS
+        ))
+        .bind(delivery_id)
+        .bind(status)
+        .bind(outcome.status_code)
+        .bind(&outcome.error)
+        .bind(outcome.retry_in_seconds.filter(|_| status == WebhookDeliveryStatus::Pending))
+        .bind(outcome.duration_ms)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| database_error("record webhook delivery attempt", e))?
+        .ok_or_else(|| ServiceError::NotFound(format!("Webhook delivery with ID {} not found", delivery_id)))
+    }
+}
@@ -0,0 +1,360 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use crate::domain::entities::worklist::{
+    NewWorklistTask, WorklistProgress, WorklistStudyFilter, WorklistTask, WorklistTaskQuery,
+    WorklistTaskResults, WorklistTaskStatus,
+};
+use crate::domain::repositories::WorklistRepository;
+use crate::domain::ServiceError;
+use crate::infrastructure::repositories::database_error;
+
+/// 작업(`t`)과 대상 Study(`s`) 컬럼
+const TASK_COLUMNS: &str = "t.id, t.project_id, t.study_id, s.study_uid, s.study_description, s.patient_id, s.study_date,
+    t.assignee_id, t.status, t.priority, t.note, t.time_spent_seconds, t.started_at, t.completed_at,
+    t.created_by, t.created_at, t.updated_at";
+
+/// 현재 작업 구간의 소요 시간 (초, 진행 중이 아니면 0)
+const RUNNING_SECONDS: &str = "COALESCE(EXTRACT(EPOCH FROM (CURRENT_TIMESTAMP - started_at))::BIGINT, 0)";
+
+#[derive(Clone)]
+pub struct WorklistRepositoryImpl {
+    pool: PgPool,
+}
+
+impl WorklistRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 작업 행을 바꾸는 문장(`RETURNING *`)의 결과를 Study 정보와 함께 조회하는 쿼리
+fn with_study(statement: &str) -> String {
+    format!(
+        "WITH t AS ({}) SELECT {} FROM t JOIN project_data_study s ON s.id = t.study_id ORDER BY t.id",
+        statement, TASK_COLUMNS
+    )
+}
+
+#[async_trait]
+impl WorklistRepository for WorklistRepositoryImpl {
+    async fn is_project_member(&self, project_id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM security_user_project WHERE user_id = $1 AND project_id = $2)"
+        )
+        .bind(user_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| database_error("check project membership", e))
+    }
+
+    async fn find_untasked_studies(&self, project_id: i32, filter: &WorklistStudyFilter) -> Result<Vec<i32>, ServiceError> {
+        sqlx::query_scalar::<_, i32>(
+            "SELECT s.id
+             FROM project_data_study s
+             WHERE s.project_id = $1
+               AND NOT EXISTS (SELECT 1 FROM annotation_worklist_task t WHERE t.study_id = s.id)
+               AND ($2::INTEGER[] IS NULL OR s.id = ANY($2))
+               AND ($3::DATE IS NULL OR s.study_date >= $3)
+               AND ($4::DATE IS NULL OR s.study_date <= $4)
+               AND ($5::TEXT IS NULL OR EXISTS (
+                    SELECT 1 FROM project_data_series se
+                    WHERE se.study_id = s.id AND upper(se.modality) = upper($5)))
+               AND ($6::TEXT IS NULL OR s.study_description ILIKE '%' || $6 || '%')
+               AND ($7::TEXT IS NULL OR s.patient_id = $7)
+             ORDER BY s.study_date NULLS LAST, s.id"
+        )
+        .bind(project_id)
+        .bind(&filter.study_ids)
+        .bind(filter.study_date_from)
+        .bind(filter.study_date_to)
+        .bind(&filter.modality)
+        .bind(&filter.study_description)
+        .bind(&filter.patient_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("find studies for worklist", e))
+    }
+
+    async fn create_tasks(
+        &self,
+        project_id: i32,
+        tasks: &[NewWorklistTask],
+        priority: i32,
+        created_by: i32,
+    ) -> Result<Vec<WorklistTask>, ServiceError> {
+        let study_ids: Vec<i32> = tasks.iter().map(|t| t.study_id).collect();
+        let assignee_ids: Vec<Option<i32>> = tasks.iter().map(|t| t.assignee_id).collect();
+
+        let query = with_study(
+            "INSERT INTO annotation_worklist_task (project_id, study_id, assignee_id, priority, created_by)
+             SELECT $1, x.study_id, x.assignee_id, $4, $5
+             FROM UNNEST($2::INTEGER[], $3::INTEGER[]) AS x(study_id, assignee_id)
+             ON CONFLICT (study_id) DO NOTHING
+             RETURNING *"
+        );
+        sqlx::query_as::<_, WorklistTask>(&query)
+            .bind(project_id)
+            .bind(&study_ids)
+            .bind(&assignee_ids)
+            .bind(priority)
+            .bind(created_by)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("create worklist tasks", e))
+    }
+
+    async fn list_tasks(&self, project_id: i32, query: &WorklistTaskQuery) -> Result<Vec<WorklistTask>, ServiceError> {
+        let sql = format!(
+            "SELECT {}
+             FROM annotation_worklist_task t
+             JOIN project_data_study s ON s.id = t.study_id
+             WHERE t.project_id = $1
+               AND ($2::worklist_task_status_enum IS NULL OR t.status = $2)
+               AND ($3::INTEGER IS NULL OR t.assignee_id = $3)
+               AND (NOT $4 OR t.assignee_id IS NULL)
+             ORDER BY t.priority DESC, t.id
+             LIMIT $5 OFFSET $6",
+            TASK_COLUMNS
+        );
+        sqlx::query_as::<_, WorklistTask>(&sql)
+            .bind(project_id)
+            .bind(query.status)
+            .bind(query.assignee_id)
+            .bind(query.unassigned)
+            .bind(query.limit)
+            .bind(query.offset)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("list worklist tasks", e))
+    }
+
+    async fn find_task(&self, project_id: i32, task_id: i32) -> Result<Option<WorklistTask>, ServiceError> {
+        let sql = format!(
+            "SELECT {}
+             FROM annotation_worklist_task t
+             JOIN project_data_study s ON s.id = t.study_id
+             WHERE t.id = $1 AND t.project_id = $2",
+            TASK_COLUMNS
+        );
+        sqlx::query_as::<_, WorklistTask>(&sql)
+            .bind(task_id)
+            .bind(project_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("get worklist task", e))
+    }
+
+    async fn find_results(&self, task_id: i32) -> Result<WorklistTaskResults, ServiceError> {
+        let rows = sqlx::query_as::<_, (Option<i32>, Option<i32>)>(
+            "SELECT annotation_id, mask_group_id
+             FROM annotation_worklist_task_result
+             WHERE task_id = $1
+             ORDER BY id"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("get worklist task results", e))?;
+
+        Ok(WorklistTaskResults {
+            annotation_ids: rows.iter().filter_map(|(annotation_id, _)| *annotation_id).collect(),
+            mask_group_ids: rows.iter().filter_map(|(_, mask_group_id)| *mask_group_id).collect(),
+        })
+    }
+
+    async fn filter_study_results(
+        &self,
+        project_id: i32,
+        study_uid: &str,
+        results: &WorklistTaskResults,
+    ) -> Result<WorklistTaskResults, ServiceError> {
+        let annotation_ids = sqlx::query_scalar::<_, i32>(
+            "SELECT id FROM annotation_annotation
+             WHERE id = ANY($1) AND project_id = $2 AND study_uid = $3 AND deleted_at IS NULL
+             ORDER BY id"
+        )
+        .bind(&results.annotation_ids)
+        .bind(project_id)
+        .bind(study_uid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("check worklist result annotations", e))?;
+
+        let mask_group_ids = sqlx::query_scalar::<_, i32>(
+            "SELECT g.id
+             FROM annotation_mask_group g
+             JOIN annotation_annotation a ON a.id = g.annotation_id
+             WHERE g.id = ANY($1) AND a.project_id = $2 AND a.study_uid = $3
+               AND g.deleted_at IS NULL AND a.deleted_at IS NULL
+             ORDER BY g.id"
+        )
+        .bind(&results.mask_group_ids)
+        .bind(project_id)
+        .bind(study_uid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| database_error("check worklist result mask groups", e))?;
+
+        Ok(WorklistTaskResults { annotation_ids, mask_group_ids })
+    }
+
+    async fn assign(&self, project_id: i32, task_id: i32, assignee_id: Option<i32>) -> Result<Option<WorklistTask>, ServiceError> {
+        let query = with_study(
+            "UPDATE annotation_worklist_task
+             SET assignee_id = $3, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND project_id = $2 AND status = 'TODO'
+             RETURNING *"
+        );
+        sqlx::query_as::<_, WorklistTask>(&query)
+            .bind(task_id)
+            .bind(project_id)
+            .bind(assignee_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("assign worklist task", e))
+    }
+
+    async fn claim(&self, project_id: i32, task_id: i32, user_id: i32) -> Result<Option<WorklistTask>, ServiceError> {
+        let query = with_study(
+            "UPDATE annotation_worklist_task
+             SET status = 'IN_PROGRESS', assignee_id = $3,
+                 started_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND project_id = $2 AND status = 'TODO'
+               AND (assignee_id IS NULL OR assignee_id = $3)
+             RETURNING *"
+        );
+        sqlx::query_as::<_, WorklistTask>(&query)
+            .bind(task_id)
+            .bind(project_id)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("claim worklist task", e))
+    }
+
+    async fn release(&self, project_id: i32, task_id: i32, user_id: i32) -> Result<Option<WorklistTask>, ServiceError> {
+        let query = with_study(&format!(
+            "UPDATE annotation_worklist_task
+             SET status = 'TODO', assignee_id = NULL,
+                 time_spent_seconds = time_spent_seconds + {},
+                 started_at = NULL, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND project_id = $2 AND status = 'IN_PROGRESS' AND assignee_id = $3
+             RETURNING *",
+            RUNNING_SECONDS
+        ));
+        sqlx::query_as::<_, WorklistTask>(&query)
+            .bind(task_id)
+            .bind(project_id)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| database_error("release worklist task", e))
+    }
+
+    async fn close(
+        &self,
+        project_id: i32,
+        task_id: i32,
+        user_id: i32,
+        to: WorklistTaskStatus,
+        note: Option<&str>,
+        results: &WorklistTaskResults,
+    ) -> Result<Option<WorklistTask>, ServiceError> {
+        let from: Vec<String> = to.closable_from().iter().map(|status| status.to_string()).collect();
+        let mut tx = self.pool.begin().await
+            .map_err(|e| database_error("begin transaction", e))?;
+
+        // 건너뛰기는 배정되지 않은 작업에도 가능하며, 이 경우 요청한 사용자를 담당자로 기록
+        let query = with_study(&format!(
+            "UPDATE annotation_worklist_task
+             SET status = $4, note = $5, assignee_id = $3,
+                 time_spent_seconds = time_spent_seconds + {},
+                 started_at = NULL, completed_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND project_id = $2 AND status::TEXT = ANY($6)
+               AND COALESCE(assignee_id, $3) = $3
+             RETURNING *",
+            RUNNING_SECONDS
+        ));
+        let task = sqlx::query_as::<_, WorklistTask>(&query)
+            .bind(task_id)
+            .bind(project_id)
+            .bind(user_id)
+            .bind(to)
+            .bind(note)
+            .bind(&from)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| database_error("close worklist task", e))?;
+
+        if task.is_some() {
+            sqlx::query(
+                "INSERT INTO annotation_worklist_task_result (task_id, annotation_id, mask_group_id)
+                 SELECT $1, annotation_id, NULL FROM UNNEST($2::INTEGER[]) AS annotation_id
+                 UNION ALL
+                 SELECT $1, NULL, mask_group_id FROM UNNEST($3::INTEGER[]) AS mask_group_id"
+            )
+            .bind(task_id)
+            .bind(&results.annotation_ids)
+            .bind(&results.mask_group_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| database_error("link worklist task results", e))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| database_error("commit transaction", e))?;
+        Ok(task)
+    }
+
+    async fn reopen(&self, project_id: i32, task_id: i32) -> Result<Option<WorklistTask>, ServiceError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| database_error("begin transaction", e))?;
+
+        let query = with_study(
+            "UPDATE annotation_worklist_task
+             SET status = 'TODO', completed_at = NULL, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1 AND project_id = $2 AND status IN ('DONE', 'SKIPPED')
+             RETURNING *"
+        );
+        let task = sqlx::query_as::<_, WorklistTask>(&query)
+            .bind(task_id)
+            .bind(project_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| database_error("reopen worklist task", e))?;
+
+        if task.is_some() {
+            sqlx::query("DELETE FROM annotation_worklist_task_result WHERE task_id = $1")
+                .bind(task_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| database_error("unlink worklist task results", e))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| database_error("commit transaction", e))?;
+        Ok(task)
+    }
+
+    async fn progress(&self, project_id: i32) -> Result<Vec<WorklistProgress>, ServiceError> {
+        let query = format!(
+            "SELECT assignee_id,
+                    COUNT(*) FILTER (WHERE status = 'TODO') AS todo,
+                    COUNT(*) FILTER (WHERE status = 'IN_PROGRESS') AS in_progress,
+                    COUNT(*) FILTER (WHERE status = 'DONE') AS done,
+                    COUNT(*) FILTER (WHERE status = 'SKIPPED') AS skipped,
+                    COALESCE(SUM(time_spent_seconds + {}), 0)::BIGINT AS time_spent_seconds
+             FROM annotation_worklist_task
+             WHERE project_id = $1
+             GROUP BY assignee_id
+             ORDER BY assignee_id NULLS LAST",
+            RUNNING_SECONDS
+        );
+        sqlx::query_as::<_, WorklistProgress>(&query)
+            .bind(project_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| database_error("get worklist progress", e))
+    }
+}
@@ -1,6 +1,8 @@
 use crate::domain::entities::project_data::{ProjectData, ProjectDataAccess, ProjectDataStudy, ProjectDataSeries, NewProjectData, UpdateProjectData, NewProjectDataAccess, UpdateProjectDataAccess, DataAccessStatus};
 use crate::domain::repositories::{ProjectDataRepository, ProjectDataAccessRepository};
+use crate::domain::entities::realtime::{RealtimeEvent, RealtimeEventType};
 use crate::domain::services::ProjectDataService;
+use crate::domain::services::realtime_events::publish_best_effort;
 use crate::domain::ServiceError;
 use std::sync::Arc;
 
@@ -24,6 +26,40 @@ where
             project_data_access_repository,
         }
     }
+
+    /// 접근 승인/거부 결과를 프로젝트 구독자에게 발행
+    async fn publish_access_review(
+        &self,
+        project_data_id: i32,
+        update_access: &UpdateProjectDataAccess,
+        accesses: &[ProjectDataAccess],
+    ) {
+        let event_type = match update_access.status {
+            Some(DataAccessStatus::Approved) => RealtimeEventType::DataAccessApproved,
+            Some(DataAccessStatus::Denied) => RealtimeEventType::DataAccessDenied,
+            _ => return,
+        };
+        let project_data = match self.project_data_repository.find_by_id(project_data_id).await {
+            Ok(Some(project_data)) => project_data,
+            Ok(None) => return,
+            Err(e) => {
+                eprintln!("Failed to load project data {} for realtime event: {}", project_data_id, e);
+                return;
+            }
+        };
+
+        for access in accesses {
+            let event = RealtimeEvent::new(
+                event_type,
+                project_data.project_id,
+                Some(project_data.study_uid.clone()),
+                access.id,
+                update_access.reviewed_by,
+            )
+            .with_subject_user(access.user_id);
+            publish_best_effort(self.project_data_access_repository.pool(), event).await;
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -147,6 +183,7 @@ where
             .map_err(|e| ServiceError::DatabaseError(e.to_string()))?
             .ok_or_else(|| ServiceError::NotFound("Data access not found".to_string()))?;
 
+        self.publish_access_review(project_data_id, &update_access, std::slice::from_ref(&access)).await;
         Ok(access)
     }
 
@@ -161,6 +198,7 @@ where
             .await
             .map_err(|e| ServiceError::DatabaseError(e.to_string()))?;
 
+        self.publish_access_review(project_data_id, &update_access, &results).await;
         Ok(results)
     }
 
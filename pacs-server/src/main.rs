@@ -43,7 +43,7 @@ mod presentation;
 
 // 애플리케이션 레이어 - Use Case 인터페이스들
 use application::use_cases::{
-    AccessControlUseCase, AnnotationUseCase, AuthUseCase, MaskGroupUseCase, MaskUseCase,
+    AccessControlUseCase, AnnotationBundleUseCase, AnnotationUseCase, AuthUseCase, CommentUseCase, DatasetExportUseCase, DatasetReleaseUseCase, TrashUseCase, LabelUseCase, WorklistUseCase, EditLockUseCase, RealtimeUseCase, WebhookUseCase, MeasurementUseCase, AnnotationPropagationUseCase, MultipartUploadUseCase, MaskGroupArchiveUseCase, MaskValidationUseCase, MaskPreviewUseCase, MaskGroupLineageUseCase, StorageDeletionUseCase, StorageReconciliationUseCase, StorageQuotaUseCase, MaskGroupUseCase, MaskImportUseCase, MaskUseCase,
     PermissionUseCase, ProjectDataAccessUseCase, ProjectUseCase, ProjectUserMatrixUseCase,
     ProjectUserUseCase, RolePermissionMatrixUseCase, RoleCapabilityMatrixUseCase, UserRegistrationUseCase, UserUseCase,
     UserProjectMatrixUseCase,
@@ -56,9 +56,9 @@ use domain::services::{
 };
 
 // 인프라스트럭처 레이어 - 리포지토리 구현체들
-use infrastructure::external::KeycloakClient;
+use infrastructure::external::{KeycloakClient, LocalObjectStorageService};
 use infrastructure::repositories::{
-    AccessLogRepositoryImpl, AnnotationRepositoryImpl, CapabilityRepositoryImpl, MaskGroupRepositoryImpl, MaskRepositoryImpl,
+    AccessLogRepositoryImpl, AnnotationBundleRepositoryImpl, AnnotationRepositoryImpl, CapabilityRepositoryImpl, CommentRepositoryImpl, DatasetExportRepositoryImpl, DatasetReleaseRepositoryImpl, TrashRepositoryImpl, LabelRepositoryImpl, WorklistRepositoryImpl, EditLockRepositoryImpl, RealtimeRepositoryImpl, WebhookRepositoryImpl, MeasurementRepositoryImpl, AnnotationPropagationRepositoryImpl, MultipartUploadRepositoryImpl, StorageDeletionRepositoryImpl, StorageReconciliationRepositoryImpl, StorageQuotaRepositoryImpl, MaskValidationRepositoryImpl, MaskPreviewRepositoryImpl, MaskGroupRepositoryImpl, MaskImportRepositoryImpl, MaskRepositoryImpl,
     PermissionRepositoryImpl, ProjectDataAccessRepositoryImpl, ProjectDataRepositoryImpl,
     ProjectRepositoryImpl, RoleRepositoryImpl, UserRepositoryImpl,
 };
@@ -67,15 +67,16 @@ use infrastructure::services::{CapabilityServiceImpl, ProjectDataServiceImpl, Us
 // JWT 인증 서비스
 use infrastructure::auth::JwtService;
 // 서명된 URL 및 객체 저장소 서비스
-use application::services::{ObjectStorageServiceFactory, SignedUrlServiceImpl};
+use application::services::{ObjectStorageService, ObjectStorageServiceFactory, SignedUrlServiceImpl};
 // 설정 관련 구조체들
 use infrastructure::config::{JwtConfig, Settings};
 // 미들웨어 (캐시 헤더, CORS)
 use infrastructure::middleware::{configure_cors, CacheHeaders};
 // 프레젠테이션 레이어 - 컨트롤러들
 use presentation::controllers::{
-    access_control_controller, annotation_controller, auth_controller, mask_controller,
-    mask_group_controller, project_controller, role_controller,
+    access_control_controller, annotation_bundle_controller, annotation_controller, dataset_export_controller, dataset_release_controller, trash_controller, label_controller, worklist_controller, edit_lock_controller, realtime_controller, webhook_controller, measurement_controller, annotation_propagation_controller, multipart_upload_controller, mask_group_archive_controller, mask_validation_controller, mask_preview_controller, mask_group_lineage_controller, local_storage_controller, storage_reconciliation_controller, storage_quota_controller, annotation_review_controller, auth_controller,
+    comment_controller,
+    mask_controller, mask_group_controller, mask_import_controller, project_controller, role_controller,
     project_data_access_controller, project_user_controller, project_user_matrix_controller,
     user_project_matrix_controller,
     role_permission_matrix_controller, user_controller, user_registration_controller,
@@ -214,6 +215,36 @@ async fn main() -> std::io::Result<()> {
     let mask_group_repo = Arc::new(MaskGroupRepositoryImpl::new(pool.clone()));
     // 마스크 관련 데이터 접근을 위한 리포지토리 (Arc로 래핑하여 공유 소유권)
     let mask_repo = Arc::new(MaskRepositoryImpl::new(pool.clone()));
+    // 마스크 임포트 작업 관련 데이터 접근을 위한 리포지토리
+    let mask_import_repo = Arc::new(MaskImportRepositoryImpl::new(pool.clone()));
+    // 마스크 그룹 멀티파트 업로드 세션 관리를 위한 리포지토리
+    let multipart_upload_repo = Arc::new(MultipartUploadRepositoryImpl::new(pool.clone()));
+    // 어노테이션 / 마스크 그룹 코멘트 관련 데이터 접근을 위한 리포지토리
+    let comment_repo = Arc::new(CommentRepositoryImpl::new(pool.clone()));
+    // 어노테이션 번들 내보내기/가져오기를 위한 리포지토리
+    let annotation_bundle_repo = Arc::new(AnnotationBundleRepositoryImpl::new(pool.clone()));
+    let dataset_export_repo = Arc::new(DatasetExportRepositoryImpl::new(pool.clone()));
+    let dataset_release_repo = Arc::new(DatasetReleaseRepositoryImpl::new(pool.clone()));
+    // 휴지통(삭제된 어노테이션 / 마스크 그룹) 조회, 복원, 영구 삭제를 위한 리포지토리
+    let trash_repo = Arc::new(TrashRepositoryImpl::new(pool.clone()));
+    // 마스크 삭제 / 휴지통 영구 삭제 시 예약된 Object Storage 삭제 대기열
+    let storage_deletion_repo = Arc::new(StorageDeletionRepositoryImpl::new(pool.clone()));
+    // Object Storage와 마스크 행의 정합성 점검 기록을 위한 리포지토리
+    let storage_reconciliation_repo = Arc::new(StorageReconciliationRepositoryImpl::new(pool.clone()));
+    // 프로젝트/사용자별 저장 용량 사용량과 한도를 위한 리포지토리
+    let storage_quota_repo = Arc::new(StorageQuotaRepositoryImpl::new(pool.clone()));
+    // 프로젝트 라벨 분류 체계 관리를 위한 리포지토리
+    let label_repo = Arc::new(LabelRepositoryImpl::new(pool.clone()));
+    // 어노테이션 작업 목록(Worklist) 관리를 위한 리포지토리
+    let worklist_repo = Arc::new(WorklistRepositoryImpl::new(pool.clone()));
+    // Study / 어노테이션 편집 잠금을 위한 리포지토리
+    let edit_lock_repo = Arc::new(EditLockRepositoryImpl::new(pool.clone()));
+    // 실시간 변경 피드를 위한 리포지토리 (Postgres LISTEN/NOTIFY)
+    let realtime_repo = Arc::new(RealtimeRepositoryImpl::new(pool.clone()));
+    // Webhook 구독 / 아웃박스 / 전송을 위한 리포지토리
+    let webhook_repo = Arc::new(WebhookRepositoryImpl::new(pool.clone()));
+    let measurement_repo = Arc::new(MeasurementRepositoryImpl::new(pool.clone()));
+    let propagation_repo = Arc::new(AnnotationPropagationRepositoryImpl::new(pool.clone()));
     // 프로젝트 데이터 관련 데이터 접근을 위한 리포지토리
     let project_data_repo = Arc::new(ProjectDataRepositoryImpl::new(pool.clone()));
     // 프로젝트 데이터 접근 권한 관련 데이터 접근을 위한 리포지토리
@@ -283,26 +314,83 @@ async fn main() -> std::io::Result<()> {
         UserRegistrationServiceImpl::new(pool.clone(), (*keycloak_client).clone());
     // Initialize Object Storage service
     print!("☁️  Initializing Object Storage service... ");
-    let object_storage = ObjectStorageServiceFactory::create(
-        &settings.object_storage.provider,
-        &settings.object_storage.bucket_name,
-        &settings.object_storage.region,
-        &settings.object_storage.endpoint,
-        &settings.object_storage.access_key,
-        &settings.object_storage.secret_key,
-    )
-    .await
-    .map_err(|e| {
-        eprintln!("❌ Failed to initialize Object Storage: {}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
-    })?;
+    // 로컬 디스크 스토리지는 Signed URL 라우트가 필요하므로 구체 타입을 함께 보관
+    let local_object_storage = if settings.object_storage.provider.eq_ignore_ascii_case("local") {
+        let local_storage = LocalObjectStorageService::new(
+            &settings.object_storage.bucket_name,
+            &settings.object_storage.endpoint,
+            &settings.object_storage.secret_key,
+        )
+        .await
+        .map_err(|e| {
+            eprintln!("❌ Failed to initialize Object Storage: {}", e);
+            std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
+        })?;
+        Some(Arc::new(local_storage))
+    } else {
+        None
+    };
+    // 여러 유스케이스가 하나의 스토리지 인스턴스를 공유
+    let object_storage: Arc<dyn ObjectStorageService> = match &local_object_storage {
+        Some(local_storage) => local_storage.clone(),
+        None => {
+            let object_storage = ObjectStorageServiceFactory::create(
+                &settings.object_storage.provider,
+                &settings.object_storage.bucket_name,
+                &settings.object_storage.region,
+                &settings.object_storage.endpoint,
+                &settings.object_storage.access_key,
+                &settings.object_storage.secret_key,
+            )
+            .await
+            .map_err(|e| {
+                eprintln!("❌ Failed to initialize Object Storage: {}", e);
+                std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
+            })?;
+            Arc::from(object_storage)
+        }
+    };
     let signed_url_service = Arc::new(SignedUrlServiceImpl::new(
-        object_storage,
+        Box::new(object_storage.clone()),
         settings.signed_url.default_ttl,
         settings.signed_url.max_ttl,
     ));
     println!("✅ Done (Provider: {})", settings.object_storage.provider);
 
+    // 휴지통 보관 기간 및 정리 주기
+    let trash_retention_days = std::env::var("TRASH_RETENTION_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(domain::entities::DEFAULT_TRASH_RETENTION_DAYS);
+    let trash_purge_interval = std::env::var("TRASH_PURGE_INTERVAL_SECONDS")
+        .unwrap_or_else(|_| "3600".to_string())
+        .parse::<u64>()
+        .unwrap_or(3600);
+
+    // 완료되지 않은 멀티파트 업로드 보관 시간 및 정리 주기
+    let multipart_upload_expiry_hours = std::env::var("MULTIPART_UPLOAD_EXPIRY_HOURS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(domain::entities::DEFAULT_MULTIPART_UPLOAD_EXPIRY_HOURS);
+    let multipart_cleanup_interval = std::env::var("MULTIPART_UPLOAD_CLEANUP_INTERVAL_SECONDS")
+        .unwrap_or_else(|_| "3600".to_string())
+        .parse::<u64>()
+        .unwrap_or(3600);
+
+    // 스토리지 정합성 점검 주기, 유예 기간, 고아 객체 처리 방식 (REPORT / QUARANTINE / DELETE)
+    let storage_reconciliation_interval = std::env::var("STORAGE_RECONCILIATION_INTERVAL_SECONDS")
+        .unwrap_or_else(|_| "86400".to_string())
+        .parse::<u64>()
+        .unwrap_or(86400);
+    let storage_reconciliation_grace_hours = std::env::var("STORAGE_RECONCILIATION_GRACE_HOURS")
+        .ok()
+        .and_then(|v| v.parse::<i32>().ok())
+        .unwrap_or(domain::entities::DEFAULT_RECONCILIATION_GRACE_HOURS);
+    let storage_orphan_action = std::env::var("STORAGE_RECONCILIATION_ORPHAN_ACTION")
+        .ok()
+        .and_then(|v| v.parse::<domain::entities::StorageOrphanAction>().ok())
+        .unwrap_or_default();
+
     // Initialize use cases
     print!("📋 Initializing use cases... ");
     let auth_use_case = Arc::new(AuthUseCase::new(auth_service));
@@ -316,9 +404,100 @@ async fn main() -> std::io::Result<()> {
         signed_url_service.clone(),
     ));
     let mask_use_case = Arc::new(MaskUseCase::new(
+        mask_service.clone(),
+        mask_group_service.clone(),
+        signed_url_service.clone(),
+    ));
+    let mask_import_use_case = Arc::new(MaskImportUseCase::new(
+        mask_group_service.clone(),
         mask_service,
+        signed_url_service.clone(),
+        mask_import_repo,
+        object_storage.clone(),
+    ));
+    let multipart_upload_use_case = Arc::new(MultipartUploadUseCase::new(
+        mask_group_service.clone(),
+        multipart_upload_repo,
+        object_storage.clone(),
+        settings.signed_url.default_ttl,
+        settings.signed_url.max_ttl,
+        chrono::Duration::hours(multipart_upload_expiry_hours),
+    ));
+    let mask_group_archive_use_case = Arc::new(MaskGroupArchiveUseCase::new(
+        mask_group_service.clone(),
+        Arc::new(annotation_repo.clone()),
+        Arc::new(AccessLogRepositoryImpl::new(pool.clone())),
+        object_storage.clone(),
+    ));
+    let mask_validation_use_case = Arc::new(MaskValidationUseCase::new(
+        Arc::new(MaskValidationRepositoryImpl::new(pool.clone())),
+        mask_group_service.clone(),
+        object_storage.clone(),
+        domain::entities::MASK_VALIDATION_RETRY_POLICY,
+    ));
+    let mask_preview_use_case = Arc::new(MaskPreviewUseCase::new(
+        Arc::new(MaskPreviewRepositoryImpl::new(pool.clone())),
+        mask_group_service.clone(),
+        signed_url_service.clone(),
+        object_storage.clone(),
+        domain::entities::MASK_PREVIEW_RETRY_POLICY,
+    ));
+    let mask_group_lineage_use_case = Arc::new(MaskGroupLineageUseCase::new(
         mask_group_service.clone(),
+        object_storage.clone(),
+    ));
+    let annotation_bundle_use_case = Arc::new(AnnotationBundleUseCase::new(annotation_bundle_repo));
+    let dataset_export_use_case = Arc::new(DatasetExportUseCase::new(
         signed_url_service.clone(),
+        dataset_export_repo,
+        object_storage.clone(),
+    ));
+    let dataset_release_use_case = Arc::new(DatasetReleaseUseCase::new(
+        signed_url_service.clone(),
+        dataset_release_repo,
+        object_storage.clone(),
+    ));
+    let trash_use_case = Arc::new(TrashUseCase::new(trash_repo, trash_retention_days));
+    let storage_deletion_use_case = Arc::new(StorageDeletionUseCase::new(
+        storage_deletion_repo,
+        object_storage.clone(),
+        domain::entities::STORAGE_DELETION_RETRY_POLICY,
+    ));
+    let storage_reconciliation_use_case = Arc::new(StorageReconciliationUseCase::new(
+        storage_reconciliation_repo,
+        object_storage.clone(),
+    ));
+    // 프로젝트 역할 확인용 (관리자 전용 기능을 가진 유스케이스가 공유)
+    let project_roles: Arc<dyn domain::repositories::ProjectRepository> = Arc::new(project_repo.clone());
+    let storage_quota_use_case = Arc::new(StorageQuotaUseCase::new(storage_quota_repo, project_roles.clone()));
+    let label_use_case = Arc::new(LabelUseCase::new(label_repo, project_roles.clone()));
+    let worklist_use_case = Arc::new(WorklistUseCase::new(worklist_repo, project_roles.clone()));
+    let edit_lock_use_case = Arc::new(EditLockUseCase::new(
+        edit_lock_repo,
+        Arc::new(AccessLogRepositoryImpl::new(pool.clone())),
+        project_roles.clone(),
+    ));
+    let realtime_use_case = Arc::new(RealtimeUseCase::new(
+        realtime_repo,
+        project_roles.clone(),
+        application::use_cases::realtime_use_case::DEFAULT_REALTIME_BUFFER,
+    ));
+    let webhook_use_case = Arc::new(WebhookUseCase::new(
+        webhook_repo,
+        project_roles.clone(),
+        domain::entities::WEBHOOK_RETRY_POLICY,
+    ));
+    let measurement_use_case = Arc::new(MeasurementUseCase::new(measurement_repo, project_roles));
+    let propagation_use_case = Arc::new(AnnotationPropagationUseCase::new(propagation_repo));
+    let comment_use_case = Arc::new(CommentUseCase::new(
+        comment_repo,
+        Arc::new(AnnotationServiceImpl::new(
+            annotation_repo.clone(),
+            user_repo.clone(),
+            project_repo.clone(),
+        )),
+        mask_group_service.clone(),
+        Arc::new(project_service.clone()),
     ));
     let project_user_use_case = Arc::new(ProjectUserUseCase::new(
         Arc::new(project_service.clone()),
@@ -348,6 +527,115 @@ async fn main() -> std::io::Result<()> {
         Arc::new(UserRegistrationUseCase::new(user_registration_service));
     println!("✅ Done");
 
+    // 휴지통 정리 작업: 보관 기간이 지난 어노테이션 / 마스크 그룹을 영구 삭제하고 마스크 파일 삭제를 예약
+    print!("🗑️  Starting trash purge job... ");
+    let trash_purge_worker = trash_use_case.clone();
+    tokio::spawn(async move {
+        trash_purge_worker
+            .run_purge_loop(std::time::Duration::from_secs(trash_purge_interval))
+            .await;
+    });
+    println!(
+        "✅ Done (Retention: {}d, Interval: {}s)",
+        trash_retention_days, trash_purge_interval
+    );
+
+    // 스토리지 삭제 작업: 삭제 대기열의 객체를 지움 (실패 시 지수 백오프로 재시도)
+    let storage_deletion_interval = std::env::var("STORAGE_DELETION_INTERVAL_SECONDS")
+        .unwrap_or_else(|_| "60".to_string())
+        .parse::<u64>()
+        .unwrap_or(60);
+    print!("🧹 Starting storage deletion job... ");
+    let storage_deletion_worker = storage_deletion_use_case.clone();
+    tokio::spawn(async move {
+        storage_deletion_worker
+            .run_deletion_loop(std::time::Duration::from_secs(storage_deletion_interval))
+            .await;
+    });
+    println!("✅ Done (Interval: {}s)", storage_deletion_interval);
+
+    // 마스크 검증 작업: 업로드된 마스크 객체의 체크섬/크기/PNG를 확인하고 라벨별 통계를 기록
+    let mask_validation_interval = std::env::var("MASK_VALIDATION_INTERVAL_SECONDS")
+        .unwrap_or_else(|_| "30".to_string())
+        .parse::<u64>()
+        .unwrap_or(30);
+    print!("🩻 Starting mask validation job... ");
+    let mask_validation_worker = mask_validation_use_case.clone();
+    tokio::spawn(async move {
+        mask_validation_worker
+            .run_validation_loop(std::time::Duration::from_secs(mask_validation_interval))
+            .await;
+    });
+    println!("✅ Done (Interval: {}s)", mask_validation_interval);
+
+    // 마스크 미리보기 작업: 검증을 통과한 마스크의 썸네일/컬러 미리보기와 마스크 그룹 contact sheet 생성
+    let mask_preview_interval = std::env::var("MASK_PREVIEW_INTERVAL_SECONDS")
+        .unwrap_or_else(|_| "30".to_string())
+        .parse::<u64>()
+        .unwrap_or(30);
+    print!("🖼️ Starting mask preview job... ");
+    let mask_preview_worker = mask_preview_use_case.clone();
+    tokio::spawn(async move {
+        mask_preview_worker
+            .run_preview_loop(std::time::Duration::from_secs(mask_preview_interval))
+            .await;
+    });
+    println!("✅ Done (Interval: {}s)", mask_preview_interval);
+
+    // 스토리지 정합성 점검 작업: 행이 없는 객체 / 객체가 없는 행을 보고하고 설정에 따라 고아 객체 처리
+    print!("🔍 Starting storage reconciliation job... ");
+    let storage_reconciliation_worker = storage_reconciliation_use_case.clone();
+    tokio::spawn(async move {
+        storage_reconciliation_worker
+            .run_reconciliation_loop(
+                std::time::Duration::from_secs(storage_reconciliation_interval),
+                storage_orphan_action,
+                storage_reconciliation_grace_hours,
+            )
+            .await;
+    });
+    println!(
+        "✅ Done (Interval: {}s, Grace: {}h, Orphans: {:?})",
+        storage_reconciliation_interval, storage_reconciliation_grace_hours, storage_orphan_action
+    );
+
+    // 멀티파트 업로드 정리 작업: 만료 시각까지 완료되지 않은 업로드를 스토리지에서 중단
+    print!("📦 Starting multipart upload cleanup job... ");
+    let multipart_cleanup_worker = multipart_upload_use_case.clone();
+    tokio::spawn(async move {
+        multipart_cleanup_worker
+            .run_cleanup_loop(std::time::Duration::from_secs(multipart_cleanup_interval))
+            .await;
+    });
+    println!(
+        "✅ Done (Expiry: {}h, Interval: {}s)",
+        multipart_upload_expiry_hours, multipart_cleanup_interval
+    );
+
+    // 실시간 변경 피드: 모든 워커에서 발행된 이벤트를 받아 이 서버의 구독자에게 전달
+    print!("📡 Starting realtime event listener... ");
+    let realtime_listener = realtime_use_case.clone();
+    tokio::spawn(async move {
+        realtime_listener
+            .run_listener_loop(std::time::Duration::from_secs(5))
+            .await;
+    });
+    println!("✅ Done");
+
+    // Webhook 디스패처: 아웃박스 이벤트를 구독별로 나누고 서명된 페이로드를 전송 (실패 시 지수 백오프로 재시도)
+    let webhook_dispatch_interval = std::env::var("WEBHOOK_DISPATCH_INTERVAL_SECONDS")
+        .unwrap_or_else(|_| "5".to_string())
+        .parse::<u64>()
+        .unwrap_or(5);
+    print!("🪝 Starting webhook dispatcher... ");
+    let webhook_dispatcher = webhook_use_case.clone();
+    tokio::spawn(async move {
+        webhook_dispatcher
+            .run_dispatch_loop(std::time::Duration::from_secs(webhook_dispatch_interval))
+            .await;
+    });
+    println!("✅ Done (Interval: {}s)", webhook_dispatch_interval);
+
     // Cache configuration
     print!("💾 Configuring cache... ");
     let cache_enabled = std::env::var("CACHE_ENABLED")
@@ -438,6 +726,43 @@ async fn main() -> std::io::Result<()> {
                     .configure(|cfg| {
                         project_controller::configure_routes(cfg, project_use_case.clone())
                     })
+                    // /projects 스코프보다 먼저 등록해야 번들/데이터셋 경로가 가려지지 않음
+                    .configure(|cfg| {
+                        annotation_bundle_controller::configure_routes(cfg, annotation_bundle_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        dataset_export_controller::configure_routes(cfg, dataset_export_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        dataset_release_controller::configure_routes(cfg, dataset_release_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        trash_controller::configure_routes(cfg, trash_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        label_controller::configure_routes(cfg, label_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        worklist_controller::configure_routes(cfg, worklist_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        edit_lock_controller::configure_routes(cfg, edit_lock_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        realtime_controller::configure_routes(cfg, realtime_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        webhook_controller::configure_routes(cfg, webhook_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        measurement_controller::configure_routes(cfg, measurement_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        annotation_propagation_controller::configure_routes(cfg, propagation_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        storage_quota_controller::configure_routes(cfg, storage_quota_use_case.clone())
+                    })
                     // ========================================
                     // 📊 프로젝트-사용자 매트릭스 API (병합됨)
                     // ========================================
@@ -486,9 +811,48 @@ async fn main() -> std::io::Result<()> {
                             user_project_matrix_use_case.clone(),
                         )
                     })
+                    .configure(|cfg| {
+                        storage_reconciliation_controller::configure_routes(
+                            cfg,
+                            storage_reconciliation_use_case.clone(),
+                        )
+                    })
+                    // ========================================
+                    // 💾 로컬 스토리지 Signed URL API (provider = "local"일 때만)
+                    // ========================================
+                    .configure(|cfg| {
+                        if let Some(local_storage) = &local_object_storage {
+                            local_storage_controller::configure_routes(cfg, local_storage.clone())
+                        }
+                    })
                     // ========================================
                     // 🎨 어노테이션 및 마스크 관리 API
                     // ========================================
+                    // /annotations 스코프보다 먼저 등록해야 하위 경로가 가려지지 않음
+                    .configure(|cfg| {
+                        mask_import_controller::configure_routes(cfg, mask_import_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        multipart_upload_controller::configure_routes(cfg, multipart_upload_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        mask_group_archive_controller::configure_routes(cfg, mask_group_archive_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        mask_validation_controller::configure_routes(cfg, mask_validation_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        mask_preview_controller::configure_routes(cfg, mask_preview_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        mask_group_lineage_controller::configure_routes(cfg, mask_group_lineage_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        comment_controller::configure_routes(cfg, comment_use_case.clone())
+                    })
+                    .configure(|cfg| {
+                        annotation_review_controller::configure_routes(cfg, annotation_use_case.clone())
+                    })
                     .configure(|cfg| {
                         annotation_controller::configure_routes(cfg, annotation_use_case.clone())
                     })
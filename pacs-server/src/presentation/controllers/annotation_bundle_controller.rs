@@ -0,0 +1,181 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder, ResponseError};
+use actix_web::web::Bytes;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::stream::{self, StreamExt};
+use std::io::Write;
+use std::sync::Arc;
+use crate::application::dto::annotation_bundle_dto::{BundleExportQuery, BundleImportQuery, BundleImportReport};
+use crate::application::use_cases::AnnotationBundleUseCase;
+use crate::domain::entities::BundleRecord;
+use crate::domain::ServiceError;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 가져오기 요청 본문의 최대 크기 (256 MiB)
+const MAX_IMPORT_PAYLOAD_SIZE: usize = 256 * 1024 * 1024;
+
+/// 레코드 묶음을 NDJSON 청크로 변환하는 인코더 (선택적으로 gzip 스트림 압축)
+struct NdjsonEncoder {
+    gzip: Option<GzEncoder<Vec<u8>>>,
+}
+
+impl NdjsonEncoder {
+    fn new(gzip: bool) -> Self {
+        Self {
+            gzip: gzip.then(|| GzEncoder::new(Vec::new(), Compression::default())),
+        }
+    }
+
+    fn encode(&mut self, records: &[BundleRecord]) -> std::io::Result<Bytes> {
+        let mut lines = Vec::new();
+        for record in records {
+            serde_json::to_writer(&mut lines, record)?;
+            lines.push(b'\n');
+        }
+
+        match self.gzip.as_mut() {
+            Some(encoder) => {
+                encoder.write_all(&lines)?;
+                encoder.flush()?;
+                Ok(Bytes::from(std::mem::take(encoder.get_mut())))
+            }
+            None => Ok(Bytes::from(lines)),
+        }
+    }
+
+    fn finish(self) -> std::io::Result<Bytes> {
+        match self.gzip {
+            Some(encoder) => Ok(Bytes::from(encoder.finish()?)),
+            None => Ok(Bytes::new()),
+        }
+    }
+}
+
+/// 프로젝트 어노테이션 번들 내보내기 (NDJSON 스트리밍)
+///
+/// 프로젝트 멤버 또는 관리자만 내보낼 수 있으며, 공유되었거나 본인이 작성한 어노테이션만 포함됩니다.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/annotations/export",
+    tag = "annotations",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("gzip" = Option<bool>, Query, description = "gzip 압축 여부 (기본값 false)")
+    ),
+    responses(
+        (status = 200, description = "NDJSON bundle stream (header, annotation, history, mask_group records)", content_type = "application/x-ndjson"),
+        (status = 401, description = "Missing X-User-ID or not a member of the project"),
+        (status = 404, description = "Project not found"),
+    )
+)]
+pub async fn export_annotations<R>(
+    project_id: web::Path<i32>,
+    query: web::Query<BundleExportQuery>,
+    use_case: web::Data<Arc<AnnotationBundleUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::AnnotationBundleRepository + Send + Sync + 'static,
+{
+    let project_id = project_id.into_inner();
+    let gzip = query.gzip.unwrap_or(false);
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    let records = match use_case.export_project(project_id, user_id).await {
+        Ok(records) => records,
+        Err(e) => return e.error_response(),
+    };
+
+    // 레코드 묶음마다 청크를 내보내고, 스트림이 끝나면 gzip 트레일러를 붙임
+    let body = stream::unfold(
+        (Box::pin(records), Some(NdjsonEncoder::new(gzip))),
+        |(mut records, encoder)| async move {
+            let mut encoder = encoder?;
+            match records.next().await {
+                Some(Ok(batch)) => {
+                    let chunk = encoder.encode(&batch).map_err(|e| ServiceError::ExternalServiceError(e.to_string()));
+                    Some((chunk, (records, Some(encoder))))
+                }
+                Some(Err(e)) => Some((Err(e), (records, None))),
+                None => {
+                    let chunk = encoder.finish().map_err(|e| ServiceError::ExternalServiceError(e.to_string()));
+                    Some((chunk, (records, None)))
+                }
+            }
+        },
+    );
+
+    let (content_type, extension) = if gzip {
+        ("application/gzip", "ndjson.gz")
+    } else {
+        ("application/x-ndjson", "ndjson")
+    };
+
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"project_{}_annotations.{}\"", project_id, extension),
+        ))
+        .streaming(body)
+}
+
+/// 어노테이션 번들 가져오기
+///
+/// 요청 본문은 NDJSON 또는 gzip 압축된 NDJSON이며, 충돌이 있으면 아무것도 저장하지 않고 409를 반환합니다.
+/// 대상 프로젝트의 멤버 또는 관리자만 가져올 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/api/annotations/import",
+    tag = "annotations",
+    params(
+        ("dry_run" = Option<bool>, Query, description = "충돌만 확인하고 저장하지 않음 (기본값 false)"),
+        ("project_name" = Option<String>, Query, description = "대상 프로젝트 이름 (기본값: 번들 헤더의 프로젝트 이름)")
+    ),
+    request_body(content = String, content_type = "application/x-ndjson", description = "NDJSON bundle (optionally gzipped)"),
+    responses(
+        (status = 200, description = "Bundle imported (or dry run finished)", body = BundleImportReport),
+        (status = 400, description = "Malformed bundle"),
+        (status = 401, description = "Missing X-User-ID or not a member of the target project"),
+        (status = 409, description = "Bundle has conflicts; nothing was imported", body = BundleImportReport),
+    )
+)]
+pub async fn import_annotations<R>(
+    body: web::Bytes,
+    query: web::Query<BundleImportQuery>,
+    use_case: web::Data<Arc<AnnotationBundleUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::AnnotationBundleRepository + Send + Sync + 'static,
+{
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.import_bundle(&body, query.into_inner(), user_id).await {
+        Ok(report) if report.dry_run || report.applied => HttpResponse::Ok().json(report),
+        Ok(report) => HttpResponse::Conflict().json(report),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<R>(cfg: &mut web::ServiceConfig, use_case: Arc<AnnotationBundleUseCase<R>>)
+where
+    R: crate::domain::repositories::AnnotationBundleRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::resource("/projects/{project_id}/annotations/export")
+                .route(web::get().to(export_annotations::<R>)),
+        )
+        .service(
+            web::resource("/annotations/import")
+                .app_data(web::PayloadConfig::new(MAX_IMPORT_PAYLOAD_SIZE))
+                .route(web::post().to(import_annotations::<R>)),
+        );
+}
@@ -3,11 +3,12 @@ use serde_json::json;
 use std::sync::Arc;
 use crate::application::dto::annotation_dto::{
     CreateAnnotationRequest, UpdateAnnotationRequest,
-    AnnotationResponse, AnnotationListResponse
+    AnnotationResponse, AnnotationListResponse, AnnotationSearchRequest, AnnotationSearchResponse
 };
 use crate::application::use_cases::AnnotationUseCase;
 use crate::domain::services::annotation_service::AnnotationService;
 use crate::domain::ServiceError;
+use crate::domain::entities::AnnotationReviewStatus;
 use crate::infrastructure::repositories::{AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl};
 use crate::domain::services::AnnotationServiceImpl;
 
@@ -101,9 +102,12 @@ pub async fn get_annotation(
         ("user_id" = Option<i32>, Query, description = "사용자 ID로 필터링"),
         ("project_id" = Option<i32>, Query, description = "프로젝트 ID로 필터링"),
         ("viewer_software" = Option<String>, Query, description = "뷰어 소프트웨어로 필터링"),
+        ("review_status" = Option<AnnotationReviewStatus>, Query, description = "검토 상태로 필터링 (DRAFT, SUBMITTED, APPROVED, REJECTED, LOCKED)"),
+        ("reviewer_id" = Option<i32>, Query, description = "배정된 검토자 ID로 필터링"),
     ),
     responses(
         (status = 200, description = "List annotations successfully", body = AnnotationListResponse),
+        (status = 400, description = "Invalid review_status"),
     )
 )]
 pub async fn list_annotations(
@@ -123,23 +127,40 @@ pub async fn list_annotations(
 
     // viewer_software 파라미터 추출
     let viewer_software = query.get("viewer_software").map(|s| s.as_str());
+
+    // review_status 파라미터 추출
+    let review_status = match query.get("review_status").map(|s| s.parse::<AnnotationReviewStatus>()) {
+        Some(Ok(status)) => Some(status),
+        Some(Err(msg)) => {
+            return HttpResponse::BadRequest().json(json!({
+                "error": "Validation Error",
+                "message": msg
+            }));
+        }
+        None => None,
+    };
+    let reviewer_id = query.get("reviewer_id").and_then(|s| s.parse::<i32>().ok());
+
     // 쿼리 파라미터에 따라 다른 메서드 호출
-    let result = if let Some(study_uid) = query.get("study_instance_uid") {
+    let result = if let Some(reviewer_id) = reviewer_id {
+        // 검토자에게 배정된 annotation 목록 (검토 대기열)
+        use_case.get_annotations_by_reviewer(reviewer_id, review_status).await
+    } else if let Some(study_uid) = query.get("study_instance_uid") {
         // study_instance_uid와 user_id가 모두 있으면 사용자별 study annotation 조회
         if query.get("user_id").is_some() {
-            use_case.get_annotations_by_user_with_viewer(user_id, viewer_software).await
+            use_case.get_annotations_by_user_with_viewer(user_id, viewer_software, review_status).await
         } else {
-            use_case.get_annotations_by_study_with_viewer(study_uid, viewer_software).await
+            use_case.get_annotations_by_study_with_viewer(study_uid, viewer_software, review_status).await
         }
     } else if let Some(project_id_str) = query.get("project_id") {
         if let Ok(project_id) = project_id_str.parse::<i32>() {
-            use_case.get_annotations_by_project_with_viewer(project_id, viewer_software).await
+            use_case.get_annotations_by_project_with_viewer(project_id, viewer_software, review_status).await
         } else {
-            use_case.get_annotations_by_user_with_viewer(user_id, viewer_software).await
+            use_case.get_annotations_by_user_with_viewer(user_id, viewer_software, review_status).await
         }
     } else {
         // 기본적으로 사용자의 annotation 목록 반환 (user_id 쿼리 파라미터가 있으면 그것을 사용)
-        use_case.get_annotations_by_user_with_viewer(user_id, viewer_software).await
+        use_case.get_annotations_by_user_with_viewer(user_id, viewer_software, review_status).await
     };
 
     match result {
@@ -151,6 +172,54 @@ pub async fn list_annotations(
     }
 }
 
+/// Annotation 통합 검색
+///
+/// X-User-ID 헤더의 사용자가 볼 수 있는 어노테이션
+/// (프로젝트 멤버이면서 공유되었거나 본인이 작성한 어노테이션)만 반환합니다.
+#[utoipa::path(
+    post,
+    path = "/api/annotations/search",
+    tag = "annotations",
+    request_body = AnnotationSearchRequest,
+    responses(
+        (status = 200, description = "Search annotations successfully", body = AnnotationSearchResponse),
+        (status = 400, description = "Invalid filter, limit or cursor"),
+        (status = 401, description = "Missing or invalid X-User-ID header"),
+    )
+)]
+pub async fn search_annotations(
+    req: web::Json<AnnotationSearchRequest>,
+    use_case: web::Data<Arc<AnnotationUseCase<AnnotationServiceImpl<AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl>>>>,
+    http_req: HttpRequest,
+) -> impl Responder {
+    let viewer_id = match http_req
+        .headers()
+        .get("X-User-ID")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.parse::<i32>().ok())
+    {
+        Some(viewer_id) => viewer_id,
+        None => {
+            return HttpResponse::Unauthorized().json(json!({
+                "error": "Unauthorized",
+                "message": "X-User-ID header is required"
+            }));
+        }
+    };
+
+    match use_case.search_annotations(req.into_inner(), viewer_id).await {
+        Ok(result) => HttpResponse::Ok().json(result),
+        Err(ServiceError::ValidationError(msg)) => HttpResponse::BadRequest().json(json!({
+            "error": "Validation Error",
+            "message": msg
+        })),
+        Err(e) => HttpResponse::InternalServerError().json(json!({
+            "error": "Internal Server Error",
+            "message": e.to_string()
+        })),
+    }
+}
+
 #[utoipa::path(
     put,
     path = "/api/annotations/{annotation_id}",
@@ -162,15 +231,24 @@ pub async fn list_annotations(
     responses(
         (status = 200, description = "Annotation updated successfully", body = AnnotationResponse),
         (status = 404, description = "Annotation not found"),
-        (status = 400, description = "Invalid request"),
+        (status = 400, description = "Invalid request, or locked by another user"),
     )
 )]
 pub async fn update_annotation(
     annotation_id: web::Path<i32>,
     req: web::Json<UpdateAnnotationRequest>,
     use_case: web::Data<Arc<AnnotationUseCase<AnnotationServiceImpl<AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl>>>>,
+    http_req: HttpRequest,
 ) -> impl Responder {
-    match use_case.update_annotation(*annotation_id, req.into_inner()).await {
+    // TODO: 실제 인증에서 user_id를 가져와야 함
+    let user_id = http_req
+        .headers()
+        .get("X-User-ID")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.parse::<i32>().ok())
+        .unwrap_or(1);
+
+    match use_case.update_annotation(*annotation_id, req.into_inner(), user_id).await {
         Ok(annotation) => HttpResponse::Ok().json(annotation),
         Err(ServiceError::NotFound(msg)) => HttpResponse::NotFound().json(json!({
             "error": "Not Found",
@@ -187,6 +265,9 @@ pub async fn update_annotation(
     }
 }
 
+/// Annotation 삭제
+///
+/// 어노테이션과 하위 마스크 그룹을 프로젝트 휴지통으로 이동합니다.
 #[utoipa::path(
     delete,
     path = "/api/annotations/{annotation_id}",
@@ -195,15 +276,25 @@ pub async fn update_annotation(
         ("annotation_id" = i32, Path, description = "Annotation ID")
     ),
     responses(
-        (status = 200, description = "Annotation deleted successfully"),
+        (status = 200, description = "Annotation moved to trash"),
+        (status = 400, description = "Annotation is locked"),
         (status = 404, description = "Annotation not found"),
     )
 )]
 pub async fn delete_annotation(
     annotation_id: web::Path<i32>,
     use_case: web::Data<Arc<AnnotationUseCase<AnnotationServiceImpl<AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl>>>>,
+    http_req: HttpRequest,
 ) -> impl Responder {
-    match use_case.delete_annotation(*annotation_id).await {
+    // TODO: 실제 인증에서 user_id를 가져와야 함
+    let user_id = http_req
+        .headers()
+        .get("X-User-ID")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.parse::<i32>().ok())
+        .unwrap_or(1);
+
+    match use_case.delete_annotation(*annotation_id, user_id).await {
         Ok(_) => HttpResponse::Ok().json(json!({
             "message": "Annotation deleted successfully"
         })),
@@ -211,6 +302,10 @@ pub async fn delete_annotation(
             "error": "Not Found",
             "message": msg
         })),
+        Err(ServiceError::ValidationError(msg)) => HttpResponse::BadRequest().json(json!({
+            "error": "Validation Error",
+            "message": msg
+        })),
         Err(e) => HttpResponse::InternalServerError().json(json!({
             "error": "Internal Server Error",
             "message": e.to_string()
@@ -224,6 +319,7 @@ pub fn configure_routes(cfg: &mut web::ServiceConfig, use_case: Arc<AnnotationUs
             web::scope("/annotations")
                 .route("", web::post().to(create_annotation))
                 .route("", web::get().to(list_annotations))
+                .route("/search", web::post().to(search_annotations))
                 .route("/{annotation_id}", web::get().to(get_annotation))
                 .route("/{annotation_id}", web::put().to(update_annotation))
                 .route("/{annotation_id}", web::delete().to(delete_annotation)),
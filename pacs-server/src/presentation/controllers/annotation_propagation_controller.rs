@@ -0,0 +1,104 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::annotation_propagation_dto::{
+    LesionTrackingQuery, LesionTrackingResponse, PropagateAnnotationsRequest, PropagateAnnotationsResponse,
+};
+use crate::application::use_cases::AnnotationPropagationUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 어노테이션을 같은 환자의 후속 Study로 전파
+///
+/// 복사본은 요청한 사용자의 DRAFT 어노테이션으로 만들어지며 원본 어노테이션과 연결됩니다.
+/// Series는 Series Description, 없으면 Modality로 대응시키고, 클라이언트 측정값은 복사하지 않습니다.
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/propagations",
+    tag = "annotation-propagation",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = PropagateAnnotationsRequest,
+    responses(
+        (status = 200, description = "Annotations propagated", body = PropagateAnnotationsResponse),
+        (status = 400, description = "Studies belong to different patients or annotations are not on the source study"),
+        (status = 401, description = "Not a project member"),
+        (status = 404, description = "Study or annotation not found"),
+        (status = 409, description = "Annotation was propagated concurrently"),
+    )
+)]
+pub async fn propagate_annotations<R>(
+    path: web::Path<i32>,
+    req: web::Json<PropagateAnnotationsRequest>,
+    use_case: web::Data<Arc<AnnotationPropagationUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::AnnotationPropagationRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.propagate(project_id, req.into_inner(), user_id).await {
+        Ok(result) => HttpResponse::Ok().json(result),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 환자의 병변별 시점 측정값 (병변 ID는 최초 어노테이션 ID)
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/lesion-tracking",
+    tag = "annotation-propagation",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("patient_id" = String, Query, description = "Patient ID"),
+        ("lesion_id" = Option<i32>, Query, description = "Lesion ID (root annotation ID)"),
+        ("tracked_only" = Option<bool>, Query, description = "Only lesions annotated on two or more studies (default false)")
+    ),
+    responses(
+        (status = 200, description = "Lesions with measurements per timepoint", body = LesionTrackingResponse),
+        (status = 400, description = "Missing patient ID"),
+        (status = 401, description = "Not a project member"),
+    )
+)]
+pub async fn track_lesions<R>(
+    path: web::Path<i32>,
+    query: web::Query<LesionTrackingQuery>,
+    use_case: web::Data<Arc<AnnotationPropagationUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::AnnotationPropagationRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.track_lesions(project_id, query.into_inner(), user_id).await {
+        Ok(lesions) => HttpResponse::Ok().json(lesions),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<R>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<AnnotationPropagationUseCase<R>>,
+)
+where
+    R: crate::domain::repositories::AnnotationPropagationRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/propagations")
+                .route("", web::post().to(propagate_annotations::<R>))
+        )
+        .service(
+            web::scope("/projects/{project_id}/lesion-tracking")
+                .route("", web::get().to(track_lesions::<R>))
+        );
+}
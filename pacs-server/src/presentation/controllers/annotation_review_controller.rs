@@ -0,0 +1,151 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::annotation_dto::AnnotationResponse;
+use crate::application::dto::annotation_review_dto::{
+    AssignReviewerRequest, ChangeReviewStatusRequest, CreateReviewCommentRequest,
+    ReviewCommentListResponse, ReviewCommentResponse,
+};
+use crate::application::use_cases::AnnotationUseCase;
+use crate::infrastructure::repositories::{AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl};
+use crate::domain::services::AnnotationServiceImpl;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 어노테이션 검토 상태 전환
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/review/status",
+    tag = "annotations",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    request_body = ChangeReviewStatusRequest,
+    responses(
+        (status = 200, description = "Review status changed successfully", body = AnnotationResponse),
+        (status = 400, description = "Invalid transition"),
+        (status = 401, description = "User is not allowed to perform this transition"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn change_review_status(
+    annotation_id: web::Path<i32>,
+    req: web::Json<ChangeReviewStatusRequest>,
+    use_case: web::Data<Arc<AnnotationUseCase<AnnotationServiceImpl<AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl>>>>,
+    http_req: HttpRequest,
+) -> impl Responder {
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.change_review_status(*annotation_id, req.into_inner(), user_id).await {
+        Ok(annotation) => HttpResponse::Ok().json(annotation),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 어노테이션 검토자 배정
+#[utoipa::path(
+    put,
+    path = "/api/annotations/{annotation_id}/review/reviewer",
+    tag = "annotations",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    request_body = AssignReviewerRequest,
+    responses(
+        (status = 200, description = "Reviewer assigned successfully", body = AnnotationResponse),
+        (status = 400, description = "Reviewer does not have a reviewer role in the project"),
+        (status = 401, description = "Not allowed to comment on this annotation"),
+        (status = 404, description = "Annotation or reviewer not found"),
+    )
+)]
+pub async fn assign_reviewer(
+    annotation_id: web::Path<i32>,
+    req: web::Json<AssignReviewerRequest>,
+    use_case: web::Data<Arc<AnnotationUseCase<AnnotationServiceImpl<AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl>>>>,
+    http_req: HttpRequest,
+) -> impl Responder {
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.assign_reviewer(*annotation_id, req.into_inner(), user_id).await {
+        Ok(annotation) => HttpResponse::Ok().json(annotation),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 어노테이션 검토 코멘트 작성
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/review/comments",
+    tag = "annotations",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    request_body = CreateReviewCommentRequest,
+    responses(
+        (status = 201, description = "Review comment created successfully", body = ReviewCommentResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Not allowed to comment on this annotation"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn create_review_comment(
+    annotation_id: web::Path<i32>,
+    req: web::Json<CreateReviewCommentRequest>,
+    use_case: web::Data<Arc<AnnotationUseCase<AnnotationServiceImpl<AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl>>>>,
+    http_req: HttpRequest,
+) -> impl Responder {
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.add_review_comment(*annotation_id, req.into_inner(), user_id).await {
+        Ok(comment) => HttpResponse::Created().json(comment),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 어노테이션 검토 코멘트 목록 조회
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/review/comments",
+    tag = "annotations",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    responses(
+        (status = 200, description = "Review comments retrieved successfully", body = ReviewCommentListResponse),
+        (status = 401, description = "Not allowed to view this annotation's review comments"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn list_review_comments(
+    annotation_id: web::Path<i32>,
+    use_case: web::Data<Arc<AnnotationUseCase<AnnotationServiceImpl<AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl>>>>,
+    http_req: HttpRequest,
+) -> impl Responder {
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_review_comments(*annotation_id, user_id).await {
+        Ok(comments) => HttpResponse::Ok().json(comments),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes(cfg: &mut web::ServiceConfig, use_case: Arc<AnnotationUseCase<AnnotationServiceImpl<AnnotationRepositoryImpl, UserRepositoryImpl, ProjectRepositoryImpl>>>) {
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/annotations/{annotation_id}/review")
+                .route("/status", web::post().to(change_review_status))
+                .route("/reviewer", web::put().to(assign_reviewer))
+                .route("/comments", web::post().to(create_review_comment))
+                .route("/comments", web::get().to(list_review_comments)),
+        );
+}
@@ -0,0 +1,351 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::comment_dto::{
+    CommentHistoryResponse, CommentResponse, CommentThreadListResponse, CreateCommentRequest,
+    ResolveCommentRequest, UpdateCommentRequest,
+};
+use crate::application::use_cases::CommentUseCase;
+use crate::domain::entities::CommentTargetType;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 어노테이션 코멘트 스레드 목록 조회
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/comments",
+    tag = "comments",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    responses(
+        (status = 200, description = "Comment threads retrieved successfully", body = CommentThreadListResponse),
+        (status = 401, description = "User cannot view this annotation"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn list_annotation_comments<CR, AS, MGS, PS>(
+    annotation_id: web::Path<i32>,
+    use_case: web::Data<Arc<CommentUseCase<CR, AS, MGS, PS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    CR: crate::domain::repositories::CommentRepository + Send + Sync + 'static,
+    AS: crate::domain::services::AnnotationService + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    PS: crate::domain::services::ProjectService + Send + Sync + 'static,
+{
+    let annotation_id = annotation_id.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_comments(annotation_id, CommentTargetType::Annotation, annotation_id, user_id).await {
+        Ok(threads) => HttpResponse::Ok().json(threads),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 어노테이션 코멘트 작성
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/comments",
+    tag = "comments",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    request_body = CreateCommentRequest,
+    responses(
+        (status = 201, description = "Comment created successfully", body = CommentResponse),
+        (status = 400, description = "Invalid body, parent or mention"),
+        (status = 401, description = "User cannot view this annotation"),
+        (status = 404, description = "Annotation or parent comment not found"),
+    )
+)]
+pub async fn create_annotation_comment<CR, AS, MGS, PS>(
+    annotation_id: web::Path<i32>,
+    req: web::Json<CreateCommentRequest>,
+    use_case: web::Data<Arc<CommentUseCase<CR, AS, MGS, PS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    CR: crate::domain::repositories::CommentRepository + Send + Sync + 'static,
+    AS: crate::domain::services::AnnotationService + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    PS: crate::domain::services::ProjectService + Send + Sync + 'static,
+{
+    let annotation_id = annotation_id.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case
+        .create_comment(annotation_id, CommentTargetType::Annotation, annotation_id, req.into_inner(), user_id)
+        .await
+    {
+        Ok(comment) => HttpResponse::Created().json(comment),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 마스크 그룹 코멘트 스레드 목록 조회
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/comments",
+    tag = "comments",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask Group ID")
+    ),
+    responses(
+        (status = 200, description = "Comment threads retrieved successfully", body = CommentThreadListResponse),
+        (status = 401, description = "User cannot view this annotation"),
+        (status = 404, description = "Annotation or mask group not found"),
+    )
+)]
+pub async fn list_mask_group_comments<CR, AS, MGS, PS>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<CommentUseCase<CR, AS, MGS, PS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    CR: crate::domain::repositories::CommentRepository + Send + Sync + 'static,
+    AS: crate::domain::services::AnnotationService + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    PS: crate::domain::services::ProjectService + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_comments(annotation_id, CommentTargetType::MaskGroup, group_id, user_id).await {
+        Ok(threads) => HttpResponse::Ok().json(threads),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 마스크 그룹 코멘트 작성
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/comments",
+    tag = "comments",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask Group ID")
+    ),
+    request_body = CreateCommentRequest,
+    responses(
+        (status = 201, description = "Comment created successfully", body = CommentResponse),
+        (status = 400, description = "Invalid body, parent or mention"),
+        (status = 401, description = "User cannot view this annotation"),
+        (status = 404, description = "Annotation, mask group or parent comment not found"),
+    )
+)]
+pub async fn create_mask_group_comment<CR, AS, MGS, PS>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<CreateCommentRequest>,
+    use_case: web::Data<Arc<CommentUseCase<CR, AS, MGS, PS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    CR: crate::domain::repositories::CommentRepository + Send + Sync + 'static,
+    AS: crate::domain::services::AnnotationService + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    PS: crate::domain::services::ProjectService + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case
+        .create_comment(annotation_id, CommentTargetType::MaskGroup, group_id, req.into_inner(), user_id)
+        .await
+    {
+        Ok(comment) => HttpResponse::Created().json(comment),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 코멘트 수정 (작성자만 가능)
+#[utoipa::path(
+    put,
+    path = "/api/comments/{comment_id}",
+    tag = "comments",
+    params(
+        ("comment_id" = i32, Path, description = "Comment ID")
+    ),
+    request_body = UpdateCommentRequest,
+    responses(
+        (status = 200, description = "Comment updated successfully", body = CommentResponse),
+        (status = 400, description = "Invalid body or mention"),
+        (status = 401, description = "User is not the author"),
+        (status = 404, description = "Comment not found"),
+    )
+)]
+pub async fn update_comment<CR, AS, MGS, PS>(
+    comment_id: web::Path<i32>,
+    req: web::Json<UpdateCommentRequest>,
+    use_case: web::Data<Arc<CommentUseCase<CR, AS, MGS, PS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    CR: crate::domain::repositories::CommentRepository + Send + Sync + 'static,
+    AS: crate::domain::services::AnnotationService + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    PS: crate::domain::services::ProjectService + Send + Sync + 'static,
+{
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.update_comment(*comment_id, req.into_inner(), user_id).await {
+        Ok(comment) => HttpResponse::Ok().json(comment),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 코멘트 삭제 (작성자만 가능)
+#[utoipa::path(
+    delete,
+    path = "/api/comments/{comment_id}",
+    tag = "comments",
+    params(
+        ("comment_id" = i32, Path, description = "Comment ID")
+    ),
+    responses(
+        (status = 204, description = "Comment deleted successfully"),
+        (status = 401, description = "User is not the author"),
+        (status = 404, description = "Comment not found"),
+    )
+)]
+pub async fn delete_comment<CR, AS, MGS, PS>(
+    comment_id: web::Path<i32>,
+    use_case: web::Data<Arc<CommentUseCase<CR, AS, MGS, PS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    CR: crate::domain::repositories::CommentRepository + Send + Sync + 'static,
+    AS: crate::domain::services::AnnotationService + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    PS: crate::domain::services::ProjectService + Send + Sync + 'static,
+{
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.delete_comment(*comment_id, user_id).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 코멘트 스레드 해결 / 미해결 상태 변경
+#[utoipa::path(
+    put,
+    path = "/api/comments/{comment_id}/resolve",
+    tag = "comments",
+    params(
+        ("comment_id" = i32, Path, description = "Comment ID")
+    ),
+    request_body = ResolveCommentRequest,
+    responses(
+        (status = 200, description = "Resolved state changed successfully", body = CommentResponse),
+        (status = 400, description = "Comment is a reply or deleted"),
+        (status = 401, description = "User cannot view this annotation"),
+        (status = 404, description = "Comment not found"),
+    )
+)]
+pub async fn resolve_comment<CR, AS, MGS, PS>(
+    comment_id: web::Path<i32>,
+    req: web::Json<ResolveCommentRequest>,
+    use_case: web::Data<Arc<CommentUseCase<CR, AS, MGS, PS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    CR: crate::domain::repositories::CommentRepository + Send + Sync + 'static,
+    AS: crate::domain::services::AnnotationService + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    PS: crate::domain::services::ProjectService + Send + Sync + 'static,
+{
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.set_resolved(*comment_id, req.into_inner(), user_id).await {
+        Ok(comment) => HttpResponse::Ok().json(comment),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 코멘트 변경 이력 조회
+#[utoipa::path(
+    get,
+    path = "/api/comments/{comment_id}/history",
+    tag = "comments",
+    params(
+        ("comment_id" = i32, Path, description = "Comment ID")
+    ),
+    responses(
+        (status = 200, description = "Comment history retrieved successfully", body = Vec<CommentHistoryResponse>),
+        (status = 401, description = "User cannot view this annotation"),
+        (status = 404, description = "Comment not found"),
+    )
+)]
+pub async fn get_comment_history<CR, AS, MGS, PS>(
+    comment_id: web::Path<i32>,
+    use_case: web::Data<Arc<CommentUseCase<CR, AS, MGS, PS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    CR: crate::domain::repositories::CommentRepository + Send + Sync + 'static,
+    AS: crate::domain::services::AnnotationService + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    PS: crate::domain::services::ProjectService + Send + Sync + 'static,
+{
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_comment_history(*comment_id, user_id).await {
+        Ok(history) => HttpResponse::Ok().json(history),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<CR, AS, MGS, PS>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<CommentUseCase<CR, AS, MGS, PS>>,
+)
+where
+    CR: crate::domain::repositories::CommentRepository + Send + Sync + 'static,
+    AS: crate::domain::services::AnnotationService + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    PS: crate::domain::services::ProjectService + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/annotations/{annotation_id}/mask-groups/{group_id}/comments")
+                .route("", web::get().to(list_mask_group_comments::<CR, AS, MGS, PS>))
+                .route("", web::post().to(create_mask_group_comment::<CR, AS, MGS, PS>))
+        )
+        .service(
+            web::scope("/annotations/{annotation_id}/comments")
+                .route("", web::get().to(list_annotation_comments::<CR, AS, MGS, PS>))
+                .route("", web::post().to(create_annotation_comment::<CR, AS, MGS, PS>))
+        )
+        .service(
+            web::scope("/comments/{comment_id}")
+                .route("", web::put().to(update_comment::<CR, AS, MGS, PS>))
+                .route("", web::delete().to(delete_comment::<CR, AS, MGS, PS>))
+                .route("/resolve", web::put().to(resolve_comment::<CR, AS, MGS, PS>))
+                .route("/history", web::get().to(get_comment_history::<CR, AS, MGS, PS>))
+        );
+}
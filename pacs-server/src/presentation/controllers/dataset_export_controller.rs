@@ -0,0 +1,135 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::dataset_export_dto::{
+    CreateDatasetExportRequest, DatasetExportJobListResponse, DatasetExportJobResponse,
+};
+use crate::application::use_cases::DatasetExportUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 데이터셋 내보내기 시작
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/dataset-exports",
+    tag = "dataset-exports",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = CreateDatasetExportRequest,
+    responses(
+        (status = 202, description = "Export job accepted", body = DatasetExportJobResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Project not found"),
+    )
+)]
+pub async fn create_dataset_export<SUS, DER>(
+    path: web::Path<i32>,
+    req: web::Json<CreateDatasetExportRequest>,
+    use_case: web::Data<Arc<DatasetExportUseCase<SUS, DER>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    DER: crate::domain::repositories::DatasetExportRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.start_export(project_id, req.into_inner(), user_id).await {
+        Ok(job) => HttpResponse::Accepted().json(job),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 데이터셋 내보내기 작업 목록 조회
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/dataset-exports",
+    tag = "dataset-exports",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Export jobs retrieved successfully", body = DatasetExportJobListResponse),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Project not found"),
+    )
+)]
+pub async fn list_dataset_exports<SUS, DER>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<DatasetExportUseCase<SUS, DER>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    DER: crate::domain::repositories::DatasetExportRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_export_jobs(project_id, user_id).await {
+        Ok(jobs) => HttpResponse::Ok().json(jobs),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 데이터셋 내보내기 작업 상태 조회
+///
+/// 완료된 작업은 결과 파일의 다운로드 Signed URL을 함께 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/dataset-exports/{job_id}",
+    tag = "dataset-exports",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("job_id" = i32, Path, description = "Export job ID")
+    ),
+    responses(
+        (status = 200, description = "Export job retrieved successfully", body = DatasetExportJobResponse),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Export job not found"),
+    )
+)]
+pub async fn get_dataset_export<SUS, DER>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<DatasetExportUseCase<SUS, DER>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    DER: crate::domain::repositories::DatasetExportRepository + Send + Sync + 'static,
+{
+    let (project_id, job_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_export_job(project_id, job_id, user_id).await {
+        Ok(job) => HttpResponse::Ok().json(job),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<SUS, DER>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<DatasetExportUseCase<SUS, DER>>,
+)
+where
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    DER: crate::domain::repositories::DatasetExportRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/dataset-exports")
+                .route("", web::post().to(create_dataset_export::<SUS, DER>))
+                .route("", web::get().to(list_dataset_exports::<SUS, DER>))
+                .route("/{job_id}", web::get().to(get_dataset_export::<SUS, DER>))
+        );
+}
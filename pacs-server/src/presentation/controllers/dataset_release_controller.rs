@@ -0,0 +1,178 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::dataset_release_dto::{
+    CreateDatasetReleaseRequest, DatasetReleaseDiffQuery, DatasetReleaseDiffResponse,
+    DatasetReleaseListResponse, DatasetReleaseResponse,
+};
+use crate::application::use_cases::DatasetReleaseUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 데이터셋 릴리스 생성
+///
+/// 선택한 어노테이션 버전과 마스크를 불변 스냅샷으로 고정합니다.
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/dataset-releases",
+    tag = "dataset-releases",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = CreateDatasetReleaseRequest,
+    responses(
+        (status = 201, description = "Release created successfully", body = DatasetReleaseResponse),
+        (status = 400, description = "Invalid name or empty selection"),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Project not found"),
+        (status = 409, description = "Release name already exists"),
+    )
+)]
+pub async fn create_dataset_release<SUS, DRR>(
+    path: web::Path<i32>,
+    req: web::Json<CreateDatasetReleaseRequest>,
+    use_case: web::Data<Arc<DatasetReleaseUseCase<SUS, DRR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    DRR: crate::domain::repositories::DatasetReleaseRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.create_release(project_id, req.into_inner(), user_id).await {
+        Ok(release) => HttpResponse::Created().json(release),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 데이터셋 릴리스 목록 조회
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/dataset-releases",
+    tag = "dataset-releases",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Releases retrieved successfully", body = DatasetReleaseListResponse),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Project not found"),
+    )
+)]
+pub async fn list_dataset_releases<SUS, DRR>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<DatasetReleaseUseCase<SUS, DRR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    DRR: crate::domain::repositories::DatasetReleaseRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_releases(project_id, user_id).await {
+        Ok(releases) => HttpResponse::Ok().json(releases),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 데이터셋 릴리스 조회 (매니페스트 다운로드 URL 포함)
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/dataset-releases/{release_id}",
+    tag = "dataset-releases",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("release_id" = i32, Path, description = "Release ID")
+    ),
+    responses(
+        (status = 200, description = "Release retrieved successfully", body = DatasetReleaseResponse),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Release not found"),
+    )
+)]
+pub async fn get_dataset_release<SUS, DRR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<DatasetReleaseUseCase<SUS, DRR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    DRR: crate::domain::repositories::DatasetReleaseRepository + Send + Sync + 'static,
+{
+    let (project_id, release_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_release(project_id, release_id, user_id).await {
+        Ok(release) => HttpResponse::Ok().json(release),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 두 데이터셋 릴리스 비교
+///
+/// 기준 릴리스 대비 추가/삭제/변경된 어노테이션(히스토리 ID 기준)과 마스크(체크섬 기준)를 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/dataset-releases/{release_id}/diff",
+    tag = "dataset-releases",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("release_id" = i32, Path, description = "Release ID"),
+        ("base" = i32, Query, description = "기준 릴리스 ID")
+    ),
+    responses(
+        (status = 200, description = "Release diff", body = DatasetReleaseDiffResponse),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Release not found"),
+    )
+)]
+pub async fn diff_dataset_releases<SUS, DRR>(
+    path: web::Path<(i32, i32)>,
+    query: web::Query<DatasetReleaseDiffQuery>,
+    use_case: web::Data<Arc<DatasetReleaseUseCase<SUS, DRR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    DRR: crate::domain::repositories::DatasetReleaseRepository + Send + Sync + 'static,
+{
+    let (project_id, release_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.diff_releases(project_id, release_id, query.base, user_id).await {
+        Ok(diff) => HttpResponse::Ok().json(diff),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<SUS, DRR>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<DatasetReleaseUseCase<SUS, DRR>>,
+)
+where
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    DRR: crate::domain::repositories::DatasetReleaseRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/dataset-releases")
+                .route("", web::post().to(create_dataset_release::<SUS, DRR>))
+                .route("", web::get().to(list_dataset_releases::<SUS, DRR>))
+                .route("/{release_id}", web::get().to(get_dataset_release::<SUS, DRR>))
+                .route("/{release_id}/diff", web::get().to(diff_dataset_releases::<SUS, DRR>))
+        );
+}
@@ -0,0 +1,214 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::edit_lock_dto::{
+    AcquireEditLockRequest, EditLockHeartbeatRequest, EditLockListQuery, EditLockListResponse, EditLockResponse,
+};
+use crate::application::use_cases::EditLockUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 프로젝트의 유효한 편집 잠금 조회
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/edit-locks",
+    tag = "edit-locks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("study_uid" = Option<String>, Query, description = "Study Instance UID")
+    ),
+    responses(
+        (status = 200, description = "Active edit locks", body = EditLockListResponse),
+        (status = 401, description = "Not a member of the project"),
+    )
+)]
+pub async fn list_edit_locks<ELR, ALR>(
+    path: web::Path<i32>,
+    query: web::Query<EditLockListQuery>,
+    use_case: web::Data<Arc<EditLockUseCase<ELR, ALR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    ELR: crate::domain::repositories::EditLockRepository + Send + Sync + 'static,
+    ALR: crate::domain::repositories::AccessLogRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_locks(project_id, query.into_inner(), user_id).await {
+        Ok(locks) => HttpResponse::Ok().json(locks),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// Study 또는 어노테이션 편집 잠금 획득
+///
+/// 이미 가진 잠금이면 만료 시간을 갱신합니다. 잠금은 TTL 안에 heartbeat로 갱신하지 않으면 만료됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/edit-locks",
+    tag = "edit-locks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = AcquireEditLockRequest,
+    responses(
+        (status = 200, description = "Lock acquired or renewed", body = EditLockResponse),
+        (status = 400, description = "Invalid target or TTL"),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Annotation not found"),
+        (status = 409, description = "Locked by another user"),
+    )
+)]
+pub async fn acquire_edit_lock<ELR, ALR>(
+    path: web::Path<i32>,
+    req: web::Json<AcquireEditLockRequest>,
+    use_case: web::Data<Arc<EditLockUseCase<ELR, ALR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    ELR: crate::domain::repositories::EditLockRepository + Send + Sync + 'static,
+    ALR: crate::domain::repositories::AccessLogRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.acquire_lock(project_id, req.into_inner(), user_id).await {
+        Ok(lock) => HttpResponse::Ok().json(lock),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 편집 잠금 갱신 (heartbeat)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/edit-locks/{lock_id}/heartbeat",
+    tag = "edit-locks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("lock_id" = i32, Path, description = "Edit lock ID")
+    ),
+    request_body = EditLockHeartbeatRequest,
+    responses(
+        (status = 200, description = "Lock renewed", body = EditLockResponse),
+        (status = 400, description = "Invalid TTL"),
+        (status = 401, description = "Not a member of the project or lock held by another user"),
+        (status = 404, description = "Lock not found or expired"),
+    )
+)]
+pub async fn heartbeat_edit_lock<ELR, ALR>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<EditLockHeartbeatRequest>,
+    use_case: web::Data<Arc<EditLockUseCase<ELR, ALR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    ELR: crate::domain::repositories::EditLockRepository + Send + Sync + 'static,
+    ALR: crate::domain::repositories::AccessLogRepository + Send + Sync + 'static,
+{
+    let (project_id, lock_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.heartbeat(project_id, lock_id, req.into_inner(), user_id).await {
+        Ok(lock) => HttpResponse::Ok().json(lock),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 본인 편집 잠금 해제
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/edit-locks/{lock_id}",
+    tag = "edit-locks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("lock_id" = i32, Path, description = "Edit lock ID")
+    ),
+    responses(
+        (status = 204, description = "Lock released"),
+        (status = 401, description = "Not a member of the project or lock held by another user"),
+        (status = 404, description = "Lock not found or expired"),
+    )
+)]
+pub async fn release_edit_lock<ELR, ALR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<EditLockUseCase<ELR, ALR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    ELR: crate::domain::repositories::EditLockRepository + Send + Sync + 'static,
+    ALR: crate::domain::repositories::AccessLogRepository + Send + Sync + 'static,
+{
+    let (project_id, lock_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.release_lock(project_id, lock_id, user_id).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 다른 사용자의 편집 잠금 강제 해제 (프로젝트 관리자)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/edit-locks/{lock_id}/force-release",
+    tag = "edit-locks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("lock_id" = i32, Path, description = "Edit lock ID")
+    ),
+    responses(
+        (status = 200, description = "Lock force-released", body = EditLockResponse),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Lock not found or expired"),
+    )
+)]
+pub async fn force_release_edit_lock<ELR, ALR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<EditLockUseCase<ELR, ALR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    ELR: crate::domain::repositories::EditLockRepository + Send + Sync + 'static,
+    ALR: crate::domain::repositories::AccessLogRepository + Send + Sync + 'static,
+{
+    let (project_id, lock_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.force_release_lock(project_id, lock_id, user_id).await {
+        Ok(lock) => HttpResponse::Ok().json(lock),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<ELR, ALR>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<EditLockUseCase<ELR, ALR>>,
+)
+where
+    ELR: crate::domain::repositories::EditLockRepository + Send + Sync + 'static,
+    ALR: crate::domain::repositories::AccessLogRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/edit-locks")
+                .route("", web::get().to(list_edit_locks::<ELR, ALR>))
+                .route("", web::post().to(acquire_edit_lock::<ELR, ALR>))
+                .route("/{lock_id}", web::delete().to(release_edit_lock::<ELR, ALR>))
+                .route("/{lock_id}/heartbeat", web::post().to(heartbeat_edit_lock::<ELR, ALR>))
+                .route("/{lock_id}/force-release", web::post().to(force_release_edit_lock::<ELR, ALR>))
+        );
+}
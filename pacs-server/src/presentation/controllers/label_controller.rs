@@ -0,0 +1,280 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::label_dto::{
+    ApplyLabelMigrationRequest, LabelImportResponse, LabelListResponse, LabelMigrationPreviewResponse,
+    LabelMigrationResponse, LabelRequest, LabelResponse,
+};
+use crate::application::use_cases::LabelUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 프로젝트 라벨 분류 체계 조회
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/labels",
+    tag = "labels",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Labels retrieved successfully", body = LabelListResponse),
+        (status = 401, description = "Not a member of the project"),
+    )
+)]
+pub async fn list_labels<LR>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<LabelUseCase<LR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    LR: crate::domain::repositories::LabelRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_labels(project_id, user_id).await {
+        Ok(labels) => HttpResponse::Ok().json(labels),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 라벨 추가 (프로젝트 관리자)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/labels",
+    tag = "labels",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = LabelRequest,
+    responses(
+        (status = 201, description = "Label created successfully", body = LabelResponse),
+        (status = 400, description = "Invalid label or conflicting name/alias"),
+        (status = 401, description = "Not a project admin"),
+    )
+)]
+pub async fn create_label<LR>(
+    path: web::Path<i32>,
+    req: web::Json<LabelRequest>,
+    use_case: web::Data<Arc<LabelUseCase<LR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    LR: crate::domain::repositories::LabelRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.create_label(project_id, req.into_inner(), user_id).await {
+        Ok(label) => HttpResponse::Created().json(label),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 라벨 수정 (프로젝트 관리자, 전체 필드 교체)
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/labels/{label_id}",
+    tag = "labels",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("label_id" = i32, Path, description = "Label ID")
+    ),
+    request_body = LabelRequest,
+    responses(
+        (status = 200, description = "Label updated successfully", body = LabelResponse),
+        (status = 400, description = "Invalid label, conflicting name/alias or hierarchy cycle"),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Label not found"),
+    )
+)]
+pub async fn update_label<LR>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<LabelRequest>,
+    use_case: web::Data<Arc<LabelUseCase<LR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    LR: crate::domain::repositories::LabelRepository + Send + Sync + 'static,
+{
+    let (project_id, label_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.update_label(project_id, label_id, req.into_inner(), user_id).await {
+        Ok(label) => HttpResponse::Ok().json(label),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 라벨 삭제 (프로젝트 관리자)
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/labels/{label_id}",
+    tag = "labels",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("label_id" = i32, Path, description = "Label ID")
+    ),
+    responses(
+        (status = 204, description = "Label deleted successfully"),
+        (status = 400, description = "Label has child labels"),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Label not found"),
+    )
+)]
+pub async fn delete_label<LR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<LabelUseCase<LR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    LR: crate::domain::repositories::LabelRepository + Send + Sync + 'static,
+{
+    let (project_id, label_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.delete_label(project_id, label_id, user_id).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// CSV로 라벨 가져오기 (프로젝트 관리자)
+///
+/// 헤더: `name,display_name,color,code_scheme,code_value,code_meaning,parent,allowed_tools,aliases`
+/// (`name` 외에는 선택, `allowed_tools`/`aliases`는 `;`로 구분, `parent`는 상위 라벨 이름)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/labels/import",
+    tag = "labels",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body(content = String, content_type = "text/csv", description = "Label taxonomy CSV"),
+    responses(
+        (status = 200, description = "Labels imported successfully", body = LabelImportResponse),
+        (status = 400, description = "Invalid CSV or resulting taxonomy"),
+        (status = 401, description = "Not a project admin"),
+    )
+)]
+pub async fn import_labels<LR>(
+    path: web::Path<i32>,
+    body: String,
+    use_case: web::Data<Arc<LabelUseCase<LR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    LR: crate::domain::repositories::LabelRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.import_csv(project_id, &body, user_id).await {
+        Ok(result) => HttpResponse::Ok().json(result),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 사용 중인 자유 입력 라벨과 정식 라벨 매핑 제안 조회
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/labels/migration",
+    tag = "labels",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Label usage and suggestions", body = LabelMigrationPreviewResponse),
+        (status = 401, description = "Not a member of the project"),
+    )
+)]
+pub async fn preview_label_migration<LR>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<LabelUseCase<LR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    LR: crate::domain::repositories::LabelRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.preview_migration(project_id, user_id).await {
+        Ok(preview) => HttpResponse::Ok().json(preview),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 자유 입력 라벨을 정식 라벨 이름으로 일괄 변경 (프로젝트 관리자)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/labels/migration",
+    tag = "labels",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = ApplyLabelMigrationRequest,
+    responses(
+        (status = 200, description = "Labels migrated successfully", body = LabelMigrationResponse),
+        (status = 400, description = "Project has no taxonomy or invalid mapping"),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Target label not found"),
+    )
+)]
+pub async fn apply_label_migration<LR>(
+    path: web::Path<i32>,
+    req: web::Json<ApplyLabelMigrationRequest>,
+    use_case: web::Data<Arc<LabelUseCase<LR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    LR: crate::domain::repositories::LabelRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.apply_migration(project_id, req.into_inner(), user_id).await {
+        Ok(result) => HttpResponse::Ok().json(result),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<LR>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<LabelUseCase<LR>>,
+)
+where
+    LR: crate::domain::repositories::LabelRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/labels")
+                .route("", web::get().to(list_labels::<LR>))
+                .route("", web::post().to(create_label::<LR>))
+                .route("/import", web::post().to(import_labels::<LR>))
+                .route("/migration", web::get().to(preview_label_migration::<LR>))
+                .route("/migration", web::post().to(apply_label_migration::<LR>))
+                .route("/{label_id}", web::put().to(update_label::<LR>))
+                .route("/{label_id}", web::delete().to(delete_label::<LR>))
+        );
+}
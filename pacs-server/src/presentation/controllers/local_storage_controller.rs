@@ -0,0 +1,143 @@
+use actix_web::{http::header, web, HttpRequest, HttpResponse, Responder};
+use futures::StreamExt;
+use serde_json::json;
+use std::sync::Arc;
+use crate::application::services::ObjectStorageError;
+use crate::infrastructure::external::{LocalObjectStorageService, LocalSignedUrlQuery};
+
+fn error_response(error: ObjectStorageError) -> HttpResponse {
+    match error {
+        ObjectStorageError::FileNotFound(msg) => HttpResponse::NotFound().json(json!({
+            "error": "Not Found",
+            "message": msg
+        })),
+        ObjectStorageError::PermissionDenied(msg) => HttpResponse::Forbidden().json(json!({
+            "error": "Forbidden",
+            "message": msg
+        })),
+        ObjectStorageError::InvalidRequest(msg) => HttpResponse::BadRequest().json(json!({
+            "error": "Validation Error",
+            "message": msg
+        })),
+        e => HttpResponse::InternalServerError().json(json!({
+            "error": "Storage Error",
+            "message": e.to_string()
+        })),
+    }
+}
+
+/// Signed URL로 객체 업로드 (로컬 스토리지)
+#[utoipa::path(
+    put,
+    path = "/api/storage/objects/{path}",
+    tag = "storage",
+    params(
+        ("path" = String, Path, description = "Object path"),
+        ("expires" = i64, Query, description = "Expiry (Unix timestamp)"),
+        ("signature" = String, Query, description = "HMAC-SHA256 signature"),
+        ("content_type" = Option<String>, Query, description = "Content type bound to the URL"),
+        ("upload_id" = Option<String>, Query, description = "Multipart upload ID"),
+        ("part_number" = Option<i32>, Query, description = "Multipart part number")
+    ),
+    request_body(content = Vec<u8>, content_type = "application/octet-stream"),
+    responses(
+        (status = 200, description = "Object stored; ETag header holds the SHA-256 checksum"),
+        (status = 400, description = "Invalid path or upload"),
+        (status = 403, description = "Invalid or expired signature"),
+        (status = 404, description = "Multipart upload not found"),
+    )
+)]
+pub async fn put_object(
+    path: web::Path<String>,
+    query: web::Query<LocalSignedUrlQuery>,
+    payload: web::Payload,
+    storage: web::Data<Arc<LocalObjectStorageService>>,
+    http_req: HttpRequest,
+) -> impl Responder {
+    let file_path = path.into_inner();
+    let query = query.into_inner();
+    if let Err(e) = storage.verify_signature("PUT", &file_path, &query) {
+        return error_response(e);
+    }
+
+    let request_content_type = http_req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+
+    let result = match (&query.upload_id, query.part_number) {
+        (Some(upload_id), Some(part_number)) => {
+            storage.write_part_stream(&file_path, upload_id, part_number, payload).await
+        }
+        _ => {
+            // S3와 마찬가지로 URL에 고정된 Content-Type과 다른 요청은 거부
+            if let (Some(signed), Some(sent)) = (&query.content_type, &request_content_type) {
+                if signed != sent {
+                    return error_response(ObjectStorageError::PermissionDenied(
+                        "Content-Type does not match the signed URL".to_string(),
+                    ));
+                }
+            }
+            let content_type = query.content_type.clone().or(request_content_type);
+            storage.write_object_stream(&file_path, payload, content_type.as_deref()).await
+        }
+    };
+
+    match result {
+        Ok(etag) => HttpResponse::Ok()
+            .insert_header((header::ETAG, format!("\"{}\"", etag)))
+            .finish(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Signed URL로 객체 다운로드 (로컬 스토리지)
+#[utoipa::path(
+    get,
+    path = "/api/storage/objects/{path}",
+    tag = "storage",
+    params(
+        ("path" = String, Path, description = "Object path"),
+        ("expires" = i64, Query, description = "Expiry (Unix timestamp)"),
+        ("signature" = String, Query, description = "HMAC-SHA256 signature")
+    ),
+    responses(
+        (status = 200, description = "Object content", content_type = "application/octet-stream"),
+        (status = 403, description = "Invalid or expired signature"),
+        (status = 404, description = "Object not found"),
+    )
+)]
+pub async fn get_object(
+    path: web::Path<String>,
+    query: web::Query<LocalSignedUrlQuery>,
+    storage: web::Data<Arc<LocalObjectStorageService>>,
+) -> impl Responder {
+    let file_path = path.into_inner();
+    if let Err(e) = storage.verify_signature("GET", &file_path, &query) {
+        return error_response(e);
+    }
+
+    match storage.read_object_stream(&file_path).await {
+        Ok((stream, metadata)) => {
+            let mut response = HttpResponse::Ok();
+            response
+                .content_type(metadata.mime_type.as_deref().unwrap_or("application/octet-stream"))
+                .no_chunking(metadata.file_size as u64);
+            if let Some(checksum) = &metadata.checksum {
+                response.insert_header((header::ETAG, format!("\"{}\"", checksum)));
+            }
+            response.streaming(Box::pin(stream.map(|chunk| chunk.map(web::Bytes::from))))
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+pub fn configure_routes(cfg: &mut web::ServiceConfig, storage: Arc<LocalObjectStorageService>) {
+    cfg.app_data(web::Data::new(storage))
+        .service(
+            web::scope("/storage/objects")
+                .route("/{path:.*}", web::put().to(put_object))
+                .route("/{path:.*}", web::get().to(get_object))
+        );
+}
@@ -0,0 +1,100 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use actix_web::web::Bytes;
+use chrono::Utc;
+use futures::stream::{self, StreamExt};
+use std::sync::Arc;
+use crate::application::dto::mask_group_archive_dto::MaskGroupArchiveQuery;
+use crate::application::use_cases::MaskGroupArchiveUseCase;
+use crate::domain::ServiceError;
+use crate::infrastructure::archive::ZipStreamWriter;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 마스크 그룹 ZIP 다운로드
+///
+/// 마스크 PNG와 마스크 메타데이터 `manifest.json`을 담은 ZIP을 스토리지에서 읽는 대로 스트리밍합니다.
+/// 슬라이스 범위와 라벨로 대상 마스크를 거를 수 있으며, 스토리지에 없는 마스크는 manifest의 `missing`에 기록됩니다.
+/// 다운로드 요청은 접근 로그에 기록됩니다.
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/archive",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID"),
+        ("slice_start" = Option<i32>, Query, description = "포함할 첫 슬라이스 인덱스"),
+        ("slice_end" = Option<i32>, Query, description = "포함할 마지막 슬라이스 인덱스"),
+        ("labels" = Option<String>, Query, description = "포함할 라벨 이름 (쉼표로 구분)")
+    ),
+    responses(
+        (status = 200, description = "ZIP stream of mask files and manifest.json (X-Mask-Count: number of selected masks)", content_type = "application/zip"),
+        (status = 400, description = "Invalid slice range or too many masks"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+    )
+)]
+pub async fn download_archive<MGS, AR, ALR>(
+    path: web::Path<(i32, i32)>,
+    query: web::Query<MaskGroupArchiveQuery>,
+    use_case: web::Data<Arc<MaskGroupArchiveUseCase<MGS, AR, ALR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    AR: crate::domain::repositories::AnnotationRepository + Send + Sync + 'static,
+    ALR: crate::domain::repositories::AccessLogRepository + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    let archive = match use_case.prepare_archive(annotation_id, group_id, query.into_inner(), user_id).await {
+        Ok(archive) => archive,
+        Err(e) => return e.error_response(),
+    };
+
+    // 항목마다 로컬 헤더와 데이터를 내보내고, 스트림이 끝나면 중앙 디렉터리를 붙임
+    let body = stream::unfold(
+        (archive.entries, Some(ZipStreamWriter::new(Utc::now()))),
+        |(mut entries, writer)| async move {
+            let mut writer = writer?;
+            match entries.next().await {
+                Some(Ok(entry)) => {
+                    let chunk = writer.add_entry(&entry.name, &entry.data).map(Bytes::from).map_err(ServiceError::from);
+                    Some((chunk, (entries, Some(writer))))
+                }
+                Some(Err(e)) => Some((Err(e), (entries, None))),
+                None => {
+                    let chunk = writer.finish().map(Bytes::from).map_err(ServiceError::from);
+                    Some((chunk, (entries, None)))
+                }
+            }
+        },
+    );
+
+    HttpResponse::Ok()
+        .content_type("application/zip")
+        .insert_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"{}\"", archive.file_name),
+        ))
+        .insert_header(("X-Mask-Count", archive.mask_count.to_string()))
+        .streaming(body)
+}
+
+pub fn configure_routes<MGS, AR, ALR>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<MaskGroupArchiveUseCase<MGS, AR, ALR>>,
+)
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    AR: crate::domain::repositories::AnnotationRepository + Send + Sync + 'static,
+    ALR: crate::domain::repositories::AccessLogRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/annotations/{annotation_id}/mask-groups/{group_id}/archive")
+                .route("", web::get().to(download_archive::<MGS, AR, ALR>))
+        );
+}
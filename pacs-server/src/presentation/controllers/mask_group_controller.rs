@@ -4,7 +4,8 @@ use std::sync::Arc;
 use crate::application::dto::mask_group_dto::{
     CreateMaskGroupRequest, UpdateMaskGroupRequest, MaskGroupResponse,
     MaskGroupListResponse, MaskGroupDetailResponse, SignedUrlRequest,
-    SignedUrlResponse, CompleteUploadRequest, CompleteUploadResponse
+    SignedUrlResponse, CompleteUploadRequest, CompleteUploadResponse,
+    BatchUploadUrlRequest, BatchDownloadUrlRequest, BatchSignedUrlResponse
 };
 use crate::application::use_cases::MaskGroupUseCase;
 use crate::domain::ServiceError;
@@ -350,7 +351,7 @@ where
     request_body = SignedUrlRequest,
     responses(
         (status = 200, description = "Signed URL generated successfully", body = SignedUrlResponse),
-        (status = 400, description = "Invalid request"),
+        (status = 400, description = "Invalid request or storage quota exceeded"),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "Mask group not found"),
         (status = 403, description = "Forbidden - insufficient permissions"),
@@ -407,6 +408,142 @@ where
     }
 }
 
+/// Signed URL 일괄 생성 (업로드용)
+///
+/// 파일 목록의 업로드 URL을 한 번에 발급합니다. 권한과 저장 용량 한도는 그룹에 대해 한 번 확인하며 TTL은 모든 URL에 동일하게 적용됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/upload-urls",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask Group ID")
+    ),
+    request_body = BatchUploadUrlRequest,
+    responses(
+        (status = 200, description = "Signed URLs generated successfully", body = BatchSignedUrlResponse),
+        (status = 400, description = "Invalid files or TTL, or storage quota exceeded"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+    )
+)]
+pub async fn generate_upload_urls<MGS, SUS>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<BatchUploadUrlRequest>,
+    use_case: web::Data<Arc<MaskGroupUseCase<MGS, SUS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync,
+    SUS: crate::application::services::SignedUrlService + Send + Sync,
+{
+    let (annotation_id, group_id) = path.into_inner();
+
+    // X-User-ID 헤더에서 user_id 추출
+    let user_id = http_req
+        .headers()
+        .get("X-User-ID")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.parse::<i32>().ok())
+        .unwrap_or(1); // 기본값은 1 (기존 코드와 호환)
+
+    match use_case.generate_upload_urls(annotation_id, group_id, req.into_inner(), user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(ServiceError::NotFound(msg)) => HttpResponse::NotFound().json(json!({
+            "error": "Not Found",
+            "message": msg
+        })),
+        Err(ServiceError::Unauthorized(msg)) => HttpResponse::Unauthorized().json(json!({
+            "error": "Unauthorized",
+            "message": msg
+        })),
+        Err(ServiceError::ValidationError(msg)) => HttpResponse::BadRequest().json(json!({
+            "error": "Validation Error",
+            "message": msg
+        })),
+        Err(ServiceError::AlreadyExists(msg)) => HttpResponse::Conflict().json(json!({
+            "error": "Already Exists",
+            "message": msg
+        })),
+        Err(ServiceError::DatabaseError(msg)) => HttpResponse::InternalServerError().json(json!({
+            "error": "Database Error",
+            "message": msg
+        })),
+        Err(ServiceError::ExternalServiceError(msg)) => HttpResponse::InternalServerError().json(json!({
+            "error": "External Service Error",
+            "message": msg
+        })),
+    }
+}
+
+/// Signed URL 일괄 생성 (다운로드용)
+///
+/// 그룹의 모든 마스크 또는 지정한 슬라이스 인덱스/파일명의 다운로드 URL을 한 번에 발급합니다. 그룹에 없는 항목은 `missing_*`로 반환합니다.
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/download-urls",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask Group ID")
+    ),
+    request_body = BatchDownloadUrlRequest,
+    responses(
+        (status = 200, description = "Signed URLs generated successfully", body = BatchSignedUrlResponse),
+        (status = 400, description = "Invalid TTL or too many masks"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+    )
+)]
+pub async fn generate_download_urls<MGS, SUS>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<BatchDownloadUrlRequest>,
+    use_case: web::Data<Arc<MaskGroupUseCase<MGS, SUS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync,
+    SUS: crate::application::services::SignedUrlService + Send + Sync,
+{
+    let (annotation_id, group_id) = path.into_inner();
+
+    // X-User-ID 헤더에서 user_id 추출
+    let user_id = http_req
+        .headers()
+        .get("X-User-ID")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.parse::<i32>().ok())
+        .unwrap_or(1); // 기본값은 1 (기존 코드와 호환)
+
+    match use_case.generate_download_urls(annotation_id, group_id, req.into_inner(), user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(ServiceError::NotFound(msg)) => HttpResponse::NotFound().json(json!({
+            "error": "Not Found",
+            "message": msg
+        })),
+        Err(ServiceError::Unauthorized(msg)) => HttpResponse::Unauthorized().json(json!({
+            "error": "Unauthorized",
+            "message": msg
+        })),
+        Err(ServiceError::ValidationError(msg)) => HttpResponse::BadRequest().json(json!({
+            "error": "Validation Error",
+            "message": msg
+        })),
+        Err(ServiceError::AlreadyExists(msg)) => HttpResponse::Conflict().json(json!({
+            "error": "Already Exists",
+            "message": msg
+        })),
+        Err(ServiceError::DatabaseError(msg)) => HttpResponse::InternalServerError().json(json!({
+            "error": "Database Error",
+            "message": msg
+        })),
+        Err(ServiceError::ExternalServiceError(msg)) => HttpResponse::InternalServerError().json(json!({
+            "error": "External Service Error",
+            "message": msg
+        })),
+    }
+}
+
 /// 업로드 완료 처리
 #[utoipa::path(
     post,
@@ -494,6 +631,8 @@ where
                 .route("/{group_id}", web::put().to(update_mask_group::<MGS, SUS>))
                 .route("/{group_id}", web::delete().to(delete_mask_group::<MGS, SUS>))
                 .route("/{group_id}/upload-url", web::post().to(generate_upload_url::<MGS, SUS>))
+                .route("/{group_id}/upload-urls", web::post().to(generate_upload_urls::<MGS, SUS>))
+                .route("/{group_id}/download-urls", web::post().to(generate_download_urls::<MGS, SUS>))
                 .route("/{group_id}/complete-upload", web::post().to(complete_upload::<MGS, SUS>))
         );
 }
@@ -0,0 +1,152 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::mask_group_dto::MaskGroupResponse;
+use crate::application::dto::mask_group_lineage_dto::{
+    MaskGroupComparisonQuery, MaskGroupComparisonResponse, MaskGroupLineageResponse, SetDerivedFromRequest,
+};
+use crate::application::use_cases::MaskGroupLineageUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 어노테이션의 마스크 그룹 계보 조회
+///
+/// 마스크 그룹을 파생 관계(`derived_from_id`)에 따라 루트부터 깊이 우선 순서로 반환합니다.
+/// 어노테이션 소유자가 아니면 자신이 만든 그룹만 보입니다.
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-groups/lineage",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    responses(
+        (status = 200, description = "Mask group lineage", body = MaskGroupLineageResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn get_lineage<MGS>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<MaskGroupLineageUseCase<MGS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+{
+    let annotation_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_lineage(annotation_id, user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 마스크 그룹 파생 원본 설정
+///
+/// 같은 어노테이션의 다른 마스크 그룹을 파생 원본으로 지정하거나 `null`로 해제합니다.
+/// 순환이 생기거나 원본이 다른 어노테이션에 있으면 400을 반환합니다.
+#[utoipa::path(
+    put,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/derived-from",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID")
+    ),
+    request_body = SetDerivedFromRequest,
+    responses(
+        (status = 200, description = "Mask group updated", body = MaskGroupResponse),
+        (status = 400, description = "Invalid derived_from group, cycle, or locked by another user"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+    )
+)]
+pub async fn set_derived_from<MGS>(
+    path: web::Path<(i32, i32)>,
+    request: web::Json<SetDerivedFromRequest>,
+    use_case: web::Data<Arc<MaskGroupLineageUseCase<MGS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.set_derived_from(annotation_id, group_id, user_id, request.into_inner()).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 마스크 그룹 비교
+///
+/// 같은 슬라이스끼리 맞춰 라벨별 Dice, Jaccard, 부피 차이와 Hausdorff 거리(픽셀)를 계산합니다.
+/// 라벨이 한쪽에만 있는 슬라이스는 Hausdorff 거리에 반영하지 않고 `unmatched_slices`로 보고합니다.
+/// `reference_id`를 생략하면 그룹의 파생 원본을 기준으로 비교합니다.
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/comparison",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Candidate mask group ID"),
+        ("reference_id" = Option<i32>, Query, description = "기준 마스크 그룹 ID (기본값은 파생 원본)"),
+        ("labels" = Option<String>, Query, description = "비교할 라벨 (쉼표로 구분)")
+    ),
+    responses(
+        (status = 200, description = "Per-label comparison metrics", body = MaskGroupComparisonResponse),
+        (status = 400, description = "No reference group or too many masks"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+    )
+)]
+pub async fn compare_mask_groups<MGS>(
+    path: web::Path<(i32, i32)>,
+    query: web::Query<MaskGroupComparisonQuery>,
+    use_case: web::Data<Arc<MaskGroupLineageUseCase<MGS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.compare(annotation_id, group_id, user_id, query.into_inner()).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<MGS>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<MaskGroupLineageUseCase<MGS>>,
+)
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+{
+    // `/mask-groups/{group_id}`보다 먼저 등록되어야 함
+    cfg.app_data(web::Data::new(use_case))
+        .route(
+            "/annotations/{annotation_id}/mask-groups/lineage",
+            web::get().to(get_lineage::<MGS>),
+        )
+        .route(
+            "/annotations/{annotation_id}/mask-groups/{group_id}/derived-from",
+            web::put().to(set_derived_from::<MGS>),
+        )
+        .route(
+            "/annotations/{annotation_id}/mask-groups/{group_id}/comparison",
+            web::get().to(compare_mask_groups::<MGS>),
+        );
+}
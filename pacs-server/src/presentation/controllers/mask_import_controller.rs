@@ -0,0 +1,183 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::mask_import_dto::{
+    CreateMaskImportRequest, MaskImportJobListResponse, MaskImportJobResponse,
+    MaskImportUploadUrlRequest, MaskImportUploadUrlResponse,
+};
+use crate::application::use_cases::MaskImportUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 라벨 볼륨 원본 업로드 URL 생성
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/mask-imports/upload-url",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    request_body = MaskImportUploadUrlRequest,
+    responses(
+        (status = 200, description = "Upload URL generated successfully", body = MaskImportUploadUrlResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn generate_import_upload_url<MGS, MS, SUS, MIR>(
+    path: web::Path<i32>,
+    req: web::Json<MaskImportUploadUrlRequest>,
+    use_case: web::Data<Arc<MaskImportUseCase<MGS, MS, SUS, MIR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    MS: crate::domain::services::MaskService + Send + Sync + 'static,
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    MIR: crate::domain::repositories::MaskImportRepository + Send + Sync + 'static,
+{
+    let annotation_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.generate_source_upload_url(annotation_id, req.into_inner(), user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 라벨 볼륨 임포트 시작
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/mask-imports",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    request_body = CreateMaskImportRequest,
+    responses(
+        (status = 202, description = "Import job accepted", body = MaskImportJobResponse),
+        (status = 400, description = "Invalid request or unsupported format"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn create_mask_import<MGS, MS, SUS, MIR>(
+    path: web::Path<i32>,
+    req: web::Json<CreateMaskImportRequest>,
+    use_case: web::Data<Arc<MaskImportUseCase<MGS, MS, SUS, MIR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    MS: crate::domain::services::MaskService + Send + Sync + 'static,
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    MIR: crate::domain::repositories::MaskImportRepository + Send + Sync + 'static,
+{
+    let annotation_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.start_import(annotation_id, req.into_inner(), user_id).await {
+        Ok(job) => HttpResponse::Accepted().json(job),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 라벨 볼륨 임포트 작업 목록 조회
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-imports",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    responses(
+        (status = 200, description = "Import jobs retrieved successfully", body = MaskImportJobListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn list_mask_imports<MGS, MS, SUS, MIR>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<MaskImportUseCase<MGS, MS, SUS, MIR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    MS: crate::domain::services::MaskService + Send + Sync + 'static,
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    MIR: crate::domain::repositories::MaskImportRepository + Send + Sync + 'static,
+{
+    let annotation_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_import_jobs(annotation_id, user_id).await {
+        Ok(jobs) => HttpResponse::Ok().json(jobs),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 라벨 볼륨 임포트 작업 상태 조회
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-imports/{job_id}",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("job_id" = i32, Path, description = "Import job ID")
+    ),
+    responses(
+        (status = 200, description = "Import job retrieved successfully", body = MaskImportJobResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Import job not found"),
+    )
+)]
+pub async fn get_mask_import<MGS, MS, SUS, MIR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<MaskImportUseCase<MGS, MS, SUS, MIR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    MS: crate::domain::services::MaskService + Send + Sync + 'static,
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    MIR: crate::domain::repositories::MaskImportRepository + Send + Sync + 'static,
+{
+    let (annotation_id, job_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_import_job(annotation_id, job_id, user_id).await {
+        Ok(job) => HttpResponse::Ok().json(job),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<MGS, MS, SUS, MIR>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<MaskImportUseCase<MGS, MS, SUS, MIR>>,
+)
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    MS: crate::domain::services::MaskService + Send + Sync + 'static,
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+    MIR: crate::domain::repositories::MaskImportRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/annotations/{annotation_id}/mask-imports")
+                .route("", web::post().to(create_mask_import::<MGS, MS, SUS, MIR>))
+                .route("", web::get().to(list_mask_imports::<MGS, MS, SUS, MIR>))
+                .route("/upload-url", web::post().to(generate_import_upload_url::<MGS, MS, SUS, MIR>))
+                .route("/{job_id}", web::get().to(get_mask_import::<MGS, MS, SUS, MIR>))
+        );
+}
@@ -0,0 +1,64 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::mask_preview_dto::{ContactSheetQuery, MaskGroupContactSheetResponse};
+use crate::application::use_cases::MaskPreviewUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 마스크 그룹 contact sheet 조회
+///
+/// 그룹의 마스크 썸네일을 슬라이스 순서로 격자에 모은 이미지의 생성 상태와 서명된 다운로드 URL을 반환합니다.
+/// 마스크가 바뀌면 백그라운드 작업이 다시 만들며, 그동안에는 이전 이미지를 내려받을 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/contact-sheet",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID"),
+        ("expires_in" = Option<u64>, Query, description = "다운로드 URL 만료 시간 (초)")
+    ),
+    responses(
+        (status = 200, description = "Contact sheet status and download URL", body = MaskGroupContactSheetResponse),
+        (status = 400, description = "Invalid expiration"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+    )
+)]
+pub async fn get_contact_sheet<R, MGS, SUS>(
+    path: web::Path<(i32, i32)>,
+    query: web::Query<ContactSheetQuery>,
+    use_case: web::Data<Arc<MaskPreviewUseCase<R, MGS, SUS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::MaskPreviewRepository + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_contact_sheet(annotation_id, group_id, user_id, query.expires_in).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<R, MGS, SUS>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<MaskPreviewUseCase<R, MGS, SUS>>,
+)
+where
+    R: crate::domain::repositories::MaskPreviewRepository + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    SUS: crate::application::services::SignedUrlService + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .route(
+            "/annotations/{annotation_id}/mask-groups/{group_id}/contact-sheet",
+            web::get().to(get_contact_sheet::<R, MGS, SUS>),
+        );
+}
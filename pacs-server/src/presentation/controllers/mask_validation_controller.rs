@@ -0,0 +1,98 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::mask_validation_dto::{MaskGroupValidationResponse, MaskValidationRequeueResponse};
+use crate::application::use_cases::MaskValidationUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 마스크 그룹 검증 결과 조회
+///
+/// 마스크별 서버 검증 상태와 실패 사유, 라벨 값별 복셀 수/경계 상자를 반환하고,
+/// 검증을 통과한 마스크의 라벨별 복셀 수를 그룹 단위로 합산합니다.
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/validation",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID")
+    ),
+    responses(
+        (status = 200, description = "Mask validation results", body = MaskGroupValidationResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+    )
+)]
+pub async fn get_validation<R, MGS>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<MaskValidationUseCase<R, MGS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::MaskValidationRepository + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_validation(annotation_id, group_id, user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 마스크 그룹 재검증 요청
+///
+/// 그룹의 모든 마스크를 검증 대기 상태로 되돌리며, 백그라운드 검증 작업이 다시 처리합니다.
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/validation/requeue",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID")
+    ),
+    responses(
+        (status = 202, description = "Masks queued for validation", body = MaskValidationRequeueResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+    )
+)]
+pub async fn requeue_validation<R, MGS>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<MaskValidationUseCase<R, MGS>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::MaskValidationRepository + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.requeue(annotation_id, group_id, user_id).await {
+        Ok(response) => HttpResponse::Accepted().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<R, MGS>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<MaskValidationUseCase<R, MGS>>,
+)
+where
+    R: crate::domain::repositories::MaskValidationRepository + Send + Sync + 'static,
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/annotations/{annotation_id}/mask-groups/{group_id}/validation")
+                .route("", web::get().to(get_validation::<R, MGS>))
+                .route("/requeue", web::post().to(requeue_validation::<R, MGS>))
+        );
+}
@@ -0,0 +1,246 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::measurement_dto::{
+    AnnotationMeasurementListResponse, AnnotationMeasurementResponse, InstanceMetadataListResponse, InstanceMetadataQuery,
+    MeasurementDiscrepancyQuery, RecomputeMeasurementsRequest, RecomputeMeasurementsResponse, UpsertInstanceMetadataRequest,
+};
+use crate::application::use_cases::MeasurementUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 인스턴스 메타데이터(픽셀 간격) 등록
+///
+/// 이미 등록된 인스턴스는 갱신하고, 기본적으로 해당 Series의 어노테이션 측정값을 다시 계산합니다.
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/instance-metadata",
+    tag = "measurements",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = UpsertInstanceMetadataRequest,
+    responses(
+        (status = 200, description = "Instance metadata saved", body = InstanceMetadataListResponse),
+        (status = 400, description = "Invalid pixel spacing or too many instances"),
+        (status = 401, description = "Not a project admin"),
+    )
+)]
+pub async fn upsert_instance_metadata<R>(
+    path: web::Path<i32>,
+    req: web::Json<UpsertInstanceMetadataRequest>,
+    use_case: web::Data<Arc<MeasurementUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::MeasurementRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.upsert_instance_metadata(project_id, req.into_inner(), user_id).await {
+        Ok(instances) => HttpResponse::Ok().json(instances),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 인스턴스 메타데이터 목록
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/instance-metadata",
+    tag = "measurements",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("study_uid" = Option<String>, Query, description = "Study Instance UID"),
+        ("series_uid" = Option<String>, Query, description = "Series Instance UID"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of instances (default 100, max 1000)")
+    ),
+    responses(
+        (status = 200, description = "Instance metadata", body = InstanceMetadataListResponse),
+        (status = 401, description = "Not a project member"),
+    )
+)]
+pub async fn list_instance_metadata<R>(
+    path: web::Path<i32>,
+    query: web::Query<InstanceMetadataQuery>,
+    use_case: web::Data<Arc<MeasurementUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::MeasurementRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_instance_metadata(project_id, query.into_inner(), user_id).await {
+        Ok(instances) => HttpResponse::Ok().json(instances),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 어노테이션의 서버 계산 측정값 (아직 계산하지 않았으면 계산)
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/measurements/annotations/{annotation_id}",
+    tag = "measurements",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    responses(
+        (status = 200, description = "Computed measurements", body = AnnotationMeasurementResponse),
+        (status = 401, description = "Not a project member"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn get_annotation_measurement<R>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<MeasurementUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::MeasurementRepository + Send + Sync + 'static,
+{
+    let (project_id, annotation_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_measurement(project_id, annotation_id, user_id).await {
+        Ok(measurement) => HttpResponse::Ok().json(measurement),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 어노테이션 측정값 재계산
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/measurements/annotations/{annotation_id}/recompute",
+    tag = "measurements",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    responses(
+        (status = 200, description = "Recomputed measurements", body = AnnotationMeasurementResponse),
+        (status = 401, description = "Not a project member"),
+        (status = 404, description = "Annotation not found"),
+    )
+)]
+pub async fn recompute_annotation_measurement<R>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<MeasurementUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::MeasurementRepository + Send + Sync + 'static,
+{
+    let (project_id, annotation_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.recompute_measurement(project_id, annotation_id, user_id).await {
+        Ok(measurement) => HttpResponse::Ok().json(measurement),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 프로젝트 어노테이션 측정값 일괄 재계산 (본문 생략 시 계산 결과가 없는 어노테이션만)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/measurements/recompute",
+    tag = "measurements",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body(content = Option<RecomputeMeasurementsRequest>, description = "Recompute options"),
+    responses(
+        (status = 200, description = "Recompute summary", body = RecomputeMeasurementsResponse),
+        (status = 401, description = "Not a project admin"),
+    )
+)]
+pub async fn recompute_project_measurements<R>(
+    path: web::Path<i32>,
+    req: Option<web::Json<RecomputeMeasurementsRequest>>,
+    use_case: web::Data<Arc<MeasurementUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::MeasurementRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+    let request = req.map(|r| r.into_inner()).unwrap_or_default();
+
+    match use_case.recompute_project(project_id, request, user_id).await {
+        Ok(result) => HttpResponse::Ok().json(result),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 클라이언트 측정값과 서버 계산 측정값이 다른 어노테이션 목록 (최근 계산순)
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/measurements/discrepancies",
+    tag = "measurements",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of measurements (default 50, max 500)")
+    ),
+    responses(
+        (status = 200, description = "Measurements with discrepancies", body = AnnotationMeasurementListResponse),
+        (status = 401, description = "Not a project member"),
+    )
+)]
+pub async fn list_measurement_discrepancies<R>(
+    path: web::Path<i32>,
+    query: web::Query<MeasurementDiscrepancyQuery>,
+    use_case: web::Data<Arc<MeasurementUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::MeasurementRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_discrepancies(project_id, query.into_inner(), user_id).await {
+        Ok(measurements) => HttpResponse::Ok().json(measurements),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<R>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<MeasurementUseCase<R>>,
+)
+where
+    R: crate::domain::repositories::MeasurementRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/instance-metadata")
+                .route("", web::get().to(list_instance_metadata::<R>))
+                .route("", web::put().to(upsert_instance_metadata::<R>))
+        )
+        .service(
+            web::scope("/projects/{project_id}/measurements")
+                .route("/recompute", web::post().to(recompute_project_measurements::<R>))
+                .route("/discrepancies", web::get().to(list_measurement_discrepancies::<R>))
+                .route("/annotations/{annotation_id}", web::get().to(get_annotation_measurement::<R>))
+                .route("/annotations/{annotation_id}/recompute", web::post().to(recompute_annotation_measurement::<R>))
+        );
+}
@@ -1,3 +1,4 @@
+pub mod request_user;
 pub mod auth_controller;
 pub mod auth_controller_docs;
 pub mod user_controller;
@@ -5,8 +6,30 @@ pub mod project_controller;
 pub mod role_controller;
 pub mod access_control_controller;
 pub mod annotation_controller;
+pub mod annotation_review_controller;
+pub mod annotation_bundle_controller;
+pub mod dataset_export_controller;
+pub mod dataset_release_controller;
+pub mod trash_controller;
+pub mod label_controller;
+pub mod worklist_controller;
+pub mod edit_lock_controller;
+pub mod realtime_controller;
+pub mod webhook_controller;
+pub mod measurement_controller;
+pub mod annotation_propagation_controller;
+pub mod multipart_upload_controller;
+pub mod mask_group_archive_controller;
+pub mod mask_validation_controller;
+pub mod mask_preview_controller;
+pub mod mask_group_lineage_controller;
+pub mod local_storage_controller;
+pub mod storage_reconciliation_controller;
+pub mod storage_quota_controller;
 pub mod mask_group_controller;
 pub mod mask_controller;
+pub mod mask_import_controller;
+pub mod comment_controller;
 pub mod project_user_controller;
 pub mod project_user_matrix_controller;
 pub mod user_project_matrix_controller;
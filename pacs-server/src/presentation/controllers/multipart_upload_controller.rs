@@ -0,0 +1,259 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::multipart_upload_dto::{
+    CompleteMultipartUploadRequest, InitiateMultipartUploadRequest, MultipartPartUrlsRequest,
+    MultipartPartUrlsResponse, MultipartUploadDetailResponse, MultipartUploadListResponse, MultipartUploadResponse,
+};
+use crate::application::use_cases::MultipartUploadUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 멀티파트 업로드 시작
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/multipart-uploads",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID")
+    ),
+    request_body = InitiateMultipartUploadRequest,
+    responses(
+        (status = 201, description = "Multipart upload started", body = MultipartUploadResponse),
+        (status = 400, description = "Invalid file or part size, or storage quota exceeded"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+        (status = 409, description = "An upload of the same file is in progress"),
+    )
+)]
+pub async fn initiate_multipart_upload<MGS, R>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<InitiateMultipartUploadRequest>,
+    use_case: web::Data<Arc<MultipartUploadUseCase<MGS, R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    R: crate::domain::repositories::MultipartUploadRepository + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.initiate(annotation_id, group_id, req.into_inner(), user_id).await {
+        Ok(response) => HttpResponse::Created().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 마스크 그룹의 멀티파트 업로드 목록
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/multipart-uploads",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID")
+    ),
+    responses(
+        (status = 200, description = "Multipart uploads", body = MultipartUploadListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mask group not found"),
+    )
+)]
+pub async fn list_multipart_uploads<MGS, R>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<MultipartUploadUseCase<MGS, R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    R: crate::domain::repositories::MultipartUploadRepository + Send + Sync + 'static,
+{
+    let (annotation_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list(annotation_id, group_id, user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 멀티파트 업로드 진행 상태 (이어받기용)
+#[utoipa::path(
+    get,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/multipart-uploads/{upload_id}",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID"),
+        ("upload_id" = i32, Path, description = "Multipart upload ID")
+    ),
+    responses(
+        (status = 200, description = "Uploaded and missing parts", body = MultipartUploadDetailResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Multipart upload not found"),
+    )
+)]
+pub async fn get_multipart_upload<MGS, R>(
+    path: web::Path<(i32, i32, i32)>,
+    use_case: web::Data<Arc<MultipartUploadUseCase<MGS, R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    R: crate::domain::repositories::MultipartUploadRepository + Send + Sync + 'static,
+{
+    let (annotation_id, group_id, upload_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get(annotation_id, group_id, upload_id, user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 파트 업로드 URL 발급
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/multipart-uploads/{upload_id}/part-urls",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID"),
+        ("upload_id" = i32, Path, description = "Multipart upload ID")
+    ),
+    request_body = MultipartPartUrlsRequest,
+    responses(
+        (status = 200, description = "Part upload URLs generated", body = MultipartPartUrlsResponse),
+        (status = 400, description = "Invalid part number or upload is not in progress"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Multipart upload not found"),
+    )
+)]
+pub async fn generate_part_urls<MGS, R>(
+    path: web::Path<(i32, i32, i32)>,
+    req: web::Json<MultipartPartUrlsRequest>,
+    use_case: web::Data<Arc<MultipartUploadUseCase<MGS, R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    R: crate::domain::repositories::MultipartUploadRepository + Send + Sync + 'static,
+{
+    let (annotation_id, group_id, upload_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.generate_part_urls(annotation_id, group_id, upload_id, req.into_inner(), user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 멀티파트 업로드 완료
+#[utoipa::path(
+    post,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/multipart-uploads/{upload_id}/complete",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID"),
+        ("upload_id" = i32, Path, description = "Multipart upload ID")
+    ),
+    request_body = CompleteMultipartUploadRequest,
+    responses(
+        (status = 200, description = "Upload completed", body = MultipartUploadResponse),
+        (status = 400, description = "Parts missing or mismatched"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Multipart upload not found"),
+    )
+)]
+pub async fn complete_multipart_upload<MGS, R>(
+    path: web::Path<(i32, i32, i32)>,
+    req: web::Json<CompleteMultipartUploadRequest>,
+    use_case: web::Data<Arc<MultipartUploadUseCase<MGS, R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    R: crate::domain::repositories::MultipartUploadRepository + Send + Sync + 'static,
+{
+    let (annotation_id, group_id, upload_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.complete(annotation_id, group_id, upload_id, req.into_inner(), user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 멀티파트 업로드 중단
+#[utoipa::path(
+    delete,
+    path = "/api/annotations/{annotation_id}/mask-groups/{group_id}/multipart-uploads/{upload_id}",
+    tag = "mask-groups",
+    params(
+        ("annotation_id" = i32, Path, description = "Annotation ID"),
+        ("group_id" = i32, Path, description = "Mask group ID"),
+        ("upload_id" = i32, Path, description = "Multipart upload ID")
+    ),
+    responses(
+        (status = 200, description = "Upload aborted", body = MultipartUploadResponse),
+        (status = 400, description = "Upload is not in progress"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Multipart upload not found"),
+    )
+)]
+pub async fn abort_multipart_upload<MGS, R>(
+    path: web::Path<(i32, i32, i32)>,
+    use_case: web::Data<Arc<MultipartUploadUseCase<MGS, R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    R: crate::domain::repositories::MultipartUploadRepository + Send + Sync + 'static,
+{
+    let (annotation_id, group_id, upload_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.abort(annotation_id, group_id, upload_id, user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<MGS, R>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<MultipartUploadUseCase<MGS, R>>,
+)
+where
+    MGS: crate::domain::services::MaskGroupService + Send + Sync + 'static,
+    R: crate::domain::repositories::MultipartUploadRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/annotations/{annotation_id}/mask-groups/{group_id}/multipart-uploads")
+                .route("", web::post().to(initiate_multipart_upload::<MGS, R>))
+                .route("", web::get().to(list_multipart_uploads::<MGS, R>))
+                .route("/{upload_id}", web::get().to(get_multipart_upload::<MGS, R>))
+                .route("/{upload_id}", web::delete().to(abort_multipart_upload::<MGS, R>))
+                .route("/{upload_id}/part-urls", web::post().to(generate_part_urls::<MGS, R>))
+                .route("/{upload_id}/complete", web::post().to(complete_multipart_upload::<MGS, R>))
+        );
+}
@@ -0,0 +1,129 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::{interval_at, Instant, Interval};
+use crate::application::dto::realtime_dto::RealtimeEventQuery;
+use crate::application::use_cases::realtime_use_case::{RealtimeFeed, RealtimeUseCase};
+use crate::domain::entities::realtime::{RealtimeEvent, REALTIME_RESYNC_EVENT};
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 연결 유지용 주석을 보내고 구독자의 프로젝트 멤버 여부를 다시 확인하는 주기
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// SSE 프레임
+fn sse_frame(event: &str, data: &str) -> web::Bytes {
+    web::Bytes::from(format!("event: {}\ndata: {}\n\n", event, data))
+}
+
+fn event_frame(event: &RealtimeEvent) -> web::Bytes {
+    let data = serde_json::to_string(event).unwrap_or_else(|_| "{}".to_string());
+    sse_frame(event.event_type.as_str(), &data)
+}
+
+/// 스트림 상태
+struct FeedState<R>
+where
+    R: crate::domain::repositories::RealtimeRepository + Send + Sync + 'static,
+{
+    feed: RealtimeFeed,
+    use_case: Arc<RealtimeUseCase<R>>,
+    keep_alive: Interval,
+}
+
+/// 다음으로 보낼 프레임 (구독이 끝나면 None)
+async fn next_frame<R>(mut state: FeedState<R>) -> Option<(Result<web::Bytes, actix_web::Error>, FeedState<R>)>
+where
+    R: crate::domain::repositories::RealtimeRepository + Send + Sync + 'static,
+{
+    loop {
+        tokio::select! {
+            received = state.feed.receiver.recv() => match received {
+                Ok(event) if state.feed.subscription.accepts(&event) => {
+                    return Some((Ok(event_frame(&event)), state));
+                }
+                Ok(_) => continue,
+                // 놓친 이벤트가 있으면 클라이언트가 목록을 다시 조회하도록 알림
+                Err(RecvError::Lagged(skipped)) => {
+                    let frame = sse_frame(REALTIME_RESYNC_EVENT, &json!({ "skipped": skipped }).to_string());
+                    return Some((Ok(frame), state));
+                }
+                Err(RecvError::Closed) => return None,
+            },
+            _ = state.keep_alive.tick() => {
+                match state.use_case.is_still_member(&state.feed.subscription).await {
+                    Ok(false) => return None,
+                    Ok(true) => {}
+                    Err(e) => eprintln!("Failed to recheck realtime subscriber: {}", e),
+                }
+                return Some((Ok(web::Bytes::from_static(b": keep-alive\n\n")), state));
+            }
+        }
+    }
+}
+
+/// 프로젝트 실시간 변경 이벤트 구독 (Server-Sent Events)
+///
+/// 어노테이션 생성/수정/삭제, 마스크 그룹 업로드 완료, 데이터 접근 승인/거부, 편집 잠금 획득/해제 이벤트를 보냅니다.
+/// 데이터 접근 이벤트는 대상 사용자와 프로젝트 관리자만 받습니다.
+/// 공유되지 않은 어노테이션의 이벤트(해당 어노테이션의 편집 잠금 포함)는 작성자와 프로젝트 관리자만 받습니다.
+/// 이벤트를 놓치면 `RESYNC` 이벤트를 보내며, 클라이언트는 목록을 다시 조회해야 합니다.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/events",
+    tag = "realtime",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("study_uid" = Option<String>, Query, description = "Only events of this Study Instance UID")
+    ),
+    responses(
+        (status = 200, description = "Event stream", content_type = "text/event-stream", body = RealtimeEvent),
+        (status = 401, description = "Missing X-User-ID or not a member of the project"),
+    )
+)]
+pub async fn stream_project_events<R>(
+    path: web::Path<i32>,
+    query: web::Query<RealtimeEventQuery>,
+    use_case: web::Data<Arc<RealtimeUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::RealtimeRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    let feed = match use_case.subscribe(project_id, query.into_inner(), user_id).await {
+        Ok(feed) => feed,
+        Err(e) => return e.error_response(),
+    };
+    let state = FeedState {
+        feed,
+        use_case: use_case.get_ref().clone(),
+        keep_alive: interval_at(Instant::now() + KEEP_ALIVE_INTERVAL, KEEP_ALIVE_INTERVAL),
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .insert_header(("X-Accel-Buffering", "no"))
+        .streaming(futures::stream::unfold(state, next_frame))
+}
+
+pub fn configure_routes<R>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<RealtimeUseCase<R>>,
+)
+where
+    R: crate::domain::repositories::RealtimeRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::resource("/projects/{project_id}/events")
+                .route(web::get().to(stream_project_events::<R>)),
+        );
+}
@@ -0,0 +1,36 @@
+use actix_web::HttpRequest;
+use crate::domain::ServiceError;
+
+/// 요청한 사용자 ID를 담는 헤더
+pub const USER_ID_HEADER: &str = "X-User-ID";
+
+/// X-User-ID 헤더에서 사용자 ID 추출 (없거나 잘못되면 401)
+pub fn extract_user_id(http_req: &HttpRequest) -> Result<i32, ServiceError> {
+    http_req
+        .headers()
+        .get(USER_ID_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.parse::<i32>().ok())
+        .ok_or_else(|| ServiceError::Unauthorized(format!("{} header is required", USER_ID_HEADER)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    #[test]
+    fn test_extract_user_id_reads_header() {
+        let req = TestRequest::default().insert_header((USER_ID_HEADER, "42")).to_http_request();
+        assert_eq!(extract_user_id(&req).unwrap(), 42);
+    }
+
+    #[test]
+    fn test_extract_user_id_rejects_missing_or_invalid_header() {
+        let missing = TestRequest::default().to_http_request();
+        assert!(matches!(extract_user_id(&missing), Err(ServiceError::Unauthorized(_))));
+
+        let invalid = TestRequest::default().insert_header((USER_ID_HEADER, "abc")).to_http_request();
+        assert!(matches!(extract_user_id(&invalid), Err(ServiceError::Unauthorized(_))));
+    }
+}
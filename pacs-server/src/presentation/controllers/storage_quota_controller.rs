@@ -0,0 +1,202 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::storage_quota_dto::{
+    ProjectStorageUsageResponse, StorageQuotaResponse, StorageUsageRecalculationResponse, UpdateStorageQuotaRequest,
+    UserStorageUsageResponse,
+};
+use crate::application::use_cases::StorageQuotaUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 프로젝트 저장 용량 사용량 조회
+///
+/// 프로젝트 전체와 사용자별 마스크 저장 사용량, 프로젝트 한도를 반환합니다. 프로젝트 멤버만 조회할 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/storage/usage",
+    tag = "storage-quotas",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Project storage usage", body = ProjectStorageUsageResponse),
+        (status = 401, description = "Not a member of the project"),
+    )
+)]
+pub async fn get_project_usage<R>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<StorageQuotaUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::StorageQuotaRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_project_usage(project_id, user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 프로젝트 저장 용량 한도 설정
+///
+/// 경고 한도를 넘으면 업로드는 허용하고 `STORAGE_QUOTA_THRESHOLD_REACHED` 이벤트를 한 번 기록하며,
+/// 업로드 한도를 넘는 업로드 URL은 발급하지 않습니다. null이면 해당 한도가 없습니다. 프로젝트 관리자만 설정할 수 있습니다.
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/storage/quota",
+    tag = "storage-quotas",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = UpdateStorageQuotaRequest,
+    responses(
+        (status = 200, description = "Storage quota updated", body = StorageQuotaResponse),
+        (status = 400, description = "Invalid limits"),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Project not found"),
+    )
+)]
+pub async fn set_project_quota<R>(
+    path: web::Path<i32>,
+    req: web::Json<UpdateStorageQuotaRequest>,
+    use_case: web::Data<Arc<StorageQuotaUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::StorageQuotaRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.set_project_quota(project_id, req.into_inner(), user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 사용자 저장 용량 사용량 조회
+///
+/// 사용자가 생성한 마스크 그룹의 프로젝트별 사용량과 사용자 한도를 반환합니다. 본인만 조회할 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/api/users/{user_id}/storage/usage",
+    tag = "storage-quotas",
+    params(
+        ("user_id" = i32, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "User storage usage", body = UserStorageUsageResponse),
+        (status = 401, description = "Users can only view their own usage"),
+    )
+)]
+pub async fn get_user_usage<R>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<StorageQuotaUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::StorageQuotaRepository + Send + Sync + 'static,
+{
+    let target_user_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_user_usage(target_user_id, user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 사용자 저장 용량 한도 설정
+#[utoipa::path(
+    put,
+    path = "/api/admin/storage/quotas/users/{user_id}",
+    tag = "admin",
+    params(
+        ("user_id" = i32, Path, description = "User ID")
+    ),
+    request_body = UpdateStorageQuotaRequest,
+    responses(
+        (status = 200, description = "Storage quota updated", body = StorageQuotaResponse),
+        (status = 400, description = "Invalid limits"),
+        (status = 404, description = "User not found"),
+    )
+)]
+pub async fn set_user_quota<R>(
+    path: web::Path<i32>,
+    req: web::Json<UpdateStorageQuotaRequest>,
+    use_case: web::Data<Arc<StorageQuotaUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::StorageQuotaRepository + Send + Sync + 'static,
+{
+    let target_user_id = path.into_inner();
+    let admin_id = match extract_user_id(&http_req) {
+        Ok(admin_id) => admin_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.set_user_quota(target_user_id, req.into_inner(), admin_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 저장 용량 사용량 재계산
+///
+/// 마스크 행으로 모든 프로젝트/사용자의 사용량을 다시 계산합니다. 계산하는 동안 마스크 변경은 대기합니다.
+#[utoipa::path(
+    post,
+    path = "/api/admin/storage/usage/recalculate",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Storage usage recalculated", body = StorageUsageRecalculationResponse),
+    )
+)]
+pub async fn recalculate_usage<R>(
+    use_case: web::Data<Arc<StorageQuotaUseCase<R>>>,
+) -> impl Responder
+where
+    R: crate::domain::repositories::StorageQuotaRepository + Send + Sync + 'static,
+{
+    match use_case.recalculate_usage().await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<R>(cfg: &mut web::ServiceConfig, use_case: Arc<StorageQuotaUseCase<R>>)
+where
+    R: crate::domain::repositories::StorageQuotaRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/storage")
+                .route("/usage", web::get().to(get_project_usage::<R>))
+                .route("/quota", web::put().to(set_project_quota::<R>))
+        )
+        .service(
+            web::scope("/users/{user_id}/storage")
+                .route("/usage", web::get().to(get_user_usage::<R>))
+        )
+        // `/admin/storage/reconciliations`를 가리지 않도록 하위 경로별로 등록
+        .service(
+            web::scope("/admin/storage/quotas")
+                .route("/users/{user_id}", web::put().to(set_user_quota::<R>))
+        )
+        .service(
+            web::scope("/admin/storage/usage")
+                .route("/recalculate", web::post().to(recalculate_usage::<R>))
+        );
+}
@@ -0,0 +1,106 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::storage_reconciliation_dto::{
+    StartStorageReconciliationRequest, StorageReconciliationReportQuery, StorageReconciliationReportResponse,
+    StorageReconciliationRunListResponse, StorageReconciliationRunResponse,
+};
+use crate::application::use_cases::StorageReconciliationUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 스토리지 정합성 점검 실행
+///
+/// `masks/`, `annotations/` 아래의 객체를 마스크 행과 비교하고 끝나면 실행 집계를 반환합니다.
+/// 스토리지 오류로 중단된 실행은 `FAILED` 상태와 오류 메시지로 반환됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/admin/storage/reconciliations",
+    tag = "admin",
+    request_body = StartStorageReconciliationRequest,
+    responses(
+        (status = 200, description = "Reconciliation finished", body = StorageReconciliationRunResponse),
+        (status = 400, description = "Invalid grace period"),
+        (status = 409, description = "A reconciliation is already running"),
+    )
+)]
+pub async fn start_reconciliation<R>(
+    req: web::Json<StartStorageReconciliationRequest>,
+    use_case: web::Data<Arc<StorageReconciliationUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::StorageReconciliationRepository + Send + Sync + 'static,
+{
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.start(req.into_inner(), user_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 최근 스토리지 정합성 점검 실행 목록
+#[utoipa::path(
+    get,
+    path = "/api/admin/storage/reconciliations",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Reconciliation runs", body = StorageReconciliationRunListResponse),
+    )
+)]
+pub async fn list_reconciliations<R>(
+    use_case: web::Data<Arc<StorageReconciliationUseCase<R>>>,
+) -> impl Responder
+where
+    R: crate::domain::repositories::StorageReconciliationRepository + Send + Sync + 'static,
+{
+    match use_case.list_runs().await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 스토리지 정합성 점검 보고서
+#[utoipa::path(
+    get,
+    path = "/api/admin/storage/reconciliations/{run_id}",
+    tag = "admin",
+    params(
+        ("run_id" = i32, Path, description = "Reconciliation run ID"),
+        ("limit" = Option<i64>, Query, description = "반환할 최대 문제 수 (기본값: 1000)")
+    ),
+    responses(
+        (status = 200, description = "Reconciliation report", body = StorageReconciliationReportResponse),
+        (status = 404, description = "Reconciliation run not found"),
+    )
+)]
+pub async fn get_reconciliation_report<R>(
+    path: web::Path<i32>,
+    query: web::Query<StorageReconciliationReportQuery>,
+    use_case: web::Data<Arc<StorageReconciliationUseCase<R>>>,
+) -> impl Responder
+where
+    R: crate::domain::repositories::StorageReconciliationRepository + Send + Sync + 'static,
+{
+    let run_id = path.into_inner();
+
+    match use_case.get_report(run_id, query.limit).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<R>(cfg: &mut web::ServiceConfig, use_case: Arc<StorageReconciliationUseCase<R>>)
+where
+    R: crate::domain::repositories::StorageReconciliationRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/admin/storage/reconciliations")
+                .route("", web::post().to(start_reconciliation::<R>))
+                .route("", web::get().to(list_reconciliations::<R>))
+                .route("/{run_id}", web::get().to(get_reconciliation_report::<R>))
+        );
+}
@@ -0,0 +1,134 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use serde_json::json;
+use std::sync::Arc;
+use crate::application::dto::trash_dto::TrashListResponse;
+use crate::application::use_cases::TrashUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 프로젝트 휴지통 목록 조회
+///
+/// 삭제된 어노테이션과 (어노테이션은 활성 상태인) 마스크 그룹을 영구 삭제 예정 시간과 함께 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/trash",
+    tag = "trash",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Trash retrieved successfully", body = TrashListResponse),
+        (status = 401, description = "Not a member of the project"),
+    )
+)]
+pub async fn list_trash<TR>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<TrashUseCase<TR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    TR: crate::domain::repositories::TrashRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_trash(project_id, user_id).await {
+        Ok(trash) => HttpResponse::Ok().json(trash),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 휴지통의 어노테이션 복원
+///
+/// 어노테이션과 함께 휴지통으로 이동한 마스크 그룹도 복원됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/trash/annotations/{annotation_id}/restore",
+    tag = "trash",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("annotation_id" = i32, Path, description = "Annotation ID")
+    ),
+    responses(
+        (status = 200, description = "Annotation restored successfully"),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Annotation not found in trash"),
+    )
+)]
+pub async fn restore_annotation<TR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<TrashUseCase<TR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    TR: crate::domain::repositories::TrashRepository + Send + Sync + 'static,
+{
+    let (project_id, annotation_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.restore_annotation(project_id, annotation_id, user_id).await {
+        Ok(()) => HttpResponse::Ok().json(json!({
+            "message": "Annotation restored successfully"
+        })),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 휴지통의 마스크 그룹 복원
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/trash/mask-groups/{group_id}/restore",
+    tag = "trash",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("group_id" = i32, Path, description = "Mask Group ID")
+    ),
+    responses(
+        (status = 200, description = "Mask group restored successfully"),
+        (status = 400, description = "Annotation of the mask group is in trash"),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Mask group not found in trash"),
+    )
+)]
+pub async fn restore_mask_group<TR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<TrashUseCase<TR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    TR: crate::domain::repositories::TrashRepository + Send + Sync + 'static,
+{
+    let (project_id, group_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.restore_mask_group(project_id, group_id, user_id).await {
+        Ok(()) => HttpResponse::Ok().json(json!({
+            "message": "Mask group restored successfully"
+        })),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<TR>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<TrashUseCase<TR>>,
+)
+where
+    TR: crate::domain::repositories::TrashRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/trash")
+                .route("", web::get().to(list_trash::<TR>))
+                .route("/annotations/{annotation_id}/restore", web::post().to(restore_annotation::<TR>))
+                .route("/mask-groups/{group_id}/restore", web::post().to(restore_mask_group::<TR>))
+        );
+}
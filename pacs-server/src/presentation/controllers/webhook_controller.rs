@@ -0,0 +1,354 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::webhook_dto::{
+    CreateWebhookRequest, UpdateWebhookRequest, WebhookDeliveryListResponse, WebhookDeliveryLogResponse,
+    WebhookDeliveryQuery, WebhookDeliveryResponse, WebhookListResponse, WebhookResponse,
+};
+use crate::application::use_cases::WebhookUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// 프로젝트 Webhook 목록
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/webhooks",
+    tag = "webhooks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Webhooks", body = WebhookListResponse),
+        (status = 401, description = "Not a project admin"),
+    )
+)]
+pub async fn list_webhooks<R>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<WebhookUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_webhooks(project_id, user_id).await {
+        Ok(webhooks) => HttpResponse::Ok().json(webhooks),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// Webhook 생성
+///
+/// 응답의 `secret`으로 `X-PACS-Signature` 헤더(`sha256=` + HMAC-SHA256("{timestamp}.{body}"))를 검증할 수 있으며,
+/// 서명 키는 이 응답에서만 확인할 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/webhooks",
+    tag = "webhooks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = 201, description = "Webhook created", body = WebhookResponse),
+        (status = 400, description = "Invalid name, URL or event type"),
+        (status = 401, description = "Not a project admin"),
+    )
+)]
+pub async fn create_webhook<R>(
+    path: web::Path<i32>,
+    req: web::Json<CreateWebhookRequest>,
+    use_case: web::Data<Arc<WebhookUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.create_webhook(project_id, req.into_inner(), user_id).await {
+        Ok(webhook) => HttpResponse::Created().json(webhook),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// Webhook 조회
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/webhooks/{webhook_id}",
+    tag = "webhooks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("webhook_id" = i32, Path, description = "Webhook ID")
+    ),
+    responses(
+        (status = 200, description = "Webhook", body = WebhookResponse),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Webhook not found"),
+    )
+)]
+pub async fn get_webhook<R>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<WebhookUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    let (project_id, webhook_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_webhook(project_id, webhook_id, user_id).await {
+        Ok(webhook) => HttpResponse::Ok().json(webhook),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// Webhook 수정 (이름, URL, 이벤트 종류, 활성화 여부)
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/webhooks/{webhook_id}",
+    tag = "webhooks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("webhook_id" = i32, Path, description = "Webhook ID")
+    ),
+    request_body = UpdateWebhookRequest,
+    responses(
+        (status = 200, description = "Webhook updated", body = WebhookResponse),
+        (status = 400, description = "Invalid name, URL or event type"),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Webhook not found"),
+    )
+)]
+pub async fn update_webhook<R>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<UpdateWebhookRequest>,
+    use_case: web::Data<Arc<WebhookUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    let (project_id, webhook_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.update_webhook(project_id, webhook_id, req.into_inner(), user_id).await {
+        Ok(webhook) => HttpResponse::Ok().json(webhook),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// Webhook 삭제 (전송 기록도 함께 삭제)
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/webhooks/{webhook_id}",
+    tag = "webhooks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("webhook_id" = i32, Path, description = "Webhook ID")
+    ),
+    responses(
+        (status = 204, description = "Webhook deleted"),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Webhook not found"),
+    )
+)]
+pub async fn delete_webhook<R>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<WebhookUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    let (project_id, webhook_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.delete_webhook(project_id, webhook_id, user_id).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 프로젝트 Webhook 전송 목록 (최신순)
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/webhooks/deliveries",
+    tag = "webhooks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("webhook_id" = Option<i32>, Query, description = "Webhook ID"),
+        ("status" = Option<String>, Query, description = "Delivery status (PENDING, SUCCEEDED, DEAD)"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of deliveries (default 50, max 500)")
+    ),
+    responses(
+        (status = 200, description = "Webhook deliveries", body = WebhookDeliveryListResponse),
+        (status = 401, description = "Not a project admin"),
+    )
+)]
+pub async fn list_webhook_deliveries<R>(
+    path: web::Path<i32>,
+    query: web::Query<WebhookDeliveryQuery>,
+    use_case: web::Data<Arc<WebhookUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_deliveries(project_id, query.into_inner(), user_id).await {
+        Ok(deliveries) => HttpResponse::Ok().json(deliveries),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 재시도 횟수를 모두 소진한 Webhook 전송 목록 (dead-letter)
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/webhooks/dead-letters",
+    tag = "webhooks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("webhook_id" = Option<i32>, Query, description = "Webhook ID"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of deliveries (default 50, max 500)")
+    ),
+    responses(
+        (status = 200, description = "Dead-lettered deliveries", body = WebhookDeliveryListResponse),
+        (status = 401, description = "Not a project admin"),
+    )
+)]
+pub async fn list_webhook_dead_letters<R>(
+    path: web::Path<i32>,
+    query: web::Query<WebhookDeliveryQuery>,
+    use_case: web::Data<Arc<WebhookUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_dead_letters(project_id, query.into_inner(), user_id).await {
+        Ok(deliveries) => HttpResponse::Ok().json(deliveries),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// Webhook 전송과 시도 로그
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/webhooks/deliveries/{delivery_id}",
+    tag = "webhooks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("delivery_id" = i64, Path, description = "Webhook delivery ID")
+    ),
+    responses(
+        (status = 200, description = "Delivery log", body = WebhookDeliveryLogResponse),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Delivery not found"),
+    )
+)]
+pub async fn get_webhook_delivery<R>(
+    path: web::Path<(i32, i64)>,
+    use_case: web::Data<Arc<WebhookUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    let (project_id, delivery_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_delivery_log(project_id, delivery_id, user_id).await {
+        Ok(log) => HttpResponse::Ok().json(log),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// dead-letter 전송 다시 시도 (시도 횟수를 초기화하고 전송 대기로 되돌림)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/webhooks/deliveries/{delivery_id}/retry",
+    tag = "webhooks",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("delivery_id" = i64, Path, description = "Webhook delivery ID")
+    ),
+    responses(
+        (status = 200, description = "Delivery scheduled for retry", body = WebhookDeliveryResponse),
+        (status = 400, description = "Delivery is not dead-lettered"),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Delivery not found"),
+    )
+)]
+pub async fn retry_webhook_delivery<R>(
+    path: web::Path<(i32, i64)>,
+    use_case: web::Data<Arc<WebhookUseCase<R>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    let (project_id, delivery_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.retry_delivery(project_id, delivery_id, user_id).await {
+        Ok(delivery) => HttpResponse::Ok().json(delivery),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<R>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<WebhookUseCase<R>>,
+)
+where
+    R: crate::domain::repositories::WebhookRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/webhooks")
+                .route("", web::get().to(list_webhooks::<R>))
+                .route("", web::post().to(create_webhook::<R>))
+                .route("/deliveries", web::get().to(list_webhook_deliveries::<R>))
+                .route("/dead-letters", web::get().to(list_webhook_dead_letters::<R>))
+                .route("/deliveries/{delivery_id}", web::get().to(get_webhook_delivery::<R>))
+                .route("/deliveries/{delivery_id}/retry", web::post().to(retry_webhook_delivery::<R>))
+                .route("/{webhook_id}", web::get().to(get_webhook::<R>))
+                .route("/{webhook_id}", web::put().to(update_webhook::<R>))
+                .route("/{webhook_id}", web::delete().to(delete_webhook::<R>))
+        );
+}
@@ -0,0 +1,401 @@
+use actix_web::{web, HttpResponse, Responder, HttpRequest, ResponseError};
+use std::sync::Arc;
+use crate::application::dto::worklist_dto::{
+    AssignWorklistTaskRequest, CompleteWorklistTaskRequest, CreateWorklistTasksRequest, CreateWorklistTasksResponse,
+    SkipWorklistTaskRequest, WorklistProjectProgressResponse, WorklistTaskListQuery, WorklistTaskListResponse,
+    WorklistTaskResponse,
+};
+use crate::application::use_cases::WorklistUseCase;
+use crate::presentation::controllers::request_user::extract_user_id;
+
+/// Study에서 작업 생성 (프로젝트 관리자)
+///
+/// `study_ids`와 필터 조건(Study 날짜, Modality, 설명, 환자 ID)에 맞고 아직 작업이 없는 Study마다 작업을 만들고,
+/// `assignee_ids`가 있으면 순서대로 돌아가며 배정합니다.
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/worklist/tasks",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    request_body = CreateWorklistTasksRequest,
+    responses(
+        (status = 201, description = "Tasks created successfully", body = CreateWorklistTasksResponse),
+        (status = 400, description = "Invalid filter or assignee is not a project member"),
+        (status = 401, description = "Not a project admin"),
+    )
+)]
+pub async fn create_worklist_tasks<WR>(
+    path: web::Path<i32>,
+    req: web::Json<CreateWorklistTasksRequest>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.create_tasks(project_id, req.into_inner(), user_id).await {
+        Ok(result) => HttpResponse::Created().json(result),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 작업 목록 조회
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/worklist/tasks",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("status" = Option<String>, Query, description = "TODO, IN_PROGRESS, DONE, SKIPPED"),
+        ("assignee_id" = Option<i32>, Query, description = "담당자 ID"),
+        ("unassigned" = Option<bool>, Query, description = "배정되지 않은 작업만"),
+        ("page" = Option<i64>, Query, description = "페이지 번호 (기본값: 1)"),
+        ("page_size" = Option<i64>, Query, description = "페이지 크기 (기본값: 50, 최대 500)")
+    ),
+    responses(
+        (status = 200, description = "Tasks retrieved successfully", body = WorklistTaskListResponse),
+        (status = 400, description = "Invalid status"),
+        (status = 401, description = "Not a member of the project"),
+    )
+)]
+pub async fn list_worklist_tasks<WR>(
+    path: web::Path<i32>,
+    query: web::Query<WorklistTaskListQuery>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.list_tasks(project_id, query.into_inner(), user_id).await {
+        Ok(tasks) => HttpResponse::Ok().json(tasks),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 작업 조회 (결과 어노테이션/마스크 그룹 포함)
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/worklist/tasks/{task_id}",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("task_id" = i32, Path, description = "Worklist task ID")
+    ),
+    responses(
+        (status = 200, description = "Task retrieved successfully", body = WorklistTaskResponse),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Task not found"),
+    )
+)]
+pub async fn get_worklist_task<WR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let (project_id, task_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_task(project_id, task_id, user_id).await {
+        Ok(task) => HttpResponse::Ok().json(task),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 대기 중인 작업의 담당자 변경 (프로젝트 관리자)
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/worklist/tasks/{task_id}/assignee",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("task_id" = i32, Path, description = "Worklist task ID")
+    ),
+    request_body = AssignWorklistTaskRequest,
+    responses(
+        (status = 200, description = "Task assigned successfully", body = WorklistTaskResponse),
+        (status = 400, description = "Task is not waiting or assignee is not a project member"),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Task not found"),
+    )
+)]
+pub async fn assign_worklist_task<WR>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<AssignWorklistTaskRequest>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let (project_id, task_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.assign_task(project_id, task_id, req.into_inner(), user_id).await {
+        Ok(task) => HttpResponse::Ok().json(task),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 작업 가져가기 (배정되지 않았거나 본인에게 배정된 대기 작업)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/worklist/tasks/{task_id}/claim",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("task_id" = i32, Path, description = "Worklist task ID")
+    ),
+    responses(
+        (status = 200, description = "Task claimed successfully", body = WorklistTaskResponse),
+        (status = 400, description = "Task is not waiting or is assigned to another user"),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Task not found"),
+    )
+)]
+pub async fn claim_worklist_task<WR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let (project_id, task_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.claim_task(project_id, task_id, user_id).await {
+        Ok(task) => HttpResponse::Ok().json(task),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 진행 중인 본인 작업 내려놓기 (소요 시간을 누적하고 대기 상태로 되돌림)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/worklist/tasks/{task_id}/release",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("task_id" = i32, Path, description = "Worklist task ID")
+    ),
+    responses(
+        (status = 200, description = "Task released successfully", body = WorklistTaskResponse),
+        (status = 400, description = "Task is not in progress by the user"),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Task not found"),
+    )
+)]
+pub async fn release_worklist_task<WR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let (project_id, task_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.release_task(project_id, task_id, user_id).await {
+        Ok(task) => HttpResponse::Ok().json(task),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 진행 중인 본인 작업 완료 (작업 Study의 어노테이션/마스크 그룹 연결)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/worklist/tasks/{task_id}/complete",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("task_id" = i32, Path, description = "Worklist task ID")
+    ),
+    request_body = CompleteWorklistTaskRequest,
+    responses(
+        (status = 200, description = "Task completed successfully", body = WorklistTaskResponse),
+        (status = 400, description = "Task is not in progress by the user or results do not belong to the task study"),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Task not found"),
+    )
+)]
+pub async fn complete_worklist_task<WR>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<CompleteWorklistTaskRequest>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let (project_id, task_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.complete_task(project_id, task_id, req.into_inner(), user_id).await {
+        Ok(task) => HttpResponse::Ok().json(task),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 본인 작업 또는 배정되지 않은 작업 건너뛰기
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/worklist/tasks/{task_id}/skip",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("task_id" = i32, Path, description = "Worklist task ID")
+    ),
+    request_body = SkipWorklistTaskRequest,
+    responses(
+        (status = 200, description = "Task skipped successfully", body = WorklistTaskResponse),
+        (status = 400, description = "Task is already closed or assigned to another user"),
+        (status = 401, description = "Not a member of the project"),
+        (status = 404, description = "Task not found"),
+    )
+)]
+pub async fn skip_worklist_task<WR>(
+    path: web::Path<(i32, i32)>,
+    req: web::Json<SkipWorklistTaskRequest>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let (project_id, task_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.skip_task(project_id, task_id, req.into_inner(), user_id).await {
+        Ok(task) => HttpResponse::Ok().json(task),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 완료되었거나 건너뛴 작업을 다시 대기 상태로 (프로젝트 관리자)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/worklist/tasks/{task_id}/reopen",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID"),
+        ("task_id" = i32, Path, description = "Worklist task ID")
+    ),
+    responses(
+        (status = 200, description = "Task reopened successfully", body = WorklistTaskResponse),
+        (status = 400, description = "Task is not closed"),
+        (status = 401, description = "Not a project admin"),
+        (status = 404, description = "Task not found"),
+    )
+)]
+pub async fn reopen_worklist_task<WR>(
+    path: web::Path<(i32, i32)>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let (project_id, task_id) = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.reopen_task(project_id, task_id, user_id).await {
+        Ok(task) => HttpResponse::Ok().json(task),
+        Err(e) => e.error_response(),
+    }
+}
+
+/// 프로젝트 전체 및 담당자별 작업 진행 현황
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/worklist/progress",
+    tag = "worklist",
+    params(
+        ("project_id" = i32, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Progress retrieved successfully", body = WorklistProjectProgressResponse),
+        (status = 401, description = "Not a member of the project"),
+    )
+)]
+pub async fn get_worklist_progress<WR>(
+    path: web::Path<i32>,
+    use_case: web::Data<Arc<WorklistUseCase<WR>>>,
+    http_req: HttpRequest,
+) -> impl Responder
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    let project_id = path.into_inner();
+    let user_id = match extract_user_id(&http_req) {
+        Ok(user_id) => user_id,
+        Err(e) => return e.error_response(),
+    };
+
+    match use_case.get_progress(project_id, user_id).await {
+        Ok(progress) => HttpResponse::Ok().json(progress),
+        Err(e) => e.error_response(),
+    }
+}
+
+pub fn configure_routes<WR>(
+    cfg: &mut web::ServiceConfig,
+    use_case: Arc<WorklistUseCase<WR>>,
+)
+where
+    WR: crate::domain::repositories::WorklistRepository + Send + Sync + 'static,
+{
+    cfg.app_data(web::Data::new(use_case))
+        .service(
+            web::scope("/projects/{project_id}/worklist")
+                .route("/tasks", web::get().to(list_worklist_tasks::<WR>))
+                .route("/tasks", web::post().to(create_worklist_tasks::<WR>))
+                .route("/tasks/{task_id}", web::get().to(get_worklist_task::<WR>))
+                .route("/tasks/{task_id}/assignee", web::put().to(assign_worklist_task::<WR>))
+                .route("/tasks/{task_id}/claim", web::post().to(claim_worklist_task::<WR>))
+                .route("/tasks/{task_id}/release", web::post().to(release_worklist_task::<WR>))
+                .route("/tasks/{task_id}/complete", web::post().to(complete_worklist_task::<WR>))
+                .route("/tasks/{task_id}/skip", web::post().to(skip_worklist_task::<WR>))
+                .route("/tasks/{task_id}/reopen", web::post().to(reopen_worklist_task::<WR>))
+                .route("/progress", web::get().to(get_worklist_progress::<WR>))
+        );
+}
@@ -1,8 +1,30 @@
 use utoipa::OpenApi;
 use crate::presentation::controllers::auth_controller_docs::*;
 use crate::presentation::controllers::annotation_controller::*;
+use crate::presentation::controllers::annotation_review_controller::*;
 use crate::presentation::controllers::project_controller::*;
 use crate::presentation::controllers::mask_group_controller::*;
+use crate::presentation::controllers::mask_import_controller::*;
+use crate::presentation::controllers::comment_controller;
+use crate::presentation::controllers::annotation_bundle_controller;
+use crate::presentation::controllers::dataset_export_controller;
+use crate::presentation::controllers::dataset_release_controller;
+use crate::presentation::controllers::trash_controller;
+use crate::presentation::controllers::label_controller;
+use crate::presentation::controllers::worklist_controller;
+use crate::presentation::controllers::edit_lock_controller;
+use crate::presentation::controllers::realtime_controller;
+use crate::presentation::controllers::webhook_controller;
+use crate::presentation::controllers::measurement_controller;
+use crate::presentation::controllers::annotation_propagation_controller;
+use crate::presentation::controllers::multipart_upload_controller;
+use crate::presentation::controllers::mask_group_archive_controller;
+use crate::presentation::controllers::mask_validation_controller;
+use crate::presentation::controllers::mask_preview_controller;
+use crate::presentation::controllers::mask_group_lineage_controller;
+use crate::presentation::controllers::local_storage_controller;
+use crate::presentation::controllers::storage_reconciliation_controller;
+use crate::presentation::controllers::storage_quota_controller;
 use crate::presentation::controllers::project_user_matrix_controller::*;
 use crate::presentation::controllers::user_project_matrix_controller;
 use crate::presentation::controllers::role_permission_matrix_controller::*;
@@ -11,7 +33,27 @@ use crate::application::dto::auth_dto::*;
 use crate::application::dto::user_dto::*;
 use crate::application::dto::project_dto::*;
 use crate::application::dto::annotation_dto::*;
+use crate::application::dto::annotation_review_dto::*;
 use crate::application::dto::mask_group_dto::*;
+use crate::application::dto::mask_import_dto::*;
+use crate::application::dto::comment_dto::*;
+use crate::application::dto::annotation_bundle_dto::*;
+use crate::application::dto::dataset_export_dto::*;
+use crate::application::dto::dataset_release_dto::*;
+use crate::application::dto::trash_dto::*;
+use crate::application::dto::label_dto::*;
+use crate::application::dto::worklist_dto::*;
+use crate::application::dto::edit_lock_dto::*;
+use crate::application::dto::webhook_dto::*;
+use crate::application::dto::measurement_dto::*;
+use crate::application::dto::annotation_propagation_dto::*;
+use crate::application::dto::multipart_upload_dto::*;
+use crate::application::dto::mask_group_archive_dto::*;
+use crate::application::dto::mask_validation_dto::*;
+use crate::application::dto::mask_preview_dto::*;
+use crate::application::dto::mask_group_lineage_dto::*;
+use crate::application::dto::storage_reconciliation_dto::*;
+use crate::application::dto::storage_quota_dto::*;
 use crate::application::dto::permission_dto::*;
 use crate::application::dto::project_user_dto::{UserWithRoleResponse, ProjectWithRoleResponse, AssignRoleRequest, BatchAssignRolesRequest, UserRoleAssignment, RoleAssignmentResponse, BatchRoleAssignmentResponse, FailedAssignment};
 use crate::application::dto::project_user_matrix_dto::*;
@@ -31,8 +73,16 @@ use crate::application::dto::user_registration_dto::*;
         create_annotation,
         get_annotation,
         list_annotations,
+        search_annotations,
+        annotation_bundle_controller::export_annotations,
+        annotation_bundle_controller::import_annotations,
         update_annotation,
         delete_annotation,
+        // Annotation Review endpoints
+        change_review_status,
+        assign_reviewer,
+        create_review_comment,
+        list_review_comments,
         // Project endpoints
         create_project,
         get_project,
@@ -45,7 +95,109 @@ use crate::application::dto::user_registration_dto::*;
         update_mask_group,
         delete_mask_group,
         generate_upload_url,
+        generate_upload_urls,
+        generate_download_urls,
         complete_upload,
+        // Mask Import endpoints
+        generate_import_upload_url,
+        create_mask_import,
+        list_mask_imports,
+        get_mask_import,
+        // Comment endpoints
+        comment_controller::list_annotation_comments,
+        comment_controller::create_annotation_comment,
+        comment_controller::list_mask_group_comments,
+        comment_controller::create_mask_group_comment,
+        comment_controller::update_comment,
+        comment_controller::delete_comment,
+        comment_controller::resolve_comment,
+        comment_controller::get_comment_history,
+        // Dataset Export endpoints
+        dataset_export_controller::create_dataset_export,
+        dataset_export_controller::list_dataset_exports,
+        dataset_export_controller::get_dataset_export,
+        // Dataset Release endpoints
+        dataset_release_controller::create_dataset_release,
+        dataset_release_controller::list_dataset_releases,
+        dataset_release_controller::get_dataset_release,
+        dataset_release_controller::diff_dataset_releases,
+        // Trash endpoints
+        trash_controller::list_trash,
+        trash_controller::restore_annotation,
+        trash_controller::restore_mask_group,
+        label_controller::list_labels,
+        label_controller::create_label,
+        label_controller::update_label,
+        label_controller::delete_label,
+        label_controller::import_labels,
+        label_controller::preview_label_migration,
+        label_controller::apply_label_migration,
+        // Worklist endpoints
+        worklist_controller::create_worklist_tasks,
+        worklist_controller::list_worklist_tasks,
+        worklist_controller::get_worklist_task,
+        worklist_controller::assign_worklist_task,
+        worklist_controller::claim_worklist_task,
+        worklist_controller::release_worklist_task,
+        worklist_controller::complete_worklist_task,
+        worklist_controller::skip_worklist_task,
+        worklist_controller::reopen_worklist_task,
+        worklist_controller::get_worklist_progress,
+        // Edit lock endpoints
+        edit_lock_controller::list_edit_locks,
+        edit_lock_controller::acquire_edit_lock,
+        edit_lock_controller::heartbeat_edit_lock,
+        edit_lock_controller::release_edit_lock,
+        edit_lock_controller::force_release_edit_lock,
+        // Realtime endpoints
+        realtime_controller::stream_project_events,
+        // Webhook endpoints
+        webhook_controller::list_webhooks,
+        webhook_controller::create_webhook,
+        webhook_controller::get_webhook,
+        webhook_controller::update_webhook,
+        webhook_controller::delete_webhook,
+        webhook_controller::list_webhook_deliveries,
+        webhook_controller::list_webhook_dead_letters,
+        webhook_controller::get_webhook_delivery,
+        webhook_controller::retry_webhook_delivery,
+        // Measurement endpoints
+        measurement_controller::upsert_instance_metadata,
+        measurement_controller::list_instance_metadata,
+        measurement_controller::get_annotation_measurement,
+        measurement_controller::recompute_annotation_measurement,
+        measurement_controller::recompute_project_measurements,
+        measurement_controller::list_measurement_discrepancies,
+        // Annotation propagation endpoints
+        annotation_propagation_controller::propagate_annotations,
+        annotation_propagation_controller::track_lesions,
+        // Mask group multipart upload endpoints
+        multipart_upload_controller::initiate_multipart_upload,
+        multipart_upload_controller::list_multipart_uploads,
+        multipart_upload_controller::get_multipart_upload,
+        multipart_upload_controller::generate_part_urls,
+        multipart_upload_controller::complete_multipart_upload,
+        multipart_upload_controller::abort_multipart_upload,
+        mask_group_archive_controller::download_archive,
+        mask_validation_controller::get_validation,
+        mask_validation_controller::requeue_validation,
+        mask_preview_controller::get_contact_sheet,
+        mask_group_lineage_controller::get_lineage,
+        mask_group_lineage_controller::set_derived_from,
+        mask_group_lineage_controller::compare_mask_groups,
+        // Local storage signed URL endpoints
+        local_storage_controller::put_object,
+        local_storage_controller::get_object,
+        // Storage reconciliation endpoints
+        storage_reconciliation_controller::start_reconciliation,
+        storage_reconciliation_controller::list_reconciliations,
+        storage_reconciliation_controller::get_reconciliation_report,
+        // Storage quota endpoints
+        storage_quota_controller::get_project_usage,
+        storage_quota_controller::set_project_quota,
+        storage_quota_controller::get_user_usage,
+        storage_quota_controller::set_user_quota,
+        storage_quota_controller::recalculate_usage,
         // Project User Matrix endpoints
         get_matrix,
         // User Project Matrix endpoints
@@ -91,6 +243,22 @@ use crate::application::dto::user_registration_dto::*;
             UpdateAnnotationRequest,
             AnnotationResponse,
             AnnotationListResponse,
+            AnnotationSearchRequest,
+            AnnotationSearchResponse,
+            crate::domain::entities::AnnotationSortField,
+            crate::domain::entities::SortDirection,
+            crate::domain::entities::JsonPathPredicate,
+            crate::domain::entities::JsonPredicateOp,
+            BundleImportReport,
+            BundleRecordCounts,
+            BundleConflict,
+            // Annotation Review DTOs
+            ChangeReviewStatusRequest,
+            AssignReviewerRequest,
+            CreateReviewCommentRequest,
+            ReviewCommentResponse,
+            ReviewCommentListResponse,
+            crate::domain::entities::AnnotationReviewStatus,
             // Mask Group DTOs
             CreateMaskGroupRequest,
             UpdateMaskGroupRequest,
@@ -99,8 +267,160 @@ use crate::application::dto::user_registration_dto::*;
             MaskGroupDetailResponse,
             SignedUrlRequest,
             SignedUrlResponse,
+            BatchUploadFile,
+            BatchUploadUrlRequest,
+            BatchDownloadUrlRequest,
+            BatchSignedUrlItem,
+            BatchSignedUrlResponse,
             CompleteUploadRequest,
             CompleteUploadResponse,
+            // Mask Import DTOs
+            MaskImportUploadUrlRequest,
+            MaskImportUploadUrlResponse,
+            CreateMaskImportRequest,
+            MaskImportJobResponse,
+            MaskImportJobListResponse,
+            crate::domain::entities::MaskImportStatus,
+            crate::domain::entities::UnmappedFrame,
+            // Comment DTOs
+            CreateCommentRequest,
+            UpdateCommentRequest,
+            ResolveCommentRequest,
+            CommentResponse,
+            CommentThreadListResponse,
+            CommentHistoryResponse,
+            crate::domain::entities::CommentTargetType,
+            // Dataset Export DTOs
+            CreateDatasetExportRequest,
+            DatasetExportJobResponse,
+            DatasetExportJobListResponse,
+            crate::domain::entities::DatasetExportFormat,
+            crate::domain::entities::DatasetExportStatus,
+            crate::domain::entities::DatasetExportFilter,
+            // Dataset Release DTOs
+            CreateDatasetReleaseRequest,
+            DatasetReleaseResponse,
+            DatasetReleaseListResponse,
+            DatasetReleaseDiffResponse,
+            crate::domain::entities::ReleaseItemDiff,
+            // Trash DTOs
+            TrashListResponse,
+            TrashedAnnotationResponse,
+            TrashedMaskGroupResponse,
+            // Label Taxonomy DTOs
+            LabelRequest,
+            LabelResponse,
+            LabelListResponse,
+            LabelImportResponse,
+            LabelMigrationStatus,
+            LabelMigrationEntry,
+            LabelMigrationPreviewResponse,
+            LabelMappingRequest,
+            ApplyLabelMigrationRequest,
+            AppliedLabelMapping,
+            LabelMigrationResponse,
+            // Worklist DTOs
+            crate::domain::entities::WorklistTaskStatus,
+            CreateWorklistTasksRequest,
+            CreateWorklistTasksResponse,
+            AssignWorklistTaskRequest,
+            CompleteWorklistTaskRequest,
+            SkipWorklistTaskRequest,
+            WorklistTaskResponse,
+            WorklistTaskListResponse,
+            WorklistProgressResponse,
+            WorklistProjectProgressResponse,
+            // Edit lock DTOs
+            crate::domain::entities::EditLockTarget,
+            AcquireEditLockRequest,
+            EditLockHeartbeatRequest,
+            EditLockResponse,
+            EditLockListResponse,
+            // Realtime DTOs
+            crate::domain::entities::RealtimeEventType,
+            crate::domain::entities::RealtimeEvent,
+            // Webhook DTOs
+            crate::domain::entities::WebhookEventType,
+            crate::domain::entities::WebhookDeliveryStatus,
+            CreateWebhookRequest,
+            UpdateWebhookRequest,
+            WebhookResponse,
+            WebhookListResponse,
+            WebhookDeliveryResponse,
+            WebhookDeliveryListResponse,
+            WebhookDeliveryAttemptResponse,
+            WebhookDeliveryLogResponse,
+            // Measurement DTOs
+            InstanceMetadataItem,
+            UpsertInstanceMetadataRequest,
+            InstanceMetadataResponse,
+            InstanceMetadataListResponse,
+            AnnotationMeasurementResponse,
+            AnnotationMeasurementListResponse,
+            RecomputeMeasurementsRequest,
+            RecomputeMeasurementsResponse,
+            crate::domain::entities::ComputedMeasurement,
+            crate::domain::entities::MeasurementDiscrepancy,
+            // Annotation propagation DTOs
+            PropagateAnnotationsRequest,
+            PropagatedAnnotationResponse,
+            SkippedPropagationResponse,
+            PropagateAnnotationsResponse,
+            LesionTimepointResponse,
+            LesionResponse,
+            LesionTrackingResponse,
+            // Mask group multipart upload DTOs
+            InitiateMultipartUploadRequest,
+            MultipartPartUrlsRequest,
+            CompletedPartRequest,
+            CompleteMultipartUploadRequest,
+            MultipartUploadResponse,
+            UploadedPartResponse,
+            MultipartUploadDetailResponse,
+            MultipartPartUrlResponse,
+            MultipartPartUrlsResponse,
+            MultipartUploadListResponse,
+            crate::domain::entities::MultipartUploadStatus,
+            // Mask group archive DTOs
+            MaskGroupArchiveQuery,
+            MaskArchiveEntry,
+            MaskGroupArchiveManifest,
+            // Mask validation DTOs
+            MaskLabelStatsResponse,
+            MaskValidationResponse,
+            MaskGroupLabelVolumeResponse,
+            MaskValidationCounts,
+            MaskGroupValidationResponse,
+            MaskValidationRequeueResponse,
+            crate::domain::entities::MaskValidationStatus,
+            // Mask preview DTOs
+            ContactSheetQuery,
+            MaskGroupContactSheetResponse,
+            crate::domain::entities::MaskPreviewStatus,
+            // Mask group lineage DTOs
+            MaskGroupLineageNodeResponse,
+            MaskGroupLineageResponse,
+            SetDerivedFromRequest,
+            MaskGroupComparisonQuery,
+            LabelComparisonResponse,
+            MaskGroupComparisonResponse,
+            // Storage reconciliation DTOs
+            StartStorageReconciliationRequest,
+            StorageReconciliationRunResponse,
+            StorageReconciliationFindingResponse,
+            StorageReconciliationReportResponse,
+            StorageReconciliationRunListResponse,
+            crate::domain::entities::StorageReconciliationStatus,
+            crate::domain::entities::StorageOrphanAction,
+            crate::domain::entities::StorageReconciliationFindingKind,
+            // Storage quota DTOs
+            UpdateStorageQuotaRequest,
+            StorageQuotaResponse,
+            StorageUsageEntryResponse,
+            ProjectStorageUsageResponse,
+            UserStorageUsageResponse,
+            StorageUsageRecalculationResponse,
+            crate::domain::entities::StorageQuotaLevel,
             // Permission DTOs
             RoleWithPermissionsResponse,
             RolesWithPermissionsListResponse,
@@ -165,6 +485,19 @@ use crate::application::dto::user_registration_dto::*;
         (name = "access-control", description = "Access control endpoints - 접근 제어 API"),
         (name = "annotations", description = "Annotation management endpoints - 어노테이션 관리 API"),
         (name = "mask-groups", description = "Mask Group management endpoints - 마스크 그룹 관리 API"),
+        (name = "comments", description = "Annotation / Mask Group comment endpoints - 코멘트 스레드 API"),
+        (name = "dataset-exports", description = "ML dataset export endpoints (COCO, CSV/JSON manifest) - 데이터셋 내보내기 API"),
+        (name = "dataset-releases", description = "Immutable dataset release endpoints - 데이터셋 릴리스(스냅샷) API"),
+        (name = "trash", description = "Trash (soft-deleted annotations and mask groups) endpoints - 휴지통 API"),
+        (name = "labels", description = "Project label taxonomy endpoints - 라벨 분류 체계 API"),
+        (name = "worklist", description = "Annotation worklist endpoints - 어노테이션 작업 목록 API"),
+        (name = "edit-locks", description = "Study / annotation edit lock endpoints - 편집 잠금 API"),
+        (name = "realtime", description = "Realtime change feed endpoints - 실시간 변경 이벤트 API"),
+        (name = "webhooks", description = "Project webhook endpoints - Webhook 구독 및 전송 로그 API"),
+        (name = "measurements", description = "Annotation measurement endpoints - 서버 측정값 계산 및 인스턴스 메타데이터 API"),
+        (name = "annotation-propagation", description = "Follow-up propagation endpoints - 후속 Study 어노테이션 전파 및 병변 추적 API"),
+        (name = "storage", description = "Local object storage endpoints - 로컬 디스크 스토리지 Signed URL API"),
+        (name = "storage-quotas", description = "Storage usage and quota endpoints - 프로젝트/사용자 저장 용량 사용량 및 한도 API"),
         (name = "project-users", description = "Project User Role management endpoints - 프로젝트 사용자 역할 관리 API"),
         (name = "project-user-matrix", description = "Project User Matrix endpoints - 프로젝트 사용자 매트릭스 API"),
         (name = "user-project-matrix", description = "User Project Matrix endpoints - 유저 프로젝트 매트릭스 API"),
@@ -0,0 +1,195 @@
+mod common;
+
+#[cfg(test)]
+mod annotation_bundle_tests {
+    use std::sync::Arc;
+    use futures::StreamExt;
+    use pacs_server::application::dto::BundleImportQuery;
+    use pacs_server::application::use_cases::AnnotationBundleUseCase;
+    use pacs_server::domain::entities::{BundleRecord, NewAnnotation};
+    use pacs_server::domain::repositories::AnnotationRepository;
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{AnnotationBundleRepositoryImpl, AnnotationRepositoryImpl};
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user, add_member};
+
+    async fn create_project(pool: &PgPool, name: &str) -> i32 {
+        sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(name)
+            .fetch_one(pool)
+            .await
+            .expect("Failed to create test project")
+    }
+
+    fn new_annotation(project_id: i32, user_id: i32, is_shared: bool) -> NewAnnotation {
+        NewAnnotation {
+            project_id,
+            user_id,
+            study_uid: "1.2.3.bundle".to_string(),
+            series_uid: None,
+            instance_uid: None,
+            tool_name: "Circle Tool".to_string(),
+            tool_version: None,
+            data: serde_json::json!({"type": "circle", "radius": 5}),
+            is_shared,
+            viewer_software: None,
+            description: None,
+            measurement_values: None,
+        }
+    }
+
+    async fn export(use_case: &AnnotationBundleUseCase<AnnotationBundleRepositoryImpl>, project_id: i32, user_id: i32) -> Vec<u8> {
+        let mut bundle = Vec::new();
+        let mut stream = Box::pin(use_case.export_project(project_id, user_id).await.unwrap());
+        while let Some(batch) = stream.next().await {
+            for record in batch.unwrap() {
+                serde_json::to_writer(&mut bundle, &record).unwrap();
+                bundle.push(b'\n');
+            }
+        }
+        bundle
+    }
+
+    async fn cleanup(pool: &PgPool, project_ids: &[i32], user_ids: &[i32]) {
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = ANY($1)")
+            .bind(project_ids)
+            .execute(pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = ANY($1)")
+            .bind(project_ids)
+            .execute(pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(user_ids)
+            .execute(pool)
+            .await
+            .ok();
+    }
+
+    #[tokio::test]
+    async fn test_export_then_import_is_idempotent() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let user_id = create_user(&pool, &format!("bundle_user_{}", suffix)).await;
+        let source_project = create_project(&pool, &format!("bundle_source_{}", suffix)).await;
+        let target_name = format!("bundle_target_{}", suffix);
+        let target_project = create_project(&pool, &target_name).await;
+        add_member(&pool, source_project, user_id, "USER").await;
+        add_member(&pool, target_project, user_id, "USER").await;
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let annotation = annotation_repo.create(new_annotation(source_project, user_id, true)).await.unwrap();
+        sqlx::query("INSERT INTO annotation_mask_group (annotation_id, group_name, created_by) VALUES ($1, 'liver', $2)")
+            .bind(annotation.id)
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let use_case = AnnotationBundleUseCase::new(Arc::new(AnnotationBundleRepositoryImpl::new(pool.clone())));
+
+        let bundle = export(&use_case, source_project, user_id).await;
+        let kinds: Vec<String> = String::from_utf8(bundle.clone())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<BundleRecord>(line).unwrap().kind().to_string())
+            .collect();
+        assert_eq!(kinds, vec!["header", "annotation", "history", "mask_group"]);
+
+        // 원본 프로젝트에 다시 가져오면 모두 건너뜀
+        let report = use_case.import_bundle(&bundle, BundleImportQuery::default(), user_id).await.unwrap();
+        assert!(report.applied);
+        assert_eq!(report.annotations.skipped, 1);
+        assert_eq!(report.mask_groups.skipped, 1);
+
+        // 다른 프로젝트로 가져오려 하면 external_id 충돌
+        let query = || BundleImportQuery { dry_run: Some(true), project_name: Some(target_name.clone()) };
+        let report = use_case.import_bundle(&bundle, query(), user_id).await.unwrap();
+        assert!(!report.applied);
+        assert_eq!(report.conflicts.len(), 2);
+
+        // 원본 어노테이션을 지우면 대상 프로젝트로 새로 생성되고, 재실행 시에는 건너뜀
+        sqlx::query("DELETE FROM annotation_annotation WHERE id = $1")
+            .bind(annotation.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let import = || BundleImportQuery { dry_run: None, project_name: Some(target_name.clone()) };
+        let report = use_case.import_bundle(&bundle, import(), user_id).await.unwrap();
+        assert!(report.applied, "{:?}", report.conflicts);
+        assert_eq!(report.annotations.created, 1);
+        assert_eq!(report.history.created, 1);
+        assert_eq!(report.mask_groups.created, 1);
+
+        let report = use_case.import_bundle(&bundle, import(), user_id).await.unwrap();
+        assert!(report.applied);
+        assert_eq!(report.annotations.skipped, 1);
+        assert_eq!(report.history.skipped, 1);
+        assert_eq!(report.mask_groups.skipped, 1);
+
+        let imported: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM annotation_annotation WHERE project_id = $1")
+            .bind(target_project)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(imported, 1);
+
+        cleanup(&pool, &[source_project, target_project], &[user_id]).await;
+    }
+
+    #[tokio::test]
+    async fn test_export_and_import_require_project_access() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let owner_id = create_user(&pool, &format!("bundle_owner_{}", suffix)).await;
+        let member_id = create_user(&pool, &format!("bundle_member_{}", suffix)).await;
+        let outsider_id = create_user(&pool, &format!("bundle_outsider_{}", suffix)).await;
+        let admin_id = create_user(&pool, &format!("bundle_admin_{}", suffix)).await;
+        let source_project = create_project(&pool, &format!("bundle_acl_source_{}", suffix)).await;
+        let target_name = format!("bundle_acl_target_{}", suffix);
+        let target_project = create_project(&pool, &target_name).await;
+        let admin_project = create_project(&pool, &format!("bundle_acl_admin_{}", suffix)).await;
+        add_member(&pool, source_project, owner_id, "USER").await;
+        add_member(&pool, source_project, member_id, "USER").await;
+        add_member(&pool, admin_project, admin_id, "ADMIN").await;
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        annotation_repo.create(new_annotation(source_project, owner_id, true)).await.unwrap();
+        annotation_repo.create(new_annotation(source_project, owner_id, false)).await.unwrap();
+
+        let use_case = AnnotationBundleUseCase::new(Arc::new(AnnotationBundleRepositoryImpl::new(pool.clone())));
+        let annotation_count = |bundle: &[u8]| {
+            String::from_utf8(bundle.to_vec())
+                .unwrap()
+                .lines()
+                .filter(|line| serde_json::from_str::<BundleRecord>(line).unwrap().kind() == "annotation")
+                .count()
+        };
+
+        // 작성자는 비공유 어노테이션까지, 다른 멤버와 관리자는 공유된 것만
+        assert_eq!(annotation_count(&export(&use_case, source_project, owner_id).await), 2);
+        let bundle = export(&use_case, source_project, member_id).await;
+        assert_eq!(annotation_count(&bundle), 1);
+        assert_eq!(annotation_count(&export(&use_case, source_project, admin_id).await), 1);
+        assert!(matches!(
+            use_case.export_project(source_project, outsider_id).await.err(),
+            Some(ServiceError::Unauthorized(_))
+        ));
+
+        // 대상 프로젝트 멤버가 아니면 dry run도 불가, 관리자는 가능
+        let query = || BundleImportQuery { dry_run: Some(true), project_name: Some(target_name.clone()) };
+        for user_id in [member_id, outsider_id] {
+            assert!(matches!(
+                use_case.import_bundle(&bundle, query(), user_id).await,
+                Err(ServiceError::Unauthorized(_))
+            ));
+        }
+        assert!(use_case.import_bundle(&bundle, query(), admin_id).await.is_ok());
+
+        cleanup(&pool, &[source_project, target_project, admin_project], &[owner_id, member_id, outsider_id, admin_id]).await;
+    }
+}
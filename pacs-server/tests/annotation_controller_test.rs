@@ -290,7 +290,7 @@ mod annotation_controller_tests {
             measurement_values: None,
         };
 
-        let update_result = annotation_use_case.update_annotation(annotation_id, update_req).await;
+        let update_result = annotation_use_case.update_annotation(annotation_id, update_req, user_id).await;
         assert!(update_result.is_ok());
 
         // Cleanup
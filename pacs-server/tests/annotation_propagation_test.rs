@@ -0,0 +1,191 @@
+mod common;
+
+#[cfg(test)]
+mod annotation_propagation_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::annotation_propagation_dto::{LesionTrackingQuery, PropagateAnnotationsRequest};
+    use pacs_server::application::use_cases::AnnotationPropagationUseCase;
+    use pacs_server::domain::entities::{AnnotationReviewStatus, NewAnnotation};
+    use pacs_server::domain::repositories::AnnotationRepository;
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{AnnotationPropagationRepositoryImpl, AnnotationRepositoryImpl};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_member};
+
+    /// Study와 (Series UID, Series Description, Modality, Series Number) Series 등록
+    async fn create_study(
+        pool: &PgPool,
+        project_id: i32,
+        study_uid: &str,
+        patient_id: &str,
+        study_date: &str,
+        series: &[(&str, &str, &str, i32)],
+    ) {
+        let study_id: i32 = sqlx::query_scalar(
+            "INSERT INTO project_data_study (project_id, study_uid, patient_id, study_date)
+             VALUES ($1, $2, $3, $4::DATE) RETURNING id"
+        )
+        .bind(project_id)
+        .bind(study_uid)
+        .bind(patient_id)
+        .bind(study_date)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        for (series_uid, description, modality, number) in series {
+            sqlx::query(
+                "INSERT INTO project_data_series (study_id, series_uid, series_description, modality, series_number)
+                 VALUES ($1, $2, $3, $4, $5)"
+            )
+            .bind(study_id)
+            .bind(series_uid)
+            .bind(description)
+            .bind(modality)
+            .bind(number)
+            .execute(pool)
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_propagate_baseline_lesions_to_follow_up_study() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar(
+            "INSERT INTO security_project (name, status) VALUES ($1, 'COMPLETED') RETURNING id"
+        )
+        .bind(format!("propagation_project_{}", suffix))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let reader_id = create_member(&pool, project_id, &format!("pr_reader_{}", suffix), "ANNOTATOR").await;
+        let patient_id = format!("PAT-{}", suffix);
+
+        let uid = |name: &str| format!("1.2.840.{}.{}", project_id, name);
+        create_study(&pool, project_id, &uid("1"), &patient_id, "2025-01-10", &[
+            (&uid("1.1"), "AX T1 POST", "MR", 5),
+            (&uid("1.2"), "Localizer", "MR", 1),
+            (&uid("1.3"), "Dose report", "SR", 9),
+        ]).await;
+        create_study(&pool, project_id, &uid("2"), &patient_id, "2025-04-10", &[
+            (&uid("2.1"), "Ax  t1 post", "MR", 6),
+            (&uid("2.2"), "Survey", "MR", 2),
+        ]).await;
+        create_study(&pool, project_id, &uid("3"), "OTHER", "2025-04-11", &[]).await;
+        sqlx::query(
+            "INSERT INTO dicom_instance_metadata (project_id, study_uid, series_uid, instance_uid, pixel_spacing_row, pixel_spacing_column)
+             VALUES ($1, $2, $3, $4, 0.5, 0.5)"
+        )
+        .bind(project_id)
+        .bind(uid("2"))
+        .bind(uid("2.2"))
+        .bind(uid("2.2.1"))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let create = |series: Option<String>, description: &str| NewAnnotation {
+            project_id,
+            user_id: reader_id,
+            study_uid: uid("1"),
+            series_uid: series.clone(),
+            instance_uid: series.map(|s| format!("{}.1", s)),
+            tool_name: "Length Tool".to_string(),
+            tool_version: None,
+            viewer_software: None,
+            data: json!({"points": [[0, 0], [30, 40]]}),
+            description: Some(description.to_string()),
+            is_shared: true,
+            measurement_values: Some(json!([{"type": "length", "value": 50.0, "unit": "px"}])),
+        };
+        let by_description = annotation_repo.create(create(Some(uid("1.1")), "target lesion 1")).await.unwrap();
+        let by_modality = annotation_repo.create(create(Some(uid("1.2")), "target lesion 2")).await.unwrap();
+        let unmatched = annotation_repo.create(create(Some(uid("1.3")), "non-target")).await.unwrap();
+        let study_level = annotation_repo.create(create(None, "study note")).await.unwrap();
+
+        let use_case = AnnotationPropagationUseCase::new(Arc::new(AnnotationPropagationRepositoryImpl::new(pool.clone())));
+        let request = |target: &str, ids: Vec<i32>| PropagateAnnotationsRequest {
+            source_study_uid: uid("1"),
+            target_study_uid: uid(target),
+            annotation_ids: ids,
+        };
+
+        // 다른 환자의 Study로는 전파할 수 없음
+        let result = use_case.propagate(project_id, request("3", vec![by_description.id]), reader_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let result = use_case.propagate(project_id, request("9", vec![by_description.id]), reader_id).await;
+        assert!(matches!(result, Err(ServiceError::NotFound(_))));
+
+        let ids = vec![by_description.id, by_modality.id, unmatched.id, study_level.id];
+        let result = use_case.propagate(project_id, request("2", ids.clone()), reader_id).await.unwrap();
+        assert!(result.skipped.is_empty());
+        let copies: Vec<(i32, Option<String>, Option<String>, String)> = result
+            .propagated
+            .iter()
+            .map(|p| (p.source_annotation_id, p.series_uid.clone(), p.instance_uid.clone(), p.series_match.clone()))
+            .collect();
+        assert_eq!(copies, vec![
+            (by_description.id, Some(uid("2.1")), None, "SERIES_DESCRIPTION".to_string()),
+            (by_modality.id, Some(uid("2.2")), Some(uid("2.2.1")), "MODALITY".to_string()),
+            (unmatched.id, None, None, "UNMATCHED".to_string()),
+            (study_level.id, None, None, "STUDY_LEVEL".to_string()),
+        ]);
+
+        // 복사본은 DRAFT이며 클라이언트 측정값은 복사하지 않음
+        let copy = annotation_repo.find_by_id(result.propagated[0].annotation_id).await.unwrap().unwrap();
+        assert_eq!(copy.study_uid, uid("2"));
+        assert_eq!(copy.review_status, AnnotationReviewStatus::Draft);
+        assert_eq!(copy.data, by_description.data);
+        assert!(copy.measurement_values.is_none());
+
+        // 같은 Study로 다시 전파하면 건너뜀
+        let again = use_case.propagate(project_id, request("2", vec![by_description.id]), reader_id).await.unwrap();
+        assert!(again.propagated.is_empty());
+        assert_eq!(again.skipped[0].source_annotation_id, by_description.id);
+
+        let lesions = use_case
+            .track_lesions(
+                project_id,
+                LesionTrackingQuery { patient_id: patient_id.clone(), lesion_id: None, tracked_only: Some(true) },
+                reader_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(lesions.lesions.len(), 4);
+        let lesion = &lesions.lesions[0];
+        assert_eq!(lesion.lesion_id, by_description.id);
+        assert_eq!(lesion.description.as_deref(), Some("target lesion 1"));
+        let studies: Vec<(String, Option<String>)> = lesion
+            .timepoints
+            .iter()
+            .map(|t| (t.study_uid.clone(), t.study_date.clone()))
+            .collect();
+        assert_eq!(studies, vec![
+            (uid("1"), Some("2025-01-10".to_string())),
+            (uid("2"), Some("2025-04-10".to_string())),
+        ]);
+        assert_eq!(lesion.timepoints[1].source_annotation_id, Some(by_description.id));
+        assert!(lesion.timepoints[0].computed_measurements.is_none());
+        assert!(lesion.timepoints[1].computed_measurements.is_some());
+
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(reader_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
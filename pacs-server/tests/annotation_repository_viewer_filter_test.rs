@@ -94,21 +94,21 @@ use pacs_server::infrastructure::repositories::AnnotationRepositoryImpl;
         repository.create(annotation3).await.unwrap();
 
         // OHIF Viewer로 필터링
-        let ohif_annotations = repository.find_by_user_id_with_viewer(user_id, Some("OHIF Viewer")).await.unwrap();
+        let ohif_annotations = repository.find_by_user_id_with_viewer(user_id, Some("OHIF Viewer"), None).await.unwrap();
         assert_eq!(ohif_annotations.len(), 2);
         assert!(ohif_annotations.iter().all(|a| a.viewer_software == Some("OHIF Viewer".to_string())));
 
         // DICOM Viewer로 필터링
-        let dicom_annotations = repository.find_by_user_id_with_viewer(user_id, Some("DICOM Viewer")).await.unwrap();
+        let dicom_annotations = repository.find_by_user_id_with_viewer(user_id, Some("DICOM Viewer"), None).await.unwrap();
         assert_eq!(dicom_annotations.len(), 1);
         assert!(dicom_annotations.iter().all(|a| a.viewer_software == Some("DICOM Viewer".to_string())));
 
         // 필터 없이 모든 어노테이션 조회
-        let all_annotations = repository.find_by_user_id_with_viewer(user_id, None).await.unwrap();
+        let all_annotations = repository.find_by_user_id_with_viewer(user_id, None, None).await.unwrap();
         assert_eq!(all_annotations.len(), 3);
 
         // 존재하지 않는 viewer_software로 필터링
-        let no_annotations = repository.find_by_user_id_with_viewer(user_id, Some("NonExistent Viewer")).await.unwrap();
+        let no_annotations = repository.find_by_user_id_with_viewer(user_id, Some("NonExistent Viewer"), None).await.unwrap();
         assert_eq!(no_annotations.len(), 0);
 
         cleanup_test_data(&pool, user_id, project_id).await;
@@ -157,17 +157,17 @@ use pacs_server::infrastructure::repositories::AnnotationRepositoryImpl;
         repository.create(annotation2).await.unwrap();
 
         // OHIF Viewer로 필터링
-        let ohif_annotations = repository.find_by_project_id_with_viewer(project_id, Some("OHIF Viewer")).await.unwrap();
+        let ohif_annotations = repository.find_by_project_id_with_viewer(project_id, Some("OHIF Viewer"), None).await.unwrap();
         assert_eq!(ohif_annotations.len(), 1);
         assert!(ohif_annotations.iter().all(|a| a.viewer_software == Some("OHIF Viewer".to_string())));
 
         // DICOM Viewer로 필터링
-        let dicom_annotations = repository.find_by_project_id_with_viewer(project_id, Some("DICOM Viewer")).await.unwrap();
+        let dicom_annotations = repository.find_by_project_id_with_viewer(project_id, Some("DICOM Viewer"), None).await.unwrap();
         assert_eq!(dicom_annotations.len(), 1);
         assert!(dicom_annotations.iter().all(|a| a.viewer_software == Some("DICOM Viewer".to_string())));
 
         // 필터 없이 모든 어노테이션 조회
-        let all_annotations = repository.find_by_project_id_with_viewer(project_id, None).await.unwrap();
+        let all_annotations = repository.find_by_project_id_with_viewer(project_id, None, None).await.unwrap();
         assert_eq!(all_annotations.len(), 2);
 
         cleanup_test_data(&pool, user_id, project_id).await;
@@ -217,17 +217,17 @@ use pacs_server::infrastructure::repositories::AnnotationRepositoryImpl;
         repository.create(annotation2).await.unwrap();
 
         // OHIF Viewer로 필터링
-        let ohif_annotations = repository.find_by_study_uid_with_viewer(study_uid, Some("OHIF Viewer")).await.unwrap();
+        let ohif_annotations = repository.find_by_study_uid_with_viewer(study_uid, Some("OHIF Viewer"), None).await.unwrap();
         assert_eq!(ohif_annotations.len(), 1);
         assert!(ohif_annotations.iter().all(|a| a.viewer_software == Some("OHIF Viewer".to_string())));
 
         // DICOM Viewer로 필터링
-        let dicom_annotations = repository.find_by_study_uid_with_viewer(study_uid, Some("DICOM Viewer")).await.unwrap();
+        let dicom_annotations = repository.find_by_study_uid_with_viewer(study_uid, Some("DICOM Viewer"), None).await.unwrap();
         assert_eq!(dicom_annotations.len(), 1);
         assert!(dicom_annotations.iter().all(|a| a.viewer_software == Some("DICOM Viewer".to_string())));
 
         // 필터 없이 모든 어노테이션 조회
-        let all_annotations = repository.find_by_study_uid_with_viewer(study_uid, None).await.unwrap();
+        let all_annotations = repository.find_by_study_uid_with_viewer(study_uid, None, None).await.unwrap();
         assert_eq!(all_annotations.len(), 2);
 
         cleanup_test_data(&pool, user_id, project_id).await;
@@ -276,10 +276,10 @@ use pacs_server::infrastructure::repositories::AnnotationRepositoryImpl;
         repository.create(annotation2).await.unwrap();
 
         // 대소문자 구분하여 필터링
-        let ohif_annotations = repository.find_by_user_id_with_viewer(user_id, Some("OHIF Viewer")).await.unwrap();
+        let ohif_annotations = repository.find_by_user_id_with_viewer(user_id, Some("OHIF Viewer"), None).await.unwrap();
         assert_eq!(ohif_annotations.len(), 1);
 
-        let ohif_lower_annotations = repository.find_by_user_id_with_viewer(user_id, Some("ohif viewer")).await.unwrap();
+        let ohif_lower_annotations = repository.find_by_user_id_with_viewer(user_id, Some("ohif viewer"), None).await.unwrap();
         assert_eq!(ohif_lower_annotations.len(), 1);
 
         // 대소문자가 다른 경우 결과가 다름
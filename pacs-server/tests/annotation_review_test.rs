@@ -0,0 +1,194 @@
+mod common;
+
+#[cfg(test)]
+mod annotation_review_tests {
+    use pacs_server::application::dto::annotation_review_dto::{AssignReviewerRequest, CreateReviewCommentRequest};
+    use pacs_server::application::use_cases::AnnotationUseCase;
+    use pacs_server::domain::entities::{AnnotationReviewStatus, NewAnnotation};
+    use pacs_server::domain::repositories::AnnotationRepository;
+    use pacs_server::domain::services::AnnotationServiceImpl;
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{AnnotationRepositoryImpl, ProjectRepositoryImpl, UserRepositoryImpl};
+    use crate::common::{setup_pool, create_user, create_member};
+
+    fn comment(text: &str) -> CreateReviewCommentRequest {
+        CreateReviewCommentRequest { comment: text.to_string() }
+    }
+
+    #[tokio::test]
+    async fn test_review_comments_follow_annotation_visibility() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("review_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = create_member(&pool, project_id, &format!("review_owner_{}", suffix), "USER").await;
+        let reviewer_id = create_member(&pool, project_id, &format!("review_reviewer_{}", suffix), "REVIEWER").await;
+        let member_id = create_member(&pool, project_id, &format!("review_member_{}", suffix), "USER").await;
+        let outsider_id = create_user(&pool, &format!("review_outsider_{}", suffix)).await;
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let mut annotation_ids = Vec::new();
+        for is_shared in [false, true] {
+            let annotation = annotation_repo
+                .create(NewAnnotation {
+                    project_id,
+                    user_id: owner_id,
+                    study_uid: "1.2.3.review".to_string(),
+                    series_uid: None,
+                    instance_uid: None,
+                    tool_name: "Length Tool".to_string(),
+                    tool_version: None,
+                    data: serde_json::json!({"type": "length"}),
+                    is_shared,
+                    viewer_software: None,
+                    description: None,
+                    measurement_values: None,
+                })
+                .await
+                .unwrap();
+            annotation_ids.push(annotation.id);
+        }
+        let (private_id, shared_id) = (annotation_ids[0], annotation_ids[1]);
+
+        let use_case = AnnotationUseCase::new(AnnotationServiceImpl::new(
+            AnnotationRepositoryImpl::new(pool.clone()),
+            UserRepositoryImpl::new(pool.clone()),
+            ProjectRepositoryImpl::new(pool.clone()),
+        ));
+
+        // 공유되지 않은 어노테이션은 작성자와 배정된 검토자만
+        use_case.add_review_comment(private_id, comment("please review"), owner_id).await.unwrap();
+        for user_id in [reviewer_id, member_id, outsider_id] {
+            assert!(matches!(
+                use_case.get_review_comments(private_id, user_id).await,
+                Err(ServiceError::Unauthorized(_))
+            ));
+            assert!(matches!(
+                use_case.add_review_comment(private_id, comment("hi"), user_id).await,
+                Err(ServiceError::Unauthorized(_))
+            ));
+        }
+        use_case
+            .assign_reviewer(private_id, AssignReviewerRequest { reviewer_id }, owner_id)
+            .await
+            .unwrap();
+        use_case.add_review_comment(private_id, comment("looks good"), reviewer_id).await.unwrap();
+        assert_eq!(use_case.get_review_comments(private_id, owner_id).await.unwrap().total, 2);
+        assert!(matches!(
+            use_case.get_review_comments(private_id, member_id).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+
+        // 공유된 어노테이션은 프로젝트 멤버 모두, 멤버가 아니면 불가
+        use_case.add_review_comment(shared_id, comment("shared note"), member_id).await.unwrap();
+        assert_eq!(use_case.get_review_comments(shared_id, member_id).await.unwrap().total, 1);
+        assert!(matches!(
+            use_case.get_review_comments(shared_id, outsider_id).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            use_case.get_review_comments(shared_id + 1_000_000, owner_id).await,
+            Err(ServiceError::NotFound(_))
+        ));
+
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![owner_id, reviewer_id, member_id, outsider_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+    #[tokio::test]
+    async fn test_list_filters_review_status_in_query() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("review_filter_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = create_member(&pool, project_id, &format!("review_filter_owner_{}", suffix), "USER").await;
+        let study_uid = format!("1.2.3.filter.{}", suffix);
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let mut annotation_ids = Vec::new();
+        for viewer in ["OHIF Viewer", "OHIF Viewer", "DICOM Viewer"] {
+            let annotation = annotation_repo
+                .create(NewAnnotation {
+                    project_id,
+                    user_id: owner_id,
+                    study_uid: study_uid.clone(),
+                    series_uid: None,
+                    instance_uid: None,
+                    tool_name: "Length Tool".to_string(),
+                    tool_version: None,
+                    data: serde_json::json!({"type": "length"}),
+                    is_shared: false,
+                    viewer_software: Some(viewer.to_string()),
+                    description: None,
+                    measurement_values: None,
+                })
+                .await
+                .unwrap();
+            annotation_ids.push(annotation.id);
+        }
+        for id in [annotation_ids[0], annotation_ids[2]] {
+            annotation_repo
+                .update_review_status(id, owner_id, AnnotationReviewStatus::Draft, AnnotationReviewStatus::Submitted, None)
+                .await
+                .unwrap();
+        }
+
+        let use_case = AnnotationUseCase::new(AnnotationServiceImpl::new(
+            AnnotationRepositoryImpl::new(pool.clone()),
+            UserRepositoryImpl::new(pool.clone()),
+            ProjectRepositoryImpl::new(pool.clone()),
+        ));
+        let submitted = Some(AnnotationReviewStatus::Submitted);
+
+        let by_user = use_case.get_annotations_by_user_with_viewer(owner_id, None, submitted).await.unwrap();
+        assert_eq!(by_user.total, 2);
+        assert!(by_user.annotations.iter().all(|a| a.review_status == AnnotationReviewStatus::Submitted));
+        let by_project = annotation_repo
+            .find_by_project_id_with_viewer(project_id, Some("OHIF Viewer"), submitted)
+            .await
+            .unwrap();
+        assert_eq!(by_project.iter().map(|a| a.id).collect::<Vec<_>>(), vec![annotation_ids[0]]);
+        let drafts = use_case
+            .get_annotations_by_study_with_viewer(&study_uid, None, Some(AnnotationReviewStatus::Draft))
+            .await
+            .unwrap();
+        assert_eq!(drafts.annotations.iter().map(|a| a.id).collect::<Vec<_>>(), vec![annotation_ids[1]]);
+        assert_eq!(use_case.get_annotations_by_study_with_viewer(&study_uid, None, None).await.unwrap().total, 3);
+
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(owner_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
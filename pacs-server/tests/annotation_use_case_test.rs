@@ -355,7 +355,7 @@ async fn cleanup_test_data(pool: &Arc<sqlx::Pool<sqlx::Postgres>>) {
             description: Some("Updated annotation".to_string()),
         };
 
-        let result = annotation_use_case.update_annotation(annotation_id, update_req).await;
+        let result = annotation_use_case.update_annotation(annotation_id, update_req, user_id).await;
         match result {
             Ok(_) => println!("Annotation updated successfully"),
             Err(e) => {
@@ -432,7 +432,7 @@ async fn cleanup_test_data(pool: &Arc<sqlx::Pool<sqlx::Postgres>>) {
             .expect("Failed to create test annotation");
         let annotation_id = annotation.id;
 
-        let result = annotation_use_case.delete_annotation(annotation_id).await;
+        let result = annotation_use_case.delete_annotation(annotation_id, user_id).await;
         assert!(result.is_ok());
 
         // Verify annotation was deleted
@@ -667,21 +667,21 @@ async fn cleanup_test_data(pool: &Arc<sqlx::Pool<sqlx::Postgres>>) {
         annotation_use_case.create_annotation(annotation3, user_id, project_id).await.unwrap();
 
         // OHIF Viewer로 필터링
-        let ohif_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("OHIF Viewer")).await.unwrap();
+        let ohif_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("OHIF Viewer"), None).await.unwrap();
         assert_eq!(ohif_annotations.total, 2);
         assert!(ohif_annotations.annotations.iter().all(|a| a.viewer_software == Some("OHIF Viewer".to_string())));
 
         // DICOM Viewer로 필터링
-        let dicom_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("DICOM Viewer")).await.unwrap();
+        let dicom_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("DICOM Viewer"), None).await.unwrap();
         assert_eq!(dicom_annotations.total, 1);
         assert!(dicom_annotations.annotations.iter().all(|a| a.viewer_software == Some("DICOM Viewer".to_string())));
 
         // 필터 없이 모든 어노테이션 조회
-        let all_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, None).await.unwrap();
+        let all_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, None, None).await.unwrap();
         assert_eq!(all_annotations.total, 3);
 
         // 존재하지 않는 viewer_software로 필터링
-        let no_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("NonExistent Viewer")).await.unwrap();
+        let no_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("NonExistent Viewer"), None).await.unwrap();
         assert_eq!(no_annotations.total, 0);
 
         cleanup_test_data(&pool).await;
@@ -729,17 +729,17 @@ async fn cleanup_test_data(pool: &Arc<sqlx::Pool<sqlx::Postgres>>) {
         annotation_use_case.create_annotation(annotation2, user_id, project_id).await.unwrap();
 
         // OHIF Viewer로 필터링
-        let ohif_annotations = annotation_use_case.get_annotations_by_project_with_viewer(project_id, Some("OHIF Viewer")).await.unwrap();
+        let ohif_annotations = annotation_use_case.get_annotations_by_project_with_viewer(project_id, Some("OHIF Viewer"), None).await.unwrap();
         assert_eq!(ohif_annotations.total, 1);
         assert!(ohif_annotations.annotations.iter().all(|a| a.viewer_software == Some("OHIF Viewer".to_string())));
 
         // DICOM Viewer로 필터링
-        let dicom_annotations = annotation_use_case.get_annotations_by_project_with_viewer(project_id, Some("DICOM Viewer")).await.unwrap();
+        let dicom_annotations = annotation_use_case.get_annotations_by_project_with_viewer(project_id, Some("DICOM Viewer"), None).await.unwrap();
         assert_eq!(dicom_annotations.total, 1);
         assert!(dicom_annotations.annotations.iter().all(|a| a.viewer_software == Some("DICOM Viewer".to_string())));
 
         // 필터 없이 모든 어노테이션 조회
-        let all_annotations = annotation_use_case.get_annotations_by_project_with_viewer(project_id, None).await.unwrap();
+        let all_annotations = annotation_use_case.get_annotations_by_project_with_viewer(project_id, None, None).await.unwrap();
         assert_eq!(all_annotations.total, 2);
 
         cleanup_test_data(&pool).await;
@@ -788,17 +788,17 @@ async fn cleanup_test_data(pool: &Arc<sqlx::Pool<sqlx::Postgres>>) {
         annotation_use_case.create_annotation(annotation2, user_id, project_id).await.unwrap();
 
         // OHIF Viewer로 필터링
-        let ohif_annotations = annotation_use_case.get_annotations_by_study_with_viewer(study_uid, Some("OHIF Viewer")).await.unwrap();
+        let ohif_annotations = annotation_use_case.get_annotations_by_study_with_viewer(study_uid, Some("OHIF Viewer"), None).await.unwrap();
         assert_eq!(ohif_annotations.total, 1);
         assert!(ohif_annotations.annotations.iter().all(|a| a.viewer_software == Some("OHIF Viewer".to_string())));
 
         // DICOM Viewer로 필터링
-        let dicom_annotations = annotation_use_case.get_annotations_by_study_with_viewer(study_uid, Some("DICOM Viewer")).await.unwrap();
+        let dicom_annotations = annotation_use_case.get_annotations_by_study_with_viewer(study_uid, Some("DICOM Viewer"), None).await.unwrap();
         assert_eq!(dicom_annotations.total, 1);
         assert!(dicom_annotations.annotations.iter().all(|a| a.viewer_software == Some("DICOM Viewer".to_string())));
 
         // 필터 없이 모든 어노테이션 조회
-        let all_annotations = annotation_use_case.get_annotations_by_study_with_viewer(study_uid, None).await.unwrap();
+        let all_annotations = annotation_use_case.get_annotations_by_study_with_viewer(study_uid, None, None).await.unwrap();
         assert_eq!(all_annotations.total, 2);
 
         cleanup_test_data(&pool).await;
@@ -80,7 +80,7 @@ mod annotation_viewer_filter_performance_tests {
 
         // 성능 테스트: OHIF Viewer 필터링
         let start = Instant::now();
-        let ohif_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("OHIF Viewer")).await.unwrap();
+        let ohif_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("OHIF Viewer"), None).await.unwrap();
         let ohif_duration = start.elapsed();
         
         assert_eq!(ohif_annotations.total, 34); // 100개 중 34개 (0, 3, 6, 9, ...)
@@ -89,7 +89,7 @@ mod annotation_viewer_filter_performance_tests {
 
         // 성능 테스트: DICOM Viewer 필터링
         let start = Instant::now();
-        let dicom_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("DICOM Viewer")).await.unwrap();
+        let dicom_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("DICOM Viewer"), None).await.unwrap();
         let dicom_duration = start.elapsed();
         
         assert_eq!(dicom_annotations.total, 33); // 100개 중 33개 (1, 4, 7, 10, ...)
@@ -98,7 +98,7 @@ mod annotation_viewer_filter_performance_tests {
 
         // 성능 테스트: 필터 없이 모든 어노테이션 조회
         let start = Instant::now();
-        let all_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, None).await.unwrap();
+        let all_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, None, None).await.unwrap();
         let all_duration = start.elapsed();
         
         assert_eq!(all_annotations.total, 100);
@@ -107,7 +107,7 @@ mod annotation_viewer_filter_performance_tests {
 
         // 성능 테스트: 존재하지 않는 viewer_software 필터링
         let start = Instant::now();
-        let no_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("NonExistent Viewer")).await.unwrap();
+        let no_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("NonExistent Viewer"), None).await.unwrap();
         let no_duration = start.elapsed();
         
         assert_eq!(no_annotations.total, 0);
@@ -142,11 +142,11 @@ mod annotation_viewer_filter_performance_tests {
         let start = Instant::now();
         
         let futures = vec![
-            annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("OHIF Viewer")),
-            annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("DICOM Viewer")),
-            annotation_use_case.get_annotations_by_user_with_viewer(user_id, None),
-            annotation_use_case.get_annotations_by_project_with_viewer(project_id, Some("OHIF Viewer")),
-            annotation_use_case.get_annotations_by_project_with_viewer(project_id, None),
+            annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("OHIF Viewer"), None),
+            annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("DICOM Viewer"), None),
+            annotation_use_case.get_annotations_by_user_with_viewer(user_id, None, None),
+            annotation_use_case.get_annotations_by_project_with_viewer(project_id, Some("OHIF Viewer"), None),
+            annotation_use_case.get_annotations_by_project_with_viewer(project_id, None, None),
         ];
 
         let results = futures::future::join_all(futures).await;
@@ -197,9 +197,9 @@ mod annotation_viewer_filter_performance_tests {
         // 메모리 사용량 측정을 위한 반복 요청
         let start = Instant::now();
         for _ in 0..10 {
-            let _ohif_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("OHIF Viewer")).await.unwrap();
-            let _dicom_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("DICOM Viewer")).await.unwrap();
-            let _all_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, None).await.unwrap();
+            let _ohif_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("OHIF Viewer"), None).await.unwrap();
+            let _dicom_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, Some("DICOM Viewer"), None).await.unwrap();
+            let _all_annotations = annotation_use_case.get_annotations_by_user_with_viewer(user_id, None, None).await.unwrap();
         }
         let duration = start.elapsed();
 
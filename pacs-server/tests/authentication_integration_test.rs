@@ -471,6 +471,7 @@ mod authentication_integration_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Auth test group".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -0,0 +1,169 @@
+mod common;
+
+#[cfg(test)]
+mod comment_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::comment_dto::CreateCommentRequest;
+    use pacs_server::application::use_cases::CommentUseCase;
+    use pacs_server::domain::entities::{CommentTargetType, NewAnnotation};
+    use pacs_server::domain::repositories::AnnotationRepository;
+    use pacs_server::domain::services::{AnnotationServiceImpl, MaskGroupServiceImpl, ProjectServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, CommentRepositoryImpl, MaskGroupRepositoryImpl, ProjectRepositoryImpl,
+        RoleRepositoryImpl, UserRepositoryImpl,
+    };
+    use crate::common::{setup_pool, create_user};
+
+    fn new_annotation(project_id: i32, user_id: i32, is_shared: bool) -> NewAnnotation {
+        NewAnnotation {
+            project_id,
+            user_id,
+            study_uid: "1.2.3.comment".to_string(),
+            series_uid: None,
+            instance_uid: None,
+            tool_name: "Polygon Tool".to_string(),
+            tool_version: None,
+            data: serde_json::json!({"type": "polygon", "points": [[0, 0], [1, 0], [1, 1]]}),
+            is_shared,
+            viewer_software: None,
+            description: None,
+            measurement_values: None,
+        }
+    }
+
+    fn comment(body: &str) -> CreateCommentRequest {
+        CreateCommentRequest { body: body.to_string(), parent_id: None }
+    }
+
+    #[tokio::test]
+    async fn test_comment_visibility_follows_annotation_sharing() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let owner = create_user(&pool, &format!("comment_owner_{}", suffix)).await;
+        let member = create_user(&pool, &format!("comment_member_{}", suffix)).await;
+        let outsider = create_user(&pool, &format!("comment_outsider_{}", suffix)).await;
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("comment_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        for user_id in [owner, member] {
+            sqlx::query("INSERT INTO security_user_project (user_id, project_id) VALUES ($1, $2)")
+                .bind(user_id)
+                .bind(project_id)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let shared = annotation_repo.create(new_annotation(project_id, owner, true)).await.unwrap();
+        let private = annotation_repo.create(new_annotation(project_id, owner, false)).await.unwrap();
+
+        let private_group: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'group') RETURNING id"
+        )
+        .bind(private.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let use_case = CommentUseCase::new(
+            Arc::new(CommentRepositoryImpl::new(pool.clone())),
+            Arc::new(AnnotationServiceImpl::new(
+                AnnotationRepositoryImpl::new(pool.clone()),
+                UserRepositoryImpl::new(pool.clone()),
+                ProjectRepositoryImpl::new(pool.clone()),
+            )),
+            Arc::new(MaskGroupServiceImpl::new(
+                Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+                Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+                Arc::new(UserRepositoryImpl::new(pool.clone())),
+            )),
+            Arc::new(ProjectServiceImpl::new(
+                ProjectRepositoryImpl::new(pool.clone()),
+                UserRepositoryImpl::new(pool.clone()),
+                RoleRepositoryImpl::new(pool.clone()),
+            )),
+        );
+        let annotation_target = CommentTargetType::Annotation;
+
+        // 공유된 어노테이션: 프로젝트 멤버는 보고 쓸 수 있고, 외부 사용자는 불가
+        let shared_comment = use_case
+            .create_comment(shared.id, annotation_target, shared.id, comment("경계 확인 부탁드립니다."), owner)
+            .await
+            .unwrap();
+        use_case
+            .create_comment(shared.id, annotation_target, shared.id, comment("확인했습니다."), member)
+            .await
+            .unwrap();
+        let listed = use_case.list_comments(shared.id, annotation_target, shared.id, member).await.unwrap();
+        assert_eq!(listed.total_count, 2);
+        assert!(matches!(
+            use_case.list_comments(shared.id, annotation_target, shared.id, outsider).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            use_case.get_comment_history(shared_comment.id, outsider).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+
+        // 공유되지 않은 어노테이션: 작성자만 보고 쓸 수 있음
+        let private_comment = use_case
+            .create_comment(private.id, annotation_target, private.id, comment("메모"), owner)
+            .await
+            .unwrap();
+        assert_eq!(
+            use_case.list_comments(private.id, annotation_target, private.id, owner).await.unwrap().total_count,
+            1
+        );
+        for user_id in [member, outsider] {
+            assert!(matches!(
+                use_case.list_comments(private.id, annotation_target, private.id, user_id).await,
+                Err(ServiceError::Unauthorized(_))
+            ));
+            assert!(matches!(
+                use_case
+                    .create_comment(private.id, annotation_target, private.id, comment("보이나요?"), user_id)
+                    .await,
+                Err(ServiceError::Unauthorized(_))
+            ));
+            assert!(matches!(
+                use_case.get_comment_history(private_comment.id, user_id).await,
+                Err(ServiceError::Unauthorized(_))
+            ));
+        }
+
+        // 마스크 그룹 코멘트도 상위 어노테이션의 가시성을 따름
+        use_case
+            .create_comment(private.id, CommentTargetType::MaskGroup, private_group, comment("슬라이스 3"), owner)
+            .await
+            .unwrap();
+        assert!(matches!(
+            use_case
+                .list_comments(private.id, CommentTargetType::MaskGroup, private_group, member)
+                .await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        for user_id in [owner, member, outsider] {
+            sqlx::query("DELETE FROM security_user WHERE id = $1")
+                .bind(user_id)
+                .execute(&pool)
+                .await
+                .ok();
+        }
+    }
+}
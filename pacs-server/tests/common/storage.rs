@@ -0,0 +1,200 @@
+//! 통합 테스트용 메모리 객체 스토리지
+//!
+//! 객체 메타데이터와 멀티파트 업로드 상태만 메모리에 보관하고,
+//! 지정한 객체의 삭제를 실패시켜 스토리지 장애를 흉내낼 수 있다.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Mutex;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use pacs_server::application::services::{
+    CompletedPart, ObjectStorageError, ObjectStorageService, SignedUrlOptions, UploadedFile, UploadedPart,
+};
+
+/// 파트 번호 -> (ETag, 크기)
+type StoredParts = BTreeMap<i32, (String, i64)>;
+
+#[derive(Default)]
+pub struct MemoryObjectStorage {
+    objects: Mutex<BTreeMap<String, UploadedFile>>,
+    failing: Mutex<BTreeSet<String>>,
+    deleted: Mutex<Vec<String>>,
+    /// 업로드 ID별 (경로, 업로드된 파트)
+    uploads: Mutex<HashMap<String, (String, StoredParts)>>,
+    completed: Mutex<Vec<(String, Vec<i32>)>>,
+    aborted: Mutex<Vec<String>>,
+}
+
+impl MemoryObjectStorage {
+    /// 크기 0인 객체를 방금 업로드한 것처럼 추가
+    pub fn put(&self, key: &str) {
+        self.put_object(key, 0, None, Duration::zero());
+    }
+
+    /// `age` 전에 업로드된 객체를 메타데이터와 함께 추가
+    pub fn put_object(&self, key: &str, size: i64, checksum: Option<&str>, age: Duration) {
+        self.objects.lock().unwrap().insert(key.to_string(), UploadedFile {
+            file_path: key.to_string(),
+            file_size: size,
+            checksum: checksum.map(str::to_string),
+            mime_type: None,
+            last_modified: Some((Utc::now() - age).to_rfc3339()),
+        });
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.objects.lock().unwrap().contains_key(key)
+    }
+
+    /// `failing`이면 해당 객체의 삭제가 S3 오류로 실패
+    pub fn set_failing(&self, key: &str, failing: bool) {
+        let mut keys = self.failing.lock().unwrap();
+        if failing {
+            keys.insert(key.to_string());
+        } else {
+            keys.remove(key);
+        }
+    }
+
+    /// 클라이언트가 발급받은 URL로 파트를 PUT한 것처럼 기록
+    pub fn put_part(&self, upload_id: &str, part_number: i32, size: i64) {
+        let mut uploads = self.uploads.lock().unwrap();
+        let (_, parts) = uploads.get_mut(upload_id).expect("upload exists");
+        parts.insert(part_number, (format!("\"etag-{}-{}\"", part_number, size), size));
+    }
+
+    /// 삭제 요청된 경로 (요청 순서)
+    pub fn deleted(&self) -> Vec<String> {
+        self.deleted.lock().unwrap().clone()
+    }
+
+    /// 완료된 멀티파트 업로드의 (경로, 파트 번호)
+    pub fn completed(&self) -> Vec<(String, Vec<i32>)> {
+        self.completed.lock().unwrap().clone()
+    }
+
+    /// 중단된 멀티파트 업로드 ID
+    pub fn aborted(&self) -> Vec<String> {
+        self.aborted.lock().unwrap().clone()
+    }
+}
+
+fn unsupported() -> ObjectStorageError {
+    ObjectStorageError::InvalidRequest("unsupported in test".to_string())
+}
+
+#[async_trait]
+impl ObjectStorageService for MemoryObjectStorage {
+    async fn generate_upload_url(&self, _: &str, _: SignedUrlOptions) -> Result<String, ObjectStorageError> {
+        Err(unsupported())
+    }
+    async fn generate_download_url(&self, _: &str, _: u64) -> Result<String, ObjectStorageError> {
+        Err(unsupported())
+    }
+    async fn delete_file(&self, file_path: &str) -> Result<(), ObjectStorageError> {
+        if self.failing.lock().unwrap().contains(file_path) {
+            return Err(ObjectStorageError::S3Error("simulated outage".to_string()));
+        }
+        self.objects.lock().unwrap().remove(file_path);
+        self.deleted.lock().unwrap().push(file_path.to_string());
+        Ok(())
+    }
+    async fn get_file_metadata(&self, file_path: &str) -> Result<UploadedFile, ObjectStorageError> {
+        self.objects
+            .lock()
+            .unwrap()
+            .get(file_path)
+            .cloned()
+            .ok_or_else(|| ObjectStorageError::FileNotFound(file_path.to_string()))
+    }
+    async fn file_exists(&self, file_path: &str) -> Result<bool, ObjectStorageError> {
+        Ok(self.contains(file_path))
+    }
+    async fn list_files(&self, prefix: &str, max_keys: Option<i32>) -> Result<Vec<String>, ObjectStorageError> {
+        Ok(self.objects
+            .lock()
+            .unwrap()
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .take(max_keys.map_or(usize::MAX, |max| max as usize))
+            .cloned()
+            .collect())
+    }
+    async fn copy_file(&self, _: &str, _: &str) -> Result<(), ObjectStorageError> {
+        Err(unsupported())
+    }
+    async fn move_file(&self, source_path: &str, destination_path: &str) -> Result<(), ObjectStorageError> {
+        let mut objects = self.objects.lock().unwrap();
+        let mut object = objects
+            .remove(source_path)
+            .ok_or_else(|| ObjectStorageError::FileNotFound(source_path.to_string()))?;
+        object.file_path = destination_path.to_string();
+        objects.insert(destination_path.to_string(), object);
+        Ok(())
+    }
+    async fn upload_file(&self, _: &str, _: Vec<u8>, _: Option<&str>) -> Result<(), ObjectStorageError> {
+        Err(unsupported())
+    }
+    async fn download_file(&self, _: &str) -> Result<Vec<u8>, ObjectStorageError> {
+        Err(unsupported())
+    }
+    async fn initiate_multipart_upload(&self, file_path: &str, _: Option<&str>) -> Result<String, ObjectStorageError> {
+        let upload_id = uuid::Uuid::new_v4().to_string();
+        self.uploads
+            .lock()
+            .unwrap()
+            .insert(upload_id.clone(), (file_path.to_string(), BTreeMap::new()));
+        Ok(upload_id)
+    }
+    async fn generate_upload_part_url(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        part_number: i32,
+        ttl_seconds: u64,
+    ) -> Result<String, ObjectStorageError> {
+        Ok(format!(
+            "https://storage.test/{}?uploadId={}&partNumber={}&ttl={}",
+            file_path, upload_id, part_number, ttl_seconds
+        ))
+    }
+    async fn list_uploaded_parts(&self, _: &str, upload_id: &str) -> Result<Vec<UploadedPart>, ObjectStorageError> {
+        let uploads = self.uploads.lock().unwrap();
+        let (_, parts) = uploads
+            .get(upload_id)
+            .ok_or_else(|| ObjectStorageError::FileNotFound(upload_id.to_string()))?;
+        Ok(parts
+            .iter()
+            .map(|(number, (etag, size))| UploadedPart { part_number: *number, etag: etag.clone(), size: *size })
+            .collect())
+    }
+    async fn complete_multipart_upload(
+        &self,
+        file_path: &str,
+        upload_id: &str,
+        parts: &[CompletedPart],
+    ) -> Result<(), ObjectStorageError> {
+        let uploaded = self
+            .uploads
+            .lock()
+            .unwrap()
+            .remove(upload_id)
+            .map(|(_, parts)| parts)
+            .unwrap_or_default();
+        let size = parts
+            .iter()
+            .filter_map(|part| uploaded.get(&part.part_number).map(|(_, size)| *size))
+            .sum();
+        self.put_object(file_path, size, None, Duration::zero());
+        self.completed
+            .lock()
+            .unwrap()
+            .push((file_path.to_string(), parts.iter().map(|part| part.part_number).collect()));
+        Ok(())
+    }
+    async fn abort_multipart_upload(&self, _: &str, upload_id: &str) -> Result<(), ObjectStorageError> {
+        self.uploads.lock().unwrap().remove(upload_id);
+        self.aborted.lock().unwrap().push(upload_id.to_string());
+        Ok(())
+    }
+}
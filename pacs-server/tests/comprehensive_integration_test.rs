@@ -309,6 +309,7 @@ mod comprehensive_integration_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Comprehensive test group".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -421,6 +422,7 @@ mod comprehensive_integration_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Mask test group".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -753,6 +755,7 @@ mod comprehensive_integration_tests {
             slice_count: -1, // Negative slice count
             mask_type: "".to_string(), // Empty mask type
             description: Some("".to_string()), // Empty description
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -0,0 +1,251 @@
+mod common;
+
+#[cfg(test)]
+mod dataset_export_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::CreateDatasetExportRequest;
+    use pacs_server::application::services::{ObjectStorageService, SignedUrlServiceImpl};
+    use pacs_server::application::use_cases::DatasetExportUseCase;
+    use pacs_server::domain::entities::{
+        AnnotationReviewStatus, DatasetExportCounts, DatasetExportFilter, DatasetExportFormat,
+        DatasetExportStatus, NewAnnotation, NewDatasetExportJob,
+    };
+    use pacs_server::domain::repositories::{AnnotationRepository, DatasetExportRepository};
+    use pacs_server::infrastructure::external::LocalObjectStorageService;
+    use pacs_server::infrastructure::imaging::encode_binary_mask_png;
+    use pacs_server::infrastructure::repositories::{AnnotationRepositoryImpl, DatasetExportRepositoryImpl};
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user};
+
+    async fn create_annotation(
+        repo: &AnnotationRepositoryImpl,
+        project_id: i32,
+        user_id: i32,
+        data: serde_json::Value,
+        is_shared: bool,
+    ) -> i32 {
+        repo.create(NewAnnotation {
+            project_id,
+            user_id,
+            study_uid: "1.2.3.dataset".to_string(),
+            series_uid: Some("1.2.3.dataset.1".to_string()),
+            instance_uid: Some("1.2.3.dataset.1.1".to_string()),
+            tool_name: "Polygon Tool".to_string(),
+            tool_version: None,
+            data,
+            is_shared,
+            viewer_software: None,
+            description: None,
+            measurement_values: None,
+        })
+        .await
+        .unwrap()
+        .id
+    }
+
+    /// 마스크 그룹과 마스크를 만들고 마스크 파일 경로를 반환
+    async fn create_mask(pool: &PgPool, annotation_id: i32, model_name: &str, label: &str) -> String {
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name, model_name) VALUES ($1, 'group', $2) RETURNING id"
+        )
+        .bind(annotation_id)
+        .bind(model_name)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        let file_path = format!("masks/annotation_{}/group_{}/slice_0000_{}.png", annotation_id, group_id, label);
+        sqlx::query(
+            "INSERT INTO annotation_mask (mask_group_id, slice_index, sop_instance_uid, label_name, file_path)
+             VALUES ($1, 0, '1.2.3.dataset.1.1', $2, $3)"
+        )
+        .bind(group_id)
+        .bind(label)
+        .bind(&file_path)
+        .execute(pool)
+        .await
+        .unwrap();
+        file_path
+    }
+
+    #[tokio::test]
+    async fn test_dataset_export_filters_and_job_lifecycle() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let owner = create_user(&pool, &format!("dataset_owner_{}", suffix)).await;
+        let other = create_user(&pool, &format!("dataset_other_{}", suffix)).await;
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("dataset_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO security_user_project (user_id, project_id) VALUES ($1, $2)")
+            .bind(owner)
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let polygon = serde_json::json!({"type": "polygon", "label": "liver", "points": [[0, 0], [4, 0], [4, 4]]});
+        let shared = create_annotation(&annotation_repo, project_id, owner, polygon.clone(), true).await;
+        let private = create_annotation(&annotation_repo, project_id, owner, polygon, false).await;
+        create_mask(&pool, shared, "unet", "liver").await;
+        create_mask(&pool, private, "nnunet", "tumor").await;
+        sqlx::query("UPDATE annotation_annotation SET review_status = 'APPROVED' WHERE id = $1")
+            .bind(shared)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let repo = DatasetExportRepositoryImpl::new(pool.clone());
+        assert!(repo.is_project_member(project_id, owner).await.unwrap());
+        assert!(!repo.is_project_member(project_id, other).await.unwrap());
+
+        let all = DatasetExportFilter::default();
+        assert_eq!(repo.find_annotations(project_id, &all, Some(owner)).await.unwrap().len(), 2);
+        // 다른 사용자는 공유된 어노테이션과 그 마스크만 볼 수 있음
+        assert_eq!(repo.find_annotations(project_id, &all, Some(other)).await.unwrap().len(), 1);
+        assert_eq!(repo.find_masks(project_id, &all, Some(other)).await.unwrap().len(), 1);
+
+        let by_model = DatasetExportFilter { model_name: Some("nnunet".to_string()), ..Default::default() };
+        let annotations = repo.find_annotations(project_id, &by_model, Some(owner)).await.unwrap();
+        assert_eq!(annotations.iter().map(|a| a.annotation_id).collect::<Vec<_>>(), vec![private]);
+        let masks = repo.find_masks(project_id, &by_model, Some(owner)).await.unwrap();
+        assert_eq!(masks.len(), 1);
+        assert_eq!(masks[0].label(), "tumor");
+
+        let by_label = DatasetExportFilter { label: Some("liver".to_string()), ..Default::default() };
+        assert_eq!(repo.find_annotations(project_id, &by_label, Some(owner)).await.unwrap().len(), 2);
+        assert_eq!(repo.find_masks(project_id, &by_label, Some(owner)).await.unwrap().len(), 1);
+
+        let approved = DatasetExportFilter { review_status: Some(AnnotationReviewStatus::Approved), ..Default::default() };
+        let masks = repo.find_masks(project_id, &approved, Some(owner)).await.unwrap();
+        assert_eq!(masks.iter().map(|m| m.annotation_id).collect::<Vec<_>>(), vec![shared]);
+
+        let job = repo
+            .create(&NewDatasetExportJob {
+                project_id,
+                format: DatasetExportFormat::Coco,
+                filter: approved.clone(),
+                include_signed_urls: false,
+                url_ttl_seconds: None,
+                requested_by: Some(owner),
+            })
+            .await
+            .unwrap();
+        assert_eq!(job.status, DatasetExportStatus::Pending);
+        assert_eq!(job.filter(), approved);
+
+        repo.mark_running(job.id).await.unwrap();
+        let counts = DatasetExportCounts { annotations: 1, masks: 1, skipped: 0 };
+        let completed = repo.mark_completed(job.id, "exports/test.coco.json", counts).await.unwrap();
+        assert_eq!(completed.status, DatasetExportStatus::Completed);
+        assert_eq!(completed.mask_count, 1);
+        assert_eq!(repo.list_by_project(project_id).await.unwrap().len(), 1);
+
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![owner, other])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+
+    #[tokio::test]
+    async fn test_coco_export_skips_missing_mask_files() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let owner = create_user(&pool, &format!("dataset_coco_owner_{}", suffix)).await;
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("dataset_coco_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO security_user_project (user_id, project_id) VALUES ($1, $2)")
+            .bind(owner)
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let polygon = serde_json::json!({"type": "polygon", "label": "liver", "points": [[0, 0], [4, 0], [4, 4]]});
+        let annotation = create_annotation(&annotation_repo, project_id, owner, polygon, true).await;
+        let uploaded = create_mask(&pool, annotation, "unet", "liver").await;
+        // 스토리지에 업로드되지 않은 마스크
+        create_mask(&pool, annotation, "unet", "spleen").await;
+
+        let root = std::env::temp_dir().join(format!("pacs-dataset-export-{}", suffix));
+        let storage = Arc::new(
+            LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+                .await
+                .unwrap(),
+        );
+        let mut pixels = vec![0u8; 16];
+        pixels[5] = 1;
+        storage
+            .upload_file(&uploaded, encode_binary_mask_png(4, 4, &pixels).unwrap(), Some("image/png"))
+            .await
+            .unwrap();
+
+        let use_case = DatasetExportUseCase::new(
+            Arc::new(SignedUrlServiceImpl::new(Box::new(storage.clone()), 600, 3600)),
+            Arc::new(DatasetExportRepositoryImpl::new(pool.clone())),
+            storage.clone(),
+        );
+        let job = use_case
+            .start_export(
+                project_id,
+                CreateDatasetExportRequest {
+                    format: DatasetExportFormat::Coco,
+                    label: None,
+                    model_name: None,
+                    review_status: None,
+                    include_signed_urls: None,
+                    url_ttl_seconds: None,
+                },
+                owner,
+            )
+            .await
+            .unwrap();
+
+        let mut job = use_case.get_export_job(project_id, job.id, owner).await.unwrap();
+        for _ in 0..50 {
+            if matches!(job.status, DatasetExportStatus::Completed | DatasetExportStatus::Failed) {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            job = use_case.get_export_job(project_id, job.id, owner).await.unwrap();
+        }
+        assert_eq!(job.status, DatasetExportStatus::Completed, "{:?}", job.error_message);
+        assert_eq!((job.annotation_count, job.mask_count, job.skipped_count), (1, 1, 1));
+
+        let _ = std::fs::remove_dir_all(&root);
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(owner)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
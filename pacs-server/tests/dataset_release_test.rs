@@ -0,0 +1,275 @@
+mod common;
+
+#[cfg(test)]
+mod dataset_release_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::dataset_release_dto::CreateDatasetReleaseRequest;
+    use pacs_server::application::services::{ObjectStorageService, SignedUrlServiceImpl};
+    use pacs_server::application::use_cases::DatasetReleaseUseCase;
+    use pacs_server::domain::entities::{
+        DatasetExportFilter, NewAnnotation, NewDatasetRelease, ReleaseAnnotationSnapshot, ReleaseItemDiff,
+        ReleaseMaskSnapshot,
+    };
+    use pacs_server::domain::repositories::{AnnotationRepository, DatasetReleaseRepository};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::external::LocalObjectStorageService;
+    use pacs_server::infrastructure::imaging::encode_binary_mask_png;
+    use pacs_server::infrastructure::repositories::{AnnotationRepositoryImpl, DatasetReleaseRepositoryImpl};
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user};
+
+    fn new_release(
+        project_id: i32,
+        name: &str,
+        created_by: i32,
+        annotations: Vec<ReleaseAnnotationSnapshot>,
+        masks: Vec<ReleaseMaskSnapshot>,
+    ) -> NewDatasetRelease {
+        NewDatasetRelease {
+            project_id,
+            name: name.to_string(),
+            description: None,
+            release_key: uuid::Uuid::new_v4(),
+            manifest_path: format!("releases/project_{}/{}/manifest.json", project_id, name),
+            filter: DatasetExportFilter::default(),
+            created_by: Some(created_by),
+            annotations,
+            masks,
+        }
+    }
+
+    async fn count_snapshot_histories(pool: &PgPool, annotation_id: i32) -> i64 {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM annotation_annotation_history WHERE annotation_id = $1 AND action = 'release_snapshot'"
+        )
+        .bind(annotation_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_release_pins_versions_and_is_immutable() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let user_id = create_user(&pool, &format!("release_user_{}", suffix)).await;
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("release_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(NewAnnotation {
+                project_id,
+                user_id,
+                study_uid: "1.2.3.release".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "polygon", "points": [[0, 0], [1, 0], [1, 1]]}),
+                is_shared: true,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+
+        let repo = DatasetReleaseRepositoryImpl::new(pool.clone());
+        let filter = DatasetExportFilter::default();
+
+        // 생성 시 기록된 히스토리가 그대로 고정됨
+        let first = repo.pin_annotations(project_id, &filter, None, user_id).await.unwrap();
+        assert_eq!(first.len(), 1);
+        assert!(!first[0].snapshot_pending);
+        let again = repo.pin_annotations(project_id, &filter, None, user_id).await.unwrap();
+        assert_eq!(first[0].history_id, again[0].history_id);
+
+        // 히스토리 없이 data가 바뀌면 새 히스토리 ID를 예약하고, 릴리스 저장 시 스냅샷 히스토리로 기록
+        sqlx::query("UPDATE annotation_annotation SET data = '{\"type\": \"point\"}'::jsonb WHERE id = $1")
+            .bind(annotation.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let changed = repo.pin_annotations(project_id, &filter, Some(&[annotation.id]), user_id).await.unwrap();
+        assert_ne!(changed[0].history_id, first[0].history_id);
+        assert_eq!(changed[0].data, serde_json::json!({"type": "point"}));
+        assert!(changed[0].snapshot_pending);
+        assert_eq!(count_snapshot_histories(&pool, annotation.id).await, 0);
+
+        let mask = |checksum: &str| ReleaseMaskSnapshot {
+            mask_id: 1,
+            mask_group_id: 1,
+            annotation_id: annotation.id,
+            sop_instance_uid: None,
+            slice_index: Some(0),
+            label_name: None,
+            checksum: checksum.to_string(),
+            source_path: "masks/a.png".to_string(),
+            object_key: "releases/a.png".to_string(),
+        };
+        let v1 = repo
+            .create(&new_release(project_id, "v1", user_id, first.clone(), vec![mask("aaa")]))
+            .await
+            .unwrap();
+        let v2 = repo
+            .create(&new_release(project_id, "v2", user_id, changed.clone(), vec![mask("aaa")]))
+            .await
+            .unwrap();
+        assert_eq!((v1.annotation_count, v1.mask_count), (1, 1));
+        assert_eq!(count_snapshot_histories(&pool, annotation.id).await, 1);
+        let pinned = repo.pin_annotations(project_id, &filter, None, user_id).await.unwrap();
+        assert_eq!(pinned[0].history_id, changed[0].history_id);
+        assert!(!pinned[0].snapshot_pending);
+
+        let duplicate = repo.create(&new_release(project_id, "v1", user_id, vec![], vec![])).await;
+        assert!(matches!(duplicate, Err(ServiceError::AlreadyExists(_))));
+
+        let immutable = sqlx::query("UPDATE annotation_dataset_release SET name = 'renamed' WHERE id = $1")
+            .bind(v1.id)
+            .execute(&pool)
+            .await;
+        assert!(immutable.is_err());
+
+        let annotations = ReleaseItemDiff::compute(
+            &repo.get_annotation_pins(v1.id).await.unwrap(),
+            &repo.get_annotation_pins(v2.id).await.unwrap(),
+        );
+        assert_eq!(annotations.changed, vec![annotation.id]);
+        let masks = ReleaseItemDiff::compute(
+            &repo.get_mask_pins(v1.id).await.unwrap(),
+            &repo.get_mask_pins(v2.id).await.unwrap(),
+        );
+        assert_eq!(masks.unchanged_count, 1);
+
+        // 프로젝트 삭제 시 릴리스도 함께 삭제됨
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(repo.get_by_id(v1.id).await.unwrap().is_none());
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+
+    #[tokio::test]
+    async fn test_failed_release_leaves_no_objects_or_histories() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let user_id = create_user(&pool, &format!("release_user_{}", suffix)).await;
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("release_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO security_user_project (user_id, project_id) VALUES ($1, $2)")
+            .bind(user_id)
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(NewAnnotation {
+                project_id,
+                user_id,
+                study_uid: "1.2.3.release".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "polygon", "points": [[0, 0], [1, 0], [1, 1]]}),
+                is_shared: true,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        // 히스토리 없이 data를 바꿔 릴리스 저장 시 스냅샷 히스토리가 필요하도록 만듦
+        sqlx::query("UPDATE annotation_annotation SET data = '{\"type\": \"point\"}'::jsonb WHERE id = $1")
+            .bind(annotation.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'group') RETURNING id"
+        )
+        .bind(annotation.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let mut file_paths = Vec::new();
+        for slice in 0..2 {
+            let file_path = format!("masks/annotation_{}/group_{}/slice_{:04}.png", annotation.id, group_id, slice);
+            sqlx::query("INSERT INTO annotation_mask (mask_group_id, slice_index, file_path) VALUES ($1, $2, $3)")
+                .bind(group_id)
+                .bind(slice)
+                .bind(&file_path)
+                .execute(&pool)
+                .await
+                .unwrap();
+            file_paths.push(file_path);
+        }
+
+        let root = std::env::temp_dir().join(format!("pacs-dataset-release-{}", suffix));
+        let storage = Arc::new(
+            LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+                .await
+                .unwrap(),
+        );
+        let png = encode_binary_mask_png(2, 2, &[1, 0, 0, 0]).unwrap();
+        // 두 번째 마스크 파일은 업로드하지 않아 첫 번째 사본을 만든 뒤 생성이 실패함
+        storage.upload_file(&file_paths[0], png.clone(), Some("image/png")).await.unwrap();
+
+        let repo = Arc::new(DatasetReleaseRepositoryImpl::new(pool.clone()));
+        let use_case = DatasetReleaseUseCase::new(
+            Arc::new(SignedUrlServiceImpl::new(Box::new(storage.clone()), 600, 3600)),
+            repo.clone(),
+            storage.clone(),
+        );
+        let request = || CreateDatasetReleaseRequest {
+            name: "v1".to_string(),
+            description: None,
+            annotation_ids: None,
+            label: None,
+            model_name: None,
+            review_status: None,
+        };
+        let releases_prefix = format!("releases/project_{}", project_id);
+
+        let failed = use_case.create_release(project_id, request(), user_id).await;
+        assert!(matches!(failed, Err(ServiceError::ExternalServiceError(_))));
+        assert!(storage.list_files(&releases_prefix, None).await.unwrap().is_empty());
+        assert!(repo.find_by_name(project_id, "v1").await.unwrap().is_none());
+        assert_eq!(count_snapshot_histories(&pool, annotation.id).await, 0);
+
+        // 누락된 파일을 올리면 같은 이름으로 다시 만들 수 있음
+        storage.upload_file(&file_paths[1], png, Some("image/png")).await.unwrap();
+        let release = use_case.create_release(project_id, request(), user_id).await.unwrap();
+        assert_eq!((release.annotation_count, release.mask_count), (1, 2));
+        assert_eq!(storage.list_files(&releases_prefix, None).await.unwrap().len(), 3);
+        assert_eq!(count_snapshot_histories(&pool, annotation.id).await, 1);
+
+        let _ = std::fs::remove_dir_all(&root);
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
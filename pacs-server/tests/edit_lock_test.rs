@@ -0,0 +1,264 @@
+mod common;
+
+#[cfg(test)]
+mod edit_lock_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::edit_lock_dto::{
+        AcquireEditLockRequest, EditLockHeartbeatRequest, EditLockListQuery,
+    };
+    use pacs_server::application::use_cases::EditLockUseCase;
+    use pacs_server::domain::entities::edit_lock::EditLockTarget;
+    use pacs_server::domain::entities::mask_group::UpdateMaskGroup;
+    use pacs_server::domain::services::{AnnotationService, AnnotationServiceImpl, MaskGroupService, MaskGroupServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{
+        AccessLogRepositoryImpl, AnnotationRepositoryImpl, EditLockRepositoryImpl, MaskGroupRepositoryImpl,
+        ProjectRepositoryImpl, UserRepositoryImpl,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_member};
+
+    async fn create_annotation(pool: &PgPool, project_id: i32, user_id: i32, study_uid: &str) -> i32 {
+        sqlx::query_scalar(
+            "INSERT INTO annotation_annotation (project_id, user_id, study_uid, tool_name, data, is_shared)
+             VALUES ($1, $2, $3, 'Polygon Tool', '{}'::jsonb, true) RETURNING id"
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .bind(study_uid)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    fn study_lock(study_uid: &str) -> AcquireEditLockRequest {
+        AcquireEditLockRequest { study_uid: Some(study_uid.to_string()), ..Default::default() }
+    }
+
+    fn annotation_lock(annotation_id: i32) -> AcquireEditLockRequest {
+        AcquireEditLockRequest { annotation_id: Some(annotation_id), ..Default::default() }
+    }
+
+    #[tokio::test]
+    async fn test_edit_lock_acquire_heartbeat_and_release() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar(
+            "INSERT INTO security_project (name, status) VALUES ($1, 'COMPLETED') RETURNING id"
+        )
+        .bind(format!("edit_lock_project_{}", suffix))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let admin_id = create_member(&pool, project_id, &format!("el_admin_{}", suffix), "PROJECT_ADMIN").await;
+        let reader_a = create_member(&pool, project_id, &format!("el_reader_a_{}", suffix), "ANNOTATOR").await;
+        let reader_b = create_member(&pool, project_id, &format!("el_reader_b_{}", suffix), "ANNOTATOR").await;
+
+        let study_1 = format!("1.2.840.{}.1", project_id);
+        let study_2 = format!("1.2.840.{}.2", project_id);
+        let annotation_1 = create_annotation(&pool, project_id, reader_a, &study_1).await;
+        let annotation_2 = create_annotation(&pool, project_id, reader_a, &study_1).await;
+
+        let use_case = EditLockUseCase::new(
+            Arc::new(EditLockRepositoryImpl::new(pool.clone())),
+            Arc::new(AccessLogRepositoryImpl::new(pool.clone())),
+            Arc::new(ProjectRepositoryImpl::new(pool.clone())),
+        );
+
+        // 대상은 정확히 하나, TTL은 범위 안이어야 함
+        let result = use_case.acquire_lock(project_id, AcquireEditLockRequest::default(), reader_a).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let too_short = AcquireEditLockRequest { ttl_seconds: Some(1), ..study_lock(&study_1) };
+        let result = use_case.acquire_lock(project_id, too_short, reader_a).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 어노테이션 잠금은 같은 어노테이션과 Study 잠금만 막음
+        let lock_a = use_case.acquire_lock(project_id, annotation_lock(annotation_1), reader_a).await.unwrap();
+        assert_eq!(lock_a.target_type, EditLockTarget::Annotation);
+        assert_eq!(lock_a.study_uid, study_1);
+        let result = use_case.acquire_lock(project_id, annotation_lock(annotation_1), reader_b).await;
+        assert!(matches!(result, Err(ServiceError::AlreadyExists(_))));
+        let result = use_case.acquire_lock(project_id, study_lock(&study_1), reader_b).await;
+        assert!(matches!(result, Err(ServiceError::AlreadyExists(_))));
+        let lock_b = use_case.acquire_lock(project_id, annotation_lock(annotation_2), reader_b).await.unwrap();
+        let lock_study_2 = use_case.acquire_lock(project_id, study_lock(&study_2), reader_b).await.unwrap();
+        assert_eq!(lock_study_2.target_type, EditLockTarget::Study);
+
+        // 다시 획득하면 같은 잠금이 갱신됨
+        let again = use_case.acquire_lock(project_id, annotation_lock(annotation_1), reader_a).await.unwrap();
+        assert_eq!(again.id, lock_a.id);
+
+        // 다른 멤버도 잠금을 볼 수 있음
+        let listed = use_case
+            .list_locks(project_id, EditLockListQuery { study_uid: Some(study_1.clone()) }, admin_id)
+            .await
+            .unwrap();
+        assert_eq!(listed.locks.iter().map(|l| l.id).collect::<Vec<_>>(), vec![lock_a.id, lock_b.id]);
+        assert_eq!(listed.locks[0].holder_username, Some(format!("el_reader_a_{}", suffix)));
+
+        // heartbeat와 해제는 본인 잠금만
+        let heartbeat = EditLockHeartbeatRequest { ttl_seconds: Some(600) };
+        let result = use_case.heartbeat(project_id, lock_a.id, heartbeat.clone(), reader_b).await;
+        assert!(matches!(result, Err(ServiceError::Unauthorized(_))));
+        let renewed = use_case.heartbeat(project_id, lock_a.id, heartbeat, reader_a).await.unwrap();
+        assert!(renewed.expires_at > lock_a.expires_at);
+        let result = use_case.release_lock(project_id, lock_a.id, reader_b).await;
+        assert!(matches!(result, Err(ServiceError::Unauthorized(_))));
+        use_case.release_lock(project_id, lock_a.id, reader_a).await.unwrap();
+        let result = use_case.release_lock(project_id, lock_a.id, reader_a).await;
+        assert!(matches!(result, Err(ServiceError::NotFound(_))));
+
+        // 강제 해제는 프로젝트 관리자만
+        let result = use_case.force_release_lock(project_id, lock_b.id, reader_a).await;
+        assert!(matches!(result, Err(ServiceError::Unauthorized(_))));
+        let forced = use_case.force_release_lock(project_id, lock_b.id, admin_id).await.unwrap();
+        assert_eq!(forced.holder_id, reader_b);
+
+        // 만료된 잠금은 보이지 않고, 다른 사용자가 새로 획득할 수 있음
+        sqlx::query("UPDATE annotation_edit_lock SET expires_at = CURRENT_TIMESTAMP - INTERVAL '1 second' WHERE id = $1")
+            .bind(lock_study_2.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let listed = use_case.list_locks(project_id, EditLockListQuery::default(), reader_a).await.unwrap();
+        assert!(listed.locks.is_empty());
+        let taken_over = use_case.acquire_lock(project_id, study_lock(&study_2), reader_a).await.unwrap();
+        assert_eq!(taken_over.holder_id, reader_a);
+
+        // 획득(거부 포함)과 해제가 접근 로그에 남음
+        let logged: Vec<(String, String)> = sqlx::query_as(
+            "SELECT action, result FROM security_access_log WHERE project_id = $1 ORDER BY id"
+        )
+        .bind(project_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        let count = |action: &str, result: &str| logged.iter().filter(|(a, r)| a == action && r == result).count();
+        assert_eq!(count("EDIT_LOCK_ACQUIRE", "SUCCESS"), 4);
+        assert_eq!(count("EDIT_LOCK_ACQUIRE", "DENIED"), 2);
+        assert_eq!(count("EDIT_LOCK_RELEASE", "SUCCESS"), 1);
+        assert_eq!(count("EDIT_LOCK_FORCE_RELEASE", "SUCCESS"), 1);
+
+        sqlx::query("DELETE FROM security_access_log WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![admin_id, reader_a, reader_b])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+
+    #[tokio::test]
+    async fn test_edit_lock_enforced_on_annotation_and_mask_group_changes() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar(
+            "INSERT INTO security_project (name, status) VALUES ($1, 'COMPLETED') RETURNING id"
+        )
+        .bind(format!("edit_lock_enforce_{}", suffix))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let holder = create_member(&pool, project_id, &format!("el_holder_{}", suffix), "ANNOTATOR").await;
+        let editor = create_member(&pool, project_id, &format!("el_editor_{}", suffix), "ANNOTATOR").await;
+
+        let study_uid = format!("1.2.840.{}.1", project_id);
+        let annotation_id = create_annotation(&pool, project_id, editor, &study_uid).await;
+        let mask_group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'group') RETURNING id"
+        )
+        .bind(annotation_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let lock_use_case = EditLockUseCase::new(
+            Arc::new(EditLockRepositoryImpl::new(pool.clone())),
+            Arc::new(AccessLogRepositoryImpl::new(pool.clone())),
+            Arc::new(ProjectRepositoryImpl::new(pool.clone())),
+        );
+        let annotation_service = AnnotationServiceImpl::new(
+            AnnotationRepositoryImpl::new(pool.clone()),
+            UserRepositoryImpl::new(pool.clone()),
+            ProjectRepositoryImpl::new(pool.clone()),
+        );
+        let mask_group_service = MaskGroupServiceImpl::new(
+            Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+            Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+            Arc::new(UserRepositoryImpl::new(pool.clone())),
+        );
+        let rename = UpdateMaskGroup::new(mask_group_id).with_group_name("renamed".to_string());
+
+        // Study 잠금이 있으면 다른 사용자는 그 Study의 어노테이션과 마스크 그룹을 바꿀 수 없음
+        let study_lock = lock_use_case
+            .acquire_lock(project_id, AcquireEditLockRequest { study_uid: Some(study_uid.clone()), ..Default::default() }, holder)
+            .await
+            .unwrap();
+        let result = annotation_service.update_annotation(annotation_id, json!({"label": "edited"}), true, editor).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let result = annotation_service
+            .update_annotation_with_measurements(annotation_id, json!({"label": "edited"}), true, None, editor)
+            .await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let result = mask_group_service.update_mask_group(mask_group_id, &rename, editor).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let result = annotation_service.delete_annotation(annotation_id, editor).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 잠금을 가진 사용자는 수정할 수 있음
+        let updated = annotation_service.update_annotation(annotation_id, json!({"label": "by holder"}), true, holder).await.unwrap();
+        assert_eq!(updated.data["label"], "by holder");
+        lock_use_case.release_lock(project_id, study_lock.id, holder).await.unwrap();
+
+        // 어노테이션 잠금
+        let annotation_lock = lock_use_case
+            .acquire_lock(project_id, AcquireEditLockRequest { annotation_id: Some(annotation_id), ..Default::default() }, holder)
+            .await
+            .unwrap();
+        let result = mask_group_service.update_mask_group(mask_group_id, &rename, editor).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        lock_use_case.release_lock(project_id, annotation_lock.id, holder).await.unwrap();
+
+        // 잠금이 없으면 수정/삭제 가능
+        let renamed = mask_group_service.update_mask_group(mask_group_id, &rename, editor).await.unwrap();
+        assert_eq!(renamed.group_name, Some("renamed".to_string()));
+        annotation_service.delete_annotation(annotation_id, editor).await.unwrap();
+
+        sqlx::query("DELETE FROM security_access_log WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![holder, editor])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
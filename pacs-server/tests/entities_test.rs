@@ -284,6 +284,9 @@ mod annotation_tests {
             created_at: Utc.timestamp_opt(1234567890, 0).unwrap(),
             updated_at: Utc::timestamp_opt(1234567890, 0).unwrap(),
             measurement_values: None,
+            review_status: AnnotationReviewStatus::Draft,
+            reviewer_id: None,
+            reviewed_at: None,
         };
 
         assert_eq!(annotation.tool_name, "Arrow");
@@ -408,6 +408,7 @@ mod error_handling_tests {
             slice_count: -1, // Negative slice count
             mask_type: "".to_string(), // Empty mask type
             description: Some("Invalid mask group".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -442,6 +443,7 @@ mod error_handling_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Error test group".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -494,6 +496,7 @@ mod error_handling_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Unauthorized test group".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -523,6 +526,7 @@ mod error_handling_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Duplicate test group".to_string()),
+            derived_from_id: None,
         };
 
         // Create first mask group
@@ -563,6 +567,7 @@ mod error_handling_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Database error test group".to_string()),
+            derived_from_id: None,
         };
 
         // Try to create mask group for non-existent annotation
@@ -615,6 +620,7 @@ mod error_handling_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Storage error test group".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -745,6 +751,7 @@ mod error_handling_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Concurrent test group".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
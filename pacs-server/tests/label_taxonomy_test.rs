@@ -0,0 +1,176 @@
+mod common;
+
+#[cfg(test)]
+mod label_taxonomy_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::label_dto::{ApplyLabelMigrationRequest, LabelMigrationStatus, LabelRequest};
+    use pacs_server::application::use_cases::LabelUseCase;
+    use pacs_server::domain::entities::{NewAnnotation, NewMask};
+    use pacs_server::domain::services::{AnnotationService, AnnotationServiceImpl, MaskService, MaskServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, LabelRepositoryImpl, MaskGroupRepositoryImpl, MaskRepositoryImpl,
+        ProjectRepositoryImpl, UserRepositoryImpl,
+    };
+    use crate::common::{setup_pool, create_member};
+
+    fn new_annotation(project_id: i32, user_id: i32, tool_name: &str, label: &str) -> NewAnnotation {
+        NewAnnotation {
+            project_id,
+            user_id,
+            study_uid: "1.2.3.labels".to_string(),
+            series_uid: None,
+            instance_uid: None,
+            tool_name: tool_name.to_string(),
+            tool_version: None,
+            data: serde_json::json!({"type": "polygon", "label": label}),
+            is_shared: true,
+            viewer_software: None,
+            description: None,
+            measurement_values: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_taxonomy_import_validation_and_migration() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        // AnnotationService가 프로젝트를 조회하므로 엔티티로 읽을 수 있는 상태로 생성
+        let project_id: i32 = sqlx::query_scalar(
+            "INSERT INTO security_project (name, status) VALUES ($1, 'COMPLETED') RETURNING id"
+        )
+        .bind(format!("label_project_{}", suffix))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let admin_id = create_member(&pool, project_id, &format!("label_admin_{}", suffix), "PROJECT_ADMIN").await;
+        let member_id = create_member(&pool, project_id, &format!("label_member_{}", suffix), "VIEWER").await;
+
+        let annotation_service = AnnotationServiceImpl::new(
+            AnnotationRepositoryImpl::new(pool.clone()),
+            UserRepositoryImpl::new(pool.clone()),
+            ProjectRepositoryImpl::new(pool.clone()),
+        );
+        let mask_service = MaskServiceImpl::new(
+            Arc::new(MaskRepositoryImpl::new(pool.clone())),
+            Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+            Arc::new(UserRepositoryImpl::new(pool.clone())),
+        );
+        let use_case = LabelUseCase::new(Arc::new(LabelRepositoryImpl::new(pool.clone())), Arc::new(ProjectRepositoryImpl::new(pool.clone())));
+
+        // 분류 체계가 없으면 자유 입력 라벨을 그대로 저장
+        let legacy = annotation_service
+            .create_annotation(new_annotation(project_id, admin_id, "Polygon Tool", "LIVER_SEG"))
+            .await
+            .unwrap();
+        assert_eq!(legacy.data["label"], "LIVER_SEG");
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'legacy') RETURNING id"
+        )
+        .bind(legacy.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let mut legacy_mask = NewMask::with_defaults(group_id, "masks/legacy.png".to_string());
+        legacy_mask.label_name = Some("Liver".to_string());
+        mask_service.create_mask(&legacy_mask).await.unwrap();
+
+        // 관리자만 분류 체계를 수정할 수 있음
+        let csv = "name,color,code_scheme,code_value,parent,allowed_tools,aliases\n\
+                   abdomen,#00aa00,,,,,\n\
+                   liver,#aa0000,SNOMED,10200004,abdomen,Polygon Tool;Brush Tool,hepar\n";
+        let denied = use_case.import_csv(project_id, csv, member_id).await;
+        assert!(matches!(denied, Err(ServiceError::Unauthorized(_))));
+        let imported = use_case.import_csv(project_id, csv, admin_id).await.unwrap();
+        assert_eq!((imported.created, imported.updated), (2, 0));
+        let liver = imported.labels.iter().find(|l| l.name == "liver").unwrap();
+        let abdomen = imported.labels.iter().find(|l| l.name == "abdomen").unwrap();
+        assert_eq!(liver.parent_id, Some(abdomen.id));
+        assert_eq!(liver.code_scheme.as_deref(), Some("SCT"));
+
+        // 이미 있는 이름/별칭과 겹치거나 순환을 만드는 라벨은 거부
+        let duplicate = LabelRequest {
+            name: "Hepar".to_string(),
+            display_name: None,
+            color: None,
+            code_scheme: None,
+            code_value: None,
+            code_meaning: None,
+            parent_id: None,
+            allowed_tools: vec![],
+            aliases: vec![],
+        };
+        let result = use_case.create_label(project_id, duplicate, admin_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let reparent = LabelRequest {
+            name: "abdomen".to_string(),
+            display_name: None,
+            color: None,
+            code_scheme: None,
+            code_value: None,
+            code_meaning: None,
+            parent_id: Some(liver.id),
+            allowed_tools: vec![],
+            aliases: vec![],
+        };
+        let result = use_case.update_label(project_id, abdomen.id, reparent, admin_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let result = use_case.delete_label(project_id, abdomen.id, admin_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 분류 체계가 생기면 라벨을 검증하고 정식 이름으로 저장
+        let annotation = annotation_service
+            .create_annotation(new_annotation(project_id, admin_id, "Brush Tool", "HEPAR"))
+            .await
+            .unwrap();
+        assert_eq!(annotation.data["label"], "liver");
+        let unknown = annotation_service
+            .create_annotation(new_annotation(project_id, admin_id, "Brush Tool", "spleen"))
+            .await;
+        assert!(matches!(unknown, Err(ServiceError::ValidationError(_))));
+        let wrong_tool = annotation_service
+            .create_annotation(new_annotation(project_id, admin_id, "Length Tool", "liver"))
+            .await;
+        assert!(matches!(wrong_tool, Err(ServiceError::ValidationError(_))));
+
+        let mut mask = NewMask::with_defaults(group_id, "masks/new.png".to_string());
+        mask.label_name = Some("Abdomen".to_string());
+        assert_eq!(mask_service.create_mask(&mask).await.unwrap().label_name.as_deref(), Some("abdomen"));
+        mask.label_name = Some("kidney".to_string());
+        assert!(matches!(mask_service.create_mask(&mask).await, Err(ServiceError::ValidationError(_))));
+
+        // 기존 자유 입력 라벨의 매핑 제안과 일괄 변경
+        let preview = use_case.preview_migration(project_id, member_id).await.unwrap();
+        let entry = |label: &str| preview.entries.iter().find(|e| e.label == label).unwrap();
+        assert_eq!(entry("LIVER_SEG").status, LabelMigrationStatus::Suggested);
+        assert_eq!(entry("LIVER_SEG").suggested_label.as_deref(), Some("liver"));
+        assert_eq!(entry("Liver").status, LabelMigrationStatus::Suggested);
+        assert_eq!(entry("liver").status, LabelMigrationStatus::Canonical);
+
+        let migrated = use_case
+            .apply_migration(project_id, ApplyLabelMigrationRequest { mappings: vec![], apply_suggestions: true }, admin_id)
+            .await
+            .unwrap();
+        assert_eq!(migrated.masks_updated, 1);
+        assert_eq!(migrated.annotations_updated, 1);
+        let legacy = annotation_service.get_annotation_by_id(legacy.id).await.unwrap();
+        assert_eq!(legacy.data["label"], "liver");
+        let history = annotation_service.get_annotation_history(legacy.id).await.unwrap();
+        assert!(history.iter().any(|h| h.data_before.as_ref().map(|d| d["label"] == "LIVER_SEG").unwrap_or(false)));
+
+        let preview = use_case.preview_migration(project_id, member_id).await.unwrap();
+        assert!(preview.entries.iter().all(|e| e.status == LabelMigrationStatus::Canonical));
+
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![admin_id, member_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod local_object_storage_tests {
+    use actix_web::{http::StatusCode, test, web, App};
+    use pacs_server::application::services::{CompletedPart, ObjectStorageService, ObjectStorageServiceFactory, SignedUrlOptions};
+    use pacs_server::infrastructure::external::LocalObjectStorageService;
+    use pacs_server::presentation::controllers::local_storage_controller::configure_routes;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn temp_root() -> PathBuf {
+        std::env::temp_dir().join(format!("pacs-local-storage-test-{}", Uuid::new_v4()))
+    }
+
+    #[actix_web::test]
+    async fn test_signed_urls_round_trip_through_server_routes() {
+        let root = temp_root();
+        // 공개 주소를 비우면 상대 경로 URL이 발급되어 테스트 서비스로 바로 요청할 수 있음
+        let storage = Arc::new(LocalObjectStorageService::new(&root, "", "integration-key").await.unwrap());
+        let app = test::init_service(
+            App::new().service(web::scope("/api").configure(|cfg| configure_routes(cfg, storage.clone()))),
+        )
+        .await;
+
+        let file_path = "annotations/7/liver mask.png";
+        let upload_url = storage
+            .generate_upload_url(
+                file_path,
+                SignedUrlOptions { ttl_seconds: 300, content_type: Some("image/png".to_string()), ..Default::default() },
+            )
+            .await
+            .unwrap();
+        assert!(upload_url.starts_with("/api/storage/objects/annotations/7/liver%20mask.png?"));
+
+        // Content-Type이 서명과 다르면 거부
+        let req = test::TestRequest::put()
+            .uri(&upload_url)
+            .insert_header(("Content-Type", "text/plain"))
+            .set_payload("nope")
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+
+        let req = test::TestRequest::put()
+            .uri(&upload_url)
+            .insert_header(("Content-Type", "image/png"))
+            .set_payload("png-bytes")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(resp.headers().contains_key("etag"));
+
+        let metadata = storage.get_file_metadata(file_path).await.unwrap();
+        assert_eq!(metadata.file_size, 9);
+        assert_eq!(metadata.mime_type.as_deref(), Some("image/png"));
+
+        // 업로드 URL로는 다운로드할 수 없음
+        let req = test::TestRequest::get().uri(&upload_url).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+
+        let download_url = storage.generate_download_url(file_path, 300).await.unwrap();
+        let req = test::TestRequest::get().uri(&download_url).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("content-type").unwrap(), "image/png");
+        assert_eq!(test::read_body(resp).await.as_ref(), b"png-bytes");
+
+        // 서명을 변조하면 거부
+        let tampered = download_url.replace("signature=", "signature=00");
+        let req = test::TestRequest::get().uri(&tampered).to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::FORBIDDEN);
+
+        let _ = tokio::fs::remove_dir_all(root).await;
+    }
+
+    #[actix_web::test]
+    async fn test_multipart_parts_uploaded_through_part_urls() {
+        let root = temp_root();
+        let storage = Arc::new(LocalObjectStorageService::new(&root, "", "integration-key").await.unwrap());
+        let app = test::init_service(
+            App::new().service(web::scope("/api").configure(|cfg| configure_routes(cfg, storage.clone()))),
+        )
+        .await;
+
+        let file_path = "annotations/7/mask_groups/3/volume.nii.gz";
+        let upload_id = storage.initiate_multipart_upload(file_path, Some("application/gzip")).await.unwrap();
+
+        let mut completed = Vec::new();
+        for (part_number, body) in [(1, "first-"), (2, "second")] {
+            let url = storage.generate_upload_part_url(file_path, &upload_id, part_number, 300).await.unwrap();
+            let req = test::TestRequest::put().uri(&url).set_payload(body).to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            let etag = resp.headers().get("etag").unwrap().to_str().unwrap().to_string();
+            completed.push(CompletedPart { part_number, etag });
+        }
+
+        let parts = storage.list_uploaded_parts(file_path, &upload_id).await.unwrap();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[1].size, 6);
+
+        storage.complete_multipart_upload(file_path, &upload_id, &completed).await.unwrap();
+        assert_eq!(storage.download_file(file_path).await.unwrap(), b"first-second");
+        assert_eq!(storage.list_files("annotations/7/", None).await.unwrap(), vec![file_path.to_string()]);
+
+        let _ = tokio::fs::remove_dir_all(root).await;
+    }
+
+    #[tokio::test]
+    async fn test_factory_creates_local_provider() {
+        let root = temp_root();
+        let storage = ObjectStorageServiceFactory::create(
+            "local",
+            root.to_str().unwrap(),
+            "",
+            "http://pacs.local:8080/",
+            "",
+            "factory-key",
+        )
+        .await
+        .unwrap();
+
+        let url = storage.generate_download_url("a/b.png", 60).await.unwrap();
+        assert!(url.starts_with("http://pacs.local:8080/api/storage/objects/a/b.png?expires="));
+
+        storage.upload_file("a/b.png", b"data".to_vec(), None).await.unwrap();
+        storage.move_file("a/b.png", "c/d.png").await.unwrap();
+        assert!(storage.file_exists("c/d.png").await.unwrap());
+        assert!(!storage.file_exists("a/b.png").await.unwrap());
+
+        let _ = tokio::fs::remove_dir_all(root).await;
+    }
+}
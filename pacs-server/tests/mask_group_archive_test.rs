@@ -0,0 +1,190 @@
+mod common;
+
+#[cfg(test)]
+mod mask_group_archive_tests {
+    use std::sync::Arc;
+    use futures::StreamExt;
+    use pacs_server::application::dto::mask_group_archive_dto::{MaskGroupArchiveManifest, MaskGroupArchiveQuery};
+    use pacs_server::application::services::ObjectStorageService;
+    use pacs_server::application::use_cases::MaskGroupArchiveUseCase;
+    use pacs_server::domain::entities::{NewAnnotation, NewMask};
+    use pacs_server::domain::repositories::{AnnotationRepository, MaskRepository};
+    use pacs_server::domain::services::MaskGroupServiceImpl;
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::archive::ZipStreamWriter;
+    use pacs_server::infrastructure::external::LocalObjectStorageService;
+    use pacs_server::infrastructure::repositories::{
+        AccessLogRepositoryImpl, AnnotationRepositoryImpl, MaskGroupRepositoryImpl, MaskRepositoryImpl, UserRepositoryImpl,
+    };
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user};
+
+    async fn access_log_results(pool: &PgPool, user_id: i32) -> Vec<String> {
+        sqlx::query_scalar(
+            "SELECT result FROM security_access_log
+             WHERE user_id = $1 AND action = 'MASK_GROUP_ARCHIVE_DOWNLOAD'
+             ORDER BY id"
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_streams_filtered_masks_with_manifest() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("archive_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = create_user(&pool, &format!("archive_owner_{}", suffix)).await;
+        let outsider_id = create_user(&pool, &format!("archive_outsider_{}", suffix)).await;
+
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(NewAnnotation {
+                project_id,
+                user_id: owner_id,
+                study_uid: "1.2.3.archive".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "polygon"}),
+                is_shared: false,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name, created_by) VALUES ($1, 'organs', $2) RETURNING id"
+        )
+        .bind(annotation.id)
+        .bind(owner_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let root = std::env::temp_dir().join(format!("pacs-archive-{}", suffix));
+        let storage = Arc::new(
+            LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+                .await
+                .unwrap(),
+        );
+
+        // 슬라이스 0~3의 liver 마스크와 슬라이스 1의 spleen 마스크, 슬라이스 2는 스토리지에 없음
+        let mask_repo = MaskRepositoryImpl::new(pool.clone());
+        let masks = [(0, "liver"), (1, "liver"), (2, "liver"), (3, "liver"), (1, "spleen")];
+        for (slice_index, label) in masks {
+            let file_path = format!(
+                "masks/annotation_{}/group_{}/{}_{:03}.png",
+                annotation.id, group_id, label, slice_index
+            );
+            if slice_index != 2 {
+                storage
+                    .upload_file(&file_path, format!("{}-{}", label, slice_index).into_bytes(), Some("image/png"))
+                    .await
+                    .unwrap();
+            }
+            mask_repo
+                .create(&NewMask {
+                    mask_group_id: group_id,
+                    slice_index: Some(slice_index),
+                    sop_instance_uid: None,
+                    label_name: Some(label.to_string()),
+                    file_path,
+                    mime_type: Some("image/png".to_string()),
+                    file_size: Some(7),
+                    checksum: None,
+                    width: None,
+                    height: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let use_case = MaskGroupArchiveUseCase::new(
+            Arc::new(MaskGroupServiceImpl::new(
+                Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+                Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+                Arc::new(UserRepositoryImpl::new(pool.clone())),
+            )),
+            Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+            Arc::new(AccessLogRepositoryImpl::new(pool.clone())),
+            storage.clone(),
+        );
+
+        let query = MaskGroupArchiveQuery {
+            slice_start: Some(1),
+            slice_end: Some(3),
+            labels: Some("liver".to_string()),
+        };
+        let archive = use_case.prepare_archive(annotation.id, group_id, query.clone(), owner_id).await.unwrap();
+        assert_eq!(archive.file_name, format!("mask_group_{}.zip", group_id));
+        assert_eq!(archive.mask_count, 3);
+
+        // 항목은 하나씩 읽히고 manifest가 마지막에 옴
+        let entries: Vec<_> = archive.entries.map(|entry| entry.unwrap()).collect().await;
+        let names: Vec<&str> = entries.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, vec!["masks/liver_001.png", "masks/liver_003.png", "manifest.json"]);
+        assert_eq!(entries[0].data, b"liver-1");
+
+        let manifest: MaskGroupArchiveManifest = serde_json::from_slice(&entries[2].data).unwrap();
+        assert_eq!(manifest.mask_group_id, group_id);
+        assert_eq!(manifest.masks.len(), 2);
+        assert_eq!(manifest.masks[1].archive_path.as_deref(), Some("masks/liver_003.png"));
+        assert_eq!(manifest.missing.len(), 1);
+        assert_eq!(manifest.missing[0].slice_index, Some(2));
+        assert_eq!(manifest.filter.labels.as_deref(), Some("liver"));
+
+        // ZIP 종료 레코드의 항목 수
+        let mut writer = ZipStreamWriter::new(chrono::Utc::now());
+        let mut zip = Vec::new();
+        for entry in &entries {
+            zip.extend(writer.add_entry(&entry.name, &entry.data).unwrap());
+        }
+        zip.extend(writer.finish().unwrap());
+        let end = zip.len() - 22;
+        assert_eq!(&zip[end..end + 4], b"PK\x05\x06");
+        assert_eq!(u16::from_le_bytes([zip[end + 10], zip[end + 11]]), 3);
+
+        // 권한이 없으면 거부하고 거부도 기록
+        assert!(matches!(
+            use_case.prepare_archive(annotation.id, group_id, query.clone(), outsider_id).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            use_case.prepare_archive(annotation.id + 1_000_000, group_id, query, owner_id).await,
+            Err(ServiceError::NotFound(_))
+        ));
+        let invalid = MaskGroupArchiveQuery { slice_start: Some(3), slice_end: Some(1), labels: None };
+        assert!(matches!(
+            use_case.prepare_archive(annotation.id, group_id, invalid, owner_id).await,
+            Err(ServiceError::ValidationError(_))
+        ));
+        assert_eq!(access_log_results(&pool, owner_id).await, vec!["SUCCESS".to_string()]);
+        assert_eq!(access_log_results(&pool, outsider_id).await, vec!["DENIED".to_string()]);
+
+        let _ = std::fs::remove_dir_all(&root);
+        sqlx::query("DELETE FROM security_access_log WHERE user_id = ANY($1)")
+            .bind(vec![owner_id, outsider_id])
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![owner_id, outsider_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
@@ -0,0 +1,188 @@
+mod common;
+
+#[cfg(test)]
+mod mask_group_batch_url_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::mask_group_dto::{BatchDownloadUrlRequest, BatchUploadFile, BatchUploadUrlRequest};
+    use pacs_server::application::services::SignedUrlServiceImpl;
+    use pacs_server::application::use_cases::MaskGroupUseCase;
+    use pacs_server::domain::entities::{NewAnnotation, NewMask};
+    use pacs_server::domain::repositories::{AnnotationRepository, MaskRepository};
+    use pacs_server::domain::services::MaskGroupServiceImpl;
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::external::LocalObjectStorageService;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MaskGroupRepositoryImpl, MaskRepositoryImpl, UserRepositoryImpl,
+    };
+    use crate::common::{setup_pool, create_user};
+
+    fn upload_file(filename: &str, slice_index: i32) -> BatchUploadFile {
+        BatchUploadFile {
+            filename: filename.to_string(),
+            mime_type: "image/png".to_string(),
+            file_size: Some(100),
+            slice_index: Some(slice_index),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_upload_and_download_urls() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("batch_url_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = create_user(&pool, &format!("batch_url_owner_{}", suffix)).await;
+        let outsider_id = create_user(&pool, &format!("batch_url_outsider_{}", suffix)).await;
+
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(NewAnnotation {
+                project_id,
+                user_id: owner_id,
+                study_uid: "1.2.3.batch".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "polygon"}),
+                is_shared: false,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name, created_by) VALUES ($1, 'liver', $2) RETURNING id"
+        )
+        .bind(annotation.id)
+        .bind(owner_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let root = std::env::temp_dir().join(format!("pacs-batch-url-{}", suffix));
+        let storage = LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+            .await
+            .unwrap();
+        let use_case = MaskGroupUseCase::new(
+            Arc::new(MaskGroupServiceImpl::new(
+                Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+                Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+                Arc::new(UserRepositoryImpl::new(pool.clone())),
+            )),
+            Arc::new(SignedUrlServiceImpl::new(Box::new(storage), 600, 3600)),
+        );
+
+        // 업로드 URL은 요청 순서대로 같은 TTL로 발급
+        let request = BatchUploadUrlRequest {
+            files: (0..3).map(|i| upload_file(&format!("slice_{:03}.png", i), i)).collect(),
+            ttl_seconds: Some(1200),
+        };
+        let uploads = use_case.generate_upload_urls(annotation.id, group_id, request.clone(), owner_id).await.unwrap();
+        assert_eq!(uploads.method, "PUT");
+        assert_eq!(uploads.expires_in, 1200);
+        assert_eq!(uploads.urls.len(), 3);
+        assert_eq!(uploads.urls[2].slice_index, Some(2));
+        assert_eq!(
+            uploads.urls[2].file_path,
+            format!("masks/annotation_{}/group_{}/slice_002.png", annotation.id, group_id)
+        );
+
+        // 권한은 그룹 단위로 확인하며 다른 어노테이션 경로로는 접근 불가
+        assert!(matches!(
+            use_case.generate_upload_urls(annotation.id, group_id, request.clone(), outsider_id).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            use_case.generate_upload_urls(annotation.id + 1_000_000, group_id, request.clone(), owner_id).await,
+            Err(ServiceError::NotFound(_))
+        ));
+
+        // TTL 한도와 파일명은 URL을 발급하기 전에 검증
+        let mut too_long = request.clone();
+        too_long.ttl_seconds = Some(7200);
+        assert!(matches!(
+            use_case.generate_upload_urls(annotation.id, group_id, too_long, owner_id).await,
+            Err(ServiceError::ValidationError(message)) if message.contains("TTL")
+        ));
+        let duplicated = BatchUploadUrlRequest {
+            files: vec![upload_file("a.png", 0), upload_file("a.png", 1)],
+            ttl_seconds: None,
+        };
+        assert!(matches!(
+            use_case.generate_upload_urls(annotation.id, group_id, duplicated, owner_id).await,
+            Err(ServiceError::ValidationError(_))
+        ));
+
+        let mask_repo = MaskRepositoryImpl::new(pool.clone());
+        for url in &uploads.urls {
+            mask_repo
+                .create(&NewMask {
+                    mask_group_id: group_id,
+                    slice_index: url.slice_index,
+                    sop_instance_uid: None,
+                    label_name: None,
+                    file_path: url.file_path.clone(),
+                    mime_type: Some("image/png".to_string()),
+                    file_size: Some(100),
+                    checksum: None,
+                    width: None,
+                    height: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        // 선택 조건이 없으면 그룹의 모든 마스크를 슬라이스 순서로 반환
+        let all = use_case
+            .generate_download_urls(annotation.id, group_id, BatchDownloadUrlRequest::default(), owner_id)
+            .await
+            .unwrap();
+        assert_eq!(all.method, "GET");
+        assert_eq!(all.expires_in, 600);
+        assert_eq!(all.urls.iter().map(|url| url.slice_index).collect::<Vec<_>>(), vec![Some(0), Some(1), Some(2)]);
+        assert!(all.urls.iter().all(|url| url.mask_id.is_some()));
+
+        // 슬라이스 인덱스/파일명으로 선택하고 없는 항목은 따로 알림
+        let selected = use_case
+            .generate_download_urls(
+                annotation.id,
+                group_id,
+                BatchDownloadUrlRequest {
+                    slice_indices: Some(vec![2, 7]),
+                    file_names: Some(vec!["slice_000.png".to_string(), "missing.png".to_string()]),
+                    ttl_seconds: Some(300),
+                },
+                owner_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(selected.urls.iter().map(|url| url.slice_index).collect::<Vec<_>>(), vec![Some(0), Some(2)]);
+        assert_eq!(selected.missing_slice_indices, vec![7]);
+        assert_eq!(selected.missing_file_names, vec!["missing.png".to_string()]);
+        assert_eq!(selected.expires_in, 300);
+
+        assert!(matches!(
+            use_case
+                .generate_download_urls(annotation.id, group_id, BatchDownloadUrlRequest::default(), outsider_id)
+                .await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+
+        let _ = std::fs::remove_dir_all(&root);
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![owner_id, outsider_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
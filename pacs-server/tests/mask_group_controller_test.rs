@@ -278,6 +278,7 @@ use sqlx::Row;
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Test description".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -312,6 +313,7 @@ use sqlx::Row;
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Test description".to_string()),
+            derived_from_id: None,
         };
 
         let create_req = test::TestRequest::post()
@@ -371,6 +373,7 @@ use sqlx::Row;
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Test description".to_string()),
+            derived_from_id: None,
         };
 
         let create_req = test::TestRequest::post()
@@ -424,6 +427,7 @@ use sqlx::Row;
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Test description".to_string()),
+            derived_from_id: None,
         };
 
         let create_req = test::TestRequest::post()
@@ -466,6 +470,7 @@ use sqlx::Row;
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Test description".to_string()),
+            derived_from_id: None,
         };
 
         let create_req = test::TestRequest::post()
@@ -504,6 +509,7 @@ use sqlx::Row;
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Test description".to_string()),
+            derived_from_id: None,
         };
 
         let create_req = test::TestRequest::post()
@@ -557,6 +563,7 @@ use sqlx::Row;
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Test description".to_string()),
+            derived_from_id: None,
         };
 
         let create_req = test::TestRequest::post()
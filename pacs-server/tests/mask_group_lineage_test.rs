@@ -0,0 +1,242 @@
+mod common;
+
+#[cfg(test)]
+mod mask_group_lineage_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::mask_group_lineage_dto::{MaskGroupComparisonQuery, SetDerivedFromRequest};
+    use pacs_server::application::services::ObjectStorageService;
+    use pacs_server::application::use_cases::MaskGroupLineageUseCase;
+    use pacs_server::domain::entities::{NewAnnotation, NewMask, NewMaskGroup};
+    use pacs_server::domain::repositories::{AnnotationRepository, MaskRepository};
+    use pacs_server::domain::services::{MaskGroupService, MaskGroupServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::external::LocalObjectStorageService;
+    use pacs_server::infrastructure::imaging::encode_binary_mask_png;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MaskGroupRepositoryImpl, MaskRepositoryImpl, UserRepositoryImpl,
+    };
+    use crate::common::{setup_pool, create_user};
+
+    fn new_annotation(project_id: i32, user_id: i32) -> NewAnnotation {
+        NewAnnotation {
+            project_id,
+            user_id,
+            study_uid: "1.2.3.lineage".to_string(),
+            series_uid: None,
+            instance_uid: None,
+            tool_name: "Brush Tool".to_string(),
+            tool_version: None,
+            data: serde_json::json!({"type": "mask"}),
+            is_shared: false,
+            viewer_software: None,
+            description: None,
+            measurement_values: None,
+        }
+    }
+
+    /// 4x4 마스크에서 주어진 위치만 전경
+    fn mask_png(foreground: &[usize]) -> Vec<u8> {
+        let mut pixels = vec![0u8; 16];
+        for index in foreground {
+            pixels[*index] = 1;
+        }
+        encode_binary_mask_png(4, 4, &pixels).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_lineage_and_comparison() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("lineage_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = create_user(&pool, &format!("lineage_owner_{}", suffix)).await;
+        let outsider_id = create_user(&pool, &format!("lineage_outsider_{}", suffix)).await;
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let annotation = annotation_repo.create(new_annotation(project_id, owner_id)).await.unwrap();
+        let other_annotation = annotation_repo.create(new_annotation(project_id, owner_id)).await.unwrap();
+
+        let mask_group_service = Arc::new(MaskGroupServiceImpl::new(
+            Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+            Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+            Arc::new(UserRepositoryImpl::new(pool.clone())),
+        ));
+        let ai_group = mask_group_service
+            .create_mask_group(&NewMaskGroup::with_ai_model(
+                annotation.id,
+                "liver_ai".to_string(),
+                "UNet".to_string(),
+                "2.1.0".to_string(),
+                "CT".to_string(),
+                3,
+                Some(owner_id),
+            ))
+            .await
+            .unwrap();
+        let corrected_group = mask_group_service
+            .create_mask_group(
+                &NewMaskGroup::manual(annotation.id, "liver_corrected".to_string(), "CT".to_string(), 3, None, Some(owner_id))
+                    .with_derived_from(ai_group.id),
+            )
+            .await
+            .unwrap();
+        assert_eq!(corrected_group.derived_from_id, Some(ai_group.id));
+        let other_group = mask_group_service
+            .create_mask_group(&NewMaskGroup::with_defaults(other_annotation.id, Some(owner_id)))
+            .await
+            .unwrap();
+
+        // 다른 어노테이션의 그룹에서는 파생할 수 없음
+        assert!(matches!(
+            mask_group_service
+                .create_mask_group(&NewMaskGroup::with_defaults(annotation.id, Some(owner_id)).with_derived_from(other_group.id))
+                .await,
+            Err(ServiceError::ValidationError(_))
+        ));
+
+        let root = std::env::temp_dir().join(format!("pacs-lineage-{}", suffix));
+        let storage = Arc::new(
+            LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+                .await
+                .unwrap(),
+        );
+
+        // (그룹, 슬라이스, 라벨, 전경 위치, 업로드 여부)
+        let masks = [
+            (ai_group.id, 0, "liver", vec![0, 1], true),
+            (ai_group.id, 0, "spleen", vec![4], false),
+            (ai_group.id, 1, "liver", vec![5], true),
+            (corrected_group.id, 0, "liver", vec![1, 2, 15], true),
+            (corrected_group.id, 0, "tumor", vec![5], true),
+            (corrected_group.id, 2, "liver", vec![5], true),
+        ];
+        let mask_repo = MaskRepositoryImpl::new(pool.clone());
+        for (index, (group_id, slice_index, label, foreground, uploaded)) in masks.iter().enumerate() {
+            let file_path = format!("masks/annotation_{}/group_{}/{}_{}.png", annotation.id, group_id, label, index);
+            if *uploaded {
+                storage.upload_file(&file_path, mask_png(foreground), Some("image/png")).await.unwrap();
+            }
+            mask_repo
+                .create(&NewMask {
+                    mask_group_id: *group_id,
+                    slice_index: Some(*slice_index),
+                    sop_instance_uid: None,
+                    label_name: Some(label.to_string()),
+                    file_path,
+                    mime_type: Some("image/png".to_string()),
+                    file_size: None,
+                    checksum: None,
+                    width: Some(4),
+                    height: Some(4),
+                })
+                .await
+                .unwrap();
+        }
+
+        let use_case = MaskGroupLineageUseCase::new(mask_group_service.clone(), storage.clone());
+
+        let lineage = use_case.get_lineage(annotation.id, owner_id).await.unwrap();
+        let summary: Vec<(i32, u32, i64)> = lineage.groups.iter().map(|group| (group.id, group.depth, group.mask_count)).collect();
+        assert_eq!(summary, vec![(ai_group.id, 0, 3), (corrected_group.id, 1, 3)]);
+        assert_eq!(lineage.groups[0].derived_ids, vec![corrected_group.id]);
+        assert!(matches!(
+            use_case.get_lineage(annotation.id, outsider_id).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+
+        // 파생 원본을 생략하면 AI 결과와 비교
+        let comparison = use_case
+            .compare(annotation.id, corrected_group.id, owner_id, MaskGroupComparisonQuery::default())
+            .await
+            .unwrap();
+        assert_eq!(comparison.reference_id, ai_group.id);
+        assert_eq!(comparison.reference_model_name.as_deref(), Some("UNet"));
+        assert_eq!(
+            (comparison.matched_slices, comparison.reference_only_slices, comparison.candidate_only_slices),
+            (1, 1, 1)
+        );
+        assert_eq!(comparison.warnings.len(), 1);
+        assert!(comparison.warnings[0].contains("not found"));
+
+        assert_eq!(comparison.labels.len(), 2);
+        let liver = &comparison.labels[0];
+        assert_eq!(liver.label_name.as_deref(), Some("liver"));
+        assert_eq!((liver.reference_voxels, liver.candidate_voxels, liver.intersection_voxels), (2, 3, 1));
+        assert_eq!(liver.dice, Some(0.4));
+        assert_eq!(liver.jaccard, Some(0.25));
+        assert_eq!(liver.volume_difference_voxels, 1);
+        assert_eq!(liver.relative_volume_difference, Some(0.5));
+        // (3,3)에서 가장 가까운 기준 픽셀 (1,0)까지
+        assert_eq!(liver.hausdorff_distance_pixels, Some(13f64.sqrt()));
+        assert_eq!((liver.compared_slices, liver.unmatched_slices), (1, 0));
+        let tumor = &comparison.labels[1];
+        assert_eq!((tumor.dice, tumor.jaccard), (Some(0.0), Some(0.0)));
+        assert_eq!((tumor.relative_volume_difference, tumor.hausdorff_distance_pixels), (None, None));
+        // 비교 대상에만 있는 라벨은 Hausdorff 거리 없이 unmatched로 보고
+        assert_eq!((tumor.compared_slices, tumor.unmatched_slices), (1, 1));
+
+        let filtered = use_case
+            .compare(
+                annotation.id,
+                corrected_group.id,
+                owner_id,
+                MaskGroupComparisonQuery { reference_id: None, labels: Some("liver, ".to_string()) },
+            )
+            .await
+            .unwrap();
+        assert_eq!(filtered.labels.len(), 1);
+        assert!(filtered.warnings.is_empty());
+
+        assert!(matches!(
+            use_case.compare(annotation.id, ai_group.id, owner_id, MaskGroupComparisonQuery::default()).await,
+            Err(ServiceError::ValidationError(_))
+        ));
+        assert!(matches!(
+            use_case.compare(annotation.id, corrected_group.id, outsider_id, MaskGroupComparisonQuery::default()).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+
+        // 순환, 자기 자신, 다른 어노테이션은 거부
+        for (group_id, derived_from_id) in [(ai_group.id, corrected_group.id), (ai_group.id, ai_group.id)] {
+            assert!(matches!(
+                use_case
+                    .set_derived_from(annotation.id, group_id, owner_id, SetDerivedFromRequest { derived_from_id: Some(derived_from_id) })
+                    .await,
+                Err(ServiceError::ValidationError(_))
+            ));
+        }
+        assert!(matches!(
+            use_case
+                .set_derived_from(annotation.id, ai_group.id, owner_id, SetDerivedFromRequest { derived_from_id: Some(other_group.id) })
+                .await,
+            Err(ServiceError::NotFound(_))
+        ));
+
+        let cleared = use_case
+            .set_derived_from(annotation.id, corrected_group.id, owner_id, SetDerivedFromRequest { derived_from_id: None })
+            .await
+            .unwrap();
+        assert_eq!(cleared.derived_from_id, None);
+        let reversed = use_case
+            .set_derived_from(annotation.id, ai_group.id, owner_id, SetDerivedFromRequest { derived_from_id: Some(corrected_group.id) })
+            .await
+            .unwrap();
+        assert_eq!(reversed.derived_from_id, Some(corrected_group.id));
+
+        let _ = std::fs::remove_dir_all(&root);
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![owner_id, outsider_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
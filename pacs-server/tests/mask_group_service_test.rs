@@ -50,6 +50,7 @@ fn create_test_mask_group() -> MaskGroup {
         mask_type: Some("segmentation".to_string()),
         description: Some("Test description".to_string()),
         created_by: Some(101),
+        derived_from_id: None,
         created_at: DateTime::from_naive_utc_and_offset(
             NaiveDateTime::from_timestamp_opt(1640995200, 0).unwrap(),
             Utc,
@@ -8,7 +8,7 @@ use pacs_server::application::dto::mask_group_dto::{
     CreateMaskGroupRequest, UpdateMaskGroupRequest, SignedUrlRequest, 
     CompleteUploadRequest
 };
-use pacs_server::domain::entities::{MaskGroup, NewMaskGroup, UpdateMaskGroup, MaskGroupStats, Mask};
+use pacs_server::domain::entities::{MaskGroup, NewMaskGroup, UpdateMaskGroup, MaskGroupStats, Mask, MaskGroupLineageNode};
 use pacs_server::domain::services::MaskGroupService;
 use pacs_server::domain::ServiceError;
 use pacs_server::application::services::{SignedUrlService, SignedUrlError};
@@ -21,8 +21,8 @@ mock! {
     impl MaskGroupService for MaskGroupService {
         async fn create_mask_group(&self, new_mask_group: &NewMaskGroup) -> Result<MaskGroup, ServiceError>;
         async fn get_mask_group_by_id(&self, id: i32) -> Result<Option<MaskGroup>, ServiceError>;
-        async fn update_mask_group(&self, id: i32, update_mask_group: &UpdateMaskGroup) -> Result<MaskGroup, ServiceError>;
-        async fn delete_mask_group(&self, id: i32) -> Result<(), ServiceError>;
+        async fn update_mask_group(&self, id: i32, update_mask_group: &UpdateMaskGroup, editor_id: i32) -> Result<MaskGroup, ServiceError>;
+        async fn delete_mask_group(&self, id: i32, deleted_by: i32) -> Result<(), ServiceError>;
         async fn list_mask_groups(
             &self,
             annotation_id: Option<i32>,
@@ -43,6 +43,10 @@ mock! {
         ) -> Result<i64, ServiceError>;
         async fn can_access_mask_group(&self, user_id: i32, mask_group_id: i32) -> Result<bool, ServiceError>;
         async fn can_create_mask_group(&self, user_id: i32, annotation_id: i32) -> Result<bool, ServiceError>;
+        async fn complete_upload(&self, mask_group_id: i32, completed_by: i32) -> Result<(), ServiceError>;
+        async fn check_storage_quota(&self, mask_group_id: i32, upload_bytes: Option<i64>) -> Result<Option<String>, ServiceError>;
+        async fn get_mask_group_lineage(&self, annotation_id: i32) -> Result<Vec<MaskGroupLineageNode>, ServiceError>;
+        async fn set_mask_group_derived_from(&self, id: i32, derived_from_id: Option<i32>, editor_id: i32) -> Result<MaskGroup, ServiceError>;
     }
 }
 
@@ -103,6 +107,7 @@ fn create_test_mask_group() -> MaskGroup {
         mask_type: Some("segmentation".to_string()),
         description: Some("Test description".to_string()),
         created_by: Some(1),
+        derived_from_id: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     }
@@ -150,6 +155,7 @@ async fn test_mask_group_use_case_create_mask_group_success() {
         slice_count: 100,
         mask_type: "segmentation".to_string(),
         description: Some("Test description".to_string()),
+        derived_from_id: None,
     };
 
     let result = mask_group_use_case.create_mask_group(request.clone(), 1).await;
@@ -271,7 +277,7 @@ async fn test_mask_group_use_case_update_mask_group_success() {
     mock_mask_group_service
         .expect_update_mask_group()
         .times(1)
-        .returning(|_, _| {
+        .returning(|_, _, _| {
             let mut mask_group = create_test_mask_group();
             mask_group.group_name = Some("Updated Group".to_string());
             mask_group.slice_count = Some(150);
@@ -317,9 +323,9 @@ async fn test_mask_group_use_case_delete_mask_group_success() {
     
     mock_mask_group_service
         .expect_delete_mask_group()
-        .with(mockall::predicate::eq(1))
+        .with(mockall::predicate::eq(1), mockall::predicate::eq(1))
         .times(1)
-        .returning(|_| Ok(()));
+        .returning(|_, _| Ok(()));
     
     let mask_group_use_case = MaskGroupUseCase::new(
         Arc::new(mock_mask_group_service),
@@ -389,6 +395,12 @@ async fn test_mask_group_use_case_complete_upload_success() {
         .with(mockall::predicate::eq(1), mockall::predicate::eq(1))
         .times(1)
         .returning(|_, _| Ok(true));
+
+    mock_mask_group_service
+        .expect_complete_upload()
+        .with(mockall::predicate::eq(1), mockall::predicate::eq(1))
+        .times(1)
+        .returning(|_, _| Ok(()));
     
     let mask_group_use_case = MaskGroupUseCase::new(
         Arc::new(mock_mask_group_service),
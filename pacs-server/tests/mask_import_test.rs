@@ -0,0 +1,253 @@
+mod common;
+
+#[cfg(test)]
+mod mask_import_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::mask_import_dto::{CreateMaskImportRequest, MaskImportUploadUrlRequest};
+    use pacs_server::application::services::{ObjectStorageService, SignedUrlServiceImpl};
+    use pacs_server::application::use_cases::MaskImportUseCase;
+    use pacs_server::domain::entities::{MaskImportStatus, NewAnnotation, NewMaskImportJob};
+    use pacs_server::domain::repositories::{AnnotationRepository, MaskImportRepository};
+    use pacs_server::domain::services::{MaskGroupServiceImpl, MaskServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::external::LocalObjectStorageService;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MaskGroupRepositoryImpl, MaskImportRepositoryImpl, MaskRepositoryImpl,
+        UserRepositoryImpl,
+    };
+    use crate::common::{setup_pool, create_user};
+
+    fn new_annotation(project_id: i32, user_id: i32) -> NewAnnotation {
+        NewAnnotation {
+            project_id,
+            user_id,
+            study_uid: "1.2.3.import".to_string(),
+            series_uid: None,
+            instance_uid: None,
+            tool_name: "Brush Tool".to_string(),
+            tool_version: None,
+            data: serde_json::json!({"type": "mask"}),
+            is_shared: false,
+            viewer_software: None,
+            description: None,
+            measurement_values: None,
+        }
+    }
+
+    /// 테스트용 UINT8 NIfTI-1 볼륨
+    fn build_nifti_u8(nx: i16, ny: i16, nz: i16, voxels: &[u8]) -> Vec<u8> {
+        let mut header = vec![0u8; 348];
+        header[0..4].copy_from_slice(&348i32.to_le_bytes());
+        let dims: [i16; 8] = [3, nx, ny, nz, 1, 1, 1, 1];
+        for (i, d) in dims.iter().enumerate() {
+            header[40 + i * 2..42 + i * 2].copy_from_slice(&d.to_le_bytes());
+        }
+        header[70..72].copy_from_slice(&2i16.to_le_bytes());
+        header[72..74].copy_from_slice(&8i16.to_le_bytes());
+        header[108..112].copy_from_slice(&352f32.to_le_bytes());
+        header[344..348].copy_from_slice(b"n+1\0");
+
+        let mut out = header;
+        out.extend_from_slice(&[0u8; 4]);
+        out.extend_from_slice(voxels);
+        out
+    }
+
+    fn import_request(source_path: String) -> CreateMaskImportRequest {
+        CreateMaskImportRequest {
+            source_path,
+            format: None,
+            group_name: Some("imported".to_string()),
+            model_name: None,
+            version: None,
+            modality: Some("CT".to_string()),
+            mask_type: None,
+            description: None,
+            instance_uids: None,
+            label_names: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_requires_issued_source_path() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("import_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = create_user(&pool, &format!("import_owner_{}", suffix)).await;
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let annotation = annotation_repo.create(new_annotation(project_id, owner_id)).await.unwrap();
+        let other_annotation = annotation_repo.create(new_annotation(project_id, owner_id)).await.unwrap();
+
+        let root = std::env::temp_dir().join(format!("pacs-import-{}", suffix));
+        let storage = Arc::new(
+            LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+                .await
+                .unwrap(),
+        );
+        let use_case = MaskImportUseCase::new(
+            Arc::new(MaskGroupServiceImpl::new(
+                Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+                Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+                Arc::new(UserRepositoryImpl::new(pool.clone())),
+            )),
+            Arc::new(MaskServiceImpl::new(
+                Arc::new(MaskRepositoryImpl::new(pool.clone())),
+                Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+                Arc::new(UserRepositoryImpl::new(pool.clone())),
+            )),
+            Arc::new(SignedUrlServiceImpl::new(Box::new(storage.clone()), 600, 3600)),
+            Arc::new(MaskImportRepositoryImpl::new(pool.clone())),
+            storage.clone(),
+        );
+
+        let upload = use_case
+            .generate_source_upload_url(
+                annotation.id,
+                MaskImportUploadUrlRequest { filename: "seg.nii".to_string(), ttl_seconds: None },
+                owner_id,
+            )
+            .await
+            .unwrap();
+        assert!(upload.source_path.starts_with(&format!("imports/annotation_{}/", annotation.id)));
+        let volume = build_nifti_u8(2, 2, 1, &[1, 0, 0, 1]);
+        storage.upload_file(&upload.source_path, volume.clone(), None).await.unwrap();
+
+        // 다른 어노테이션의 업로드 경로나 마스크 객체는 존재하더라도 가져올 수 없음
+        let foreign_upload = format!("imports/annotation_{}/other.nii", other_annotation.id);
+        let foreign_mask = format!("masks/annotation_{}/group_1/seg.nii", other_annotation.id);
+        for path in [&foreign_upload, &foreign_mask] {
+            storage.upload_file(path, volume.clone(), None).await.unwrap();
+        }
+        let rejected = [
+            foreign_upload,
+            foreign_mask,
+            format!("imports/annotation_{}/../annotation_{}/other.nii", annotation.id, other_annotation.id),
+            format!("imports/annotation_{}/", annotation.id),
+        ];
+        for path in rejected {
+            assert!(
+                matches!(
+                    use_case.start_import(annotation.id, import_request(path.clone()), owner_id).await,
+                    Err(ServiceError::ValidationError(_))
+                ),
+                "{}",
+                path
+            );
+        }
+        let job_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM annotation_mask_import_job WHERE annotation_id = ANY($1)")
+            .bind(vec![annotation.id, other_annotation.id])
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(job_count, 0);
+
+        // 발급받은 경로는 임포트됨
+        let job = use_case
+            .start_import(annotation.id, import_request(upload.source_path.clone()), owner_id)
+            .await
+            .unwrap();
+        let mut status = job.status;
+        for _ in 0..50 {
+            if matches!(status, MaskImportStatus::Completed | MaskImportStatus::Failed) {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            status = use_case.get_import_job(annotation.id, job.id, owner_id).await.unwrap().status;
+        }
+        let job = use_case.get_import_job(annotation.id, job.id, owner_id).await.unwrap();
+        assert_eq!(job.status, MaskImportStatus::Completed, "{:?}", job.error_message);
+        assert_eq!(job.imported_masks, 1);
+
+        let _ = std::fs::remove_dir_all(&root);
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(owner_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+
+    #[tokio::test]
+    async fn test_failed_import_removes_created_mask_group() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("import_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = create_user(&pool, &format!("import_owner_{}", suffix)).await;
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(new_annotation(project_id, owner_id))
+            .await
+            .unwrap();
+
+        let repo = MaskImportRepositoryImpl::new(pool.clone());
+        let job = repo
+            .create(&NewMaskImportJob::new(
+                annotation.id,
+                format!("imports/annotation_{}/seg.nii", annotation.id),
+                "nifti".to_string(),
+                Some(owner_id),
+            ))
+            .await
+            .unwrap();
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'imported') RETURNING id"
+        )
+        .bind(annotation.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO annotation_mask (mask_group_id, slice_index, file_path) VALUES ($1, 0, $2)")
+            .bind(group_id)
+            .bind(format!("masks/annotation_{}/group_{}/slice_0000_label_1.png", annotation.id, group_id))
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        repo.mark_failed(job.id, Some(group_id), "upload failed").await.unwrap();
+
+        let job = repo.get_by_id(job.id).await.unwrap().unwrap();
+        assert_eq!(job.status, MaskImportStatus::Failed);
+        assert_eq!(job.mask_group_id, None);
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM annotation_mask_group WHERE id = $1")
+            .bind(group_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+
+        // 업로드된 슬라이스 객체는 그룹 경로 단위로 삭제 예약됨
+        let prefix = format!("masks/annotation_{}/group_{}/", annotation.id, group_id);
+        let queued: Vec<(bool, String)> = sqlx::query_as(
+            "DELETE FROM storage_deletion_queue WHERE object_key = $1 RETURNING is_prefix, reason"
+        )
+        .bind(&prefix)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(queued, vec![(true, "MASK_IMPORT_FAILED".to_string())]);
+
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(owner_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
@@ -0,0 +1,229 @@
+mod common;
+
+#[cfg(test)]
+mod mask_preview_tests {
+    use std::sync::Arc;
+    use pacs_server::application::services::{ObjectStorageService, SignedUrlServiceImpl};
+    use pacs_server::application::use_cases::{MaskPreviewUseCase, MaskUseCase};
+    use pacs_server::domain::entities::{
+        mask_preview_key, mask_thumbnail_key, MaskPreviewStatus, NewAnnotation, NewMask, UpdateMask,
+        MASK_PREVIEW_RETRY_POLICY,
+    };
+    use pacs_server::domain::repositories::{AnnotationRepository, MaskRepository};
+    use pacs_server::domain::services::{MaskGroupServiceImpl, MaskServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::external::LocalObjectStorageService;
+    use pacs_server::infrastructure::imaging::{decode_label_mask_png, encode_binary_mask_png};
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MaskGroupRepositoryImpl, MaskPreviewRepositoryImpl, MaskRepositoryImpl,
+        UserRepositoryImpl,
+    };
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user};
+
+    /// 미리보기 또는 contact sheet가 아직 `PENDING`인지
+    async fn has_pending_work(pool: &PgPool, mask_group_id: i32) -> bool {
+        sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM annotation_mask WHERE mask_group_id = $1 AND preview_status = 'PENDING')
+                 OR EXISTS (SELECT 1 FROM annotation_mask_group WHERE id = $1 AND contact_sheet_status = 'PENDING')"
+        )
+        .bind(mask_group_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    async fn queued_keys(pool: &PgPool, prefix: &str) -> Vec<String> {
+        sqlx::query_scalar(
+            "SELECT object_key FROM storage_deletion_queue WHERE object_key LIKE $1 || '%' ORDER BY object_key"
+        )
+        .bind(prefix)
+        .fetch_all(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_generates_previews_and_contact_sheet() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("preview_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = create_user(&pool, &format!("preview_owner_{}", suffix)).await;
+        let outsider_id = create_user(&pool, &format!("preview_outsider_{}", suffix)).await;
+
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(NewAnnotation {
+                project_id,
+                user_id: owner_id,
+                study_uid: "1.2.3.preview".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Brush Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "mask"}),
+                is_shared: false,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name, created_by) VALUES ($1, 'liver', $2) RETURNING id"
+        )
+        .bind(annotation.id)
+        .bind(owner_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let root = std::env::temp_dir().join(format!("pacs-preview-{}", suffix));
+        let storage = Arc::new(
+            LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+                .await
+                .unwrap(),
+        );
+
+        // 두 장은 정상 PNG, 한 장은 검증을 통과했다고 가정한 손상된 객체
+        let png = encode_binary_mask_png(3, 2, &[0, 1, 0, 0, 1, 1]).unwrap();
+        let uploads = [(0, png.clone()), (1, png.clone()), (2, b"not a png".to_vec())];
+        let mask_repo = MaskRepositoryImpl::new(pool.clone());
+        let mut masks = Vec::new();
+        for (slice_index, data) in uploads {
+            let file_path = format!("masks/annotation_{}/group_{}/liver_{:03}.png", annotation.id, group_id, slice_index);
+            storage.upload_file(&file_path, data, Some("image/png")).await.unwrap();
+            let mask = mask_repo
+                .create(&NewMask {
+                    mask_group_id: group_id,
+                    slice_index: Some(slice_index),
+                    sop_instance_uid: None,
+                    label_name: Some("liver".to_string()),
+                    file_path,
+                    mime_type: Some("image/png".to_string()),
+                    file_size: None,
+                    checksum: None,
+                    width: Some(3),
+                    height: Some(2),
+                })
+                .await
+                .unwrap();
+            masks.push(mask);
+        }
+        sqlx::query("UPDATE annotation_mask SET validation_status = 'VALID' WHERE mask_group_id = $1")
+            .bind(group_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mask_group_service = Arc::new(MaskGroupServiceImpl::new(
+            Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+            Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+            Arc::new(UserRepositoryImpl::new(pool.clone())),
+        ));
+        let signed_url_service = Arc::new(SignedUrlServiceImpl::new(Box::new(storage.clone()), 600, 3600));
+        let use_case = MaskPreviewUseCase::new(
+            Arc::new(MaskPreviewRepositoryImpl::new(pool.clone())),
+            mask_group_service.clone(),
+            signed_url_service.clone(),
+            storage.clone(),
+            MASK_PREVIEW_RETRY_POLICY,
+        );
+
+        // 다른 테스트가 남긴 대기 중인 작업이 먼저 처리될 수 있으므로 그룹이 끝날 때까지 반복
+        for _ in 0..20 {
+            if !has_pending_work(&pool, group_id).await {
+                break;
+            }
+            use_case.process_pending().await.unwrap();
+        }
+
+        // 정상 마스크는 썸네일/미리보기를 derived/ 아래에 저장
+        let thumbnail = storage.download_file(&mask_thumbnail_key(&masks[0].file_path)).await.unwrap();
+        assert_eq!(decode_label_mask_png(&thumbnail).unwrap(), (3, 2, vec![0, 255, 0, 0, 255, 255]));
+        assert!(storage.file_exists(&mask_preview_key(&masks[1].file_path)).await.unwrap());
+
+        let sheet = use_case.get_contact_sheet(annotation.id, group_id, owner_id, Some(120)).await.unwrap();
+        assert_eq!(sheet.status, MaskPreviewStatus::Ready);
+        assert_eq!(sheet.mask_count, 2);
+        assert_eq!(sheet.expires_in, Some(120));
+        assert!(sheet.url.is_some());
+
+        // MaskResponse에는 생성된 미리보기만 URL로 노출
+        let mask_use_case = MaskUseCase::new(
+            Arc::new(MaskServiceImpl::new(
+                Arc::new(MaskRepositoryImpl::new(pool.clone())),
+                Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+                Arc::new(UserRepositoryImpl::new(pool.clone())),
+            )),
+            mask_group_service.clone(),
+            signed_url_service.clone(),
+        );
+        let listed = mask_use_case.list_masks(Some(group_id), owner_id, None, None).await.unwrap();
+        assert_eq!(listed.masks.len(), 3);
+        for response in &listed.masks {
+            let ready = response.id != masks[2].id;
+            assert_eq!(response.thumbnail_url.is_some(), ready);
+            assert_eq!(response.preview_url.is_some(), ready);
+        }
+        let failure: (MaskPreviewStatus, Option<String>) = sqlx::query_as(
+            "SELECT preview_status, preview_error FROM annotation_mask WHERE id = $1"
+        )
+        .bind(masks[2].id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(failure.0, MaskPreviewStatus::Failed);
+        assert!(failure.1.is_some());
+
+        // 파일 경로가 바뀌면 다시 생성하고 이전 경로의 파생 객체는 삭제 예약
+        let derived_prefix = format!("derived/masks/annotation_{}/", annotation.id);
+        let mut update = UpdateMask::new(masks[1].id);
+        update.file_path = Some(format!("masks/annotation_{}/group_{}/liver_001_v2.png", annotation.id, group_id));
+        mask_repo.update(masks[1].id, &update).await.unwrap();
+        assert!(has_pending_work(&pool, group_id).await);
+        assert_eq!(
+            queued_keys(&pool, &derived_prefix).await,
+            vec![mask_preview_key(&masks[1].file_path), mask_thumbnail_key(&masks[1].file_path)]
+        );
+
+        // 다시 만드는 동안에도 이전 contact sheet는 내려받을 수 있음
+        let sheet = use_case.get_contact_sheet(annotation.id, group_id, owner_id, None).await.unwrap();
+        assert_eq!(sheet.status, MaskPreviewStatus::Pending);
+        assert!(sheet.url.is_some());
+
+        // 마스크를 삭제하면 파생 객체도 삭제 예약
+        mask_repo.delete(masks[0].id).await.unwrap();
+        assert_eq!(queued_keys(&pool, &derived_prefix).await.len(), 4);
+
+        assert!(matches!(
+            use_case.get_contact_sheet(annotation.id, group_id, outsider_id, None).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            use_case.get_contact_sheet(annotation.id + 1_000_000, group_id, owner_id, None).await,
+            Err(ServiceError::NotFound(_))
+        ));
+
+        let _ = std::fs::remove_dir_all(&root);
+        sqlx::query("DELETE FROM storage_deletion_queue WHERE object_key LIKE $1 || '%'")
+            .bind(&derived_prefix)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![owner_id, outsider_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
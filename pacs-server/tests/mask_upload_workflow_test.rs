@@ -309,6 +309,7 @@ mod mask_upload_workflow_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("간 분할을 위한 AI 모델 결과".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -492,6 +493,7 @@ mod mask_upload_workflow_tests {
                 slice_count: 50,
                 mask_type: mask_type.to_string(),
                 description: Some(format!("{}을 위한 AI 모델 결과", group_name)),
+                derived_from_id: None,
             };
 
             let req = test::TestRequest::post()
@@ -537,6 +539,7 @@ mod mask_upload_workflow_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Test description".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use pacs_server::application::use_cases::MaskUseCase;
 use pacs_server::domain::services::{MaskService, MaskGroupService};
 use pacs_server::domain::ServiceError;
-use pacs_server::domain::entities::{Mask, MaskGroup, NewMask, UpdateMask, NewMaskGroup, UpdateMaskGroup, MaskStats, MaskGroupStats};
+use pacs_server::domain::entities::{Mask, MaskGroup, NewMask, UpdateMask, NewMaskGroup, UpdateMaskGroup, MaskStats, MaskGroupStats, MaskGroupLineageNode, MaskPreview};
 use pacs_server::application::dto::mask_dto::{
     CreateMaskRequest, UpdateMaskRequest, DownloadUrlRequest
 };
@@ -143,6 +143,10 @@ impl MaskService for MockMaskService {
     async fn can_create_mask(&self, _user_id: i32, _mask_group_id: i32) -> Result<bool, ServiceError> {
         Ok(true)
     }
+
+    async fn get_mask_previews(&self, _mask_ids: &[i32]) -> Result<Vec<MaskPreview>, ServiceError> {
+        Ok(Vec::new())
+    }
 }
 
 // Mock MaskGroupService for testing
@@ -177,6 +181,7 @@ impl MaskGroupService for MockMaskGroupService {
             mask_type: Some("segmentation".to_string()),
             description: Some("Test description".to_string()),
             created_by: Some(1),
+            derived_from_id: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -187,7 +192,7 @@ impl MaskGroupService for MockMaskGroupService {
         Ok(self.mask_groups.get(&id).cloned())
     }
 
-    async fn update_mask_group(&self, _id: i32, _update_mask_group: &UpdateMaskGroup) -> Result<MaskGroup, ServiceError> {
+    async fn update_mask_group(&self, _id: i32, _update_mask_group: &UpdateMaskGroup, _editor_id: i32) -> Result<MaskGroup, ServiceError> {
         let mask_group = MaskGroup {
             id: 1,
             annotation_id: 1,
@@ -199,13 +204,14 @@ impl MaskGroupService for MockMaskGroupService {
             mask_type: Some("bounding_box".to_string()),
             description: Some("Updated description".to_string()),
             created_by: Some(1),
+            derived_from_id: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
         Ok(mask_group)
     }
 
-    async fn delete_mask_group(&self, id: i32) -> Result<(), ServiceError> {
+    async fn delete_mask_group(&self, id: i32, _deleted_by: i32) -> Result<(), ServiceError> {
         if self.mask_groups.contains_key(&id) {
             Ok(())
         } else {
@@ -253,6 +259,22 @@ impl MaskGroupService for MockMaskGroupService {
     async fn can_create_mask_group(&self, _user_id: i32, _annotation_id: i32) -> Result<bool, ServiceError> {
         Ok(true)
     }
+
+    async fn complete_upload(&self, _mask_group_id: i32, _completed_by: i32) -> Result<(), ServiceError> {
+        Ok(())
+    }
+
+    async fn check_storage_quota(&self, _mask_group_id: i32, _upload_bytes: Option<i64>) -> Result<Option<String>, ServiceError> {
+        Ok(None)
+    }
+
+    async fn get_mask_group_lineage(&self, _annotation_id: i32) -> Result<Vec<MaskGroupLineageNode>, ServiceError> {
+        Ok(Vec::new())
+    }
+
+    async fn set_mask_group_derived_from(&self, _id: i32, _derived_from_id: Option<i32>, _editor_id: i32) -> Result<MaskGroup, ServiceError> {
+        Err(ServiceError::NotFound("Mask group not found".into()))
+    }
 }
 
 // Mock SignedUrlService for testing
@@ -368,6 +390,7 @@ fn create_test_mask_group() -> MaskGroup {
         mask_type: Some("segmentation".to_string()),
         description: Some("Test description".to_string()),
         created_by: Some(1),
+        derived_from_id: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     }
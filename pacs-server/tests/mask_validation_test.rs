@@ -0,0 +1,210 @@
+mod common;
+
+#[cfg(test)]
+mod mask_validation_tests {
+    use std::sync::Arc;
+    use pacs_server::application::services::ObjectStorageService;
+    use pacs_server::application::use_cases::MaskValidationUseCase;
+    use pacs_server::domain::entities::{MaskValidationStatus, NewAnnotation, NewMask, UpdateMask, MASK_VALIDATION_RETRY_POLICY};
+    use pacs_server::domain::repositories::{AnnotationRepository, MaskRepository};
+    use pacs_server::domain::services::MaskGroupServiceImpl;
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::external::LocalObjectStorageService;
+    use pacs_server::infrastructure::imaging::encode_binary_mask_png;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MaskGroupRepositoryImpl, MaskRepositoryImpl, MaskValidationRepositoryImpl,
+        UserRepositoryImpl,
+    };
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user};
+
+    async fn pending_count(pool: &PgPool, mask_group_id: i32) -> i64 {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM annotation_mask WHERE mask_group_id = $1 AND validation_status = 'PENDING'"
+        )
+        .bind(mask_group_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_validates_uploaded_masks_and_aggregates_labels() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("validation_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = create_user(&pool, &format!("validation_owner_{}", suffix)).await;
+        let outsider_id = create_user(&pool, &format!("validation_outsider_{}", suffix)).await;
+
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(NewAnnotation {
+                project_id,
+                user_id: owner_id,
+                study_uid: "1.2.3.validation".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Brush Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "mask"}),
+                is_shared: false,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name, created_by) VALUES ($1, 'liver', $2) RETURNING id"
+        )
+        .bind(annotation.id)
+        .bind(owner_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        // 참조 인스턴스는 3 columns x 2 rows
+        let instance_uid = format!("1.2.3.validation.{}", suffix);
+        sqlx::query(
+            "INSERT INTO dicom_instance_metadata
+                 (project_id, study_uid, series_uid, instance_uid, pixel_spacing_row, pixel_spacing_column, rows, columns)
+             VALUES ($1, '1.2.3.validation', '1.2.3.validation.1', $2, 0.5, 0.5, 2, 3)"
+        )
+        .bind(project_id)
+        .bind(&instance_uid)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let root = std::env::temp_dir().join(format!("pacs-validation-{}", suffix));
+        let storage = Arc::new(
+            LocalObjectStorageService::new(&root, "http://localhost:8080", "test-key")
+                .await
+                .unwrap(),
+        );
+
+        let png = encode_binary_mask_png(3, 2, &[0, 1, 0, 0, 1, 1]).unwrap();
+        let wide_png = encode_binary_mask_png(4, 2, &[1, 0, 0, 0, 0, 0, 0, 1]).unwrap();
+        // (슬라이스, 업로드할 데이터, 보고된 체크섬)
+        let uploads = [
+            (0, Some(png.as_slice()), None),
+            (1, Some(png.as_slice()), None),
+            (2, Some(png.as_slice()), Some("0".repeat(64))),
+            (3, None, None),
+            (4, Some(wide_png.as_slice()), None),
+        ];
+        let mask_repo = MaskRepositoryImpl::new(pool.clone());
+        let mut mask_ids = Vec::new();
+        for (slice_index, data, checksum) in uploads {
+            let file_path = format!("masks/annotation_{}/group_{}/liver_{:03}.png", annotation.id, group_id, slice_index);
+            if let Some(data) = data {
+                storage.upload_file(&file_path, data.to_vec(), Some("image/png")).await.unwrap();
+            }
+            let mask = mask_repo
+                .create(&NewMask {
+                    mask_group_id: group_id,
+                    slice_index: Some(slice_index),
+                    sop_instance_uid: Some(instance_uid.clone()),
+                    label_name: Some("liver".to_string()),
+                    file_path,
+                    mime_type: Some("image/png".to_string()),
+                    file_size: Some(1),
+                    checksum,
+                    width: Some(3),
+                    height: Some(2),
+                })
+                .await
+                .unwrap();
+            mask_ids.push(mask.id);
+        }
+        assert_eq!(pending_count(&pool, group_id).await, 5);
+
+        let use_case = MaskValidationUseCase::new(
+            Arc::new(MaskValidationRepositoryImpl::new(pool.clone())),
+            Arc::new(MaskGroupServiceImpl::new(
+                Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+                Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+                Arc::new(UserRepositoryImpl::new(pool.clone())),
+            )),
+            storage.clone(),
+            MASK_VALIDATION_RETRY_POLICY,
+        );
+
+        // 다른 테스트가 남긴 대기 중인 마스크가 먼저 처리될 수 있으므로 그룹이 끝날 때까지 반복
+        for _ in 0..20 {
+            if pending_count(&pool, group_id).await == 0 {
+                break;
+            }
+            use_case.process_pending().await.unwrap();
+        }
+
+        let validation = use_case.get_validation(annotation.id, group_id, owner_id).await.unwrap();
+        assert_eq!(validation.counts.total, 5);
+        assert_eq!(validation.counts.valid, 2);
+        assert_eq!(validation.counts.invalid, 3);
+        let statuses: Vec<MaskValidationStatus> = validation.masks.iter().map(|mask| mask.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                MaskValidationStatus::Valid,
+                MaskValidationStatus::Valid,
+                MaskValidationStatus::Invalid,
+                MaskValidationStatus::Invalid,
+                MaskValidationStatus::Invalid,
+            ]
+        );
+        assert!(validation.masks[2].errors[0].contains("Checksum mismatch"));
+        assert!(validation.masks[3].errors[0].contains("not found"));
+        assert_eq!(validation.masks[4].errors.len(), 2);
+
+        // 통과한 마스크에 라벨 통계를 저장하고 실제 크기와 체크섬으로 보정
+        let stats = &validation.masks[0].label_stats;
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].voxel_count, 3);
+        assert_eq!(stats[0].bounding_box, [1, 0, 2, 2]);
+        assert_eq!(stats[0].label_name.as_deref(), Some("liver"));
+        let stored = mask_repo.get_by_id(mask_ids[0]).await.unwrap().unwrap();
+        assert_eq!(stored.file_size, Some(png.len() as i64));
+        assert_eq!(stored.checksum, validation.masks[0].content_sha256);
+
+        // 그룹 단위 합계는 통과한 마스크만
+        assert_eq!(validation.labels.len(), 1);
+        assert_eq!(validation.labels[0].voxel_count, 6);
+        assert_eq!(validation.labels[0].mask_count, 2);
+        assert_eq!((validation.labels[0].slice_start, validation.labels[0].slice_end), (Some(0), Some(1)));
+
+        // 파일이 바뀌면 다시 검증 대기
+        let mut update = UpdateMask::new(mask_ids[4]);
+        update.file_path = Some(format!("masks/annotation_{}/group_{}/liver_000.png", annotation.id, group_id));
+        mask_repo.update(mask_ids[4], &update).await.unwrap();
+        assert_eq!(pending_count(&pool, group_id).await, 1);
+
+        assert!(matches!(
+            use_case.get_validation(annotation.id, group_id, outsider_id).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            use_case.requeue(annotation.id + 1_000_000, group_id, owner_id).await,
+            Err(ServiceError::NotFound(_))
+        ));
+        let requeued = use_case.requeue(annotation.id, group_id, owner_id).await.unwrap();
+        assert_eq!(requeued.requeued, 5);
+        assert_eq!(pending_count(&pool, group_id).await, 5);
+
+        let _ = std::fs::remove_dir_all(&root);
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![owner_id, outsider_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
@@ -0,0 +1,171 @@
+mod common;
+
+#[cfg(test)]
+mod measurement_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::measurement_dto::{
+        InstanceMetadataItem, InstanceMetadataQuery, MeasurementDiscrepancyQuery, RecomputeMeasurementsRequest,
+        UpsertInstanceMetadataRequest,
+    };
+    use pacs_server::application::use_cases::MeasurementUseCase;
+    use pacs_server::domain::entities::NewAnnotation;
+    use pacs_server::domain::services::{AnnotationService, AnnotationServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MeasurementRepositoryImpl, ProjectRepositoryImpl, UserRepositoryImpl,
+    };
+    use serde_json::{json, Value};
+    use crate::common::{setup_pool, create_member};
+
+    #[tokio::test]
+    async fn test_measurements_use_instance_pixel_spacing_and_flag_discrepancies() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar(
+            "INSERT INTO security_project (name, status) VALUES ($1, 'COMPLETED') RETURNING id"
+        )
+        .bind(format!("measurement_project_{}", suffix))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let admin_id = create_member(&pool, project_id, &format!("ms_admin_{}", suffix), "PROJECT_ADMIN").await;
+        let annotator_id = create_member(&pool, project_id, &format!("ms_annotator_{}", suffix), "ANNOTATOR").await;
+
+        let use_case = MeasurementUseCase::new(
+            Arc::new(MeasurementRepositoryImpl::new(pool.clone())),
+            Arc::new(ProjectRepositoryImpl::new(pool.clone())),
+        );
+        let annotation_service = AnnotationServiceImpl::new(
+            AnnotationRepositoryImpl::new(pool.clone()),
+            UserRepositoryImpl::new(pool.clone()),
+            ProjectRepositoryImpl::new(pool.clone()),
+        );
+
+        let study_uid = format!("1.2.840.{}.1", project_id);
+        let series_uid = format!("1.2.840.{}.1.1", project_id);
+        let annotation = |instance: &str, tool_name: &str, data: Value, measurement_values: Value| NewAnnotation {
+            project_id,
+            user_id: annotator_id,
+            study_uid: study_uid.clone(),
+            series_uid: Some(series_uid.clone()),
+            instance_uid: Some(format!("{}.{}", series_uid, instance)),
+            tool_name: tool_name.to_string(),
+            tool_version: None,
+            viewer_software: None,
+            data,
+            description: None,
+            is_shared: false,
+            measurement_values: Some(measurement_values),
+        };
+
+        // 메타데이터가 없으면 픽셀 단위로 계산
+        let length = annotation_service
+            .create_annotation(annotation(
+                "1",
+                "Length Tool",
+                json!({"points": [[0, 0], [40, 30]]}),
+                json!([{"type": "length", "value": 25.0, "unit": "mm"}]),
+            ))
+            .await
+            .unwrap();
+        let measurement = use_case.get_measurement(project_id, length.id, annotator_id).await.unwrap();
+        assert_eq!(measurement.geometry_type.as_deref(), Some("LENGTH"));
+        assert_eq!(measurement.spacing_source, "NONE");
+        assert!(measurement.pixel_spacing.is_none());
+        assert_eq!(measurement.measurements[0].value, 50.0);
+        assert_eq!(measurement.measurements[0].unit, "px");
+
+        // 프로젝트 관리자만 메타데이터를 등록할 수 있고 픽셀 간격은 양수 두 개
+        let item = |instance: &str, pixel_spacing: Vec<f64>| InstanceMetadataItem {
+            study_uid: study_uid.clone(),
+            series_uid: series_uid.clone(),
+            instance_uid: format!("{}.{}", series_uid, instance),
+            pixel_spacing,
+            rows: Some(512),
+            columns: Some(512),
+        };
+        let request = |items| UpsertInstanceMetadataRequest { instances: items, recompute: None };
+        let result = use_case.upsert_instance_metadata(project_id, request(vec![item("1", vec![0.5, 0.5])]), annotator_id).await;
+        assert!(matches!(result, Err(ServiceError::Unauthorized(_))));
+        let result = use_case.upsert_instance_metadata(project_id, request(vec![item("1", vec![0.5])]), admin_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let result = use_case.upsert_instance_metadata(project_id, request(vec![item("1", vec![0.0, 0.5])]), admin_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 등록하면 같은 Series의 어노테이션을 다시 계산 (40x30px * 0.5mm = 25mm, 클라이언트 값과 일치)
+        let saved = use_case
+            .upsert_instance_metadata(project_id, request(vec![item("1", vec![0.5, 0.5])]), admin_id)
+            .await
+            .unwrap();
+        assert_eq!(saved.instances.len(), 1);
+        assert_eq!(saved.recomputed_annotations, Some(1));
+        let measurement = use_case.get_measurement(project_id, length.id, annotator_id).await.unwrap();
+        assert_eq!(measurement.spacing_source, "INSTANCE");
+        assert_eq!(measurement.pixel_spacing, Some(vec![0.5, 0.5]));
+        assert_eq!(measurement.measurements[0].value, 25.0);
+        assert_eq!(measurement.measurements[0].unit, "mm");
+        assert!(!measurement.has_discrepancy);
+
+        // 메타데이터가 없는 인스턴스는 같은 Series의 픽셀 간격을 사용하고, 다른 클라이언트 값은 불일치로 표시
+        let rectangle = annotation_service
+            .create_annotation(annotation(
+                "2",
+                "Rectangle Tool",
+                json!({"x": 10, "y": 10, "width": 20, "height": 10}),
+                json!({"area": 75.0, "unit": "mm2"}),
+            ))
+            .await
+            .unwrap();
+        let measurement = use_case.recompute_measurement(project_id, rectangle.id, annotator_id).await.unwrap();
+        assert_eq!(measurement.spacing_source, "SERIES");
+        let area = measurement.measurements.iter().find(|m| m.measurement_type == "area").unwrap();
+        assert_eq!((area.value, area.unit.as_str()), (50.0, "mm2"));
+        assert!(measurement.has_discrepancy);
+        assert_eq!(measurement.discrepancies.len(), 1);
+        assert_eq!(measurement.discrepancies[0].client_value, 75.0);
+
+        let discrepancies = use_case
+            .list_discrepancies(project_id, MeasurementDiscrepancyQuery::default(), annotator_id)
+            .await
+            .unwrap();
+        let ids: Vec<i32> = discrepancies.measurements.iter().map(|m| m.annotation_id).collect();
+        assert_eq!(ids, vec![rectangle.id]);
+
+        let listed = use_case
+            .list_instance_metadata(project_id, InstanceMetadataQuery { series_uid: Some(series_uid.clone()), ..Default::default() }, annotator_id)
+            .await
+            .unwrap();
+        assert_eq!(listed.instances.len(), 1);
+
+        // 프로젝트 전체 재계산은 관리자만 (기본값은 계산 결과가 없는 어노테이션만)
+        let result = use_case.recompute_project(project_id, RecomputeMeasurementsRequest::default(), annotator_id).await;
+        assert!(matches!(result, Err(ServiceError::Unauthorized(_))));
+        let summary = use_case.recompute_project(project_id, RecomputeMeasurementsRequest::default(), admin_id).await.unwrap();
+        assert_eq!(summary.processed, 0);
+        let summary = use_case
+            .recompute_project(project_id, RecomputeMeasurementsRequest { only_missing: Some(false) }, admin_id)
+            .await
+            .unwrap();
+        assert_eq!((summary.processed, summary.with_discrepancy, summary.failed), (2, 1, 0));
+
+        let result = use_case.get_measurement(project_id, i32::MAX, annotator_id).await;
+        assert!(matches!(result, Err(ServiceError::NotFound(_))));
+
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![admin_id, annotator_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
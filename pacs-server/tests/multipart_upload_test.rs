@@ -0,0 +1,232 @@
+mod common;
+
+#[cfg(test)]
+mod multipart_upload_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::multipart_upload_dto::{
+        CompleteMultipartUploadRequest, CompletedPartRequest, InitiateMultipartUploadRequest, MultipartPartUrlsRequest,
+    };
+    use pacs_server::application::use_cases::MultipartUploadUseCase;
+    use pacs_server::domain::entities::{MultipartUploadStatus, NewAnnotation};
+    use pacs_server::domain::repositories::AnnotationRepository;
+    use pacs_server::domain::services::MaskGroupServiceImpl;
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MaskGroupRepositoryImpl, MultipartUploadRepositoryImpl, UserRepositoryImpl,
+    };
+    use crate::common::{setup_pool, create_user};
+    use crate::common::storage::MemoryObjectStorage;
+
+    const MIB: i64 = 1024 * 1024;
+
+    #[tokio::test]
+    async fn test_resumable_multipart_mask_upload() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let user_id = create_user(&pool, &format!("multipart_user_{}", suffix)).await;
+        let project_id: i32 = sqlx::query_scalar(
+            "INSERT INTO security_project (name, status) VALUES ($1, 'COMPLETED') RETURNING id"
+        )
+        .bind(format!("multipart_project_{}", suffix))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let annotation = annotation_repo
+            .create(NewAnnotation {
+                project_id,
+                user_id,
+                study_uid: "1.2.3.multipart".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Segmentation Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "segmentation"}),
+                is_shared: false,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name, created_by) VALUES ($1, 'liver', $2) RETURNING id"
+        )
+        .bind(annotation.id)
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let storage = Arc::new(MemoryObjectStorage::default());
+        let use_case = MultipartUploadUseCase::new(
+            Arc::new(MaskGroupServiceImpl::new(
+                Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+                Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+                Arc::new(UserRepositoryImpl::new(pool.clone())),
+            )),
+            Arc::new(MultipartUploadRepositoryImpl::new(pool.clone())),
+            storage.clone(),
+            600,
+            3600,
+            chrono::Duration::hours(24),
+        );
+
+        // 파트 크기는 5 MiB 이상이어야 함
+        let request = |part_size: i64| InitiateMultipartUploadRequest {
+            filename: "liver mask.nii.gz".to_string(),
+            mime_type: Some("application/gzip".to_string()),
+            total_size: 12 * MIB,
+            part_size: Some(part_size),
+        };
+        let result = use_case.initiate(annotation.id, group_id, request(MIB), user_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let result = use_case.initiate(annotation.id + 1_000_000, group_id, request(5 * MIB), user_id).await;
+        assert!(matches!(result, Err(ServiceError::NotFound(_))));
+
+        let upload = use_case.initiate(annotation.id, group_id, request(5 * MIB), user_id).await.unwrap();
+        assert_eq!(upload.part_count, 3);
+        assert_eq!(upload.file_path, format!("masks/annotation_{}/group_{}/liver_mask.nii.gz", annotation.id, group_id));
+        let result = use_case.initiate(annotation.id, group_id, request(5 * MIB), user_id).await;
+        assert!(matches!(result, Err(ServiceError::AlreadyExists(_))));
+
+        let storage_upload_id: String = sqlx::query_scalar(
+            "SELECT upload_id FROM annotation_mask_group_multipart_upload WHERE id = $1"
+        )
+        .bind(upload.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let urls = use_case
+            .generate_part_urls(annotation.id, group_id, upload.id, MultipartPartUrlsRequest::default(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(urls.parts.iter().map(|p| (p.part_number, p.size)).collect::<Vec<_>>(), vec![
+            (1, 5 * MIB),
+            (2, 5 * MIB),
+            (3, 2 * MIB),
+        ]);
+        assert_eq!(urls.expires_in, 600);
+
+        // 두 번째 파트 도중 연결이 끊김
+        storage.put_part(&storage_upload_id, 1, 5 * MIB);
+        let result = use_case
+            .complete(annotation.id, group_id, upload.id, CompleteMultipartUploadRequest::default(), user_id)
+            .await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 이어받기: 업로드된 파트와 빠진 파트 확인 후 빠진 파트만 다시 요청
+        let detail = use_case.get(annotation.id, group_id, upload.id, user_id).await.unwrap();
+        assert_eq!(detail.missing_parts, vec![2, 3]);
+        assert_eq!(detail.uploaded_bytes, 5 * MIB);
+        let urls = use_case
+            .generate_part_urls(
+                annotation.id,
+                group_id,
+                upload.id,
+                MultipartPartUrlsRequest { part_numbers: None, ttl_seconds: Some(86_400) },
+                user_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(urls.parts.iter().map(|p| p.part_number).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(urls.expires_in, 3600);
+        let result = use_case
+            .generate_part_urls(
+                annotation.id,
+                group_id,
+                upload.id,
+                MultipartPartUrlsRequest { part_numbers: Some(vec![4]), ttl_seconds: None },
+                user_id,
+            )
+            .await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        storage.put_part(&storage_upload_id, 2, 5 * MIB);
+        storage.put_part(&storage_upload_id, 3, 2 * MIB);
+        let stale_etag = CompleteMultipartUploadRequest {
+            parts: Some(vec![CompletedPartRequest { part_number: 1, etag: "stale".to_string() }]),
+        };
+        let result = use_case.complete(annotation.id, group_id, upload.id, stale_etag, user_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        let completed = use_case
+            .complete(annotation.id, group_id, upload.id, CompleteMultipartUploadRequest::default(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(completed.status, MultipartUploadStatus::Completed);
+        assert!(completed.completed_at.is_some());
+        assert_eq!(storage.completed()[0], (upload.file_path.clone(), vec![1, 2, 3]));
+        let result = use_case.abort(annotation.id, group_id, upload.id, user_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 만료 시각이 지난 업로드는 정리 작업이 스토리지에서 중단
+        let stale = use_case
+            .initiate(
+                annotation.id,
+                group_id,
+                InitiateMultipartUploadRequest {
+                    filename: "spleen.nii.gz".to_string(),
+                    mime_type: None,
+                    total_size: MIB,
+                    part_size: None,
+                },
+                user_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(stale.part_count, 1);
+        let fresh = use_case
+            .initiate(
+                annotation.id,
+                group_id,
+                InitiateMultipartUploadRequest {
+                    filename: "kidney.nii.gz".to_string(),
+                    mime_type: None,
+                    total_size: MIB,
+                    part_size: None,
+                },
+                user_id,
+            )
+            .await
+            .unwrap();
+        sqlx::query(
+            "UPDATE annotation_mask_group_multipart_upload SET expires_at = NOW() - INTERVAL '1 minute' WHERE id = $1"
+        )
+        .bind(stale.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        let cleanup = use_case.cleanup_expired().await.unwrap();
+        assert!(cleanup.aborted >= 1);
+
+        let uploads = use_case.list(annotation.id, group_id, user_id).await.unwrap().uploads;
+        let status = |id: i32| uploads.iter().find(|u| u.id == id).unwrap().status;
+        assert_eq!(status(stale.id), MultipartUploadStatus::Aborted);
+        assert_eq!(status(fresh.id), MultipartUploadStatus::InProgress);
+        assert_eq!(status(upload.id), MultipartUploadStatus::Completed);
+
+        let aborted = use_case.abort(annotation.id, group_id, fresh.id, user_id).await.unwrap();
+        assert_eq!(aborted.status, MultipartUploadStatus::Aborted);
+        assert_eq!(storage.aborted().len(), 2);
+
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
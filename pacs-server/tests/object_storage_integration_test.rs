@@ -306,6 +306,7 @@ mod object_storage_integration_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("S3 storage test group".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -309,6 +309,7 @@ mod performance_tests {
             slice_count: 1000, // Large number of slices
             mask_type: "segmentation".to_string(),
             description: Some("Large file performance test".to_string()),
+            derived_from_id: None,
         };
 
         let start = Instant::now();
@@ -342,6 +343,7 @@ mod performance_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Concurrent upload test".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -405,6 +407,7 @@ mod performance_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("Bulk creation test".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -472,6 +475,7 @@ mod performance_tests {
             slice_count: 100,
             mask_type: "segmentation".to_string(),
             description: Some("URL generation test".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -540,6 +544,7 @@ mod performance_tests {
                 slice_count: 50,
                 mask_type: "segmentation".to_string(),
                 description: Some(format!("Query test group {}", i)),
+                derived_from_id: None,
             };
 
             let req = test::TestRequest::post()
@@ -624,6 +629,7 @@ mod performance_tests {
             slice_count: 1000,
             mask_type: "segmentation".to_string(),
             description: Some("Memory usage test".to_string()),
+            derived_from_id: None,
         };
 
         let req = test::TestRequest::post()
@@ -0,0 +1,241 @@
+mod common;
+
+#[cfg(test)]
+mod realtime_tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use pacs_server::application::dto::edit_lock_dto::AcquireEditLockRequest;
+    use pacs_server::application::dto::realtime_dto::RealtimeEventQuery;
+    use pacs_server::application::use_cases::realtime_use_case::RealtimeFeed;
+    use pacs_server::application::use_cases::{EditLockUseCase, RealtimeUseCase};
+    use pacs_server::domain::entities::realtime::{RealtimeEvent, RealtimeEventType};
+    use pacs_server::domain::entities::NewAnnotation;
+    use pacs_server::domain::services::realtime_events;
+    use pacs_server::domain::services::{AnnotationService, AnnotationServiceImpl, MaskGroupService, MaskGroupServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{
+        AccessLogRepositoryImpl, AnnotationRepositoryImpl, EditLockRepositoryImpl, MaskGroupRepositoryImpl,
+        ProjectRepositoryImpl, RealtimeRepositoryImpl, UserRepositoryImpl,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user, create_member};
+
+    /// 구독자가 받을 수 있는 다음 이벤트 (없으면 None)
+    async fn next_event(feed: &mut RealtimeFeed, wait: Duration) -> Option<RealtimeEvent> {
+        let deadline = tokio::time::Instant::now() + wait;
+        loop {
+            let event = tokio::time::timeout_at(deadline, feed.receiver.recv()).await.ok()?.ok()?;
+            if feed.subscription.accepts(&event) {
+                return Some(event);
+            }
+        }
+    }
+
+    /// 리스너가 LISTEN을 시작할 때까지 시험 이벤트를 발행
+    async fn wait_for_listener(pool: &PgPool, feed: &mut RealtimeFeed, project_id: i32) {
+        let probe = RealtimeEvent::new(RealtimeEventType::EditLockReleased, project_id, Some("probe".to_string()), 0, None);
+        for _ in 0..50 {
+            realtime_events::publish(pool, &probe).await.unwrap();
+            if let Ok(Ok(_)) = tokio::time::timeout(Duration::from_millis(100), feed.receiver.recv()).await {
+                // 남은 시험 이벤트 비우기
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                while feed.receiver.try_recv().is_ok() {}
+                return;
+            }
+        }
+        panic!("Realtime listener did not start");
+    }
+
+    #[tokio::test]
+    async fn test_realtime_feed_delivers_filtered_project_events() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar(
+            "INSERT INTO security_project (name, status) VALUES ($1, 'COMPLETED') RETURNING id"
+        )
+        .bind(format!("realtime_project_{}", suffix))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let admin_id = create_member(&pool, project_id, &format!("rt_admin_{}", suffix), "PROJECT_ADMIN").await;
+        let reader_a = create_member(&pool, project_id, &format!("rt_reader_a_{}", suffix), "ANNOTATOR").await;
+        let reader_b = create_member(&pool, project_id, &format!("rt_reader_b_{}", suffix), "ANNOTATOR").await;
+        let outsider = create_user(&pool, &format!("rt_outsider_{}", suffix)).await;
+
+        let study_1 = format!("1.2.840.{}.1", project_id);
+        let study_2 = format!("1.2.840.{}.2", project_id);
+
+        let use_case = Arc::new(RealtimeUseCase::new(
+            Arc::new(RealtimeRepositoryImpl::new(pool.clone())),
+            Arc::new(ProjectRepositoryImpl::new(pool.clone())),
+            256,
+        ));
+        let listener = use_case.clone();
+        let listener_task = tokio::spawn(async move {
+            listener.run_listener_loop(Duration::from_millis(100)).await;
+        });
+
+        // 프로젝트 멤버만 구독할 수 있음
+        let result = use_case.subscribe(project_id, RealtimeEventQuery::default(), outsider).await;
+        assert!(matches!(result, Err(ServiceError::Unauthorized(_))));
+
+        let mut admin_feed = use_case.subscribe(project_id, RealtimeEventQuery::default(), admin_id).await.unwrap();
+        assert!(admin_feed.subscription.is_project_admin);
+        let mut study_1_feed = use_case
+            .subscribe(project_id, RealtimeEventQuery { study_uid: Some(study_1.clone()) }, reader_a)
+            .await
+            .unwrap();
+        let mut reader_b_feed = use_case.subscribe(project_id, RealtimeEventQuery::default(), reader_b).await.unwrap();
+        wait_for_listener(&pool, &mut admin_feed, project_id).await;
+        while study_1_feed.receiver.try_recv().is_ok() {}
+        while reader_b_feed.receiver.try_recv().is_ok() {}
+
+        let wait = Duration::from_secs(5);
+        let annotation_service = AnnotationServiceImpl::new(
+            AnnotationRepositoryImpl::new(pool.clone()),
+            UserRepositoryImpl::new(pool.clone()),
+            ProjectRepositoryImpl::new(pool.clone()),
+        );
+
+        // 어노테이션 생성/수정
+        let annotation = annotation_service
+            .create_annotation(NewAnnotation {
+                project_id,
+                user_id: reader_a,
+                study_uid: study_1.clone(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                viewer_software: None,
+                data: json!({"points": [[0, 0], [1, 1]]}),
+                description: None,
+                is_shared: false,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let created = next_event(&mut study_1_feed, wait).await.unwrap();
+        assert_eq!(created.event_type, RealtimeEventType::AnnotationCreated);
+        assert_eq!((created.resource_id, created.actor_id), (annotation.id, Some(reader_a)));
+        assert_eq!(created.study_uid.as_deref(), Some(study_1.as_str()));
+
+        annotation_service.update_annotation(annotation.id, json!({"points": []}), false, reader_b).await.unwrap();
+        let updated = next_event(&mut study_1_feed, wait).await.unwrap();
+        assert_eq!(updated.event_type, RealtimeEventType::AnnotationUpdated);
+        assert_eq!(updated.actor_id, Some(reader_b));
+
+        // 마스크 그룹 업로드 완료
+        let mask_group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'group') RETURNING id"
+        )
+        .bind(annotation.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let mask_group_service = MaskGroupServiceImpl::new(
+            Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+            Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+            Arc::new(UserRepositoryImpl::new(pool.clone())),
+        );
+        mask_group_service.complete_upload(mask_group_id, reader_a).await.unwrap();
+        let uploaded = next_event(&mut study_1_feed, wait).await.unwrap();
+        assert_eq!(uploaded.event_type, RealtimeEventType::MaskGroupUploaded);
+        assert_eq!(uploaded.resource_id, mask_group_id);
+
+        // 공유되지 않은 어노테이션의 이벤트는 작성자가 아닌 멤버에게 전달되지 않음
+        // 다른 Study의 편집 잠금: Study 구독자는 받지 않고 프로젝트 구독자는 받음
+        let lock_use_case = EditLockUseCase::new(
+            Arc::new(EditLockRepositoryImpl::new(pool.clone())),
+            Arc::new(AccessLogRepositoryImpl::new(pool.clone())),
+            Arc::new(ProjectRepositoryImpl::new(pool.clone())),
+        );
+        let lock = lock_use_case
+            .acquire_lock(project_id, AcquireEditLockRequest { study_uid: Some(study_2.clone()), ..Default::default() }, reader_b)
+            .await
+            .unwrap();
+        lock_use_case.force_release_lock(project_id, lock.id, admin_id).await.unwrap();
+        let event_types: Vec<RealtimeEventType> = {
+            let mut events = Vec::new();
+            while let Some(event) = next_event(&mut reader_b_feed, Duration::from_millis(500)).await {
+                events.push(event);
+            }
+            let released = events.last().unwrap();
+            assert_eq!((released.resource_id, released.actor_id), (lock.id, Some(admin_id)));
+            events.into_iter().map(|e| e.event_type).collect()
+        };
+        assert_eq!(
+            event_types,
+            vec![RealtimeEventType::EditLockAcquired, RealtimeEventType::EditLockReleased]
+        );
+
+        // 공유되지 않은 어노테이션의 편집 잠금도 작성자가 아닌 멤버에게 전달되지 않음
+        let annotation_lock = lock_use_case
+            .acquire_lock(
+                project_id,
+                AcquireEditLockRequest { annotation_id: Some(annotation.id), ..Default::default() },
+                reader_a,
+            )
+            .await
+            .unwrap();
+        let acquired = next_event(&mut study_1_feed, wait).await.unwrap();
+        assert_eq!((acquired.event_type, acquired.resource_id), (RealtimeEventType::EditLockAcquired, annotation_lock.id));
+        lock_use_case.release_lock(project_id, annotation_lock.id, reader_a).await.unwrap();
+        let released = next_event(&mut study_1_feed, wait).await.unwrap();
+        assert_eq!(released.event_type, RealtimeEventType::EditLockReleased);
+        assert!(next_event(&mut reader_b_feed, Duration::from_millis(300)).await.is_none());
+
+        // 데이터 접근 이벤트는 대상 사용자와 관리자만 받음
+        let approved = RealtimeEvent::new(RealtimeEventType::DataAccessApproved, project_id, Some(study_1.clone()), 99, Some(admin_id))
+            .with_subject_user(reader_a);
+        realtime_events::publish(&pool, &approved).await.unwrap();
+        annotation_service.delete_annotation(annotation.id, reader_a).await.unwrap();
+
+        let received = next_event(&mut study_1_feed, wait).await.unwrap();
+        assert_eq!(received.event_type, RealtimeEventType::DataAccessApproved);
+        let deleted = next_event(&mut study_1_feed, wait).await.unwrap();
+        assert_eq!(deleted.event_type, RealtimeEventType::AnnotationDeleted);
+        assert!(next_event(&mut study_1_feed, Duration::from_millis(300)).await.is_none());
+        assert!(next_event(&mut reader_b_feed, Duration::from_millis(300)).await.is_none());
+
+        let mut admin_types = Vec::new();
+        while let Some(event) = next_event(&mut admin_feed, Duration::from_millis(500)).await {
+            admin_types.push(event.event_type);
+        }
+        assert_eq!(admin_types.len(), 9);
+        assert!(admin_types.contains(&RealtimeEventType::DataAccessApproved));
+
+        // 멤버에서 빠지면 구독이 유지되지 않음
+        sqlx::query("DELETE FROM security_user_project WHERE user_id = $1 AND project_id = $2")
+            .bind(reader_b)
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(!use_case.is_still_member(&reader_b_feed.subscription).await.unwrap());
+
+        listener_task.abort();
+        sqlx::query("DELETE FROM security_access_log WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![admin_id, reader_a, reader_b, outsider])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
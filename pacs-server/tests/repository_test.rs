@@ -632,7 +632,7 @@ mod annotation_repository_tests {
         assert!(found.iter().any(|a| a.id == created.id));
 
         // Cleanup
-        repo.delete(created.id).await.unwrap();
+        repo.delete(created.id, user.id).await.unwrap();
         sqlx::query("DELETE FROM security_user_project WHERE user_id = $1")
             .bind(user.id)
             .execute(&pool)
@@ -701,7 +701,7 @@ mod annotation_repository_tests {
         assert_eq!(updated.unwrap().data, updated_data);
 
         // Cleanup
-        repo.delete(created.id).await.unwrap();
+        repo.delete(created.id, user.id).await.unwrap();
         sqlx::query("DELETE FROM security_user_project WHERE user_id = $1")
             .bind(user.id)
             .execute(&pool)
@@ -763,7 +763,7 @@ mod annotation_repository_tests {
         };
 
         let created = repo.create(new_annotation).await.unwrap();
-        let deleted = repo.delete(created.id).await.unwrap();
+        let deleted = repo.delete(created.id, user.id).await.unwrap();
         assert!(deleted);
 
         let found = repo.find_by_id(created.id).await.unwrap();
@@ -849,7 +849,7 @@ mod annotation_repository_tests {
         assert!(history_entries.iter().any(|h| h.id == created_history.id));
 
         // Cleanup
-        repo.delete(created.id).await.unwrap();
+        repo.delete(created.id, user.id).await.unwrap();
         sqlx::query("DELETE FROM security_user_project WHERE user_id = $1")
             .bind(user.id)
             .execute(&pool)
@@ -1707,7 +1707,7 @@ async fn test_annotation_service_update_annotation() {
 
     // Update annotation
     let updated_data = json!({"type": "rectangle", "x": 200, "y": 300, "width": 100, "height": 80});
-    let updated = annotation_service.update_annotation(created.id, updated_data.clone(), false).await.unwrap();
+    let updated = annotation_service.update_annotation(created.id, updated_data.clone(), false, user.id).await.unwrap();
     assert_eq!(updated.data, updated_data);
 
     cleanup_test_data(&pool).await;
@@ -1760,7 +1760,7 @@ async fn test_annotation_service_delete_annotation() {
     };
 
     let created = annotation_service.create_annotation(new_annotation).await.unwrap();
-    annotation_service.delete_annotation(created.id).await.unwrap();
+    annotation_service.delete_annotation(created.id, created.user_id).await.unwrap();
 
     let result = annotation_service.get_annotation_by_id(created.id).await;
     assert!(result.is_err());
@@ -210,6 +210,7 @@ async fn test_mask_upload_workflow() {
         slice_count: 120,
         mask_type: "segmentation".to_string(),
         description: Some("간 분할 마스크 그룹".to_string()),
+        derived_from_id: None,
     };
     
     let req = test::TestRequest::post()
@@ -263,6 +264,7 @@ async fn test_mask_upload_workflow_error_handling() {
         slice_count: 100,
         mask_type: "segmentation".to_string(),
         description: Some("Test description".to_string()),
+        derived_from_id: None,
     };
     
     let req = test::TestRequest::post()
@@ -0,0 +1,149 @@
+mod common;
+
+#[cfg(test)]
+mod storage_deletion_tests {
+    use std::sync::Arc;
+    use pacs_server::application::use_cases::StorageDeletionUseCase;
+    use pacs_server::domain::entities::{mask_group_mask_prefix, NewAnnotation, RetryPolicy, StorageDeletionReason};
+    use pacs_server::domain::repositories::{AnnotationRepository, MaskRepository};
+    use pacs_server::domain::services::storage_deletion_queue;
+    use pacs_server::infrastructure::repositories::{AnnotationRepositoryImpl, MaskRepositoryImpl, StorageDeletionRepositoryImpl};
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user};
+    use crate::common::storage::MemoryObjectStorage;
+
+    /// 대기열 항목의 (상태, 시도 횟수)
+    async fn queue_state(pool: &PgPool, object_key: &str) -> (String, i32) {
+        sqlx::query_as::<_, (String, i32)>(
+            "SELECT status::TEXT, attempt_count FROM storage_deletion_queue WHERE object_key = $1"
+        )
+        .bind(object_key)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    /// 재시도 대기 중인 항목을 바로 처리할 수 있게 함
+    async fn make_due(pool: &PgPool, object_key: &str) {
+        sqlx::query("UPDATE storage_deletion_queue SET next_attempt_at = CURRENT_TIMESTAMP WHERE object_key = $1")
+            .bind(object_key)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_deleted_mask_objects_are_queued_retried_and_dead_lettered() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let user_id = create_user(&pool, &format!("deletion_user_{}", suffix)).await;
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("deletion_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(NewAnnotation {
+                project_id,
+                user_id,
+                study_uid: "1.2.3.deletion".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "polygon"}),
+                is_shared: false,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'liver') RETURNING id"
+        )
+        .bind(annotation.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let prefix = mask_group_mask_prefix(annotation.id, group_id);
+        let mask_key = format!("{}slice_0000_{}.png", prefix, suffix);
+        let mask_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask (mask_group_id, file_path, slice_index) VALUES ($1, $2, 0) RETURNING id"
+        )
+        .bind(group_id)
+        .bind(&mask_key)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let storage = Arc::new(MemoryObjectStorage::default());
+        storage.put(&mask_key);
+        let use_case = StorageDeletionUseCase::new(
+            Arc::new(StorageDeletionRepositoryImpl::new(pool.clone())),
+            storage.clone(),
+            RetryPolicy::new(2, 60, 60),
+        );
+
+        // 마스크 행 삭제와 같은 트랜잭션에서 파일 삭제가 예약됨
+        MaskRepositoryImpl::new(pool.clone()).delete(mask_id).await.unwrap();
+        assert_eq!(queue_state(&pool, &mask_key).await, ("PENDING".to_string(), 0));
+
+        // 실패하면 백오프 후 재시도
+        storage.set_failing(&mask_key, true);
+        use_case.process_due().await.unwrap();
+        assert_eq!(queue_state(&pool, &mask_key).await, ("PENDING".to_string(), 1));
+        assert!(storage.contains(&mask_key));
+
+        storage.set_failing(&mask_key, false);
+        use_case.process_due().await.unwrap();
+        assert!(storage.contains(&mask_key), "retry must wait for the backoff");
+        make_due(&pool, &mask_key).await;
+        use_case.process_due().await.unwrap();
+        assert_eq!(queue_state(&pool, &mask_key).await, ("DELETED".to_string(), 2));
+        assert!(!storage.contains(&mask_key));
+
+        // prefix 항목은 행이 없는 업로드 잔여물까지 지움
+        let orphans = [format!("{}orphan_a_{}.png", prefix, suffix), format!("{}orphan_b_{}.png", prefix, suffix)];
+        for orphan in &orphans {
+            storage.put(orphan);
+        }
+        storage_deletion_queue::enqueue_prefix(&pool, &prefix, StorageDeletionReason::MaskGroupPurged)
+            .await
+            .unwrap();
+        use_case.process_due().await.unwrap();
+        assert_eq!(queue_state(&pool, &prefix).await.0, "DELETED");
+        assert!(orphans.iter().all(|orphan| !storage.contains(orphan)));
+
+        // 재시도 횟수를 모두 소진하면 dead-letter
+        let stuck_key = format!("masks/stuck_{}.png", suffix);
+        storage.put(&stuck_key);
+        storage.set_failing(&stuck_key, true);
+        storage_deletion_queue::enqueue_objects(&pool, std::slice::from_ref(&stuck_key), StorageDeletionReason::MaskDeleted)
+            .await
+            .unwrap();
+        use_case.process_due().await.unwrap();
+        make_due(&pool, &stuck_key).await;
+        let result = use_case.process_due().await.unwrap();
+        assert!(result.dead >= 1);
+        assert_eq!(queue_state(&pool, &stuck_key).await, ("DEAD".to_string(), 2));
+
+        sqlx::query("DELETE FROM storage_deletion_queue WHERE object_key = ANY($1)")
+            .bind(vec![mask_key, prefix, stuck_key])
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
@@ -0,0 +1,234 @@
+mod common;
+
+#[cfg(test)]
+mod storage_quota_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::storage_quota_dto::UpdateStorageQuotaRequest;
+    use pacs_server::application::use_cases::StorageQuotaUseCase;
+    use pacs_server::domain::entities::{NewAnnotation, NewMask, StorageQuotaLevel, UpdateMask};
+    use pacs_server::domain::repositories::{AnnotationRepository, MaskRepository, TrashRepository};
+    use pacs_server::domain::services::{MaskGroupService, MaskGroupServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MaskGroupRepositoryImpl, MaskRepositoryImpl, ProjectRepositoryImpl, StorageQuotaRepositoryImpl,
+        TrashRepositoryImpl, UserRepositoryImpl,
+    };
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user, create_member};
+
+    fn new_mask(mask_group_id: i32, file_path: String, file_size: i64) -> NewMask {
+        NewMask {
+            mask_group_id,
+            slice_index: Some(0),
+            sop_instance_uid: None,
+            label_name: None,
+            file_path,
+            mime_type: Some("image/png".to_string()),
+            file_size: Some(file_size),
+            checksum: None,
+            width: None,
+            height: None,
+        }
+    }
+
+    /// 프로젝트 사용량 (바이트, 객체 수)
+    async fn project_usage(pool: &PgPool, project_id: i32) -> (i64, i64) {
+        sqlx::query_as::<_, (i64, i64)>(
+            "SELECT COALESCE(SUM(bytes_used), 0)::BIGINT, COALESCE(SUM(object_count), 0)::BIGINT
+             FROM storage_usage WHERE project_id = $1"
+        )
+        .bind(project_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    async fn threshold_events(pool: &PgPool, project_id: i32) -> Vec<serde_json::Value> {
+        sqlx::query_scalar::<_, serde_json::Value>(
+            "SELECT payload FROM webhook_outbox_event
+             WHERE project_id = $1 AND event_type = 'STORAGE_QUOTA_THRESHOLD_REACHED'
+             ORDER BY id"
+        )
+        .bind(project_id)
+        .fetch_all(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_usage_accounting_quota_enforcement_and_threshold_alert() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("quota_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let admin_id = create_member(&pool, project_id, &format!("quota_admin_{}", suffix), "PROJECT_ADMIN").await;
+        let outsider_id = create_user(&pool, &format!("quota_outsider_{}", suffix)).await;
+
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(NewAnnotation {
+                project_id,
+                user_id: admin_id,
+                study_uid: "1.2.3.quota".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "polygon"}),
+                is_shared: false,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name, created_by) VALUES ($1, 'liver', $2) RETURNING id"
+        )
+        .bind(annotation.id)
+        .bind(admin_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let use_case = StorageQuotaUseCase::new(
+            Arc::new(StorageQuotaRepositoryImpl::new(pool.clone())),
+            Arc::new(ProjectRepositoryImpl::new(pool.clone())),
+        );
+        let mask_group_service = MaskGroupServiceImpl::new(
+            Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+            Arc::new(AnnotationRepositoryImpl::new(pool.clone())),
+            Arc::new(UserRepositoryImpl::new(pool.clone())),
+        );
+        let mask_repo = MaskRepositoryImpl::new(pool.clone());
+
+        // 한도는 프로젝트 관리자만 설정하며 경고 한도가 업로드 한도보다 클 수 없음
+        let quota = UpdateStorageQuotaRequest { soft_limit_bytes: Some(150), hard_limit_bytes: Some(300) };
+        assert!(matches!(
+            use_case.set_project_quota(project_id, quota.clone(), outsider_id).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+        let invalid = UpdateStorageQuotaRequest { soft_limit_bytes: Some(400), hard_limit_bytes: Some(300) };
+        assert!(matches!(
+            use_case.set_project_quota(project_id, invalid, admin_id).await,
+            Err(ServiceError::ValidationError(_))
+        ));
+        let saved = use_case.set_project_quota(project_id, quota, admin_id).await.unwrap();
+        assert_eq!((saved.soft_limit_bytes, saved.hard_limit_bytes), (Some(150), Some(300)));
+
+        // 마스크를 만들거나 바꾸거나 지우면 같은 트랜잭션에서 사용량이 반영됨
+        let first = mask_repo
+            .create(&new_mask(group_id, format!("masks/quota_{}/a.png", suffix), 100))
+            .await
+            .unwrap();
+        assert_eq!(project_usage(&pool, project_id).await, (100, 1));
+
+        // 업로드 한도를 넘는 업로드 URL은 거부, 경고 한도 이상이면 경고
+        assert_eq!(mask_group_service.check_storage_quota(group_id, Some(10)).await.unwrap(), None);
+        let warning = mask_group_service.check_storage_quota(group_id, Some(100)).await.unwrap();
+        assert!(warning.unwrap().contains("soft limit"));
+        assert!(matches!(
+            mask_group_service.check_storage_quota(group_id, Some(250)).await,
+            Err(ServiceError::ValidationError(message)) if message.contains("quota exceeded")
+        ));
+
+        // 경고 한도를 넘으면 한 번만 이벤트 기록
+        let second = mask_repo
+            .create(&new_mask(group_id, format!("masks/quota_{}/b.png", suffix), 60))
+            .await
+            .unwrap();
+        assert_eq!(project_usage(&pool, project_id).await, (160, 2));
+        let mut update = UpdateMask::new(second.id);
+        update.file_size = Some(70);
+        mask_repo.update(second.id, &update).await.unwrap();
+        assert_eq!(project_usage(&pool, project_id).await, (170, 2));
+        let events = threshold_events(&pool, project_id).await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0]["scope"], "PROJECT");
+        assert_eq!(events[0]["bytes_used"], 160);
+        assert_eq!(events[0]["soft_limit_bytes"], 150);
+
+        // 경고 한도 아래로 내려가면 다시 알릴 수 있음
+        mask_repo.delete(first.id).await.unwrap();
+        assert_eq!(project_usage(&pool, project_id).await, (70, 1));
+        mask_repo
+            .create(&new_mask(group_id, format!("masks/quota_{}/c.png", suffix), 100))
+            .await
+            .unwrap();
+        assert_eq!(threshold_events(&pool, project_id).await.len(), 2);
+
+        let usage = use_case.get_project_usage(project_id, admin_id).await.unwrap();
+        assert_eq!((usage.bytes_used, usage.object_count), (170, 2));
+        assert_eq!(usage.quota_level, StorageQuotaLevel::SoftLimitExceeded);
+        assert_eq!(usage.users.len(), 1);
+        assert_eq!(usage.users[0].user_id, admin_id);
+        assert!(matches!(
+            use_case.get_project_usage(project_id, outsider_id).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+
+        let user_usage = use_case.get_user_usage(admin_id, admin_id).await.unwrap();
+        assert_eq!(user_usage.bytes_used, 170);
+        assert!(user_usage.quota.is_none());
+        assert!(matches!(
+            use_case.get_user_usage(admin_id, outsider_id).await,
+            Err(ServiceError::Unauthorized(_))
+        ));
+
+        // 사용자 한도도 업로드 URL 발급 시 적용됨
+        let user_quota = UpdateStorageQuotaRequest { soft_limit_bytes: None, hard_limit_bytes: Some(200) };
+        use_case.set_user_quota(admin_id, user_quota, outsider_id).await.unwrap();
+        assert!(matches!(
+            mask_group_service.check_storage_quota(group_id, Some(50)).await,
+            Err(ServiceError::ValidationError(message)) if message.starts_with(&format!("User {}", admin_id))
+        ));
+
+        // 사용량이 어긋나면 마스크 행으로 다시 계산
+        sqlx::query("UPDATE storage_usage SET bytes_used = 1 WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        use_case.recalculate_usage().await.unwrap();
+        assert_eq!(project_usage(&pool, project_id).await, (170, 2));
+
+        // 휴지통에서 영구 삭제하면 사용량 차감
+        sqlx::query("UPDATE annotation_mask_group SET deleted_at = CURRENT_TIMESTAMP - INTERVAL '31 days' WHERE id = $1")
+            .bind(group_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        TrashRepositoryImpl::new(pool.clone())
+            .purge_mask_group(group_id, chrono::Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(project_usage(&pool, project_id).await, (0, 0));
+
+        sqlx::query("DELETE FROM storage_deletion_queue WHERE object_key LIKE $1")
+            .bind(format!("masks/%{}%", suffix))
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query(&format!(
+            "DELETE FROM storage_deletion_queue WHERE object_key LIKE 'masks/annotation_{}/%'",
+            annotation.id
+        ))
+        .execute(&pool)
+        .await
+        .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![admin_id, outsider_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
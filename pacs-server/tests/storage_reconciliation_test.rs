@@ -0,0 +1,187 @@
+mod common;
+
+#[cfg(test)]
+mod storage_reconciliation_tests {
+    use std::sync::Arc;
+    use chrono::Duration;
+    use pacs_server::application::dto::StartStorageReconciliationRequest;
+    use pacs_server::application::use_cases::StorageReconciliationUseCase;
+    use pacs_server::domain::entities::{
+        mask_group_mask_prefix, NewAnnotation, StorageOrphanAction, StorageReconciliationFindingKind,
+        StorageReconciliationStatus,
+    };
+    use pacs_server::domain::repositories::AnnotationRepository;
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{AnnotationRepositoryImpl, StorageReconciliationRepositoryImpl};
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user};
+    use crate::common::storage::MemoryObjectStorage;
+
+    /// 2일 전에 만들어진 마스크 행 추가
+    async fn insert_old_mask(pool: &PgPool, group_id: i32, file_path: &str, file_size: i64, checksum: Option<&str>) -> i32 {
+        sqlx::query_scalar(
+            "INSERT INTO annotation_mask (mask_group_id, file_path, slice_index, file_size, checksum, created_at)
+             VALUES ($1, $2, 0, $3, $4, CURRENT_TIMESTAMP - INTERVAL '2 days') RETURNING id"
+        )
+        .bind(group_id)
+        .bind(file_path)
+        .bind(file_size)
+        .bind(checksum)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_reconciliation_reports_and_handles_orphans() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let user_id = create_user(&pool, &format!("reconcile_user_{}", suffix)).await;
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("reconcile_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let annotation = AnnotationRepositoryImpl::new(pool.clone())
+            .create(NewAnnotation {
+                project_id,
+                user_id,
+                study_uid: "1.2.3.reconcile".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "polygon"}),
+                is_shared: false,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'liver') RETURNING id"
+        )
+        .bind(annotation.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let prefix = mask_group_mask_prefix(annotation.id, group_id);
+        let key = |name: &str| format!("{}{}_{}.png", prefix, name, suffix);
+        let md5 = "9e107d9d372bb6826bd81d3542a419d6";
+        let old = Duration::days(2);
+
+        let storage = Arc::new(MemoryObjectStorage::default());
+        // 정상 행, 객체가 없는 행, 크기/체크섬이 다른 행
+        insert_old_mask(&pool, group_id, &key("ok"), 10, Some(md5)).await;
+        storage.put_object(&key("ok"), 10, Some(&format!("\"{}\"", md5)), old);
+        let dangling_id = insert_old_mask(&pool, group_id, &key("dangling"), 10, None).await;
+        let mismatch_id = insert_old_mask(&pool, group_id, &key("mismatch"), 100, Some(md5)).await;
+        storage.put_object(&key("mismatch"), 50, Some("\"e4d909c290d0fb1ca068ffaddf22cbd0\""), old);
+        // 행이 없는 객체: 오래된 객체, 업로드 중일 수 있는 최근 객체, 남아 있는 어노테이션의 파일
+        storage.put_object(&key("orphan"), 7, None, old);
+        storage.put_object(&key("recent"), 7, None, Duration::minutes(5));
+        let annotation_file = format!("annotations/annotation_{}/measurements_{}.json", annotation.id, suffix);
+        storage.put_object(&annotation_file, 3, None, old);
+
+        let use_case = StorageReconciliationUseCase::new(
+            Arc::new(StorageReconciliationRepositoryImpl::new(pool.clone())),
+            storage.clone(),
+        );
+
+        // 업로드 중인 객체를 보호하기 위해 유예 기간 없이 격리/삭제할 수 없음
+        let invalid = use_case
+            .start(StartStorageReconciliationRequest {
+                orphan_action: Some(StorageOrphanAction::Delete),
+                grace_period_hours: Some(0),
+            }, user_id)
+            .await;
+        assert!(matches!(invalid, Err(ServiceError::ValidationError(_))));
+
+        let run = use_case
+            .start(StartStorageReconciliationRequest {
+                orphan_action: Some(StorageOrphanAction::Quarantine),
+                grace_period_hours: Some(24),
+            }, user_id)
+            .await
+            .unwrap();
+        assert_eq!(run.status, StorageReconciliationStatus::Completed);
+        assert_eq!(run.triggered_by, Some(user_id));
+        assert!(run.orphans_quarantined >= 1);
+
+        let report = use_case.get_report(run.id, Some(10_000)).await.unwrap();
+        let ours: Vec<_> = report.findings.iter().filter(|f| f.object_key.contains(&suffix)).collect();
+        let orphan = ours.iter().find(|f| f.kind == StorageReconciliationFindingKind::OrphanObject).unwrap();
+        assert_eq!(orphan.object_key, key("orphan"));
+        assert_eq!(orphan.action, StorageOrphanAction::Quarantine);
+        let quarantined = orphan.quarantine_key.clone().unwrap();
+        assert!(quarantined.starts_with(&format!("quarantine/run_{}/", run.id)));
+        assert!(storage.contains(&quarantined) && !storage.contains(&key("orphan")));
+
+        let dangling = ours.iter().find(|f| f.kind == StorageReconciliationFindingKind::DanglingRow).unwrap();
+        assert_eq!(dangling.mask_id, Some(dangling_id));
+        let size = ours.iter().find(|f| f.kind == StorageReconciliationFindingKind::SizeMismatch).unwrap();
+        assert_eq!((size.mask_id, size.expected.as_deref(), size.actual.as_deref()), (Some(mismatch_id), Some("100"), Some("50")));
+        assert!(ours.iter().any(|f| f.kind == StorageReconciliationFindingKind::ChecksumMismatch && f.mask_id == Some(mismatch_id)));
+        // 정상 행, 최근 객체, 어노테이션 파일은 보고하지 않음
+        assert_eq!(ours.len(), 4);
+        assert!(storage.contains(&key("recent")));
+
+        // 삭제 모드는 고아 객체를 삭제 대기열에 예약하고, 예약된 객체는 다시 보고하지 않음
+        storage.put_object(&key("orphan_delete"), 7, None, old);
+        let delete_run = use_case
+            .reconcile(None, StorageOrphanAction::Delete, 24)
+            .await
+            .unwrap()
+            .expect("no other reconciliation should be running");
+        assert_eq!(delete_run.triggered_by, None);
+        let reason: String = sqlx::query_scalar(
+            "SELECT reason FROM storage_deletion_queue WHERE object_key = $1 AND status = 'PENDING'"
+        )
+        .bind(key("orphan_delete"))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(reason, "RECONCILIATION_ORPHAN");
+
+        let report_run = use_case.reconcile(None, StorageOrphanAction::Report, 24).await.unwrap().unwrap();
+        let report = use_case.get_report(report_run.id, Some(10_000)).await.unwrap();
+        assert!(!report.findings.iter().any(|f| f.object_key == key("orphan_delete")));
+
+        // 진행 중인 실행이 있으면 새 실행을 시작하지 않음
+        let running_id: i32 = sqlx::query_scalar(
+            "INSERT INTO storage_reconciliation_run (grace_period_hours) VALUES (24) RETURNING id"
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let conflict = use_case.start(StartStorageReconciliationRequest::default(), user_id).await;
+        assert!(matches!(conflict, Err(ServiceError::AlreadyExists(_))));
+
+        let runs = use_case.list_runs().await.unwrap();
+        assert!(runs.runs.iter().any(|r| r.id == delete_run.id));
+
+        sqlx::query("DELETE FROM storage_reconciliation_run WHERE id = ANY($1)")
+            .bind(vec![run.id, delete_run.id, report_run.id, running_id])
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM storage_deletion_queue WHERE object_key = $1")
+            .bind(key("orphan_delete"))
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
@@ -0,0 +1,163 @@
+mod common;
+
+#[cfg(test)]
+mod trash_tests {
+    use std::sync::Arc;
+    use pacs_server::application::use_cases::{StorageDeletionUseCase, TrashUseCase};
+    use pacs_server::domain::entities::{NewAnnotation, STORAGE_DELETION_RETRY_POLICY};
+    use pacs_server::domain::repositories::{AnnotationRepository, MaskGroupRepository};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MaskGroupRepositoryImpl, StorageDeletionRepositoryImpl, TrashRepositoryImpl,
+    };
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user};
+    use crate::common::storage::MemoryObjectStorage;
+
+    async fn create_mask_group(pool: &PgPool, annotation_id: i32, file_path: &str) -> i32 {
+        let group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'liver') RETURNING id"
+        )
+        .bind(annotation_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO annotation_mask (mask_group_id, file_path, slice_index) VALUES ($1, $2, 0)")
+            .bind(group_id)
+            .bind(file_path)
+            .execute(pool)
+            .await
+            .unwrap();
+        group_id
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_restore_and_purge() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let user_id = create_user(&pool, &format!("trash_user_{}", suffix)).await;
+        let project_id: i32 = sqlx::query_scalar("INSERT INTO security_project (name) VALUES ($1) RETURNING id")
+            .bind(format!("trash_project_{}", suffix))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO security_user_project (user_id, project_id) VALUES ($1, $2)")
+            .bind(user_id)
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let mask_group_repo = MaskGroupRepositoryImpl::new(pool.clone());
+        let annotation = annotation_repo
+            .create(NewAnnotation {
+                project_id,
+                user_id,
+                study_uid: "1.2.3.trash".to_string(),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                data: serde_json::json!({"type": "polygon"}),
+                is_shared: false,
+                viewer_software: None,
+                description: None,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        let cascaded_group = create_mask_group(&pool, annotation.id, "masks/cascaded.png").await;
+        let single_group = create_mask_group(&pool, annotation.id, "masks/single.png").await;
+
+        let storage = Arc::new(MemoryObjectStorage::default());
+        let use_case = TrashUseCase::new(Arc::new(TrashRepositoryImpl::new(pool.clone())), 30);
+        let deletion_use_case = StorageDeletionUseCase::new(
+            Arc::new(StorageDeletionRepositoryImpl::new(pool.clone())),
+            storage.clone(),
+            STORAGE_DELETION_RETRY_POLICY,
+        );
+
+        // 마스크 그룹 하나를 먼저 삭제한 뒤 어노테이션 삭제 (나머지 그룹은 어노테이션과 함께 이동)
+        mask_group_repo.delete(single_group, user_id).await.unwrap();
+        assert!(mask_group_repo.get_by_id(single_group).await.unwrap().is_none());
+        assert!(annotation_repo.delete(annotation.id, user_id).await.unwrap());
+        assert!(!annotation_repo.delete(annotation.id, user_id).await.unwrap());
+        assert!(annotation_repo.find_by_id(annotation.id).await.unwrap().is_none());
+        assert!(annotation_repo.find_by_project_id(project_id).await.unwrap().is_empty());
+        assert!(mask_group_repo.get_by_id(cascaded_group).await.unwrap().is_none());
+
+        let trash = use_case.list_trash(project_id, user_id).await.unwrap();
+        assert_eq!(trash.annotations.len(), 1);
+        assert_eq!(trash.annotations[0].mask_group_count, 2);
+        assert!(trash.mask_groups.is_empty());
+
+        // 어노테이션이 휴지통에 있으면 마스크 그룹만 복원할 수 없음
+        let blocked = use_case.restore_mask_group(project_id, single_group, user_id).await;
+        assert!(matches!(blocked, Err(ServiceError::ValidationError(_))));
+
+        // 어노테이션 복원 시 함께 삭제된 그룹만 되살아남
+        use_case.restore_annotation(project_id, annotation.id, user_id).await.unwrap();
+        assert!(annotation_repo.find_by_id(annotation.id).await.unwrap().is_some());
+        assert!(mask_group_repo.get_by_id(cascaded_group).await.unwrap().is_some());
+        assert!(mask_group_repo.get_by_id(single_group).await.unwrap().is_none());
+        let history = annotation_repo.get_history(annotation.id).await.unwrap();
+        assert!(history.iter().any(|h| h.action == "delete"));
+        assert!(history.iter().any(|h| h.action == "restore"));
+
+        let trash = use_case.list_trash(project_id, user_id).await.unwrap();
+        assert!(trash.annotations.is_empty());
+        assert_eq!(trash.mask_groups.len(), 1);
+
+        // 보관 기간이 남은 항목은 정리되지 않음
+        use_case.purge_expired().await.unwrap();
+        deletion_use_case.process_due().await.unwrap();
+        assert!(mask_group_repo.get_by_id(cascaded_group).await.unwrap().is_some());
+        assert!(!storage.deleted().contains(&"masks/single.png".to_string()));
+
+        // 보관 기간이 지나면 행을 영구 삭제하고, 삭제 대기열이 마스크 파일을 지움
+        sqlx::query("UPDATE annotation_mask_group SET deleted_at = deleted_at - INTERVAL '31 days' WHERE id = $1")
+            .bind(single_group)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(use_case.purge_expired().await.unwrap().objects_queued >= 1);
+        assert!(!storage.deleted().contains(&"masks/single.png".to_string()));
+        deletion_use_case.process_due().await.unwrap();
+        assert!(storage.deleted().contains(&"masks/single.png".to_string()));
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM annotation_mask_group WHERE id = $1")
+            .bind(single_group)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+
+        annotation_repo.delete(annotation.id, user_id).await.unwrap();
+        sqlx::query("UPDATE annotation_annotation SET deleted_at = deleted_at - INTERVAL '31 days' WHERE id = $1")
+            .bind(annotation.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        use_case.purge_expired().await.unwrap();
+        deletion_use_case.process_due().await.unwrap();
+        assert!(storage.deleted().contains(&"masks/cascaded.png".to_string()));
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM annotation_annotation WHERE id = $1")
+            .bind(annotation.id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
@@ -0,0 +1,265 @@
+mod common;
+
+#[cfg(test)]
+mod webhook_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::webhook_dto::{CreateWebhookRequest, UpdateWebhookRequest, WebhookDeliveryQuery};
+    use pacs_server::application::use_cases::WebhookUseCase;
+    use pacs_server::domain::entities::webhook::{
+        sign_webhook_payload, WebhookDeliveryStatus, WebhookEventType, WEBHOOK_SIGNATURE_HEADER,
+        WEBHOOK_TIMESTAMP_HEADER,
+    };
+    use pacs_server::domain::entities::{AnnotationReviewStatus, NewAnnotation, RetryPolicy};
+    use pacs_server::domain::repositories::AnnotationRepository;
+    use pacs_server::domain::services::{AnnotationService, AnnotationServiceImpl, MaskGroupService, MaskGroupServiceImpl};
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{
+        AnnotationRepositoryImpl, MaskGroupRepositoryImpl, ProjectRepositoryImpl, UserRepositoryImpl, WebhookRepositoryImpl,
+    };
+    use serde_json::json;
+    use crate::common::{setup_pool, create_member};
+
+    /// 서명 헤더가 본문과 서명 키로 계산한 값과 같은지
+    fn has_valid_signature(request: &mockito::Request, secret: &str) -> bool {
+        let header = |name: &str| {
+            request
+                .header(name)
+                .first()
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let (Some(timestamp), Some(signature)) = (header(WEBHOOK_TIMESTAMP_HEADER), header(WEBHOOK_SIGNATURE_HEADER)) else {
+            return false;
+        };
+        let Ok(timestamp) = timestamp.parse::<i64>() else {
+            return false;
+        };
+        let body = request.utf8_lossy_body().unwrap_or_default();
+        signature == sign_webhook_payload(secret, timestamp, &body)
+    }
+
+    #[tokio::test]
+    async fn test_outbox_events_are_signed_retried_and_dead_lettered() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar(
+            "INSERT INTO security_project (name, status) VALUES ($1, 'COMPLETED') RETURNING id"
+        )
+        .bind(format!("webhook_project_{}", suffix))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let admin_id = create_member(&pool, project_id, &format!("wh_admin_{}", suffix), "PROJECT_ADMIN").await;
+        let annotator_id = create_member(&pool, project_id, &format!("wh_annotator_{}", suffix), "ANNOTATOR").await;
+
+        // 실패한 전송은 바로 다시 시도하고 세 번째 실패에서 dead-letter
+        let retry_policy = RetryPolicy::new(3, 0, 0);
+        let use_case = WebhookUseCase::new(
+            Arc::new(WebhookRepositoryImpl::new(pool.clone())),
+            Arc::new(ProjectRepositoryImpl::new(pool.clone())),
+            retry_policy,
+        );
+
+        let mut server = mockito::Server::new_async().await;
+        let request = |path: &str, event_types| CreateWebhookRequest {
+            name: format!("hook {}", path),
+            url: format!("{}{}", server.url(), path),
+            event_types,
+        };
+
+        // 프로젝트 관리자만 관리할 수 있고 URL은 http/https만 허용
+        let result = use_case.create_webhook(project_id, request("/approved", None), annotator_id).await;
+        assert!(matches!(result, Err(ServiceError::Unauthorized(_))));
+        let invalid = CreateWebhookRequest { url: "ftp://example.com/hook".to_string(), ..request("/approved", None) };
+        let result = use_case.create_webhook(project_id, invalid, admin_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        let approved_hook = use_case
+            .create_webhook(project_id, request("/approved", Some(vec![WebhookEventType::AnnotationApproved])), admin_id)
+            .await
+            .unwrap();
+        let failing_hook = use_case.create_webhook(project_id, request("/failing", None), admin_id).await.unwrap();
+        let approved_secret = approved_hook.secret.clone().unwrap();
+        assert!(approved_secret.starts_with("whsec_"));
+        let listed = use_case.get_webhook(project_id, approved_hook.id, admin_id).await.unwrap();
+        assert!(listed.secret.is_none());
+        assert_eq!(listed.event_types, vec!["ANNOTATION_APPROVED".to_string()]);
+
+        let approved_mock = server
+            .mock("POST", "/approved")
+            .match_header("x-pacs-event", "ANNOTATION_APPROVED")
+            .match_request(move |request| has_valid_signature(request, &approved_secret))
+            .with_status(204)
+            .expect(1)
+            .create_async()
+            .await;
+        let failing_mock = server
+            .mock("POST", "/failing")
+            .with_status(500)
+            .with_body("upstream unavailable")
+            .expect(6)
+            .create_async()
+            .await;
+
+        // 어노테이션 승인과 마스크 그룹 업로드 완료가 아웃박스에 기록됨
+        let annotation_repo = AnnotationRepositoryImpl::new(pool.clone());
+        let annotation_service = AnnotationServiceImpl::new(
+            annotation_repo.clone(),
+            UserRepositoryImpl::new(pool.clone()),
+            ProjectRepositoryImpl::new(pool.clone()),
+        );
+        let annotation = annotation_service
+            .create_annotation(NewAnnotation {
+                project_id,
+                user_id: annotator_id,
+                study_uid: format!("1.2.840.{}.1", project_id),
+                series_uid: None,
+                instance_uid: None,
+                tool_name: "Polygon Tool".to_string(),
+                tool_version: None,
+                viewer_software: None,
+                data: json!({"points": [[0, 0], [1, 1]]}),
+                description: None,
+                is_shared: false,
+                measurement_values: None,
+            })
+            .await
+            .unwrap();
+        annotation_repo
+            .update_review_status(annotation.id, annotator_id, AnnotationReviewStatus::Draft, AnnotationReviewStatus::Submitted, None)
+            .await
+            .unwrap()
+            .unwrap();
+        annotation_repo
+            .update_review_status(annotation.id, admin_id, AnnotationReviewStatus::Submitted, AnnotationReviewStatus::Approved, Some("looks good"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mask_group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'group') RETURNING id"
+        )
+        .bind(annotation.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let mask_group_service = MaskGroupServiceImpl::new(
+            Arc::new(MaskGroupRepositoryImpl::new(pool.clone())),
+            Arc::new(annotation_repo.clone()),
+            Arc::new(UserRepositoryImpl::new(pool.clone())),
+        );
+        mask_group_service.complete_upload(mask_group_id, annotator_id).await.unwrap();
+
+        let outbox: Vec<(String, serde_json::Value)> = sqlx::query_as(
+            "SELECT event_type, payload FROM webhook_outbox_event WHERE project_id = $1 ORDER BY id"
+        )
+        .bind(project_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(outbox.len(), 2);
+        assert_eq!(outbox[0].0, "ANNOTATION_APPROVED");
+        assert_eq!(outbox[0].1["annotation_id"], annotation.id);
+        assert_eq!(outbox[0].1["approved_by"], admin_id);
+        assert_eq!(outbox[1].0, "MASK_GROUP_UPLOADED");
+        assert_eq!(outbox[1].1["mask_group_id"], mask_group_id);
+
+        // 첫 시도 + 재시도 두 번
+        for _ in 0..3 {
+            use_case.dispatch_once().await.unwrap();
+        }
+        approved_mock.assert_async().await;
+        failing_mock.assert_async().await;
+
+        let delivered = use_case
+            .list_deliveries(project_id, WebhookDeliveryQuery { webhook_id: Some(approved_hook.id), ..Default::default() }, admin_id)
+            .await
+            .unwrap()
+            .deliveries;
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].status, WebhookDeliveryStatus::Succeeded);
+        assert_eq!((delivered[0].attempt_count, delivered[0].last_status_code), (1, Some(204)));
+
+        let dead_letters = use_case
+            .list_dead_letters(project_id, WebhookDeliveryQuery::default(), admin_id)
+            .await
+            .unwrap()
+            .deliveries;
+        assert_eq!(dead_letters.len(), 2);
+        assert!(dead_letters.iter().all(|d| d.webhook_id == failing_hook.id && d.attempt_count == 3));
+        let mask_delivery = dead_letters.iter().find(|d| d.event_type == "MASK_GROUP_UPLOADED").unwrap();
+        let log = use_case.get_delivery_log(project_id, mask_delivery.id, admin_id).await.unwrap();
+        assert_eq!(log.attempts.iter().map(|a| a.attempt_number).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert!(log.attempts.iter().all(|a| a.status_code == Some(500)));
+        assert!(log.attempts[0].error.as_deref().unwrap().contains("upstream unavailable"));
+
+        // 성공한 전송은 다시 시도할 수 없음
+        let result = use_case.retry_delivery(project_id, delivered[0].id, admin_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 수신 측이 복구된 뒤 dead-letter 하나를 다시 전송
+        failing_mock.remove_async().await;
+        let recovered_mock = server
+            .mock("POST", "/failing")
+            .match_header("x-pacs-event", "MASK_GROUP_UPLOADED")
+            .match_header("x-pacs-delivery", mask_delivery.id.to_string().as_str())
+            .with_status(200)
+            .expect(1)
+            .create_async()
+            .await;
+        let retried = use_case.retry_delivery(project_id, mask_delivery.id, admin_id).await.unwrap();
+        assert_eq!((retried.status, retried.attempt_count), (WebhookDeliveryStatus::Pending, 0));
+        use_case.dispatch_once().await.unwrap();
+        recovered_mock.assert_async().await;
+
+        let log = use_case.get_delivery_log(project_id, mask_delivery.id, admin_id).await.unwrap();
+        assert_eq!(log.delivery.status, WebhookDeliveryStatus::Succeeded);
+        assert_eq!(log.attempts.len(), 4);
+        assert_eq!((log.attempts[3].attempt_number, log.attempts[3].status_code), (4, Some(200)));
+        let dead_letters = use_case.list_dead_letters(project_id, WebhookDeliveryQuery::default(), admin_id).await.unwrap();
+        assert_eq!(dead_letters.deliveries.len(), 1);
+
+        // 비활성화한 구독은 새 이벤트를 받지 않음
+        use_case
+            .update_webhook(project_id, approved_hook.id, UpdateWebhookRequest { is_active: Some(false), ..Default::default() }, admin_id)
+            .await
+            .unwrap();
+        use_case.delete_webhook(project_id, failing_hook.id, admin_id).await.unwrap();
+        annotation_repo
+            .update_review_status(annotation.id, admin_id, AnnotationReviewStatus::Approved, AnnotationReviewStatus::Submitted, None)
+            .await
+            .unwrap();
+        annotation_repo
+            .update_review_status(annotation.id, admin_id, AnnotationReviewStatus::Submitted, AnnotationReviewStatus::Approved, None)
+            .await
+            .unwrap();
+        use_case.dispatch_once().await.unwrap();
+        let deliveries = use_case.list_deliveries(project_id, WebhookDeliveryQuery::default(), admin_id).await.unwrap();
+        assert_eq!(deliveries.deliveries.len(), 1);
+        let undispatched: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM webhook_outbox_event WHERE project_id = $1 AND dispatched_at IS NULL"
+        )
+        .bind(project_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(undispatched, 0);
+
+        sqlx::query("DELETE FROM annotation_annotation WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![admin_id, annotator_id])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}
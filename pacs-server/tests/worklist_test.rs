@@ -0,0 +1,215 @@
+mod common;
+
+#[cfg(test)]
+mod worklist_tests {
+    use std::sync::Arc;
+    use pacs_server::application::dto::worklist_dto::{
+        AssignWorklistTaskRequest, CompleteWorklistTaskRequest, CreateWorklistTasksRequest, SkipWorklistTaskRequest,
+        WorklistTaskListQuery,
+    };
+    use pacs_server::application::use_cases::WorklistUseCase;
+    use pacs_server::domain::entities::WorklistTaskStatus;
+    use pacs_server::domain::ServiceError;
+    use pacs_server::infrastructure::repositories::{ProjectRepositoryImpl, WorklistRepositoryImpl};
+    use sqlx::PgPool;
+    use crate::common::{setup_pool, create_user, create_member};
+
+    async fn create_study(pool: &PgPool, project_id: i32, study_uid: &str, study_date: &str, modality: &str) -> i32 {
+        let study_id: i32 = sqlx::query_scalar(
+            "INSERT INTO project_data_study (project_id, study_uid, study_description, study_date)
+             VALUES ($1, $2, 'Chest study', $3::DATE) RETURNING id"
+        )
+        .bind(project_id)
+        .bind(study_uid)
+        .bind(study_date)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO project_data_series (study_id, series_uid, modality) VALUES ($1, $2, $3)")
+            .bind(study_id)
+            .bind(format!("{}.1", study_uid))
+            .bind(modality)
+            .execute(pool)
+            .await
+            .unwrap();
+        study_id
+    }
+
+    async fn create_annotation(pool: &PgPool, project_id: i32, user_id: i32, study_uid: &str) -> i32 {
+        sqlx::query_scalar(
+            "INSERT INTO annotation_annotation (project_id, user_id, study_uid, tool_name, data, is_shared)
+             VALUES ($1, $2, $3, 'Polygon Tool', '{}'::jsonb, true) RETURNING id"
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .bind(study_uid)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_worklist_assignment_claim_and_progress() {
+        let pool = setup_pool().await;
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let project_id: i32 = sqlx::query_scalar(
+            "INSERT INTO security_project (name, status) VALUES ($1, 'COMPLETED') RETURNING id"
+        )
+        .bind(format!("worklist_project_{}", suffix))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let admin_id = create_member(&pool, project_id, &format!("wl_admin_{}", suffix), "PROJECT_ADMIN").await;
+        let reader_a = create_member(&pool, project_id, &format!("wl_reader_a_{}", suffix), "ANNOTATOR").await;
+        let reader_b = create_member(&pool, project_id, &format!("wl_reader_b_{}", suffix), "ANNOTATOR").await;
+        let outsider = create_user(&pool, &format!("wl_outsider_{}", suffix)).await;
+
+        let uid = |n: u32| format!("1.2.840.{}.{}", project_id, n);
+        let ct_1 = create_study(&pool, project_id, &uid(1), "2025-01-10", "CT").await;
+        let ct_2 = create_study(&pool, project_id, &uid(2), "2025-02-10", "CT").await;
+        let mr_1 = create_study(&pool, project_id, &uid(3), "2025-03-10", "MR").await;
+
+        let use_case = WorklistUseCase::new(Arc::new(WorklistRepositoryImpl::new(pool.clone())), Arc::new(ProjectRepositoryImpl::new(pool.clone())));
+
+        // 관리자만 작업을 만들 수 있고, 담당자는 프로젝트 멤버여야 함
+        let by_modality = CreateWorklistTasksRequest {
+            modality: Some("ct".to_string()),
+            assignee_ids: vec![reader_a, reader_b],
+            priority: 5,
+            ..Default::default()
+        };
+        let denied = use_case.create_tasks(project_id, by_modality.clone(), reader_a).await;
+        assert!(matches!(denied, Err(ServiceError::Unauthorized(_))));
+        let invalid_assignee = CreateWorklistTasksRequest { assignee_ids: vec![outsider], ..by_modality.clone() };
+        let result = use_case.create_tasks(project_id, invalid_assignee, admin_id).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 필터로 생성: CT Study 두 개가 두 판독자에게 돌아가며 배정됨
+        let created = use_case.create_tasks(project_id, by_modality, admin_id).await.unwrap();
+        assert_eq!(created.tasks.len(), 2);
+        assert_eq!(created.tasks[0].study_id, ct_1);
+        assert_eq!(created.tasks[0].assignee_id, Some(reader_a));
+        assert_eq!(created.tasks[1].study_id, ct_2);
+        assert_eq!(created.tasks[1].assignee_id, Some(reader_b));
+        let task_a = created.tasks[0].id;
+        let task_b = created.tasks[1].id;
+
+        // 직접 지정: 이미 작업이 있는 Study는 건너뜀
+        let manual = CreateWorklistTasksRequest {
+            study_ids: Some(vec![ct_1, mr_1]),
+            ..Default::default()
+        };
+        let created = use_case.create_tasks(project_id, manual, admin_id).await.unwrap();
+        assert_eq!(created.tasks.len(), 1);
+        assert_eq!(created.tasks[0].assignee_id, None);
+        assert_eq!(created.skipped_study_ids, vec![ct_1]);
+        let task_mr = created.tasks[0].id;
+
+        let unassigned = use_case
+            .list_tasks(project_id, WorklistTaskListQuery { unassigned: Some(true), ..Default::default() }, reader_a)
+            .await
+            .unwrap();
+        assert_eq!(unassigned.tasks.iter().map(|t| t.id).collect::<Vec<_>>(), vec![task_mr]);
+
+        // 다른 판독자에게 배정된 작업은 가져갈 수 없음
+        let result = use_case.claim_task(project_id, task_a, reader_b).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let claimed = use_case.claim_task(project_id, task_a, reader_a).await.unwrap();
+        assert_eq!(claimed.status, WorklistTaskStatus::InProgress);
+        let result = use_case.claim_task(project_id, task_a, reader_a).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 내려놓으면 배정 해제된 대기 작업이 되어 다른 판독자가 가져갈 수 있음
+        let released = use_case.release_task(project_id, task_a, reader_a).await.unwrap();
+        assert_eq!(released.status, WorklistTaskStatus::Todo);
+        assert_eq!(released.assignee_id, None);
+        let claimed = use_case.claim_task(project_id, task_a, reader_b).await.unwrap();
+        assert_eq!(claimed.assignee_id, Some(reader_b));
+
+        // 진행 중인 작업은 담당자를 바꿀 수 없음
+        let result = use_case
+            .assign_task(project_id, task_a, AssignWorklistTaskRequest { assignee_id: Some(reader_a) }, admin_id)
+            .await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        // 완료 시 결과물은 작업 Study에 속해야 함
+        let study_uid = claimed.study_uid.clone();
+        let annotation_id = create_annotation(&pool, project_id, reader_b, &study_uid).await;
+        let other_annotation = create_annotation(&pool, project_id, reader_b, "1.2.3.other").await;
+        let mask_group_id: i32 = sqlx::query_scalar(
+            "INSERT INTO annotation_mask_group (annotation_id, group_name) VALUES ($1, 'worklist') RETURNING id"
+        )
+        .bind(annotation_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let wrong_study = CompleteWorklistTaskRequest {
+            annotation_ids: vec![annotation_id, other_annotation],
+            ..Default::default()
+        };
+        let result = use_case.complete_task(project_id, task_a, wrong_study, reader_b).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+        let complete = CompleteWorklistTaskRequest {
+            annotation_ids: vec![annotation_id],
+            mask_group_ids: vec![mask_group_id],
+            note: Some("done".to_string()),
+        };
+        let result = use_case.complete_task(project_id, task_a, complete.clone(), reader_a).await;
+        assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+        let completed = use_case.complete_task(project_id, task_a, complete, reader_b).await.unwrap();
+        assert_eq!(completed.status, WorklistTaskStatus::Done);
+        assert!(completed.completed_at.is_some());
+        let task = use_case.get_task(project_id, task_a, reader_a).await.unwrap();
+        assert_eq!(task.annotation_ids, vec![annotation_id]);
+        assert_eq!(task.mask_group_ids, vec![mask_group_id]);
+
+        // 배정되지 않은 작업은 가져가지 않고도 건너뛸 수 있음
+        let skipped = use_case
+            .skip_task(project_id, task_mr, SkipWorklistTaskRequest { note: Some("wrong modality".to_string()) }, reader_a)
+            .await
+            .unwrap();
+        assert_eq!(skipped.status, WorklistTaskStatus::Skipped);
+        assert_eq!(skipped.assignee_id, Some(reader_a));
+
+        // 진행 현황
+        let result = use_case.get_progress(project_id, outsider).await;
+        assert!(matches!(result, Err(ServiceError::Unauthorized(_))));
+        let progress = use_case.get_progress(project_id, admin_id).await.unwrap();
+        assert_eq!(progress.project.total, 3);
+        assert_eq!((progress.project.todo, progress.project.done, progress.project.skipped), (1, 1, 1));
+        let user_b = progress.users.iter().find(|p| p.assignee_id == Some(reader_b)).unwrap();
+        assert_eq!((user_b.todo, user_b.done), (1, 1));
+        assert!(progress.users.iter().all(|p| p.assignee_id.is_some()));
+        assert_eq!(
+            use_case.get_task(project_id, task_b, reader_b).await.unwrap().status,
+            WorklistTaskStatus::Todo
+        );
+
+        // 관리자가 다시 열면 결과물 연결이 해제됨
+        let result = use_case.reopen_task(project_id, task_a, reader_b).await;
+        assert!(matches!(result, Err(ServiceError::Unauthorized(_))));
+        let reopened = use_case.reopen_task(project_id, task_a, admin_id).await.unwrap();
+        assert_eq!(reopened.status, WorklistTaskStatus::Todo);
+        assert_eq!(reopened.assignee_id, Some(reader_b));
+        assert!(reopened.annotation_ids.is_empty());
+
+        sqlx::query("DELETE FROM annotation_annotation WHERE id = ANY($1)")
+            .bind(vec![annotation_id, other_annotation])
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_project WHERE id = $1")
+            .bind(project_id)
+            .execute(&pool)
+            .await
+            .ok();
+        sqlx::query("DELETE FROM security_user WHERE id = ANY($1)")
+            .bind(vec![admin_id, reader_a, reader_b, outsider])
+            .execute(&pool)
+            .await
+            .ok();
+    }
+}